@@ -13,6 +13,7 @@ library Messages {
     uint8 private constant SLOW_ORDER_RESPONSE = 2;
     uint8 private constant FAST_MARKET_ORDER = 11;
     uint8 private constant FAST_FILL = 12;
+    uint8 private constant FAST_MARKET_ORDER_V2 = 13;
 
     // VAA fields.
     uint256 private constant SIG_COUNT_OFFSET = 5;
@@ -47,6 +48,15 @@ library Messages {
         bytes redeemerMessage;
     }
 
+    struct FastMarketOrderV2 {
+        FastMarketOrder order;
+        uint64 gasDropoff;
+        uint64 gasDropoffFee;
+        uint64 relayerFee;
+        bytes32 destinationMint;
+        uint64 destinationMinAmountOut;
+    }
+
     struct SlowOrderResponse {
         uint64 baseFee;
     }
@@ -110,6 +120,63 @@ library Messages {
         _checkLength(encoded, offset);
     }
 
+    function encode(FastMarketOrderV2 memory orderV2)
+        internal
+        pure
+        returns (bytes memory encoded)
+    {
+        FastMarketOrder memory order = orderV2.order;
+        encoded = abi.encodePacked(
+            FAST_MARKET_ORDER_V2,
+            order.amountIn,
+            order.minAmountOut,
+            order.targetChain,
+            order.redeemer,
+            order.sender,
+            order.refundAddress,
+            order.maxFee,
+            order.initAuctionFee,
+            order.deadline,
+            _encodeRedeemerMessage(order.redeemerMessage)
+        );
+        encoded = abi.encodePacked(
+            encoded,
+            orderV2.gasDropoff,
+            orderV2.gasDropoffFee,
+            orderV2.relayerFee,
+            orderV2.destinationMint,
+            orderV2.destinationMinAmountOut
+        );
+    }
+
+    function decodeFastMarketOrderV2(bytes memory encoded)
+        internal
+        pure
+        returns (FastMarketOrderV2 memory orderV2)
+    {
+        uint256 offset = _checkPayloadId(encoded, 0, FAST_MARKET_ORDER_V2);
+
+        // Parse the encoded message.
+        FastMarketOrder memory order = orderV2.order;
+        (order.amountIn, offset) = encoded.asUint64Unchecked(offset);
+        (order.minAmountOut, offset) = encoded.asUint64Unchecked(offset);
+        (order.targetChain, offset) = encoded.asUint16Unchecked(offset);
+        (order.redeemer, offset) = encoded.asBytes32Unchecked(offset);
+        (order.sender, offset) = encoded.asBytes32Unchecked(offset);
+        (order.refundAddress, offset) = encoded.asBytes32Unchecked(offset);
+        (order.maxFee, offset) = encoded.asUint64Unchecked(offset);
+        (order.initAuctionFee, offset) = encoded.asUint64Unchecked(offset);
+        (order.deadline, offset) = encoded.asUint32Unchecked(offset);
+        (order.redeemerMessage, offset) = _decodeRedeemerMessage(encoded, offset);
+        (orderV2.gasDropoff, offset) = encoded.asUint64Unchecked(offset);
+        (orderV2.gasDropoffFee, offset) = encoded.asUint64Unchecked(offset);
+        (orderV2.relayerFee, offset) = encoded.asUint64Unchecked(offset);
+        (orderV2.destinationMint, offset) = encoded.asBytes32Unchecked(offset);
+        (orderV2.destinationMinAmountOut, offset) = encoded.asUint64Unchecked(offset);
+
+        _checkLength(encoded, offset);
+    }
+
     function encode(FastFill memory fastFill) internal pure returns (bytes memory encoded) {
         encoded = abi.encodePacked(
             FAST_FILL,
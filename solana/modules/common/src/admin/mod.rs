@@ -1,6 +1,6 @@
 pub mod utils;
 
-use anchor_lang::prelude::Pubkey;
+use anchor_lang::prelude::*;
 
 pub trait Ownable {
     fn owner(&self) -> &Pubkey;
@@ -12,6 +12,11 @@ pub trait PendingOwner: Ownable {
     fn pending_owner(&self) -> &Option<Pubkey>;
 
     fn pending_owner_mut(&mut self) -> &mut Option<Pubkey>;
+
+    /// Slot after which the pending owner can no longer accept ownership.
+    fn pending_owner_expiry_slot(&self) -> u64;
+
+    fn pending_owner_expiry_slot_mut(&mut self) -> &mut u64;
 }
 
 pub trait OwnerAssistant: Ownable {
@@ -20,8 +25,78 @@ pub trait OwnerAssistant: Ownable {
     fn owner_assistant_mut(&mut self) -> &mut Pubkey;
 }
 
+/// Owner assistant rotation happens in two steps, where the proposed assistant must accept the role
+/// before replacing the current one.
+pub trait PendingOwnerAssistant: OwnerAssistant {
+    fn pending_owner_assistant(&self) -> &Option<Pubkey>;
+
+    fn pending_owner_assistant_mut(&mut self) -> &mut Option<Pubkey>;
+}
+
+/// Roles that can be granted in addition to the owner and owner assistant. Each role only grants
+/// access to the instructions relevant to it.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum Role {
+    /// Can pause and unpause the program.
+    Pauser,
+
+    /// Can add and manage router endpoints.
+    EndpointManager,
+
+    /// Can manage fee parameters and fee recipients.
+    FeeManager,
+}
+
+/// Roles granted to a specific holder.
+pub trait Roles {
+    fn holder(&self) -> &Pubkey;
+
+    fn roles(&self) -> &[Role];
+
+    fn roles_mut(&mut self) -> &mut Vec<Role>;
+}
+
+/// M-of-N set of members, whose PDA can be assigned as a program's owner. When the owner is an owner
+/// set, [only_owner](utils::ownable::only_owner) and
+/// [only_authorized](utils::assistant::only_authorized) are only satisfied when the program signs
+/// for the owner set after `threshold` members have approved a transaction.
+pub trait OwnerSet {
+    fn members(&self) -> &[Pubkey];
+
+    fn threshold(&self) -> u8;
+}
+
+/// Approvals collected from [OwnerSet] members for a given transaction.
+pub trait OwnerSetApprovals {
+    fn approvals(&self) -> &[Pubkey];
+
+    fn approvals_mut(&mut self) -> &mut Vec<Pubkey>;
+}
+
 #[cfg(test)]
 mod tests {
-    //    use super::*;
-    // TODO
+    use super::utils::owner_set::is_valid_owner_set;
+    use anchor_lang::prelude::*;
+
+    #[test]
+    fn test_valid_owner_set() {
+        let members = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        assert!(is_valid_owner_set(&members, 1));
+        assert!(is_valid_owner_set(&members, 2));
+        assert!(is_valid_owner_set(&members, 3));
+    }
+
+    #[test]
+    fn test_invalid_owner_set() {
+        let member = Pubkey::new_unique();
+        assert!(!is_valid_owner_set(&[], 0));
+        assert!(!is_valid_owner_set(&[member], 0));
+        assert!(!is_valid_owner_set(&[member], 2));
+        assert!(!is_valid_owner_set(&[member, member], 1));
+        assert!(!is_valid_owner_set(&[member, Pubkey::default()], 1));
+    }
 }
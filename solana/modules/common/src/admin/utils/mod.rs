@@ -2,6 +2,12 @@ pub mod assistant;
 
 pub mod ownable;
 
+pub mod owner_set;
+
 pub mod pending_owner;
 
+pub mod pending_owner_assistant;
+
+pub mod roles;
+
 pub mod upgrade;
@@ -0,0 +1,76 @@
+use crate::admin::{OwnerSet, OwnerSetApprovals};
+use anchor_lang::prelude::*;
+
+pub fn only_owner_set_member<A>(
+    acct: &Account<A>,
+    member: &Signer,
+    custom_error: Error,
+) -> Result<bool>
+where
+    A: OwnerSet + Clone + AccountSerialize + AccountDeserialize,
+{
+    if acct.members().contains(&member.key()) {
+        Ok(true)
+    } else {
+        Err(custom_error.with_account_name("member"))
+    }
+}
+
+/// An owner set is valid if it has at least one member, none of its members are the zero pubkey or
+/// repeated, and the threshold is attainable (i.e. it is nonzero and does not exceed the number of
+/// members).
+pub fn is_valid_owner_set(members: &[Pubkey], threshold: u8) -> bool {
+    if threshold == 0 || usize::from(threshold) > members.len() {
+        return false;
+    }
+
+    members.iter().enumerate().all(|(i, member)| {
+        *member != Pubkey::default()
+            && !members
+                .iter()
+                .skip(i.saturating_add(1))
+                .any(|other| other == member)
+    })
+}
+
+/// Drop approvals from accounts that are no longer members of the owner set.
+pub fn prune_approvals<T>(members: &[Pubkey], acct: &mut Account<T>)
+where
+    T: OwnerSetApprovals + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.approvals_mut()
+        .retain(|approver| members.contains(approver));
+}
+
+/// Record a member's approval after dropping approvals from former members. Returns false if this
+/// member has already approved.
+pub fn approve<A, T>(owner_set: &Account<A>, acct: &mut Account<T>, member: &Pubkey) -> bool
+where
+    A: OwnerSet + Clone + AccountSerialize + AccountDeserialize,
+    T: OwnerSetApprovals + Clone + AccountSerialize + AccountDeserialize,
+{
+    prune_approvals(owner_set.members(), acct);
+
+    if acct.approvals().contains(member) {
+        false
+    } else {
+        acct.approvals_mut().push(*member);
+        true
+    }
+}
+
+/// Only approvals from current members count towards the threshold. If a member was removed from the
+/// owner set after approving, their approval is ignored.
+pub fn threshold_met<A, T>(owner_set: &Account<A>, approvals: &Account<T>) -> bool
+where
+    A: OwnerSet + Clone + AccountSerialize + AccountDeserialize,
+    T: OwnerSetApprovals + Clone + AccountSerialize + AccountDeserialize,
+{
+    let num_approved = approvals
+        .approvals()
+        .iter()
+        .filter(|approver| owner_set.members().contains(approver))
+        .count();
+
+    num_approved >= usize::from(owner_set.threshold())
+}
@@ -16,11 +16,19 @@ where
     pending.is_some() && only_pending_owner_unchecked(acct, pending_owner)
 }
 
-pub fn transfer_ownership<A>(acct: &mut Account<A>, new_owner: &Pubkey)
+pub fn is_pending_owner_expired<A>(acct: &Account<A>, current_slot: u64) -> bool
+where
+    A: PendingOwner + Clone + AccountSerialize + AccountDeserialize,
+{
+    current_slot > acct.pending_owner_expiry_slot()
+}
+
+pub fn transfer_ownership<A>(acct: &mut Account<A>, new_owner: &Pubkey, expiry_slot: u64)
 where
     A: PendingOwner + Clone + AccountSerialize + AccountDeserialize,
 {
     acct.pending_owner_mut().replace(*new_owner);
+    *acct.pending_owner_expiry_slot_mut() = expiry_slot;
 }
 
 pub fn accept_ownership_unchecked<A>(acct: &mut Account<A>)
@@ -29,6 +37,7 @@ where
 {
     *acct.owner_mut() = *acct.pending_owner().as_ref().unwrap();
     *acct.pending_owner_mut() = None;
+    *acct.pending_owner_expiry_slot_mut() = Default::default();
 }
 
 pub fn accept_ownership<A>(acct: &mut Account<A>) -> bool
@@ -48,4 +57,5 @@ where
     A: PendingOwner + Clone + AccountSerialize + AccountDeserialize,
 {
     *acct.pending_owner_mut() = None;
+    *acct.pending_owner_expiry_slot_mut() = Default::default();
 }
@@ -0,0 +1,54 @@
+use crate::admin::PendingOwnerAssistant;
+use anchor_lang::prelude::*;
+
+pub fn only_pending_owner_assistant_unchecked<A>(
+    acct: &Account<A>,
+    pending_owner_assistant: &Pubkey,
+) -> bool
+where
+    A: PendingOwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.pending_owner_assistant().unwrap() == *pending_owner_assistant
+}
+
+pub fn only_pending_owner_assistant<A>(acct: &Account<A>, pending_owner_assistant: &Pubkey) -> bool
+where
+    A: PendingOwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+{
+    let pending = acct.pending_owner_assistant();
+    pending.is_some() && only_pending_owner_assistant_unchecked(acct, pending_owner_assistant)
+}
+
+pub fn transfer_owner_assistant<A>(acct: &mut Account<A>, new_assistant: &Pubkey)
+where
+    A: PendingOwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.pending_owner_assistant_mut().replace(*new_assistant);
+}
+
+pub fn accept_owner_assistant_unchecked<A>(acct: &mut Account<A>)
+where
+    A: PendingOwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+{
+    *acct.owner_assistant_mut() = *acct.pending_owner_assistant().as_ref().unwrap();
+    *acct.pending_owner_assistant_mut() = None;
+}
+
+pub fn accept_owner_assistant<A>(acct: &mut Account<A>) -> bool
+where
+    A: PendingOwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+{
+    if acct.pending_owner_assistant().is_some() {
+        accept_owner_assistant_unchecked(acct);
+        true
+    } else {
+        false
+    }
+}
+
+pub fn cancel_transfer_owner_assistant<A>(acct: &mut Account<A>)
+where
+    A: PendingOwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+{
+    *acct.pending_owner_assistant_mut() = None;
+}
@@ -0,0 +1,74 @@
+use crate::admin::{OwnerAssistant, Role, Roles};
+use anchor_lang::prelude::*;
+
+pub fn has_role<R>(acct: &Account<R>, holder: &Pubkey, role: Role) -> bool
+where
+    R: Roles + Clone + AccountSerialize + AccountDeserialize,
+{
+    acct.holder() == holder && acct.roles().contains(&role)
+}
+
+pub fn only_role_holder<R>(
+    acct: &Account<R>,
+    holder: &Signer,
+    role: Role,
+    custom_error: Error,
+) -> Result<bool>
+where
+    R: Roles + Clone + AccountSerialize + AccountDeserialize,
+{
+    if has_role(acct, &holder.key(), role) {
+        Ok(true)
+    } else {
+        Err(custom_error.with_pubkeys((*acct.holder(), holder.key())))
+    }
+}
+
+/// Like [only_authorized](super::assistant::only_authorized), but the signer may also be a holder
+/// of the specified role. The role account is optional because the owner and owner assistant do
+/// not need one.
+pub fn only_authorized_or_role<A, R>(
+    acct: &Account<A>,
+    role_acct: Option<&Account<R>>,
+    authority: &Signer,
+    role: Role,
+    custom_error: Error,
+) -> Result<bool>
+where
+    A: OwnerAssistant + Clone + AccountSerialize + AccountDeserialize,
+    R: Roles + Clone + AccountSerialize + AccountDeserialize,
+{
+    let authority_key = authority.key();
+    if acct.owner() == &authority_key
+        || acct.owner_assistant() == &authority_key
+        || role_acct.is_some_and(|role_acct| has_role(role_acct, &authority_key, role))
+    {
+        Ok(true)
+    } else {
+        Err(custom_error)
+    }
+}
+
+/// Grant a role. Returns false if the role has already been granted.
+pub fn grant_role<R>(acct: &mut Account<R>, role: Role) -> bool
+where
+    R: Roles + Clone + AccountSerialize + AccountDeserialize,
+{
+    if acct.roles().contains(&role) {
+        false
+    } else {
+        acct.roles_mut().push(role);
+        true
+    }
+}
+
+/// Revoke a role. Returns false if the role was not granted.
+pub fn revoke_role<R>(acct: &mut Account<R>, role: Role) -> bool
+where
+    R: Roles + Clone + AccountSerialize + AccountDeserialize,
+{
+    let roles = acct.roles_mut();
+    let len_before = roles.len();
+    roles.retain(|granted| *granted != role);
+    roles.len() != len_before
+}
@@ -0,0 +1,58 @@
+//! Interface a redeemer program implements to have the Token Router deliver a fill's tokens to its
+//! vault and call it with the fill, all in one instruction. See the Token Router SDK for accounts
+//! that redeemer programs can use to receive this call.
+
+use anchor_lang::{prelude::*, solana_program};
+
+/// Seed prefix of the Token Router's callback authority, which signs for every
+/// `on_liquidity_layer_fill` call. This authority is not used for anything else.
+pub const CALLBACK_AUTHORITY_SEED_PREFIX: &[u8] = b"callback-authority";
+
+/// Seed prefix of a redeemer program's vault authority. The Token Router only delivers tokens to a
+/// token account owned by this PDA of the redeemer program.
+pub const VAULT_AUTHORITY_SEED_PREFIX: &[u8] = b"liquidity-layer-vault";
+
+#[derive(Accounts)]
+pub struct OnLiquidityLayerFill<'info> {
+    /// Token Router's callback authority.
+    #[account(signer)]
+    pub callback_authority: AccountInfo<'info>,
+
+    /// Redeemer program's vault token account, which received the fill amount.
+    #[account(mut)]
+    pub vault_token: AccountInfo<'info>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OnLiquidityLayerFillArgs {
+    /// Wormhole chain ID reflecting where the order was created.
+    pub source_chain: u16,
+    /// Universal address of the order sender.
+    pub order_sender: [u8; 32],
+    /// Amount transferred to the vault token account.
+    pub amount: u64,
+    /// Message encoded by the order sender for the redeemer.
+    pub redeemer_message: Vec<u8>,
+}
+
+/// Processor to call a redeemer program with a delivered fill.
+pub fn on_liquidity_layer_fill<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, OnLiquidityLayerFill<'info>>,
+    args: OnLiquidityLayerFillArgs,
+) -> Result<()> {
+    let selector = &solana_program::hash::hash(b"global:on_liquidity_layer_fill").to_bytes()[..8];
+
+    let mut data = selector.to_vec();
+    args.serialize(&mut data)?;
+
+    solana_program::program::invoke_signed(
+        &solana_program::instruction::Instruction {
+            program_id: ctx.program.key(),
+            accounts: ctx.to_account_metas(None),
+            data,
+        },
+        &ctx.to_account_infos(),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
@@ -2,6 +2,14 @@ pub use wormhole_cctp_solana;
 
 pub mod admin;
 
+pub mod fill_callback;
+
+pub mod ntt;
+
+pub mod realloc;
+
+pub mod token_bridge;
+
 pub use liquidity_layer_messages as messages;
 pub use messages::wormhole_io;
 
@@ -9,6 +17,7 @@ pub const WORMHOLE_MESSAGE_NONCE: u32 = 0;
 
 pub const CORE_MESSAGE_SEED_PREFIX: &[u8] = b"core-msg";
 pub const CCTP_MESSAGE_SEED_PREFIX: &[u8] = b"cctp-msg";
+pub const NTT_OUTBOX_ITEM_SEED_PREFIX: &[u8] = b"ntt-outbox";
 
 pub const TRANSFER_AUTHORITY_SEED_PREFIX: &[u8] = b"transfer-authority";
 
@@ -0,0 +1,70 @@
+//! Native Token Transfers (NTT) manager program accounts and CPI calls used by the liquidity layer
+//! to move NTT-enabled tokens. The NTT manager program ID is configured per router endpoint, so
+//! these calls are not bound to a specific deployment.
+
+use anchor_lang::{prelude::*, solana_program};
+
+#[derive(Accounts)]
+pub struct TransferLock<'info> {
+    #[account(mut, signer)]
+    pub payer: AccountInfo<'info>,
+
+    /// Owner of the source token account.
+    #[account(signer)]
+    pub sender: AccountInfo<'info>,
+
+    /// Source token account.
+    #[account(mut)]
+    pub from: AccountInfo<'info>,
+
+    pub mint: AccountInfo<'info>,
+
+    /// Outbox item created by the NTT manager, which is released by the transceiver.
+    #[account(mut, signer)]
+    pub outbox_item: AccountInfo<'info>,
+
+    /// NTT manager's custody token account, which locks the transferred tokens.
+    #[account(mut)]
+    pub custody: AccountInfo<'info>,
+
+    /// Transceiver that will release the outbox item.
+    pub transceiver: AccountInfo<'info>,
+
+    pub token_program: AccountInfo<'info>,
+
+    pub system_program: AccountInfo<'info>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferArgs {
+    /// Amount of tokens to transfer.
+    pub amount: u64,
+    /// Wormhole Chain ID of the target network.
+    pub recipient_chain: u16,
+    /// Recipient of the tokens on the target network.
+    pub recipient_address: [u8; 32],
+    /// Whether the transfer should be queued if the outbound rate limit is exceeded.
+    pub should_queue: bool,
+}
+
+/// Processor to transfer tokens by locking them in the NTT manager's custody.
+pub fn transfer_lock<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferLock<'info>>,
+    args: TransferArgs,
+) -> Result<()> {
+    let selector = &solana_program::hash::hash(b"global:transfer_lock").to_bytes()[..8];
+
+    let mut data = selector.to_vec();
+    args.serialize(&mut data)?;
+
+    solana_program::program::invoke_signed(
+        &solana_program::instruction::Instruction {
+            program_id: ctx.program.key(),
+            accounts: ctx.to_account_metas(None),
+            data,
+        },
+        &ctx.to_account_infos(),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
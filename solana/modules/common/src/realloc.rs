@@ -0,0 +1,35 @@
+use anchor_lang::{prelude::*, system_program};
+
+/// Grow a program-owned account to `new_len` bytes, topping up its lamports from `payer` so that
+/// it remains rent exempt. Accounts that are already at least `new_len` bytes are left untouched.
+///
+/// NOTE: Newly allocated bytes are zeroed, but the caller is expected to rewrite the account data
+/// in its new layout afterwards.
+pub fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let lamports_needed = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if lamports_needed > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            lamports_needed,
+        )?;
+    }
+
+    account.realloc(new_len, true).map_err(Into::into)
+}
@@ -0,0 +1,176 @@
+//! Wormhole Token Bridge program accounts and CPI calls used by the liquidity layer to move assets
+//! to and from networks that do not support CCTP.
+
+pub use wormhole_raw_vaas::token_bridge::{TokenBridgeMessage, TransferWithMessage};
+pub use wormhole_solana_consts::{
+    TOKEN_BRIDGE_CUSTODY_AUTHORITY, TOKEN_BRIDGE_EMITTER_AUTHORITY, TOKEN_BRIDGE_PROGRAM_ID,
+    TOKEN_BRIDGE_TRANSFER_AUTHORITY,
+};
+
+use anchor_lang::{prelude::*, solana_program};
+
+/// Seed prefix of the Token Bridge program's redeemer account, which must sign to redeem a transfer
+/// with message when it is derived from the redeemer program.
+pub const REDEEMER_SEED_PREFIX: &[u8] = b"redeemer";
+
+#[derive(Accounts)]
+pub struct TransferNativeWithPayload<'info> {
+    #[account(mut, signer)]
+    pub payer: AccountInfo<'info>,
+
+    /// Seeds must be \["config"\] (Token Bridge program).
+    pub config: AccountInfo<'info>,
+
+    /// Source token account. The transfer authority must be delegated to transfer from this
+    /// account.
+    #[account(mut)]
+    pub from: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub mint: AccountInfo<'info>,
+
+    /// Seeds must be \[mint\] (Token Bridge program).
+    #[account(mut)]
+    pub custody: AccountInfo<'info>,
+
+    /// Seeds must be \["authority_signer"\] (Token Bridge program).
+    pub transfer_authority: AccountInfo<'info>,
+
+    /// Seeds must be \["custody_signer"\] (Token Bridge program).
+    pub custody_authority: AccountInfo<'info>,
+
+    /// Seeds must be \["Bridge"\] (Wormhole Core Bridge program).
+    #[account(mut)]
+    pub core_bridge_config: AccountInfo<'info>,
+
+    #[account(mut, signer)]
+    pub core_message: AccountInfo<'info>,
+
+    /// Seeds must be \["emitter"\] (Token Bridge program).
+    pub core_emitter: AccountInfo<'info>,
+
+    /// Seeds must be \["Sequence", core_emitter\] (Wormhole Core Bridge program).
+    #[account(mut)]
+    pub core_emitter_sequence: AccountInfo<'info>,
+
+    /// Seeds must be \["fee_collector"\] (Wormhole Core Bridge program).
+    #[account(mut)]
+    pub core_fee_collector: AccountInfo<'info>,
+
+    pub clock: AccountInfo<'info>,
+
+    /// Account encoded as the sender of the transfer.
+    #[account(signer)]
+    pub sender: AccountInfo<'info>,
+
+    pub rent: AccountInfo<'info>,
+
+    pub system_program: AccountInfo<'info>,
+
+    pub token_program: AccountInfo<'info>,
+
+    pub core_bridge_program: AccountInfo<'info>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferNativeWithPayloadArgs {
+    /// Wormhole message nonce.
+    pub nonce: u32,
+    /// Amount of tokens to transfer.
+    pub amount: u64,
+    /// Recipient of the tokens on the target network.
+    pub target_address: [u8; 32],
+    /// Wormhole Chain ID of the target network.
+    pub target_chain: u16,
+    /// Message delivered to the recipient with the transfer.
+    pub payload: Vec<u8>,
+    /// If specified, the sender is derived from this program ID.
+    pub cpi_program_id: Option<Pubkey>,
+}
+
+/// Processor to transfer native tokens with a message via the Token Bridge program. The
+/// [transfer_authority](TransferNativeWithPayload::transfer_authority) must be delegated at least
+/// the transfer amount before calling this method.
+pub fn transfer_native_with_payload<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferNativeWithPayload<'info>>,
+    args: TransferNativeWithPayloadArgs,
+) -> Result<()> {
+    const IX_SELECTOR: u8 = 12;
+
+    solana_program::program::invoke_signed(
+        &solana_program::instruction::Instruction {
+            program_id: TOKEN_BRIDGE_PROGRAM_ID,
+            accounts: ctx.to_account_metas(None),
+            data: (IX_SELECTOR, args).try_to_vec()?,
+        },
+        &ctx.to_account_infos(),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+#[derive(Accounts)]
+pub struct CompleteNativeWithPayload<'info> {
+    #[account(mut, signer)]
+    pub payer: AccountInfo<'info>,
+
+    /// Seeds must be \["config"\] (Token Bridge program).
+    pub config: AccountInfo<'info>,
+
+    /// Posted VAA (Wormhole Core Bridge program).
+    pub vaa: AccountInfo<'info>,
+
+    /// Seeds must be \[emitter_address, emitter_chain, sequence\] (Token Bridge program).
+    #[account(mut)]
+    pub claim: AccountInfo<'info>,
+
+    /// Seeds must be \[emitter_chain, emitter_address\] (Token Bridge program).
+    pub registered_emitter: AccountInfo<'info>,
+
+    /// Destination token account, which is encoded as the recipient of the transfer.
+    #[account(mut)]
+    pub to: AccountInfo<'info>,
+
+    /// Owner of the destination token account.
+    #[account(signer)]
+    pub redeemer: AccountInfo<'info>,
+
+    /// Token account for relayer fees. For transfers with a message, this is the same as `to`.
+    #[account(mut)]
+    pub to_fees: AccountInfo<'info>,
+
+    /// Seeds must be \[mint\] (Token Bridge program).
+    #[account(mut)]
+    pub custody: AccountInfo<'info>,
+
+    pub mint: AccountInfo<'info>,
+
+    /// Seeds must be \["custody_signer"\] (Token Bridge program).
+    pub custody_authority: AccountInfo<'info>,
+
+    pub rent: AccountInfo<'info>,
+
+    pub system_program: AccountInfo<'info>,
+
+    pub token_program: AccountInfo<'info>,
+
+    pub core_bridge_program: AccountInfo<'info>,
+}
+
+/// Processor to redeem native tokens transferred with a message via the Token Bridge program.
+pub fn complete_native_with_payload<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, CompleteNativeWithPayload<'info>>,
+) -> Result<()> {
+    const IX_SELECTOR: u8 = 9;
+
+    solana_program::program::invoke_signed(
+        &solana_program::instruction::Instruction {
+            program_id: TOKEN_BRIDGE_PROGRAM_ID,
+            accounts: ctx.to_account_metas(None),
+            data: vec![IX_SELECTOR],
+        },
+        &ctx.to_account_infos(),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
@@ -0,0 +1,2 @@
+mod router_endpoint_registry;
+pub use router_endpoint_registry::*;
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use matching_engine::state::{RegisteredRouterEndpoint, RouterEndpoint, RouterEndpointRegistry};
+
+/// Address of the Matching Engine's router endpoint registry.
+pub fn router_endpoint_registry_address() -> Pubkey {
+    Pubkey::find_program_address(&[RouterEndpointRegistry::SEED_PREFIX], &matching_engine::ID).0
+}
+
+/// Address of the Matching Engine's router endpoint for the specified chain.
+pub fn router_endpoint_address(chain: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[RouterEndpoint::SEED_PREFIX, &chain.to_be_bytes()],
+        &matching_engine::ID,
+    )
+    .0
+}
+
+/// Iterates over the endpoints listed in the router endpoint registry's account data (ordered by
+/// chain ID), yielding each endpoint's address along with its chain and protocol.
+pub fn iter_router_endpoints(
+    mut data: &[u8],
+) -> Result<impl Iterator<Item = (Pubkey, RegisteredRouterEndpoint)>> {
+    let registry = RouterEndpointRegistry::try_deserialize(&mut data)?;
+    Ok(registry
+        .endpoints
+        .into_iter()
+        .map(|endpoint| (router_endpoint_address(endpoint.chain), endpoint)))
+}
@@ -0,0 +1,3 @@
+pub mod accounts;
+
+pub use matching_engine::cpi::*;
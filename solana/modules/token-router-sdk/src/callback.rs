@@ -0,0 +1,38 @@
+//! Interface for redeemer programs that receive fills from the Token Router's
+//! `deliver_prepared_fill` instruction. A redeemer program opts in by being the redeemer encoded in
+//! the fill and implementing an instruction with the following signature:
+//!
+//! ```ignore
+//! pub fn on_liquidity_layer_fill(
+//!     ctx: Context<MyAccounts>,
+//!     source_chain: u16,
+//!     order_sender: [u8; 32],
+//!     amount: u64,
+//!     redeemer_message: Vec<u8>,
+//! ) -> Result<()>
+//! ```
+//!
+//! The first account of `MyAccounts` is the Token Router's callback authority, which is a signer
+//! whose address is [callback_authority]. The second account is the redeemer program's vault token
+//! account, which is owned by its [vault_authority] and has already received the fill amount. Any
+//! other accounts the redeemer program needs are passed to `deliver_prepared_fill` as remaining
+//! accounts.
+
+pub use common::fill_callback::{
+    OnLiquidityLayerFillArgs, CALLBACK_AUTHORITY_SEED_PREFIX, VAULT_AUTHORITY_SEED_PREFIX,
+};
+
+use anchor_lang::prelude::*;
+
+/// Address of the Token Router's callback authority. Only the Token Router can sign with this
+/// authority, so a call with this signer is authenticated.
+pub fn callback_authority() -> Pubkey {
+    let (authority, _) =
+        Pubkey::find_program_address(&[CALLBACK_AUTHORITY_SEED_PREFIX], &token_router::id());
+    authority
+}
+
+/// Address of the vault authority for a redeemer program.
+pub fn vault_authority(redeemer_program: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_AUTHORITY_SEED_PREFIX], redeemer_program)
+}
@@ -1,3 +1,5 @@
 pub mod accounts;
 
+pub mod callback;
+
 pub use token_router::cpi::*;
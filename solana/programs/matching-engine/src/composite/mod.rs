@@ -3,16 +3,24 @@ use std::ops::{Deref, DerefMut};
 use crate::{
     error::MatchingEngineError,
     state::{
-        Auction, AuctionStatus, Custodian, FastFillSequencer, MessageProtocol,
-        PreparedOrderResponse, ReservedFastFillSequence, RouterEndpoint,
+        Auction, AuctionStatus, Custodian, FastFillSequencer, MessageProtocol, OwnerSet,
+        PreparedOrderResponse, ReservedFastFillSequence, RoleHolder, RouterEndpoint,
+        RouterEndpointRegistry, SupportedMint,
     },
     utils::{self, VaaDigest},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::{
-    admin::utils::{assistant::only_authorized, ownable::only_owner},
+    admin::{
+        utils::{
+            assistant::only_authorized, ownable::only_owner, owner_set::only_owner_set_member,
+            roles::only_authorized_or_role,
+        },
+        Role,
+    },
     messages::raw::LiquidityLayerMessage,
+    token_bridge,
     wormhole_cctp_solana::{
         cctp::{message_transmitter_program, token_messenger_minter_program},
         wormhole::{core_bridge_program, VaaAccount},
@@ -34,18 +42,66 @@ impl<'info> Deref for Usdc<'info> {
     }
 }
 
+/// Mint that orders can be auctioned and settled with. USDC is always supported. Any other mint
+/// must have a [SupportedMint] account.
+#[derive(Accounts)]
+pub struct CheckedMint<'info> {
+    #[account(
+        constraint = {
+            mint.key() == common::USDC_MINT || supported_mint.is_some()
+        } @ MatchingEngineError::UnsupportedMint,
+    )]
+    pub mint: Box<Account<'info, token::Mint>>,
+
+    /// Only required if the mint is not USDC.
+    #[account(
+        seeds = [
+            SupportedMint::SEED_PREFIX,
+            mint.key().as_ref(),
+        ],
+        bump = supported_mint.bump,
+    )]
+    pub supported_mint: Option<Box<Account<'info, SupportedMint>>>,
+}
+
+impl<'info> CheckedMint<'info> {
+    /// Token account that collects fees for orders of this mint settled without an auction.
+    pub fn fee_recipient_token(&self, custodian: &Custodian) -> Pubkey {
+        match &self.supported_mint {
+            Some(supported_mint) => supported_mint.fee_recipient_token,
+            None => custodian.fee_recipient_token,
+        }
+    }
+}
+
+impl<'info> Deref for CheckedMint<'info> {
+    type Target = Account<'info, token::Mint>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.mint
+    }
+}
+
 /// Mint recipient token account, which is encoded as the mint recipient in the CCTP message.
 /// The CCTP Token Messenger Minter program will transfer the amount encoded in the CCTP message
 /// from its custody account to this account.
 ///
-/// CHECK: Mutable. Seeds must be \["custody"\].
+/// CHECK: Mutable. Must be the custodian's associated token account for its mint.
 ///
 /// NOTE: This account must be encoded as the mint recipient in the CCTP message.
 #[derive(Accounts)]
 pub struct CctpMintRecipientMut<'info> {
     #[account(
         mut,
-        address = crate::CCTP_MINT_RECIPIENT
+        constraint = {
+            mint_recipient.key() == crate::CCTP_MINT_RECIPIENT
+                || mint_recipient.key()
+                    == anchor_spl::associated_token::get_associated_token_address(
+                        &Pubkey::create_program_address(Custodian::SIGNER_SEEDS, &crate::id())
+                            .unwrap(),
+                        &mint_recipient.mint,
+                    )
+        } @ MatchingEngineError::InvalidMintRecipient,
     )]
     pub mint_recipient: Box<Account<'info, token::TokenAccount>>,
 }
@@ -174,6 +230,135 @@ pub struct AdminMut<'info> {
     pub custodian: Box<Account<'info, Custodian>>,
 }
 
+/// Either the owner, owner assistant or a holder of the [Pauser](Role::Pauser) role.
+#[derive(Accounts)]
+pub struct PauserMut<'info> {
+    #[account(
+        constraint = only_authorized_or_role(
+            &custodian,
+            role_holder.as_deref(),
+            &authority,
+            Role::Pauser,
+            error!(MatchingEngineError::OwnerAssistantOrRoleOnly)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    pub custodian: Box<Account<'info, Custodian>>,
+
+    /// Only required if the authority is neither the owner nor the owner assistant.
+    #[account(
+        seeds = [
+            RoleHolder::SEED_PREFIX,
+            authority.key().as_ref(),
+        ],
+        bump = role_holder.bump,
+    )]
+    pub role_holder: Option<Box<Account<'info, RoleHolder>>>,
+}
+
+/// Either the owner, owner assistant or a holder of the [EndpointManager](Role::EndpointManager)
+/// role.
+#[derive(Accounts)]
+pub struct EndpointManager<'info> {
+    #[account(
+        constraint = only_authorized_or_role(
+            &custodian,
+            role_holder.as_deref(),
+            &authority,
+            Role::EndpointManager,
+            error!(MatchingEngineError::OwnerAssistantOrRoleOnly)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    pub custodian: CheckedCustodian<'info>,
+
+    /// Only required if the authority is neither the owner nor the owner assistant.
+    #[account(
+        seeds = [
+            RoleHolder::SEED_PREFIX,
+            authority.key().as_ref(),
+        ],
+        bump = role_holder.bump,
+    )]
+    pub role_holder: Option<Box<Account<'info, RoleHolder>>>,
+}
+
+/// Either the owner, owner assistant or a holder of the [FeeManager](Role::FeeManager) role.
+#[derive(Accounts)]
+pub struct FeeManagerMut<'info> {
+    #[account(
+        constraint = only_authorized_or_role(
+            &custodian,
+            role_holder.as_deref(),
+            &authority,
+            Role::FeeManager,
+            error!(MatchingEngineError::OwnerAssistantOrRoleOnly)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    pub custodian: Box<Account<'info, Custodian>>,
+
+    /// Only required if the authority is neither the owner nor the owner assistant.
+    #[account(
+        seeds = [
+            RoleHolder::SEED_PREFIX,
+            authority.key().as_ref(),
+        ],
+        bump = role_holder.bump,
+    )]
+    pub role_holder: Option<Box<Account<'info, RoleHolder>>>,
+}
+
+#[derive(Accounts)]
+pub struct OwnerSetMember<'info> {
+    #[account(
+        constraint = only_owner_set_member(
+            &owner_set,
+            &member,
+            error!(MatchingEngineError::OwnerSetMemberOnly)
+        )?
+    )]
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [OwnerSet::SEED_PREFIX],
+        bump = owner_set.bump,
+    )]
+    pub owner_set: Box<Account<'info, OwnerSet>>,
+}
+
+#[derive(Accounts)]
+pub struct OwnerSetMemberMut<'info> {
+    #[account(
+        constraint = only_owner_set_member(
+            &owner_set,
+            &member,
+            error!(MatchingEngineError::OwnerSetMemberOnly)
+        )?
+    )]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [OwnerSet::SEED_PREFIX],
+        bump = owner_set.bump,
+    )]
+    pub owner_set: Box<Account<'info, OwnerSet>>,
+}
+
 #[derive(Accounts)]
 pub struct LocalTokenRouter<'info> {
     /// CHECK: Must be an executable (the Token Router program), whose ID will be used to derive the
@@ -225,6 +410,30 @@ impl<'info> DerefMut for ExistingMutRouterEndpoint<'info> {
     }
 }
 
+#[derive(Accounts)]
+pub struct RouterEndpointRegistryMut<'info> {
+    #[account(
+        mut,
+        seeds = [RouterEndpointRegistry::SEED_PREFIX],
+        bump = registry.bump,
+    )]
+    pub registry: Box<Account<'info, RouterEndpointRegistry>>,
+}
+
+impl<'info> Deref for RouterEndpointRegistryMut<'info> {
+    type Target = Account<'info, RouterEndpointRegistry>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.registry
+    }
+}
+
+impl<'info> DerefMut for RouterEndpointRegistryMut<'info> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.registry
+    }
+}
+
 #[derive(Accounts)]
 pub struct LiveRouterEndpoint<'info> {
     #[account(
@@ -236,6 +445,7 @@ pub struct LiveRouterEndpoint<'info> {
         constraint = {
             endpoint.protocol != MessageProtocol::None
         } @ MatchingEngineError::EndpointDisabled,
+        constraint = !endpoint.pause.is_active(Clock::get()?.slot) @ MatchingEngineError::EndpointPaused,
     )]
     pub endpoint: Box<Account<'info, RouterEndpoint>>,
 }
@@ -269,7 +479,7 @@ pub struct FastOrderPath<'info> {
                 MatchingEngineError::InvalidSourceRouter
             );
             require!(
-                path.from_endpoint.address == vaa.emitter_address(),
+                path.from_endpoint.is_source_address(&vaa.emitter_address(), Clock::get()?.slot),
                 MatchingEngineError::InvalidSourceRouter
             );
 
@@ -384,10 +594,10 @@ pub struct ExecuteOrder<'info> {
     )]
     pub active_auction: ActiveAuction<'info>,
 
-    /// Must be a token account, whose mint is [common::USDC_MINT].
+    /// Must be a token account, whose mint is the auction's mint.
     #[account(
         mut,
-        token::mint = common::USDC_MINT,
+        token::mint = active_auction.custody_token.mint,
     )]
     pub executor_token: Box<Account<'info, token::TokenAccount>>,
 
@@ -425,6 +635,71 @@ pub struct WormholePublishMessage<'info> {
     pub core_bridge_program: UncheckedAccount<'info>,
 }
 
+#[derive(Accounts)]
+pub struct TokenBridgeTransferNative<'info> {
+    /// CHECK: Mutable. This address must equal the mint of the transferred asset, which is checked
+    /// by the instruction using this account.
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["config"\] (Token Bridge program).
+    pub config: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \[mint\] (Token Bridge program).
+    #[account(mut)]
+    pub custody_token: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["authority_signer"\] (Token Bridge program).
+    #[account(address = token_bridge::TOKEN_BRIDGE_TRANSFER_AUTHORITY)]
+    pub transfer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["custody_signer"\] (Token Bridge program).
+    #[account(address = token_bridge::TOKEN_BRIDGE_CUSTODY_AUTHORITY)]
+    pub custody_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["emitter"\] (Token Bridge program).
+    #[account(address = token_bridge::TOKEN_BRIDGE_EMITTER_AUTHORITY)]
+    pub emitter: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["Bridge"\] (Wormhole Core Bridge program).
+    #[account(mut)]
+    pub core_bridge_config: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["Sequence"\, emitter] (Wormhole Core Bridge program).
+    #[account(mut)]
+    pub emitter_sequence: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["fee_collector"\] (Wormhole Core Bridge program).
+    #[account(mut)]
+    pub core_fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal Wormhole Core Bridge program ID.
+    #[account(address = core_bridge_program::id())]
+    pub core_bridge_program: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal Token Bridge program ID.
+    #[account(address = token_bridge::TOKEN_BRIDGE_PROGRAM_ID)]
+    pub token_bridge_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct NttTransfer<'info> {
+    /// CHECK: This address must equal the mint of the transferred asset, which is checked by the
+    /// instruction using this account.
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. NTT manager's custody token account for the mint (NTT manager program).
+    #[account(mut)]
+    pub custody_token: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal the transceiver of the target router endpoint's NTT protocol.
+    pub transceiver: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal the manager of the target router endpoint's NTT protocol.
+    #[account(executable)]
+    pub ntt_manager_program: UncheckedAccount<'info>,
+}
+
 #[derive(Accounts)]
 pub struct CctpDepositForBurn<'info> {
     /// Circle-supported mint.
@@ -2,6 +2,7 @@
 pub enum MatchingEngineError {
     OwnerOnly = 0x2,
     OwnerOrAssistantOnly = 0x4,
+    OwnerAssistantOrRoleOnly = 0x6,
 
     U64Overflow = 0x10,
     U32Overflow = 0x12,
@@ -19,16 +20,36 @@ pub enum MatchingEngineError {
     VaaMismatch = 0x4c,
     RedeemerMessageTooLarge = 0x4e,
 
+    InvalidMint = 0x50,
+    UnsupportedMint = 0x52,
+    MintMismatch = 0x54,
+
     InvalidSourceRouter = 0x60,
     InvalidTargetRouter = 0x62,
     EndpointDisabled = 0x64,
     InvalidCctpEndpoint = 0x66,
+    EndpointPaused = 0x68,
+    EndpointThroughputExceeded = 0x6a,
+    InvalidInboundLimit = 0x6c,
+    RouterEndpointRegistryFull = 0x6e,
+    RouterEndpointNotDisabled = 0x70,
+    RouterEndpointRemovalDelayNotElapsed = 0x72,
+    InvalidPreviousAddressExpiry = 0x74,
+    InvalidEndpointLimits = 0x76,
+    InvalidTokenBridgeEndpoint = 0x78,
+    InvalidNttEndpoint = 0x7a,
+    InboundNotionalRequired = 0x7c,
 
     Paused = 0x80,
+    InvalidUnpauseSlot = 0x82,
+    CircuitBreakerTripped = 0x84,
+    InvalidCircuitBreakerThreshold = 0x86,
+    CircuitBreakerRequired = 0x88,
 
     AssistantZeroPubkey = 0x100,
     FeeRecipientZeroPubkey = 0x101,
     ImmutableProgram = 0x102,
+    RoleHolderZeroPubkey = 0x103,
     ZeroDuration = 0x104,
     ZeroGracePeriod = 0x106,
     ZeroPenaltyPeriod = 0x107,
@@ -47,6 +68,16 @@ pub enum MatchingEngineError {
     NoTransferOwnershipRequest = 0x206,
     NotPendingOwner = 0x208,
     InvalidChain = 0x20c,
+    OwnershipTransferRequestExpired = 0x20e,
+    InvalidOwnerSet = 0x210,
+    OwnerSetMemberOnly = 0x212,
+    OwnerSetTransactionAlreadyApproved = 0x214,
+    OwnerSetThresholdNotMet = 0x216,
+    InvalidOwnerSetTransaction = 0x218,
+    RoleAlreadyGranted = 0x220,
+    RoleNotGranted = 0x222,
+    NoPendingOwnerAssistant = 0x224,
+    NotPendingOwnerAssistant = 0x226,
 
     ChainNotAllowed = 0x240,
     InvalidMintRecipient = 0x242,
@@ -83,6 +114,18 @@ pub enum MatchingEngineError {
     AuctionAlreadySettled = 0x43a,
     InvalidBaseFeeToken = 0x43c,
     BaseFeeTokenRequired = 0x43e,
+    OrderAmountBelowMinimum = 0x440,
+    OrderAmountAboveMaximum = 0x442,
+    InvalidAmountOut = 0x444,
+    InvalidDestinationAsset = 0x446,
+    FastFillCustodyTokenRequired = 0x448,
+    GasDropoffTooLarge = 0x44a,
+    GasDropoffRecipientRequired = 0x44c,
+    FastFillNotExpired = 0x44e,
+    FastFillEscrowTokenRequired = 0x450,
+    AmountOutTooLow = 0x452,
+    UserRewardTokenRequired = 0x454,
+    InvalidGasDropoffFeeToken = 0x456,
 
     CannotCloseAuctionYet = 0x500,
     AuctionHistoryNotFull = 0x502,
@@ -1,4 +1,4 @@
-use crate::state::MessageProtocol;
+use crate::state::{AuctionDestinationAssetInfo, MessageProtocol};
 use anchor_lang::prelude::*;
 
 #[event]
@@ -17,4 +17,5 @@ pub struct AuctionUpdated {
     pub amount_in: u64,
     pub total_deposit: u64,
     pub max_offer_price_allowed: Option<u64>,
+    pub destination_asset_info: Option<AuctionDestinationAssetInfo>,
 }
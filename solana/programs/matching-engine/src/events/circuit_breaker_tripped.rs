@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct CircuitBreakerTripped {
+    pub source_chain: u16,
+    pub global_notional: u64,
+    pub chain_notional: u64,
+}
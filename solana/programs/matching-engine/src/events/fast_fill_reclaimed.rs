@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+use crate::state::FastFillSeeds;
+
+#[event]
+pub struct FastFillReclaimed {
+    pub prepared_by: Pubkey,
+    pub fast_fill: FastFillSeeds,
+    pub recipient_token: Pubkey,
+}
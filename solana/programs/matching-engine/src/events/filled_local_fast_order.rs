@@ -6,4 +6,11 @@ pub struct LocalFastOrderFilled {
     pub seeds: FastFillSeeds,
     pub info: FastFillInfo,
     pub auction: Option<Pubkey>,
+
+    /// Mint of the fill amount.
+    pub mint: Pubkey,
+
+    /// Fee earned by a relayer who automatically delivers the fill. See
+    /// [FastFill::relayer_fee](crate::state::FastFill::relayer_fee).
+    pub relayer_fee: u64,
 }
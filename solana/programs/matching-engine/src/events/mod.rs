@@ -7,9 +7,15 @@ pub use auction_settled::*;
 mod auction_updated;
 pub use auction_updated::*;
 
+mod circuit_breaker_tripped;
+pub use circuit_breaker_tripped::*;
+
 mod enacted;
 pub use enacted::*;
 
+mod fast_fill_reclaimed;
+pub use fast_fill_reclaimed::*;
+
 mod fast_fill_redeemed;
 pub use fast_fill_redeemed::*;
 
@@ -22,5 +28,14 @@ pub use filled_local_fast_order::*;
 mod order_executed;
 pub use order_executed::*;
 
+mod pause_updated;
+pub use pause_updated::*;
+
 mod proposed;
 pub use proposed::*;
+
+mod router_endpoint_pause_updated;
+pub use router_endpoint_pause_updated::*;
+
+mod router_endpoint_removed;
+pub use router_endpoint_removed::*;
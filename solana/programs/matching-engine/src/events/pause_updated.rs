@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{PauseFlag, PauseTarget};
+
+#[event]
+#[derive(Debug)]
+pub struct PauseUpdated {
+    pub target: PauseTarget,
+    pub flag: PauseFlag,
+    pub set_by: Pubkey,
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PauseFlag;
+
+#[event]
+#[derive(Debug)]
+pub struct RouterEndpointPauseUpdated {
+    pub chain: u16,
+    pub flag: PauseFlag,
+    pub set_by: Pubkey,
+}
@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct RouterEndpointRemoved {
+    pub chain: u16,
+    pub removed_by: Pubkey,
+}
@@ -13,9 +13,13 @@ use processor::*;
 pub mod state;
 
 pub mod utils;
-pub use utils::admin::AddCctpRouterEndpointArgs;
+pub use utils::admin::{
+    AddCctpRouterEndpointArgs, AddNttRouterEndpointArgs, AddTokenBridgeRouterEndpointArgs,
+};
 
 use anchor_lang::{prelude::*, solana_program::pubkey};
+use common::admin::Role;
+use state::EndpointLimits;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "mainnet")] {
@@ -37,12 +41,16 @@ cfg_if::cfg_if! {
 }
 
 const AUCTION_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"auction-custody";
+const AUCTION_DESTINATION_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"auction-destination-custody";
+const FAST_FILL_ESCROW_TOKEN_SEED_PREFIX: &[u8] = b"fast-fill-escrow";
 const LOCAL_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"local-custody";
 const PREPARED_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"prepared-custody";
 
 const FEE_PRECISION_MAX: u32 = 1_000_000;
 const VAA_AUCTION_EXPIRATION_TIME: i64 = 2 * 60 * 60; // 2 hours
 const EXECUTE_FAST_ORDER_LOCAL_ADDITIONAL_GRACE_PERIOD: u64 = 5; // slots
+const OWNERSHIP_TRANSFER_REQUEST_EXPIRATION: u64 = 432_000; // slots (roughly 2 days)
+const DEFAULT_ROUTER_ENDPOINT_REMOVAL_DELAY: u64 = 1_512_000; // slots (roughly 7 days)
 
 #[program]
 pub mod matching_engine {
@@ -62,7 +70,7 @@ pub mod matching_engine {
     }
 
     /// This instruction is used to pause or unpause further processing of new auctions. Only the
-    /// `owner` or `owner_assistant` can pause the program.
+    /// `owner`, `owner_assistant` or a `Pauser` role holder can pause the program.
     ///
     /// # Arguments
     ///
@@ -72,9 +80,41 @@ pub mod matching_engine {
         processor::set_pause(ctx, pause)
     }
 
+    /// This instruction is used to pause or unpause a specific stage of order processing (new
+    /// auctions, offer improvement, order execution, fast fill creation or settlement). A pause can
+    /// be lifted automatically at a specified slot. Only the `owner`, `owner_assistant` or a
+    /// `Pauser` role holder can pause a stage.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `SetStagePause` context.
+    /// * `args` - The `SetStagePauseArgs`, which has the stage, pause flag, optional unpause slot
+    ///            and reason code.
+    pub fn set_stage_pause(ctx: Context<SetStagePause>, args: SetStagePauseArgs) -> Result<()> {
+        processor::set_stage_pause(ctx, args)
+    }
+
+    /// This instruction is used to configure the circuit breaker, which caps the notional amount of
+    /// inbound fast orders per window of slots, both globally and per source chain. Once either
+    /// threshold is exceeded, new auctions are refused until the window rolls over. The circuit
+    /// breaker is disabled when the window size is zero. This instruction can only be called by
+    /// the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `UpdateCircuitBreaker` context.
+    /// * `args` - The `UpdateCircuitBreakerArgs`, which has the window size in slots and the global
+    ///            and per chain thresholds.
+    pub fn update_circuit_breaker(
+        ctx: Context<UpdateCircuitBreaker>,
+        args: UpdateCircuitBreakerArgs,
+    ) -> Result<()> {
+        processor::update_circuit_breaker(ctx, args)
+    }
+
     /// This instruction is used to add a new Token Router endpoint from a foreign chain. The
-    /// endpoint must be CCTP compatible. This instruction can only be called by the `owner` or
-    /// `owner_assistant`.
+    /// endpoint must be CCTP compatible. This instruction can only be called by the `owner`,
+    /// `owner_assistant` or an `EndpointManager` role holder.
     ///
     /// # Arguments
     ///
@@ -89,7 +129,7 @@ pub mod matching_engine {
 
     /// This instruction is used to add a new Local Router endpoint. Local means that the
     /// Token Router program exists on Solana. This instruction can only be called by the
-    /// `owner` or `owner_assistant`.
+    /// `owner`, `owner_assistant` or an `EndpointManager` role holder.
     ///
     /// # Arguments
     ///
@@ -98,9 +138,43 @@ pub mod matching_engine {
         processor::add_local_router_endpoint(ctx)
     }
 
+    /// This instruction is used to add a new Token Router endpoint from a foreign chain whose
+    /// assets are transferred via Native Token Transfers (NTT). The endpoint specifies the NTT
+    /// manager program and transceiver used for delivery. This instruction can only be called by
+    /// the `owner`, `owner_assistant` or an `EndpointManager` role holder.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `AddNttRouterEndpoint` context.
+    /// * `args` - The `AddNttRouterEndpointArgs`, see `admin.rs`.
+    pub fn add_ntt_router_endpoint(
+        ctx: Context<AddNttRouterEndpoint>,
+        args: AddNttRouterEndpointArgs,
+    ) -> Result<()> {
+        processor::add_ntt_router_endpoint(ctx, args)
+    }
+
+    /// This instruction is used to add a new Token Router endpoint from a foreign chain that does
+    /// not support CCTP. Assets are transferred to and from this endpoint via the Wormhole Token
+    /// Bridge. This instruction can only be called by the `owner`, `owner_assistant` or an
+    /// `EndpointManager` role holder.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `AddTokenBridgeRouterEndpoint` context.
+    /// * `args` - The `AddTokenBridgeRouterEndpointArgs`, see `admin.rs`.
+    pub fn add_token_bridge_router_endpoint(
+        ctx: Context<AddTokenBridgeRouterEndpoint>,
+        args: AddTokenBridgeRouterEndpointArgs,
+    ) -> Result<()> {
+        processor::add_token_bridge_router_endpoint(ctx, args)
+    }
+
     /// This instruction is used to disable a router endpoint. This instruction does not close the
     /// account, it only sets the `protocol` to `None` and clears the `address` and
-    /// `mint_recipient`. This instruction can only be called by the `owner`.
+    /// `mint_recipient`. The endpoint can be removed with `remove_router_endpoint` once it has been
+    /// disabled for the router endpoint removal delay. This instruction can only be called by the
+    /// `owner`.
     ///
     /// # Arguments
     ///
@@ -109,17 +183,118 @@ pub mod matching_engine {
         processor::disable_router_endpoint(ctx)
     }
 
-    /// This instruction is used to update a CCTP router endpoint. It allows the caller to change
-    /// the `address`, `mint_recipient`, and `domain`. This instruction can only be called by the
+    /// This instruction is used to remove a router endpoint that has been disabled for at least the
+    /// router endpoint removal delay. The endpoint account (and its local custody token account if
+    /// it exists) is closed and its lamports are returned to the `owner`. This instruction can only
+    /// be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `RemoveRouterEndpoint` context.
+    pub fn remove_router_endpoint(ctx: Context<RemoveRouterEndpoint>) -> Result<()> {
+        processor::remove_router_endpoint(ctx)
+    }
+
+    /// This instruction is used to migrate a router endpoint added by a previous program version,
+    /// whose account is reallocated (with rent paid by the `payer`) to fit the fields added since
+    /// then. It also creates the endpoint's `InboundNotional` account if it does not exist. This
+    /// instruction can be called by anyone.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`   - `MigrateRouterEndpoint` context.
+    /// * `chain` - Chain ID of the router endpoint.
+    pub fn migrate_router_endpoint(ctx: Context<MigrateRouterEndpoint>, chain: u16) -> Result<()> {
+        processor::migrate_router_endpoint(ctx, chain)
+    }
+
+    /// This instruction is used to add a mint (other than USDC, which is always supported) whose
+    /// orders can be auctioned and settled. It also creates the custodian's token account for this
+    /// mint, which is used as the CCTP mint recipient. This instruction can only be called by the
     /// `owner`.
     ///
     /// # Arguments
     ///
+    /// * `ctx` - `AddSupportedMint` context.
+    pub fn add_supported_mint(ctx: Context<AddSupportedMint>) -> Result<()> {
+        processor::add_supported_mint(ctx)
+    }
+
+    /// This instruction is used to remove a supported mint so new auctions cannot be started with
+    /// it. Its `SupportedMint` account is closed and its lamports are returned to the `owner`. This
+    /// instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `RemoveSupportedMint` context.
+    pub fn remove_supported_mint(ctx: Context<RemoveSupportedMint>) -> Result<()> {
+        processor::remove_supported_mint(ctx)
+    }
+
+    /// This instruction is used to temporarily pause a router endpoint without disabling it. While
+    /// paused, orders to and from this endpoint are rejected. A pause can be lifted automatically at
+    /// a specified slot. Only the `owner`, `owner_assistant` or a `Pauser` role holder can pause an
+    /// endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `SetRouterEndpointPause` context.
+    /// * `args` - The `SetRouterEndpointPauseArgs`, which has the pause flag, optional unpause slot
+    ///            and reason code.
+    pub fn set_router_endpoint_pause(
+        ctx: Context<SetRouterEndpointPause>,
+        args: SetRouterEndpointPauseArgs,
+    ) -> Result<()> {
+        processor::set_router_endpoint_pause(ctx, args)
+    }
+
+    /// This instruction is used to cap the notional amount of fast orders accepted from a router
+    /// endpoint per window of slots. Setting a window of zero slots removes the limit. Only the
+    /// `owner`, `owner_assistant` or an `EndpointManager` role holder can set this limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `SetRouterEndpointInboundLimit` context.
+    /// * `args` - The `SetRouterEndpointInboundLimitArgs`, which has the window size in slots and
+    ///            the max notional amount per window.
+    pub fn set_router_endpoint_inbound_limit(
+        ctx: Context<SetRouterEndpointInboundLimit>,
+        args: SetRouterEndpointInboundLimitArgs,
+    ) -> Result<()> {
+        processor::set_router_endpoint_inbound_limit(ctx, args)
+    }
+
+    /// This instruction is used to set the optional limits on orders destined for a router
+    /// endpoint, which are the max redeemer message length and the min and max order amounts. These
+    /// limits are enforced when market orders are prepared by the Token Router and when auctions
+    /// are started. Only the `owner`, `owner_assistant` or an `EndpointManager` role holder can set
+    /// these limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`    - `SetRouterEndpointLimits` context.
+    /// * `limits` - The `EndpointLimits`.
+    pub fn set_router_endpoint_limits(
+        ctx: Context<SetRouterEndpointLimits>,
+        limits: EndpointLimits,
+    ) -> Result<()> {
+        processor::set_router_endpoint_limits(ctx, limits)
+    }
+
+    /// This instruction is used to update a CCTP router endpoint. It allows the caller to change
+    /// the `address`, `mint_recipient`, and `domain`. When the `address` changes, the previous
+    /// address can still be accepted as a source of messages until a specified slot so that
+    /// messages in flight from the old emitter can be processed. This instruction can only be
+    /// called by the `owner`.
+    ///
+    /// # Arguments
+    ///
     /// * `ctx`  - `UpdateCctpRouterEndpoint` context.
-    /// * `args` - The `AddCctpRouterEndpointArgs`, see `admin.rs`.
+    /// * `args` - The `UpdateCctpRouterEndpointArgs`, which has the `AddCctpRouterEndpointArgs`
+    ///            (see `admin.rs`) and the optional expiry slot of the previous address.
     pub fn update_cctp_router_endpoint(
         ctx: Context<UpdateCctpRouterEndpoint>,
-        args: AddCctpRouterEndpointArgs,
+        args: UpdateCctpRouterEndpointArgs,
     ) -> Result<()> {
         processor::update_cctp_router_endpoint(ctx, args)
     }
@@ -134,9 +309,45 @@ pub mod matching_engine {
         processor::update_local_router_endpoint(ctx)
     }
 
+    /// This instruction is used to update an NTT router endpoint. It allows the caller to change
+    /// the `address`, `mint_recipient`, NTT manager and transceiver. When the `address` changes, the
+    /// previous address can still be accepted as a source of messages until a specified slot. This
+    /// instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `UpdateNttRouterEndpoint` context.
+    /// * `args` - The `UpdateNttRouterEndpointArgs`, which has the `AddNttRouterEndpointArgs`
+    ///            (see `admin.rs`) and the optional expiry slot of the previous address.
+    pub fn update_ntt_router_endpoint(
+        ctx: Context<UpdateNttRouterEndpoint>,
+        args: UpdateNttRouterEndpointArgs,
+    ) -> Result<()> {
+        processor::update_ntt_router_endpoint(ctx, args)
+    }
+
+    /// This instruction is used to update a Token Bridge router endpoint. It allows the caller to
+    /// change the `address` and `mint_recipient`. When the `address` changes, the previous address
+    /// can still be accepted as a source of messages until a specified slot. This instruction can
+    /// only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `UpdateTokenBridgeRouterEndpoint` context.
+    /// * `args` - The `UpdateTokenBridgeRouterEndpointArgs`, which has the
+    ///            `AddTokenBridgeRouterEndpointArgs` (see `admin.rs`) and the optional expiry slot
+    ///            of the previous address.
+    pub fn update_token_bridge_router_endpoint(
+        ctx: Context<UpdateTokenBridgeRouterEndpoint>,
+        args: UpdateTokenBridgeRouterEndpointArgs,
+    ) -> Result<()> {
+        processor::update_token_bridge_router_endpoint(ctx, args)
+    }
+
     /// This instruction sets the `pending_owner` field in the `Custodian` account. This instruction
     /// can only be called by the `owner`. The `pending_owner` address must be valid, meaning it
-    /// cannot be the zero address or the current owner.
+    /// cannot be the zero address or the current owner. The request expires if it is not confirmed
+    /// within `OWNERSHIP_TRANSFER_REQUEST_EXPIRATION` slots.
     ///
     /// # Arguments
     ///
@@ -149,7 +360,8 @@ pub mod matching_engine {
 
     /// This instruction confirms the ownership transfer request and sets the new `owner` in the
     /// `Custodian` account. This instruction can only be called by the `pending_owner`. The
-    /// `pending_owner` must be the same as the `pending_owner` in the `Custodian` account.
+    /// `pending_owner` must be the same as the `pending_owner` in the `Custodian` account, and the
+    /// request must not have expired.
     ///
     /// # Arguments
     ///
@@ -172,6 +384,111 @@ pub mod matching_engine {
         processor::cancel_ownership_transfer_request(ctx)
     }
 
+    /// This instruction is used to grant a role to a holder, creating the holder's `RoleHolder`
+    /// account if it does not exist. This instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `GrantRole` context.
+    /// * `role` - The `Role` to grant.
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+        processor::grant_role(ctx, role)
+    }
+
+    /// This instruction is used to revoke a role from a holder. This instruction can only be called
+    /// by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `RevokeRole` context.
+    /// * `role` - The `Role` to revoke.
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+        processor::revoke_role(ctx, role)
+    }
+
+    /// This instruction is used to create the program's `OwnerSet` account, which holds M-of-N
+    /// members who can collectively act as the program's owner. The owner set only takes control
+    /// once ownership is transferred to the `OwnerSet` address. This instruction can only be called
+    /// by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `InitializeOwnerSet` context.
+    /// * `args` - The `OwnerSetArgs`, which has the threshold and members.
+    pub fn initialize_owner_set(
+        ctx: Context<InitializeOwnerSet>,
+        args: OwnerSetArgs,
+    ) -> Result<()> {
+        processor::initialize_owner_set(ctx, args)
+    }
+
+    /// This instruction is used to replace the threshold and members of the `OwnerSet` account.
+    /// This instruction can only be called by the `owner`, which is the owner set itself if
+    /// ownership has been transferred to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `UpdateOwnerSet` context.
+    /// * `args` - The `OwnerSetArgs`, which has the threshold and members.
+    ///
+    /// Pending `OwnerSetTransaction` accounts may be passed as remaining accounts, which drops
+    /// approvals from members who are no longer in the set.
+    pub fn update_owner_set<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateOwnerSet<'info>>,
+        args: OwnerSetArgs,
+    ) -> Result<()> {
+        processor::update_owner_set(ctx, args)
+    }
+
+    /// This instruction is used by an owner set member to propose an instruction for this program,
+    /// which the owner set will sign for once enough members have approved it. The proposer's
+    /// approval is recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `ProposeOwnerSetTransaction` context.
+    /// * `args` - The `OwnerSetTransactionArgs`, which has the instruction's accounts and data.
+    pub fn propose_owner_set_transaction(
+        ctx: Context<ProposeOwnerSetTransaction>,
+        args: OwnerSetTransactionArgs,
+    ) -> Result<()> {
+        processor::propose_owner_set_transaction(ctx, args)
+    }
+
+    /// This instruction is used by an owner set member to approve a proposed owner set
+    /// transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ApproveOwnerSetTransaction` context.
+    pub fn approve_owner_set_transaction(ctx: Context<ApproveOwnerSetTransaction>) -> Result<()> {
+        processor::approve_owner_set_transaction(ctx)
+    }
+
+    /// This instruction is used to execute an owner set transaction once the threshold of member
+    /// approvals has been met. The transaction's accounts must be passed as remaining accounts. The
+    /// transaction account is closed and its lamports are returned to the proposer. This
+    /// instruction can be called by anyone.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ExecuteOwnerSetTransaction` context.
+    pub fn execute_owner_set_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteOwnerSetTransaction<'info>>,
+    ) -> Result<()> {
+        processor::execute_owner_set_transaction(ctx)
+    }
+
+    /// This instruction is used to close a proposed owner set transaction without executing it.
+    /// This instruction can only be called by the member who proposed the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `CancelOwnerSetTransaction` context.
+    pub fn cancel_owner_set_transaction(ctx: Context<CancelOwnerSetTransaction>) -> Result<()> {
+        processor::cancel_owner_set_transaction(ctx)
+    }
+
     /// This instruction is used to propose new auction parameters. A proposal cannot be enacted
     /// until one epoch has passed. This instruction can only be called by the `owner` or
     /// `owner_assistant`.
@@ -208,8 +525,9 @@ pub mod matching_engine {
         processor::close_proposal(ctx)
     }
 
-    /// This instruction is used to update the `owner_assistant` field in the `Custodian` account.
-    /// This instruction can only be called by the `owner`.
+    /// This instruction is used to propose a new `owner_assistant`, which is set as the
+    /// `pending_owner_assistant` in the `Custodian` account. The new assistant must accept the role
+    /// using `accept_owner_assistant`. This instruction can only be called by the `owner`.
     ///
     /// # Arguments
     ///
@@ -218,8 +536,34 @@ pub mod matching_engine {
         processor::update_owner_assistant(ctx)
     }
 
+    /// This instruction is used to accept the owner assistant role, replacing the `owner_assistant`
+    /// with the `pending_owner_assistant` in the `Custodian` account. This instruction can only be
+    /// called by the `pending_owner_assistant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `AcceptOwnerAssistant` context.
+    pub fn accept_owner_assistant(ctx: Context<AcceptOwnerAssistant>) -> Result<()> {
+        processor::accept_owner_assistant(ctx)
+    }
+
+    /// This instruction is used to update the number of slots a router endpoint must be disabled
+    /// for before it can be removed. This instruction can only be called by the `owner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`   - `UpdateRouterEndpointRemovalDelay` context.
+    /// * `delay` - Number of slots.
+    pub fn update_router_endpoint_removal_delay(
+        ctx: Context<UpdateRouterEndpointRemovalDelay>,
+        delay: u64,
+    ) -> Result<()> {
+        processor::update_router_endpoint_removal_delay(ctx, delay)
+    }
+
     /// This instruction is used to update the `fee_recipient` field in the `Custodian` account.
-    /// This instruction can only be called by the `owner` or `owner_assistant`.
+    /// This instruction can only be called by the `owner`, `owner_assistant` or a `FeeManager` role
+    /// holder.
     ///
     /// # Arguments
     ///
@@ -228,8 +572,25 @@ pub mod matching_engine {
         processor::update_fee_recipient(ctx)
     }
 
+    /// This instruction is used to update the maximum gas drop-off (in lamports) a fast market
+    /// order delivered to Solana can request. This instruction can only be called by the `owner`,
+    /// `owner_assistant` or a `FeeManager` role holder.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`             - `UpdateMaxGasDropoff` context.
+    /// * `max_gas_dropoff` - Maximum gas drop-off in lamports.
+    pub fn update_max_gas_dropoff(
+        ctx: Context<UpdateMaxGasDropoff>,
+        max_gas_dropoff: u64,
+    ) -> Result<()> {
+        processor::update_max_gas_dropoff(ctx, max_gas_dropoff)
+    }
+
     /// This instruction is used for executing logic during an upgrade. This instruction can only be
-    /// called by the `upgrade_manager_program`.
+    /// called by the `upgrade_manager_program`. If the `Custodian` account still has its legacy
+    /// layout, it is reallocated (with rent paid by the `payer`) and rewritten with new fields set to
+    /// their defaults.
     ///
     /// # Arguments
     ///
@@ -267,6 +628,40 @@ pub mod matching_engine {
         processor::improve_offer(ctx, offer_price)
     }
 
+    /// This instruction is used to create a new auction for a `FastMarketOrder` destined for
+    /// Solana, where participants bid on the amount of a non-USDC asset delivered to the recipient
+    /// instead of an offer price. The order must name this destination asset and the minimum
+    /// amount out of it. The security deposit is paid in USDC and the amount out is held in an
+    /// auction-specific destination custody account. This instruction can be called by anyone.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`        - `PlaceInitialOfferDestinationAsset` context.
+    /// * `amount_out` - The amount of the destination asset the caller is willing to deliver in
+    ///                  order to fulfill the fast order.
+    pub fn place_initial_offer_destination_asset(
+        ctx: Context<PlaceInitialOfferDestinationAsset>,
+        amount_out: u64,
+    ) -> Result<()> {
+        processor::place_initial_offer_destination_asset(ctx, amount_out)
+    }
+
+    /// This instruction is used to improve an existing destination asset auction offer. The
+    /// `amount_out` must exceed the current amount out by at least the auction's minimum offer
+    /// delta. This instruction can be called by anyone.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`        - `ImproveOfferDestinationAsset` context.
+    /// * `amount_out` - The amount of the destination asset the caller is willing to deliver in
+    ///                  order to fulfill the fast order.
+    pub fn improve_offer_destination_asset(
+        ctx: Context<ImproveOfferDestinationAsset>,
+        amount_out: u64,
+    ) -> Result<()> {
+        processor::improve_offer_destination_asset(ctx, amount_out)
+    }
+
     /// This instruction is used to execute the fast order after the auction period has ended.
     /// It should be executed before the `grace_period` has ended, otherwise the best offer will
     /// incur a penalty. Once executed, a CCTP transfer will be sent to the recipient encoded in the
@@ -281,7 +676,9 @@ pub mod matching_engine {
 
     /// This instruction is used to execute the fast order after the auction period has ended.
     /// It should be executed before the `grace_period` has ended, otherwise the best offer will
-    /// incur a penalty. Once executed, a `FastFill` account will be created.
+    /// incur a penalty. Once executed, a `FastFill` account will be created. If the order requests
+    /// a gas drop-off, the `payer` transfers it to the order's redeemer and is compensated with the
+    /// order's gas drop-off fee.
     ///
     /// # Arguments
     ///
@@ -290,6 +687,32 @@ pub mod matching_engine {
         processor::execute_fast_order_local(ctx)
     }
 
+    /// This instruction is used to execute the fast order after the auction period has ended.
+    /// It should be executed before the `grace_period` has ended, otherwise the best offer will
+    /// incur a penalty. Once executed, the tokens are transferred via the target router endpoint's
+    /// NTT manager and a `Fill` message is published for the target chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ExecuteFastOrderNtt` context.
+    pub fn execute_fast_order_ntt(ctx: Context<ExecuteFastOrderNtt>) -> Result<()> {
+        processor::execute_fast_order_ntt(ctx)
+    }
+
+    /// This instruction is used to execute the fast order after the auction period has ended.
+    /// It should be executed before the `grace_period` has ended, otherwise the best offer will
+    /// incur a penalty. Once executed, a Token Bridge transfer will be sent to the mint recipient
+    /// of the target router endpoint, whose chain does not support CCTP.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ExecuteFastOrderTokenBridge` context.
+    pub fn execute_fast_order_token_bridge(
+        ctx: Context<ExecuteFastOrderTokenBridge>,
+    ) -> Result<()> {
+        processor::execute_fast_order_token_bridge(ctx)
+    }
+
     /// This instruction is used to complete the fast fill after the `FastFill` account has been
     /// created. The Token Router program on Solana will invoke this instruction to complete the
     /// fast fill, marking it as redeemed. Tokens will be deposited into the local endpoint's
@@ -345,7 +768,8 @@ pub mod matching_engine {
     /// This instruction is used to settle a `FastMarketOrder` with no corresponding auction. This
     /// instruction can be called by anyone, but the sum of `init_auction_fee` and `base_fee`
     /// associated with relaying a finalized VAA will be paid to the `fee_recipient`. This
-    /// instruction creates a `FastFill` account.
+    /// instruction creates a `FastFill` account, whose relayer fee is read from the `fast_vaa`
+    /// account if it is provided.
     ///
     /// # Arguments
     ///
@@ -354,6 +778,32 @@ pub mod matching_engine {
         processor::settle_auction_none_local(ctx)
     }
 
+    /// This instruction is used to route funds via an NTT manager for a `FastMarketOrder` with no
+    /// corresponding auction on Solana. This instruction can be called by anyone, but the sum of
+    /// `init_auction_fee` and `base_fee` associated with relaying a finalized VAA will be paid to
+    /// the `fee_recipient`. This instruction generates a `Fill` message.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `SettleAuctionNoneNtt` context.
+    pub fn settle_auction_none_ntt(ctx: Context<SettleAuctionNoneNtt>) -> Result<()> {
+        processor::settle_auction_none_ntt(ctx)
+    }
+
+    /// This instruction is used to route funds via the Token Bridge for a `FastMarketOrder` with
+    /// no corresponding auction on Solana. This instruction can be called by anyone, but the sum of
+    /// `init_auction_fee` and `base_fee` associated with relaying a finalized VAA will be paid to
+    /// the `fee_recipient`. This instruction generates a `Fill` message.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `SettleAuctionNoneTokenBridge` context.
+    pub fn settle_auction_none_token_bridge(
+        ctx: Context<SettleAuctionNoneTokenBridge>,
+    ) -> Result<()> {
+        processor::settle_auction_none_token_bridge(ctx)
+    }
+
     /// This instruction is used to reserve a sequence number for a fast fill. Fast fills are orders
     /// that have been fulfilled and are destined for Solana and are seeded by source chain, order
     /// sender and sequence number (similar to how Wormhole VAAs are identified by emitter chain,
@@ -414,6 +864,42 @@ pub mod matching_engine {
         processor::close_redeemed_fast_fill(ctx)
     }
 
+    /// This instruction is used to migrate a `FastFill` account created by a previous version of
+    /// this program. The account is reallocated to fit the fields appended to `FastFill` since
+    /// then. This instruction can be called by anyone, who pays for any additional rent.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `MigrateFastFill` context.
+    pub fn migrate_fast_fill(ctx: Context<MigrateFastFill>) -> Result<()> {
+        processor::migrate_fast_fill(ctx)
+    }
+
+    /// This instruction is used to reclaim a `FastFill` account that was not redeemed within
+    /// `FastFill::EXPIRY_SECONDS` of being issued. The fill amount is delivered to the redeemer's
+    /// associated token account. If this token account cannot receive the fill amount, the amount
+    /// is held in the redeemer's escrow token account instead, which the redeemer can claim with
+    /// `claim_fast_fill_escrow`. Lamports are returned to the creator of the `FastFill` account.
+    /// This instruction can be called by anyone.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ReclaimExpiredFastFill` context.
+    pub fn reclaim_expired_fast_fill(ctx: Context<ReclaimExpiredFastFill>) -> Result<()> {
+        processor::reclaim_expired_fast_fill(ctx)
+    }
+
+    /// This instruction is used by the redeemer of reclaimed fast fills to claim the amounts held
+    /// in its escrow token account. The escrow token account is closed and its lamports are sent to
+    /// the redeemer.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `ClaimFastFillEscrow` context.
+    pub fn claim_fast_fill_escrow(ctx: Context<ClaimFastFillEscrow>) -> Result<()> {
+        processor::claim_fast_fill_escrow(ctx)
+    }
+
     /// This instruction is used to close an auction account after the auction has been settled and
     /// the VAA's timestamp indicates the order has expired. This instruction can be called by
     /// anyone to return the auction's preparer lamports from the rent required to keep this account
@@ -0,0 +1,32 @@
+use crate::{error::MatchingEngineError, state::Custodian};
+use anchor_lang::prelude::*;
+use common::admin::utils::pending_owner_assistant;
+
+#[derive(Accounts)]
+pub struct AcceptOwnerAssistant<'info> {
+    /// Must be the pending owner assistant set in the [Custodian] account.
+    pending_owner_assistant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+        constraint = {
+            custodian.pending_owner_assistant.is_some()
+        } @ MatchingEngineError::NoPendingOwnerAssistant,
+        constraint = {
+            pending_owner_assistant::only_pending_owner_assistant_unchecked(
+                &custodian,
+                &pending_owner_assistant.key()
+            )
+        } @ MatchingEngineError::NotPendingOwnerAssistant,
+    )]
+    custodian: Box<Account<'info, Custodian>>,
+}
+
+pub fn accept_owner_assistant(ctx: Context<AcceptOwnerAssistant>) -> Result<()> {
+    pending_owner_assistant::accept_owner_assistant_unchecked(&mut ctx.accounts.custodian);
+
+    // Done.
+    Ok(())
+}
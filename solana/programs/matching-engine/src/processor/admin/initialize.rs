@@ -1,7 +1,7 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{AuctionConfig, Custodian},
+    state::{AuctionConfig, CircuitBreaker, Custodian},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -39,6 +39,15 @@ pub struct Initialize<'info> {
     )]
     auction_config: Box<Account<'info, AuctionConfig>>,
 
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + CircuitBreaker::INIT_SPACE,
+        seeds = [CircuitBreaker::SEED_PREFIX],
+        bump,
+    )]
+    circuit_breaker: Box<Account<'info, CircuitBreaker>>,
+
     /// CHECK: This account must not be the zero pubkey.
     /// TODO: do we prevent the owner from being the owner assistant?
     #[account(
@@ -153,6 +162,17 @@ pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()>
         fee_recipient_token: ctx.accounts.fee_recipient_token.key(),
         auction_config_id,
         next_proposal_id: Default::default(),
+        pending_owner_expiry_slot: Default::default(),
+        pending_owner_assistant: None,
+        pause_flags: Default::default(),
+        circuit_breaker: Default::default(),
+        router_endpoint_removal_delay: crate::DEFAULT_ROUTER_ENDPOINT_REMOVAL_DELAY,
+        max_gas_dropoff: Default::default(),
+    });
+
+    ctx.accounts.circuit_breaker.set_inner(CircuitBreaker {
+        bump: ctx.bumps.circuit_breaker,
+        global: Default::default(),
     });
 
     ctx.accounts.auction_config.set_inner(AuctionConfig {
@@ -1,3 +1,6 @@
+mod accept;
+pub use accept::*;
+
 mod close_proposal;
 pub use close_proposal::*;
 
@@ -7,17 +10,29 @@ pub use initialize::*;
 mod set_pause;
 pub use set_pause::*;
 
+mod set_stage_pause;
+pub use set_stage_pause::*;
+
 mod migrate;
 pub use migrate::*;
 
 mod ownership_transfer_request;
 pub use ownership_transfer_request::*;
 
+mod owner_set;
+pub use owner_set::*;
+
 mod propose;
 pub use propose::*;
 
+mod role;
+pub use role::*;
+
 mod router_endpoint;
 pub use router_endpoint::*;
 
+mod supported_mint;
+pub use supported_mint::*;
+
 mod update;
 pub use update::*;
@@ -0,0 +1,32 @@
+mod initialize;
+pub use initialize::*;
+
+mod transaction;
+pub use transaction::*;
+
+mod update;
+pub use update::*;
+
+use crate::{error::MatchingEngineError, state::OwnerSet};
+use anchor_lang::prelude::*;
+use common::admin::utils::owner_set;
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OwnerSetArgs {
+    /// Number of member approvals required to execute an owner set transaction.
+    pub threshold: u8,
+
+    /// Members of the owner set. There can be at most [OwnerSet::MAX_MEMBERS] members.
+    pub members: Vec<Pubkey>,
+}
+
+fn require_valid_owner_set(args: &OwnerSetArgs) -> Result<()> {
+    require!(
+        args.members.len() <= OwnerSet::MAX_MEMBERS
+            && owner_set::is_valid_owner_set(&args.members, args.threshold),
+        MatchingEngineError::InvalidOwnerSet
+    );
+
+    // Done.
+    Ok(())
+}
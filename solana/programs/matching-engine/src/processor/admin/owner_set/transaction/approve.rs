@@ -0,0 +1,32 @@
+use crate::{composite::*, error::MatchingEngineError, state::OwnerSetTransaction};
+use anchor_lang::prelude::*;
+use common::admin::utils::owner_set;
+
+#[derive(Accounts)]
+pub struct ApproveOwnerSetTransaction<'info> {
+    owner_set_member: OwnerSetMember<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            OwnerSetTransaction::SEED_PREFIX,
+            &transaction.id.to_be_bytes()
+        ],
+        bump = transaction.bump,
+    )]
+    transaction: Box<Account<'info, OwnerSetTransaction>>,
+}
+
+pub fn approve_owner_set_transaction(ctx: Context<ApproveOwnerSetTransaction>) -> Result<()> {
+    require!(
+        owner_set::approve(
+            &ctx.accounts.owner_set_member.owner_set,
+            &mut ctx.accounts.transaction,
+            &ctx.accounts.owner_set_member.member.key()
+        ),
+        MatchingEngineError::OwnerSetTransactionAlreadyApproved
+    );
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,11 @@
+mod approve;
+pub use approve::*;
+
+mod cancel;
+pub use cancel::*;
+
+mod execute;
+pub use execute::*;
+
+mod propose;
+pub use propose::*;
@@ -0,0 +1,43 @@
+use crate::{
+    composite::*,
+    state::{OwnerSet, OwnerSetTransaction},
+};
+use anchor_lang::prelude::*;
+use common::admin::utils::owner_set;
+
+#[derive(Accounts)]
+pub struct UpdateOwnerSet<'info> {
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        mut,
+        seeds = [OwnerSet::SEED_PREFIX],
+        bump = owner_set.bump,
+    )]
+    owner_set: Account<'info, OwnerSet>,
+}
+
+pub fn update_owner_set<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateOwnerSet<'info>>,
+    args: super::OwnerSetArgs,
+) -> Result<()> {
+    super::require_valid_owner_set(&args)?;
+
+    let super::OwnerSetArgs { threshold, members } = args;
+
+    let owner_set = &mut ctx.accounts.owner_set;
+    owner_set.threshold = threshold;
+    owner_set.members = members;
+
+    // Pending transactions passed in as remaining accounts have approvals from former members
+    // dropped now. Any other pending transaction is pruned when it is next approved, and approvals
+    // from former members never count towards the threshold.
+    for acc_info in ctx.remaining_accounts {
+        let mut transaction = Account::<OwnerSetTransaction>::try_from(acc_info)?;
+        owner_set::prune_approvals(&owner_set.members, &mut transaction);
+        transaction.exit(&crate::ID)?;
+    }
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::{composite::*, error::MatchingEngineError, state::RoleHolder};
+use anchor_lang::prelude::*;
+use common::admin::{utils::roles, Role};
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    /// Role holder.
+    ///
+    /// CHECK: Must not be zero pubkey.
+    #[account(
+        constraint = {
+            holder.key() != Pubkey::default()
+        } @ MatchingEngineError::RoleHolderZeroPubkey,
+    )]
+    holder: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RoleHolder::INIT_SPACE,
+        seeds = [
+            RoleHolder::SEED_PREFIX,
+            holder.key().as_ref(),
+        ],
+        bump,
+    )]
+    role_holder: Account<'info, RoleHolder>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+    let role_holder = &mut ctx.accounts.role_holder;
+    role_holder.bump = ctx.bumps.role_holder;
+    role_holder.holder = ctx.accounts.holder.key();
+
+    require!(
+        roles::grant_role(role_holder, role),
+        MatchingEngineError::RoleAlreadyGranted
+    );
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,5 @@
+mod grant;
+pub use grant::*;
+
+mod revoke;
+pub use revoke::*;
@@ -1,6 +1,6 @@
 use crate::{
     composite::*,
-    state::RouterEndpoint,
+    state::{InboundNotional, RouterEndpoint, RouterEndpointRegistry},
     utils::{self, admin::AddCctpRouterEndpointArgs},
 };
 use anchor_lang::prelude::*;
@@ -15,7 +15,7 @@ pub struct AddCctpRouterEndpoint<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
-    admin: Admin<'info>,
+    admin: EndpointManager<'info>,
 
     #[account(
         init,
@@ -29,21 +29,44 @@ pub struct AddCctpRouterEndpoint<'info> {
     )]
     router_endpoint: Account<'info, RouterEndpoint>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RouterEndpointRegistry::INIT_SPACE,
+        seeds = [RouterEndpointRegistry::SEED_PREFIX],
+        bump,
+    )]
+    router_endpoint_registry: Box<Account<'info, RouterEndpointRegistry>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + InboundNotional::INIT_SPACE,
+        seeds = [
+            InboundNotional::SEED_PREFIX,
+            &args.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    inbound_notional: Box<Account<'info, InboundNotional>>,
+
+    /// Mint of the asset transferred by orders from this endpoint.
+    mint: CheckedMint<'info>,
+
     #[account(
         init,
         payer = payer,
-        token::mint = usdc,
+        token::mint = mint,
         token::authority = router_endpoint,
         seeds = [
             crate::LOCAL_CUSTODY_TOKEN_SEED_PREFIX,
             &args.chain.to_be_bytes(),
+            utils::local_custody_mint_seed(&mint.key()),
         ],
         bump,
     )]
     local_custody_token: Box<Account<'info, token::TokenAccount>>,
 
-    usdc: Usdc<'info>,
-
     /// CHECK: Seeds must be \["remote_token_messenger"\, remote_domain.to_string()] (CCTP Token
     /// Messenger Minter program).
     #[account(
@@ -68,5 +91,15 @@ pub fn add_cctp_router_endpoint(
         &mut ctx.accounts.router_endpoint,
         args,
         ctx.bumps.router_endpoint.into(),
+    )?;
+
+    ctx.accounts.router_endpoint.mint = ctx.accounts.mint.key();
+    ctx.accounts.inbound_notional.bump = ctx.bumps.inbound_notional;
+
+    let registry = &mut ctx.accounts.router_endpoint_registry;
+    registry.bump = ctx.bumps.router_endpoint_registry;
+    registry.upsert(
+        ctx.accounts.router_endpoint.chain,
+        ctx.accounts.router_endpoint.protocol,
     )
 }
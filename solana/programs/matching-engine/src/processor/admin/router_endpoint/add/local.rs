@@ -1,4 +1,8 @@
-use crate::{composite::*, state::RouterEndpoint, utils};
+use crate::{
+    composite::*,
+    state::{InboundNotional, RouterEndpoint, RouterEndpointRegistry},
+    utils,
+};
 use anchor_lang::prelude::*;
 use common::wormhole_cctp_solana::wormhole::SOLANA_CHAIN;
 
@@ -7,7 +11,7 @@ pub struct AddLocalRouterEndpoint<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
-    admin: Admin<'info>,
+    admin: EndpointManager<'info>,
 
     #[account(
         init,
@@ -21,6 +25,27 @@ pub struct AddLocalRouterEndpoint<'info> {
     )]
     router_endpoint: Account<'info, RouterEndpoint>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RouterEndpointRegistry::INIT_SPACE,
+        seeds = [RouterEndpointRegistry::SEED_PREFIX],
+        bump,
+    )]
+    router_endpoint_registry: Box<Account<'info, RouterEndpointRegistry>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + InboundNotional::INIT_SPACE,
+        seeds = [
+            InboundNotional::SEED_PREFIX,
+            &SOLANA_CHAIN.to_be_bytes(),
+        ],
+        bump,
+    )]
+    inbound_notional: Box<Account<'info, InboundNotional>>,
+
     local: LocalTokenRouter<'info>,
 
     system_program: Program<'info, System>,
@@ -33,5 +58,15 @@ pub fn add_local_router_endpoint(ctx: Context<AddLocalRouterEndpoint>) -> Result
         &ctx.accounts.local.token_router_emitter,
         &ctx.accounts.local.token_router_mint_recipient,
         ctx.bumps.router_endpoint.into(),
+    )?;
+
+    ctx.accounts.router_endpoint.mint = common::USDC_MINT;
+    ctx.accounts.inbound_notional.bump = ctx.bumps.inbound_notional;
+
+    let registry = &mut ctx.accounts.router_endpoint_registry;
+    registry.bump = ctx.bumps.router_endpoint_registry;
+    registry.upsert(
+        ctx.accounts.router_endpoint.chain,
+        ctx.accounts.router_endpoint.protocol,
     )
 }
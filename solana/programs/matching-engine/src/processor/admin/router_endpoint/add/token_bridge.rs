@@ -0,0 +1,90 @@
+use crate::{
+    composite::*,
+    state::{InboundNotional, RouterEndpoint, RouterEndpointRegistry},
+    utils::{self, admin::AddTokenBridgeRouterEndpointArgs},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+#[instruction(args: AddTokenBridgeRouterEndpointArgs)]
+pub struct AddTokenBridgeRouterEndpoint<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: EndpointManager<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RouterEndpoint::INIT_SPACE,
+        seeds = [
+            RouterEndpoint::SEED_PREFIX,
+            &args.chain.to_be_bytes()
+        ],
+        bump,
+    )]
+    router_endpoint: Account<'info, RouterEndpoint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RouterEndpointRegistry::INIT_SPACE,
+        seeds = [RouterEndpointRegistry::SEED_PREFIX],
+        bump,
+    )]
+    router_endpoint_registry: Box<Account<'info, RouterEndpointRegistry>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + InboundNotional::INIT_SPACE,
+        seeds = [
+            InboundNotional::SEED_PREFIX,
+            &args.chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    inbound_notional: Box<Account<'info, InboundNotional>>,
+
+    /// Mint of the asset transferred by orders from this endpoint.
+    mint: CheckedMint<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = router_endpoint,
+        seeds = [
+            crate::LOCAL_CUSTODY_TOKEN_SEED_PREFIX,
+            &args.chain.to_be_bytes(),
+            utils::local_custody_mint_seed(&mint.key()),
+        ],
+        bump,
+    )]
+    local_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+pub fn add_token_bridge_router_endpoint(
+    ctx: Context<AddTokenBridgeRouterEndpoint>,
+    args: AddTokenBridgeRouterEndpointArgs,
+) -> Result<()> {
+    utils::admin::handle_add_token_bridge_router_endpoint(
+        &mut ctx.accounts.router_endpoint,
+        args,
+        ctx.bumps.router_endpoint.into(),
+    )?;
+
+    ctx.accounts.router_endpoint.mint = ctx.accounts.mint.key();
+    ctx.accounts.inbound_notional.bump = ctx.bumps.inbound_notional;
+
+    let registry = &mut ctx.accounts.router_endpoint_registry;
+    registry.bump = ctx.bumps.router_endpoint_registry;
+    registry.upsert(
+        ctx.accounts.router_endpoint.chain,
+        ctx.accounts.router_endpoint.protocol,
+    )
+}
@@ -6,14 +6,20 @@ pub struct DisableRouterEndpoint<'info> {
     admin: OwnerOnly<'info>,
 
     router_endpoint: ExistingMutRouterEndpoint<'info>,
+
+    router_endpoint_registry: RouterEndpointRegistryMut<'info>,
 }
 
 pub fn disable_router_endpoint(ctx: Context<DisableRouterEndpoint>) -> Result<()> {
+    ctx.accounts.router_endpoint.disabled_slot = Some(Clock::get()?.slot);
+    ctx.accounts.router_endpoint.previous_address = None;
+
     let endpoint = &mut ctx.accounts.router_endpoint.info;
     endpoint.protocol = MessageProtocol::None;
     endpoint.address = Default::default();
     endpoint.mint_recipient = Default::default();
 
-    // Done.
-    Ok(())
+    ctx.accounts
+        .router_endpoint_registry
+        .upsert(endpoint.chain, endpoint.protocol)
 }
@@ -0,0 +1,61 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{InboundLimit, InboundNotional, NotionalAccumulator},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRouterEndpointInboundLimit<'info> {
+    admin: EndpointManager<'info>,
+
+    router_endpoint: ExistingMutRouterEndpoint<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboundNotional::SEED_PREFIX,
+            &router_endpoint.chain.to_be_bytes(),
+        ],
+        bump = inbound_notional.bump,
+    )]
+    inbound_notional: Account<'info, InboundNotional>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SetRouterEndpointInboundLimitArgs {
+    /// Number of slots in each window. Zero removes the limit.
+    pub window_slots: u64,
+
+    /// Maximum notional amount of fast orders accepted from this endpoint within a window.
+    pub max_notional: u64,
+}
+
+pub fn set_router_endpoint_inbound_limit(
+    ctx: Context<SetRouterEndpointInboundLimit>,
+    args: SetRouterEndpointInboundLimitArgs,
+) -> Result<()> {
+    let SetRouterEndpointInboundLimitArgs {
+        window_slots,
+        max_notional,
+    } = args;
+
+    require!(
+        window_slots == 0 || max_notional != 0,
+        MatchingEngineError::InvalidInboundLimit
+    );
+
+    ctx.accounts.router_endpoint.inbound_limit = InboundLimit {
+        window_slots,
+        max_notional,
+    };
+
+    // Changing the limit starts a new window.
+    ctx.accounts.inbound_notional.inbound_limit = NotionalAccumulator {
+        window_start_slot: Clock::get()?.slot,
+        notional: 0,
+    };
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::{composite::*, error::MatchingEngineError, state::EndpointLimits};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRouterEndpointLimits<'info> {
+    admin: EndpointManager<'info>,
+
+    router_endpoint: ExistingMutRouterEndpoint<'info>,
+}
+
+pub fn set_router_endpoint_limits(
+    ctx: Context<SetRouterEndpointLimits>,
+    limits: EndpointLimits,
+) -> Result<()> {
+    if let (Some(min_amount), Some(max_amount)) = (limits.min_order_amount, limits.max_order_amount)
+    {
+        require!(
+            min_amount <= max_amount,
+            MatchingEngineError::InvalidEndpointLimits
+        );
+    }
+
+    ctx.accounts.router_endpoint.limits = limits;
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use crate::state::{EndpointInfo, InboundNotional, MessageProtocol, RouterEndpoint};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct MigrateRouterEndpoint<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: Seeds must be \["endpoint", chain.to_be_bytes()\]. This account is deserialized in
+    /// the instruction handler using either its legacy or current layout.
+    #[account(
+        mut,
+        owner = crate::id(),
+        seeds = [
+            RouterEndpoint::SEED_PREFIX,
+            &chain.to_be_bytes()
+        ],
+        bump,
+    )]
+    router_endpoint: UncheckedAccount<'info>,
+
+    /// Endpoints added before inbound notional amounts were tracked do not have this account.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + InboundNotional::INIT_SPACE,
+        seeds = [
+            InboundNotional::SEED_PREFIX,
+            &chain.to_be_bytes(),
+        ],
+        bump,
+    )]
+    inbound_notional: Box<Account<'info, InboundNotional>>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Router endpoint layout of the initially deployed program, which only supported USDC. Fields
+/// added since then are appended to the end of [RouterEndpoint], so accounts with this layout are
+/// reallocated and rewritten when migrating.
+#[derive(AnchorDeserialize)]
+struct LegacyRouterEndpoint {
+    bump: u8,
+    chain: u16,
+    address: [u8; 32],
+    mint_recipient: [u8; 32],
+    protocol: MessageProtocol,
+}
+
+pub fn migrate_router_endpoint(ctx: Context<MigrateRouterEndpoint>, _chain: u16) -> Result<()> {
+    ctx.accounts.inbound_notional.bump = ctx.bumps.inbound_notional;
+
+    let acc_info = ctx.accounts.router_endpoint.to_account_info();
+
+    let router_endpoint = {
+        let data = acc_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == RouterEndpoint::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        if data.len() >= 8 + RouterEndpoint::INIT_SPACE {
+            msg!("Nothing to migrate");
+            return Ok(());
+        }
+
+        let LegacyRouterEndpoint {
+            bump,
+            chain,
+            address,
+            mint_recipient,
+            protocol,
+        } = LegacyRouterEndpoint::deserialize(&mut &data[8..])?;
+
+        RouterEndpoint {
+            bump,
+            info: EndpointInfo {
+                chain,
+                address,
+                mint_recipient,
+                protocol,
+            },
+            pause: Default::default(),
+            inbound_limit: Default::default(),
+            disabled_slot: None,
+            previous_address: None,
+            limits: Default::default(),
+            mint: common::USDC_MINT,
+        }
+    };
+
+    common::realloc::realloc_account(
+        &acc_info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        8 + RouterEndpoint::INIT_SPACE,
+    )?;
+    router_endpoint.try_serialize(&mut &mut acc_info.try_borrow_mut_data()?[..])?;
+
+    msg!("Migrated router endpoint");
+
+    // Done.
+    Ok(())
+}
@@ -4,5 +4,20 @@ pub use add::*;
 mod disable;
 pub use disable::*;
 
+mod inbound_limit;
+pub use inbound_limit::*;
+
+mod limits;
+pub use limits::*;
+
+mod migrate;
+pub use migrate::*;
+
+mod pause;
+pub use pause::*;
+
+mod remove;
+pub use remove::*;
+
 mod update;
 pub use update::*;
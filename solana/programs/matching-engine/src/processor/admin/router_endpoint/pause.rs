@@ -0,0 +1,55 @@
+use crate::{composite::*, error::MatchingEngineError, state::PauseFlag};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct SetRouterEndpointPause<'info> {
+    admin: PauserMut<'info>,
+
+    router_endpoint: ExistingMutRouterEndpoint<'info>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SetRouterEndpointPauseArgs {
+    pub paused: bool,
+
+    /// Slot at which the pause is lifted automatically. This must be in the future and can only be
+    /// specified when pausing.
+    pub unpause_slot: Option<u64>,
+
+    pub reason: u16,
+}
+
+pub fn set_router_endpoint_pause(
+    ctx: Context<SetRouterEndpointPause>,
+    args: SetRouterEndpointPauseArgs,
+) -> Result<()> {
+    let SetRouterEndpointPauseArgs {
+        paused,
+        unpause_slot,
+        reason,
+    } = args;
+
+    if let Some(unpause_slot) = unpause_slot {
+        require!(
+            paused && unpause_slot > Clock::get()?.slot,
+            MatchingEngineError::InvalidUnpauseSlot
+        );
+    }
+
+    let flag = PauseFlag {
+        paused,
+        unpause_slot,
+        reason,
+    };
+    ctx.accounts.router_endpoint.pause = flag;
+
+    emit_cpi!(crate::events::RouterEndpointPauseUpdated {
+        chain: ctx.accounts.router_endpoint.chain,
+        flag,
+        set_by: ctx.accounts.admin.authority.key(),
+    });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{InboundNotional, MessageProtocol, RouterEndpoint},
+    utils,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::admin::utils::ownable::only_owner;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RemoveRouterEndpoint<'info> {
+    /// The owner receives the rent from the closed accounts.
+    #[account(
+        mut,
+        constraint = only_owner(
+            &custodian,
+            &owner,
+            error!(MatchingEngineError::OwnerOnly)
+        )?
+    )]
+    owner: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            RouterEndpoint::SEED_PREFIX,
+            &router_endpoint.chain.to_be_bytes()
+        ],
+        bump = router_endpoint.bump,
+        constraint = {
+            require!(
+                router_endpoint.protocol == MessageProtocol::None,
+                MatchingEngineError::RouterEndpointNotDisabled
+            );
+
+            let disabled_slot = router_endpoint
+                .disabled_slot
+                .ok_or_else(|| MatchingEngineError::RouterEndpointNotDisabled)?;
+            require!(
+                Clock::get()?.slot.saturating_sub(disabled_slot)
+                    >= custodian.router_endpoint_removal_delay,
+                MatchingEngineError::RouterEndpointRemovalDelayNotElapsed
+            );
+
+            true
+        }
+    )]
+    router_endpoint: Box<Account<'info, RouterEndpoint>>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            InboundNotional::SEED_PREFIX,
+            &router_endpoint.chain.to_be_bytes(),
+        ],
+        bump = inbound_notional.bump,
+    )]
+    inbound_notional: Box<Account<'info, InboundNotional>>,
+
+    /// This token account only exists for CCTP endpoints. If it exists, it must be empty in order
+    /// to be closed.
+    ///
+    /// CHECK: Seeds must be \["local-custody", router_endpoint.chain.to_be_bytes(), mint seed\].
+    #[account(
+        mut,
+        seeds = [
+            crate::LOCAL_CUSTODY_TOKEN_SEED_PREFIX,
+            &router_endpoint.chain.to_be_bytes(),
+            utils::local_custody_mint_seed(&router_endpoint.mint),
+        ],
+        bump,
+    )]
+    local_custody_token: UncheckedAccount<'info>,
+
+    router_endpoint_registry: RouterEndpointRegistryMut<'info>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn remove_router_endpoint(ctx: Context<RemoveRouterEndpoint>) -> Result<()> {
+    let router_endpoint = &ctx.accounts.router_endpoint;
+    let chain = router_endpoint.chain;
+
+    if !ctx.accounts.local_custody_token.data_is_empty() {
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.local_custody_token.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: router_endpoint.to_account_info(),
+            },
+            &[&[
+                RouterEndpoint::SEED_PREFIX,
+                &chain.to_be_bytes(),
+                &[router_endpoint.bump],
+            ]],
+        ))?;
+    }
+
+    ctx.accounts.router_endpoint_registry.remove(chain);
+
+    emit_cpi!(crate::events::RouterEndpointRemoved {
+        chain,
+        removed_by: ctx.accounts.owner.key(),
+    });
+
+    // Done.
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::{
     composite::*,
+    error::MatchingEngineError,
     utils::{self, admin::AddCctpRouterEndpointArgs},
 };
 use anchor_lang::prelude::*;
@@ -8,16 +9,16 @@ use common::wormhole_cctp_solana::cctp::token_messenger_minter_program::{
 };
 
 #[derive(Accounts)]
-#[instruction(args: AddCctpRouterEndpointArgs)]
+#[instruction(args: UpdateCctpRouterEndpointArgs)]
 pub struct UpdateCctpRouterEndpoint<'info> {
     admin: OwnerOnly<'info>,
 
     #[account(
         constraint = {
             require_eq!(
-                args.chain,
+                args.endpoint.chain,
                 router_endpoint.chain,
-                crate::error::MatchingEngineError::InvalidEndpoint,
+                MatchingEngineError::InvalidEndpoint,
             );
 
             true
@@ -25,12 +26,16 @@ pub struct UpdateCctpRouterEndpoint<'info> {
     )]
     router_endpoint: ExistingMutRouterEndpoint<'info>,
 
+    /// NOTE: Updating an endpoint also registers it, which covers endpoints added before the
+    /// registry existed.
+    router_endpoint_registry: RouterEndpointRegistryMut<'info>,
+
     /// CHECK: Seeds must be \["remote_token_messenger"\, remote_domain.to_string()] (CCTP Token
     /// Messenger Minter program).
     #[account(
         seeds = [
             RemoteTokenMessenger::SEED_PREFIX,
-            args.cctp_domain.to_string().as_ref()
+            args.endpoint.cctp_domain.to_string().as_ref()
         ],
         bump,
         seeds::program = token_messenger_minter_program::id(),
@@ -38,9 +43,39 @@ pub struct UpdateCctpRouterEndpoint<'info> {
     remote_token_messenger: Account<'info, RemoteTokenMessenger>,
 }
 
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateCctpRouterEndpointArgs {
+    pub endpoint: AddCctpRouterEndpointArgs,
+
+    /// If the address changes, the previous address is still accepted as a source of messages
+    /// until this slot, which must be in the future. Otherwise the previous address is no longer
+    /// accepted.
+    pub previous_address_expiry_slot: Option<u64>,
+}
+
 pub fn update_cctp_router_endpoint(
     ctx: Context<UpdateCctpRouterEndpoint>,
-    args: AddCctpRouterEndpointArgs,
+    args: UpdateCctpRouterEndpointArgs,
 ) -> Result<()> {
-    utils::admin::handle_add_cctp_router_endpoint(&mut ctx.accounts.router_endpoint, args, None)
+    let UpdateCctpRouterEndpointArgs {
+        endpoint,
+        previous_address_expiry_slot,
+    } = args;
+
+    utils::admin::handle_update_previous_address(
+        &mut ctx.accounts.router_endpoint,
+        endpoint.address,
+        previous_address_expiry_slot,
+    )?;
+
+    utils::admin::handle_add_cctp_router_endpoint(
+        &mut ctx.accounts.router_endpoint,
+        endpoint,
+        None,
+    )?;
+
+    ctx.accounts.router_endpoint_registry.upsert(
+        ctx.accounts.router_endpoint.chain,
+        ctx.accounts.router_endpoint.protocol,
+    )
 }
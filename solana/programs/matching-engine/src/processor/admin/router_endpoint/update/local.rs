@@ -18,6 +18,10 @@ pub struct UpdateLocalRouterEndpoint<'info> {
     )]
     router_endpoint: ExistingMutRouterEndpoint<'info>,
 
+    /// NOTE: Updating an endpoint also registers it, which covers endpoints added before the
+    /// registry existed.
+    router_endpoint_registry: RouterEndpointRegistryMut<'info>,
+
     local: LocalTokenRouter<'info>,
 }
 
@@ -28,5 +32,10 @@ pub fn update_local_router_endpoint(ctx: Context<UpdateLocalRouterEndpoint>) ->
         &ctx.accounts.local.token_router_emitter,
         &ctx.accounts.local.token_router_mint_recipient,
         None,
+    )?;
+
+    ctx.accounts.router_endpoint_registry.upsert(
+        ctx.accounts.router_endpoint.chain,
+        ctx.accounts.router_endpoint.protocol,
     )
 }
@@ -3,3 +3,9 @@ pub use cctp::*;
 
 mod local;
 pub use local::*;
+
+mod ntt;
+pub use ntt::*;
+
+mod token_bridge;
+pub use token_bridge::*;
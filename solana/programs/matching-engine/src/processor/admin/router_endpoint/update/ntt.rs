@@ -0,0 +1,64 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    utils::{self, admin::AddNttRouterEndpointArgs},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(args: UpdateNttRouterEndpointArgs)]
+pub struct UpdateNttRouterEndpoint<'info> {
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        constraint = {
+            require_eq!(
+                args.endpoint.chain,
+                router_endpoint.chain,
+                MatchingEngineError::InvalidEndpoint,
+            );
+
+            true
+        }
+    )]
+    router_endpoint: ExistingMutRouterEndpoint<'info>,
+
+    router_endpoint_registry: RouterEndpointRegistryMut<'info>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateNttRouterEndpointArgs {
+    pub endpoint: AddNttRouterEndpointArgs,
+
+    /// If the address changes, the previous address is still accepted as a source of messages
+    /// until this slot, which must be in the future. Otherwise the previous address is no longer
+    /// accepted.
+    pub previous_address_expiry_slot: Option<u64>,
+}
+
+pub fn update_ntt_router_endpoint(
+    ctx: Context<UpdateNttRouterEndpoint>,
+    args: UpdateNttRouterEndpointArgs,
+) -> Result<()> {
+    let UpdateNttRouterEndpointArgs {
+        endpoint,
+        previous_address_expiry_slot,
+    } = args;
+
+    utils::admin::handle_update_previous_address(
+        &mut ctx.accounts.router_endpoint,
+        endpoint.address,
+        previous_address_expiry_slot,
+    )?;
+
+    utils::admin::handle_add_ntt_router_endpoint(
+        &mut ctx.accounts.router_endpoint,
+        endpoint,
+        None,
+    )?;
+
+    ctx.accounts.router_endpoint_registry.upsert(
+        ctx.accounts.router_endpoint.chain,
+        ctx.accounts.router_endpoint.protocol,
+    )
+}
@@ -0,0 +1,56 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{PauseFlag, PauseTarget},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct SetStagePause<'info> {
+    admin: PauserMut<'info>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SetStagePauseArgs {
+    pub target: PauseTarget,
+    pub paused: bool,
+
+    /// Slot at which the pause is lifted automatically. This must be in the future and can only be
+    /// specified when pausing.
+    pub unpause_slot: Option<u64>,
+
+    pub reason: u16,
+}
+
+pub fn set_stage_pause(ctx: Context<SetStagePause>, args: SetStagePauseArgs) -> Result<()> {
+    let SetStagePauseArgs {
+        target,
+        paused,
+        unpause_slot,
+        reason,
+    } = args;
+
+    if let Some(unpause_slot) = unpause_slot {
+        require!(
+            paused && unpause_slot > Clock::get()?.slot,
+            MatchingEngineError::InvalidUnpauseSlot
+        );
+    }
+
+    let flag = PauseFlag {
+        paused,
+        unpause_slot,
+        reason,
+    };
+    *ctx.accounts.admin.custodian.pause_flags.get_mut(target) = flag;
+
+    emit_cpi!(crate::events::PauseUpdated {
+        target,
+        flag,
+        set_by: ctx.accounts.admin.authority.key(),
+    });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::{composite::*, error::MatchingEngineError, state::SupportedMint};
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token};
+
+#[derive(Accounts)]
+pub struct AddSupportedMint<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    /// USDC is always supported, so it cannot be added.
+    #[account(
+        constraint = {
+            mint.key() != common::USDC_MINT
+        } @ MatchingEngineError::InvalidMint,
+    )]
+    mint: Box<Account<'info, token::Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SupportedMint::INIT_SPACE,
+        seeds = [
+            SupportedMint::SEED_PREFIX,
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    supported_mint: Account<'info, SupportedMint>,
+
+    /// Token account that collects fees for orders of this mint settled without an auction.
+    #[account(token::mint = mint)]
+    fee_recipient_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Custodian's token account for this mint, which is encoded as the mint recipient in CCTP
+    /// messages that transfer this mint.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = admin.custodian,
+    )]
+    cctp_mint_recipient: Box<Account<'info, token::TokenAccount>>,
+
+    token_program: Program<'info, token::Token>,
+    associated_token_program: Program<'info, associated_token::AssociatedToken>,
+    system_program: Program<'info, System>,
+}
+
+pub fn add_supported_mint(ctx: Context<AddSupportedMint>) -> Result<()> {
+    ctx.accounts.supported_mint.set_inner(SupportedMint {
+        bump: ctx.bumps.supported_mint,
+        mint: ctx.accounts.mint.key(),
+        fee_recipient_token: ctx.accounts.fee_recipient_token.key(),
+    });
+
+    // Done.
+    Ok(())
+}
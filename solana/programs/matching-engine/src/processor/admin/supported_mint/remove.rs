@@ -0,0 +1,38 @@
+use crate::{composite::*, error::MatchingEngineError, state::SupportedMint};
+use anchor_lang::prelude::*;
+use common::admin::utils::ownable::only_owner;
+
+/// Removing a supported mint prevents new auctions of this mint from starting. Auctions that have
+/// already started in this mint can still be settled, but orders of this mint without an auction
+/// cannot be prepared or settled until the mint is added again.
+#[derive(Accounts)]
+pub struct RemoveSupportedMint<'info> {
+    /// The owner receives the rent from the closed account.
+    #[account(
+        mut,
+        constraint = only_owner(
+            &custodian,
+            &owner,
+            error!(MatchingEngineError::OwnerOnly)
+        )?
+    )]
+    owner: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            SupportedMint::SEED_PREFIX,
+            supported_mint.mint.as_ref(),
+        ],
+        bump = supported_mint.bump,
+    )]
+    supported_mint: Account<'info, SupportedMint>,
+}
+
+pub fn remove_supported_mint(_ctx: Context<RemoveSupportedMint>) -> Result<()> {
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::{composite::*, error::MatchingEngineError, state::CircuitBreakerConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateCircuitBreaker<'info> {
+    admin: OwnerOnlyMut<'info>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct UpdateCircuitBreakerArgs {
+    /// Number of slots in each window. Zero disables the circuit breaker.
+    pub window_slots: u64,
+
+    pub global_threshold: u64,
+
+    pub chain_threshold: u64,
+}
+
+pub fn update_circuit_breaker(
+    ctx: Context<UpdateCircuitBreaker>,
+    args: UpdateCircuitBreakerArgs,
+) -> Result<()> {
+    let UpdateCircuitBreakerArgs {
+        window_slots,
+        global_threshold,
+        chain_threshold,
+    } = args;
+
+    require!(
+        window_slots == 0 || (global_threshold != 0 && chain_threshold != 0),
+        MatchingEngineError::InvalidCircuitBreakerThreshold
+    );
+
+    // NOTE: Notional amounts accumulated within the current windows are preserved.
+    ctx.accounts.admin.custodian.circuit_breaker = CircuitBreakerConfig {
+        window_slots,
+        global_threshold,
+        chain_threshold,
+    };
+
+    // Done.
+    Ok(())
+}
@@ -4,7 +4,7 @@ use anchor_spl::token;
 
 #[derive(Accounts)]
 pub struct UpdateFeeRecipient<'info> {
-    admin: AdminMut<'info>,
+    admin: FeeManagerMut<'info>,
 
     #[account(
         associated_token::mint = common::USDC_MINT,
@@ -0,0 +1,17 @@
+use crate::composite::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMaxGasDropoff<'info> {
+    admin: FeeManagerMut<'info>,
+}
+
+pub fn update_max_gas_dropoff(
+    ctx: Context<UpdateMaxGasDropoff>,
+    max_gas_dropoff: u64,
+) -> Result<()> {
+    ctx.accounts.admin.custodian.max_gas_dropoff = max_gas_dropoff;
+
+    // Done.
+    Ok(())
+}
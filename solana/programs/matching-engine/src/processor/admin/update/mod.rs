@@ -1,8 +1,17 @@
 mod auction_parameters;
 pub use auction_parameters::*;
 
+mod circuit_breaker;
+pub use circuit_breaker::*;
+
 mod fee_recipient_token;
 pub use fee_recipient_token::*;
 
+mod max_gas_dropoff;
+pub use max_gas_dropoff::*;
+
 mod owner_assistant;
 pub use owner_assistant::*;
+
+mod router_endpoint_removal_delay;
+pub use router_endpoint_removal_delay::*;
@@ -1,6 +1,6 @@
 use crate::{composite::*, error::MatchingEngineError};
 use anchor_lang::prelude::*;
-use common::admin::utils::assistant;
+use common::admin::utils::pending_owner_assistant;
 
 #[derive(Accounts)]
 pub struct UpdateOwnerAssistant<'info> {
@@ -18,9 +18,10 @@ pub struct UpdateOwnerAssistant<'info> {
 }
 
 pub fn update_owner_assistant(ctx: Context<UpdateOwnerAssistant>) -> Result<()> {
-    assistant::transfer_owner_assistant(
+    // The new assistant must accept the role before replacing the current assistant.
+    pending_owner_assistant::transfer_owner_assistant(
         &mut ctx.accounts.admin.custodian,
-        &ctx.accounts.new_owner_assistant,
+        &ctx.accounts.new_owner_assistant.key(),
     );
 
     // Done.
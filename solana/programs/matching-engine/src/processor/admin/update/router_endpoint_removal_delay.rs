@@ -0,0 +1,17 @@
+use crate::composite::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateRouterEndpointRemovalDelay<'info> {
+    admin: OwnerOnlyMut<'info>,
+}
+
+pub fn update_router_endpoint_removal_delay(
+    ctx: Context<UpdateRouterEndpointRemovalDelay>,
+    delay: u64,
+) -> Result<()> {
+    ctx.accounts.admin.custodian.router_endpoint_removal_delay = delay;
+
+    // Done.
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{Custodian, MessageProtocol},
+    state::{Custodian, MessageProtocol, PauseTarget},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -36,6 +36,11 @@ pub struct ExecuteFastOrderCctp<'info> {
     )]
     cctp_message: UncheckedAccount<'info>,
 
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::OrderExecution, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
     custodian: CheckedCustodian<'info>,
 
     execute_order: ExecuteOrder<'info>,
@@ -1,9 +1,10 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{Custodian, FastFill, ReservedFastFillSequence},
+    state::{Auction, Custodian, FastFill, PauseTarget, ReservedFastFillSequence},
+    utils,
 };
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program};
 use anchor_spl::token;
 use common::messages::raw::LiquidityLayerMessage;
 
@@ -13,6 +14,18 @@ pub struct ExecuteFastOrderLocal<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
+    #[account(
+        constraint = {
+            let current_slot = Clock::get()?.slot;
+            require!(
+                !custodian.is_paused(PauseTarget::OrderExecution, current_slot)
+                    && !custodian.is_paused(PauseTarget::FastFillCreation, current_slot),
+                MatchingEngineError::Paused
+            );
+
+            true
+        }
+    )]
     custodian: CheckedCustodian<'info>,
 
     execute_order: ExecuteOrder<'info>,
@@ -80,23 +93,138 @@ pub struct ExecuteFastOrderLocal<'info> {
         seeds = [
             crate::LOCAL_CUSTODY_TOKEN_SEED_PREFIX,
             &execute_order.fast_vaa.load_unchecked().emitter_chain().to_be_bytes(),
+            utils::local_custody_mint_seed(&execute_order.active_auction.custody_token.mint),
         ],
         bump,
     )]
     local_custody_token: Box<Account<'info, token::TokenAccount>>,
 
+    /// Only required if the fast fill is delivered in a destination asset. This token account will
+    /// be owned by the fast fill, which is redeemed from it.
+    #[account(
+        mut,
+        seeds = [
+            crate::AUCTION_DESTINATION_CUSTODY_TOKEN_SEED_PREFIX,
+            execute_order.active_auction.key().as_ref(),
+        ],
+        bump,
+    )]
+    destination_custody_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
+    /// Redeemer encoded in the fast market order, which receives the gas drop-off from the payer.
+    /// This account is only required if the order requests a gas drop-off.
+    ///
+    /// CHECK: Mutable. Must equal the fast market order's redeemer.
+    #[account(
+        mut,
+        address = order_redeemer(&execute_order),
+    )]
+    gas_dropoff_recipient: Option<UncheckedAccount<'info>>,
+
+    /// Token account of the order's redeemer, which receives the user reward portion of a penalty
+    /// in the auctioned asset. This account is only required if the fast fill is delivered in a
+    /// destination asset and the execution is penalized with a user reward.
+    #[account(
+        mut,
+        token::mint = execute_order.active_auction.custody_token.mint,
+        token::authority = order_redeemer(&execute_order),
+    )]
+    user_reward_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
     system_program: Program<'info, System>,
     token_program: Program<'info, token::Token>,
 
     sysvars: RequiredSysvars<'info>,
 }
 
+fn order_redeemer(execute_order: &ExecuteOrder) -> Pubkey {
+    let vaa = execute_order.fast_vaa.load_unchecked();
+
+    // We can unwrap and convert to FastMarketOrder unchecked because we validate the VAA hash
+    // equals the one encoded in the auction account.
+    LiquidityLayerMessage::try_from(vaa.payload())
+        .unwrap()
+        .to_fast_market_order_unchecked()
+        .redeemer()
+        .into()
+}
+
+fn order_relayer_fee(execute_order: &ExecuteOrder) -> u64 {
+    let vaa = execute_order.fast_vaa.load_unchecked();
+
+    LiquidityLayerMessage::try_from(vaa.payload())
+        .unwrap()
+        .to_fast_market_order_unchecked()
+        .relayer_fee()
+}
+
+/// Transfers the gas drop-off requested by the fast market order (if any) from the payer to the
+/// order's redeemer. Returns the gas drop-off fee, which compensates the payer via the executor
+/// token account.
+fn pay_gas_dropoff(accounts: &ExecuteFastOrderLocal) -> Result<u64> {
+    let (gas_dropoff, gas_dropoff_fee) = {
+        let vaa = accounts.execute_order.fast_vaa.load_unchecked();
+        let order = LiquidityLayerMessage::try_from(vaa.payload())
+            .unwrap()
+            .to_fast_market_order_unchecked();
+
+        (order.gas_dropoff(), order.gas_dropoff_fee())
+    };
+
+    if gas_dropoff == 0 {
+        return Ok(Default::default());
+    }
+
+    // The gas drop-off fee is transferred to the executor token account, so it must belong to the
+    // payer who funds the gas drop-off.
+    if gas_dropoff_fee > 0 {
+        require_keys_eq!(
+            accounts.execute_order.executor_token.owner,
+            accounts.payer.key(),
+            MatchingEngineError::InvalidGasDropoffFeeToken
+        );
+    }
+
+    let gas_dropoff_recipient = accounts
+        .gas_dropoff_recipient
+        .as_ref()
+        .ok_or_else(|| MatchingEngineError::GasDropoffRecipientRequired)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: accounts.payer.to_account_info(),
+                to: gas_dropoff_recipient.to_account_info(),
+            },
+        ),
+        gas_dropoff,
+    )?;
+
+    Ok(gas_dropoff_fee)
+}
+
 pub fn execute_fast_order_local(ctx: Context<ExecuteFastOrderLocal>) -> Result<()> {
+    match ctx
+        .accounts
+        .execute_order
+        .active_auction
+        .info
+        .as_ref()
+        .unwrap()
+        .destination_asset_info
+    {
+        Some(info) => handle_execute_fast_order_local_destination_asset(ctx, info.mint),
+        None => handle_execute_fast_order_local(ctx),
+    }
+}
+
+fn handle_execute_fast_order_local(ctx: Context<ExecuteFastOrderLocal>) -> Result<()> {
     let custodian = &ctx.accounts.custodian;
     let token_program = &ctx.accounts.token_program;
 
     let super::PreparedOrderExecution {
-        user_amount: amount,
+        user_amount,
         fill,
         order_executed_event,
     } = super::handle_execute_fast_order(
@@ -109,12 +237,18 @@ pub fn execute_fast_order_local(ctx: Context<ExecuteFastOrderLocal>) -> Result<(
     // being penalized so they can collect the base fee at settlement.
     emit_cpi!(order_executed_event);
 
+    // The payer is compensated for the gas drop-off from the user's amount.
+    let gas_dropoff_fee = pay_gas_dropoff(ctx.accounts)?.min(user_amount);
+    let amount = user_amount.saturating_sub(gas_dropoff_fee);
+
     let fast_fill = FastFill::new(
         fill,
         ctx.accounts.reserved_sequence.fast_fill_seeds.sequence,
         ctx.bumps.fast_fill,
         ctx.accounts.payer.key(),
         amount,
+        ctx.accounts.local_custody_token.mint,
+        order_relayer_fee(&ctx.accounts.execute_order),
     );
 
     // Emit the fast fill.
@@ -122,11 +256,28 @@ pub fn execute_fast_order_local(ctx: Context<ExecuteFastOrderLocal>) -> Result<(
         seeds: fast_fill.seeds,
         info: fast_fill.info,
         auction: ctx.accounts.execute_order.active_auction.key().into(),
+        mint: fast_fill.mint,
+        relayer_fee: fast_fill.relayer_fee,
     });
     ctx.accounts.fast_fill.set_inner(fast_fill);
 
     let auction_custody_token = &ctx.accounts.execute_order.active_auction.custody_token;
 
+    if gas_dropoff_fee > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: auction_custody_token.to_account_info(),
+                    to: ctx.accounts.execute_order.executor_token.to_account_info(),
+                    authority: custodian.to_account_info(),
+                },
+                &[Custodian::SIGNER_SEEDS],
+            ),
+            gas_dropoff_fee,
+        )?;
+    }
+
     // Transfer funds to the local custody account.
     token::transfer(
         CpiContext::new_with_signer(
@@ -162,3 +313,102 @@ pub fn execute_fast_order_local(ctx: Context<ExecuteFastOrderLocal>) -> Result<(
         .reserved_sequence
         .close(ctx.accounts.reserve_beneficiary.to_account_info())
 }
+
+fn handle_execute_fast_order_local_destination_asset(
+    ctx: Context<ExecuteFastOrderLocal>,
+    mint: Pubkey,
+) -> Result<()> {
+    let token_program = &ctx.accounts.token_program;
+
+    // The payer is compensated for the gas drop-off from the auction custody token account, which
+    // the solver who bid the amount out accounts for in its bid.
+    let gas_dropoff_fee = pay_gas_dropoff(ctx.accounts)?;
+
+    let super::PreparedOrderExecution {
+        user_amount: amount,
+        fill,
+        order_executed_event,
+    } = super::handle_execute_fast_order_destination_asset(
+        &mut ctx.accounts.execute_order,
+        ctx.accounts.user_reward_token.as_deref(),
+        gas_dropoff_fee,
+        &ctx.accounts.token_program,
+    )?;
+
+    // Emit the order executed event, which liquidators can listen to if this execution ended up
+    // being penalized so they can collect the base fee at settlement.
+    emit_cpi!(order_executed_event);
+
+    // The fast fill owns the token account holding the amount out.
+    let fast_fill = FastFill {
+        owns_custody_token: true,
+        ..FastFill::new(
+            fill,
+            ctx.accounts.reserved_sequence.fast_fill_seeds.sequence,
+            ctx.bumps.fast_fill,
+            ctx.accounts.payer.key(),
+            amount,
+            mint,
+            // The relayer fee is denominated in USDC, so a fill in a destination asset cannot be
+            // automatically delivered.
+            Default::default(),
+        )
+    };
+
+    // Emit the fast fill.
+    emit_cpi!(crate::events::LocalFastOrderFilled {
+        seeds: fast_fill.seeds,
+        info: fast_fill.info,
+        auction: ctx.accounts.execute_order.active_auction.key().into(),
+        mint: fast_fill.mint,
+        relayer_fee: fast_fill.relayer_fee,
+    });
+    ctx.accounts.fast_fill.set_inner(fast_fill);
+
+    let active_auction = &ctx.accounts.execute_order.active_auction;
+    let auction_signer_seeds = &[
+        Auction::SEED_PREFIX,
+        active_auction.vaa_hash.as_ref(),
+        &[active_auction.bump],
+    ];
+
+    // The fast fill takes over the destination custody token account, which holds the amount out.
+    let destination_custody_token = ctx
+        .accounts
+        .destination_custody_token
+        .as_ref()
+        .ok_or_else(|| MatchingEngineError::InvalidDestinationAsset)?;
+    token::set_authority(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::SetAuthority {
+                current_authority: active_auction.to_account_info(),
+                account_or_mint: destination_custody_token.to_account_info(),
+            },
+            &[auction_signer_seeds],
+        ),
+        token::spl_token::instruction::AuthorityType::AccountOwner,
+        ctx.accounts.fast_fill.key().into(),
+    )?;
+
+    // Close the custody token account since it is no longer needed.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: active_auction.custody_token.to_account_info(),
+            destination: ctx
+                .accounts
+                .execute_order
+                .initial_participant
+                .to_account_info(),
+            authority: active_auction.to_account_info(),
+        },
+        &[auction_signer_seeds],
+    ))?;
+
+    // Finally close the reserved sequence account and give the lamports to the best offer
+    // participant.
+    ctx.accounts
+        .reserved_sequence
+        .close(ctx.accounts.reserve_beneficiary.to_account_info())
+}
@@ -4,6 +4,12 @@ pub use cctp::*;
 mod local;
 pub use local::*;
 
+mod ntt;
+pub use ntt::*;
+
+mod token_bridge;
+pub use token_bridge::*;
+
 use crate::{
     composite::*,
     error::MatchingEngineError,
@@ -101,7 +107,7 @@ fn handle_execute_fast_order<'info>(
         // init auction fee. The executor will get these funds instead.
         //
         // We check that this is a legitimate token account.
-        if utils::checked_deserialize_token_account(initial_offer_token, &common::USDC_MINT)
+        if utils::checked_deserialize_token_account(initial_offer_token, &custody_token.mint)
             .is_some()
         {
             if best_offer_token.key() != initial_offer_token.key() {
@@ -155,7 +161,7 @@ fn handle_execute_fast_order<'info>(
             // Otherwise, send the deposit and fee to the best offer token. If the best offer token
             // doesn't exist at this point (which would be unusual), we will reserve these funds
             // for the executor token.
-            if utils::checked_deserialize_token_account(best_offer_token, &common::USDC_MINT)
+            if utils::checked_deserialize_token_account(best_offer_token, &custody_token.mint)
                 .is_some()
             {
                 token::transfer(
@@ -240,3 +246,208 @@ fn handle_execute_fast_order<'info>(
         order_executed_event,
     })
 }
+
+/// Executes an order whose fast fill is delivered in a destination asset. The auction custody token
+/// account only holds the security deposit, which pays the init auction fee and any penalty. The
+/// destination custody token account holds the amount out, which is left for the caller to
+/// deliver.
+///
+/// NOTE: Because the fast fill is denominated in the destination asset, the user reward portion of
+/// a penalty is transferred to the user reward token account instead of being added to the fill.
+fn handle_execute_fast_order_destination_asset<'info>(
+    execute_order: &mut ExecuteOrder<'info>,
+    user_reward_token: Option<&Account<'info, token::TokenAccount>>,
+    gas_dropoff_fee: u64,
+    token_program: &Program<'info, token::Token>,
+) -> Result<PreparedOrderExecution> {
+    let auction = &mut execute_order.active_auction.auction;
+    let fast_vaa = &execute_order.fast_vaa;
+    let custody_token = &execute_order.active_auction.custody_token;
+    let config = &execute_order.active_auction.config;
+    let executor_token = &execute_order.executor_token;
+    let best_offer_token = &execute_order.active_auction.best_offer_token;
+    let initial_offer_token = &execute_order.initial_offer_token;
+
+    let vaa = fast_vaa.load_unchecked();
+    let order = LiquidityLayerMessage::try_from(vaa.payload())
+        .unwrap()
+        .to_fast_market_order_unchecked();
+
+    let auction_info = auction.info.as_ref().unwrap();
+    let amount_out = auction_info
+        .destination_asset_info
+        .as_ref()
+        .ok_or_else(|| MatchingEngineError::InvalidDestinationAsset)?
+        .amount_out;
+    let current_slot = Clock::get().unwrap().slot;
+
+    // Orders delivered in a destination asset are only executed locally, so the grace period is
+    // extended like any other locally executed order.
+    let DepositPenalty {
+        penalty,
+        user_reward,
+    } = utils::auction::compute_deposit_penalty(
+        config,
+        auction_info,
+        current_slot,
+        crate::EXECUTE_FAST_ORDER_LOCAL_ADDITIONAL_GRACE_PERIOD.into(),
+    );
+
+    let auction_signer_seeds = &[
+        Auction::SEED_PREFIX,
+        auction.vaa_hash.as_ref(),
+        &[auction.bump],
+    ];
+
+    // Keep track of the remaining amount in the custody token account. Whatever remains will go to
+    // the executor.
+    let mut remaining_custodied_amount = custody_token.amount;
+
+    // The user reward is paid from the security deposit.
+    if user_reward > 0 {
+        let user_reward_token =
+            user_reward_token.ok_or_else(|| MatchingEngineError::UserRewardTokenRequired)?;
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: custody_token.to_account_info(),
+                    to: user_reward_token.to_account_info(),
+                    authority: auction.to_account_info(),
+                },
+                &[auction_signer_seeds],
+            ),
+            user_reward,
+        )?;
+
+        remaining_custodied_amount = remaining_custodied_amount.saturating_sub(user_reward);
+    }
+
+    // Compensate the executor for paying the gas drop-off. The solver who bid the amount out
+    // accounts for this fee in its bid.
+    let gas_dropoff_fee = gas_dropoff_fee.min(remaining_custodied_amount);
+    if gas_dropoff_fee > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: custody_token.to_account_info(),
+                    to: executor_token.to_account_info(),
+                    authority: auction.to_account_info(),
+                },
+                &[auction_signer_seeds],
+            ),
+            gas_dropoff_fee,
+        )?;
+
+        remaining_custodied_amount = remaining_custodied_amount.saturating_sub(gas_dropoff_fee);
+    }
+
+    let penalized = penalty > 0;
+
+    // Pay the auction initiator their fee from the security deposit if the initial offer token
+    // account still exists and is not the best offer token account. Otherwise the fee is returned
+    // with the security deposit.
+    if best_offer_token.key() != initial_offer_token.key()
+        && utils::checked_deserialize_token_account(initial_offer_token, &custody_token.mint)
+            .is_some()
+    {
+        let init_auction_fee = order.init_auction_fee().min(remaining_custodied_amount);
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: custody_token.to_account_info(),
+                    to: initial_offer_token.to_account_info(),
+                    authority: auction.to_account_info(),
+                },
+                &[auction_signer_seeds],
+            ),
+            init_auction_fee,
+        )?;
+
+        remaining_custodied_amount = remaining_custodied_amount.saturating_sub(init_auction_fee);
+    }
+
+    if best_offer_token.key() == executor_token.key() {
+        // If the best offer token is equal to the executor token, just send whatever remains in
+        // the custody token account.
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: custody_token.to_account_info(),
+                    to: best_offer_token.to_account_info(),
+                    authority: auction.to_account_info(),
+                },
+                &[auction_signer_seeds],
+            ),
+            remaining_custodied_amount,
+        )?;
+    } else {
+        // Otherwise, return the security deposit less the penalty to the best offer token. If the
+        // best offer token doesn't exist at this point, these funds go to the executor token.
+        if utils::checked_deserialize_token_account(best_offer_token, &custody_token.mint).is_some()
+        {
+            let deposit = remaining_custodied_amount.saturating_sub(penalty);
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: custody_token.to_account_info(),
+                        to: best_offer_token.to_account_info(),
+                        authority: auction.to_account_info(),
+                    },
+                    &[auction_signer_seeds],
+                ),
+                deposit,
+            )?;
+
+            remaining_custodied_amount = remaining_custodied_amount.saturating_sub(deposit);
+        }
+
+        // And pay the executor whatever remains in the auction custody token account.
+        if remaining_custodied_amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: custody_token.to_account_info(),
+                        to: executor_token.to_account_info(),
+                        authority: auction.to_account_info(),
+                    },
+                    &[auction_signer_seeds],
+                ),
+                remaining_custodied_amount,
+            )?;
+        }
+    }
+
+    let order_executed_event = OrderExecuted {
+        fast_vaa_hash: auction.vaa_hash,
+        vaa: fast_vaa.key(),
+        source_chain: auction_info.source_chain,
+        target_protocol: auction.target_protocol,
+        penalized,
+    };
+
+    // Set the auction status to completed.
+    auction.status = AuctionStatus::Completed {
+        slot: current_slot,
+        execute_penalty: if penalized { penalty.into() } else { None },
+    };
+
+    Ok(PreparedOrderExecution {
+        user_amount: amount_out,
+        fill: Fill {
+            source_chain: vaa.emitter_chain(),
+            order_sender: order.sender(),
+            redeemer: order.redeemer(),
+            redeemer_message: order
+                .message_to_vec()
+                .try_into()
+                .map_err(|_| MatchingEngineError::RedeemerMessageTooLarge)?,
+        },
+        order_executed_event,
+    })
+}
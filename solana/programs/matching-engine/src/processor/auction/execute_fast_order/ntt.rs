@@ -0,0 +1,205 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Custodian, MessageProtocol, PauseTarget},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{
+    ntt,
+    wormhole_cctp_solana::wormhole::core_bridge_program::{self, Commitment},
+    wormhole_io::TypePrefixedPayload,
+};
+
+/// Accounts required for [execute_fast_order_ntt].
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ExecuteFastOrderNtt<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["core-msg", auction\].
+    #[account(
+        mut,
+        seeds = [
+            common::CORE_MESSAGE_SEED_PREFIX,
+            execute_order.active_auction.key().as_ref(),
+        ],
+        bump,
+    )]
+    core_message: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["ntt-outbox", auction\].
+    #[account(
+        mut,
+        seeds = [
+            common::NTT_OUTBOX_ITEM_SEED_PREFIX,
+            execute_order.active_auction.key().as_ref(),
+        ],
+        bump,
+    )]
+    ntt_outbox_item: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::OrderExecution, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
+    custodian: CheckedCustodian<'info>,
+
+    execute_order: ExecuteOrder<'info>,
+
+    #[account(
+        constraint = {
+            require_eq!(
+                to_router_endpoint.protocol,
+                execute_order.active_auction.target_protocol,
+                MatchingEngineError::InvalidEndpoint
+            );
+
+            true
+        }
+    )]
+    to_router_endpoint: LiveRouterEndpoint<'info>,
+
+    wormhole: WormholePublishMessage<'info>,
+
+    #[account(
+        constraint = {
+            ntt.mint.key() == execute_order.active_auction.custody_token.mint
+        } @ MatchingEngineError::MintMismatch,
+    )]
+    ntt: NttTransfer<'info>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, token::Token>,
+
+    sysvars: RequiredSysvars<'info>,
+}
+
+pub fn execute_fast_order_ntt(ctx: Context<ExecuteFastOrderNtt>) -> Result<()> {
+    match ctx.accounts.to_router_endpoint.protocol {
+        MessageProtocol::Ntt {
+            manager,
+            transceiver,
+        } => handle_execute_fast_order_ntt(ctx, manager, transceiver),
+        _ => err!(MatchingEngineError::InvalidNttEndpoint),
+    }
+}
+
+fn handle_execute_fast_order_ntt(
+    ctx: Context<ExecuteFastOrderNtt>,
+    manager: Pubkey,
+    transceiver: Pubkey,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.ntt.ntt_manager_program.key(),
+        manager,
+        MatchingEngineError::InvalidNttEndpoint
+    );
+    require_keys_eq!(
+        ctx.accounts.ntt.transceiver.key(),
+        transceiver,
+        MatchingEngineError::InvalidNttEndpoint
+    );
+
+    let custodian = &ctx.accounts.custodian;
+    let token_program = &ctx.accounts.token_program;
+
+    let super::PreparedOrderExecution {
+        user_amount: amount,
+        fill,
+        order_executed_event,
+    } = super::handle_execute_fast_order(
+        &mut ctx.accounts.execute_order,
+        &ctx.accounts.custodian,
+        &ctx.accounts.token_program,
+    )?;
+
+    let active_auction = &ctx.accounts.execute_order.active_auction;
+    let auction_custody_token = &active_auction.custody_token;
+    let payer = &ctx.accounts.payer;
+    let system_program = &ctx.accounts.system_program;
+
+    // Send the tokens to the destination chain via the NTT manager.
+    ntt::transfer_lock(
+        CpiContext::new_with_signer(
+            ctx.accounts.ntt.ntt_manager_program.to_account_info(),
+            ntt::TransferLock {
+                payer: payer.to_account_info(),
+                sender: custodian.to_account_info(),
+                from: auction_custody_token.to_account_info(),
+                mint: ctx.accounts.ntt.mint.to_account_info(),
+                outbox_item: ctx.accounts.ntt_outbox_item.to_account_info(),
+                custody: ctx.accounts.ntt.custody_token.to_account_info(),
+                transceiver: ctx.accounts.ntt.transceiver.to_account_info(),
+                token_program: token_program.to_account_info(),
+                system_program: system_program.to_account_info(),
+            },
+            &[
+                Custodian::SIGNER_SEEDS,
+                &[
+                    common::NTT_OUTBOX_ITEM_SEED_PREFIX,
+                    active_auction.key().as_ref(),
+                    &[ctx.bumps.ntt_outbox_item],
+                ],
+            ],
+        ),
+        ntt::TransferArgs {
+            amount,
+            recipient_chain: ctx.accounts.to_router_endpoint.chain,
+            recipient_address: ctx.accounts.to_router_endpoint.mint_recipient,
+            should_queue: false,
+        },
+    )?;
+
+    // Publish the fill, which the destination router reconciles with the NTT transfer.
+    core_bridge_program::cpi::post_message(
+        CpiContext::new_with_signer(
+            ctx.accounts.wormhole.core_bridge_program.to_account_info(),
+            core_bridge_program::cpi::PostMessage {
+                payer: payer.to_account_info(),
+                message: ctx.accounts.core_message.to_account_info(),
+                emitter: custodian.to_account_info(),
+                config: ctx.accounts.wormhole.config.to_account_info(),
+                emitter_sequence: ctx.accounts.wormhole.emitter_sequence.to_account_info(),
+                fee_collector: ctx.accounts.wormhole.fee_collector.to_account_info(),
+                system_program: system_program.to_account_info(),
+                clock: ctx.accounts.sysvars.clock.to_account_info(),
+                rent: ctx.accounts.sysvars.rent.to_account_info(),
+            },
+            &[
+                Custodian::SIGNER_SEEDS,
+                &[
+                    common::CORE_MESSAGE_SEED_PREFIX,
+                    active_auction.key().as_ref(),
+                    &[ctx.bumps.core_message],
+                ],
+            ],
+        ),
+        core_bridge_program::cpi::PostMessageArgs {
+            nonce: common::WORMHOLE_MESSAGE_NONCE,
+            payload: fill.to_vec(),
+            commitment: Commitment::Finalized,
+        },
+    )?;
+
+    // Emit the order executed event, which liquidators can listen to if this execution ended up
+    // being penalized so they can collect the base fee at settlement.
+    emit_cpi!(order_executed_event);
+
+    // Finally close the account since it is no longer needed.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: auction_custody_token.to_account_info(),
+            destination: ctx
+                .accounts
+                .execute_order
+                .initial_participant
+                .to_account_info(),
+            authority: custodian.to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))
+}
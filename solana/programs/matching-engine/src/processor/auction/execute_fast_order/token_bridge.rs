@@ -0,0 +1,164 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Custodian, MessageProtocol, PauseTarget},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{token_bridge, wormhole_io::TypePrefixedPayload};
+
+/// Accounts required for [execute_fast_order_token_bridge].
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ExecuteFastOrderTokenBridge<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["core-msg", auction\].
+    #[account(
+        mut,
+        seeds = [
+            common::CORE_MESSAGE_SEED_PREFIX,
+            execute_order.active_auction.key().as_ref(),
+        ],
+        bump,
+    )]
+    core_message: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::OrderExecution, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
+    custodian: CheckedCustodian<'info>,
+
+    execute_order: ExecuteOrder<'info>,
+
+    #[account(
+        constraint = {
+            require_eq!(
+                to_router_endpoint.protocol,
+                execute_order.active_auction.target_protocol,
+                MatchingEngineError::InvalidEndpoint
+            );
+
+            true
+        }
+    )]
+    to_router_endpoint: LiveRouterEndpoint<'info>,
+
+    #[account(
+        constraint = {
+            token_bridge.mint.key() == execute_order.active_auction.custody_token.mint
+        } @ MatchingEngineError::MintMismatch,
+    )]
+    token_bridge: TokenBridgeTransferNative<'info>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, token::Token>,
+
+    sysvars: RequiredSysvars<'info>,
+}
+
+pub fn execute_fast_order_token_bridge(ctx: Context<ExecuteFastOrderTokenBridge>) -> Result<()> {
+    match ctx.accounts.to_router_endpoint.protocol {
+        MessageProtocol::TokenBridge => handle_execute_fast_order_token_bridge(ctx),
+        _ => err!(MatchingEngineError::InvalidTokenBridgeEndpoint),
+    }
+}
+
+fn handle_execute_fast_order_token_bridge(ctx: Context<ExecuteFastOrderTokenBridge>) -> Result<()> {
+    let custodian = &ctx.accounts.custodian;
+    let token_program = &ctx.accounts.token_program;
+
+    let super::PreparedOrderExecution {
+        user_amount: amount,
+        fill,
+        order_executed_event,
+    } = super::handle_execute_fast_order(
+        &mut ctx.accounts.execute_order,
+        &ctx.accounts.custodian,
+        &ctx.accounts.token_program,
+    )?;
+
+    let active_auction = &ctx.accounts.execute_order.active_auction;
+    let auction_custody_token = &active_auction.custody_token;
+    let token_bridge = &ctx.accounts.token_bridge;
+
+    // The Token Bridge transfers from the auction custody token account using its transfer
+    // authority, so the custodian must delegate the amount to it first.
+    token::approve(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Approve {
+                to: auction_custody_token.to_account_info(),
+                delegate: token_bridge.transfer_authority.to_account_info(),
+                authority: custodian.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        amount,
+    )?;
+
+    // Send the Token Bridge transfer to the destination chain.
+    token_bridge::transfer_native_with_payload(
+        CpiContext::new_with_signer(
+            token_bridge.token_bridge_program.to_account_info(),
+            token_bridge::TransferNativeWithPayload {
+                payer: ctx.accounts.payer.to_account_info(),
+                config: token_bridge.config.to_account_info(),
+                from: auction_custody_token.to_account_info(),
+                mint: token_bridge.mint.to_account_info(),
+                custody: token_bridge.custody_token.to_account_info(),
+                transfer_authority: token_bridge.transfer_authority.to_account_info(),
+                custody_authority: token_bridge.custody_authority.to_account_info(),
+                core_bridge_config: token_bridge.core_bridge_config.to_account_info(),
+                core_message: ctx.accounts.core_message.to_account_info(),
+                core_emitter: token_bridge.emitter.to_account_info(),
+                core_emitter_sequence: token_bridge.emitter_sequence.to_account_info(),
+                core_fee_collector: token_bridge.core_fee_collector.to_account_info(),
+                clock: ctx.accounts.sysvars.clock.to_account_info(),
+                sender: custodian.to_account_info(),
+                rent: ctx.accounts.sysvars.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: token_program.to_account_info(),
+                core_bridge_program: token_bridge.core_bridge_program.to_account_info(),
+            },
+            &[
+                Custodian::SIGNER_SEEDS,
+                &[
+                    common::CORE_MESSAGE_SEED_PREFIX,
+                    active_auction.key().as_ref(),
+                    &[ctx.bumps.core_message],
+                ],
+            ],
+        ),
+        token_bridge::TransferNativeWithPayloadArgs {
+            nonce: common::WORMHOLE_MESSAGE_NONCE,
+            amount,
+            target_address: ctx.accounts.to_router_endpoint.mint_recipient,
+            target_chain: ctx.accounts.to_router_endpoint.chain,
+            payload: fill.to_vec(),
+            cpi_program_id: None,
+        },
+    )?;
+
+    // Emit the order executed event, which liquidators can listen to if this execution ended up
+    // being penalized so they can collect the base fee at settlement.
+    emit_cpi!(order_executed_event);
+
+    // Finally close the account since it is no longer needed.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: auction_custody_token.to_account_info(),
+            destination: ctx
+                .accounts
+                .execute_order
+                .initial_participant
+                .to_account_info(),
+            authority: custodian.to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))
+}
@@ -1,4 +1,9 @@
-use crate::{composite::*, error::MatchingEngineError, state::Auction, utils};
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Auction, PauseTarget},
+    utils,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::TRANSFER_AUTHORITY_SEED_PREFIX;
@@ -20,11 +25,24 @@ pub struct ImproveOffer<'info> {
     )]
     transfer_authority: UncheckedAccount<'info>,
 
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::OfferImprovement, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
+    custodian: CheckedCustodian<'info>,
+
     #[account(
         constraint = {
             // This is safe because we know that this is an active auction.
             let info = active_auction.info.as_ref().unwrap();
 
+            // Offers for a destination asset are improved by the amount out instead.
+            require!(
+                info.destination_asset_info.is_none(),
+                MatchingEngineError::InvalidDestinationAsset
+            );
+
             require!(
                 info.within_auction_duration(&active_auction.config),
                 MatchingEngineError::AuctionPeriodExpired
@@ -80,7 +98,7 @@ pub fn improve_offer(ctx: Context<ImproveOffer>, offer_price: u64) -> Result<()>
             // If the best offer token happens to be closed, we will just keep the funds in the
             // auction custody account. The executor token account will collect these funds when the
             // order is executed.
-            if utils::checked_deserialize_token_account(best_offer_token, &common::USDC_MINT)
+            if utils::checked_deserialize_token_account(best_offer_token, &custody_token.mint)
                 .is_some()
             {
                 token::transfer(
@@ -149,6 +167,7 @@ pub fn improve_offer(ctx: Context<ImproveOffer>, offer_price: u64) -> Result<()>
             total_deposit: info.total_deposit(),
             max_offer_price_allowed: utils::auction::compute_min_allowed_offer(config, info)
                 .checked_sub(1),
+            destination_asset_info: info.destination_asset_info,
         }));
     }
 
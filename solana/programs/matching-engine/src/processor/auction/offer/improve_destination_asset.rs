@@ -0,0 +1,262 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Auction, PauseTarget},
+    utils,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::TRANSFER_AUTHORITY_SEED_PREFIX;
+
+#[derive(Accounts)]
+#[instruction(amount_out: u64)]
+#[event_cpi]
+pub struct ImproveOfferDestinationAsset<'info> {
+    /// The auction participant needs to set approval to this PDA for both the offer token and the
+    /// destination offer token.
+    ///
+    /// CHECK: Seeds must be \["transfer-authority", auction.key(), amount_out.to_be_bytes()\].
+    #[account(
+        seeds = [
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            active_auction.key().as_ref(),
+            &amount_out.to_be_bytes()
+        ],
+        bump
+    )]
+    transfer_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::OfferImprovement, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        constraint = {
+            // This is safe because we know that this is an active auction.
+            let info = active_auction.info.as_ref().unwrap();
+            let destination_asset_info = info
+                .destination_asset_info
+                .as_ref()
+                .ok_or_else(|| MatchingEngineError::InvalidDestinationAsset)?;
+
+            require!(
+                info.within_auction_duration(&active_auction.config),
+                MatchingEngineError::AuctionPeriodExpired
+            );
+
+            require!(
+                amount_out
+                    > utils::auction::compute_min_allowed_amount_out(
+                        &active_auction.config,
+                        destination_asset_info.amount_out
+                    ),
+                MatchingEngineError::CarpingNotAllowed
+            );
+
+            true
+        }
+    )]
+    active_auction: ActiveAuction<'info>,
+
+    #[account(
+        constraint = {
+            offer_token.key() != active_auction.custody_token.key()
+        } @ MatchingEngineError::InvalidOfferToken,
+    )]
+    offer_token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::AUCTION_DESTINATION_CUSTODY_TOKEN_SEED_PREFIX,
+            active_auction.key().as_ref(),
+        ],
+        bump = destination_asset_info(&active_auction).custody_token_bump,
+    )]
+    destination_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(
+        token::mint = destination_custody_token.mint,
+        constraint = {
+            destination_offer_token.key() != destination_custody_token.key()
+        } @ MatchingEngineError::InvalidOfferToken,
+    )]
+    destination_offer_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: Mutable. Must have the same key in the auction's destination asset info.
+    #[account(
+        mut,
+        address = destination_asset_info(&active_auction).best_offer_token,
+    )]
+    destination_best_offer_token: UncheckedAccount<'info>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+fn destination_asset_info(
+    active_auction: &ActiveAuction,
+) -> crate::state::AuctionDestinationAssetInfo {
+    // This is safe because the active auction constraint checks that this info exists.
+    active_auction
+        .info
+        .as_ref()
+        .and_then(|info| info.destination_asset_info)
+        .unwrap()
+}
+
+pub fn improve_offer_destination_asset(
+    ctx: Context<ImproveOfferDestinationAsset>,
+    amount_out: u64,
+) -> Result<()> {
+    let offer_token = &ctx.accounts.offer_token;
+    let destination_offer_token = &ctx.accounts.destination_offer_token;
+
+    {
+        let ActiveAuction {
+            auction,
+            custody_token,
+            best_offer_token,
+            config: _,
+        } = &ctx.accounts.active_auction;
+        let destination_custody_token = &ctx.accounts.destination_custody_token;
+        let destination_best_offer_token = &ctx.accounts.destination_best_offer_token;
+
+        let token_program = &ctx.accounts.token_program;
+
+        let auction_signer_seeds = &[
+            Auction::SEED_PREFIX,
+            auction.vaa_hash.as_ref(),
+            &[auction.bump],
+        ];
+        let auction_key = auction.key();
+        let transfer_authority_seeds = &[
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            auction_key.as_ref(),
+            &amount_out.to_be_bytes(),
+            &[ctx.bumps.transfer_authority],
+        ];
+
+        // Replace the security deposit, but only if the pubkeys are different (just like
+        // improving an offer price).
+        if offer_token.key() != best_offer_token.key() {
+            let total_deposit = auction.info.as_ref().unwrap().total_deposit();
+
+            // If the best offer token happens to be closed, we will just keep the funds in the
+            // auction custody account. The executor token account will collect these funds when the
+            // order is executed.
+            if utils::checked_deserialize_token_account(best_offer_token, &custody_token.mint)
+                .is_some()
+            {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        token::Transfer {
+                            from: custody_token.to_account_info(),
+                            to: best_offer_token.to_account_info(),
+                            authority: auction.to_account_info(),
+                        },
+                        &[auction_signer_seeds],
+                    ),
+                    total_deposit,
+                )?;
+            }
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: offer_token.to_account_info(),
+                        to: custody_token.to_account_info(),
+                        authority: ctx.accounts.transfer_authority.to_account_info(),
+                    },
+                    &[transfer_authority_seeds],
+                ),
+                total_deposit,
+            )?;
+        }
+
+        // The previous best offer's amount out is refunded if its token account still exists.
+        // Otherwise (or if the same token account is improving its own offer) the previous amount
+        // out stays in custody and only the difference is transferred.
+        let previous_amount_out = destination_asset_info(&ctx.accounts.active_auction).amount_out;
+        let refund_previous = destination_offer_token.key() != destination_best_offer_token.key()
+            && utils::checked_deserialize_token_account(
+                destination_best_offer_token,
+                &destination_custody_token.mint,
+            )
+            .is_some();
+
+        let deposit_amount = if refund_previous {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: destination_custody_token.to_account_info(),
+                        to: destination_best_offer_token.to_account_info(),
+                        authority: auction.to_account_info(),
+                    },
+                    &[auction_signer_seeds],
+                ),
+                previous_amount_out,
+            )?;
+
+            amount_out
+        } else {
+            // This is safe because the new amount out must exceed the previous amount out.
+            amount_out.saturating_sub(previous_amount_out)
+        };
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: destination_offer_token.to_account_info(),
+                    to: destination_custody_token.to_account_info(),
+                    authority: ctx.accounts.transfer_authority.to_account_info(),
+                },
+                &[transfer_authority_seeds],
+            ),
+            deposit_amount,
+        )?;
+    }
+
+    // Update info before we emit event.
+    {
+        let info = ctx.accounts.active_auction.info.as_mut().unwrap();
+        info.best_offer_token = offer_token.key();
+
+        let destination_asset_info = info.destination_asset_info.as_mut().unwrap();
+        destination_asset_info.best_offer_token = destination_offer_token.key();
+        destination_asset_info.amount_out = amount_out;
+    }
+
+    // Emit the auction updated event.
+    {
+        let auction = &ctx.accounts.active_auction;
+        let config = &auction.config;
+        let info = auction.info.as_ref().unwrap();
+
+        // Emit event for auction participants to listen to.
+        emit_cpi!(crate::utils::log_emit(crate::events::AuctionUpdated {
+            config_id: info.config_id,
+            fast_vaa_hash: auction.vaa_hash,
+            vaa: Default::default(),
+            source_chain: info.source_chain,
+            target_protocol: auction.target_protocol,
+            redeemer_message_len: info.redeemer_message_len,
+            end_slot: info.auction_end_slot(config),
+            best_offer_token: offer_token.key(),
+            token_balance_before: offer_token.amount,
+            amount_in: info.amount_in,
+            total_deposit: info.total_deposit(),
+            max_offer_price_allowed: None,
+            destination_asset_info: info.destination_asset_info,
+        }));
+    }
+
+    // Done.
+    Ok(())
+}
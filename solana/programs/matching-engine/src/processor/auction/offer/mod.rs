@@ -1,5 +1,8 @@
 mod improve;
 pub use improve::*;
 
+mod improve_destination_asset;
+pub use improve_destination_asset::*;
+
 mod place_initial;
 pub use place_initial::*;
@@ -1,7 +1,10 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{Auction, AuctionConfig, AuctionInfo, AuctionStatus, MessageProtocol},
+    state::{
+        Auction, AuctionConfig, AuctionInfo, AuctionStatus, CircuitBreaker, InboundNotional,
+        MessageProtocol, PauseTarget,
+    },
     utils,
 };
 use anchor_lang::prelude::*;
@@ -29,7 +32,11 @@ pub struct PlaceInitialOfferCctp<'info> {
     transfer_authority: UncheckedAccount<'info>,
 
     /// NOTE: This account is only used to pause inbound auctions.
-    #[account(constraint = !custodian.paused @ MatchingEngineError::Paused)]
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::NewAuctions, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
     custodian: CheckedCustodian<'info>,
 
     #[account(
@@ -48,32 +55,35 @@ pub struct PlaceInitialOfferCctp<'info> {
     #[account(
         constraint = {
             match fast_order_path.to_endpoint.protocol {
-                MessageProtocol::Cctp { .. } | MessageProtocol::Local { .. } => (),
+                MessageProtocol::Cctp { .. }
+                | MessageProtocol::Local { .. }
+                | MessageProtocol::TokenBridge
+                | MessageProtocol::Ntt { .. } => (),
                 _ => return err!(MatchingEngineError::InvalidEndpoint),
             }
 
+            super::require_auctionable_order(&fast_order_path, &custodian)?;
+
             let fast_vaa = fast_order_path.fast_vaa.load_unchecked();
             let message = LiquidityLayerMessage::try_from(fast_vaa.payload()).unwrap();
-            let order = message
-                .fast_market_order()
-                .ok_or_else(|| MatchingEngineError::InvalidPayloadId)?;
-
-            let curr_time = Clock::get().unwrap().unix_timestamp;
-
-            // Check to see if the deadline has expired.
-            let deadline = order.deadline();
-            let expiration =
-                i64::from(fast_vaa.timestamp()).saturating_add(crate::VAA_AUCTION_EXPIRATION_TIME);
-            require!(
-                (deadline == 0 || curr_time < deadline.into()) && curr_time < expiration,
-                MatchingEngineError::FastMarketOrderExpired,
-            );
-
+            let order = message.to_fast_market_order_unchecked();
             require!(
                 offer_price <= order.max_fee(),
                 MatchingEngineError::OfferPriceTooHigh
             );
 
+            // Orders that name a destination asset must be auctioned by the amount out of that
+            // asset instead.
+            if matches!(
+                fast_order_path.to_endpoint.protocol,
+                MessageProtocol::Local { .. }
+            ) {
+                require!(
+                    order.destination_mint() == [0; 32],
+                    MatchingEngineError::InvalidDestinationAsset
+                );
+            }
+
             true
         }
     )]
@@ -93,12 +103,13 @@ pub struct PlaceInitialOfferCctp<'info> {
     )]
     auction: Box<Account<'info, Auction>>,
 
+    #[account(token::mint = mint)]
     offer_token: Box<Account<'info, token::TokenAccount>>,
 
     #[account(
         init,
         payer = payer,
-        token::mint = usdc,
+        token::mint = mint,
         token::authority = auction,
         seeds = [
             crate::AUCTION_CUSTODY_TOKEN_SEED_PREFIX,
@@ -108,10 +119,36 @@ pub struct PlaceInitialOfferCctp<'info> {
     )]
     auction_custody_token: Box<Account<'info, token::TokenAccount>>,
 
-    usdc: Usdc<'info>,
+    /// Mint of the auctioned asset, which is determined by the source endpoint.
+    #[account(
+        constraint = {
+            mint.key() == fast_order_path.from_endpoint.mint
+        } @ MatchingEngineError::MintMismatch,
+    )]
+    mint: CheckedMint<'info>,
 
     system_program: Program<'info, System>,
     token_program: Program<'info, token::Token>,
+
+    /// Notional amounts accepted from the source endpoint. This account is only required if the
+    /// source endpoint's inbound limit or the circuit breaker is enabled.
+    #[account(
+        mut,
+        seeds = [
+            InboundNotional::SEED_PREFIX,
+            &fast_order_path.from_endpoint.chain.to_be_bytes(),
+        ],
+        bump = inbound_notional.bump,
+    )]
+    inbound_notional: Option<Box<Account<'info, InboundNotional>>>,
+
+    /// This account is only required if the circuit breaker is enabled.
+    #[account(
+        mut,
+        seeds = [CircuitBreaker::SEED_PREFIX],
+        bump = circuit_breaker.bump,
+    )]
+    circuit_breaker: Option<Box<Account<'info, CircuitBreaker>>>,
 }
 
 pub fn place_initial_offer_cctp(
@@ -127,6 +164,23 @@ pub fn place_initial_offer_cctp(
     // Parse the transfer amount from the VAA.
     let amount_in = order.amount_in();
 
+    // Record this order against the source endpoint's throughput limit and the circuit breaker.
+    if let Some(event) = super::record_inbound_order(
+        &ctx.accounts.fast_order_path.from_endpoint,
+        &ctx.accounts.custodian,
+        ctx.accounts
+            .inbound_notional
+            .as_deref_mut()
+            .map(|acct| &mut **acct),
+        ctx.accounts
+            .circuit_breaker
+            .as_deref_mut()
+            .map(|acct| &mut **acct),
+        amount_in,
+    )? {
+        emit_cpi!(event);
+    }
+
     // Saturating to u64::MAX is safe here. If the amount really ends up being this large, the
     // checked addition below will catch it.
     let security_deposit =
@@ -181,6 +235,7 @@ pub fn place_initial_offer_cctp(
         total_deposit: info.total_deposit(),
         max_offer_price_allowed: utils::auction::compute_min_allowed_offer(config, info)
             .checked_sub(1),
+        destination_asset_info: info.destination_asset_info,
     }));
 
     // Finally transfer tokens from the offer authority's token account to the
@@ -0,0 +1,311 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{
+        Auction, AuctionConfig, AuctionDestinationAssetInfo, AuctionInfo, AuctionStatus,
+        CircuitBreaker, InboundNotional, MessageProtocol, PauseTarget,
+    },
+    utils,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{messages::raw::LiquidityLayerMessage, TRANSFER_AUTHORITY_SEED_PREFIX};
+
+#[derive(Accounts)]
+#[instruction(amount_out: u64)]
+#[event_cpi]
+pub struct PlaceInitialOfferDestinationAsset<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// The auction participant needs to set approval to this PDA for both the offer token and the
+    /// destination offer token.
+    ///
+    /// CHECK: Seeds must be \["transfer-authority", auction.key(), amount_out.to_be_bytes()\].
+    #[account(
+        seeds = [
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            auction.key().as_ref(),
+            &amount_out.to_be_bytes()
+        ],
+        bump
+    )]
+    transfer_authority: UncheckedAccount<'info>,
+
+    /// NOTE: This account is only used to pause inbound auctions.
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::NewAuctions, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        constraint = {
+            require_eq!(
+                auction_config.id,
+                custodian.auction_config_id,
+                MatchingEngineError::AuctionConfigMismatch,
+            );
+
+            true
+        }
+    )]
+    auction_config: Account<'info, AuctionConfig>,
+
+    /// Only orders destined for Solana can be delivered in a destination asset.
+    #[account(
+        constraint = {
+            require!(
+                matches!(
+                    fast_order_path.to_endpoint.protocol,
+                    MessageProtocol::Local { .. }
+                ),
+                MatchingEngineError::InvalidTargetRouter
+            );
+
+            super::require_auctionable_order(&fast_order_path, &custodian)?;
+
+            // The auction is settled with USDC, so the source endpoint must transfer USDC.
+            require_keys_eq!(
+                fast_order_path.from_endpoint.mint,
+                common::USDC_MINT,
+                MatchingEngineError::MintMismatch
+            );
+
+            require!(amount_out > 0, MatchingEngineError::InvalidAmountOut);
+
+            // The order must have opted into delivery in this destination asset, and the offer
+            // must deliver at least the order's minimum amount out. Improved offers can only
+            // increase the amount out.
+            let fast_vaa = fast_order_path.fast_vaa.load_unchecked();
+            let message = LiquidityLayerMessage::try_from(fast_vaa.payload()).unwrap();
+            let order = message.to_fast_market_order_unchecked();
+            require!(
+                order.destination_mint() == destination_mint.key().to_bytes(),
+                MatchingEngineError::InvalidDestinationAsset
+            );
+            require!(
+                amount_out >= order.destination_min_amount_out(),
+                MatchingEngineError::AmountOutTooLow
+            );
+
+            true
+        }
+    )]
+    fast_order_path: FastOrderPath<'info>,
+
+    /// Notional amounts accepted from the source endpoint. This account is only required if the
+    /// source endpoint's inbound limit or the circuit breaker is enabled.
+    #[account(
+        mut,
+        seeds = [
+            InboundNotional::SEED_PREFIX,
+            &fast_order_path.from_endpoint.chain.to_be_bytes(),
+        ],
+        bump = inbound_notional.bump,
+    )]
+    inbound_notional: Option<Box<Account<'info, InboundNotional>>>,
+
+    /// This account is only required if the circuit breaker is enabled.
+    #[account(
+        mut,
+        seeds = [CircuitBreaker::SEED_PREFIX],
+        bump = circuit_breaker.bump,
+    )]
+    circuit_breaker: Option<Box<Account<'info, CircuitBreaker>>>,
+
+    /// This account should only be created once, and should never be changed to
+    /// init_if_needed. Otherwise someone can game an existing auction.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Auction::INIT_SPACE,
+        seeds = [
+            Auction::SEED_PREFIX,
+            fast_order_path.fast_vaa.load_unchecked().digest().as_ref(),
+        ],
+        bump
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    /// Token account that the security deposit is transferred from. This token account will be
+    /// repaid the amount in when the auction is settled.
+    #[account(token::mint = usdc)]
+    offer_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = usdc,
+        token::authority = auction,
+        seeds = [
+            crate::AUCTION_CUSTODY_TOKEN_SEED_PREFIX,
+            auction.key().as_ref(),
+        ],
+        bump,
+    )]
+    auction_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    usdc: Usdc<'info>,
+
+    /// Mint of the asset delivered by the fast fill, which must be the destination mint encoded in
+    /// the fast market order. If this mint were USDC, the initial offer should be placed with an
+    /// offer price instead.
+    #[account(
+        constraint = {
+            destination_mint.key() != common::USDC_MINT
+        } @ MatchingEngineError::InvalidMint,
+    )]
+    destination_mint: CheckedMint<'info>,
+
+    /// Token account that the amount out is transferred from.
+    #[account(token::mint = destination_mint)]
+    destination_offer_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = destination_mint,
+        token::authority = auction,
+        seeds = [
+            crate::AUCTION_DESTINATION_CUSTODY_TOKEN_SEED_PREFIX,
+            auction.key().as_ref(),
+        ],
+        bump,
+    )]
+    destination_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn place_initial_offer_destination_asset(
+    ctx: Context<PlaceInitialOfferDestinationAsset>,
+    amount_out: u64,
+) -> Result<()> {
+    // Create zero copy reference to `FastMarketOrder` payload.
+    let fast_vaa = ctx.accounts.fast_order_path.fast_vaa.load_unchecked();
+    let order = LiquidityLayerMessage::try_from(fast_vaa.payload())
+        .unwrap()
+        .to_fast_market_order_unchecked();
+
+    // Parse the transfer amount from the VAA.
+    let amount_in = order.amount_in();
+
+    // Record this order against the source endpoint's throughput limit and the circuit breaker.
+    if let Some(event) = super::record_inbound_order(
+        &ctx.accounts.fast_order_path.from_endpoint,
+        &ctx.accounts.custodian,
+        ctx.accounts
+            .inbound_notional
+            .as_deref_mut()
+            .map(|acct| &mut **acct),
+        ctx.accounts
+            .circuit_breaker
+            .as_deref_mut()
+            .map(|acct| &mut **acct),
+        amount_in,
+    )? {
+        emit_cpi!(event);
+    }
+
+    // Saturating to u64::MAX is safe here. If the amount really ends up being this large, the
+    // transfer below will fail.
+    let security_deposit =
+        order
+            .max_fee()
+            .saturating_add(utils::auction::compute_notional_security_deposit(
+                &ctx.accounts.auction_config,
+                amount_in,
+            ));
+
+    // Set up the Auction account for this auction. There is no offer price because participants
+    // bid on the amount out of the destination asset.
+    let config = &ctx.accounts.auction_config;
+    let initial_offer_token = ctx.accounts.offer_token.key();
+    ctx.accounts.auction.set_inner(Auction {
+        bump: ctx.bumps.auction,
+        vaa_hash: fast_vaa.digest().0,
+        vaa_timestamp: fast_vaa.timestamp(),
+        target_protocol: ctx.accounts.fast_order_path.to_endpoint.protocol,
+        status: AuctionStatus::Active,
+        prepared_by: ctx.accounts.payer.key(),
+        info: AuctionInfo {
+            config_id: config.id,
+            custody_token_bump: ctx.bumps.auction_custody_token,
+            vaa_sequence: fast_vaa.sequence(),
+            source_chain: fast_vaa.emitter_chain(),
+            best_offer_token: initial_offer_token,
+            initial_offer_token,
+            start_slot: Clock::get().unwrap().slot,
+            amount_in,
+            security_deposit,
+            offer_price: Default::default(),
+            redeemer_message_len: order.redeemer_message_len(),
+            destination_asset_info: AuctionDestinationAssetInfo {
+                custody_token_bump: ctx.bumps.destination_custody_token,
+                amount_out,
+                mint: ctx.accounts.destination_mint.key(),
+                best_offer_token: ctx.accounts.destination_offer_token.key(),
+            }
+            .into(),
+        }
+        .into(),
+    });
+
+    let info = ctx.accounts.auction.info.as_ref().unwrap();
+
+    // Emit event for auction participants to listen to.
+    emit_cpi!(crate::utils::log_emit(crate::events::AuctionUpdated {
+        config_id: info.config_id,
+        fast_vaa_hash: ctx.accounts.auction.vaa_hash,
+        vaa: ctx.accounts.fast_order_path.fast_vaa.key().into(),
+        source_chain: info.source_chain,
+        target_protocol: ctx.accounts.auction.target_protocol,
+        redeemer_message_len: info.redeemer_message_len,
+        end_slot: info.auction_end_slot(config),
+        best_offer_token: initial_offer_token,
+        token_balance_before: ctx.accounts.offer_token.amount,
+        amount_in,
+        total_deposit: info.total_deposit(),
+        max_offer_price_allowed: None,
+        destination_asset_info: info.destination_asset_info,
+    }));
+
+    let auction_key = ctx.accounts.auction.key();
+    let transfer_authority_seeds = &[
+        TRANSFER_AUTHORITY_SEED_PREFIX,
+        auction_key.as_ref(),
+        &amount_out.to_be_bytes(),
+        &[ctx.bumps.transfer_authority],
+    ];
+
+    // Transfer the security deposit to the auction's custody account.
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.offer_token.to_account_info(),
+                to: ctx.accounts.auction_custody_token.to_account_info(),
+                authority: ctx.accounts.transfer_authority.to_account_info(),
+            },
+            &[transfer_authority_seeds],
+        ),
+        security_deposit,
+    )?;
+
+    // Finally transfer the amount out to the auction's destination custody account.
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.destination_offer_token.to_account_info(),
+                to: ctx.accounts.destination_custody_token.to_account_info(),
+                authority: ctx.accounts.transfer_authority.to_account_info(),
+            },
+            &[transfer_authority_seeds],
+        ),
+        amount_out,
+    )
+}
@@ -1,2 +1,127 @@
 mod cctp;
 pub use cctp::*;
+
+mod destination_asset;
+pub use destination_asset::*;
+
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    events::CircuitBreakerTripped,
+    state::{CircuitBreaker, Custodian, InboundNotional, MessageProtocol, RouterEndpoint},
+};
+use anchor_lang::prelude::*;
+use common::messages::raw::LiquidityLayerMessage;
+
+/// Checks that the fast market order can still be auctioned and that it is within the target
+/// endpoint's order limits.
+fn require_auctionable_order(fast_order_path: &FastOrderPath, custodian: &Custodian) -> Result<()> {
+    let fast_vaa = fast_order_path.fast_vaa.load_unchecked();
+    let message = LiquidityLayerMessage::try_from(fast_vaa.payload()).unwrap();
+    let order = message
+        .fast_market_order()
+        .ok_or_else(|| MatchingEngineError::InvalidPayloadId)?;
+
+    let curr_time = Clock::get().unwrap().unix_timestamp;
+
+    // Check to see if the deadline has expired.
+    let deadline = order.deadline();
+    let expiration =
+        i64::from(fast_vaa.timestamp()).saturating_add(crate::VAA_AUCTION_EXPIRATION_TIME);
+    require!(
+        (deadline == 0 || curr_time < deadline.into()) && curr_time < expiration,
+        MatchingEngineError::FastMarketOrderExpired,
+    );
+
+    // Enforce the target endpoint's order limits.
+    let limits = &fast_order_path.to_endpoint.limits;
+    require!(
+        !limits.exceeds_max_redeemer_message_len(order.redeemer_message_len().into()),
+        MatchingEngineError::RedeemerMessageTooLarge
+    );
+    require!(
+        !limits.is_below_min_order_amount(order.amount_in()),
+        MatchingEngineError::OrderAmountBelowMinimum
+    );
+    require!(
+        !limits.is_above_max_order_amount(order.amount_in()),
+        MatchingEngineError::OrderAmountAboveMaximum
+    );
+
+    // A gas drop-off is only delivered for orders destined for Solana, where it cannot exceed the
+    // governed maximum.
+    if matches!(
+        fast_order_path.to_endpoint.protocol,
+        MessageProtocol::Local { .. }
+    ) {
+        require!(
+            order.gas_dropoff() <= custodian.max_gas_dropoff,
+            MatchingEngineError::GasDropoffTooLarge
+        );
+    }
+
+    Ok(())
+}
+
+/// Records a new auction's order against the source endpoint's inbound limit and the circuit
+/// breaker. If this order trips the circuit breaker, the event to emit is returned.
+///
+/// NOTE: The inbound notional and circuit breaker accounts are only required when the limits using
+/// them are enabled, so auctions are not serialized by writes to these accounts otherwise.
+fn record_inbound_order(
+    from_endpoint: &RouterEndpoint,
+    custodian: &Custodian,
+    inbound_notional: Option<&mut InboundNotional>,
+    circuit_breaker: Option<&mut CircuitBreaker>,
+    amount_in: u64,
+) -> Result<Option<CircuitBreakerTripped>> {
+    let config = &custodian.circuit_breaker;
+    if !from_endpoint.inbound_limit.is_enabled() && !config.is_enabled() {
+        return Ok(None);
+    }
+
+    let inbound_notional =
+        inbound_notional.ok_or_else(|| error!(MatchingEngineError::InboundNotionalRequired))?;
+
+    let current_slot = Clock::get().unwrap().slot;
+    require!(
+        from_endpoint.inbound_limit.try_record(
+            &mut inbound_notional.inbound_limit,
+            amount_in,
+            current_slot
+        ),
+        MatchingEngineError::EndpointThroughputExceeded
+    );
+
+    if !config.is_enabled() {
+        return Ok(None);
+    }
+
+    let circuit_breaker =
+        circuit_breaker.ok_or_else(|| error!(MatchingEngineError::CircuitBreakerRequired))?;
+
+    // New auctions are refused while the circuit breaker is tripped.
+    require!(
+        !config.is_tripped(
+            &circuit_breaker.global,
+            &inbound_notional.circuit_breaker,
+            current_slot
+        ),
+        MatchingEngineError::CircuitBreakerTripped
+    );
+    if config.record(
+        &mut circuit_breaker.global,
+        &mut inbound_notional.circuit_breaker,
+        amount_in,
+        current_slot,
+    ) {
+        Ok(CircuitBreakerTripped {
+            source_chain: from_endpoint.chain,
+            global_notional: circuit_breaker.global.notional,
+            chain_notional: inbound_notional.circuit_breaker.notional,
+        }
+        .into())
+    } else {
+        Ok(None)
+    }
+}
@@ -2,7 +2,8 @@ use crate::{
     composite::*,
     error::MatchingEngineError,
     state::{
-        Custodian, PreparedOrderResponse, PreparedOrderResponseInfo, PreparedOrderResponseSeeds,
+        Auction, CircuitBreaker, Custodian, InboundNotional, PreparedOrderResponse,
+        PreparedOrderResponseInfo, PreparedOrderResponseSeeds,
     },
 };
 use anchor_lang::prelude::*;
@@ -13,6 +14,7 @@ use common::{
 };
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct PrepareOrderResponseCctp<'info> {
     #[account(mut)]
     payer: Signer<'info>,
@@ -87,7 +89,7 @@ pub struct PrepareOrderResponseCctp<'info> {
     #[account(
         init_if_needed,
         payer = payer,
-        token::mint = usdc,
+        token::mint = mint,
         token::authority = prepared_order_response,
         seeds = [
             crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
@@ -102,7 +104,7 @@ pub struct PrepareOrderResponseCctp<'info> {
     /// non-existent auctions and the best offer token account will be used for orders executed on
     /// time.
     #[account(
-        token::mint = usdc,
+        token::mint = mint,
         constraint = {
             require!(
                 base_fee_token.key() != prepared_custody_token.key(),
@@ -114,12 +116,51 @@ pub struct PrepareOrderResponseCctp<'info> {
     )]
     base_fee_token: Box<Account<'info, token::TokenAccount>>,
 
-    usdc: Usdc<'info>,
+    /// Mint of the asset minted to the CCTP mint recipient.
+    #[account(
+        constraint = {
+            mint.key() == cctp.mint_recipient.mint
+                && mint.key() == fast_order_path.from_endpoint.mint
+        } @ MatchingEngineError::MintMismatch,
+    )]
+    mint: CheckedMint<'info>,
 
     cctp: CctpReceiveMessage<'info>,
 
     token_program: Program<'info, token::Token>,
     system_program: Program<'info, System>,
+
+    /// Notional amounts accepted from the source endpoint. This account is only required if the
+    /// circuit breaker is enabled.
+    #[account(
+        mut,
+        seeds = [
+            InboundNotional::SEED_PREFIX,
+            &fast_order_path.from_endpoint.chain.to_be_bytes(),
+        ],
+        bump = inbound_notional.bump,
+    )]
+    inbound_notional: Option<Box<Account<'info, InboundNotional>>>,
+
+    /// This account is only required if the circuit breaker is enabled.
+    #[account(
+        mut,
+        seeds = [CircuitBreaker::SEED_PREFIX],
+        bump = circuit_breaker.bump,
+    )]
+    circuit_breaker: Option<Box<Account<'info, CircuitBreaker>>>,
+
+    /// CHECK: This auction account may not exist. If it does not exist, this order's notional
+    /// amount is recorded by the circuit breaker. Otherwise it was recorded when the auction was
+    /// started.
+    #[account(
+        seeds = [
+            Auction::SEED_PREFIX,
+            fast_order_path.fast_vaa.load_unchecked().digest().as_ref(),
+        ],
+        bump,
+    )]
+    auction: UncheckedAccount<'info>,
 }
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
@@ -219,6 +260,38 @@ fn handle_prepare_order_response_cctp(
 
     let amount_in = order.amount_in();
 
+    // Orders without an auction are recorded by the circuit breaker here. This order cannot be
+    // refused, but it may trip the breaker so that new auctions are refused. Otherwise the auction
+    // must have been started with the minted asset.
+    if ctx.accounts.auction.data_is_empty() {
+        let config = &ctx.accounts.custodian.circuit_breaker;
+        if config.is_enabled() {
+            let inbound_notional = ctx
+                .accounts
+                .inbound_notional
+                .as_deref_mut()
+                .ok_or_else(|| error!(MatchingEngineError::InboundNotionalRequired))?;
+            let circuit_breaker = ctx
+                .accounts
+                .circuit_breaker
+                .as_deref_mut()
+                .ok_or_else(|| error!(MatchingEngineError::CircuitBreakerRequired))?;
+            let current_slot = Clock::get().unwrap().slot;
+            if config.record(
+                &mut circuit_breaker.global,
+                &mut inbound_notional.circuit_breaker,
+                amount_in,
+                current_slot,
+            ) {
+                emit_cpi!(crate::events::CircuitBreakerTripped {
+                    source_chain: ctx.accounts.fast_order_path.from_endpoint.chain,
+                    global_notional: circuit_breaker.global.notional,
+                    chain_notional: inbound_notional.circuit_breaker.notional,
+                });
+            }
+        }
+    }
+
     // Write to the prepared slow order account, which will be closed by one of the following
     // instructions:
     // * settle_auction_active_cctp
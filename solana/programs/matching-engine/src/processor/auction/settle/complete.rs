@@ -1,7 +1,8 @@
 use crate::{
+    composite::*,
     error::MatchingEngineError,
     events::SettledTokenAccountInfo,
-    state::{Auction, AuctionStatus, PreparedOrderResponse},
+    state::{Auction, AuctionStatus, PauseTarget, PreparedOrderResponse},
     utils,
 };
 use anchor_lang::prelude::*;
@@ -10,6 +11,13 @@ use anchor_spl::token::{self, TokenAccount};
 #[derive(Accounts)]
 #[event_cpi]
 pub struct SettleAuctionComplete<'info> {
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::Settlement, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
+    custodian: CheckedCustodian<'info>,
+
     /// CHECK: Must equal prepared_order_response.prepared_by, who paid the rent to post the
     /// finalized VAA.
     #[account(
@@ -110,6 +118,7 @@ fn handle_settle_auction_complete(
     let prepared_custody_token = &ctx.accounts.prepared_custody_token;
 
     let repayment = ctx.accounts.prepared_custody_token.amount;
+    let auction_mint = prepared_custody_token.mint;
 
     struct TokenAccountResult {
         balance_before: u64,
@@ -121,7 +130,7 @@ fn handle_settle_auction_complete(
         None => {
             // If the token account happens to not exist anymore, we will revert.
             let best_offer_token_data =
-                utils::checked_deserialize_token_account(best_offer_token, &common::USDC_MINT)
+                utils::checked_deserialize_token_account(best_offer_token, &auction_mint)
                     .ok_or_else(|| MatchingEngineError::BestOfferTokenRequired)?;
 
             (
@@ -136,8 +145,8 @@ fn handle_settle_auction_complete(
         // Otherwise, determine how the repayment should be divvied up.
         _ => {
             match (
-                utils::checked_deserialize_token_account(base_fee_token, &common::USDC_MINT),
-                utils::checked_deserialize_token_account(best_offer_token, &common::USDC_MINT),
+                utils::checked_deserialize_token_account(base_fee_token, &auction_mint),
+                utils::checked_deserialize_token_account(best_offer_token, &auction_mint),
             ) {
                 (Some(base_fee_token_data), Some(best_offer_token_data)) => {
                     if base_fee_token.key() == best_offer_token.key() {
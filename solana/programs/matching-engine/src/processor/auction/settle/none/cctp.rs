@@ -1,7 +1,7 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{Auction, Custodian, EndpointInfo, MessageProtocol},
+    state::{Auction, Custodian, EndpointInfo, MessageProtocol, PauseTarget},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -36,6 +36,11 @@ pub struct SettleAuctionNoneCctp<'info> {
     )]
     cctp_message: UncheckedAccount<'info>,
 
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::Settlement, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
     custodian: CheckedCustodian<'info>,
 
     /// Destination token account, which the redeemer may not own. But because the redeemer is a
@@ -45,10 +50,18 @@ pub struct SettleAuctionNoneCctp<'info> {
     /// CHECK: This token account must already exist.
     #[account(
         mut,
-        address = custodian.fee_recipient_token,
+        address = mint.fee_recipient_token(&custodian),
     )]
     fee_recipient_token: Account<'info, token::TokenAccount>,
 
+    /// Mint of the prepared order response's custody token account.
+    #[account(
+        constraint = {
+            mint.key() == prepared.custody_token.mint
+        } @ MatchingEngineError::MintMismatch,
+    )]
+    mint: CheckedMint<'info>,
+
     prepared: ClosePreparedOrderResponse<'info>,
 
     /// There should be no account data here because an auction was never created.
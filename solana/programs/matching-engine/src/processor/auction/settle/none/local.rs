@@ -1,10 +1,12 @@
 use crate::{
     composite::*,
     error::MatchingEngineError,
-    state::{Auction, AuctionStatus, Custodian, FastFill, ReservedFastFillSequence},
+    state::{Auction, AuctionStatus, Custodian, FastFill, PauseTarget, ReservedFastFillSequence},
+    utils,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use common::{messages::raw::LiquidityLayerMessage, wormhole_cctp_solana::wormhole::VaaAccount};
 
 /// Accounts required for [settle_auction_none_local].
 #[event_cpi]
@@ -13,6 +15,18 @@ pub struct SettleAuctionNoneLocal<'info> {
     #[account(mut)]
     payer: Signer<'info>,
 
+    #[account(
+        constraint = {
+            let current_slot = Clock::get()?.slot;
+            require!(
+                !custodian.is_paused(PauseTarget::Settlement, current_slot)
+                    && !custodian.is_paused(PauseTarget::FastFillCreation, current_slot),
+                MatchingEngineError::Paused
+            );
+
+            true
+        }
+    )]
     custodian: CheckedCustodian<'info>,
 
     /// Destination token account, which the redeemer may not own. But because the redeemer is a
@@ -22,7 +36,7 @@ pub struct SettleAuctionNoneLocal<'info> {
     /// CHECK: This token account must already exist.
     #[account(
         mut,
-        address = custodian.fee_recipient_token,
+        address = mint.fee_recipient_token(&custodian),
     )]
     fee_recipient_token: Account<'info, token::TokenAccount>,
 
@@ -106,15 +120,57 @@ pub struct SettleAuctionNoneLocal<'info> {
         seeds = [
             crate::LOCAL_CUSTODY_TOKEN_SEED_PREFIX,
             &prepared.order_response.source_chain.to_be_bytes(),
+            utils::local_custody_mint_seed(&prepared.custody_token.mint),
         ],
         bump,
     )]
     local_custody_token: Box<Account<'info, token::TokenAccount>>,
 
+    /// Fast VAA of the order, which encodes the relayer fee. If this account is not provided, the
+    /// fast fill will not be automatically delivered to the redeemer.
+    ///
+    /// CHECK: This VAA's digest must equal the one encoded in the prepared order response.
+    #[account(
+        constraint = {
+            let vaa = VaaAccount::load(fast_vaa)?;
+            require!(
+                vaa.digest().0 == prepared.order_response.seeds.fast_vaa_hash,
+                MatchingEngineError::VaaMismatch
+            );
+
+            true
+        }
+    )]
+    fast_vaa: Option<UncheckedAccount<'info>>,
+
     token_program: Program<'info, token::Token>,
     system_program: Program<'info, System>,
 
     sysvars: RequiredSysvars<'info>,
+
+    /// Mint of the prepared order response's custody token account.
+    #[account(
+        constraint = {
+            mint.key() == prepared.custody_token.mint
+        } @ MatchingEngineError::MintMismatch,
+    )]
+    mint: CheckedMint<'info>,
+}
+
+fn order_relayer_fee(fast_vaa: Option<&UncheckedAccount>) -> u64 {
+    match fast_vaa {
+        Some(fast_vaa) => {
+            let vaa = VaaAccount::load_unchecked(fast_vaa);
+
+            // We can unwrap and convert to FastMarketOrder unchecked because we validate the VAA
+            // hash equals the one encoded in the prepared order response.
+            LiquidityLayerMessage::try_from(vaa.payload())
+                .unwrap()
+                .to_fast_market_order_unchecked()
+                .relayer_fee()
+        }
+        None => Default::default(),
+    }
 }
 
 pub fn settle_auction_none_local(ctx: Context<SettleAuctionNoneLocal>) -> Result<()> {
@@ -145,6 +201,8 @@ pub fn settle_auction_none_local(ctx: Context<SettleAuctionNoneLocal>) -> Result
         ctx.bumps.fast_fill,
         ctx.accounts.payer.key(),
         amount,
+        ctx.accounts.local_custody_token.mint,
+        order_relayer_fee(ctx.accounts.fast_vaa.as_ref()),
     );
 
     // Emit the fast fill.
@@ -152,6 +210,8 @@ pub fn settle_auction_none_local(ctx: Context<SettleAuctionNoneLocal>) -> Result
         seeds: fast_fill.seeds,
         info: fast_fill.info,
         auction: Default::default(),
+        mint: fast_fill.mint,
+        relayer_fee: fast_fill.relayer_fee,
     });
     ctx.accounts.fast_fill.set_inner(fast_fill);
 
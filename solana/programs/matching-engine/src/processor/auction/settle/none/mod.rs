@@ -4,6 +4,12 @@ pub use cctp::*;
 mod local;
 pub use local::*;
 
+mod ntt;
+pub use ntt::*;
+
+mod token_bridge;
+pub use token_bridge::*;
+
 use crate::{
     composite::*,
     events::AuctionSettled,
@@ -0,0 +1,238 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Auction, Custodian, EndpointInfo, MessageProtocol, PauseTarget},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{
+    ntt,
+    wormhole_cctp_solana::wormhole::core_bridge_program::{self, Commitment},
+    wormhole_io::TypePrefixedPayload,
+};
+
+/// Accounts required for [settle_auction_none_ntt].
+#[derive(Accounts)]
+#[event_cpi]
+pub struct SettleAuctionNoneNtt<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["core-msg", auction\].
+    #[account(
+        mut,
+        seeds = [
+            common::CORE_MESSAGE_SEED_PREFIX,
+            auction.key().as_ref(),
+        ],
+        bump,
+    )]
+    core_message: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["ntt-outbox", auction\].
+    #[account(
+        mut,
+        seeds = [
+            common::NTT_OUTBOX_ITEM_SEED_PREFIX,
+            auction.key().as_ref(),
+        ],
+        bump,
+    )]
+    ntt_outbox_item: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::Settlement, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
+    custodian: CheckedCustodian<'info>,
+
+    /// Destination token account, which the redeemer may not own. But because the redeemer is a
+    /// signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent
+    /// to any account he chooses (this one).
+    ///
+    /// CHECK: This token account must already exist.
+    #[account(
+        mut,
+        address = mint.fee_recipient_token(&custodian),
+    )]
+    fee_recipient_token: Account<'info, token::TokenAccount>,
+
+    /// Mint of the prepared order response's custody token account.
+    #[account(
+        constraint = {
+            mint.key() == prepared.custody_token.mint
+        } @ MatchingEngineError::MintMismatch,
+    )]
+    mint: CheckedMint<'info>,
+
+    prepared: ClosePreparedOrderResponse<'info>,
+
+    /// There should be no account data here because an auction was never created.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Auction::INIT_SPACE_NO_AUCTION,
+        seeds = [
+            Auction::SEED_PREFIX,
+            prepared.order_response.seeds.fast_vaa_hash.as_ref(),
+        ],
+        bump
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    wormhole: WormholePublishMessage<'info>,
+
+    #[account(
+        constraint = {
+            ntt.mint.key() == prepared.custody_token.mint
+        } @ MatchingEngineError::MintMismatch,
+    )]
+    ntt: NttTransfer<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+
+    sysvars: RequiredSysvars<'info>,
+}
+
+pub fn settle_auction_none_ntt(ctx: Context<SettleAuctionNoneNtt>) -> Result<()> {
+    match ctx.accounts.prepared.order_response.to_endpoint.protocol {
+        MessageProtocol::Ntt {
+            manager,
+            transceiver,
+        } => handle_settle_auction_none_ntt(ctx, manager, transceiver),
+        _ => err!(MatchingEngineError::InvalidNttEndpoint),
+    }
+}
+
+fn handle_settle_auction_none_ntt(
+    ctx: Context<SettleAuctionNoneNtt>,
+    manager: Pubkey,
+    transceiver: Pubkey,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.ntt.ntt_manager_program.key(),
+        manager,
+        MatchingEngineError::InvalidNttEndpoint
+    );
+    require_keys_eq!(
+        ctx.accounts.ntt.transceiver.key(),
+        transceiver,
+        MatchingEngineError::InvalidNttEndpoint
+    );
+
+    let auction = &mut ctx.accounts.auction;
+
+    // First set data in the auction account.
+    auction.set_inner(
+        ctx.accounts
+            .prepared
+            .order_response
+            .new_auction_placeholder(ctx.bumps.auction),
+    );
+
+    let prepared_by = &ctx.accounts.prepared.by;
+    let prepared_custody_token = &ctx.accounts.prepared.custody_token;
+    let custodian = &ctx.accounts.custodian;
+    let token_program = &ctx.accounts.token_program;
+
+    let super::SettledNone {
+        user_amount: amount,
+        fill,
+        auction_settled_event,
+    } = super::settle_none_and_prepare_fill(super::SettleNoneAndPrepareFill {
+        prepared_order_response: &mut ctx.accounts.prepared.order_response,
+        prepared_custody_token,
+        auction: &mut ctx.accounts.auction,
+        fee_recipient_token: &ctx.accounts.fee_recipient_token,
+        custodian,
+        token_program,
+    })?;
+
+    let EndpointInfo {
+        chain: recipient_chain,
+        address: _,
+        mint_recipient,
+        protocol: _,
+    } = ctx.accounts.prepared.order_response.to_endpoint;
+
+    let auction = &ctx.accounts.auction;
+    let payer = &ctx.accounts.payer;
+    let system_program = &ctx.accounts.system_program;
+
+    ntt::transfer_lock(
+        CpiContext::new_with_signer(
+            ctx.accounts.ntt.ntt_manager_program.to_account_info(),
+            ntt::TransferLock {
+                payer: payer.to_account_info(),
+                sender: custodian.to_account_info(),
+                from: prepared_custody_token.to_account_info(),
+                mint: ctx.accounts.ntt.mint.to_account_info(),
+                outbox_item: ctx.accounts.ntt_outbox_item.to_account_info(),
+                custody: ctx.accounts.ntt.custody_token.to_account_info(),
+                transceiver: ctx.accounts.ntt.transceiver.to_account_info(),
+                token_program: token_program.to_account_info(),
+                system_program: system_program.to_account_info(),
+            },
+            &[
+                Custodian::SIGNER_SEEDS,
+                &[
+                    common::NTT_OUTBOX_ITEM_SEED_PREFIX,
+                    auction.key().as_ref(),
+                    &[ctx.bumps.ntt_outbox_item],
+                ],
+            ],
+        ),
+        ntt::TransferArgs {
+            amount,
+            recipient_chain,
+            recipient_address: mint_recipient,
+            should_queue: false,
+        },
+    )?;
+
+    core_bridge_program::cpi::post_message(
+        CpiContext::new_with_signer(
+            ctx.accounts.wormhole.core_bridge_program.to_account_info(),
+            core_bridge_program::cpi::PostMessage {
+                payer: payer.to_account_info(),
+                message: ctx.accounts.core_message.to_account_info(),
+                emitter: custodian.to_account_info(),
+                config: ctx.accounts.wormhole.config.to_account_info(),
+                emitter_sequence: ctx.accounts.wormhole.emitter_sequence.to_account_info(),
+                fee_collector: ctx.accounts.wormhole.fee_collector.to_account_info(),
+                system_program: system_program.to_account_info(),
+                clock: ctx.accounts.sysvars.clock.to_account_info(),
+                rent: ctx.accounts.sysvars.rent.to_account_info(),
+            },
+            &[
+                Custodian::SIGNER_SEEDS,
+                &[
+                    common::CORE_MESSAGE_SEED_PREFIX,
+                    auction.key().as_ref(),
+                    &[ctx.bumps.core_message],
+                ],
+            ],
+        ),
+        core_bridge_program::cpi::PostMessageArgs {
+            nonce: common::WORMHOLE_MESSAGE_NONCE,
+            payload: fill.to_vec(),
+            commitment: Commitment::Finalized,
+        },
+    )?;
+
+    // Emit an event indicating that the auction has been settled.
+    emit_cpi!(auction_settled_event);
+
+    // Finally close the account since it is no longer needed.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: prepared_custody_token.to_account_info(),
+            destination: prepared_by.to_account_info(),
+            authority: custodian.to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))
+}
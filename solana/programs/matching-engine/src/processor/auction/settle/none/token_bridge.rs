@@ -0,0 +1,200 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{Auction, Custodian, EndpointInfo, MessageProtocol, PauseTarget},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{token_bridge, wormhole_io::TypePrefixedPayload};
+
+/// Accounts required for [settle_auction_none_token_bridge].
+#[derive(Accounts)]
+#[event_cpi]
+pub struct SettleAuctionNoneTokenBridge<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: Mutable. Seeds must be \["core-msg", auction\].
+    #[account(
+        mut,
+        seeds = [
+            common::CORE_MESSAGE_SEED_PREFIX,
+            auction.key().as_ref(),
+        ],
+        bump,
+    )]
+    core_message: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = {
+            !custodian.is_paused(PauseTarget::Settlement, Clock::get()?.slot)
+        } @ MatchingEngineError::Paused,
+    )]
+    custodian: CheckedCustodian<'info>,
+
+    /// Destination token account, which the redeemer may not own. But because the redeemer is a
+    /// signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent
+    /// to any account he chooses (this one).
+    ///
+    /// CHECK: This token account must already exist.
+    #[account(
+        mut,
+        address = mint.fee_recipient_token(&custodian),
+    )]
+    fee_recipient_token: Account<'info, token::TokenAccount>,
+
+    /// Mint of the prepared order response's custody token account.
+    #[account(
+        constraint = {
+            mint.key() == prepared.custody_token.mint
+        } @ MatchingEngineError::MintMismatch,
+    )]
+    mint: CheckedMint<'info>,
+
+    prepared: ClosePreparedOrderResponse<'info>,
+
+    /// There should be no account data here because an auction was never created.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Auction::INIT_SPACE_NO_AUCTION,
+        seeds = [
+            Auction::SEED_PREFIX,
+            prepared.order_response.seeds.fast_vaa_hash.as_ref(),
+        ],
+        bump
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        constraint = {
+            token_bridge.mint.key() == prepared.custody_token.mint
+        } @ MatchingEngineError::MintMismatch,
+    )]
+    token_bridge: TokenBridgeTransferNative<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+
+    sysvars: RequiredSysvars<'info>,
+}
+
+pub fn settle_auction_none_token_bridge(ctx: Context<SettleAuctionNoneTokenBridge>) -> Result<()> {
+    match ctx.accounts.prepared.order_response.to_endpoint.protocol {
+        MessageProtocol::TokenBridge => handle_settle_auction_none_token_bridge(ctx),
+        _ => err!(MatchingEngineError::InvalidTokenBridgeEndpoint),
+    }
+}
+
+fn handle_settle_auction_none_token_bridge(
+    ctx: Context<SettleAuctionNoneTokenBridge>,
+) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+
+    // First set data in the auction account.
+    auction.set_inner(
+        ctx.accounts
+            .prepared
+            .order_response
+            .new_auction_placeholder(ctx.bumps.auction),
+    );
+
+    let prepared_by = &ctx.accounts.prepared.by;
+    let prepared_custody_token = &ctx.accounts.prepared.custody_token;
+    let custodian = &ctx.accounts.custodian;
+    let token_program = &ctx.accounts.token_program;
+
+    let super::SettledNone {
+        user_amount: amount,
+        fill,
+        auction_settled_event,
+    } = super::settle_none_and_prepare_fill(super::SettleNoneAndPrepareFill {
+        prepared_order_response: &mut ctx.accounts.prepared.order_response,
+        prepared_custody_token,
+        auction: &mut ctx.accounts.auction,
+        fee_recipient_token: &ctx.accounts.fee_recipient_token,
+        custodian,
+        token_program,
+    })?;
+
+    let EndpointInfo {
+        chain: target_chain,
+        address: _,
+        mint_recipient,
+        protocol: _,
+    } = ctx.accounts.prepared.order_response.to_endpoint;
+
+    let auction = &ctx.accounts.auction;
+    let token_bridge = &ctx.accounts.token_bridge;
+
+    // The Token Bridge transfers from the prepared custody token account using its transfer
+    // authority, so the custodian must delegate the amount to it first.
+    token::approve(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Approve {
+                to: prepared_custody_token.to_account_info(),
+                delegate: token_bridge.transfer_authority.to_account_info(),
+                authority: custodian.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        amount,
+    )?;
+
+    token_bridge::transfer_native_with_payload(
+        CpiContext::new_with_signer(
+            token_bridge.token_bridge_program.to_account_info(),
+            token_bridge::TransferNativeWithPayload {
+                payer: ctx.accounts.payer.to_account_info(),
+                config: token_bridge.config.to_account_info(),
+                from: prepared_custody_token.to_account_info(),
+                mint: token_bridge.mint.to_account_info(),
+                custody: token_bridge.custody_token.to_account_info(),
+                transfer_authority: token_bridge.transfer_authority.to_account_info(),
+                custody_authority: token_bridge.custody_authority.to_account_info(),
+                core_bridge_config: token_bridge.core_bridge_config.to_account_info(),
+                core_message: ctx.accounts.core_message.to_account_info(),
+                core_emitter: token_bridge.emitter.to_account_info(),
+                core_emitter_sequence: token_bridge.emitter_sequence.to_account_info(),
+                core_fee_collector: token_bridge.core_fee_collector.to_account_info(),
+                clock: ctx.accounts.sysvars.clock.to_account_info(),
+                sender: custodian.to_account_info(),
+                rent: ctx.accounts.sysvars.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: token_program.to_account_info(),
+                core_bridge_program: token_bridge.core_bridge_program.to_account_info(),
+            },
+            &[
+                Custodian::SIGNER_SEEDS,
+                &[
+                    common::CORE_MESSAGE_SEED_PREFIX,
+                    auction.key().as_ref(),
+                    &[ctx.bumps.core_message],
+                ],
+            ],
+        ),
+        token_bridge::TransferNativeWithPayloadArgs {
+            nonce: common::WORMHOLE_MESSAGE_NONCE,
+            amount,
+            target_address: mint_recipient,
+            target_chain,
+            payload: fill.to_vec(),
+            cpi_program_id: None,
+        },
+    )?;
+
+    // Emit an event indicating that the auction has been settled.
+    emit_cpi!(auction_settled_event);
+
+    // Finally close the account since it is no longer needed.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: prepared_custody_token.to_account_info(),
+            destination: prepared_by.to_account_info(),
+            authority: custodian.to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))
+}
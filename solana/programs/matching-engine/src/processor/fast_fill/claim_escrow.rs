@@ -0,0 +1,67 @@
+use crate::{composite::*, state::Custodian};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+/// Accounts required for [claim_fast_fill_escrow].
+#[derive(Accounts)]
+pub struct ClaimFastFillEscrow<'info> {
+    /// Redeemer of the reclaimed fast fills, who receives the lamports of the escrow token account.
+    #[account(mut)]
+    redeemer: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    /// Escrow token account holding the redeemer's reclaimed fill amounts. This account will be
+    /// closed at the end of this instruction.
+    ///
+    /// CHECK: Seeds must be \["fast-fill-escrow", redeemer, mint\].
+    #[account(
+        mut,
+        seeds = [
+            crate::FAST_FILL_ESCROW_TOKEN_SEED_PREFIX,
+            redeemer.key().as_ref(),
+            escrow_token.mint.as_ref(),
+        ],
+        bump,
+    )]
+    escrow_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Token account receiving the escrowed amount, which the redeemer may not own.
+    #[account(
+        mut,
+        token::mint = escrow_token.mint,
+    )]
+    recipient_token: Box<Account<'info, token::TokenAccount>>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn claim_fast_fill_escrow(ctx: Context<ClaimFastFillEscrow>) -> Result<()> {
+    let custodian = &ctx.accounts.custodian;
+    let escrow_token = &ctx.accounts.escrow_token;
+    let token_program = &ctx.accounts.token_program;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: escrow_token.to_account_info(),
+                to: ctx.accounts.recipient_token.to_account_info(),
+                authority: custodian.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        escrow_token.amount,
+    )?;
+
+    // Finally close the escrow token account.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: escrow_token.to_account_info(),
+            destination: ctx.accounts.redeemer.to_account_info(),
+            authority: custodian.to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))
+}
@@ -1,5 +1,6 @@
 use crate::{error::MatchingEngineError, state::FastFill};
 use anchor_lang::prelude::*;
+use anchor_spl::token;
 
 #[derive(Accounts)]
 pub struct CloseRedeemedFastFill<'info> {
@@ -19,8 +20,46 @@ pub struct CloseRedeemedFastFill<'info> {
         constraint = fast_fill.redeemed @ MatchingEngineError::FastFillNotRedeemed,
     )]
     fast_fill: Account<'info, FastFill>,
+
+    /// Token account owned by the fast fill if the fast fill owns its custody token account. Only
+    /// required in this case, where this account will be closed.
+    #[account(
+        mut,
+        token::mint = fast_fill.mint,
+        token::authority = fast_fill,
+    )]
+    fast_fill_custody_token: Option<Account<'info, token::TokenAccount>>,
+
+    token_program: Option<Program<'info, token::Token>>,
 }
 
-pub fn close_redeemed_fast_fill(_ctx: Context<CloseRedeemedFastFill>) -> Result<()> {
-    Ok(())
+pub fn close_redeemed_fast_fill(ctx: Context<CloseRedeemedFastFill>) -> Result<()> {
+    let fast_fill = &ctx.accounts.fast_fill;
+    if !fast_fill.owns_custody_token {
+        return Ok(());
+    }
+
+    match (
+        &ctx.accounts.fast_fill_custody_token,
+        &ctx.accounts.token_program,
+    ) {
+        (Some(fast_fill_custody_token), Some(token_program)) => {
+            token::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::CloseAccount {
+                    account: fast_fill_custody_token.to_account_info(),
+                    destination: ctx.accounts.prepared_by.to_account_info(),
+                    authority: fast_fill.to_account_info(),
+                },
+                &[&[
+                    FastFill::SEED_PREFIX,
+                    &fast_fill.seeds.source_chain.to_be_bytes(),
+                    &fast_fill.seeds.order_sender,
+                    &fast_fill.seeds.sequence.to_be_bytes(),
+                    &[fast_fill.seeds.bump],
+                ]],
+            ))
+        }
+        _ => err!(MatchingEngineError::FastFillCustodyTokenRequired),
+    }
 }
@@ -2,6 +2,7 @@ use crate::{
     composite::*,
     error::MatchingEngineError,
     state::{FastFill, RouterEndpoint},
+    utils,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -41,7 +42,7 @@ pub struct CompleteFastFill<'info> {
 
     #[account(
         mut,
-        token::mint = local_custody_token.mint,
+        token::mint = fast_fill.mint,
     )]
     token_router_custody_token: Box<Account<'info, token::TokenAccount>>,
 
@@ -69,11 +70,21 @@ pub struct CompleteFastFill<'info> {
         seeds = [
             crate::LOCAL_CUSTODY_TOKEN_SEED_PREFIX,
             &fast_fill.seeds.source_chain.to_be_bytes(),
+            utils::local_custody_mint_seed(&path.from_endpoint.mint),
         ],
         bump,
     )]
     local_custody_token: Box<Account<'info, token::TokenAccount>>,
 
+    /// Token account owned by the fast fill, which holds the fill amount if the fast fill owns its
+    /// custody token account. Only required in this case.
+    #[account(
+        mut,
+        token::mint = fast_fill.mint,
+        token::authority = fast_fill,
+    )]
+    fast_fill_custody_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
     token_program: Program<'info, token::Token>,
 }
 
@@ -87,21 +98,50 @@ pub fn complete_fast_fill(ctx: Context<CompleteFastFill>) -> Result<()> {
         fast_fill: ctx.accounts.fast_fill.seeds,
     });
 
+    let fast_fill = &ctx.accounts.fast_fill;
+
     // Finally transfer to local token router's token account.
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.local_custody_token.to_account_info(),
-                to: ctx.accounts.token_router_custody_token.to_account_info(),
-                authority: ctx.accounts.path.from_endpoint.to_account_info(),
-            },
-            &[&[
-                RouterEndpoint::SEED_PREFIX,
-                &ctx.accounts.path.from_endpoint.chain.to_be_bytes(),
-                &[ctx.accounts.path.from_endpoint.bump],
-            ]],
-        ),
-        ctx.accounts.fast_fill.info.amount,
-    )
+    if fast_fill.owns_custody_token {
+        let fast_fill_custody_token = ctx
+            .accounts
+            .fast_fill_custody_token
+            .as_ref()
+            .ok_or_else(|| MatchingEngineError::FastFillCustodyTokenRequired)?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: fast_fill_custody_token.to_account_info(),
+                    to: ctx.accounts.token_router_custody_token.to_account_info(),
+                    authority: fast_fill.to_account_info(),
+                },
+                &[&[
+                    FastFill::SEED_PREFIX,
+                    &fast_fill.seeds.source_chain.to_be_bytes(),
+                    &fast_fill.seeds.order_sender,
+                    &fast_fill.seeds.sequence.to_be_bytes(),
+                    &[fast_fill.seeds.bump],
+                ]],
+            ),
+            fast_fill.info.amount,
+        )
+    } else {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.local_custody_token.to_account_info(),
+                    to: ctx.accounts.token_router_custody_token.to_account_info(),
+                    authority: ctx.accounts.path.from_endpoint.to_account_info(),
+                },
+                &[&[
+                    RouterEndpoint::SEED_PREFIX,
+                    &ctx.accounts.path.from_endpoint.chain.to_be_bytes(),
+                    &[ctx.accounts.path.from_endpoint.bump],
+                ]],
+            ),
+            fast_fill.info.amount,
+        )
+    }
 }
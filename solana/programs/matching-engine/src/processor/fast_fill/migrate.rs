@@ -0,0 +1,120 @@
+use crate::state::{FastFill, FastFillInfo, FastFillSeeds};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+pub struct MigrateFastFill<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: Must be a [FastFill] account owned by this program. This account is deserialized in
+    /// the instruction handler using either its legacy or current layout.
+    #[account(
+        mut,
+        owner = crate::id(),
+    )]
+    fast_fill: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Fast fill info layout of the initially deployed program.
+#[derive(AnchorDeserialize)]
+struct LegacyFastFillInfo {
+    prepared_by: Pubkey,
+    amount: u64,
+    redeemer: Pubkey,
+    timestamp: i64,
+}
+
+/// Fast fill layout of the initially deployed program, which only supported USDC. Fields added
+/// since then are appended to the end of [FastFill], so accounts with this layout are reallocated
+/// and rewritten when migrating.
+#[derive(AnchorDeserialize)]
+struct LegacyFastFill {
+    seeds: FastFillSeeds,
+    redeemed: bool,
+    info: LegacyFastFillInfo,
+    redeemer_message: Vec<u8>,
+}
+
+impl LegacyFastFill {
+    fn compute_size(redeemer_message_len: usize) -> usize {
+        const FIXED: usize = 8 // DISCRIMINATOR
+            + FastFillSeeds::INIT_SPACE
+            + 1 // redeemed
+            + 32 // info.prepared_by
+            + 8 // info.amount
+            + 32 // info.redeemer
+            + 8 // info.timestamp
+            + 4 // redeemer_message_len
+        ;
+
+        redeemer_message_len.saturating_add(FIXED)
+    }
+}
+
+pub fn migrate_fast_fill(ctx: Context<MigrateFastFill>) -> Result<()> {
+    let acc_info = ctx.accounts.fast_fill.to_account_info();
+
+    let fast_fill = {
+        let data = acc_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == FastFill::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        // An account with the legacy layout is exactly the size of its legacy fields.
+        let legacy = match LegacyFastFill::deserialize(&mut &data[8..]) {
+            Ok(legacy)
+                if data.len() == LegacyFastFill::compute_size(legacy.redeemer_message.len()) =>
+            {
+                legacy
+            }
+            _ => {
+                msg!("Nothing to migrate");
+                return Ok(());
+            }
+        };
+
+        let LegacyFastFill {
+            seeds,
+            redeemed,
+            info:
+                LegacyFastFillInfo {
+                    prepared_by,
+                    amount,
+                    redeemer,
+                    timestamp,
+                },
+            redeemer_message,
+        } = legacy;
+
+        FastFill {
+            seeds,
+            redeemed,
+            info: FastFillInfo {
+                prepared_by,
+                amount,
+                redeemer,
+                timestamp,
+            },
+            redeemer_message,
+            mint: common::USDC_MINT,
+            relayer_fee: Default::default(),
+            owns_custody_token: Default::default(),
+        }
+    };
+
+    common::realloc::realloc_account(
+        &acc_info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        FastFill::compute_size(fast_fill.redeemer_message.len()),
+    )?;
+    fast_fill.try_serialize(&mut &mut acc_info.try_borrow_mut_data()?[..])?;
+
+    msg!("Migrated fast fill");
+
+    // Done.
+    Ok(())
+}
@@ -1,8 +1,17 @@
+mod claim_escrow;
+pub use claim_escrow::*;
+
 mod close_redeemed;
 pub use close_redeemed::*;
 
 mod complete;
 pub use complete::*;
 
+mod migrate;
+pub use migrate::*;
+
+mod reclaim_expired;
+pub use reclaim_expired::*;
+
 mod reserve_sequence;
 pub use reserve_sequence::*;
@@ -0,0 +1,203 @@
+use crate::{
+    composite::*,
+    error::MatchingEngineError,
+    state::{FastFill, RouterEndpoint},
+    utils,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address, token};
+
+/// Accounts required for [reclaim_expired_fast_fill].
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ReclaimExpiredFastFill<'info> {
+    /// Pays for the redeemer's escrow token account if it needs to be created.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    /// Instead of having the preparer sign for this instruction, we allow anyone to call this
+    /// instruction on behalf of the preparer.
+    ///
+    /// CHECK: Must equal the `prepared_by` field of the `fast_fill` account.
+    #[account(
+        mut,
+        address = fast_fill.info.prepared_by,
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    /// Fast fill account, which must not have been redeemed before its expiry.
+    ///
+    /// Seeds must be \["fast-fill", source_chain, order_sender, sequence\].
+    #[account(
+        mut,
+        close = prepared_by,
+        seeds = [
+            FastFill::SEED_PREFIX,
+            &fast_fill.seeds.source_chain.to_be_bytes(),
+            &fast_fill.seeds.order_sender,
+            &fast_fill.seeds.sequence.to_be_bytes(),
+        ],
+        bump = fast_fill.seeds.bump,
+        constraint = !fast_fill.redeemed @ MatchingEngineError::FastFillAlreadyRedeemed,
+        constraint = fast_fill.is_expired(
+            Clock::get()?.unix_timestamp
+        ) @ MatchingEngineError::FastFillNotExpired,
+    )]
+    fast_fill: Box<Account<'info, FastFill>>,
+
+    /// Redeemer's associated token account, which receives the fill amount if it can. Otherwise
+    /// the fill amount is held in the escrow token account.
+    ///
+    /// CHECK: Mutable. Must be the redeemer's associated token account for the fill's mint.
+    #[account(
+        mut,
+        address = get_associated_token_address(&fast_fill.info.redeemer, &fast_fill.mint),
+    )]
+    redeemer_token: UncheckedAccount<'info>,
+
+    /// Escrow token account owned by the custodian, which holds reclaimed fill amounts that could
+    /// not be delivered to the redeemer's associated token account until the redeemer claims them.
+    /// This account is only required in this case, where it is created if it does not exist yet.
+    ///
+    /// CHECK: Seeds must be \["fast-fill-escrow", redeemer, mint\].
+    #[account(
+        init_if_needed,
+        payer = payer,
+        token::mint = mint,
+        token::authority = custodian,
+        seeds = [
+            crate::FAST_FILL_ESCROW_TOKEN_SEED_PREFIX,
+            fast_fill.info.redeemer.as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    escrow_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
+    #[account(address = fast_fill.mint)]
+    mint: Box<Account<'info, token::Mint>>,
+
+    /// Router endpoint of the fill's source chain, which is the authority of the local custody
+    /// token account.
+    #[account(
+        seeds = [
+            RouterEndpoint::SEED_PREFIX,
+            &fast_fill.seeds.source_chain.to_be_bytes(),
+        ],
+        bump = from_endpoint.bump,
+    )]
+    from_endpoint: Box<Account<'info, RouterEndpoint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            crate::LOCAL_CUSTODY_TOKEN_SEED_PREFIX,
+            &fast_fill.seeds.source_chain.to_be_bytes(),
+            utils::local_custody_mint_seed(&from_endpoint.mint),
+        ],
+        bump,
+    )]
+    local_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Token account owned by the fast fill, which holds the fill amount if the fast fill owns its
+    /// custody token account. Only required in this case, where this account will be closed.
+    #[account(
+        mut,
+        token::mint = fast_fill.mint,
+        token::authority = fast_fill,
+    )]
+    fast_fill_custody_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+/// Returns the redeemer's associated token account if it can receive the fill amount. Otherwise
+/// returns the escrow token account.
+fn recipient_token<'info>(accounts: &ReclaimExpiredFastFill<'info>) -> Result<AccountInfo<'info>> {
+    let fast_fill = &accounts.fast_fill;
+    match utils::checked_deserialize_token_account(&accounts.redeemer_token, &fast_fill.mint) {
+        Some(token) if token.owner == fast_fill.info.redeemer => {
+            Ok(accounts.redeemer_token.to_account_info())
+        }
+        _ => accounts
+            .escrow_token
+            .as_ref()
+            .map(|escrow_token| escrow_token.to_account_info())
+            .ok_or_else(|| error!(MatchingEngineError::FastFillEscrowTokenRequired)),
+    }
+}
+
+pub fn reclaim_expired_fast_fill(ctx: Context<ReclaimExpiredFastFill>) -> Result<()> {
+    let fast_fill = &ctx.accounts.fast_fill;
+    let recipient_token = recipient_token(ctx.accounts)?;
+
+    emit_cpi!(crate::events::FastFillReclaimed {
+        prepared_by: fast_fill.info.prepared_by,
+        fast_fill: fast_fill.seeds,
+        recipient_token: recipient_token.key(),
+    });
+
+    let token_program = &ctx.accounts.token_program;
+
+    if !fast_fill.owns_custody_token {
+        let from_endpoint = &ctx.accounts.from_endpoint;
+
+        return token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.local_custody_token.to_account_info(),
+                    to: recipient_token,
+                    authority: from_endpoint.to_account_info(),
+                },
+                &[&[
+                    RouterEndpoint::SEED_PREFIX,
+                    &from_endpoint.chain.to_be_bytes(),
+                    &[from_endpoint.bump],
+                ]],
+            ),
+            fast_fill.info.amount,
+        );
+    }
+
+    let fast_fill_custody_token = ctx
+        .accounts
+        .fast_fill_custody_token
+        .as_ref()
+        .ok_or_else(|| MatchingEngineError::FastFillCustodyTokenRequired)?;
+
+    let fast_fill_signer_seeds = &[
+        FastFill::SEED_PREFIX,
+        &fast_fill.seeds.source_chain.to_be_bytes(),
+        &fast_fill.seeds.order_sender,
+        &fast_fill.seeds.sequence.to_be_bytes(),
+        &[fast_fill.seeds.bump],
+    ];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: fast_fill_custody_token.to_account_info(),
+                to: recipient_token,
+                authority: fast_fill.to_account_info(),
+            },
+            &[fast_fill_signer_seeds],
+        ),
+        fast_fill.info.amount,
+    )?;
+
+    // Finally close the token account owned by the fast fill.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: fast_fill_custody_token.to_account_info(),
+            destination: ctx.accounts.prepared_by.to_account_info(),
+            authority: fast_fill.to_account_info(),
+        },
+        &[fast_fill_signer_seeds],
+    ))
+}
@@ -45,7 +45,16 @@ impl std::fmt::Display for AuctionStatus {
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
 pub struct AuctionDestinationAssetInfo {
     pub custody_token_bump: u8,
+
+    /// The amount of the destination asset offered by the highest bidder.
     pub amount_out: u64,
+
+    /// Mint of the destination asset.
+    pub mint: Pubkey,
+
+    /// The highest bidder's destination asset token account, which is refunded if the offer is
+    /// improved.
+    pub best_offer_token: Pubkey,
 }
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
@@ -85,12 +94,9 @@ pub struct AuctionInfo {
     pub redeemer_message_len: u16,
 
     /// If the destination asset is not equal to the asset used for auctions, this will be some
-    /// value specifying its custody token bump and amount out.
-    ///
-    /// NOTE: Because this is an option, the `AuctionDestinationAssetInfo` having some definition while this
-    /// field is None will not impact future serialization because the option's serialized value is
-    /// zero. Only when there will be other assets will this struct's members have to be carefully
-    /// considered.
+    /// value specifying its custody token bump and amount out. Participants bid on the amount out
+    /// of this asset instead of an offer price, and the fast fill is delivered in this asset. The
+    /// highest bidder is still repaid in the auction's mint when the auction is settled.
     pub destination_asset_info: Option<AuctionDestinationAssetInfo>,
 }
 
@@ -121,9 +127,13 @@ impl AuctionInfo {
             .saturating_add(params.penalty_period.into())
     }
 
-    /// Compute amount in + security deposit.
+    /// Compute amount in + security deposit. If the fast fill is delivered in a destination asset,
+    /// the amount in is not deposited, so this is only the security deposit.
     pub fn total_deposit(&self) -> u64 {
-        self.amount_in.saturating_add(self.security_deposit)
+        match self.destination_asset_info {
+            Some(_) => self.security_deposit,
+            None => self.amount_in.saturating_add(self.security_deposit),
+        }
     }
 
     /// Determine whether the auction is still within its duration (using [Clock]).
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+
+/// Notional amount accumulated within the current window of the [CircuitBreaker].
+#[derive(
+    Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq,
+)]
+pub struct NotionalAccumulator {
+    /// Slot at which the current window started.
+    pub window_start_slot: u64,
+
+    /// Notional amount accumulated within the current window.
+    pub notional: u64,
+}
+
+impl NotionalAccumulator {
+    /// Notional amount accumulated within the window containing the current slot.
+    pub fn notional_at(&self, window_slots: u64, current_slot: u64) -> u64 {
+        if current_slot.saturating_sub(self.window_start_slot) >= window_slots {
+            0
+        } else {
+            self.notional
+        }
+    }
+
+    /// Adds the amount to the window containing the current slot, starting a new window if the
+    /// current one has elapsed.
+    pub fn add(&mut self, amount: u64, window_slots: u64, current_slot: u64) {
+        if current_slot.saturating_sub(self.window_start_slot) >= window_slots {
+            self.window_start_slot = current_slot;
+            self.notional = 0;
+        }
+        self.notional = self.notional.saturating_add(amount);
+    }
+}
+
+/// Caps the notional amount of inbound fast orders, both globally and per source chain. Once
+/// either threshold is exceeded, new auctions are refused until the window rolls over.
+#[derive(
+    Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq,
+)]
+pub struct CircuitBreakerConfig {
+    /// Number of slots in each window. Zero disables the circuit breaker.
+    pub window_slots: u64,
+
+    /// Notional amount across all source chains, which trips the breaker when exceeded.
+    pub global_threshold: u64,
+
+    /// Notional amount from any single source chain, which trips the breaker when exceeded.
+    pub chain_threshold: u64,
+}
+
+impl CircuitBreakerConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.window_slots != 0
+    }
+
+    /// Whether either the global or the source chain threshold has been exceeded within the
+    /// current window.
+    pub fn is_tripped(
+        &self,
+        global: &NotionalAccumulator,
+        chain: &NotionalAccumulator,
+        current_slot: u64,
+    ) -> bool {
+        self.is_enabled()
+            && (global.notional_at(self.window_slots, current_slot) > self.global_threshold
+                || chain.notional_at(self.window_slots, current_slot) > self.chain_threshold)
+    }
+
+    /// Records the amount against the global and source chain accumulators. Returns whether
+    /// recording this amount tripped the breaker.
+    pub fn record(
+        &self,
+        global: &mut NotionalAccumulator,
+        chain: &mut NotionalAccumulator,
+        amount: u64,
+        current_slot: u64,
+    ) -> bool {
+        if !self.is_enabled() {
+            return false;
+        }
+
+        let was_tripped = self.is_tripped(global, chain, current_slot);
+        global.add(amount, self.window_slots, current_slot);
+        chain.add(amount, self.window_slots, current_slot);
+
+        !was_tripped && self.is_tripped(global, chain, current_slot)
+    }
+}
+
+/// Notional amount of inbound fast orders across all source chains accumulated by the circuit
+/// breaker, whose configuration is stored in the [Custodian](super::Custodian).
+///
+/// NOTE: The per source chain accumulators are stored in each [InboundNotional](super::InboundNotional).
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CircuitBreaker {
+    pub bump: u8,
+    pub global: NotionalAccumulator,
+}
+
+impl CircuitBreaker {
+    pub const SEED_PREFIX: &'static [u8] = b"circuit-breaker";
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_circuit_breaker() {
+        let mut config = CircuitBreakerConfig {
+            window_slots: 0,
+            global_threshold: 100,
+            chain_threshold: 50,
+        };
+        let mut global = NotionalAccumulator::default();
+        let mut chain_a = NotionalAccumulator::default();
+        let mut chain_b = NotionalAccumulator::default();
+
+        // Disabled.
+        assert!(!config.record(&mut global, &mut chain_a, 1_000, 1));
+        assert!(!config.is_tripped(&global, &chain_a, 1));
+
+        config.window_slots = 10;
+
+        // Trip per-chain threshold.
+        assert!(!config.record(&mut global, &mut chain_a, 50, 1));
+        assert!(config.record(&mut global, &mut chain_a, 1, 2));
+        assert!(config.is_tripped(&global, &chain_a, 2));
+        assert!(!config.is_tripped(&global, &chain_b, 2));
+
+        // Trip global threshold.
+        assert!(!config.record(&mut global, &mut chain_b, 49, 3));
+        assert!(config.record(&mut global, &mut chain_b, 1, 4));
+        assert!(config.is_tripped(&global, &chain_b, 4));
+
+        // Already tripped.
+        assert!(!config.record(&mut global, &mut chain_b, 1, 5));
+
+        // Window rolls over.
+        assert!(!config.is_tripped(&global, &chain_a, 11));
+        assert!(!config.is_tripped(&global, &chain_b, 11));
+        assert!(!config.record(&mut global, &mut chain_a, 50, 11));
+        assert_eq!(global.window_start_slot, 11);
+        assert_eq!(global.notional, 50);
+    }
+}
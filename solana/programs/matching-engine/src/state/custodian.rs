@@ -1,6 +1,82 @@
+use crate::state::CircuitBreakerConfig;
 use anchor_lang::prelude::*;
 use common::admin;
 
+/// Stages of order processing that can be paused independently.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum PauseTarget {
+    /// Placing initial offers (starting auctions).
+    NewAuctions,
+
+    /// Improving offers on active auctions.
+    OfferImprovement,
+
+    /// Executing fast orders after auctions complete.
+    OrderExecution,
+
+    /// Creating fast fills for orders destined for Solana.
+    FastFillCreation,
+
+    /// Settling auctions (or orders without auctions) using finalized order responses.
+    Settlement,
+}
+
+#[derive(
+    Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq,
+)]
+pub struct PauseFlag {
+    pub paused: bool,
+
+    /// Slot at which the pause is lifted automatically. If none, the pause is in effect until it is
+    /// explicitly lifted.
+    pub unpause_slot: Option<u64>,
+
+    /// Arbitrary code describing why this flag was set, which is meant for off-chain reporting.
+    pub reason: u16,
+}
+
+impl PauseFlag {
+    pub fn is_active(&self, current_slot: u64) -> bool {
+        match self.unpause_slot {
+            Some(unpause_slot) => self.paused && current_slot < unpause_slot,
+            None => self.paused,
+        }
+    }
+}
+
+#[derive(
+    Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq,
+)]
+pub struct PauseFlags {
+    pub new_auctions: PauseFlag,
+    pub offer_improvement: PauseFlag,
+    pub order_execution: PauseFlag,
+    pub fast_fill_creation: PauseFlag,
+    pub settlement: PauseFlag,
+}
+
+impl PauseFlags {
+    pub fn get(&self, target: PauseTarget) -> &PauseFlag {
+        match target {
+            PauseTarget::NewAuctions => &self.new_auctions,
+            PauseTarget::OfferImprovement => &self.offer_improvement,
+            PauseTarget::OrderExecution => &self.order_execution,
+            PauseTarget::FastFillCreation => &self.fast_fill_creation,
+            PauseTarget::Settlement => &self.settlement,
+        }
+    }
+
+    pub fn get_mut(&mut self, target: PauseTarget) -> &mut PauseFlag {
+        match target {
+            PauseTarget::NewAuctions => &mut self.new_auctions,
+            PauseTarget::OfferImprovement => &mut self.offer_improvement,
+            PauseTarget::OrderExecution => &mut self.order_execution,
+            PauseTarget::FastFillCreation => &mut self.fast_fill_creation,
+            PauseTarget::Settlement => &mut self.settlement,
+        }
+    }
+}
+
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Custodian {
@@ -21,12 +97,43 @@ pub struct Custodian {
     pub auction_config_id: u32,
 
     pub next_proposal_id: u64,
+
+    /// Slot after which the pending owner can no longer confirm the ownership transfer request.
+    pub pending_owner_expiry_slot: u64,
+
+    /// Proposed assistant, who must accept the role before replacing the current assistant.
+    pub pending_owner_assistant: Option<Pubkey>,
+
+    /// Pause flags for each stage of order processing. These are independent of [paused], which
+    /// only pauses new auctions.
+    ///
+    /// [paused]: Self::paused
+    pub pause_flags: PauseFlags,
+
+    /// Circuit breaker thresholds for inbound fast orders. The notional amounts accumulated by the
+    /// circuit breaker are stored in the [CircuitBreaker](super::CircuitBreaker) and each
+    /// [InboundNotional](super::InboundNotional) account.
+    pub circuit_breaker: CircuitBreakerConfig,
+
+    /// Number of slots a router endpoint must be disabled for before it can be removed.
+    pub router_endpoint_removal_delay: u64,
+
+    /// Maximum gas drop-off (in lamports) a fast market order delivered to Solana can request.
+    /// Gas drop-offs are disabled when this value is zero.
+    pub max_gas_dropoff: u64,
 }
 
 impl Custodian {
     pub const SEED_PREFIX: &'static [u8] = b"emitter";
     pub const BUMP: u8 = crate::CUSTODIAN_BUMP;
     pub const SIGNER_SEEDS: &'static [&'static [u8]] = &[Self::SEED_PREFIX, &[Self::BUMP]];
+
+    /// Whether the specified stage is paused at the given slot. New auctions are also paused by the
+    /// program-wide [paused](Self::paused) flag.
+    pub fn is_paused(&self, target: PauseTarget, current_slot: u64) -> bool {
+        (target == PauseTarget::NewAuctions && self.paused)
+            || self.pause_flags.get(target).is_active(current_slot)
+    }
 }
 
 impl admin::Ownable for Custodian {
@@ -47,6 +154,14 @@ impl admin::PendingOwner for Custodian {
     fn pending_owner_mut(&mut self) -> &mut Option<Pubkey> {
         &mut self.pending_owner
     }
+
+    fn pending_owner_expiry_slot(&self) -> u64 {
+        self.pending_owner_expiry_slot
+    }
+
+    fn pending_owner_expiry_slot_mut(&mut self) -> &mut u64 {
+        &mut self.pending_owner_expiry_slot
+    }
 }
 
 impl admin::OwnerAssistant for Custodian {
@@ -59,12 +174,40 @@ impl admin::OwnerAssistant for Custodian {
     }
 }
 
+impl admin::PendingOwnerAssistant for Custodian {
+    fn pending_owner_assistant(&self) -> &Option<Pubkey> {
+        &self.pending_owner_assistant
+    }
+
+    fn pending_owner_assistant_mut(&mut self) -> &mut Option<Pubkey> {
+        &mut self.pending_owner_assistant
+    }
+}
+
 #[cfg(test)]
 mod test {
     use solana_program::pubkey::Pubkey;
 
     use super::*;
 
+    #[test]
+    fn test_pause_flag_auto_unpause() {
+        let flag = PauseFlag {
+            paused: true,
+            unpause_slot: Some(100),
+            reason: 1,
+        };
+        assert!(flag.is_active(99));
+        assert!(!flag.is_active(100));
+
+        let flag = PauseFlag {
+            unpause_slot: None,
+            ..flag
+        };
+        assert!(flag.is_active(u64::MAX));
+        assert!(!PauseFlag::default().is_active(0));
+    }
+
     #[test]
     fn test_bump() {
         let (custodian, bump) =
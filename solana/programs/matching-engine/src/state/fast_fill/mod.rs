@@ -49,23 +49,56 @@ pub struct FastFill {
 
     pub info: FastFillInfo,
     pub redeemer_message: Vec<u8>,
+
+    /// Mint of the fill amount.
+    pub mint: Pubkey,
+
+    /// If nonzero, the order sender opted into having the fill automatically delivered to the
+    /// redeemer's associated token account by a relayer, who earns this fee.
+    pub relayer_fee: u64,
+
+    /// Whether the fill amount is held in a token account owned by the [FastFill] instead of the
+    /// local custody token account, which is the case for fills delivered in a destination asset.
+    pub owns_custody_token: bool,
 }
 
 impl FastFill {
     pub const SEED_PREFIX: &'static [u8] = b"fast-fill";
 
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "integration-test")] {
+            // Arbitrary set for fast testing.
+            pub const EXPIRY_SECONDS: i64 = 8;
+        } else {
+            /// Number of seconds after the fill was issued when an unredeemed [FastFill] can be
+            /// reclaimed (90 days).
+            pub const EXPIRY_SECONDS: i64 = 7_776_000;
+        }
+    }
+
     pub(crate) fn compute_size(redeemer_message_len: usize) -> usize {
         const FIXED: usize = 8 // DISCRIMINATOR
             + FastFillSeeds::INIT_SPACE
             + 1 // redeemed
             + FastFillInfo::INIT_SPACE
             + 4 // redeemer_message_len
+            + 32 // mint
+            + 8 // relayer_fee
+            + 1 // owns_custody_token
         ;
 
         redeemer_message_len.saturating_add(FIXED)
     }
 
-    pub fn new(fill: Fill, sequence: u64, bump: u8, prepared_by: Pubkey, amount: u64) -> Self {
+    pub fn new(
+        fill: Fill,
+        sequence: u64,
+        bump: u8,
+        prepared_by: Pubkey,
+        amount: u64,
+        mint: Pubkey,
+        relayer_fee: u64,
+    ) -> Self {
         let Fill {
             source_chain,
             order_sender,
@@ -87,6 +120,46 @@ impl FastFill {
                 timestamp: Clock::get().unwrap().unix_timestamp,
             },
             redeemer_message: redeemer_message.into(),
+            mint,
+            relayer_fee,
+            owns_custody_token: Default::default(),
         }
     }
+
+    /// Whether the unredeemed fill can be reclaimed at the given unix timestamp.
+    pub fn is_expired(&self, unix_timestamp: i64) -> bool {
+        unix_timestamp.saturating_sub(self.info.timestamp) >= Self::EXPIRY_SECONDS
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn is_expired() {
+        let fast_fill = FastFill {
+            seeds: FastFillSeeds {
+                source_chain: 2,
+                order_sender: [1; 32],
+                sequence: 0,
+                bump: 255,
+            },
+            redeemed: false,
+            info: FastFillInfo {
+                prepared_by: Pubkey::new_unique(),
+                amount: 69,
+                redeemer: Pubkey::new_unique(),
+                timestamp: 1_000,
+            },
+            redeemer_message: Default::default(),
+            mint: common::USDC_MINT,
+            relayer_fee: 0,
+            owns_custody_token: false,
+        };
+
+        assert!(!fast_fill.is_expired(1_000));
+        assert!(!fast_fill.is_expired(1_000 + FastFill::EXPIRY_SECONDS - 1));
+        assert!(fast_fill.is_expired(1_000 + FastFill::EXPIRY_SECONDS));
+    }
 }
@@ -0,0 +1,21 @@
+use crate::state::NotionalAccumulator;
+use anchor_lang::prelude::*;
+
+/// Notional amounts of fast orders accepted from a router endpoint. These are kept separate from
+/// the [RouterEndpoint](super::RouterEndpoint) so that the endpoint is only read when starting
+/// auctions, and this account is only written to when a limit applies to the endpoint.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct InboundNotional {
+    pub bump: u8,
+
+    /// Notional amount accepted within the endpoint's current inbound limit window.
+    pub inbound_limit: NotionalAccumulator,
+
+    /// Notional amount accumulated by the circuit breaker for the endpoint's chain.
+    pub circuit_breaker: NotionalAccumulator,
+}
+
+impl InboundNotional {
+    pub const SEED_PREFIX: &'static [u8] = b"inbound-notional";
+}
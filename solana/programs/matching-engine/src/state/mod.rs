@@ -7,17 +7,35 @@ pub use auction_config::*;
 mod auction_history;
 pub use auction_history::*;
 
+mod circuit_breaker;
+pub use circuit_breaker::*;
+
 mod custodian;
 pub use custodian::*;
 
 mod fast_fill;
 pub use fast_fill::*;
 
+mod inbound_notional;
+pub use inbound_notional::*;
+
+mod owner_set;
+pub use owner_set::*;
+
 mod prepared_order_response;
 pub use prepared_order_response::*;
 
 mod proposal;
 pub use proposal::*;
 
+mod role_holder;
+pub use role_holder::*;
+
 pub(crate) mod router_endpoint;
 pub use router_endpoint::*;
+
+mod router_endpoint_registry;
+pub use router_endpoint_registry::*;
+
+mod supported_mint;
+pub use supported_mint::*;
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use common::admin;
+
+/// M-of-N set of members that can act as the program's owner. Once the [Custodian] owner is set to
+/// this account's address, owner-only instructions can only be invoked by executing an approved
+/// [OwnerSetTransaction].
+///
+/// [Custodian]: crate::state::Custodian
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct OwnerSet {
+    pub bump: u8,
+
+    /// Number of member approvals required to execute a transaction.
+    pub threshold: u8,
+
+    #[max_len(10)]
+    pub members: Vec<Pubkey>,
+
+    pub next_transaction_id: u64,
+}
+
+impl OwnerSet {
+    pub const SEED_PREFIX: &'static [u8] = b"owner-set";
+    pub const MAX_MEMBERS: usize = 10;
+}
+
+impl admin::OwnerSet for OwnerSet {
+    fn members(&self) -> &[Pubkey] {
+        &self.members
+    }
+
+    fn threshold(&self) -> u8 {
+        self.threshold
+    }
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub struct OwnerSetAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// Instruction for this program proposed by an [OwnerSet] member. Once enough members have
+/// approved it, anyone can execute it, where the owner set signs for the instruction.
+#[account]
+#[derive(Debug)]
+pub struct OwnerSetTransaction {
+    pub id: u64,
+    pub bump: u8,
+
+    /// Member who proposed (and paid for) this transaction.
+    pub proposed_by: Pubkey,
+
+    /// Members who have approved this transaction.
+    pub approvals: Vec<Pubkey>,
+
+    /// Accounts expected by the instruction, which must be passed as remaining accounts when this
+    /// transaction is executed.
+    pub accounts: Vec<OwnerSetAccountMeta>,
+
+    /// Encoded instruction data (including the Anchor discriminator).
+    pub data: Vec<u8>,
+}
+
+impl OwnerSetTransaction {
+    pub const SEED_PREFIX: &'static [u8] = b"owner-set-tx";
+
+    pub(crate) fn compute_size(num_accounts: usize, data_len: usize) -> usize {
+        const FIXED: usize = 8 // DISCRIMINATOR
+            + 8 // id
+            + 1 // bump
+            + 32 // proposed_by
+            + 4 + OwnerSet::MAX_MEMBERS * 32 // approvals
+            + 4 // accounts len
+            + 4 // data len
+        ;
+
+        num_accounts
+            .saturating_mul(OwnerSetAccountMeta::INIT_SPACE)
+            .saturating_add(data_len)
+            .saturating_add(FIXED)
+    }
+}
+
+impl admin::OwnerSetApprovals for OwnerSetTransaction {
+    fn approvals(&self) -> &[Pubkey] {
+        &self.approvals
+    }
+
+    fn approvals_mut(&mut self) -> &mut Vec<Pubkey> {
+        &mut self.approvals
+    }
+}
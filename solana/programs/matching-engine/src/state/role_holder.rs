@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use common::admin::{self, Role};
+
+/// Roles granted to a holder by the owner. See [Role] for what each role is permitted to do.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RoleHolder {
+    pub bump: u8,
+
+    pub holder: Pubkey,
+
+    #[max_len(3)]
+    pub roles: Vec<Role>,
+}
+
+impl RoleHolder {
+    pub const SEED_PREFIX: &'static [u8] = b"role-holder";
+}
+
+impl admin::Roles for RoleHolder {
+    fn holder(&self) -> &Pubkey {
+        &self.holder
+    }
+
+    fn roles(&self) -> &[Role] {
+        &self.roles
+    }
+
+    fn roles_mut(&mut self) -> &mut Vec<Role> {
+        &mut self.roles
+    }
+}
@@ -1,3 +1,4 @@
+use crate::state::{NotionalAccumulator, PauseFlag};
 use anchor_lang::prelude::*;
 
 /// Protocol used to transfer assets.
@@ -12,6 +13,15 @@ pub enum MessageProtocol {
         /// CCTP domain, which is how CCTP registers identifies foreign networks.
         domain: u32,
     },
+    /// Tokens to be transferred via the Wormhole Token Bridge protocol.
+    TokenBridge,
+    /// Tokens to be transferred via Native Token Transfers (NTT).
+    Ntt {
+        /// NTT manager program, which custodies or burns the transferred tokens.
+        manager: Pubkey,
+        /// Transceiver that releases the NTT manager's outbound transfers.
+        transceiver: Pubkey,
+    },
 }
 
 impl std::fmt::Display for MessageProtocol {
@@ -22,6 +32,15 @@ impl std::fmt::Display for MessageProtocol {
                 write!(f, "Local {{ program_id: {} }}", program_id)
             }
             MessageProtocol::Cctp { domain } => write!(f, "Cctp {{ domain: {} }}", domain),
+            MessageProtocol::TokenBridge => write!(f, "TokenBridge"),
+            MessageProtocol::Ntt {
+                manager,
+                transceiver,
+            } => write!(
+                f,
+                "Ntt {{ manager: {}, transceiver: {} }}",
+                manager, transceiver
+            ),
         }
     }
 }
@@ -42,12 +61,117 @@ pub struct EndpointInfo {
     pub protocol: MessageProtocol,
 }
 
+/// Optional limits on orders destined for an endpoint.
+#[derive(
+    Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq,
+)]
+pub struct EndpointLimits {
+    /// Max redeemer message length. This limit can only be more restrictive than the max enforced
+    /// by the Token Router.
+    pub max_redeemer_message_len: Option<u32>,
+
+    /// Minimum order amount.
+    pub min_order_amount: Option<u64>,
+
+    /// Maximum order amount.
+    pub max_order_amount: Option<u64>,
+}
+
+impl EndpointLimits {
+    pub fn exceeds_max_redeemer_message_len(&self, len: u32) -> bool {
+        self.max_redeemer_message_len
+            .is_some_and(|max_len| len > max_len)
+    }
+
+    pub fn is_below_min_order_amount(&self, amount: u64) -> bool {
+        self.min_order_amount
+            .is_some_and(|min_amount| amount < min_amount)
+    }
+
+    pub fn is_above_max_order_amount(&self, amount: u64) -> bool {
+        self.max_order_amount
+            .is_some_and(|max_amount| amount > max_amount)
+    }
+}
+
+/// Emitter address replaced by the most recent update, which is still accepted as a source of
+/// messages until the expiry slot.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub struct PreviousAddress {
+    pub address: [u8; 32],
+    pub expiry_slot: u64,
+}
+
+/// Cap on the notional amount of fast orders accepted from an endpoint within fixed windows of
+/// slots. The accepted amount is tracked by the endpoint's [InboundNotional](super::InboundNotional).
+#[derive(
+    Debug, Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq,
+)]
+pub struct InboundLimit {
+    /// Number of slots in each window. Zero means there is no limit.
+    pub window_slots: u64,
+
+    /// Maximum notional amount accepted within a window.
+    pub max_notional: u64,
+}
+
+impl InboundLimit {
+    pub fn is_enabled(&self) -> bool {
+        self.window_slots != 0
+    }
+
+    /// Adds the amount to the accumulator's current window. Returns false (without recording the
+    /// amount) if doing so would exceed the max notional.
+    pub fn try_record(
+        &self,
+        accumulator: &mut NotionalAccumulator,
+        amount: u64,
+        current_slot: u64,
+    ) -> bool {
+        if !self.is_enabled() {
+            return true;
+        }
+
+        match accumulator
+            .notional_at(self.window_slots, current_slot)
+            .checked_add(amount)
+        {
+            Some(total) if total <= self.max_notional => {
+                accumulator.add(amount, self.window_slots, current_slot);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
 #[account]
 #[derive(Debug, InitSpace)]
 /// Foreign emitter account data.
 pub struct RouterEndpoint {
     pub bump: u8,
     pub info: EndpointInfo,
+
+    /// Temporarily blocks orders to and from this endpoint without disabling it.
+    pub pause: PauseFlag,
+
+    /// Cap on the notional amount of fast orders accepted from this endpoint.
+    pub inbound_limit: InboundLimit,
+
+    /// Slot at which this endpoint was disabled. This endpoint can be removed once the router
+    /// endpoint removal delay has elapsed since this slot.
+    pub disabled_slot: Option<u64>,
+
+    /// Previous emitter address, which is accepted during a migration to a new address.
+    pub previous_address: Option<PreviousAddress>,
+
+    /// Limits on orders destined for this endpoint.
+    pub limits: EndpointLimits,
+
+    /// Mint of the asset transferred by orders from this endpoint, which is either USDC or a
+    /// [SupportedMint](super::SupportedMint). This mint is set when the endpoint is added and
+    /// cannot be changed by updating the endpoint.
+    pub mint: Pubkey,
 }
 
 impl std::ops::Deref for RouterEndpoint {
@@ -60,4 +184,94 @@ impl std::ops::Deref for RouterEndpoint {
 
 impl RouterEndpoint {
     pub const SEED_PREFIX: &'static [u8] = b"endpoint";
+
+    /// Whether messages emitted by the specified address are accepted from this endpoint, which is
+    /// either its current address or its previous address before the expiry slot.
+    pub fn is_source_address(&self, address: &[u8; 32], current_slot: u64) -> bool {
+        self.address == *address
+            || self.previous_address.is_some_and(|previous| {
+                previous.address == *address && current_slot < previous.expiry_slot
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_inbound_limit() {
+        let mut accumulator = NotionalAccumulator::default();
+        assert!(InboundLimit::default().try_record(&mut accumulator, u64::MAX, 1));
+        assert_eq!(accumulator.notional, 0);
+
+        // Setting the limit starts a new window.
+        let limit = InboundLimit {
+            window_slots: 10,
+            max_notional: 100,
+        };
+        let mut accumulator = NotionalAccumulator {
+            window_start_slot: 5,
+            notional: 0,
+        };
+        assert!(limit.try_record(&mut accumulator, 60, 5));
+        assert!(!limit.try_record(&mut accumulator, 41, 14));
+        assert!(limit.try_record(&mut accumulator, 40, 14));
+        assert!(!limit.try_record(&mut accumulator, 1, 14));
+
+        // New window.
+        assert!(limit.try_record(&mut accumulator, 100, 15));
+        assert_eq!(accumulator.window_start_slot, 15);
+        assert_eq!(accumulator.notional, 100);
+    }
+
+    #[test]
+    fn test_endpoint_limits() {
+        let limits = EndpointLimits::default();
+        assert!(!limits.exceeds_max_redeemer_message_len(u32::MAX));
+        assert!(!limits.is_below_min_order_amount(0));
+        assert!(!limits.is_above_max_order_amount(u64::MAX));
+
+        let limits = EndpointLimits {
+            max_redeemer_message_len: Some(100),
+            min_order_amount: Some(10),
+            max_order_amount: Some(1_000),
+        };
+        assert!(!limits.exceeds_max_redeemer_message_len(100));
+        assert!(limits.exceeds_max_redeemer_message_len(101));
+        assert!(!limits.is_below_min_order_amount(10));
+        assert!(limits.is_below_min_order_amount(9));
+        assert!(!limits.is_above_max_order_amount(1_000));
+        assert!(limits.is_above_max_order_amount(1_001));
+    }
+
+    #[test]
+    fn test_is_source_address() {
+        let mut endpoint = RouterEndpoint {
+            bump: Default::default(),
+            info: EndpointInfo {
+                chain: 2,
+                address: [1; 32],
+                mint_recipient: [1; 32],
+                protocol: MessageProtocol::Cctp { domain: 0 },
+            },
+            pause: Default::default(),
+            inbound_limit: Default::default(),
+            disabled_slot: None,
+            previous_address: None,
+            limits: Default::default(),
+            mint: Default::default(),
+        };
+        assert!(endpoint.is_source_address(&[1; 32], 0));
+        assert!(!endpoint.is_source_address(&[2; 32], 0));
+
+        endpoint.previous_address = Some(PreviousAddress {
+            address: [2; 32],
+            expiry_slot: 10,
+        });
+        assert!(endpoint.is_source_address(&[1; 32], 10));
+        assert!(endpoint.is_source_address(&[2; 32], 9));
+        assert!(!endpoint.is_source_address(&[2; 32], 10));
+        assert!(!endpoint.is_source_address(&[3; 32], 9));
+    }
 }
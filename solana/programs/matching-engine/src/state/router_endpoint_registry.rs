@@ -0,0 +1,106 @@
+use crate::{error::MatchingEngineError, state::MessageProtocol};
+use anchor_lang::prelude::*;
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct RegisteredRouterEndpoint {
+    pub chain: u16,
+    pub protocol: MessageProtocol,
+}
+
+/// Lists every configured router endpoint, ordered by chain ID, so that routes can be enumerated
+/// without scanning program accounts.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RouterEndpointRegistry {
+    pub bump: u8,
+
+    #[max_len(128)]
+    pub endpoints: Vec<RegisteredRouterEndpoint>,
+}
+
+impl RouterEndpointRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"endpoint-registry";
+
+    pub const MAX_ENDPOINTS: usize = 128;
+
+    /// Adds the endpoint to the registry or updates its protocol if it is already registered.
+    pub fn upsert(&mut self, chain: u16, protocol: MessageProtocol) -> Result<()> {
+        match self
+            .endpoints
+            .binary_search_by_key(&chain, |endpoint| endpoint.chain)
+        {
+            Ok(index) => {
+                self.endpoints[index].protocol = protocol;
+            }
+            Err(index) => {
+                require!(
+                    self.endpoints.len() < Self::MAX_ENDPOINTS,
+                    MatchingEngineError::RouterEndpointRegistryFull
+                );
+                self.endpoints
+                    .insert(index, RegisteredRouterEndpoint { chain, protocol });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the endpoint from the registry if it is registered.
+    pub fn remove(&mut self, chain: u16) {
+        if let Ok(index) = self
+            .endpoints
+            .binary_search_by_key(&chain, |endpoint| endpoint.chain)
+        {
+            self.endpoints.remove(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_upsert() {
+        let mut registry = RouterEndpointRegistry {
+            bump: Default::default(),
+            endpoints: Default::default(),
+        };
+
+        registry
+            .upsert(6, MessageProtocol::Cctp { domain: 1 })
+            .unwrap();
+        registry
+            .upsert(2, MessageProtocol::Cctp { domain: 0 })
+            .unwrap();
+        registry.upsert(6, MessageProtocol::None).unwrap();
+        assert_eq!(
+            registry.endpoints,
+            vec![
+                RegisteredRouterEndpoint {
+                    chain: 2,
+                    protocol: MessageProtocol::Cctp { domain: 0 },
+                },
+                RegisteredRouterEndpoint {
+                    chain: 6,
+                    protocol: MessageProtocol::None,
+                },
+            ]
+        );
+
+        for chain in (7..).take(RouterEndpointRegistry::MAX_ENDPOINTS.saturating_sub(2)) {
+            registry.upsert(chain, MessageProtocol::None).unwrap();
+        }
+        assert_eq!(
+            registry.endpoints.len(),
+            RouterEndpointRegistry::MAX_ENDPOINTS
+        );
+        assert!(registry.upsert(1, MessageProtocol::None).is_err());
+
+        registry.remove(2);
+        registry.remove(2);
+        assert_eq!(registry.endpoints[0].chain, 6);
+        registry.upsert(1, MessageProtocol::None).unwrap();
+        assert_eq!(registry.endpoints[0].chain, 1);
+    }
+}
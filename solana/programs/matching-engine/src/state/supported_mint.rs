@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Mint, other than USDC, whose orders can be auctioned and settled. USDC is always supported, so
+/// it never has one of these accounts.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct SupportedMint {
+    pub bump: u8,
+
+    pub mint: Pubkey,
+
+    /// Token account (whose mint is this mint) that collects fees from orders settled without an
+    /// auction.
+    pub fee_recipient_token: Pubkey,
+}
+
+impl SupportedMint {
+    pub const SEED_PREFIX: &'static [u8] = b"supported-mint";
+}
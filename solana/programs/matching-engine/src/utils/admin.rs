@@ -14,13 +14,18 @@ pub struct AddCctpRouterEndpointArgs {
     pub mint_recipient: Option<[u8; 32]>,
 }
 
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AddTokenBridgeRouterEndpointArgs {
+    pub chain: u16,
+    pub address: [u8; 32],
+    pub mint_recipient: Option<[u8; 32]>,
+}
+
 pub(crate) fn handle_add_cctp_router_endpoint(
     router_endpoint: &mut Account<RouterEndpoint>,
     args: AddCctpRouterEndpointArgs,
     router_endpoint_bump: Option<u8>,
 ) -> Result<()> {
-    let bump = router_endpoint_bump.unwrap_or_else(|| router_endpoint.bump);
-
     let AddCctpRouterEndpointArgs {
         chain,
         cctp_domain: domain,
@@ -28,6 +33,114 @@ pub(crate) fn handle_add_cctp_router_endpoint(
         mint_recipient,
     } = args;
 
+    handle_add_foreign_router_endpoint(
+        router_endpoint,
+        chain,
+        address,
+        mint_recipient,
+        MessageProtocol::Cctp { domain },
+        router_endpoint_bump,
+    )
+}
+
+pub(crate) fn handle_add_token_bridge_router_endpoint(
+    router_endpoint: &mut Account<RouterEndpoint>,
+    args: AddTokenBridgeRouterEndpointArgs,
+    router_endpoint_bump: Option<u8>,
+) -> Result<()> {
+    let AddTokenBridgeRouterEndpointArgs {
+        chain,
+        address,
+        mint_recipient,
+    } = args;
+
+    handle_add_foreign_router_endpoint(
+        router_endpoint,
+        chain,
+        address,
+        mint_recipient,
+        MessageProtocol::TokenBridge,
+        router_endpoint_bump,
+    )
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AddNttRouterEndpointArgs {
+    pub chain: u16,
+    pub address: [u8; 32],
+    pub mint_recipient: Option<[u8; 32]>,
+    pub manager: Pubkey,
+    pub transceiver: Pubkey,
+}
+
+pub(crate) fn handle_add_ntt_router_endpoint(
+    router_endpoint: &mut Account<RouterEndpoint>,
+    args: AddNttRouterEndpointArgs,
+    router_endpoint_bump: Option<u8>,
+) -> Result<()> {
+    let AddNttRouterEndpointArgs {
+        chain,
+        address,
+        mint_recipient,
+        manager,
+        transceiver,
+    } = args;
+
+    require!(
+        manager != Pubkey::default() && transceiver != Pubkey::default(),
+        MatchingEngineError::InvalidNttEndpoint
+    );
+
+    handle_add_foreign_router_endpoint(
+        router_endpoint,
+        chain,
+        address,
+        mint_recipient,
+        MessageProtocol::Ntt {
+            manager,
+            transceiver,
+        },
+        router_endpoint_bump,
+    )
+}
+
+/// Sets the previous address of an endpoint whose address is about to be updated. If an expiry slot
+/// is not specified, the previous address is no longer accepted.
+pub(crate) fn handle_update_previous_address(
+    router_endpoint: &mut Account<RouterEndpoint>,
+    new_address: [u8; 32],
+    previous_address_expiry_slot: Option<u64>,
+) -> Result<()> {
+    let previous_address = router_endpoint.address;
+    router_endpoint.previous_address = match previous_address_expiry_slot {
+        Some(expiry_slot) => {
+            require!(
+                previous_address != [0; 32]
+                    && new_address != previous_address
+                    && expiry_slot > Clock::get()?.slot,
+                MatchingEngineError::InvalidPreviousAddressExpiry
+            );
+            Some(PreviousAddress {
+                address: previous_address,
+                expiry_slot,
+            })
+        }
+        None => None,
+    };
+
+    Ok(())
+}
+
+fn handle_add_foreign_router_endpoint(
+    router_endpoint: &mut Account<RouterEndpoint>,
+    chain: u16,
+    address: [u8; 32],
+    mint_recipient: Option<[u8; 32]>,
+    protocol: MessageProtocol,
+    router_endpoint_bump: Option<u8>,
+) -> Result<()> {
+    let bump = router_endpoint_bump.unwrap_or_else(|| router_endpoint.bump);
+
     require!(
         chain != 0 && chain != SOLANA_CHAIN,
         MatchingEngineError::ChainNotAllowed
@@ -46,15 +159,16 @@ pub(crate) fn handle_add_cctp_router_endpoint(
         None => address,
     };
 
-    router_endpoint.set_inner(RouterEndpoint {
-        bump,
-        info: EndpointInfo {
-            chain,
-            address,
-            mint_recipient,
-            protocol: MessageProtocol::Cctp { domain },
-        },
-    });
+    // NOTE: Order limits, pause and throughput limit settings are preserved when an endpoint is
+    // updated.
+    router_endpoint.bump = bump;
+    router_endpoint.disabled_slot = None;
+    router_endpoint.info = EndpointInfo {
+        chain,
+        address,
+        mint_recipient,
+        protocol,
+    };
 
     // Done.
     Ok(())
@@ -69,17 +183,16 @@ pub(crate) fn handle_add_local_router_endpoint(
 ) -> Result<()> {
     let bump = router_endpoint_bump.unwrap_or_else(|| router_endpoint.bump);
 
-    router_endpoint.set_inner(RouterEndpoint {
-        bump,
-        info: EndpointInfo {
-            chain: SOLANA_CHAIN,
-            address: token_router_emitter.key().to_bytes(),
-            mint_recipient: token_router_custody_token.key().to_bytes(),
-            protocol: crate::state::MessageProtocol::Local {
-                program_id: token_router_program.key(),
-            },
+    router_endpoint.bump = bump;
+    router_endpoint.disabled_slot = None;
+    router_endpoint.info = EndpointInfo {
+        chain: SOLANA_CHAIN,
+        address: token_router_emitter.key().to_bytes(),
+        mint_recipient: token_router_custody_token.key().to_bytes(),
+        protocol: crate::state::MessageProtocol::Local {
+            program_id: token_router_program.key(),
         },
-    });
+    };
 
     Ok(())
 }
@@ -56,6 +56,13 @@ pub fn compute_min_allowed_offer(params: &AuctionParameters, info: &AuctionInfo)
         .saturating_sub(mul_bps_unsafe(info.offer_price, params.min_offer_delta_bps))
 }
 
+/// Compute the amount out of the destination asset that a new offer must exceed. This is the
+/// destination asset counterpart of [compute_min_allowed_offer].
+#[inline]
+pub fn compute_min_allowed_amount_out(params: &AuctionParameters, amount_out: u64) -> u64 {
+    amount_out.saturating_add(mul_bps_unsafe(amount_out, params.min_offer_delta_bps))
+}
+
 #[inline]
 pub fn compute_notional_security_deposit(params: &AuctionParameters, notional: u64) -> u64 {
     params
@@ -368,6 +375,25 @@ mod test {
         assert_eq!(allowed_offer, offer_price - 500000);
     }
 
+    #[test]
+    fn compute_min_amount_out_delta_zero() {
+        let mut params = params_for_test();
+        params.min_offer_delta_bps = 0;
+
+        let amount_out = 10000000;
+        let allowed_amount_out = compute_min_allowed_amount_out(&params, amount_out);
+        assert_eq!(allowed_amount_out, amount_out);
+    }
+
+    #[test]
+    fn compute_min_amount_out_delta_five_percent() {
+        let params = params_for_test();
+
+        let amount_out = 10000000;
+        let allowed_amount_out = compute_min_allowed_amount_out(&params, amount_out);
+        assert_eq!(allowed_amount_out, amount_out + 500000);
+    }
+
     fn set_up(
         security_deposit: u64,
         slots_elapsed: Option<u64>,
@@ -42,6 +42,17 @@ pub fn require_local_endpoint(endpoint: &RouterEndpoint) -> Result<bool> {
     Ok(true)
 }
 
+/// Returns the mint seed of a local custody token account, which is keyed by chain and mint. USDC
+/// local custody token accounts keep the seeds they were created with before other mints were
+/// supported (keyed only by chain), which is equivalent to an empty mint seed.
+pub fn local_custody_mint_seed(mint: &Pubkey) -> &[u8] {
+    if mint == &common::USDC_MINT {
+        &[]
+    } else {
+        mint.as_ref()
+    }
+}
+
 pub fn checked_deserialize_token_account(
     acc_info: &AccountInfo,
     expected_mint: &Pubkey,
@@ -0,0 +1,218 @@
+#![doc = include_str!("../README.md")]
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+declare_id!("MockAmm111111111111111111111111111111111111");
+
+const VAULT_SEED_PREFIX: &[u8] = b"vault";
+
+#[program]
+pub mod mock_amm {
+    use super::*;
+
+    /// This instruction creates a pool that swaps `mint_in` for `mint_out` at a fixed price, along
+    /// with the pool's vaults for each mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`               - `InitializePool` context.
+    /// * `price_numerator`   - Amount out per `price_denominator` amount in.
+    /// * `price_denominator` - Amount in per `price_numerator` amount out.
+    pub fn initialize_pool(
+        ctx: Context<InitializePool>,
+        price_numerator: u64,
+        price_denominator: u64,
+    ) -> Result<()> {
+        require!(price_denominator > 0, MockAmmError::InvalidPrice);
+
+        ctx.accounts.pool.set_inner(Pool {
+            bump: ctx.bumps.pool,
+            mint_in: ctx.accounts.mint_in.key(),
+            mint_out: ctx.accounts.mint_out.key(),
+            price_numerator,
+            price_denominator,
+        });
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction transfers `amount_in` from the source token account to the pool and
+    /// transfers the amount out at the pool's price to the destination token account.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`            - `Swap` context.
+    /// * `amount_in`      - Amount of `mint_in` to swap.
+    /// * `min_amount_out` - Minimum amount of `mint_out` the authority is willing to accept.
+    pub fn swap(ctx: Context<Swap>, amount_in: u64, min_amount_out: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+
+        let amount_out = u128::from(amount_in)
+            .checked_mul(pool.price_numerator.into())
+            .and_then(|value| value.checked_div(pool.price_denominator.into()))
+            .and_then(|value| u64::try_from(value).ok())
+            .ok_or_else(|| MockAmmError::InvalidPrice)?;
+        require!(
+            amount_out >= min_amount_out,
+            MockAmmError::InsufficientAmountOut
+        );
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.src_token.to_account_info(),
+                    to: ctx.accounts.vault_in.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            amount_in,
+        )?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.vault_out.to_account_info(),
+                    to: ctx.accounts.dst_token.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                &[&[
+                    Pool::SEED_PREFIX,
+                    pool.mint_in.as_ref(),
+                    pool.mint_out.as_ref(),
+                    &[pool.bump],
+                ]],
+            ),
+            amount_out,
+        )
+    }
+}
+
+#[error_code]
+pub enum MockAmmError {
+    InvalidPrice,
+    InsufficientAmountOut,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Pool {
+    pub bump: u8,
+    pub mint_in: Pubkey,
+    pub mint_out: Pubkey,
+    pub price_numerator: u64,
+    pub price_denominator: u64,
+}
+
+impl Pool {
+    pub const SEED_PREFIX: &'static [u8] = b"pool";
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    mint_in: Account<'info, token::Mint>,
+
+    mint_out: Account<'info, token::Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Pool::INIT_SPACE,
+        seeds = [
+            Pool::SEED_PREFIX,
+            mint_in.key().as_ref(),
+            mint_out.key().as_ref(),
+        ],
+        bump,
+    )]
+    pool: Account<'info, Pool>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint_in,
+        token::authority = pool,
+        seeds = [
+            VAULT_SEED_PREFIX,
+            pool.key().as_ref(),
+            mint_in.key().as_ref(),
+        ],
+        bump,
+    )]
+    vault_in: Account<'info, token::TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint_out,
+        token::authority = pool,
+        seeds = [
+            VAULT_SEED_PREFIX,
+            pool.key().as_ref(),
+            mint_out.key().as_ref(),
+        ],
+        bump,
+    )]
+    vault_out: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = pool.mint_in,
+    )]
+    src_token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = pool.mint_out,
+    )]
+    dst_token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        seeds = [
+            Pool::SEED_PREFIX,
+            pool.mint_in.as_ref(),
+            pool.mint_out.as_ref(),
+        ],
+        bump = pool.bump,
+    )]
+    pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED_PREFIX,
+            pool.key().as_ref(),
+            pool.mint_in.as_ref(),
+        ],
+        bump,
+    )]
+    vault_in: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED_PREFIX,
+            pool.key().as_ref(),
+            pool.mint_out.as_ref(),
+        ],
+        bump,
+    )]
+    vault_out: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
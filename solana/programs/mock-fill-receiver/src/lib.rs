@@ -0,0 +1,135 @@
+#![doc = include_str!("../README.md")]
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use token_router_sdk::callback::{self, VAULT_AUTHORITY_SEED_PREFIX};
+
+declare_id!("MockFi11Receiver111111111111111111111111111");
+
+#[program]
+pub mod mock_fill_receiver {
+    use super::*;
+
+    /// This instruction creates the receiver account and the vault token account for a mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - `Initialize` context.
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+        ctx.accounts.receiver.bump = ctx.bumps.receiver;
+
+        // Done.
+        Ok(())
+    }
+
+    /// This instruction is called by the Token Router when a fill is delivered to this program's
+    /// vault. The fill is recorded in the receiver account.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`              - `OnLiquidityLayerFill` context.
+    /// * `source_chain`     - Wormhole chain ID reflecting where the order was created.
+    /// * `order_sender`     - Universal address of the order sender.
+    /// * `amount`           - Amount delivered to the vault token account.
+    /// * `redeemer_message` - Message encoded by the order sender.
+    pub fn on_liquidity_layer_fill(
+        ctx: Context<OnLiquidityLayerFill>,
+        source_chain: u16,
+        order_sender: [u8; 32],
+        amount: u64,
+        redeemer_message: Vec<u8>,
+    ) -> Result<()> {
+        let receiver = &mut ctx.accounts.receiver;
+        receiver.num_fills = receiver.num_fills.saturating_add(1);
+        receiver.total_amount = receiver.total_amount.saturating_add(amount);
+        receiver.last_source_chain = source_chain;
+        receiver.last_order_sender = order_sender;
+        receiver.last_redeemer_message_len =
+            u32::try_from(redeemer_message.len()).unwrap_or_default();
+
+        // Done.
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Receiver {
+    pub bump: u8,
+    pub num_fills: u64,
+    pub total_amount: u64,
+    pub last_source_chain: u16,
+    pub last_order_sender: [u8; 32],
+    pub last_redeemer_message_len: u32,
+}
+
+impl Receiver {
+    pub const SEED_PREFIX: &'static [u8] = b"receiver";
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Receiver::INIT_SPACE,
+        seeds = [Receiver::SEED_PREFIX],
+        bump,
+    )]
+    receiver: Account<'info, Receiver>,
+
+    /// CHECK: Seeds must be \["liquidity-layer-vault"\].
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED_PREFIX],
+        bump,
+    )]
+    vault_authority: UncheckedAccount<'info>,
+
+    mint: Account<'info, token::Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = vault_authority,
+        seeds = [
+            VAULT_AUTHORITY_SEED_PREFIX,
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    vault_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OnLiquidityLayerFill<'info> {
+    #[account(address = callback::callback_authority())]
+    callback_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        token::authority = vault_authority,
+    )]
+    vault_token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [Receiver::SEED_PREFIX],
+        bump = receiver.bump,
+    )]
+    receiver: Account<'info, Receiver>,
+
+    /// CHECK: Seeds must be \["liquidity-layer-vault"\].
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED_PREFIX],
+        bump,
+    )]
+    vault_authority: UncheckedAccount<'info>,
+}
@@ -0,0 +1,112 @@
+#![doc = include_str!("../README.md")]
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+declare_id!("MockNttManager11111111111111111111111111111");
+
+const CUSTODY_SEED_PREFIX: &[u8] = b"custody";
+
+#[program]
+pub mod mock_ntt_manager {
+    use super::*;
+
+    /// This instruction locks tokens in custody and writes an outbox item, which would be released
+    /// to the recipient chain by the transceiver.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`  - `TransferLock` context.
+    /// * `args` - The `TransferArgs`.
+    pub fn transfer_lock(ctx: Context<TransferLock>, args: TransferArgs) -> Result<()> {
+        let TransferArgs {
+            amount,
+            recipient_chain,
+            recipient_address,
+            should_queue: _,
+        } = args;
+
+        ctx.accounts.outbox_item.set_inner(OutboxItem {
+            amount,
+            sender: ctx.accounts.sender.key(),
+            recipient_chain,
+            recipient_address,
+            transceiver: ctx.accounts.transceiver.key(),
+            released: false,
+        });
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.from.to_account_info(),
+                    to: ctx.accounts.custody.to_account_info(),
+                    authority: ctx.accounts.sender.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferArgs {
+    pub amount: u64,
+    pub recipient_chain: u16,
+    pub recipient_address: [u8; 32],
+    pub should_queue: bool,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct OutboxItem {
+    pub amount: u64,
+    pub sender: Pubkey,
+    pub recipient_chain: u16,
+    pub recipient_address: [u8; 32],
+    pub transceiver: Pubkey,
+    pub released: bool,
+}
+
+#[derive(Accounts)]
+pub struct TransferLock<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    sender: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    from: Account<'info, token::TokenAccount>,
+
+    mint: Account<'info, token::Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + OutboxItem::INIT_SPACE,
+    )]
+    outbox_item: Account<'info, OutboxItem>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        token::mint = mint,
+        token::authority = custody,
+        seeds = [
+            CUSTODY_SEED_PREFIX,
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    custody: Account<'info, token::TokenAccount>,
+
+    /// CHECK: This account is recorded in the outbox item.
+    transceiver: UncheckedAccount<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
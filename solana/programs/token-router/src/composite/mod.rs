@@ -1,14 +1,24 @@
 use std::ops::Deref;
 
-use crate::{error::TokenRouterError, state::Custodian};
+use crate::{
+    error::TokenRouterError,
+    state::{Custodian, OwnerSet, RoleHolder},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::{
-    admin::utils::{assistant::only_authorized, ownable::only_owner},
+    admin::{
+        utils::{
+            assistant::only_authorized, ownable::only_owner, owner_set::only_owner_set_member,
+            roles::only_authorized_or_role,
+        },
+        Role,
+    },
     messages::raw::LiquidityLayerMessage,
+    token_bridge::TokenBridgeMessage,
     wormhole_cctp_solana::wormhole::VaaAccount,
 };
-use matching_engine::state::RouterEndpoint;
+use matching_engine::state::{RouterEndpoint, SupportedMint};
 
 #[derive(Accounts)]
 pub struct Usdc<'info> {
@@ -25,18 +35,58 @@ impl<'info> Deref for Usdc<'info> {
     }
 }
 
+/// Mint that orders can be placed and redeemed with. USDC is always supported. Any other mint must
+/// have a [SupportedMint] account governed by the Matching Engine.
+#[derive(Accounts)]
+pub struct CheckedMint<'info> {
+    #[account(
+        constraint = {
+            mint.key() == common::USDC_MINT || supported_mint.is_some()
+        } @ TokenRouterError::UnsupportedMint,
+    )]
+    pub mint: Box<Account<'info, token::Mint>>,
+
+    /// Only required if the mint is not USDC.
+    #[account(
+        seeds = [
+            SupportedMint::SEED_PREFIX,
+            mint.key().as_ref(),
+        ],
+        bump = supported_mint.bump,
+        seeds::program = matching_engine::id(),
+    )]
+    pub supported_mint: Option<Box<Account<'info, SupportedMint>>>,
+}
+
+impl<'info> Deref for CheckedMint<'info> {
+    type Target = Account<'info, token::Mint>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.mint
+    }
+}
+
 /// Mint recipient token account, which is encoded as the mint recipient in the CCTP message.
 /// The CCTP Token Messenger Minter program will transfer the amount encoded in the CCTP message
 /// from its custody account to this account.
 ///
-/// CHECK: Mutable. Seeds must be \["custody"\].
+/// CHECK: Mutable. Must be the custodian's associated token account for its mint. Associated token
+/// accounts for mints other than USDC can be created by anyone.
 ///
 /// NOTE: This account must be encoded as the mint recipient in the CCTP message.
 #[derive(Accounts)]
 pub struct CctpMintRecipientMut<'info> {
     #[account(
         mut,
-        address = crate::CCTP_MINT_RECIPIENT
+        constraint = {
+            mint_recipient.key() == crate::CCTP_MINT_RECIPIENT
+                || mint_recipient.key()
+                    == anchor_spl::associated_token::get_associated_token_address(
+                        &Pubkey::create_program_address(Custodian::SIGNER_SEEDS, &crate::id())
+                            .unwrap(),
+                        &mint_recipient.mint,
+                    )
+        } @ TokenRouterError::InvalidMintRecipient,
     )]
     pub mint_recipient: Box<Account<'info, token::TokenAccount>>,
 }
@@ -81,6 +131,43 @@ impl<'info> Deref for LiquidityLayerVaa<'info> {
     }
 }
 
+#[derive(Accounts)]
+pub struct TokenBridgeVaa<'info> {
+    /// CHECK: This VAA account must be a posted VAA from the Wormhole Core Bridge program.
+    #[account(
+        constraint = {
+            // NOTE: This load performs an owner check.
+            let vaa = VaaAccount::load(&vaa)?;
+
+            // Is it a Token Bridge transfer with message?
+            let msg = TokenBridgeMessage::try_from(vaa.payload())
+                .map_err(|_| TokenRouterError::InvalidVaa)?;
+            require!(
+                msg.transfer_with_message().is_some(),
+                TokenRouterError::InvalidVaa
+            );
+
+            // Done.
+            true
+        }
+    )]
+    pub vaa: UncheckedAccount<'info>,
+}
+
+impl<'info> TokenBridgeVaa<'info> {
+    pub fn load_unchecked(&self) -> VaaAccount<'_> {
+        VaaAccount::load_unchecked(self)
+    }
+}
+
+impl<'info> Deref for TokenBridgeVaa<'info> {
+    type Target = UncheckedAccount<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vaa
+    }
+}
+
 #[derive(Accounts)]
 pub struct CheckedCustodian<'info> {
     #[account(
@@ -164,6 +251,107 @@ pub struct AdminMut<'info> {
     pub custodian: Box<Account<'info, Custodian>>,
 }
 
+/// Either the owner, owner assistant or a holder of the [Pauser](Role::Pauser) role.
+#[derive(Accounts)]
+pub struct PauserMut<'info> {
+    #[account(
+        constraint = only_authorized_or_role(
+            &custodian,
+            role_holder.as_deref(),
+            &authority,
+            Role::Pauser,
+            error!(TokenRouterError::OwnerAssistantOrRoleOnly)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    pub custodian: Box<Account<'info, Custodian>>,
+
+    /// Only required if the authority is neither the owner nor the owner assistant.
+    #[account(
+        seeds = [
+            RoleHolder::SEED_PREFIX,
+            authority.key().as_ref(),
+        ],
+        bump = role_holder.bump,
+    )]
+    pub role_holder: Option<Box<Account<'info, RoleHolder>>>,
+}
+
+/// Either the owner, owner assistant or a holder of the [FeeManager](Role::FeeManager) role.
+#[derive(Accounts)]
+pub struct FeeManagerMut<'info> {
+    #[account(
+        constraint = only_authorized_or_role(
+            &custodian,
+            role_holder.as_deref(),
+            &authority,
+            Role::FeeManager,
+            error!(TokenRouterError::OwnerAssistantOrRoleOnly)
+        )?
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    pub custodian: Box<Account<'info, Custodian>>,
+
+    /// Only required if the authority is neither the owner nor the owner assistant.
+    #[account(
+        seeds = [
+            RoleHolder::SEED_PREFIX,
+            authority.key().as_ref(),
+        ],
+        bump = role_holder.bump,
+    )]
+    pub role_holder: Option<Box<Account<'info, RoleHolder>>>,
+}
+
+#[derive(Accounts)]
+pub struct OwnerSetMember<'info> {
+    #[account(
+        constraint = only_owner_set_member(
+            &owner_set,
+            &member,
+            error!(TokenRouterError::OwnerSetMemberOnly)
+        )?
+    )]
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [OwnerSet::SEED_PREFIX],
+        bump = owner_set.bump,
+    )]
+    pub owner_set: Box<Account<'info, OwnerSet>>,
+}
+
+#[derive(Accounts)]
+pub struct OwnerSetMemberMut<'info> {
+    #[account(
+        constraint = only_owner_set_member(
+            &owner_set,
+            &member,
+            error!(TokenRouterError::OwnerSetMemberOnly)
+        )?
+    )]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [OwnerSet::SEED_PREFIX],
+        bump = owner_set.bump,
+    )]
+    pub owner_set: Box<Account<'info, OwnerSet>>,
+}
+
 /// Registered router endpoint representing a foreign Token Router. This account may have a CCTP
 /// domain encoded if this route is CCTP-enabled. For this instruction, it is required that
 /// [RouterEndpoint::cctp_domain] is `Some(value)`.
@@ -183,6 +371,10 @@ pub struct RegisteredEndpoint<'info> {
                 endpoint.protocol != matching_engine::state::MessageProtocol::None,
                 TokenRouterError::EndpointDisabled
             );
+            require!(
+                !endpoint.pause.is_active(Clock::get()?.slot),
+                TokenRouterError::EndpointPaused
+            );
 
             true
         }
@@ -2,6 +2,7 @@
 pub enum TokenRouterError {
     OwnerOnly = 0x2,
     OwnerOrAssistantOnly = 0x4,
+    OwnerAssistantOrRoleOnly = 0x6,
 
     U64Overflow = 0x10,
 
@@ -12,15 +13,21 @@ pub enum TokenRouterError {
     InvalidDepositPayloadId = 0x48,
     RedeemerMessageTooLarge = 0x4e,
 
+    UnsupportedMint = 0x52,
+
     InvalidSourceRouter = 0x60,
     InvalidTargetRouter = 0x62,
     EndpointDisabled = 0x64,
     InvalidCctpEndpoint = 0x66,
+    EndpointPaused = 0x68,
 
     Paused = 0x80,
 
     AssistantZeroPubkey = 0x100,
     ImmutableProgram = 0x102,
+    RoleHolderZeroPubkey = 0x103,
+    #[msg("Value exceeds 1000000")]
+    MaxIntegratorFeeBpsTooLarge = 0x104,
 
     InvalidNewOwner = 0x202,
     AlreadyOwner = 0x204,
@@ -28,9 +35,59 @@ pub enum TokenRouterError {
     NotPendingOwner = 0x208,
     EitherSenderOrProgramTransferAuthority = 0x20a,
     DelegatedAmountMismatch = 0x20c,
+    OwnershipTransferRequestExpired = 0x20e,
+    InvalidOwnerSet = 0x210,
+    OwnerSetMemberOnly = 0x212,
+    OwnerSetTransactionAlreadyApproved = 0x214,
+    OwnerSetThresholdNotMet = 0x216,
+    InvalidOwnerSetTransaction = 0x218,
+    RoleAlreadyGranted = 0x220,
+    RoleNotGranted = 0x222,
+    NoPendingOwnerAssistant = 0x224,
+    NotPendingOwnerAssistant = 0x226,
+
+    InvalidMintRecipient = 0x242,
 
     InsufficientAmount = 0x400,
     MinAmountOutTooHigh = 0x402,
     InvalidRedeemer = 0x404,
     PreparedFillTooLarge = 0x406,
+    OrderAmountBelowMinimum = 0x408,
+    OrderAmountAboveMaximum = 0x40a,
+    InvalidSwapProgram = 0x40c,
+    SwapAmountOutTooLow = 0x40e,
+    SwapAmountInRemaining = 0x410,
+    OrderExpired = 0x412,
+    OrderConditionsNotMet = 0x414,
+    InvalidSchedule = 0x416,
+    ScheduledSliceNotDue = 0x418,
+    OrderNotExpired = 0x41a,
+    IntegratorFeeTooHigh = 0x41c,
+    IntegratorFeeTokenRequired = 0x41e,
+    FastFillNotRelayable = 0x420,
+    InvalidLimitOrderAuction = 0x422,
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(clippy::panic)]
+
+    use crate::FEE_PRECISION_MAX;
+    use anchor_lang::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_max_integrator_fee_bps_too_large() {
+        match error!(TokenRouterError::MaxIntegratorFeeBpsTooLarge) {
+            Error::AnchorError(error) => {
+                assert_eq!(error.error_code_number, 6000 + 0x104);
+                assert_eq!(
+                    error.error_msg,
+                    format!("Value exceeds {FEE_PRECISION_MAX}")
+                );
+            }
+            _ => panic!(),
+        }
+    }
 }
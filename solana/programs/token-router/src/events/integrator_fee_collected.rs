@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct IntegratorFeeCollected {
+    pub prepared_order: Pubkey,
+    pub integrator_fee_token: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,5 @@
+mod integrator_fee_collected;
+pub use integrator_fee_collected::*;
+
+mod prepared_fill_consumed;
+pub use prepared_fill_consumed::*;
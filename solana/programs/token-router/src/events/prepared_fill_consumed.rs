@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[event]
+#[derive(Debug)]
+pub struct PreparedFillConsumed {
+    pub prepared_fill: Pubkey,
+    pub dst_token: Pubkey,
+    pub amount_consumed: u64,
+    pub amount_remaining: u64,
+}
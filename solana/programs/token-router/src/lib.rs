@@ -5,13 +5,19 @@ mod composite;
 
 mod error;
 
+mod events;
+
 mod processor;
-pub use processor::PrepareMarketOrderArgs;
 use processor::*;
+pub use processor::{
+    IntegratorFee, PrepareLimitOrderArgs, PrepareMarketOrderArgs, PrepareMarketOrderV2Args,
+    PrepareScheduledOrderArgs, RedeemAndSwapArgs,
+};
 
 pub mod state;
 
 use anchor_lang::{prelude::*, solana_program::pubkey};
+use common::admin::Role;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "mainnet")] {
@@ -34,6 +40,8 @@ cfg_if::cfg_if! {
 
 const PREPARED_CUSTODY_TOKEN_SEED_PREFIX: &[u8] = b"prepared-custody";
 const MAX_REDEEMER_MESSAGE_SIZE: usize = 500;
+const FEE_PRECISION_MAX: u32 = 1_000_000;
+const OWNERSHIP_TRANSFER_REQUEST_EXPIRATION: u64 = 432_000; // slots (roughly 2 days)
 
 #[program]
 pub mod token_router {
@@ -51,7 +59,8 @@ pub mod token_router {
 
     /// This instruction sets the `pending_owner` field in the `Custodian` account. This instruction
     /// can only be called by the `owner`. The `pending_owner` address must be valid, meaning it
-    /// cannot be the zero address or the current owner.
+    /// cannot be the zero address or the current owner. The request expires if it is not confirmed
+    /// within `OWNERSHIP_TRANSFER_REQUEST_EXPIRATION` slots.
     /// # Arguments
     ///
     /// * `ctx` - `SubmitOwnershipTransferRequest` context.
@@ -63,7 +72,8 @@ pub mod token_router {
 
     /// This instruction confirms the ownership transfer request and sets the new `owner` in the
     /// `Custodian` account. This instruction can only be called by the `pending_owner`. The
-    /// `pending_owner` must be the same as the `pending_owner` in the `Custodian` account.
+    /// `pending_owner` must be the same as the `pending_owner` in the `Custodian` account, and the
+    /// request must not have expired.
     /// # Arguments
     ///
     /// * `ctx` - `ConfirmOwnershipTransferRequest` context.
@@ -84,8 +94,9 @@ pub mod token_router {
         processor::cancel_ownership_transfer_request(ctx)
     }
 
-    /// This instruction is used to update the `owner_assistant` field in the `Custodian` account. This
-    /// instruction can only be called by the `owner`.
+    /// This instruction is used to propose a new `owner_assistant`, which is set as the
+    /// `pending_owner_assistant` in the `Custodian` account. The new assistant must accept the role
+    /// using `accept_owner_assistant`. This instruction can only be called by the `owner`.
     /// # Arguments
     ///
     /// * `ctx` - `UpdateOwnerAssistant` context.
@@ -93,8 +104,115 @@ pub mod token_router {
         processor::update_owner_assistant(ctx)
     }
 
-    /// This instruction is used to pause or unpause further processing of new transfer. Only the `owner`
-    /// or `owner_assistant` can pause the program.
+    /// This instruction is used to accept the owner assistant role, replacing the `owner_assistant`
+    /// with the `pending_owner_assistant` in the `Custodian` account. This instruction can only be
+    /// called by the `pending_owner_assistant`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `AcceptOwnerAssistant` context.
+    pub fn accept_owner_assistant(ctx: Context<AcceptOwnerAssistant>) -> Result<()> {
+        processor::accept_owner_assistant(ctx)
+    }
+
+    /// This instruction is used to grant a role to a holder, creating the holder's `RoleHolder`
+    /// account if it does not exist. This instruction can only be called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `GrantRole` context.
+    /// * `role` - The `Role` to grant.
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+        processor::grant_role(ctx, role)
+    }
+
+    /// This instruction is used to revoke a role from a holder. This instruction can only be called
+    /// by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `RevokeRole` context.
+    /// * `role` - The `Role` to revoke.
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+        processor::revoke_role(ctx, role)
+    }
+
+    /// This instruction is used to create the program's `OwnerSet` account, which holds M-of-N
+    /// members who can collectively act as the program's owner. The owner set only takes control
+    /// once ownership is transferred to the `OwnerSet` address. This instruction can only be called
+    /// by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `InitializeOwnerSet` context.
+    /// * `args` - The `OwnerSetArgs`, which has the threshold and members.
+    pub fn initialize_owner_set(
+        ctx: Context<InitializeOwnerSet>,
+        args: OwnerSetArgs,
+    ) -> Result<()> {
+        processor::initialize_owner_set(ctx, args)
+    }
+
+    /// This instruction is used to replace the threshold and members of the `OwnerSet` account.
+    /// This instruction can only be called by the `owner`, which is the owner set itself if
+    /// ownership has been transferred to it.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `UpdateOwnerSet` context.
+    /// * `args` - The `OwnerSetArgs`, which has the threshold and members.
+    ///
+    /// Pending `OwnerSetTransaction` accounts may be passed as remaining accounts, which drops
+    /// approvals from members who are no longer in the set.
+    pub fn update_owner_set<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateOwnerSet<'info>>,
+        args: OwnerSetArgs,
+    ) -> Result<()> {
+        processor::update_owner_set(ctx, args)
+    }
+
+    /// This instruction is used by an owner set member to propose an instruction for this program,
+    /// which the owner set will sign for once enough members have approved it. The proposer's
+    /// approval is recorded.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `ProposeOwnerSetTransaction` context.
+    /// * `args` - The `OwnerSetTransactionArgs`, which has the instruction's accounts and data.
+    pub fn propose_owner_set_transaction(
+        ctx: Context<ProposeOwnerSetTransaction>,
+        args: OwnerSetTransactionArgs,
+    ) -> Result<()> {
+        processor::propose_owner_set_transaction(ctx, args)
+    }
+
+    /// This instruction is used by an owner set member to approve a proposed owner set
+    /// transaction.
+    /// # Arguments
+    ///
+    /// * `ctx` - `ApproveOwnerSetTransaction` context.
+    pub fn approve_owner_set_transaction(ctx: Context<ApproveOwnerSetTransaction>) -> Result<()> {
+        processor::approve_owner_set_transaction(ctx)
+    }
+
+    /// This instruction is used to execute an owner set transaction once the threshold of member
+    /// approvals has been met. The transaction's accounts must be passed as remaining accounts. The
+    /// transaction account is closed and its lamports are returned to the proposer. This
+    /// instruction can be called by anyone.
+    /// # Arguments
+    ///
+    /// * `ctx` - `ExecuteOwnerSetTransaction` context.
+    pub fn execute_owner_set_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteOwnerSetTransaction<'info>>,
+    ) -> Result<()> {
+        processor::execute_owner_set_transaction(ctx)
+    }
+
+    /// This instruction is used to close a proposed owner set transaction without executing it.
+    /// This instruction can only be called by the member who proposed the transaction.
+    /// # Arguments
+    ///
+    /// * `ctx` - `CancelOwnerSetTransaction` context.
+    pub fn cancel_owner_set_transaction(ctx: Context<CancelOwnerSetTransaction>) -> Result<()> {
+        processor::cancel_owner_set_transaction(ctx)
+    }
+
+    /// This instruction is used to pause or unpause further processing of new transfer. Only the `owner`,
+    /// `owner_assistant` or a `Pauser` role holder can pause the program.
     /// # Arguments
     ///
     /// * `ctx`   - `SetPause` context.
@@ -103,8 +221,42 @@ pub mod token_router {
         processor::set_pause(ctx, paused)
     }
 
+    /// This instruction is used to allow prepared fills to be swapped with a swap program using
+    /// `redeem_and_swap`. This instruction can only be called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `AddSwapProgram` context.
+    pub fn add_swap_program(ctx: Context<AddSwapProgram>) -> Result<()> {
+        processor::add_swap_program(ctx)
+    }
+
+    /// This instruction is used to disallow a swap program by closing its `SwapProgram` account.
+    /// This instruction can only be called by the `owner`.
+    /// # Arguments
+    ///
+    /// * `ctx` - `RemoveSwapProgram` context.
+    pub fn remove_swap_program(ctx: Context<RemoveSwapProgram>) -> Result<()> {
+        processor::remove_swap_program(ctx)
+    }
+
+    /// This instruction is used to update the maximum fee an integrator can collect from an
+    /// order's amount in, where `1_000_000` is 100%. This instruction can only be called by the
+    /// `owner`, `owner_assistant` or a `FeeManager` role holder.
+    /// # Arguments
+    ///
+    /// * `ctx`         - `UpdateMaxIntegratorFeeBps` context.
+    /// * `max_fee_bps` - New maximum integrator fee.
+    pub fn update_max_integrator_fee_bps(
+        ctx: Context<UpdateMaxIntegratorFeeBps>,
+        max_fee_bps: u32,
+    ) -> Result<()> {
+        processor::update_max_integrator_fee_bps(ctx, max_fee_bps)
+    }
+
     /// This instruction is used for executing logic during an upgrade. This instruction can only be
-    /// called by the `upgrade_manager_program`.
+    /// called by the `upgrade_manager_program`. If the `Custodian` account still has its legacy
+    /// layout, it is reallocated (with rent paid by the `payer`) and rewritten with new fields set to
+    /// their defaults.
     /// # Arguments
     ///
     /// * `ctx` - `Migrate` context.
@@ -113,8 +265,9 @@ pub mod token_router {
     }
 
     /// This instruction is used to prepare a `PrepareOrder` account for a market order. The `amount_in`
-    /// is transferred from the `source` account to the `prepared_custody_token` account. Anyone
-    /// can call this instruction.
+    /// is transferred from the `source` account to the `prepared_custody_token` account. Orders
+    /// prepared with this instruction do not expire and do not pay an integrator fee (see
+    /// `prepare_market_order_v2`). Anyone can call this instruction.
     /// # Arguments
     ///
     /// * `ctx` - `PrepareMarketOrder` context.
@@ -126,6 +279,58 @@ pub mod token_router {
         processor::prepare_market_order(ctx, args)
     }
 
+    /// This instruction is used to prepare a `PrepareOrder` account for a market order like
+    /// `prepare_market_order`. But instead of being created from an arbitrary keypair, the
+    /// `prepared_order` account is a PDA derived from the order sender and the nonce tracked in
+    /// the order sender's `SenderState` account, which is incremented. The order may also be given
+    /// an expiry, after which anyone can refund it with `refund_expired_prepared_order`, and an
+    /// integrator fee, which is transferred to the `integrator_fee_token` account. Anyone can call
+    /// this instruction.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `PrepareMarketOrderV2` context.
+    /// * `args` - `PrepareMarketOrderV2Args` struct, see `prepare_v2.rs` for more info.
+    pub fn prepare_market_order_v2(
+        ctx: Context<PrepareMarketOrderV2>,
+        args: PrepareMarketOrderV2Args,
+    ) -> Result<()> {
+        processor::prepare_market_order_v2(ctx, args)
+    }
+
+    /// This instruction is used to prepare a `PrepareOrder` account for a limit order. The
+    /// `amount_in` is transferred from the `source` account to the `prepared_custody_token` account
+    /// like a market order. But the order can only be placed (by anyone) with
+    /// `place_limit_order_cctp` if it has not expired and its Matching Engine `auction` has
+    /// completed with an offer price at or below `max_offer_price`. Anyone can call this
+    /// instruction.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `PrepareLimitOrder` context.
+    /// * `args` - `PrepareLimitOrderArgs` struct, see `limit_order/prepare.rs` for more info.
+    pub fn prepare_limit_order(
+        ctx: Context<PrepareLimitOrder>,
+        args: PrepareLimitOrderArgs,
+    ) -> Result<()> {
+        processor::prepare_limit_order(ctx, args)
+    }
+
+    /// This instruction is used to prepare a `PrepareOrder` account for a scheduled order. The
+    /// `amount_in` is transferred from the `source` account to the `prepared_custody_token` account
+    /// like a market order. But the order is placed in `num_slices` slices, one every
+    /// `slice_interval` slots, where anyone who places a slice is rewarded with `keeper_reward`.
+    /// Anyone can call this instruction.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `PrepareScheduledOrder` context.
+    /// * `args` - `PrepareScheduledOrderArgs` struct, see `scheduled_order/prepare.rs` for more
+    ///            info.
+    pub fn prepare_scheduled_order(
+        ctx: Context<PrepareScheduledOrder>,
+        args: PrepareScheduledOrderArgs,
+    ) -> Result<()> {
+        processor::prepare_scheduled_order(ctx, args)
+    }
+
     /// This instruction is used to close a `PreparedOrder` account. This allows users to cancel
     /// an outbound transfer in case the order is no longer needed, or they made a mistake
     /// in the order. The `prepared_custody_token` account is closed and the tokens are refunded
@@ -137,6 +342,17 @@ pub mod token_router {
         processor::close_prepared_order(ctx)
     }
 
+    /// This instruction is used to close a `PreparedOrder` account whose expiry has passed. The
+    /// `prepared_custody_token` account is closed and the tokens are refunded to the
+    /// `refund_token` account, where the rent goes to the `prepared_by` account. Anyone can call
+    /// this instruction.
+    /// # Arguments
+    ///
+    /// * `ctx` - `RefundExpiredPreparedOrder` context.
+    pub fn refund_expired_prepared_order(ctx: Context<RefundExpiredPreparedOrder>) -> Result<()> {
+        processor::refund_expired_prepared_order(ctx)
+    }
+
     /// This instruction is used to place a `MarketOrder`. This order type transfers tokens
     /// from Solana to another registered Token Router endpoint on a different chain. This
     /// instruction requires a `prepared_market_order` account to be present. Note: this
@@ -149,6 +365,34 @@ pub mod token_router {
         processor::place_market_order_cctp(ctx)
     }
 
+    /// This instruction is used to place a limit order once its auction has completed at or below
+    /// its limit price. The caller (e.g. a keeper) collects `keeper_fee` from the amount in, which
+    /// cannot exceed the order's maximum keeper fee. The remaining amount cannot be less than the
+    /// order's minimum amount out.
+    /// # Arguments
+    ///
+    /// * `ctx`        - `PlaceLimitOrderCctp` context.
+    /// * `keeper_fee` - Fee deducted from the amount in and transferred to the `keeper_token`.
+    pub fn place_limit_order_cctp(
+        ctx: Context<PlaceLimitOrderCctp>,
+        keeper_fee: u64,
+    ) -> Result<()> {
+        processor::place_limit_order_cctp(ctx, keeper_fee)
+    }
+
+    /// This instruction is used to place the next slice of a scheduled order once it is due. Each
+    /// slice transfers tokens from Solana to another registered Token Router endpoint on a
+    /// different chain and rewards the caller with the order's keeper reward. The
+    /// `prepared_order` account is closed once the last slice is placed.
+    /// # Arguments
+    ///
+    /// * `ctx` - `PlaceScheduledOrderSliceCctp` context.
+    pub fn place_scheduled_order_slice_cctp(
+        ctx: Context<PlaceScheduledOrderSliceCctp>,
+    ) -> Result<()> {
+        processor::place_scheduled_order_slice_cctp(ctx)
+    }
+
     /// This instruction is used to redeem a `Fill` VAA and redeem tokens from a CCTP transfer. After
     /// the tokens are minted by the CCTP program, they are transferred to a token custody account.
     /// The `prepared_fill` account is populated with information from the `Fill` vaa. This
@@ -172,6 +416,17 @@ pub mod token_router {
         processor::redeem_fast_fill(ctx)
     }
 
+    /// This instruction is used to redeem a Token Bridge transfer whose message is a `Fill`. After
+    /// the tokens are released by the Token Bridge program, they are transferred to a token custody
+    /// account. The `prepared_fill` account is populated with information from the `Fill` message.
+    /// This instruction only handles Token Bridge transfers.
+    /// # Arguments
+    ///
+    /// * `ctx` - `RedeemTokenBridgeFill` context.
+    pub fn redeem_token_bridge_fill(ctx: Context<RedeemTokenBridgeFill>) -> Result<()> {
+        processor::redeem_token_bridge_fill(ctx)
+    }
+
     /// This instruction is used to consume a `prepared_fill` account. The tokens are transferred from the
     /// `prepared_custody_token` account to the `dst_token` account. The `prepared_custody_token` account is
     /// closed. This instruction can only be called by the `redeemer` that is saved in the `prepared_fill`.
@@ -181,6 +436,68 @@ pub mod token_router {
     pub fn consume_prepared_fill(ctx: Context<ConsumePreparedFill>) -> Result<()> {
         processor::consume_prepared_fill(ctx)
     }
+
+    /// This instruction is used to consume part of a `prepared_fill` account. The `amount` is
+    /// transferred from the `prepared_custody_token` account to the `dst_token` account, whose
+    /// remaining balance is the amount left to consume. Once the `prepared_custody_token` account
+    /// is drained, both it and the `prepared_fill` are closed. The consumed and remaining amounts are
+    /// emitted in a `PreparedFillConsumed` event. This instruction can only be called by the
+    /// `redeemer` that is saved in the `prepared_fill`.
+    /// # Arguments
+    ///
+    /// * `ctx`    - `ConsumePreparedFillPartial` context.
+    /// * `amount` - Amount to transfer to the `dst_token` account.
+    pub fn consume_prepared_fill_partial(
+        ctx: Context<ConsumePreparedFillPartial>,
+        amount: u64,
+    ) -> Result<()> {
+        processor::consume_prepared_fill_partial(ctx, amount)
+    }
+
+    /// This instruction is used to deliver a `prepared_fill` to a redeemer that is a program. The
+    /// tokens are transferred from the `prepared_custody_token` account to the redeemer program's
+    /// vault token account and the redeemer program's `on_liquidity_layer_fill` instruction is
+    /// called with the fill, all in this instruction. The `prepared_custody_token` and
+    /// `prepared_fill` accounts are closed. Any accounts the redeemer program needs are passed as
+    /// remaining accounts. This instruction can be called by anyone.
+    /// # Arguments
+    ///
+    /// * `ctx` - `DeliverPreparedFill` context.
+    pub fn deliver_prepared_fill<'info>(
+        ctx: Context<'_, '_, '_, 'info, DeliverPreparedFill<'info>>,
+    ) -> Result<()> {
+        processor::deliver_prepared_fill(ctx)
+    }
+
+    /// This instruction is used to consume a `prepared_fill` account by swapping its tokens with an
+    /// allowlisted swap program. The swap instruction's accounts are passed as remaining accounts,
+    /// and the `prepared_fill` signs for the `prepared_custody_token` account. The swap must
+    /// deliver at least `min_amount_out` to the redeemer's `dst_token` account and must consume all
+    /// of the `prepared_custody_token` account's tokens, which is then closed. This instruction can
+    /// only be called by the `redeemer` that is saved in the `prepared_fill`.
+    /// # Arguments
+    ///
+    /// * `ctx`  - `RedeemAndSwap` context.
+    /// * `args` - `RedeemAndSwapArgs` struct, see `redeem_and_swap.rs` for more info.
+    pub fn redeem_and_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemAndSwap<'info>>,
+        args: RedeemAndSwapArgs,
+    ) -> Result<()> {
+        processor::redeem_and_swap(ctx, args)
+    }
+
+    /// This instruction is used to deliver a `prepared_fill` redeemed from a fast fill that pays a
+    /// relayer fee. The relayer fee is transferred from the `prepared_custody_token` account to the
+    /// `relayer_token` account and the remaining tokens are transferred to the redeemer's
+    /// associated token account. The `prepared_custody_token` and `prepared_fill` accounts are
+    /// closed. This instruction can be called by anyone, who would typically redeem the fast fill
+    /// with `redeem_fast_fill` in the same transaction.
+    /// # Arguments
+    ///
+    /// * `ctx` - `RelayFastFill` context.
+    pub fn relay_fast_fill(ctx: Context<RelayFastFill>) -> Result<()> {
+        processor::relay_fast_fill(ctx)
+    }
 }
 
 #[cfg(test)]
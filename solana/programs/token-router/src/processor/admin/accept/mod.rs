@@ -0,0 +1,2 @@
+mod owner_assistant;
+pub use owner_assistant::*;
@@ -109,6 +109,9 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         owner,
         pending_owner: None,
         owner_assistant: ctx.accounts.owner_assistant.key(),
+        pending_owner_expiry_slot: Default::default(),
+        pending_owner_assistant: None,
+        max_integrator_fee_bps: Default::default(),
     });
 
     // Done.
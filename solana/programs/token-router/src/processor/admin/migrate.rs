@@ -1,13 +1,103 @@
-use crate::composite::*;
-use anchor_lang::prelude::*;
+use crate::{error::TokenRouterError, state::Custodian};
+use anchor_lang::{prelude::*, Discriminator};
 
 #[derive(Accounts)]
 pub struct Migrate<'info> {
-    admin: OwnerOnly<'info>,
+    /// Pays for any additional rent required to grow the custodian account.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Program's owner. This account is checked against the custodian's owner in the instruction
+    /// handler because the custodian may still have the layout of a previous program version.
+    owner: Signer<'info>,
+
+    /// CHECK: Seeds must be \["emitter"\]. This account is deserialized in the instruction handler
+    /// using either its legacy or current layout.
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = Custodian::BUMP,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
 }
 
-pub fn migrate(_ctx: Context<Migrate>) -> Result<()> {
-    msg!("Nothing to migrate");
+/// Custodian layout of the initially deployed program. Fields added since then are appended to the
+/// end of [Custodian], so accounts with this layout are reallocated and rewritten when migrating.
+#[derive(AnchorDeserialize)]
+struct LegacyCustodian {
+    paused: bool,
+    owner: Pubkey,
+    pending_owner: Option<Pubkey>,
+    owner_assistant: Pubkey,
+    paused_set_by: Pubkey,
+}
+
+pub fn migrate(ctx: Context<Migrate>) -> Result<()> {
+    let acc_info = ctx.accounts.custodian.to_account_info();
+
+    let (custodian, needs_migration) = {
+        let data = acc_info.try_borrow_data()?;
+        if data.len() < 8 + Custodian::INIT_SPACE {
+            require!(
+                data.len() >= 8 && data[..8] == Custodian::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+
+            let LegacyCustodian {
+                paused,
+                owner,
+                pending_owner,
+                owner_assistant,
+                paused_set_by,
+            } = LegacyCustodian::deserialize(&mut &data[8..])?;
+
+            // Give an existing pending owner the full expiration period from the time of this
+            // migration to confirm the ownership transfer request.
+            let pending_owner_expiry_slot = match pending_owner {
+                Some(_) => Clock::get()?
+                    .slot
+                    .saturating_add(crate::OWNERSHIP_TRANSFER_REQUEST_EXPIRATION),
+                None => Default::default(),
+            };
+
+            let custodian = Custodian {
+                paused,
+                owner,
+                pending_owner,
+                owner_assistant,
+                paused_set_by,
+                pending_owner_expiry_slot,
+                pending_owner_assistant: None,
+                max_integrator_fee_bps: Default::default(),
+            };
+
+            (custodian, true)
+        } else {
+            (Custodian::try_deserialize(&mut &data[..])?, false)
+        }
+    };
+
+    require_keys_eq!(
+        ctx.accounts.owner.key(),
+        custodian.owner,
+        TokenRouterError::OwnerOnly
+    );
+
+    if needs_migration {
+        common::realloc::realloc_account(
+            &acc_info,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            8 + Custodian::INIT_SPACE,
+        )?;
+        custodian.try_serialize(&mut &mut acc_info.try_borrow_mut_data()?[..])?;
+
+        msg!("Migrated custodian");
+    } else {
+        msg!("Nothing to migrate");
+    }
 
     Ok(())
 }
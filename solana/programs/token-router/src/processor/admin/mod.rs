@@ -1,6 +1,9 @@
 // mod authorize_upgrade;
 // pub use authorize_upgrade::*;
 
+mod accept;
+pub use accept::*;
+
 mod initialize;
 pub use initialize::*;
 
@@ -10,8 +13,17 @@ pub use migrate::*;
 mod ownership_transfer_request;
 pub use ownership_transfer_request::*;
 
+mod owner_set;
+pub use owner_set::*;
+
+mod role;
+pub use role::*;
+
 mod set_pause;
 pub use set_pause::*;
 
+mod swap_program;
+pub use swap_program::*;
+
 mod update;
 pub use update::*;
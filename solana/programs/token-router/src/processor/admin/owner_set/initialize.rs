@@ -0,0 +1,40 @@
+use crate::{composite::*, state::OwnerSet};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeOwnerSet<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + OwnerSet::INIT_SPACE,
+        seeds = [OwnerSet::SEED_PREFIX],
+        bump,
+    )]
+    owner_set: Account<'info, OwnerSet>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn initialize_owner_set(
+    ctx: Context<InitializeOwnerSet>,
+    args: super::OwnerSetArgs,
+) -> Result<()> {
+    super::require_valid_owner_set(&args)?;
+
+    let super::OwnerSetArgs { threshold, members } = args;
+
+    ctx.accounts.owner_set.set_inner(OwnerSet {
+        bump: ctx.bumps.owner_set,
+        threshold,
+        members,
+        next_transaction_id: Default::default(),
+    });
+
+    // Done.
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use crate::{error::TokenRouterError, state::OwnerSetTransaction};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelOwnerSetTransaction<'info> {
+    #[account(mut)]
+    proposed_by: Signer<'info>,
+
+    #[account(
+        mut,
+        close = proposed_by,
+        seeds = [
+            OwnerSetTransaction::SEED_PREFIX,
+            &transaction.id.to_be_bytes()
+        ],
+        bump = transaction.bump,
+        has_one = proposed_by @ TokenRouterError::InvalidOwnerSetTransaction,
+    )]
+    transaction: Box<Account<'info, OwnerSetTransaction>>,
+}
+
+pub fn cancel_owner_set_transaction(_ctx: Context<CancelOwnerSetTransaction>) -> Result<()> {
+    // Done.
+    Ok(())
+}
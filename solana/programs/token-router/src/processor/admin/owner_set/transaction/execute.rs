@@ -0,0 +1,95 @@
+use crate::{
+    error::TokenRouterError,
+    state::{OwnerSet, OwnerSetTransaction},
+};
+use anchor_lang::prelude::*;
+use common::admin::utils::owner_set;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteOwnerSetTransaction<'info> {
+    #[account(
+        seeds = [OwnerSet::SEED_PREFIX],
+        bump = owner_set.bump,
+    )]
+    owner_set: Box<Account<'info, OwnerSet>>,
+
+    /// CHECK: Mutable. Must equal the member who proposed the transaction.
+    #[account(
+        mut,
+        address = transaction.proposed_by,
+    )]
+    proposed_by: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = proposed_by,
+        seeds = [
+            OwnerSetTransaction::SEED_PREFIX,
+            &transaction.id.to_be_bytes()
+        ],
+        bump = transaction.bump,
+        constraint = {
+            owner_set::threshold_met(&owner_set, &transaction)
+        } @ TokenRouterError::OwnerSetThresholdNotMet,
+    )]
+    transaction: Box<Account<'info, OwnerSetTransaction>>,
+
+    token_router_program: Program<'info, crate::program::TokenRouter>,
+}
+
+pub fn execute_owner_set_transaction<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteOwnerSetTransaction<'info>>,
+) -> Result<()> {
+    let transaction = &ctx.accounts.transaction;
+
+    // The remaining accounts must match the accounts proposed in the transaction.
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        transaction.accounts.len(),
+        TokenRouterError::InvalidOwnerSetTransaction
+    );
+
+    let accounts = transaction
+        .accounts
+        .iter()
+        .zip(ctx.remaining_accounts)
+        .map(|(meta, acc_info)| {
+            require_keys_eq!(
+                meta.pubkey,
+                acc_info.key(),
+                TokenRouterError::InvalidOwnerSetTransaction
+            );
+            require_keys_neq!(
+                meta.pubkey,
+                transaction.key(),
+                TokenRouterError::InvalidOwnerSetTransaction
+            );
+
+            Ok(AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.token_router_program.to_account_info());
+
+    // The owner set signs for this instruction, which satisfies owner-only checks when the owner
+    // set is the custodian's owner.
+    invoke_signed(
+        &Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: transaction.data.clone(),
+        },
+        &account_infos,
+        &[&[OwnerSet::SEED_PREFIX, &[ctx.accounts.owner_set.bump]]],
+    )
+    .map_err(Into::into)
+}
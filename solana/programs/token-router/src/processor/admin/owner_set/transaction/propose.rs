@@ -0,0 +1,76 @@
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    state::{OwnerSetAccountMeta, OwnerSetTransaction},
+};
+use anchor_lang::prelude::*;
+use common::admin::utils::owner_set;
+
+#[derive(Accounts)]
+#[instruction(args: OwnerSetTransactionArgs)]
+pub struct ProposeOwnerSetTransaction<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    owner_set_member: OwnerSetMemberMut<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = OwnerSetTransaction::compute_size(args.accounts.len(), args.data.len()),
+        seeds = [
+            OwnerSetTransaction::SEED_PREFIX,
+            &owner_set_member.owner_set.next_transaction_id.to_be_bytes()
+        ],
+        bump,
+    )]
+    transaction: Box<Account<'info, OwnerSetTransaction>>,
+
+    system_program: Program<'info, System>,
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OwnerSetTransactionArgs {
+    /// Accounts of the instruction to be invoked by the owner set.
+    pub accounts: Vec<OwnerSetAccountMeta>,
+
+    /// Instruction data, which must begin with an instruction discriminator for this program.
+    pub data: Vec<u8>,
+}
+
+pub fn propose_owner_set_transaction(
+    ctx: Context<ProposeOwnerSetTransaction>,
+    args: OwnerSetTransactionArgs,
+) -> Result<()> {
+    let OwnerSetTransactionArgs { accounts, data } = args;
+    require!(
+        data.len() >= 8,
+        TokenRouterError::InvalidOwnerSetTransaction
+    );
+
+    let owner_set = &mut ctx.accounts.owner_set_member.owner_set;
+    let id = owner_set.next_transaction_id;
+    owner_set.next_transaction_id = id
+        .checked_add(1)
+        .ok_or_else(|| TokenRouterError::U64Overflow)?;
+
+    let proposed_by = ctx.accounts.owner_set_member.member.key();
+    ctx.accounts.transaction.set_inner(OwnerSetTransaction {
+        id,
+        bump: ctx.bumps.transaction,
+        proposed_by,
+        approvals: Default::default(),
+        accounts,
+        data,
+    });
+
+    // The proposer implicitly approves their own transaction.
+    owner_set::approve(
+        &ctx.accounts.owner_set_member.owner_set,
+        &mut ctx.accounts.transaction,
+        &proposed_by,
+    );
+
+    // Done.
+    Ok(())
+}
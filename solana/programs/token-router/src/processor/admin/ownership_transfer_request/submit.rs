@@ -18,9 +18,15 @@ pub struct SubmitOwnershipTransferRequest<'info> {
 pub fn submit_ownership_transfer_request(
     ctx: Context<SubmitOwnershipTransferRequest>,
 ) -> Result<()> {
+    // The pending owner must confirm the ownership transfer request before it expires.
+    let expiry_slot = Clock::get()?
+        .slot
+        .saturating_add(crate::OWNERSHIP_TRANSFER_REQUEST_EXPIRATION);
+
     common::admin::utils::pending_owner::transfer_ownership(
         &mut ctx.accounts.admin.custodian,
         &ctx.accounts.new_owner.key(),
+        expiry_slot,
     );
 
     // Done.
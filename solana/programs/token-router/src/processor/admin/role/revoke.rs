@@ -0,0 +1,28 @@
+use crate::{composite::*, error::TokenRouterError, state::RoleHolder};
+use anchor_lang::prelude::*;
+use common::admin::{utils::roles, Role};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    admin: OwnerOnly<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            RoleHolder::SEED_PREFIX,
+            role_holder.holder.as_ref(),
+        ],
+        bump = role_holder.bump,
+    )]
+    role_holder: Account<'info, RoleHolder>,
+}
+
+pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+    require!(
+        roles::revoke_role(&mut ctx.accounts.role_holder, role),
+        TokenRouterError::RoleNotGranted
+    );
+
+    // Done.
+    Ok(())
+}
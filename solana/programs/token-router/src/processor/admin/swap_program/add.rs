@@ -0,0 +1,41 @@
+use crate::{composite::*, error::TokenRouterError, state::SwapProgram};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddSwapProgram<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    admin: OwnerOnly<'info>,
+
+    /// CHECK: Must be an executable program other than this one.
+    #[account(
+        executable,
+        constraint = program.key() != crate::id() @ TokenRouterError::InvalidSwapProgram,
+    )]
+    program: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SwapProgram::INIT_SPACE,
+        seeds = [
+            SwapProgram::SEED_PREFIX,
+            program.key().as_ref(),
+        ],
+        bump,
+    )]
+    swap_program: Account<'info, SwapProgram>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_swap_program(ctx: Context<AddSwapProgram>) -> Result<()> {
+    ctx.accounts.swap_program.set_inner(SwapProgram {
+        bump: ctx.bumps.swap_program,
+        program_id: ctx.accounts.program.key(),
+    });
+
+    // Done.
+    Ok(())
+}
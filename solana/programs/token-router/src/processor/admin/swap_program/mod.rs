@@ -0,0 +1,5 @@
+mod add;
+pub use add::*;
+
+mod remove;
+pub use remove::*;
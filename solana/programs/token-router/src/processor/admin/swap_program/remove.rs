@@ -0,0 +1,36 @@
+use crate::{composite::*, error::TokenRouterError, state::SwapProgram};
+use anchor_lang::prelude::*;
+use common::admin::utils::ownable::only_owner;
+
+/// Removing a swap program prevents prepared fills from being swapped with it.
+#[derive(Accounts)]
+pub struct RemoveSwapProgram<'info> {
+    /// The owner receives the rent from the closed account.
+    #[account(
+        mut,
+        constraint = only_owner(
+            &custodian,
+            &owner,
+            error!(TokenRouterError::OwnerOnly)
+        )?
+    )]
+    owner: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            SwapProgram::SEED_PREFIX,
+            swap_program.program_id.as_ref(),
+        ],
+        bump = swap_program.bump,
+    )]
+    swap_program: Account<'info, SwapProgram>,
+}
+
+pub fn remove_swap_program(_ctx: Context<RemoveSwapProgram>) -> Result<()> {
+    // Done.
+    Ok(())
+}
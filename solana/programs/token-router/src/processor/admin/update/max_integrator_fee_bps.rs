@@ -0,0 +1,28 @@
+use crate::{composite::*, error::TokenRouterError};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(max_fee_bps: u32)]
+pub struct UpdateMaxIntegratorFeeBps<'info> {
+    #[account(
+        constraint = {
+            require!(
+                max_fee_bps <= crate::FEE_PRECISION_MAX,
+                TokenRouterError::MaxIntegratorFeeBpsTooLarge
+            );
+
+            true
+        }
+    )]
+    admin: FeeManagerMut<'info>,
+}
+
+pub fn update_max_integrator_fee_bps(
+    ctx: Context<UpdateMaxIntegratorFeeBps>,
+    max_fee_bps: u32,
+) -> Result<()> {
+    ctx.accounts.admin.custodian.max_integrator_fee_bps = max_fee_bps;
+
+    // Done.
+    Ok(())
+}
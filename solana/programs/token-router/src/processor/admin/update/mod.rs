@@ -1,2 +1,5 @@
+mod max_integrator_fee_bps;
+pub use max_integrator_fee_bps::*;
+
 mod owner_assistant;
 pub use owner_assistant::*;
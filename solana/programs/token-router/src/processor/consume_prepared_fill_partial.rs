@@ -0,0 +1,114 @@
+use crate::{error::TokenRouterError, state::PreparedFill};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+/// Accounts required for [consume_prepared_fill_partial].
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+#[event_cpi]
+pub struct ConsumePreparedFillPartial<'info> {
+    /// This signer must be the same one encoded in the prepared fill.
+    #[account(address = prepared_fill.redeemer)]
+    redeemer: Signer<'info>,
+
+    /// CHECK: This recipient may not necessarily be the same one encoded in the prepared fill (as
+    /// the payer). The lamports of the prepared fill and its custody token account are only sent
+    /// to this account when the custody token account is drained.
+    #[account(mut)]
+    beneficiary: UncheckedAccount<'info>,
+
+    /// This account will be closed once the custody token account is drained.
+    #[account(mut)]
+    prepared_fill: Account<'info, PreparedFill>,
+
+    /// Destination token account, which the redeemer may not own. The redeemer may have the tokens
+    /// be sent to any account he chooses (this one).
+    ///
+    /// CHECK: This token account must already exist.
+    #[account(mut)]
+    dst_token: UncheckedAccount<'info>,
+
+    /// Custody token account. This account will be closed once it is drained.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_fill.key()\].
+    #[account(
+        mut,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_fill.key().as_ref(),
+        ],
+        bump = prepared_fill.prepared_custody_token_bump,
+        constraint = {
+            require!(
+                amount > 0 && amount <= prepared_custody_token.amount,
+                TokenRouterError::InsufficientAmount
+            );
+
+            true
+        }
+    )]
+    prepared_custody_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn consume_prepared_fill_partial(
+    ctx: Context<ConsumePreparedFillPartial>,
+    amount: u64,
+) -> Result<()> {
+    let prepared_fill = &ctx.accounts.prepared_fill;
+
+    let prepared_fill_signer_seeds = &[
+        PreparedFill::SEED_PREFIX,
+        prepared_fill.seeds.fill_source.as_ref(),
+        &[prepared_fill.seeds.bump],
+    ];
+
+    let custody_token = &ctx.accounts.prepared_custody_token;
+    let token_program = &ctx.accounts.token_program;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: custody_token.to_account_info(),
+                to: ctx.accounts.dst_token.to_account_info(),
+                authority: prepared_fill.to_account_info(),
+            },
+            &[prepared_fill_signer_seeds],
+        ),
+        amount,
+    )?;
+
+    // The custody token account's balance is the amount remaining to be consumed. This amount
+    // cannot underflow because the amount was checked against this balance.
+    let amount_remaining = custody_token.amount.saturating_sub(amount);
+
+    emit_cpi!(crate::events::PreparedFillConsumed {
+        prepared_fill: prepared_fill.key(),
+        dst_token: ctx.accounts.dst_token.key(),
+        amount_consumed: amount,
+        amount_remaining,
+    });
+
+    // Keep the prepared fill open until the custody token account is drained.
+    if amount_remaining > 0 {
+        return Ok(());
+    }
+
+    // Close token account.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: custody_token.to_account_info(),
+            destination: ctx.accounts.beneficiary.to_account_info(),
+            authority: prepared_fill.to_account_info(),
+        },
+        &[prepared_fill_signer_seeds],
+    ))?;
+
+    // Finally close the prepared fill.
+    ctx.accounts
+        .prepared_fill
+        .close(ctx.accounts.beneficiary.to_account_info())
+}
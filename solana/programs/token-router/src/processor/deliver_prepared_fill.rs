@@ -0,0 +1,135 @@
+use crate::{error::TokenRouterError, state::PreparedFill};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::fill_callback::{self, CALLBACK_AUTHORITY_SEED_PREFIX, VAULT_AUTHORITY_SEED_PREFIX};
+
+/// Accounts required for [deliver_prepared_fill].
+#[derive(Accounts)]
+pub struct DeliverPreparedFill<'info> {
+    /// Signs for the call into the redeemer program. This authority is only used for this purpose.
+    ///
+    /// CHECK: Seeds must be \["callback-authority"\].
+    #[account(
+        seeds = [CALLBACK_AUTHORITY_SEED_PREFIX],
+        bump,
+    )]
+    callback_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal the `prepared_by` field of the `prepared_fill` account, which receives the
+    /// lamports of the closed accounts.
+    #[account(
+        mut,
+        address = prepared_fill.prepared_by,
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+    )]
+    prepared_fill: Account<'info, PreparedFill>,
+
+    /// Custody token account. This account will be closed at the end of this instruction.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_fill.key()\].
+    #[account(
+        mut,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_fill.key().as_ref(),
+        ],
+        bump = prepared_fill.prepared_custody_token_bump,
+    )]
+    prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Only a redeemer that is a program opts into having its fills delivered. Because a program
+    /// cannot sign for its own address, these fills can only be consumed this way.
+    ///
+    /// CHECK: Must equal the `redeemer` field of the `prepared_fill` account.
+    #[account(
+        executable,
+        address = prepared_fill.redeemer,
+    )]
+    redeemer_program: UncheckedAccount<'info>,
+
+    /// Redeemer program's vault token account.
+    #[account(
+        mut,
+        token::mint = prepared_custody_token.mint,
+        constraint = {
+            let (vault_authority, _) = Pubkey::find_program_address(
+                &[VAULT_AUTHORITY_SEED_PREFIX],
+                &redeemer_program.key(),
+            );
+
+            redeemer_vault_token.owner == vault_authority
+        } @ TokenRouterError::InvalidRedeemer,
+    )]
+    redeemer_vault_token: Box<Account<'info, token::TokenAccount>>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn deliver_prepared_fill<'info>(
+    ctx: Context<'_, '_, '_, 'info, DeliverPreparedFill<'info>>,
+) -> Result<()> {
+    let prepared_fill = &ctx.accounts.prepared_fill;
+
+    let prepared_fill_signer_seeds = &[
+        PreparedFill::SEED_PREFIX,
+        prepared_fill.seeds.fill_source.as_ref(),
+        &[prepared_fill.seeds.bump],
+    ];
+
+    let custody_token = &ctx.accounts.prepared_custody_token;
+    let token_program = &ctx.accounts.token_program;
+    let amount = custody_token.amount;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: custody_token.to_account_info(),
+                to: ctx.accounts.redeemer_vault_token.to_account_info(),
+                authority: prepared_fill.to_account_info(),
+            },
+            &[prepared_fill_signer_seeds],
+        ),
+        amount,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: custody_token.to_account_info(),
+            destination: ctx.accounts.prepared_by.to_account_info(),
+            authority: prepared_fill.to_account_info(),
+        },
+        &[prepared_fill_signer_seeds],
+    ))?;
+
+    let args = fill_callback::OnLiquidityLayerFillArgs {
+        source_chain: prepared_fill.source_chain,
+        order_sender: prepared_fill.order_sender,
+        amount,
+        redeemer_message: std::mem::take(&mut ctx.accounts.prepared_fill.redeemer_message),
+    };
+
+    // Finally call the redeemer program with the fill. Any accounts it needs are passed as
+    // remaining accounts.
+    fill_callback::on_liquidity_layer_fill(
+        CpiContext::new_with_signer(
+            ctx.accounts.redeemer_program.to_account_info(),
+            fill_callback::OnLiquidityLayerFill {
+                callback_authority: ctx.accounts.callback_authority.to_account_info(),
+                vault_token: ctx.accounts.redeemer_vault_token.to_account_info(),
+            },
+            &[&[
+                CALLBACK_AUTHORITY_SEED_PREFIX,
+                &[ctx.bumps.callback_authority],
+            ]],
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        args,
+    )
+}
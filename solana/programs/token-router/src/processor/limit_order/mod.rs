@@ -0,0 +1,5 @@
+mod place_cctp;
+pub use place_cctp::*;
+
+mod prepare;
+pub use prepare::*;
@@ -0,0 +1,233 @@
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    state::{OrderType, PreparedOrder, PreparedOrderInfo},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::TRANSFER_AUTHORITY_SEED_PREFIX;
+use solana_program::keccak;
+
+/// Accounts required for [prepare_limit_order].
+#[derive(Accounts)]
+#[instruction(args: PrepareLimitOrderArgs)]
+pub struct PrepareLimitOrder<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    /// The auction participant needs to set approval to this PDA if the sender (signer) is not
+    /// provided. The delegated amount must equal the amount in or this instruction will revert.
+    ///
+    /// NOTE: If this account is provided, the sender token's owner will be encoded as the order
+    /// sender.
+    ///
+    /// CHECK: Seeds must be \["transfer-authority", prepared_order.key(), args.hash(),
+    /// refund_token.key()\].
+    #[account(
+        seeds = [
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+            &args.hash().0,
+            refund_token.key().as_ref()
+        ],
+        bump,
+        constraint = {
+            require_eq!(
+                sender_token.delegated_amount,
+                args.amount_in,
+                TokenRouterError::DelegatedAmountMismatch,
+            );
+
+            true
+        }
+    )]
+    program_transfer_authority: Option<UncheckedAccount<'info>>,
+
+    /// Sender, who has the authority to transfer assets from the sender token account. If this
+    /// account is not provided, the program transfer authority account must be some account.
+    ///
+    /// NOTE: If this account is provided, this pubkey will be encoded as the order sender.
+    sender: Option<Signer<'info>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PreparedOrder::compute_size(args.redeemer_message.len()),
+        constraint = {
+            crate::processor::require_valid_order_args(
+                args.amount_in,
+                args.min_amount_out,
+                &args.redeemer,
+                &args.redeemer_message,
+            )?;
+
+            // The order must be placeable at some point.
+            require!(
+                args.expiry > Clock::get()?.unix_timestamp,
+                TokenRouterError::OrderExpired
+            );
+
+            true
+        }
+    )]
+    prepared_order: Account<'info, PreparedOrder>,
+
+    /// Token account where the amount in is transferred from. This account must be owned by the
+    /// sender or have delegated authority to the program transfer authority.
+    #[account(mut)]
+    sender_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Token account that the amount in is refunded to if the order is closed or expires.
+    #[account(
+        token::mint = mint,
+    )]
+    refund_token: Account<'info, token::TokenAccount>,
+
+    /// Custody token account, which holds the amount in until the order is placed or refunded.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_order.key()\].
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = custodian,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump,
+    )]
+    prepared_custody_token: Account<'info, token::TokenAccount>,
+
+    mint: CheckedMint<'info>,
+
+    #[account(
+        constraint = {
+            crate::processor::require_valid_target_endpoint(
+                &target_router_endpoint,
+                args.target_chain,
+                args.amount_in,
+                &args.redeemer_message,
+            )?;
+
+            true
+        }
+    )]
+    target_router_endpoint: RegisteredEndpoint<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+/// Arguments for [prepare_limit_order].
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PrepareLimitOrderArgs {
+    /// Amount of tokens to transfer.
+    pub amount_in: u64,
+
+    /// Maximum keeper fee deducted from [amount_in](Self::amount_in) when the order is placed.
+    pub max_keeper_fee: u64,
+
+    /// If provided, minimum amount of tokens to receive in exchange for
+    /// [amount_in](Self::amount_in).
+    pub min_amount_out: Option<u64>,
+
+    /// Matching Engine auction whose outcome determines whether the order can be placed.
+    pub auction: Pubkey,
+
+    /// Limit price. The order can only be placed once the [auction](Self::auction) has completed
+    /// with an offer price no greater than this value.
+    pub max_offer_price: u64,
+
+    /// Timestamp after which the order can no longer be placed.
+    pub expiry: i64,
+
+    /// The Wormhole chain ID of the network to transfer tokens to.
+    pub target_chain: u16,
+
+    /// The address of the redeeming contract on the target chain.
+    pub redeemer: [u8; 32],
+
+    /// Arbitrary payload to be sent to the [redeemer](Self::redeemer), which can be used to encode
+    /// instructions or data for another network's smart contract.
+    pub redeemer_message: Vec<u8>,
+}
+
+impl PrepareLimitOrderArgs {
+    pub fn hash(&self) -> keccak::Hash {
+        // Hash the serialized args so that unset and zero-valued options are distinguished.
+        keccak::hash(&self.try_to_vec().unwrap())
+    }
+}
+
+pub fn prepare_limit_order(
+    ctx: Context<PrepareLimitOrder>,
+    args: PrepareLimitOrderArgs,
+) -> Result<()> {
+    let hashed_args = args.hash();
+
+    let PrepareLimitOrderArgs {
+        amount_in,
+        max_keeper_fee,
+        min_amount_out,
+        auction,
+        max_offer_price,
+        expiry,
+        target_chain,
+        redeemer,
+        redeemer_message,
+    } = args;
+
+    let token_program = &ctx.accounts.token_program;
+    let sender_token = &ctx.accounts.sender_token;
+    let custody_token = &ctx.accounts.prepared_custody_token;
+    let refund_token = &ctx.accounts.refund_token;
+
+    let prepared_order = &mut ctx.accounts.prepared_order;
+    let prepared_order_key = prepared_order.key();
+
+    // Transfer amount to custody token account.
+    let order_sender = crate::processor::transfer_to_prepared_custody(
+        token_program,
+        ctx.accounts.sender.as_ref(),
+        ctx.accounts
+            .program_transfer_authority
+            .as_ref()
+            .map(|authority| (authority, ctx.bumps.program_transfer_authority.unwrap())),
+        &[
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            prepared_order_key.as_ref(),
+            &hashed_args.0,
+            refund_token.key().as_ref(),
+        ],
+        sender_token,
+        custody_token,
+        amount_in,
+    )?;
+
+    // Set the values in prepared order account.
+    prepared_order.set_inner(PreparedOrder {
+        info: PreparedOrderInfo {
+            order_sender,
+            prepared_by: ctx.accounts.payer.key(),
+            order_type: OrderType::Limit {
+                max_keeper_fee,
+                min_amount_out,
+                auction,
+                max_offer_price,
+                expiry,
+            },
+            src_token: sender_token.key(),
+            refund_token: refund_token.key(),
+            target_chain,
+            redeemer,
+            prepared_custody_token_bump: ctx.bumps.prepared_custody_token,
+        },
+        redeemer_message,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -3,3 +3,6 @@ pub use place_cctp::*;
 
 mod prepare;
 pub use prepare::*;
+
+mod prepare_v2;
+pub use prepare_v2::*;
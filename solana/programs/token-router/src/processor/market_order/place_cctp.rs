@@ -31,6 +31,8 @@ pub struct PlaceMarketOrderCctp<'info> {
     #[account(constraint = !custodian.paused @ TokenRouterError::Paused)]
     custodian: CheckedCustodian<'info>,
 
+    /// This account's order type is checked in the instruction handler. Limit and scheduled orders
+    /// cannot be placed with this instruction.
     #[account(
         mut,
         close = prepared_by,
@@ -161,6 +163,16 @@ pub struct PlaceMarketOrderCctp<'info> {
 ///
 /// See [burn_and_publish](wormhole_cctp_solana::cpi::burn_and_publish) for more details.
 pub fn place_market_order_cctp(ctx: Context<PlaceMarketOrderCctp>) -> Result<()> {
+    require!(
+        ctx.accounts.prepared_order.order_type.can_place(
+            Clock::get()?.unix_timestamp,
+            ctx.accounts.prepared_custody_token.amount,
+            0,
+            None
+        ),
+        TokenRouterError::OrderConditionsNotMet
+    );
+
     match ctx.accounts.target_router_endpoint.protocol {
         matching_engine::state::MessageProtocol::Cctp { domain } => {
             handle_place_market_order_cctp(ctx, domain)
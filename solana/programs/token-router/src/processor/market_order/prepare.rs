@@ -6,6 +6,7 @@ use crate::{
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use common::TRANSFER_AUTHORITY_SEED_PREFIX;
+use matching_engine::state::RouterEndpoint;
 use solana_program::keccak;
 
 /// Accounts required for [prepare_market_order].
@@ -23,7 +24,8 @@ pub struct PrepareMarketOrder<'info> {
     /// NOTE: If this account is provided, the sender token's owner will be encoded as the order
     /// sender.
     ///
-    /// CHECK: Seeds must be \["transfer-authority", prepared_order.key(), args.hash()\].
+    /// CHECK: Seeds must be \["transfer-authority", prepared_order.key(), args.hash(),
+    /// refund_token.key()\].
     #[account(
         seeds = [
             TRANSFER_AUTHORITY_SEED_PREFIX,
@@ -55,25 +57,12 @@ pub struct PrepareMarketOrder<'info> {
         payer = payer,
         space = PreparedOrder::compute_size(args.redeemer_message.len()),
         constraint = {
-            require!(args.amount_in > 0, TokenRouterError::InsufficientAmount);
-
-            // Cannot send to zero address.
-            require!(args.redeemer != [0; 32], TokenRouterError::InvalidRedeemer);
-
-            // Max message size. This constraint is enforced on every token router due to Solana's
-            // inbound payload size restriction.
-            require!(
-                args.redeemer_message.len() <= crate::MAX_REDEEMER_MESSAGE_SIZE,
-                TokenRouterError::RedeemerMessageTooLarge
-            );
-
-            // If provided, validate min amount out.
-            if let Some(min_amount_out) = args.min_amount_out {
-                require!(
-                    min_amount_out <= args.amount_in,
-                    TokenRouterError::MinAmountOutTooHigh,
-                );
-            }
+            require_valid_order_args(
+                args.amount_in,
+                args.min_amount_out,
+                &args.redeemer,
+                &args.redeemer_message,
+            )?;
 
             true
         }
@@ -84,7 +73,7 @@ pub struct PrepareMarketOrder<'info> {
     /// burn the configured [amount](TransferTokensWithPayloadArgs::amount) from this account.
     ///
     /// CHECK: This account must have delegated authority or be owned by the
-    /// [burn_source_authority](Self::burn_source_authority). Its mint must be USDC.
+    /// [burn_source_authority](Self::burn_source_authority). Its mint must be a supported mint.
     ///
     /// NOTE: This token account must have delegated transfer authority to the custodian prior to
     /// invoking this instruction.
@@ -94,7 +83,7 @@ pub struct PrepareMarketOrder<'info> {
     // TODO: Do we add a restriction that the refund token account must be the same owner as the
     // sender token account?
     #[account(
-        token::mint = usdc,
+        token::mint = mint,
     )]
     refund_token: Account<'info, token::TokenAccount>,
 
@@ -105,7 +94,7 @@ pub struct PrepareMarketOrder<'info> {
     #[account(
         init,
         payer = payer,
-        token::mint = usdc,
+        token::mint = mint,
         token::authority = custodian,
         seeds = [
             crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
@@ -115,15 +104,16 @@ pub struct PrepareMarketOrder<'info> {
     )]
     prepared_custody_token: Account<'info, token::TokenAccount>,
 
-    usdc: Usdc<'info>,
+    mint: CheckedMint<'info>,
 
     #[account(
         constraint = {
-            require_eq!(
-                target_router_endpoint.chain,
+            require_valid_target_endpoint(
+                &target_router_endpoint,
                 args.target_chain,
-                TokenRouterError::InvalidTargetRouter,
-            );
+                args.amount_in,
+                &args.redeemer_message,
+            )?;
 
             true
         }
@@ -197,12 +187,125 @@ pub fn prepare_market_order(
     let prepared_order = &mut ctx.accounts.prepared_order;
     let prepared_order_key = prepared_order.key();
 
-    // Finally transfer amount to custody token account. We perform exclusive or because we do not
-    // want to allow specifying more than one authority.
-    let order_sender = match (
+    // Finally transfer amount to custody token account.
+    let order_sender = crate::processor::transfer_to_prepared_custody(
+        token_program,
         ctx.accounts.sender.as_ref(),
-        ctx.accounts.program_transfer_authority.as_ref(),
-    ) {
+        ctx.accounts
+            .program_transfer_authority
+            .as_ref()
+            .map(|authority| (authority, ctx.bumps.program_transfer_authority.unwrap())),
+        &[
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            prepared_order_key.as_ref(),
+            &hashed_args.0,
+            refund_token.key().as_ref(),
+        ],
+        sender_token,
+        custody_token,
+        amount_in,
+    )?;
+
+    // Set the values in prepared order account.
+    prepared_order.set_inner(PreparedOrder {
+        info: PreparedOrderInfo {
+            order_sender,
+            prepared_by: ctx.accounts.payer.key(),
+            order_type: OrderType::Market { min_amount_out },
+            src_token: sender_token.key(),
+            refund_token: refund_token.key(),
+            target_chain,
+            redeemer,
+            prepared_custody_token_bump: ctx.bumps.prepared_custody_token,
+        },
+        redeemer_message,
+    });
+
+    // Done.
+    Ok(())
+}
+
+/// Validates the arguments of any order being prepared, where `amount_in` is the amount that will
+/// be placed.
+pub(crate) fn require_valid_order_args(
+    amount_in: u64,
+    min_amount_out: Option<u64>,
+    redeemer: &[u8; 32],
+    redeemer_message: &[u8],
+) -> Result<()> {
+    require!(amount_in > 0, TokenRouterError::InsufficientAmount);
+
+    // Cannot send to zero address.
+    require!(*redeemer != [0; 32], TokenRouterError::InvalidRedeemer);
+
+    // Max message size. This constraint is enforced on every token router due to Solana's inbound
+    // payload size restriction.
+    require!(
+        redeemer_message.len() <= crate::MAX_REDEEMER_MESSAGE_SIZE,
+        TokenRouterError::RedeemerMessageTooLarge
+    );
+
+    // If provided, validate min amount out.
+    if let Some(min_amount_out) = min_amount_out {
+        require!(
+            min_amount_out <= amount_in,
+            TokenRouterError::MinAmountOutTooHigh,
+        );
+    }
+
+    Ok(())
+}
+
+/// Validates that the target router endpoint is registered for the target chain and that the order
+/// is within the endpoint's order limits.
+pub(crate) fn require_valid_target_endpoint(
+    target_router_endpoint: &RouterEndpoint,
+    target_chain: u16,
+    amount_in: u64,
+    redeemer_message: &[u8],
+) -> Result<()> {
+    require_eq!(
+        target_router_endpoint.chain,
+        target_chain,
+        TokenRouterError::InvalidTargetRouter,
+    );
+
+    // Enforce the target endpoint's order limits.
+    let limits = &target_router_endpoint.limits;
+    let redeemer_message_len = u32::try_from(redeemer_message.len())
+        .map_err(|_| error!(TokenRouterError::RedeemerMessageTooLarge))?;
+    require!(
+        !limits.exceeds_max_redeemer_message_len(redeemer_message_len),
+        TokenRouterError::RedeemerMessageTooLarge
+    );
+    require!(
+        !limits.is_below_min_order_amount(amount_in),
+        TokenRouterError::OrderAmountBelowMinimum
+    );
+    require!(
+        !limits.is_above_max_order_amount(amount_in),
+        TokenRouterError::OrderAmountAboveMaximum
+    );
+
+    Ok(())
+}
+
+/// Transfers the amount in from the sender token account to the prepared custody token account and
+/// returns the order sender. We perform exclusive or because we do not want to allow specifying
+/// more than one authority.
+///
+/// NOTE: The program transfer authority's seeds exclude its bump, which is provided with the
+/// authority.
+pub(crate) fn transfer_to_prepared_custody<'info>(
+    token_program: &Program<'info, token::Token>,
+    sender: Option<&Signer<'info>>,
+    program_transfer_authority: Option<(&UncheckedAccount<'info>, u8)>,
+    program_transfer_authority_seeds: &[&[u8]],
+    sender_token: &Account<'info, token::TokenAccount>,
+    custody_token: &Account<'info, token::TokenAccount>,
+    amount_in: u64,
+) -> Result<Pubkey> {
+    match (sender, program_transfer_authority) {
         (Some(sender), None) => {
             token::transfer(
                 CpiContext::new(
@@ -216,9 +319,12 @@ pub fn prepare_market_order(
                 amount_in,
             )?;
 
-            sender.key()
+            Ok(sender.key())
         }
-        (None, Some(program_transfer_authority)) => {
+        (None, Some((program_transfer_authority, bump))) => {
+            let bump = [bump];
+            let signer_seeds = [program_transfer_authority_seeds, &[&bump]].concat();
+
             token::transfer(
                 CpiContext::new_with_signer(
                     token_program.to_account_info(),
@@ -227,37 +333,13 @@ pub fn prepare_market_order(
                         to: custody_token.to_account_info(),
                         authority: program_transfer_authority.to_account_info(),
                     },
-                    &[&[
-                        TRANSFER_AUTHORITY_SEED_PREFIX,
-                        prepared_order_key.as_ref(),
-                        &hashed_args.0,
-                        refund_token.key().as_ref(),
-                        &[ctx.bumps.program_transfer_authority.unwrap()],
-                    ]],
+                    &[&signer_seeds],
                 ),
                 amount_in,
             )?;
 
-            sender_token.owner
+            Ok(sender_token.owner)
         }
-        _ => return err!(TokenRouterError::EitherSenderOrProgramTransferAuthority),
-    };
-
-    // Set the values in prepared order account.
-    prepared_order.set_inner(PreparedOrder {
-        info: PreparedOrderInfo {
-            order_sender,
-            prepared_by: ctx.accounts.payer.key(),
-            order_type: OrderType::Market { min_amount_out },
-            src_token: sender_token.key(),
-            refund_token: refund_token.key(),
-            target_chain,
-            redeemer,
-            prepared_custody_token_bump: ctx.bumps.prepared_custody_token,
-        },
-        redeemer_message,
-    });
-
-    // Done.
-    Ok(())
+        _ => err!(TokenRouterError::EitherSenderOrProgramTransferAuthority),
+    }
 }
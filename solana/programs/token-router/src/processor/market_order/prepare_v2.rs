@@ -0,0 +1,362 @@
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    state::{Custodian, OrderType, PreparedOrder, PreparedOrderInfo, SenderState},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::TRANSFER_AUTHORITY_SEED_PREFIX;
+use solana_program::keccak;
+
+/// Accounts required for [prepare_market_order_v2].
+#[derive(Accounts)]
+#[event_cpi]
+#[instruction(args: PrepareMarketOrderV2Args)]
+pub struct PrepareMarketOrderV2<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    /// Sender, who has the authority to transfer assets from the sender token account. If this
+    /// account is not provided, the program transfer authority account must be some account.
+    ///
+    /// NOTE: If this account is provided, this pubkey will be encoded as the order sender.
+    sender: Option<Signer<'info>>,
+
+    /// Token account where the amount in is transferred from. This account must be owned by the
+    /// sender or have delegated authority to the program transfer authority.
+    #[account(mut)]
+    sender_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Order sender's state, which tracks the nonce of its next prepared order. This account is
+    /// created if it does not exist yet.
+    ///
+    /// CHECK: Seeds must be \["sender-state", order_sender\].
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SenderState::INIT_SPACE,
+        seeds = [
+            SenderState::SEED_PREFIX,
+            order_sender(&sender, &sender_token).as_ref(),
+        ],
+        bump,
+    )]
+    sender_state: Box<Account<'info, SenderState>>,
+
+    /// Prepared order, whose address is derived from the order sender and its next nonce.
+    ///
+    /// CHECK: Seeds must be \["prepared-order", order_sender, sender_state.next_nonce\].
+    #[account(
+        init,
+        payer = payer,
+        space = PreparedOrder::compute_size(args.redeemer_message.len()),
+        seeds = [
+            PreparedOrder::SEED_PREFIX,
+            order_sender(&sender, &sender_token).as_ref(),
+            &sender_state.next_nonce.to_be_bytes(),
+        ],
+        bump,
+        constraint = {
+            // The integrator fee is deducted from the amount in.
+            crate::processor::require_valid_order_args(
+                args.amount_in_after_integrator_fee(custodian.max_integrator_fee_bps)?,
+                args.min_amount_out,
+                &args.redeemer,
+                &args.redeemer_message,
+            )?;
+
+            // If provided, the order must be placeable at some point.
+            if let Some(expiry) = args.expiry {
+                require!(
+                    expiry > Clock::get()?.unix_timestamp,
+                    TokenRouterError::OrderExpired
+                );
+            }
+
+            true
+        }
+    )]
+    prepared_order: Box<Account<'info, PreparedOrder>>,
+
+    /// The auction participant needs to set approval to this PDA if the sender (signer) is not
+    /// provided. The delegated amount must equal the amount in or this instruction will revert.
+    ///
+    /// NOTE: If this account is provided, the sender token's owner will be encoded as the order
+    /// sender.
+    ///
+    /// CHECK: Seeds must be \["transfer-authority", prepared_order.key(), args.hash(),
+    /// refund_token.key()\].
+    #[account(
+        seeds = [
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+            &args.hash().0,
+            refund_token.key().as_ref()
+        ],
+        bump,
+        constraint = {
+            require_eq!(
+                sender_token.delegated_amount,
+                args.amount_in,
+                TokenRouterError::DelegatedAmountMismatch,
+            );
+
+            true
+        }
+    )]
+    program_transfer_authority: Option<UncheckedAccount<'info>>,
+
+    /// Token account that the amount in is refunded to if the order is closed or expires.
+    #[account(
+        token::mint = mint,
+    )]
+    refund_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Custody token account, which holds the amount in until the order is placed or refunded.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_order.key()\].
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = custodian,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump,
+    )]
+    prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    mint: CheckedMint<'info>,
+
+    /// Integrator's token account, which receives the integrator fee. This account is required if
+    /// an integrator fee is provided.
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    integrator_fee_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
+    #[account(
+        constraint = {
+            crate::processor::require_valid_target_endpoint(
+                &target_router_endpoint,
+                args.target_chain,
+                args.amount_in_after_integrator_fee(custodian.max_integrator_fee_bps)?,
+                &args.redeemer_message,
+            )?;
+
+            true
+        }
+    )]
+    target_router_endpoint: RegisteredEndpoint<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+/// Arguments for [prepare_market_order_v2].
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PrepareMarketOrderV2Args {
+    /// Amount of tokens to transfer.
+    pub amount_in: u64,
+
+    /// If provided, minimum amount of tokens to receive in exchange for
+    /// [amount_in](Self::amount_in).
+    pub min_amount_out: Option<u64>,
+
+    /// If provided, timestamp after which the order can no longer be placed. Once it passes, anyone
+    /// can refund the order to the [refund token](crate::state::PreparedOrderInfo::refund_token).
+    pub expiry: Option<i64>,
+
+    /// The Wormhole chain ID of the network to transfer tokens to.
+    pub target_chain: u16,
+
+    /// The address of the redeeming contract on the target chain.
+    pub redeemer: [u8; 32],
+
+    /// Arbitrary payload to be sent to the [redeemer](Self::redeemer), which can be used to encode
+    /// instructions or data for another network's smart contract.
+    pub redeemer_message: Vec<u8>,
+
+    /// If provided, fee collected by an integrator (e.g. a front-end) from
+    /// [amount_in](Self::amount_in). This fee cannot exceed the maximum integrator fee found in the
+    /// [Custodian](crate::state::Custodian).
+    pub integrator_fee: Option<IntegratorFee>,
+}
+
+/// Fee collected by an integrator when an order is prepared.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum IntegratorFee {
+    /// Fraction of the amount in, where [FEE_PRECISION_MAX](crate::FEE_PRECISION_MAX) is 100%.
+    Bps(u32),
+
+    /// Absolute amount of tokens.
+    Amount(u64),
+}
+
+impl PrepareMarketOrderV2Args {
+    pub fn hash(&self) -> keccak::Hash {
+        // Unlike the first version's arguments, these are hashed in their serialized form, which
+        // encodes whether each optional argument is provided.
+        keccak::hash(&self.try_to_vec().unwrap())
+    }
+
+    /// Returns the integrator fee deducted from the amount in, which is zero if no fee is provided.
+    /// This fee cannot exceed `max_fee_bps` of the amount in.
+    pub fn integrator_fee_amount(&self, max_fee_bps: u32) -> Result<u64> {
+        let fee = match self.integrator_fee {
+            Some(IntegratorFee::Bps(fee_bps)) => {
+                require!(
+                    fee_bps <= max_fee_bps,
+                    TokenRouterError::IntegratorFeeTooHigh
+                );
+                compute_fee(self.amount_in, fee_bps)
+            }
+            Some(IntegratorFee::Amount(fee)) => {
+                require!(
+                    fee <= compute_fee(self.amount_in, max_fee_bps),
+                    TokenRouterError::IntegratorFeeTooHigh
+                );
+                fee
+            }
+            None => Default::default(),
+        };
+
+        Ok(fee)
+    }
+
+    /// Returns the amount in after the integrator fee is deducted.
+    pub fn amount_in_after_integrator_fee(&self, max_fee_bps: u32) -> Result<u64> {
+        self.integrator_fee_amount(max_fee_bps)
+            .map(|fee| self.amount_in.saturating_sub(fee))
+    }
+}
+
+fn compute_fee(amount: u64, fee_bps: u32) -> u64 {
+    // This value cannot exceed the amount because the fee cannot exceed 100%.
+    let fee = u128::from(amount)
+        .saturating_mul(fee_bps.into())
+        .checked_div(crate::FEE_PRECISION_MAX.into())
+        .unwrap_or_default();
+
+    match u64::try_from(fee) {
+        Ok(fee) => fee,
+        Err(_) => amount,
+    }
+}
+
+/// Returns the order sender, which is the sender if provided. Otherwise the sender token's owner
+/// is the order sender.
+fn order_sender(sender: &Option<Signer>, sender_token: &token::TokenAccount) -> Pubkey {
+    match sender {
+        Some(sender) => sender.key(),
+        None => sender_token.owner,
+    }
+}
+
+pub fn prepare_market_order_v2(
+    ctx: Context<PrepareMarketOrderV2>,
+    args: PrepareMarketOrderV2Args,
+) -> Result<()> {
+    let hashed_args = args.hash();
+    let integrator_fee =
+        args.integrator_fee_amount(ctx.accounts.custodian.max_integrator_fee_bps)?;
+
+    let PrepareMarketOrderV2Args {
+        amount_in,
+        min_amount_out,
+        expiry,
+        target_chain,
+        redeemer,
+        redeemer_message,
+        integrator_fee: _,
+    } = args;
+
+    let token_program = &ctx.accounts.token_program;
+    let sender_token = &ctx.accounts.sender_token;
+    let custody_token = &ctx.accounts.prepared_custody_token;
+    let refund_token = &ctx.accounts.refund_token;
+
+    let prepared_order = &mut ctx.accounts.prepared_order;
+    let prepared_order_key = prepared_order.key();
+
+    // Transfer amount to custody token account.
+    let order_sender = crate::processor::transfer_to_prepared_custody(
+        token_program,
+        ctx.accounts.sender.as_ref(),
+        ctx.accounts
+            .program_transfer_authority
+            .as_ref()
+            .map(|authority| (authority, ctx.bumps.program_transfer_authority.unwrap())),
+        &[
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            prepared_order_key.as_ref(),
+            &hashed_args.0,
+            refund_token.key().as_ref(),
+        ],
+        sender_token,
+        custody_token,
+        amount_in,
+    )?;
+
+    // Set the values in prepared order account.
+    prepared_order.set_inner(PreparedOrder {
+        info: PreparedOrderInfo {
+            order_sender,
+            prepared_by: ctx.accounts.payer.key(),
+            order_type: match expiry {
+                Some(expiry) => OrderType::ExpiringMarket {
+                    min_amount_out,
+                    expiry,
+                },
+                None => OrderType::Market { min_amount_out },
+            },
+            src_token: sender_token.key(),
+            refund_token: refund_token.key(),
+            target_chain,
+            redeemer,
+            prepared_custody_token_bump: ctx.bumps.prepared_custody_token,
+        },
+        redeemer_message,
+    });
+
+    // Collect the integrator fee from the custody token account.
+    if integrator_fee > 0 {
+        let integrator_fee_token = ctx
+            .accounts
+            .integrator_fee_token
+            .as_ref()
+            .ok_or_else(|| error!(TokenRouterError::IntegratorFeeTokenRequired))?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: custody_token.to_account_info(),
+                    to: integrator_fee_token.to_account_info(),
+                    authority: ctx.accounts.custodian.to_account_info(),
+                },
+                &[Custodian::SIGNER_SEEDS],
+            ),
+            integrator_fee,
+        )?;
+
+        emit_cpi!(crate::events::IntegratorFeeCollected {
+            prepared_order: prepared_order_key,
+            integrator_fee_token: integrator_fee_token.key(),
+            amount: integrator_fee,
+        });
+    }
+
+    // Finally consume the order sender's nonce so its next prepared order has a new address.
+    let sender_state = &mut ctx.accounts.sender_state;
+    sender_state.bump = ctx.bumps.sender_state;
+    sender_state.next_nonce = sender_state.next_nonce.saturating_add(1);
+
+    // Done.
+    Ok(())
+}
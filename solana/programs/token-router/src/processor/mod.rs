@@ -7,8 +7,29 @@ pub use close_prepared_order::*;
 mod consume_prepared_fill;
 pub use consume_prepared_fill::*;
 
+mod consume_prepared_fill_partial;
+pub use consume_prepared_fill_partial::*;
+
+mod deliver_prepared_fill;
+pub use deliver_prepared_fill::*;
+
+mod limit_order;
+pub use limit_order::*;
+
 mod market_order;
 pub use market_order::*;
 
+mod refund_expired_prepared_order;
+pub use refund_expired_prepared_order::*;
+
+mod redeem_and_swap;
+pub use redeem_and_swap::*;
+
 mod redeem_fill;
 pub use redeem_fill::*;
+
+mod relay_fast_fill;
+pub use relay_fast_fill::*;
+
+mod scheduled_order;
+pub use scheduled_order::*;
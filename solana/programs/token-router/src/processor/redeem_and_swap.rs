@@ -0,0 +1,150 @@
+use crate::{
+    error::TokenRouterError,
+    state::{PreparedFill, SwapProgram},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+/// Arguments for [redeem_and_swap].
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RedeemAndSwapArgs {
+    /// Minimum amount of the output token the redeemer is willing to accept from the swap.
+    pub min_amount_out: u64,
+
+    /// Instruction data for the swap program. The swap instruction's accounts are passed as
+    /// remaining accounts, where the prepared fill signs as the owner of the prepared custody token
+    /// account.
+    pub swap_data: Vec<u8>,
+}
+
+/// Accounts required for [redeem_and_swap].
+#[derive(Accounts)]
+pub struct RedeemAndSwap<'info> {
+    /// This signer must be the same one encoded in the prepared fill.
+    #[account(address = prepared_fill.redeemer)]
+    redeemer: Signer<'info>,
+
+    /// CHECK: This recipient may not necessarily be the same one encoded in the prepared fill (as
+    /// the payer). See [ConsumePreparedFill](crate::processor::ConsumePreparedFill).
+    #[account(mut)]
+    beneficiary: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = beneficiary,
+    )]
+    prepared_fill: Account<'info, PreparedFill>,
+
+    /// Custody token account, which the swap program transfers the prepared fill's tokens from.
+    /// This account will be closed at the end of this instruction.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_fill.key()\].
+    #[account(
+        mut,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_fill.key().as_ref(),
+        ],
+        bump = prepared_fill.prepared_custody_token_bump,
+    )]
+    prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Redeemer's token account, which receives the output token of the swap.
+    #[account(
+        mut,
+        token::authority = redeemer,
+    )]
+    dst_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Allowlisted swap program.
+    #[account(
+        seeds = [
+            SwapProgram::SEED_PREFIX,
+            swap_program.program_id.as_ref(),
+        ],
+        bump = swap_program.bump,
+    )]
+    swap_program: Account<'info, SwapProgram>,
+
+    /// CHECK: Must equal the allowlisted swap program.
+    #[account(address = swap_program.program_id)]
+    program: UncheckedAccount<'info>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn redeem_and_swap<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemAndSwap<'info>>,
+    args: RedeemAndSwapArgs,
+) -> Result<()> {
+    let RedeemAndSwapArgs {
+        min_amount_out,
+        swap_data,
+    } = args;
+
+    let prepared_fill = &ctx.accounts.prepared_fill;
+    let prepared_fill_key = prepared_fill.key();
+    let prepared_fill_signer_seeds = &[
+        PreparedFill::SEED_PREFIX,
+        prepared_fill.seeds.fill_source.as_ref(),
+        &[prepared_fill.seeds.bump],
+    ];
+
+    let balance_before = ctx.accounts.dst_token.amount;
+
+    // The prepared fill is the only account this program signs for in the swap instruction.
+    let accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|acc_info| AccountMeta {
+            pubkey: acc_info.key(),
+            is_signer: acc_info.is_signer || acc_info.key() == prepared_fill_key,
+            is_writable: acc_info.is_writable,
+        })
+        .collect();
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.program.to_account_info());
+
+    invoke_signed(
+        &Instruction {
+            program_id: ctx.accounts.program.key(),
+            accounts,
+            data: swap_data,
+        },
+        &account_infos,
+        &[prepared_fill_signer_seeds],
+    )?;
+
+    // The swap must have delivered at least the minimum amount out to the redeemer.
+    ctx.accounts.dst_token.reload()?;
+    let amount_out = ctx.accounts.dst_token.amount.saturating_sub(balance_before);
+    require!(
+        amount_out >= min_amount_out,
+        TokenRouterError::SwapAmountOutTooLow
+    );
+
+    // All of the prepared fill's tokens must have been swapped.
+    let custody_token = &mut ctx.accounts.prepared_custody_token;
+    custody_token.reload()?;
+    require_eq!(
+        custody_token.amount,
+        0,
+        TokenRouterError::SwapAmountInRemaining
+    );
+
+    // Finally close token account.
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: custody_token.to_account_info(),
+            destination: ctx.accounts.beneficiary.to_account_info(),
+            authority: ctx.accounts.prepared_fill.to_account_info(),
+        },
+        &[prepared_fill_signer_seeds],
+    ))
+}
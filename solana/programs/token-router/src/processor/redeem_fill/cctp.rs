@@ -99,7 +99,7 @@ pub struct RedeemCctpFill<'info> {
     #[account(
         init_if_needed,
         payer = payer,
-        token::mint = usdc,
+        token::mint = mint,
         token::authority = prepared_fill,
         seeds = [
             crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
@@ -109,7 +109,8 @@ pub struct RedeemCctpFill<'info> {
     )]
     prepared_custody_token: Account<'info, token::TokenAccount>,
 
-    usdc: Usdc<'info>,
+    /// Mint of the redeemed asset.
+    mint: CheckedMint<'info>,
 
     /// Registered emitter account representing a Circle Integration on another network.
     ///
@@ -124,7 +125,7 @@ pub struct RedeemCctpFill<'info> {
                 TokenRouterError::InvalidSourceRouter
             );
             require!(
-                emitter.address == source_router_endpoint.address,
+                source_router_endpoint.is_source_address(&emitter.address, Clock::get()?.slot),
                 TokenRouterError::InvalidSourceRouter
             );
 
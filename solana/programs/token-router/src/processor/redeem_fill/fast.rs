@@ -47,7 +47,7 @@ pub struct RedeemFastFill<'info> {
     #[account(
         init_if_needed,
         payer = payer,
-        token::mint = usdc,
+        token::mint = mint,
         token::authority = prepared_fill,
         seeds = [
             crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
@@ -57,7 +57,12 @@ pub struct RedeemFastFill<'info> {
     )]
     prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
 
-    usdc: Usdc<'info>,
+    /// Mint of the fast fill amount, which is USDC unless the fast fill was delivered in a
+    /// destination asset.
+    ///
+    /// CHECK: Must equal the fast fill's mint.
+    #[account(address = fast_fill.mint)]
+    mint: UncheckedAccount<'info>,
 
     /// CHECK: Seeds must be \["emitter"] (Matching Engine program).
     matching_engine_custodian: UncheckedAccount<'info>,
@@ -68,11 +73,16 @@ pub struct RedeemFastFill<'info> {
     /// CHECK: Seeds must be \["endpoint", SOLANA_CHAIN.to_be_bytes()\] (Matching Engine program).
     matching_engine_to_endpoint: UncheckedAccount<'info>,
 
-    /// CHECK: Mutable. Seeds must be \["local-custody", source_chain.to_be_bytes()\]
+    /// CHECK: Mutable. Seeds must be \["local-custody", source_chain.to_be_bytes(), mint seed\]
     /// (Matching Engine program).
     #[account(mut)]
     matching_engine_local_custody_token: UncheckedAccount<'info>,
 
+    /// CHECK: Mutable. Token account owned by the fast fill (Matching Engine program). Only
+    /// required if the fast fill owns its custody token account.
+    #[account(mut)]
+    matching_engine_fast_fill_custody_token: Option<UncheckedAccount<'info>>,
+
     /// CHECK: Seeds must be \["__event_authority"] (Matching Engine program).
     matching_engine_event_authority: UncheckedAccount<'info>,
 
@@ -114,6 +124,11 @@ fn handle_redeem_fast_fill(ctx: Context<RedeemFastFill>) -> Result<()> {
                 .accounts
                 .matching_engine_local_custody_token
                 .to_account_info(),
+            fast_fill_custody_token: ctx
+                .accounts
+                .matching_engine_fast_fill_custody_token
+                .as_ref()
+                .map(|token| token.to_account_info()),
             token_program: ctx.accounts.token_program.to_account_info(),
             event_authority: ctx
                 .accounts
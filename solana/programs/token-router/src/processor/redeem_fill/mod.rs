@@ -4,6 +4,9 @@ pub use cctp::*;
 mod fast;
 pub use fast::*;
 
+mod token_bridge;
+pub use token_bridge::*;
+
 use anchor_lang::prelude::*;
 
 fn redeem_fill_noop() -> Result<()> {
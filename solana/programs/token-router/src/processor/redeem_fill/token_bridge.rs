@@ -0,0 +1,234 @@
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    state::{Custodian, FillType, PreparedFill, PreparedFillInfo, PreparedFillSeeds},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::{
+    messages::raw::{LiquidityLayerDepositMessage, MessageToVec},
+    token_bridge::{self, TokenBridgeMessage},
+    wormhole_cctp_solana::wormhole::core_bridge_program,
+};
+
+#[derive(Accounts)]
+struct TokenBridgeCompleteTransfer<'info> {
+    /// Mint recipient token account, which is encoded as the recipient in the Token Bridge
+    /// transfer. The Token Bridge program will transfer the amount encoded in the transfer from
+    /// its custody account to this account.
+    mint_recipient: CctpMintRecipientMut<'info>,
+
+    /// CHECK: Seeds must be \["config"\] (Token Bridge program).
+    config: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \[emitter_address, emitter_chain, sequence\] (Token Bridge
+    /// program).
+    #[account(mut)]
+    claim: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \[emitter_chain, emitter_address\] (Token Bridge program).
+    registered_emitter: UncheckedAccount<'info>,
+
+    /// CHECK: Mutable. Seeds must be \[mint\] (Token Bridge program).
+    #[account(mut)]
+    custody_token: UncheckedAccount<'info>,
+
+    /// CHECK: Seeds must be \["custody_signer"\] (Token Bridge program).
+    #[account(address = token_bridge::TOKEN_BRIDGE_CUSTODY_AUTHORITY)]
+    custody_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal Wormhole Core Bridge program ID.
+    #[account(address = core_bridge_program::id())]
+    core_bridge_program: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal Token Bridge program ID.
+    #[account(address = token_bridge::TOKEN_BRIDGE_PROGRAM_ID)]
+    token_bridge_program: UncheckedAccount<'info>,
+
+    /// CHECK: Must equal rent ID.
+    #[account(address = solana_program::sysvar::rent::id())]
+    rent: UncheckedAccount<'info>,
+}
+
+/// Accounts required for [redeem_token_bridge_fill].
+#[derive(Accounts)]
+pub struct RedeemTokenBridgeFill<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    fill_vaa: TokenBridgeVaa<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = try_compute_prepared_fill_size(&fill_vaa)?,
+        seeds = [
+            PreparedFill::SEED_PREFIX,
+            fill_vaa.key().as_ref(),
+        ],
+        bump,
+    )]
+    prepared_fill: Account<'info, PreparedFill>,
+
+    /// CHECK: Mutable. Seeds must be \["custody"\, prepared_fill.key()].
+    #[account(
+        init_if_needed,
+        payer = payer,
+        token::mint = mint,
+        token::authority = prepared_fill,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_fill.key().as_ref(),
+        ],
+        bump,
+    )]
+    prepared_custody_token: Account<'info, token::TokenAccount>,
+
+    /// Mint of the redeemed asset.
+    mint: CheckedMint<'info>,
+
+    /// Registered router endpoint of the network this transfer originated from. The sender of the
+    /// Token Bridge transfer must be this endpoint's address.
+    #[account(
+        constraint = {
+            let vaa = fill_vaa.load_unchecked();
+            require_eq!(
+                vaa.emitter_chain(),
+                source_router_endpoint.chain,
+                TokenRouterError::InvalidSourceRouter
+            );
+
+            let transfer = TokenBridgeMessage::try_from(vaa.payload())
+                .unwrap()
+                .to_transfer_with_message_unchecked();
+            require!(
+                source_router_endpoint.is_source_address(&transfer.sender(), Clock::get()?.slot),
+                TokenRouterError::InvalidSourceRouter
+            );
+
+            true
+        }
+    )]
+    source_router_endpoint: RegisteredEndpoint<'info>,
+
+    token_bridge: TokenBridgeCompleteTransfer<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+/// This instruction redeems a Wormhole Token Bridge transfer, whose message is a fill, for the
+/// [mint_recipient](TokenBridgeCompleteTransfer::mint_recipient) token account.
+pub fn redeem_token_bridge_fill(ctx: Context<RedeemTokenBridgeFill>) -> Result<()> {
+    match ctx.accounts.prepared_fill.fill_type {
+        FillType::Unset => handle_redeem_fill_token_bridge(ctx),
+        _ => super::redeem_fill_noop(),
+    }
+}
+
+fn handle_redeem_fill_token_bridge(ctx: Context<RedeemTokenBridgeFill>) -> Result<()> {
+    let mint_recipient = &ctx.accounts.token_bridge.mint_recipient;
+
+    token_bridge::complete_native_with_payload(CpiContext::new_with_signer(
+        ctx.accounts
+            .token_bridge
+            .token_bridge_program
+            .to_account_info(),
+        token_bridge::CompleteNativeWithPayload {
+            payer: ctx.accounts.payer.to_account_info(),
+            config: ctx.accounts.token_bridge.config.to_account_info(),
+            vaa: ctx.accounts.fill_vaa.to_account_info(),
+            claim: ctx.accounts.token_bridge.claim.to_account_info(),
+            registered_emitter: ctx
+                .accounts
+                .token_bridge
+                .registered_emitter
+                .to_account_info(),
+            to: mint_recipient.to_account_info(),
+            redeemer: ctx.accounts.custodian.to_account_info(),
+            to_fees: mint_recipient.to_account_info(),
+            custody: ctx.accounts.token_bridge.custody_token.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            custody_authority: ctx
+                .accounts
+                .token_bridge
+                .custody_authority
+                .to_account_info(),
+            rent: ctx.accounts.token_bridge.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            core_bridge_program: ctx
+                .accounts
+                .token_bridge
+                .core_bridge_program
+                .to_account_info(),
+        },
+        &[Custodian::SIGNER_SEEDS],
+    ))?;
+
+    let vaa = ctx.accounts.fill_vaa.load_unchecked();
+    let transfer = TokenBridgeMessage::try_from(vaa.payload())
+        .unwrap()
+        .to_transfer_with_message_unchecked();
+
+    // This is safe because we know the amount is within u64 range. Native USDC has fewer than
+    // eight decimals, so the Token Bridge did not truncate the amount.
+    let amount = u64::try_from(ruint::aliases::U256::from_be_bytes(transfer.amount())).unwrap();
+
+    // This operation is safe because we already validated the fill when computing the prepared
+    // fill size.
+    let fill = LiquidityLayerDepositMessage::try_from(transfer.payload())
+        .unwrap()
+        .to_fill_unchecked();
+
+    // Set prepared fill data.
+    ctx.accounts.prepared_fill.set_inner(PreparedFill {
+        seeds: PreparedFillSeeds {
+            fill_source: ctx.accounts.fill_vaa.key(),
+            bump: ctx.bumps.prepared_fill,
+        },
+        info: PreparedFillInfo {
+            prepared_custody_token_bump: ctx.bumps.prepared_custody_token,
+            redeemer: Pubkey::from(fill.redeemer()),
+            prepared_by: ctx.accounts.payer.key(),
+            fill_type: FillType::WormholeTokenBridgeTransfer,
+            source_chain: fill.source_chain(),
+            order_sender: fill.order_sender(),
+            timestamp: vaa.timestamp().into(),
+        },
+        redeemer_message: fill.message_to_vec(),
+    });
+
+    // Finally transfer to prepared custody account.
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: mint_recipient.to_account_info(),
+                to: ctx.accounts.prepared_custody_token.to_account_info(),
+                authority: ctx.accounts.custodian.to_account_info(),
+            },
+            &[Custodian::SIGNER_SEEDS],
+        ),
+        amount,
+    )
+}
+
+fn try_compute_prepared_fill_size(fill_vaa: &TokenBridgeVaa) -> Result<usize> {
+    let vaa = fill_vaa.load_unchecked();
+    let transfer = TokenBridgeMessage::try_from(vaa.payload())
+        .unwrap()
+        .to_transfer_with_message_unchecked();
+
+    let msg = LiquidityLayerDepositMessage::try_from(transfer.payload())
+        .map_err(|_| TokenRouterError::InvalidDepositMessage)?;
+    let fill = msg
+        .fill()
+        .ok_or_else(|| TokenRouterError::InvalidDepositPayloadId)?;
+
+    Ok(PreparedFill::compute_size(
+        fill.redeemer_message_len().into(),
+    ))
+}
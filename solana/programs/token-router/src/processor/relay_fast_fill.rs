@@ -0,0 +1,126 @@
+use crate::{
+    error::TokenRouterError,
+    state::{FillType, PreparedFill},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use matching_engine::state::FastFill;
+
+/// Accounts required for [relay_fast_fill].
+#[derive(Accounts)]
+pub struct RelayFastFill<'info> {
+    relayer: Signer<'info>,
+
+    /// Relayer's token account, which receives the relayer fee.
+    #[account(
+        mut,
+        token::mint = prepared_custody_token.mint,
+    )]
+    relayer_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: Must equal the `prepared_by` field of the `prepared_fill` account, which receives the
+    /// lamports of the closed accounts.
+    #[account(
+        mut,
+        address = prepared_fill.prepared_by,
+    )]
+    prepared_by: UncheckedAccount<'info>,
+
+    /// Fast fill that the prepared fill was redeemed from. Only a fast fill with a relayer fee can
+    /// be relayed.
+    #[account(
+        address = prepared_fill.seeds.fill_source,
+        constraint = fast_fill.relayer_fee > 0 @ TokenRouterError::FastFillNotRelayable,
+    )]
+    fast_fill: Box<Account<'info, FastFill>>,
+
+    #[account(
+        mut,
+        close = prepared_by,
+        constraint = matches!(
+            prepared_fill.fill_type,
+            FillType::FastFill
+        ) @ TokenRouterError::FastFillNotRelayable,
+    )]
+    prepared_fill: Box<Account<'info, PreparedFill>>,
+
+    /// Custody token account. This account will be closed at the end of this instruction.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_fill.key()\].
+    #[account(
+        mut,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_fill.key().as_ref(),
+        ],
+        bump = prepared_fill.prepared_custody_token_bump,
+    )]
+    prepared_custody_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: Must equal the `redeemer` field of the `prepared_fill` account.
+    #[account(address = prepared_fill.redeemer)]
+    redeemer: UncheckedAccount<'info>,
+
+    /// Redeemer's associated token account, which receives the fill amount less the relayer fee.
+    #[account(
+        mut,
+        associated_token::mint = prepared_custody_token.mint,
+        associated_token::authority = redeemer,
+    )]
+    redeemer_token: Box<Account<'info, token::TokenAccount>>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn relay_fast_fill(ctx: Context<RelayFastFill>) -> Result<()> {
+    let prepared_fill = &ctx.accounts.prepared_fill;
+
+    let prepared_fill_signer_seeds = &[
+        PreparedFill::SEED_PREFIX,
+        prepared_fill.seeds.fill_source.as_ref(),
+        &[prepared_fill.seeds.bump],
+    ];
+
+    let custody_token = &ctx.accounts.prepared_custody_token;
+    let token_program = &ctx.accounts.token_program;
+
+    // The relayer fee cannot exceed what remains in the custody token account.
+    let relayer_fee = ctx.accounts.fast_fill.relayer_fee.min(custody_token.amount);
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: custody_token.to_account_info(),
+                to: ctx.accounts.relayer_token.to_account_info(),
+                authority: prepared_fill.to_account_info(),
+            },
+            &[prepared_fill_signer_seeds],
+        ),
+        relayer_fee,
+    )?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: custody_token.to_account_info(),
+                to: ctx.accounts.redeemer_token.to_account_info(),
+                authority: prepared_fill.to_account_info(),
+            },
+            &[prepared_fill_signer_seeds],
+        ),
+        custody_token.amount.saturating_sub(relayer_fee),
+    )?;
+
+    // Finally close token account.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: custody_token.to_account_info(),
+            destination: ctx.accounts.prepared_by.to_account_info(),
+            authority: prepared_fill.to_account_info(),
+        },
+        &[prepared_fill_signer_seeds],
+    ))
+}
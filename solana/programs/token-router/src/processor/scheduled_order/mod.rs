@@ -0,0 +1,5 @@
+mod place_slice_cctp;
+pub use place_slice_cctp::*;
+
+mod prepare;
+pub use prepare::*;
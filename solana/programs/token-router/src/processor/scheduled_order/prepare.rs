@@ -0,0 +1,249 @@
+use crate::{
+    composite::*,
+    error::TokenRouterError,
+    state::{OrderType, PreparedOrder, PreparedOrderInfo},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use common::TRANSFER_AUTHORITY_SEED_PREFIX;
+use solana_program::keccak;
+
+/// Accounts required for [prepare_scheduled_order].
+#[derive(Accounts)]
+#[instruction(args: PrepareScheduledOrderArgs)]
+pub struct PrepareScheduledOrder<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    custodian: CheckedCustodian<'info>,
+
+    /// The auction participant needs to set approval to this PDA if the sender (signer) is not
+    /// provided. The delegated amount must equal the amount in or this instruction will revert.
+    ///
+    /// NOTE: If this account is provided, the sender token's owner will be encoded as the order
+    /// sender.
+    ///
+    /// CHECK: Seeds must be \["transfer-authority", prepared_order.key(), args.hash(),
+    /// refund_token.key()\].
+    #[account(
+        seeds = [
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+            &args.hash().0,
+            refund_token.key().as_ref()
+        ],
+        bump,
+        constraint = {
+            require_eq!(
+                sender_token.delegated_amount,
+                args.amount_in,
+                TokenRouterError::DelegatedAmountMismatch,
+            );
+
+            true
+        }
+    )]
+    program_transfer_authority: Option<UncheckedAccount<'info>>,
+
+    /// Sender, who has the authority to transfer assets from the sender token account. If this
+    /// account is not provided, the program transfer authority account must be some account.
+    ///
+    /// NOTE: If this account is provided, this pubkey will be encoded as the order sender.
+    sender: Option<Signer<'info>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PreparedOrder::compute_size(args.redeemer_message.len()),
+        constraint = {
+            crate::processor::require_valid_order_args(
+                args.amount_in,
+                None,
+                &args.redeemer,
+                &args.redeemer_message,
+            )?;
+
+            // There must be more than one slice, each of which places a nonzero amount after the
+            // keeper reward is taken.
+            require!(
+                args.num_slices > 1 && args.slice_interval > 0 && args.slice_amounts().is_some(),
+                TokenRouterError::InvalidSchedule
+            );
+
+            true
+        }
+    )]
+    prepared_order: Account<'info, PreparedOrder>,
+
+    /// Token account where the amount in is transferred from. This account must be owned by the
+    /// sender or have delegated authority to the program transfer authority.
+    #[account(mut)]
+    sender_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// Token account that the amount in is refunded to if the order is closed before its last
+    /// slice is placed.
+    #[account(
+        token::mint = mint,
+    )]
+    refund_token: Account<'info, token::TokenAccount>,
+
+    /// Custody token account, which holds the amount in until the order is placed or refunded.
+    ///
+    /// CHECK: Mutable. Seeds must be \["prepared-custody", prepared_order.key()\].
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = custodian,
+        seeds = [
+            crate::PREPARED_CUSTODY_TOKEN_SEED_PREFIX,
+            prepared_order.key().as_ref(),
+        ],
+        bump,
+    )]
+    prepared_custody_token: Account<'info, token::TokenAccount>,
+
+    mint: CheckedMint<'info>,
+
+    #[account(
+        constraint = {
+            // Each slice is its own order, where the last slice is the largest. The schedule was
+            // validated with the prepared order, so there are slice amounts.
+            let (slice_amount, last_slice_amount) = args.slice_amounts().unwrap();
+            for amount_in in [slice_amount, last_slice_amount] {
+                crate::processor::require_valid_target_endpoint(
+                    &target_router_endpoint,
+                    args.target_chain,
+                    amount_in,
+                    &args.redeemer_message,
+                )?;
+            }
+
+            true
+        }
+    )]
+    target_router_endpoint: RegisteredEndpoint<'info>,
+
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+/// Arguments for [prepare_scheduled_order].
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PrepareScheduledOrderArgs {
+    /// Amount of tokens to transfer over all slices.
+    pub amount_in: u64,
+
+    /// Number of slices to place [amount_in](Self::amount_in) in.
+    pub num_slices: u16,
+
+    /// Number of slots between slices.
+    pub slice_interval: u64,
+
+    /// Amount taken from each slice to reward the keeper who placed it.
+    pub keeper_reward: u64,
+
+    /// The Wormhole chain ID of the network to transfer tokens to.
+    pub target_chain: u16,
+
+    /// The address of the redeeming contract on the target chain.
+    pub redeemer: [u8; 32],
+
+    /// Arbitrary payload to be sent to the [redeemer](Self::redeemer) with each slice, which can be
+    /// used to encode instructions or data for another network's smart contract.
+    pub redeemer_message: Vec<u8>,
+}
+
+impl PrepareScheduledOrderArgs {
+    pub fn hash(&self) -> keccak::Hash {
+        keccak::hash(&self.try_to_vec().unwrap())
+    }
+
+    /// Returns the amount placed by each slice and by the last slice (which also places the
+    /// remainder of dividing the amount in), both excluding the keeper reward. Returns `None` if a
+    /// slice would not place anything.
+    pub fn slice_amounts(&self) -> Option<(u64, u64)> {
+        let amount_per_slice = self.amount_in.checked_div(self.num_slices.into())?;
+        let slice_amount = amount_per_slice
+            .checked_sub(self.keeper_reward)
+            .filter(|&amount| amount > 0)?;
+
+        let last_slice_amount = amount_per_slice
+            .checked_mul(u64::from(self.num_slices).checked_sub(1)?)
+            .and_then(|amount| self.amount_in.checked_sub(amount))
+            .and_then(|amount| amount.checked_sub(self.keeper_reward))?;
+
+        Some((slice_amount, last_slice_amount))
+    }
+}
+
+pub fn prepare_scheduled_order(
+    ctx: Context<PrepareScheduledOrder>,
+    args: PrepareScheduledOrderArgs,
+) -> Result<()> {
+    let hashed_args = args.hash();
+
+    // This is safe because the prepared order constraints check that the slice amounts are valid.
+    let (slice_amount, _) = args.slice_amounts().unwrap();
+
+    let PrepareScheduledOrderArgs {
+        amount_in,
+        num_slices,
+        slice_interval,
+        keeper_reward,
+        target_chain,
+        redeemer,
+        redeemer_message,
+    } = args;
+
+    let token_program = &ctx.accounts.token_program;
+    let sender_token = &ctx.accounts.sender_token;
+    let custody_token = &ctx.accounts.prepared_custody_token;
+    let refund_token = &ctx.accounts.refund_token;
+
+    let prepared_order = &mut ctx.accounts.prepared_order;
+    let prepared_order_key = prepared_order.key();
+
+    // Finally transfer amount to custody token account.
+    let order_sender = crate::processor::transfer_to_prepared_custody(
+        token_program,
+        ctx.accounts.sender.as_ref(),
+        ctx.accounts
+            .program_transfer_authority
+            .as_ref()
+            .map(|authority| (authority, ctx.bumps.program_transfer_authority.unwrap())),
+        &[
+            TRANSFER_AUTHORITY_SEED_PREFIX,
+            prepared_order_key.as_ref(),
+            &hashed_args.0,
+            refund_token.key().as_ref(),
+        ],
+        sender_token,
+        custody_token,
+        amount_in,
+    )?;
+
+    // Set the values in prepared order account.
+    prepared_order.set_inner(PreparedOrder {
+        info: PreparedOrderInfo {
+            order_sender,
+            prepared_by: ctx.accounts.payer.key(),
+            order_type: OrderType::Scheduled {
+                remaining_slices: num_slices,
+                slice_amount,
+                keeper_reward,
+                slice_interval,
+                next_slice_slot: Clock::get()?.slot,
+            },
+            src_token: sender_token.key(),
+            refund_token: refund_token.key(),
+            target_chain,
+            redeemer,
+            prepared_custody_token_bump: ctx.bumps.prepared_custody_token,
+        },
+        redeemer_message,
+    });
+
+    // Done.
+    Ok(())
+}
@@ -16,6 +16,17 @@ pub struct Custodian {
     /// Indicate who last set the `paused` value. When the program is first initialized, this is set
     /// to the `owner`.
     pub paused_set_by: Pubkey,
+
+    /// Slot after which the pending owner can no longer confirm the ownership transfer request.
+    pub pending_owner_expiry_slot: u64,
+
+    /// Proposed assistant, who must accept the role before replacing the current assistant.
+    pub pending_owner_assistant: Option<Pubkey>,
+
+    /// Maximum fee an integrator can collect from an order's amount in, where
+    /// [FEE_PRECISION_MAX](crate::FEE_PRECISION_MAX) is 100%. Integrator fees are disabled when
+    /// this value is zero.
+    pub max_integrator_fee_bps: u32,
 }
 
 impl Custodian {
@@ -42,6 +53,14 @@ impl common::admin::PendingOwner for Custodian {
     fn pending_owner_mut(&mut self) -> &mut Option<Pubkey> {
         &mut self.pending_owner
     }
+
+    fn pending_owner_expiry_slot(&self) -> u64 {
+        self.pending_owner_expiry_slot
+    }
+
+    fn pending_owner_expiry_slot_mut(&mut self) -> &mut u64 {
+        &mut self.pending_owner_expiry_slot
+    }
 }
 
 impl common::admin::OwnerAssistant for Custodian {
@@ -54,6 +73,16 @@ impl common::admin::OwnerAssistant for Custodian {
     }
 }
 
+impl common::admin::PendingOwnerAssistant for Custodian {
+    fn pending_owner_assistant(&self) -> &Option<Pubkey> {
+        &self.pending_owner_assistant
+    }
+
+    fn pending_owner_assistant_mut(&mut self) -> &mut Option<Pubkey> {
+        &mut self.pending_owner_assistant
+    }
+}
+
 #[cfg(test)]
 mod test {
     use solana_program::pubkey::Pubkey;
@@ -1,8 +1,20 @@
 mod custodian;
 pub use custodian::*;
 
+mod owner_set;
+pub use owner_set::*;
+
 mod prepared_fill;
 pub use prepared_fill::*;
 
 mod prepared_order;
 pub use prepared_order::*;
+
+mod role_holder;
+pub use role_holder::*;
+
+mod sender_state;
+pub use sender_state::*;
+
+mod swap_program;
+pub use swap_program::*;
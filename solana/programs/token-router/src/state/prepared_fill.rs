@@ -5,6 +5,7 @@ pub enum FillType {
     Unset,
     WormholeCctpDeposit,
     FastFill,
+    WormholeTokenBridgeTransfer,
 }
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
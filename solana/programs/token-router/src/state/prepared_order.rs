@@ -2,7 +2,105 @@ use anchor_lang::prelude::*;
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub enum OrderType {
-    Market { min_amount_out: Option<u64> },
+    Market {
+        min_amount_out: Option<u64>,
+    },
+    /// Order that stays prepared until a Matching Engine auction completes at or below a limit
+    /// price, at which point anyone (e.g. a keeper) can place it.
+    Limit {
+        /// Maximum keeper fee deducted from the amount in when the order is placed.
+        max_keeper_fee: u64,
+
+        /// If provided, minimum amount of tokens to receive in exchange for the amount in.
+        min_amount_out: Option<u64>,
+
+        /// Matching Engine auction whose outcome determines whether this order can be placed.
+        auction: Pubkey,
+
+        /// Limit price. This order can only be placed once the auction has completed with an offer
+        /// price no greater than this value.
+        max_offer_price: u64,
+
+        /// Timestamp after which this order can no longer be placed.
+        expiry: i64,
+    },
+    /// Order whose amount in is placed in equal slices at fixed slot intervals. Anyone (e.g. a
+    /// keeper) can place the next slice once it is due and collects a reward from each slice.
+    Scheduled {
+        /// Number of slices that have not been placed yet. The last slice places whatever remains
+        /// in custody.
+        remaining_slices: u16,
+
+        /// Amount placed by each slice, excluding the keeper reward.
+        slice_amount: u64,
+
+        /// Amount taken from each slice to reward the keeper who placed it.
+        keeper_reward: u64,
+
+        /// Number of slots between slices.
+        slice_interval: u64,
+
+        /// Slot at which the next slice can be placed.
+        next_slice_slot: u64,
+    },
+    /// Market order that can no longer be placed after its expiry. Anyone can refund an expired
+    /// order.
+    ExpiringMarket {
+        min_amount_out: Option<u64>,
+
+        /// Timestamp after which this order can no longer be placed.
+        expiry: i64,
+    },
+}
+
+impl OrderType {
+    /// Returns whether an order can be placed in full at the given timestamp, where `fee` is
+    /// deducted from `amount_in` when it is placed. Market orders can be placed until they expire,
+    /// but scheduled orders can only be placed in slices. Limit orders also require the offer price
+    /// of their completed auction, which is `None` if the auction has not completed.
+    pub fn can_place(
+        &self,
+        timestamp: i64,
+        amount_in: u64,
+        fee: u64,
+        auction_offer_price: Option<u64>,
+    ) -> bool {
+        match self {
+            Self::Market { .. } => true,
+            Self::ExpiringMarket { expiry, .. } => timestamp <= *expiry,
+            Self::Scheduled { .. } => false,
+            Self::Limit {
+                max_keeper_fee,
+                min_amount_out,
+                max_offer_price,
+                expiry,
+                ..
+            } => {
+                let limit_price_met = match auction_offer_price {
+                    Some(offer_price) => offer_price <= *max_offer_price,
+                    None => false,
+                };
+                let acceptable_amount_out = match min_amount_out {
+                    Some(min_amount_out) => amount_in.saturating_sub(fee) >= *min_amount_out,
+                    None => true,
+                };
+
+                timestamp <= *expiry
+                    && limit_price_met
+                    && fee <= *max_keeper_fee
+                    && acceptable_amount_out
+            }
+        }
+    }
+
+    /// Returns the timestamp after which an order can no longer be placed, if any.
+    pub fn expiry(&self) -> Option<i64> {
+        match self {
+            Self::Market { .. } => None,
+            Self::ExpiringMarket { expiry, .. } | Self::Limit { expiry, .. } => Some(*expiry),
+            Self::Scheduled { .. } => None,
+        }
+    }
 }
 
 #[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -28,6 +126,10 @@ pub struct PreparedOrder {
 }
 
 impl PreparedOrder {
+    /// Seed prefix of prepared orders whose addresses are derived from the order sender and its
+    /// nonce. Prepared orders may also be created from arbitrary keypairs.
+    pub const SEED_PREFIX: &'static [u8] = b"prepared-order";
+
     pub(crate) fn compute_size(redeemer_message_len: usize) -> usize {
         const FIXED: usize = 8 // DISCRIMINATOR
             + PreparedOrderInfo::INIT_SPACE
@@ -45,3 +147,59 @@ impl std::ops::Deref for PreparedOrder {
         &self.info
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_place_market_order_with_expiry() {
+        let order_type = OrderType::ExpiringMarket {
+            min_amount_out: None,
+            expiry: 100,
+        };
+
+        assert!(order_type.can_place(100, 1_000, 0, None));
+        assert!(!order_type.can_place(101, 1_000, 0, None));
+        assert_eq!(order_type.expiry(), Some(100));
+
+        let order_type = OrderType::Market {
+            min_amount_out: None,
+        };
+
+        assert!(order_type.can_place(i64::MAX, 1_000, 0, None));
+        assert_eq!(order_type.expiry(), None);
+    }
+
+    #[test]
+    fn can_place_limit_order() {
+        let order_type = OrderType::Limit {
+            max_keeper_fee: 10,
+            min_amount_out: Some(990),
+            auction: Pubkey::new_unique(),
+            max_offer_price: 50,
+            expiry: 100,
+        };
+
+        assert!(order_type.can_place(100, 1_000, 10, Some(50)));
+        assert!(!order_type.can_place(101, 1_000, 10, Some(50)));
+        assert!(!order_type.can_place(100, 1_000, 11, Some(50)));
+        assert!(!order_type.can_place(100, 999, 10, Some(50)));
+        assert!(!order_type.can_place(100, 1_000, 10, Some(51)));
+        assert!(!order_type.can_place(100, 1_000, 10, None));
+    }
+
+    #[test]
+    fn cannot_place_limit_order_above_max_keeper_fee() {
+        let order_type = OrderType::Limit {
+            max_keeper_fee: 0,
+            min_amount_out: None,
+            auction: Pubkey::new_unique(),
+            max_offer_price: u64::MAX,
+            expiry: 100,
+        };
+
+        assert!(order_type.can_place(100, 1_000, 0, Some(0)));
+        assert!(!order_type.can_place(100, 1_000, 1, Some(0)));
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Tracks the nonce of the next [PreparedOrder](crate::state::PreparedOrder) account prepared for
+/// an order sender. See [prepare_market_order_v2](crate::token_router::prepare_market_order_v2).
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct SenderState {
+    pub bump: u8,
+
+    /// Nonce used to derive the address of the order sender's next prepared order.
+    pub next_nonce: u64,
+}
+
+impl SenderState {
+    pub const SEED_PREFIX: &'static [u8] = b"sender-state";
+}
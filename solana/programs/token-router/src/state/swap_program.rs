@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Swap program that a redeemer may swap a prepared fill's tokens with. See
+/// [redeem_and_swap](crate::token_router::redeem_and_swap).
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct SwapProgram {
+    pub bump: u8,
+
+    pub program_id: Pubkey,
+}
+
+impl SwapProgram {
+    pub const SEED_PREFIX: &'static [u8] = b"swap-program";
+}
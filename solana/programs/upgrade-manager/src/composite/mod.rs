@@ -79,6 +79,11 @@ pub struct ExecuteUpgrade<'info> {
 
 #[derive(Accounts)]
 pub struct CommitUpgrade<'info> {
+    /// Pays for any additional rent required when the liquidity layer program migrates its
+    /// accounts.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     #[account(
         constraint = {
             require_keys_eq!(
@@ -133,6 +138,8 @@ pub struct CommitUpgrade<'info> {
         seeds::program = bpf_loader_upgradeable::id(),
     )]
     program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
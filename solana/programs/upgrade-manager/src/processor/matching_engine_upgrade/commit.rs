@@ -7,6 +7,11 @@ pub struct CommitMatchingEngineUpgrade<'info> {
     #[account(mut)]
     matching_engine_custodian: UncheckedAccount<'info>,
 
+    /// CHECK: Seeds must be \["circuit-breaker"\] (Matching Engine program). This account is
+    /// created when migrating if it does not exist.
+    #[account(mut)]
+    matching_engine_circuit_breaker: UncheckedAccount<'info>,
+
     #[account(
         constraint = {
             require_keys_eq!(
@@ -22,18 +27,26 @@ pub struct CommitMatchingEngineUpgrade<'info> {
 
 pub fn commit_matching_engine_upgrade(ctx: Context<CommitMatchingEngineUpgrade>) -> Result<()> {
     let custodian = &ctx.accounts.matching_engine_custodian;
-    let CommitUpgrade { admin, program, .. } = &ctx.accounts.commit_upgrade;
+    let CommitUpgrade {
+        payer,
+        admin,
+        program,
+        system_program,
+        ..
+    } = &ctx.accounts.commit_upgrade;
 
     // NOTE: We do not want to pass in any remaining accounts to this instruction.
     matching_engine::cpi::migrate(CpiContext::new_with_signer(
         program.to_account_info(),
         matching_engine::cpi::accounts::Migrate {
-            admin: matching_engine::cpi::accounts::OwnerOnly {
-                owner: admin.upgrade_authority.to_account_info(),
-                custodian: matching_engine::cpi::accounts::CheckedCustodian {
-                    custodian: custodian.to_account_info(),
-                },
-            },
+            payer: payer.to_account_info(),
+            owner: admin.upgrade_authority.to_account_info(),
+            custodian: custodian.to_account_info(),
+            circuit_breaker: ctx
+                .accounts
+                .matching_engine_circuit_breaker
+                .to_account_info(),
+            system_program: system_program.to_account_info(),
         },
         &[UPGRADE_AUTHORITY_SIGNER_SEEDS],
     ))?;
@@ -22,18 +22,22 @@ pub struct CommitTokenRouterUpgrade<'info> {
 
 pub fn commit_token_router_upgrade(ctx: Context<CommitTokenRouterUpgrade>) -> Result<()> {
     let custodian = &ctx.accounts.token_router_custodian;
-    let CommitUpgrade { admin, program, .. } = &ctx.accounts.commit_upgrade;
+    let CommitUpgrade {
+        payer,
+        admin,
+        program,
+        system_program,
+        ..
+    } = &ctx.accounts.commit_upgrade;
 
     // NOTE: We do not want to pass in any remaining accounts to this instruction.
     token_router::cpi::migrate(CpiContext::new_with_signer(
         program.to_account_info(),
         token_router::cpi::accounts::Migrate {
-            admin: token_router::cpi::accounts::OwnerOnly {
-                owner: admin.upgrade_authority.to_account_info(),
-                custodian: token_router::cpi::accounts::CheckedCustodian {
-                    custodian: custodian.to_account_info(),
-                },
-            },
+            payer: payer.to_account_info(),
+            owner: admin.upgrade_authority.to_account_info(),
+            custodian: custodian.to_account_info(),
+            system_program: system_program.to_account_info(),
         },
         &[UPGRADE_AUTHORITY_SIGNER_SEEDS],
     ))?;
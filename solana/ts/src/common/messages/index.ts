@@ -1,5 +1,6 @@
 import {
     FastMarketOrder,
+    FastMarketOrderV2,
     Message,
     messages,
 } from "@wormhole-foundation/example-liquidity-layer-definitions";
@@ -9,15 +10,22 @@ import { ID_DEPOSIT, LiquidityLayerDeposit } from "./deposit";
 export * from "./deposit";
 
 export const ID_FAST_MARKET_ORDER = messages("FastMarketOrder").id;
+export const ID_FAST_MARKET_ORDER_V2 = messages("FastMarketOrderV2").id;
 
 export class LiquidityLayerMessage {
     deposit?: LiquidityLayerDeposit;
     fastMarketOrder?: FastMarketOrder;
+    fastMarketOrderV2?: FastMarketOrderV2;
 
-    constructor(message: { deposit?: LiquidityLayerDeposit; fastMarketOrder?: FastMarketOrder }) {
-        const { deposit, fastMarketOrder } = message;
+    constructor(message: {
+        deposit?: LiquidityLayerDeposit;
+        fastMarketOrder?: FastMarketOrder;
+        fastMarketOrderV2?: FastMarketOrderV2;
+    }) {
+        const { deposit, fastMarketOrder, fastMarketOrderV2 } = message;
         this.deposit = deposit;
         this.fastMarketOrder = fastMarketOrder;
+        this.fastMarketOrderV2 = fastMarketOrderV2;
     }
 
     static decode(buf: Buffer): LiquidityLayerMessage {
@@ -25,6 +33,7 @@ export class LiquidityLayerMessage {
 
         let deposit: LiquidityLayerDeposit | undefined;
         let fastMarketOrder: FastMarketOrder | undefined;
+        let fastMarketOrderV2: FastMarketOrderV2 | undefined;
 
         switch (payloadId) {
             case ID_DEPOSIT: {
@@ -36,19 +45,38 @@ export class LiquidityLayerMessage {
                 if (!isChain(fastMarketOrder.targetChain)) throw new Error("Invalid target chain");
                 break;
             }
+            case ID_FAST_MARKET_ORDER_V2: {
+                fastMarketOrderV2 = Message.deserialize(new Uint8Array(buf)) as FastMarketOrderV2;
+                if (!isChain(fastMarketOrderV2.targetChain)) throw new Error("Invalid target chain");
+
+                // The second version extends the first one, so the fast market order is also set for
+                // anything that only needs the original fields.
+                const {
+                    gasDropoff,
+                    gasDropoffFee,
+                    relayerFee,
+                    destinationMint,
+                    destinationMinAmountOut,
+                    ...order
+                } = fastMarketOrderV2;
+                fastMarketOrder = { ...order, payloadId: ID_FAST_MARKET_ORDER };
+                break;
+            }
             default: {
                 throw new Error("Invalid Liquidity Layer message");
             }
         }
 
-        return new LiquidityLayerMessage({ deposit, fastMarketOrder });
+        return new LiquidityLayerMessage({ deposit, fastMarketOrder, fastMarketOrderV2 });
     }
 
     encode(): Buffer {
-        const { deposit, fastMarketOrder } = this;
+        const { deposit, fastMarketOrder, fastMarketOrderV2 } = this;
 
         if (deposit !== undefined) {
             return deposit.encode();
+        } else if (fastMarketOrderV2 !== undefined) {
+            return Buffer.from(Message.serialize(fastMarketOrderV2));
         } else if (fastMarketOrder !== undefined) {
             return Buffer.from(Message.serialize(fastMarketOrder));
         }
@@ -14,6 +14,42 @@ export type MatchingEngine = {
     "repository": "https://github.com/wormhole-foundation/example-liquidity-layer"
   },
   "instructions": [
+    {
+      "name": "acceptOwnerAssistant",
+      "docs": [
+        "This instruction is used to accept the owner assistant role, replacing the `owner_assistant`",
+        "with the `pending_owner_assistant` in the `Custodian` account. This instruction can only be",
+        "called by the `pending_owner_assistant`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `AcceptOwnerAssistant` context."
+      ],
+      "discriminator": [
+        172,
+        99,
+        207,
+        235,
+        246,
+        32,
+        232,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "pendingOwnerAssistant",
+          "docs": [
+            "Must be the pending owner assistant set in the [Custodian] account."
+          ],
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
     {
       "name": "addAuctionHistoryEntry",
       "docs": [
@@ -56,8 +92,8 @@ export type MatchingEngine = {
       "name": "addCctpRouterEndpoint",
       "docs": [
         "This instruction is used to add a new Token Router endpoint from a foreign chain. The",
-        "endpoint must be CCTP compatible. This instruction can only be called by the `owner` or",
-        "`owner_assistant`.",
+        "endpoint must be CCTP compatible. This instruction can only be called by the `owner`,",
+        "`owner_assistant` or an `EndpointManager` role holder.",
         "",
         "# Arguments",
         "",
@@ -84,7 +120,7 @@ export type MatchingEngine = {
           "name": "admin",
           "accounts": [
             {
-              "name": "ownerOrAssistant",
+              "name": "authority",
               "signer": true
             },
             {
@@ -94,6 +130,13 @@ export type MatchingEngine = {
                   "name": "custodian"
                 }
               ]
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
             }
           ]
         },
@@ -102,17 +145,32 @@ export type MatchingEngine = {
           "writable": true
         },
         {
-          "name": "localCustodyToken",
+          "name": "routerEndpointRegistry",
           "writable": true
         },
         {
-          "name": "usdc",
+          "name": "inboundNotional",
+          "writable": true
+        },
+        {
+          "name": "mint",
           "accounts": [
             {
               "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
             }
           ]
         },
+        {
+          "name": "localCustodyToken",
+          "writable": true
+        },
         {
           "name": "remoteTokenMessenger",
           "docs": [
@@ -142,7 +200,7 @@ export type MatchingEngine = {
       "docs": [
         "This instruction is used to add a new Local Router endpoint. Local means that the",
         "Token Router program exists on Solana. This instruction can only be called by the",
-        "`owner` or `owner_assistant`.",
+        "`owner`, `owner_assistant` or an `EndpointManager` role holder.",
         "",
         "# Arguments",
         "",
@@ -168,7 +226,7 @@ export type MatchingEngine = {
           "name": "admin",
           "accounts": [
             {
-              "name": "ownerOrAssistant",
+              "name": "authority",
               "signer": true
             },
             {
@@ -178,6 +236,13 @@ export type MatchingEngine = {
                   "name": "custodian"
                 }
               ]
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
             }
           ]
         },
@@ -185,6 +250,14 @@ export type MatchingEngine = {
           "name": "routerEndpoint",
           "writable": true
         },
+        {
+          "name": "routerEndpointRegistry",
+          "writable": true
+        },
+        {
+          "name": "inboundNotional",
+          "writable": true
+        },
         {
           "name": "local",
           "accounts": [
@@ -209,111 +282,140 @@ export type MatchingEngine = {
       "args": []
     },
     {
-      "name": "cancelOwnershipTransferRequest",
+      "name": "addNttRouterEndpoint",
       "docs": [
-        "This instruction cancels an ownership transfer request by resetting the `pending_owner`",
-        "field in the `Custodian` account. This instruction can only be called by the `owner`.",
+        "This instruction is used to add a new Token Router endpoint from a foreign chain whose",
+        "assets are transferred via Native Token Transfers (NTT). The endpoint specifies the NTT",
+        "manager program and transceiver used for delivery. This instruction can only be called by",
+        "the `owner`, `owner_assistant` or an `EndpointManager` role holder.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `CancelOwnershipTransferRequest` context."
+        "* `ctx`  - `AddNttRouterEndpoint` context.",
+        "* `args` - The `AddNttRouterEndpointArgs`, see `admin.rs`."
       ],
       "discriminator": [
-        167,
-        61,
-        9,
-        35,
-        192,
-        41,
-        64,
-        178
+        58,
+        164,
+        125,
+        198,
+        31,
+        250,
+        31,
+        175
       ],
       "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "admin",
           "accounts": [
             {
-              "name": "owner",
+              "name": "authority",
               "signer": true
             },
             {
               "name": "custodian",
-              "writable": true
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
             }
           ]
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "closeAuction",
-      "docs": [
-        "This instruction is used to close an auction account after the auction has been settled and",
-        "the VAA's timestamp indicates the order has expired. This instruction can be called by",
-        "anyone to return the auction's preparer lamports from the rent required to keep this account",
-        "alive. The auction data will be serialized as Anchor event CPI instruction data.",
-        "",
-        "# Arguments",
-        "",
-        "* `ctx` - `CloseAuction` context."
-      ],
-      "discriminator": [
-        225,
-        129,
-        91,
-        48,
-        215,
-        73,
-        203,
-        172
-      ],
-      "accounts": [
+        },
         {
-          "name": "auction",
+          "name": "routerEndpoint",
           "writable": true
         },
         {
-          "name": "beneficiary",
-          "docs": [
-            "[Auction::prepared_by]."
-          ],
+          "name": "routerEndpointRegistry",
           "writable": true
         },
         {
-          "name": "eventAuthority"
+          "name": "inboundNotional",
+          "writable": true
         },
         {
-          "name": "program"
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "localCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "addNttRouterEndpointArgs"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "closeProposal",
+      "name": "addSupportedMint",
       "docs": [
-        "This instruction is used to close an existing proposal by closing the proposal account. This",
-        "instruction can only be called by the `owner` or `owner_assistant`.",
+        "This instruction is used to add a mint (other than USDC, which is always supported) whose",
+        "orders can be auctioned and settled. It also creates the custodian's token account for this",
+        "mint, which is used as the CCTP mint recipient. This instruction can only be called by the",
+        "`owner`.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `CloseProposal` context."
+        "* `ctx` - `AddSupportedMint` context."
       ],
       "discriminator": [
-        213,
-        178,
         139,
-        19,
-        50,
-        191,
-        82,
-        245
+        22,
+        183,
+        232,
+        150,
+        64,
+        233,
+        106
       ],
       "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "admin",
           "accounts": [
             {
-              "name": "ownerOrAssistant",
+              "name": "owner",
               "signer": true
             },
             {
@@ -327,127 +429,118 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "proposedBy",
-          "writable": true
+          "name": "mint",
+          "docs": [
+            "USDC is always supported, so it cannot be added."
+          ]
         },
         {
-          "name": "proposal",
+          "name": "supportedMint",
           "writable": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "closeRedeemedFastFill",
-      "docs": [
-        "This instruction is used to return lamports to the creator of the `FastFill` account only",
-        "when this fill was redeemed via the Token Router program.",
-        "",
-        "# Arguments",
-        "",
-        "* `ctx` - `CloseRedeemedFastFill` context."
-      ],
-      "discriminator": [
-        44,
-        104,
-        207,
-        178,
-        224,
-        7,
-        28,
-        219
-      ],
-      "accounts": [
+        },
         {
-          "name": "preparedBy",
+          "name": "feeRecipientToken",
           "docs": [
-            "Instead of having the preparer sign for this instruction, we allow anyone to call this",
-            "instruction on behalf of the preparer.",
-            ""
+            "Token account that collects fees for orders of this mint settled without an auction."
+          ]
+        },
+        {
+          "name": "cctpMintRecipient",
+          "docs": [
+            "Custodian's token account for this mint, which is encoded as the mint recipient in CCTP",
+            "messages that transfer this mint."
           ],
           "writable": true
         },
         {
-          "name": "fastFill",
-          "writable": true
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram"
+        },
+        {
+          "name": "systemProgram"
         }
       ],
       "args": []
     },
     {
-      "name": "completeFastFill",
+      "name": "addTokenBridgeRouterEndpoint",
       "docs": [
-        "This instruction is used to complete the fast fill after the `FastFill` account has been",
-        "created. The Token Router program on Solana will invoke this instruction to complete the",
-        "fast fill, marking it as redeemed. Tokens will be deposited into the local endpoint's",
-        "custody account.",
+        "This instruction is used to add a new Token Router endpoint from a foreign chain that does",
+        "not support CCTP. Assets are transferred to and from this endpoint via the Wormhole Token",
+        "Bridge. This instruction can only be called by the `owner`, `owner_assistant` or an",
+        "`EndpointManager` role holder.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `CompleteFastFill` context."
+        "* `ctx`  - `AddTokenBridgeRouterEndpoint` context.",
+        "* `args` - The `AddTokenBridgeRouterEndpointArgs`, see `admin.rs`."
       ],
       "discriminator": [
-        113,
-        252,
-        68,
-        134,
-        84,
-        61,
-        113,
-        203
+        32,
+        145,
+        105,
+        148,
+        225,
+        70,
+        218,
+        175
       ],
       "accounts": [
         {
-          "name": "custodian",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
           "accounts": [
             {
-              "name": "custodian"
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
             }
           ]
         },
         {
-          "name": "fastFill",
-          "docs": [
-            "Fast fill account.",
-            "",
-            "NOTE: This account may have been closed if the fast fill was already redeemed, so this",
-            "deserialization will fail in this case.",
-            "",
-            "Seeds must be \\[\"fast-fill\", source_chain, order_sender, sequence\\]."
-          ],
+          "name": "routerEndpoint",
           "writable": true
         },
         {
-          "name": "tokenRouterEmitter",
-          "docs": [
-            "Only the registered local Token Router program can call this instruction. It is allowed to",
-            "invoke this instruction by using its emitter (i.e. its Custodian account) as a signer. We",
-            "double-check that this signer is the same one registered for the local router endpoint."
-          ],
-          "signer": true
+          "name": "routerEndpointRegistry",
+          "writable": true
         },
         {
-          "name": "tokenRouterCustodyToken",
+          "name": "inboundNotional",
           "writable": true
         },
         {
-          "name": "path",
+          "name": "mint",
           "accounts": [
             {
-              "name": "fromEndpoint",
-              "accounts": [
-                {
-                  "name": "endpoint"
-                }
-              ]
+              "name": "mint"
             },
             {
-              "name": "toEndpoint",
-              "accounts": [
-                {
-                  "name": "endpoint"
-                }
-              ]
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
             }
           ]
         },
@@ -459,395 +552,355 @@ export type MatchingEngine = {
           "name": "tokenProgram"
         },
         {
-          "name": "eventAuthority"
-        },
-        {
-          "name": "program"
+          "name": "systemProgram"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "addTokenBridgeRouterEndpointArgs"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "confirmOwnershipTransferRequest",
+      "name": "approveOwnerSetTransaction",
       "docs": [
-        "This instruction confirms the ownership transfer request and sets the new `owner` in the",
-        "`Custodian` account. This instruction can only be called by the `pending_owner`. The",
-        "`pending_owner` must be the same as the `pending_owner` in the `Custodian` account.",
+        "This instruction is used by an owner set member to approve a proposed owner set",
+        "transaction.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `ConfirmOwnershipTransferRequest` context."
+        "* `ctx` - `ApproveOwnerSetTransaction` context."
       ],
       "discriminator": [
-        118,
-        148,
-        109,
-        68,
-        201,
-        30,
-        139,
-        53
+        15,
+        246,
+        135,
+        33,
+        204,
+        184,
+        231,
+        11
       ],
       "accounts": [
         {
-          "name": "pendingOwner",
-          "docs": [
-            "Must be the pending owner of the program set in the [`OwnerConfig`]",
-            "account."
-          ],
-          "signer": true
+          "name": "ownerSetMember",
+          "accounts": [
+            {
+              "name": "member",
+              "signer": true
+            },
+            {
+              "name": "ownerSet"
+            }
+          ]
         },
         {
-          "name": "custodian",
+          "name": "transaction",
           "writable": true
         }
       ],
       "args": []
     },
     {
-      "name": "createFirstAuctionHistory",
+      "name": "cancelOwnerSetTransaction",
       "docs": [
-        "DEPRECATED. This instruction does not exist anymore.",
-        "",
-        "This instruction is used to create the first `AuctionHistory` account, whose PDA is derived",
-        "using ID == 0.",
+        "This instruction is used to close a proposed owner set transaction without executing it.",
+        "This instruction can only be called by the member who proposed the transaction.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `CreateFirstAuctionHistory` context."
+        "* `ctx` - `CancelOwnerSetTransaction` context."
       ],
       "discriminator": [
-        225,
-        203,
-        74,
+        223,
+        188,
+        106,
         36,
-        233,
-        11,
-        54,
-        75
+        89,
+        97,
+        65,
+        247
       ],
       "accounts": [
         {
-          "name": "dummy"
+          "name": "proposedBy",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "transaction",
+          "writable": true
         }
       ],
       "args": []
     },
     {
-      "name": "createNewAuctionHistory",
+      "name": "cancelOwnershipTransferRequest",
       "docs": [
-        "DEPRECATED. This instruction does not exist anymore.",
-        "",
-        "This instruction is used to create a new `AuctionHistory` account. The PDA is derived using",
-        "its ID. A new history account can be created only when the current one is full (number of",
-        "entries equals the hard-coded max entries).",
+        "This instruction cancels an ownership transfer request by resetting the `pending_owner`",
+        "field in the `Custodian` account. This instruction can only be called by the `owner`.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `CreateNewAuctionHistory` context."
+        "* `ctx` - `CancelOwnershipTransferRequest` context."
       ],
       "discriminator": [
-        63,
-        157,
-        83,
-        157,
-        231,
-        58,
-        207,
-        227
+        167,
+        61,
+        9,
+        35,
+        192,
+        41,
+        64,
+        178
       ],
       "accounts": [
         {
-          "name": "dummy"
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
         }
       ],
       "args": []
     },
     {
-      "name": "disableRouterEndpoint",
+      "name": "claimFastFillEscrow",
       "docs": [
-        "This instruction is used to disable a router endpoint. This instruction does not close the",
-        "account, it only sets the `protocol` to `None` and clears the `address` and",
-        "`mint_recipient`. This instruction can only be called by the `owner`.",
+        "This instruction is used by the redeemer of reclaimed fast fills to claim the amounts held",
+        "in its escrow token account. The escrow token account is closed and its lamports are sent to",
+        "the redeemer.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `DisableRouterEndpoint` context."
+        "* `ctx` - `ClaimFastFillEscrow` context."
       ],
       "discriminator": [
-        119,
-        145,
-        105,
-        95,
-        65,
-        77,
-        61,
-        69
+        2,
+        170,
+        90,
+        217,
+        142,
+        217,
+        135,
+        122
       ],
       "accounts": [
         {
-          "name": "admin",
-          "accounts": [
-            {
-              "name": "owner",
-              "signer": true
-            },
-            {
-              "name": "custodian",
-              "accounts": [
-                {
-                  "name": "custodian"
-                }
-              ]
-            }
-          ]
+          "name": "redeemer",
+          "docs": [
+            "Redeemer of the reclaimed fast fills, who receives the lamports of the escrow token account."
+          ],
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "routerEndpoint",
+          "name": "custodian",
           "accounts": [
             {
-              "name": "endpoint",
-              "writable": true
+              "name": "custodian"
             }
           ]
+        },
+        {
+          "name": "escrowToken",
+          "docs": [
+            "Escrow token account holding the redeemer's reclaimed fill amounts. This account will be",
+            "closed at the end of this instruction.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "recipientToken",
+          "docs": [
+            "Token account receiving the escrowed amount, which the redeemer may not own."
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
         }
       ],
       "args": []
     },
     {
-      "name": "executeFastOrderCctp",
+      "name": "closeAuction",
       "docs": [
-        "This instruction is used to execute the fast order after the auction period has ended.",
-        "It should be executed before the `grace_period` has ended, otherwise the best offer will",
-        "incur a penalty. Once executed, a CCTP transfer will be sent to the recipient encoded in the",
-        "`FastMarketOrder` VAA on the target chain.",
+        "This instruction is used to close an auction account after the auction has been settled and",
+        "the VAA's timestamp indicates the order has expired. This instruction can be called by",
+        "anyone to return the auction's preparer lamports from the rent required to keep this account",
+        "alive. The auction data will be serialized as Anchor event CPI instruction data.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `ExecuteFastOrderCctp` context."
+        "* `ctx` - `CloseAuction` context."
       ],
       "discriminator": [
-        176,
-        38,
-        30,
-        17,
-        230,
-        78,
-        206,
-        157
+        225,
+        129,
+        91,
+        48,
+        215,
+        73,
+        203,
+        172
       ],
       "accounts": [
         {
-          "name": "payer",
-          "writable": true,
-          "signer": true
+          "name": "auction",
+          "writable": true
         },
         {
-          "name": "coreMessage",
+          "name": "beneficiary",
+          "docs": [
+            "[Auction::prepared_by]."
+          ],
           "writable": true
         },
         {
-          "name": "cctpMessage",
-          "writable": true
+          "name": "eventAuthority"
         },
         {
-          "name": "custodian",
-          "accounts": [
-            {
-              "name": "custodian"
-            }
-          ]
-        },
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeProposal",
+      "docs": [
+        "This instruction is used to close an existing proposal by closing the proposal account. This",
+        "instruction can only be called by the `owner` or `owner_assistant`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `CloseProposal` context."
+      ],
+      "discriminator": [
+        213,
+        178,
+        139,
+        19,
+        50,
+        191,
+        82,
+        245
+      ],
+      "accounts": [
         {
-          "name": "executeOrder",
+          "name": "admin",
           "accounts": [
             {
-              "name": "fastVaa",
-              "accounts": [
-                {
-                  "name": "vaa"
-                }
-              ]
+              "name": "ownerOrAssistant",
+              "signer": true
             },
             {
-              "name": "activeAuction",
+              "name": "custodian",
               "accounts": [
                 {
-                  "name": "auction",
-                  "writable": true
-                },
-                {
-                  "name": "custodyToken",
-                  "writable": true
-                },
-                {
-                  "name": "config"
-                },
-                {
-                  "name": "bestOfferToken",
-                  "writable": true
+                  "name": "custodian"
                 }
               ]
-            },
-            {
-              "name": "executorToken",
-              "docs": [
-                "Must be a token account, whose mint is [common::USDC_MINT]."
-              ],
-              "writable": true
-            },
-            {
-              "name": "initialOfferToken",
-              "writable": true
-            },
-            {
-              "name": "initialParticipant",
-              "writable": true
-            }
-          ]
-        },
-        {
-          "name": "toRouterEndpoint",
-          "accounts": [
-            {
-              "name": "endpoint"
-            }
-          ]
-        },
-        {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "config",
-              "writable": true
-            },
-            {
-              "name": "emitterSequence",
-              "writable": true
-            },
-            {
-              "name": "feeCollector",
-              "writable": true
-            },
-            {
-              "name": "coreBridgeProgram"
             }
           ]
         },
         {
-          "name": "cctp",
-          "accounts": [
-            {
-              "name": "mint",
-              "docs": [
-                "Circle-supported mint.",
-                "",
-                "Token Messenger Minter program's local token account."
-              ],
-              "writable": true
-            },
-            {
-              "name": "tokenMessengerMinterSenderAuthority"
-            },
-            {
-              "name": "messageTransmitterConfig",
-              "writable": true
-            },
-            {
-              "name": "tokenMessenger"
-            },
-            {
-              "name": "remoteTokenMessenger",
-              "docs": [
-                "Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "tokenMinter",
-              "docs": [
-                "CHECK Seeds must be \\[\"token_minter\"\\] (CCTP Token Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "localToken",
-              "docs": [
-                "Local token account, which this program uses to validate the `mint` used to burn.",
-                ""
-              ],
-              "writable": true
-            },
-            {
-              "name": "tokenMessengerMinterEventAuthority"
-            },
-            {
-              "name": "tokenMessengerMinterProgram"
-            },
-            {
-              "name": "messageTransmitterProgram"
-            }
-          ]
+          "name": "proposedBy",
+          "writable": true
         },
         {
-          "name": "systemProgram"
-        },
+          "name": "proposal",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeRedeemedFastFill",
+      "docs": [
+        "This instruction is used to return lamports to the creator of the `FastFill` account only",
+        "when this fill was redeemed via the Token Router program.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `CloseRedeemedFastFill` context."
+      ],
+      "discriminator": [
+        44,
+        104,
+        207,
+        178,
+        224,
+        7,
+        28,
+        219
+      ],
+      "accounts": [
         {
-          "name": "tokenProgram"
+          "name": "preparedBy",
+          "docs": [
+            "Instead of having the preparer sign for this instruction, we allow anyone to call this",
+            "instruction on behalf of the preparer.",
+            ""
+          ],
+          "writable": true
         },
         {
-          "name": "sysvars",
-          "accounts": [
-            {
-              "name": "clock",
-              "docs": [
-                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
-                ""
-              ]
-            },
-            {
-              "name": "rent",
-              "docs": [
-                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
-                ""
-              ]
-            }
-          ]
+          "name": "fastFill",
+          "writable": true
         },
         {
-          "name": "eventAuthority"
+          "name": "fastFillCustodyToken",
+          "docs": [
+            "Token account owned by the fast fill if the fast fill owns its custody token account. Only",
+            "required in this case, where this account will be closed."
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "program"
+          "name": "tokenProgram",
+          "optional": true
         }
       ],
       "args": []
     },
     {
-      "name": "executeFastOrderLocal",
+      "name": "completeFastFill",
       "docs": [
-        "This instruction is used to execute the fast order after the auction period has ended.",
-        "It should be executed before the `grace_period` has ended, otherwise the best offer will",
-        "incur a penalty. Once executed, a `FastFill` account will be created.",
+        "This instruction is used to complete the fast fill after the `FastFill` account has been",
+        "created. The Token Router program on Solana will invoke this instruction to complete the",
+        "fast fill, marking it as redeemed. Tokens will be deposited into the local endpoint's",
+        "custody account.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `ExecuteFastOrderLocal` context."
+        "* `ctx` - `CompleteFastFill` context."
       ],
       "discriminator": [
-        140,
-        206,
-        26,
-        243,
-        243,
-        66,
-        24,
-        240
+        113,
+        252,
+        68,
+        134,
+        84,
+        61,
+        113,
+        203
       ],
       "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "custodian",
           "accounts": [
@@ -857,107 +910,67 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "executeOrder",
-          "accounts": [
-            {
-              "name": "fastVaa",
-              "accounts": [
-                {
-                  "name": "vaa"
-                }
-              ]
-            },
-            {
-              "name": "activeAuction",
-              "accounts": [
-                {
-                  "name": "auction",
-                  "writable": true
-                },
-                {
-                  "name": "custodyToken",
-                  "writable": true
-                },
-                {
-                  "name": "config"
-                },
-                {
-                  "name": "bestOfferToken",
-                  "writable": true
-                }
-              ]
-            },
-            {
-              "name": "executorToken",
-              "docs": [
-                "Must be a token account, whose mint is [common::USDC_MINT]."
-              ],
-              "writable": true
-            },
-            {
-              "name": "initialOfferToken",
-              "writable": true
-            },
-            {
-              "name": "initialParticipant",
-              "writable": true
-            }
-          ]
-        },
-        {
-          "name": "reservedSequence",
+          "name": "fastFill",
           "docs": [
-            "This account will be closed at the end of this instruction instead of using the close",
-            "account directive here.",
+            "Fast fill account.",
             "",
-            "NOTE: We do not need to do a VAA hash check because that was already performed when the",
-            "reserved sequence was created."
+            "NOTE: This account may have been closed if the fast fill was already redeemed, so this",
+            "deserialization will fail in this case.",
+            "",
+            "Seeds must be \\[\"fast-fill\", source_chain, order_sender, sequence\\]."
           ],
           "writable": true
         },
         {
-          "name": "reserveBeneficiary",
+          "name": "tokenRouterEmitter",
           "docs": [
-            "When the reserved sequence account was created, the beneficiary was set to the best offer",
-            "token's owner if it existed (and if not, to whomever executed the reserve fast fill sequence",
-            "instruction). This account will receive the lamports from the reserved sequence account.",
-            ""
+            "Only the registered local Token Router program can call this instruction. It is allowed to",
+            "invoke this instruction by using its emitter (i.e. its Custodian account) as a signer. We",
+            "double-check that this signer is the same one registered for the local router endpoint."
           ],
-          "writable": true
-        },
-        {
-          "name": "fastFill",
-          "writable": true
+          "signer": true
         },
         {
-          "name": "localCustodyToken",
+          "name": "tokenRouterCustodyToken",
           "writable": true
         },
         {
-          "name": "systemProgram"
-        },
-        {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "sysvars",
+          "name": "path",
           "accounts": [
             {
-              "name": "clock",
-              "docs": [
-                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
-                ""
+              "name": "fromEndpoint",
+              "accounts": [
+                {
+                  "name": "endpoint"
+                }
               ]
             },
             {
-              "name": "rent",
-              "docs": [
-                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
-                ""
+              "name": "toEndpoint",
+              "accounts": [
+                {
+                  "name": "endpoint"
+                }
               ]
             }
           ]
         },
+        {
+          "name": "localCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "fastFillCustodyToken",
+          "docs": [
+            "Token account owned by the fast fill, which holds the fill amount if the fast fill owns its",
+            "custody token account. Only required in this case."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
         {
           "name": "eventAuthority"
         },
@@ -968,202 +981,124 @@ export type MatchingEngine = {
       "args": []
     },
     {
-      "name": "improveOffer",
+      "name": "confirmOwnershipTransferRequest",
       "docs": [
-        "This instruction is used to improve an existing auction offer. The `offer_price` must be",
-        "greater than the current `offer_price` in the auction. This instruction will revert if the",
-        "`offer_price` is less than the current `offer_price`. This instruction can be called by",
-        "anyone.",
+        "This instruction confirms the ownership transfer request and sets the new `owner` in the",
+        "`Custodian` account. This instruction can only be called by the `pending_owner`. The",
+        "`pending_owner` must be the same as the `pending_owner` in the `Custodian` account, and the",
+        "request must not have expired.",
         "",
         "# Arguments",
         "",
-        "* `ctx`         - `ImproveOffer` context.",
-        "* `offer_price` - The fee that the caller is willing to accept in order for fufilling the",
-        "fast order. This fee is paid in USDC."
+        "* `ctx` - `ConfirmOwnershipTransferRequest` context."
       ],
       "discriminator": [
-        171,
-        112,
-        46,
-        172,
-        194,
-        135,
-        23,
-        102
+        118,
+        148,
+        109,
+        68,
+        201,
+        30,
+        139,
+        53
       ],
       "accounts": [
         {
-          "name": "transferAuthority",
+          "name": "pendingOwner",
           "docs": [
-            "The auction participant needs to set approval to this PDA.",
-            ""
-          ]
-        },
-        {
-          "name": "activeAuction",
-          "accounts": [
-            {
-              "name": "auction",
-              "writable": true
-            },
-            {
-              "name": "custodyToken",
-              "writable": true
-            },
-            {
-              "name": "config"
-            },
-            {
-              "name": "bestOfferToken",
-              "writable": true
-            }
-          ]
-        },
-        {
-          "name": "offerToken"
-        },
-        {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "eventAuthority"
+            "Must be the pending owner of the program set in the [`OwnerConfig`]",
+            "account."
+          ],
+          "signer": true
         },
         {
-          "name": "program"
+          "name": "custodian",
+          "writable": true
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "createFirstAuctionHistory",
+      "docs": [
+        "DEPRECATED. This instruction does not exist anymore.",
+        "",
+        "This instruction is used to create the first `AuctionHistory` account, whose PDA is derived",
+        "using ID == 0.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `CreateFirstAuctionHistory` context."
+      ],
+      "discriminator": [
+        225,
+        203,
+        74,
+        36,
+        233,
+        11,
+        54,
+        75
+      ],
+      "accounts": [
         {
-          "name": "offerPrice",
-          "type": "u64"
+          "name": "dummy"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "initialize",
+      "name": "createNewAuctionHistory",
       "docs": [
-        "This instruction is be used to generate the program's `custodian` and `auction_config`",
-        "configs. It also reates the `owner` and `fee_recipient` accounts. Finally, it sets the",
-        "upgrade authority to the `upgrade_manager_authority`. Upgrades are managed by the",
-        "`upgrade_manager_program`.",
+        "DEPRECATED. This instruction does not exist anymore.",
+        "",
+        "This instruction is used to create a new `AuctionHistory` account. The PDA is derived using",
+        "its ID. A new history account can be created only when the current one is full (number of",
+        "entries equals the hard-coded max entries).",
         "",
         "# Arguments",
         "",
-        "* `ctx`  - `Initialize` context.",
-        "* `args` - Initialize args, which has the initial [AuctionParameters]."
+        "* `ctx` - `CreateNewAuctionHistory` context."
       ],
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        63,
+        157,
+        83,
+        157,
+        231,
+        58,
+        207,
+        227
       ],
       "accounts": [
         {
-          "name": "owner",
-          "docs": [
-            "Owner of the program, who presumably deployed this program."
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "custodian",
-          "docs": [
-            "Custodian account, which saves program data useful for other",
-            "instructions."
-          ],
-          "writable": true
-        },
-        {
-          "name": "auctionConfig",
-          "writable": true
-        },
-        {
-          "name": "ownerAssistant",
-          "docs": [
-            "TODO: do we prevent the owner from being the owner assistant?"
-          ]
-        },
-        {
-          "name": "feeRecipient"
-        },
-        {
-          "name": "feeRecipientToken"
-        },
-        {
-          "name": "cctpMintRecipient",
-          "writable": true
-        },
-        {
-          "name": "usdc",
-          "accounts": [
-            {
-              "name": "mint"
-            }
-          ]
-        },
-        {
-          "name": "programData",
-          "docs": [
-            "We use the program data to make sure this owner is the upgrade authority (the true owner,",
-            "who deployed this program)."
-          ],
-          "writable": true
-        },
-        {
-          "name": "upgradeManagerAuthority"
-        },
-        {
-          "name": "upgradeManagerProgram"
-        },
-        {
-          "name": "bpfLoaderUpgradeableProgram"
-        },
-        {
-          "name": "systemProgram"
-        },
-        {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "associatedTokenProgram"
+          "name": "dummy"
         }
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "initializeArgs"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "migrate",
+      "name": "disableRouterEndpoint",
       "docs": [
-        "This instruction is used for executing logic during an upgrade. This instruction can only be",
-        "called by the `upgrade_manager_program`.",
+        "This instruction is used to disable a router endpoint. This instruction does not close the",
+        "account, it only sets the `protocol` to `None` and clears the `address` and",
+        "`mint_recipient`. The endpoint can be removed with `remove_router_endpoint` once it has been",
+        "disabled for the router endpoint removal delay. This instruction can only be called by the",
+        "`owner`.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `Migrate` context."
+        "* `ctx` - `DisableRouterEndpoint` context."
       ],
       "discriminator": [
-        155,
-        234,
-        231,
-        146,
-        236,
-        158,
-        162,
-        30
+        119,
+        145,
+        105,
+        95,
+        65,
+        77,
+        61,
+        69
       ],
       "accounts": [
         {
@@ -1182,31 +1117,49 @@ export type MatchingEngine = {
               ]
             }
           ]
+        },
+        {
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "routerEndpointRegistry",
+          "accounts": [
+            {
+              "name": "registry",
+              "writable": true
+            }
+          ]
         }
       ],
       "args": []
     },
     {
-      "name": "placeInitialOfferCctp",
+      "name": "executeFastOrderCctp",
       "docs": [
-        "This instruction is used to create a new auction given a valid `FastMarketOrder` vaa. This",
-        "instruction will record information about the auction and transfer funds from the payer to",
-        "an auction-specific token custody account. This instruction can be called by anyone.",
+        "This instruction is used to execute the fast order after the auction period has ended.",
+        "It should be executed before the `grace_period` has ended, otherwise the best offer will",
+        "incur a penalty. Once executed, a CCTP transfer will be sent to the recipient encoded in the",
+        "`FastMarketOrder` VAA on the target chain.",
+        "",
         "# Arguments",
         "",
-        "* `ctx`         - `PlaceInitialOfferCctp` context.",
-        "* `offer_price` - The fee that the caller is willing to accept in order for fufilling the",
-        "fast order. This fee is paid in USDC."
+        "* `ctx` - `ExecuteFastOrderCctp` context."
       ],
       "discriminator": [
-        157,
-        156,
-        175,
-        35,
-        91,
-        249,
-        1,
-        129
+        176,
+        38,
+        30,
+        17,
+        230,
+        78,
+        206,
+        157
       ],
       "accounts": [
         {
@@ -1215,11 +1168,12 @@ export type MatchingEngine = {
           "signer": true
         },
         {
-          "name": "transferAuthority",
-          "docs": [
-            "The auction participant needs to set approval to this PDA.",
-            ""
-          ]
+          "name": "coreMessage",
+          "writable": true
+        },
+        {
+          "name": "cctpMessage",
+          "writable": true
         },
         {
           "name": "custodian",
@@ -1230,10 +1184,7 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "auctionConfig"
-        },
-        {
-          "name": "fastOrderPath",
+          "name": "executeOrder",
           "accounts": [
             {
               "name": "fastVaa",
@@ -1244,172 +1195,67 @@ export type MatchingEngine = {
               ]
             },
             {
-              "name": "path",
+              "name": "activeAuction",
               "accounts": [
                 {
-                  "name": "fromEndpoint",
-                  "accounts": [
-                    {
-                      "name": "endpoint"
-                    }
-                  ]
+                  "name": "auction",
+                  "writable": true
                 },
                 {
-                  "name": "toEndpoint",
-                  "accounts": [
-                    {
-                      "name": "endpoint"
-                    }
-                  ]
+                  "name": "custodyToken",
+                  "writable": true
+                },
+                {
+                  "name": "config"
+                },
+                {
+                  "name": "bestOfferToken",
+                  "writable": true
                 }
               ]
+            },
+            {
+              "name": "executorToken",
+              "docs": [
+                "Must be a token account, whose mint is the auction's mint."
+              ],
+              "writable": true
+            },
+            {
+              "name": "initialOfferToken",
+              "writable": true
+            },
+            {
+              "name": "initialParticipant",
+              "writable": true
             }
           ]
         },
         {
-          "name": "auction",
-          "docs": [
-            "This account should only be created once, and should never be changed to",
-            "init_if_needed. Otherwise someone can game an existing auction."
-          ],
-          "writable": true
-        },
-        {
-          "name": "offerToken"
-        },
-        {
-          "name": "auctionCustodyToken",
-          "writable": true
-        },
-        {
-          "name": "usdc",
+          "name": "toRouterEndpoint",
           "accounts": [
             {
-              "name": "mint"
+              "name": "endpoint"
             }
           ]
         },
         {
-          "name": "systemProgram"
-        },
-        {
-          "name": "tokenProgram"
-        },
-        {
-          "name": "eventAuthority"
-        },
-        {
-          "name": "program"
-        }
-      ],
-      "args": [
-        {
-          "name": "offerPrice",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "prepareOrderResponseCctp",
-      "docs": [
-        "This instruction is used to prepare the order response for a CCTP transfer. This instruction",
-        "will redeem the finalized transfer associated with a particular auction, and deposit the",
-        "funds to the `prepared_custody_token` account that is created during execution. This",
-        "instruction will create a `PreparedOrderResponse` account that will be used to settle the",
-        "auction.",
-        "",
-        "# Arguments",
-        "",
-        "* `ctx` - `PrepareOrderResponseCctp` context."
-      ],
-      "discriminator": [
-        221,
-        178,
-        184,
-        43,
-        247,
-        248,
-        90,
-        160
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "custodian",
+          "name": "wormhole",
           "accounts": [
             {
-              "name": "custodian"
-            }
-          ]
-        },
-        {
-          "name": "fastOrderPath",
-          "accounts": [
-            {
-              "name": "fastVaa",
-              "accounts": [
-                {
-                  "name": "vaa"
-                }
-              ]
+              "name": "config",
+              "writable": true
             },
             {
-              "name": "path",
-              "accounts": [
-                {
-                  "name": "fromEndpoint",
-                  "accounts": [
-                    {
-                      "name": "endpoint"
-                    }
-                  ]
-                },
-                {
-                  "name": "toEndpoint",
-                  "accounts": [
-                    {
-                      "name": "endpoint"
-                    }
-                  ]
-                }
-              ]
-            }
-          ]
-        },
-        {
-          "name": "finalizedVaa",
-          "accounts": [
+              "name": "emitterSequence",
+              "writable": true
+            },
             {
-              "name": "vaa"
-            }
-          ]
-        },
-        {
-          "name": "preparedOrderResponse",
-          "writable": true
-        },
-        {
-          "name": "preparedCustodyToken",
-          "writable": true
-        },
-        {
-          "name": "baseFeeToken",
-          "docs": [
-            "This token account will be the one that collects the base fee only if an auction's order",
-            "was executed late. Otherwise, the protocol's fee recipient token account will be used for",
-            "non-existent auctions and the best offer token account will be used for orders executed on",
-            "time."
-          ]
-        },
-        {
-          "name": "usdc",
-          "accounts": [
+              "name": "feeCollector",
+              "writable": true
+            },
             {
-              "name": "mint"
+              "name": "coreBridgeProgram"
             }
           ]
         },
@@ -1417,29 +1263,20 @@ export type MatchingEngine = {
           "name": "cctp",
           "accounts": [
             {
-              "name": "mintRecipient",
-              "accounts": [
-                {
-                  "name": "mintRecipient",
-                  "writable": true
-                }
-              ]
-            },
-            {
-              "name": "messageTransmitterAuthority"
-            },
-            {
-              "name": "messageTransmitterConfig"
-            },
-            {
-              "name": "usedNonces",
+              "name": "mint",
               "docs": [
-                "first_nonce.to_string()\\] (CCTP Message Transmitter program)."
+                "Circle-supported mint.",
+                "",
+                "Token Messenger Minter program's local token account."
               ],
               "writable": true
             },
             {
-              "name": "messageTransmitterEventAuthority"
+              "name": "tokenMessengerMinterSenderAuthority"
+            },
+            {
+              "name": "messageTransmitterConfig",
+              "writable": true
             },
             {
               "name": "tokenMessenger"
@@ -1451,27 +1288,19 @@ export type MatchingEngine = {
               ]
             },
             {
-              "name": "tokenMinter"
+              "name": "tokenMinter",
+              "docs": [
+                "CHECK Seeds must be \\[\"token_minter\"\\] (CCTP Token Messenger Minter program)."
+              ]
             },
             {
               "name": "localToken",
               "docs": [
-                "Token Messenger Minter's Local Token account. This program uses the mint of this account to",
-                "validate the `mint_recipient` token account's mint.",
+                "Local token account, which this program uses to validate the `mint` used to burn.",
                 ""
               ],
               "writable": true
             },
-            {
-              "name": "tokenPair",
-              "docs": [
-                "Token Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "tokenMessengerMinterCustodyToken",
-              "writable": true
-            },
             {
               "name": "tokenMessengerMinterEventAuthority"
             },
@@ -1484,44 +1313,61 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "tokenProgram"
+          "name": "systemProgram"
         },
         {
-          "name": "systemProgram"
-        }
-      ],
-      "args": [
+          "name": "tokenProgram"
+        },
         {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "cctpMessageArgs"
+          "name": "sysvars",
+          "accounts": [
+            {
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
             }
-          }
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "proposeAuctionParameters",
+      "name": "executeFastOrderLocal",
       "docs": [
-        "This instruction is used to propose new auction parameters. A proposal cannot be enacted",
-        "until one epoch has passed. This instruction can only be called by the `owner` or",
-        "`owner_assistant`.",
+        "This instruction is used to execute the fast order after the auction period has ended.",
+        "It should be executed before the `grace_period` has ended, otherwise the best offer will",
+        "incur a penalty. Once executed, a `FastFill` account will be created. If the order requests",
+        "a gas drop-off, the `payer` transfers it to the order's redeemer and is compensated with the",
+        "order's gas drop-off fee.",
         "",
         "# Arguments",
         "",
-        "* `ctx`    - `ProposeAuctionParameters` context.",
-        "* `params` - The new `AuctionParameters`, see `auction_config.rs`."
+        "* `ctx` - `ExecuteFastOrderLocal` context."
       ],
       "discriminator": [
-        86,
-        19,
-        21,
-        43,
-        32,
-        106,
-        249,
-        80
+        140,
+        206,
+        26,
+        243,
+        243,
+        66,
+        24,
+        240
       ],
       "accounts": [
         {
@@ -1530,158 +1376,318 @@ export type MatchingEngine = {
           "signer": true
         },
         {
-          "name": "admin",
+          "name": "custodian",
           "accounts": [
             {
-              "name": "ownerOrAssistant",
-              "signer": true
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "executeOrder",
+          "accounts": [
+            {
+              "name": "fastVaa",
+              "accounts": [
+                {
+                  "name": "vaa"
+                }
+              ]
             },
             {
-              "name": "custodian",
+              "name": "activeAuction",
               "accounts": [
                 {
-                  "name": "custodian"
+                  "name": "auction",
+                  "writable": true
+                },
+                {
+                  "name": "custodyToken",
+                  "writable": true
+                },
+                {
+                  "name": "config"
+                },
+                {
+                  "name": "bestOfferToken",
+                  "writable": true
                 }
               ]
+            },
+            {
+              "name": "executorToken",
+              "docs": [
+                "Must be a token account, whose mint is the auction's mint."
+              ],
+              "writable": true
+            },
+            {
+              "name": "initialOfferToken",
+              "writable": true
+            },
+            {
+              "name": "initialParticipant",
+              "writable": true
             }
           ]
         },
         {
-          "name": "proposal",
+          "name": "reservedSequence",
+          "docs": [
+            "This account will be closed at the end of this instruction instead of using the close",
+            "account directive here.",
+            "",
+            "NOTE: We do not need to do a VAA hash check because that was already performed when the",
+            "reserved sequence was created."
+          ],
           "writable": true
         },
         {
-          "name": "systemProgram"
+          "name": "reserveBeneficiary",
+          "docs": [
+            "When the reserved sequence account was created, the beneficiary was set to the best offer",
+            "token's owner if it existed (and if not, to whomever executed the reserve fast fill sequence",
+            "instruction). This account will receive the lamports from the reserved sequence account.",
+            ""
+          ],
+          "writable": true
         },
         {
-          "name": "epochSchedule"
+          "name": "fastFill",
+          "writable": true
         },
         {
-          "name": "eventAuthority"
+          "name": "localCustodyToken",
+          "writable": true
         },
         {
-          "name": "program"
-        }
-      ],
-      "args": [
-        {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "auctionParameters"
+          "name": "destinationCustodyToken",
+          "docs": [
+            "Only required if the fast fill is delivered in a destination asset. This token account will",
+            "be owned by the fast fill, which is redeemed from it."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "gasDropoffRecipient",
+          "docs": [
+            "Redeemer encoded in the fast market order, which receives the gas drop-off from the payer.",
+            "This account is only required if the order requests a gas drop-off.",
+            ""
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "userRewardToken",
+          "docs": [
+            "Token account of the order's redeemer, which receives the user reward portion of a penalty",
+            "in the auctioned asset. This account is only required if the fast fill is delivered in a",
+            "destination asset and the execution is penalized with a user reward."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "sysvars",
+          "accounts": [
+            {
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
             }
-          }
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "reserveFastFillSequenceActiveAuction",
+      "name": "executeFastOrderNtt",
       "docs": [
-        "This instruction is used to reserve a sequence number for a fast fill. Fast fills are orders",
-        "that have been fulfilled and are destined for Solana and are seeded by source chain, order",
-        "sender and sequence number (similar to how Wormhole VAAs are identified by emitter chain,",
-        "emitter address and sequence number).",
-        "",
-        "Prior to executing `execute_fast_order_local` after the duration of an auction, the winning",
-        "auction participant should call this instruction to reserve the fast fill's sequence number.",
-        "This sequence number is warehoused in the `ReservedFastFillSequence` account and will be",
-        "closed when the order is executed.",
-        "",
-        "Auction participants can listen to the `FastFillSequenceReserved` event to track when he",
-        "(or associated payer) called this instruction so he can execute local orders easily.",
+        "This instruction is used to execute the fast order after the auction period has ended.",
+        "It should be executed before the `grace_period` has ended, otherwise the best offer will",
+        "incur a penalty. Once executed, the tokens are transferred via the target router endpoint's",
+        "NTT manager and a `Fill` message is published for the target chain.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `ReserveFastFillSequenceActiveAuction` context."
+        "* `ctx` - `ExecuteFastOrderNtt` context."
       ],
       "discriminator": [
-        206,
-        255,
-        241,
-        68,
-        224,
-        129,
-        210,
-        187
+        151,
+        74,
+        203,
+        249,
+        79,
+        89,
+        43,
+        53
       ],
       "accounts": [
         {
-          "name": "reserveSequence",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "coreMessage",
+          "writable": true
+        },
+        {
+          "name": "nttOutboxItem",
+          "writable": true
+        },
+        {
+          "name": "custodian",
           "accounts": [
             {
-              "name": "payer",
-              "writable": true,
-              "signer": true
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "executeOrder",
+          "accounts": [
+            {
+              "name": "fastVaa",
+              "accounts": [
+                {
+                  "name": "vaa"
+                }
+              ]
             },
             {
-              "name": "fastOrderPath",
+              "name": "activeAuction",
               "accounts": [
                 {
-                  "name": "fastVaa",
-                  "accounts": [
-                    {
-                      "name": "vaa"
-                    }
-                  ]
+                  "name": "auction",
+                  "writable": true
                 },
                 {
-                  "name": "path",
-                  "accounts": [
-                    {
-                      "name": "fromEndpoint",
-                      "accounts": [
-                        {
-                          "name": "endpoint"
-                        }
-                      ]
-                    },
-                    {
-                      "name": "toEndpoint",
-                      "accounts": [
-                        {
-                          "name": "endpoint"
-                        }
-                      ]
-                    }
-                  ]
+                  "name": "custodyToken",
+                  "writable": true
+                },
+                {
+                  "name": "config"
+                },
+                {
+                  "name": "bestOfferToken",
+                  "writable": true
                 }
               ]
             },
             {
-              "name": "sequencer",
+              "name": "executorToken",
               "docs": [
-                "This sequencer determines the next reserved sequence. If it does not exist for a given",
-                "source chain and sender, it will be created.",
-                "",
-                "Auction participants may want to consider pricing the creation of this account into their",
-                "offer prices by checking whether this sequencer already exists for those orders destined for",
-                "Solana."
+                "Must be a token account, whose mint is the auction's mint."
               ],
               "writable": true
             },
             {
-              "name": "reserved",
-              "docs": [
-                "This account will be used to determine the sequence of the next fast fill. When a local",
-                "order is executed or an non-existent auction is settled, this account will be closed."
-              ],
+              "name": "initialOfferToken",
               "writable": true
             },
             {
-              "name": "auction",
+              "name": "initialParticipant",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "toRouterEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint"
+            }
+          ]
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "config",
+              "writable": true
+            },
+            {
+              "name": "emitterSequence",
+              "writable": true
+            },
+            {
+              "name": "feeCollector",
+              "writable": true
+            },
+            {
+              "name": "coreBridgeProgram"
+            }
+          ]
+        },
+        {
+          "name": "ntt",
+          "accounts": [
+            {
+              "name": "mint",
               "docs": [
-                "must have been created by this point. Otherwise the auction account must reflect a completed",
-                "auction."
-              ],
+                "instruction using this account."
+              ]
+            },
+            {
+              "name": "custodyToken",
               "writable": true
             },
             {
-              "name": "systemProgram"
+              "name": "transceiver"
+            },
+            {
+              "name": "nttManagerProgram"
             }
           ]
         },
         {
-          "name": "auctionConfig"
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "sysvars",
+          "accounts": [
+            {
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
+            }
+          ]
         },
         {
           "name": "eventAuthority"
@@ -1693,123 +1699,170 @@ export type MatchingEngine = {
       "args": []
     },
     {
-      "name": "reserveFastFillSequenceNoAuction",
+      "name": "executeFastOrderTokenBridge",
       "docs": [
-        "This instruction is used to reserve a sequence number for a fast fill. Fast fills are orders",
-        "that have been fulfilled and are destined for Solana and are seeded by source chain, order",
-        "sender and sequence number (similar to how Wormhole VAAs are identified by emitter chain,",
-        "emitter address and sequence number).",
+        "This instruction is used to execute the fast order after the auction period has ended.",
+        "It should be executed before the `grace_period` has ended, otherwise the best offer will",
+        "incur a penalty. Once executed, a Token Bridge transfer will be sent to the mint recipient",
+        "of the target router endpoint, whose chain does not support CCTP.",
         "",
-        "Prior to executing `settle_auction_none_local` if there is no auction, whomever prepared the",
-        "order response should call this instruction to reserve the fast fill's sequence number.",
-        "This sequence number is warehoused in the `ReservedFastFillSequence` account and will be",
-        "closed when the funds are finally settled.",
+        "# Arguments",
         "",
-        "NOTE: This instruction is expected to be in the same transaction as the one that executes",
-        "the prepare order response instruction. If it is not, there is a risk that after preparing",
-        "the order response that someone starts an auction. This scenario risks the preparer's rent",
-        "that he paid because the winning auction participant can take his lamports when he calls",
-        "settle auction complete. Although this is an unlikely scenario, it is possible if there is",
-        "no deadline specified to start the auction and no participants use the fast VAA to start an",
-        "auction until the finalized VAA exists (which guarantees that the funds have finalized on",
-        "the source network).",
-        "",
-        "# Arguments",
-        "",
-        "* `ctx` - `ReserveFastFillSequenceNoAuction` context."
+        "* `ctx` - `ExecuteFastOrderTokenBridge` context."
       ],
       "discriminator": [
-        61,
-        148,
-        140,
-        87,
-        186,
-        87,
-        212,
-        239
+        191,
+        86,
+        50,
+        9,
+        138,
+        106,
+        156,
+        189
       ],
       "accounts": [
         {
-          "name": "reserveSequence",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "coreMessage",
+          "writable": true
+        },
+        {
+          "name": "custodian",
           "accounts": [
             {
-              "name": "payer",
-              "writable": true,
-              "signer": true
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "executeOrder",
+          "accounts": [
+            {
+              "name": "fastVaa",
+              "accounts": [
+                {
+                  "name": "vaa"
+                }
+              ]
             },
             {
-              "name": "fastOrderPath",
+              "name": "activeAuction",
               "accounts": [
                 {
-                  "name": "fastVaa",
-                  "accounts": [
-                    {
-                      "name": "vaa"
-                    }
-                  ]
+                  "name": "auction",
+                  "writable": true
                 },
                 {
-                  "name": "path",
-                  "accounts": [
-                    {
-                      "name": "fromEndpoint",
-                      "accounts": [
-                        {
-                          "name": "endpoint"
-                        }
-                      ]
-                    },
-                    {
-                      "name": "toEndpoint",
-                      "accounts": [
-                        {
-                          "name": "endpoint"
-                        }
-                      ]
-                    }
-                  ]
+                  "name": "custodyToken",
+                  "writable": true
+                },
+                {
+                  "name": "config"
+                },
+                {
+                  "name": "bestOfferToken",
+                  "writable": true
                 }
               ]
             },
             {
-              "name": "sequencer",
+              "name": "executorToken",
               "docs": [
-                "This sequencer determines the next reserved sequence. If it does not exist for a given",
-                "source chain and sender, it will be created.",
-                "",
-                "Auction participants may want to consider pricing the creation of this account into their",
-                "offer prices by checking whether this sequencer already exists for those orders destined for",
-                "Solana."
+                "Must be a token account, whose mint is the auction's mint."
               ],
               "writable": true
             },
             {
-              "name": "reserved",
-              "docs": [
-                "This account will be used to determine the sequence of the next fast fill. When a local",
-                "order is executed or an non-existent auction is settled, this account will be closed."
-              ],
+              "name": "initialOfferToken",
               "writable": true
             },
             {
-              "name": "auction",
+              "name": "initialParticipant",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "toRouterEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint"
+            }
+          ]
+        },
+        {
+          "name": "tokenBridge",
+          "accounts": [
+            {
+              "name": "mint",
               "docs": [
-                "must have been created by this point. Otherwise the auction account must reflect a completed",
-                "auction."
+                "by the instruction using this account."
               ],
               "writable": true
             },
             {
-              "name": "systemProgram"
+              "name": "config"
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            },
+            {
+              "name": "transferAuthority"
+            },
+            {
+              "name": "custodyAuthority"
+            },
+            {
+              "name": "emitter"
+            },
+            {
+              "name": "coreBridgeConfig",
+              "writable": true
+            },
+            {
+              "name": "emitterSequence",
+              "writable": true
+            },
+            {
+              "name": "coreFeeCollector",
+              "writable": true
+            },
+            {
+              "name": "coreBridgeProgram"
+            },
+            {
+              "name": "tokenBridgeProgram"
             }
           ]
         },
         {
-          "name": "preparedOrderResponse",
-          "docs": [
-            "The preparer will be the beneficiary of the reserved fast fill sequence account when it is",
-            "closed. This instruction will not allow this account to be provided if there is an existing",
-            "auction, which would enforce the order be executed when it is time to complete the auction."
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "sysvars",
+          "accounts": [
+            {
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
+            }
           ]
         },
         {
@@ -1822,109 +1875,177 @@ export type MatchingEngine = {
       "args": []
     },
     {
-      "name": "setPause",
+      "name": "executeOwnerSetTransaction",
       "docs": [
-        "This instruction is used to pause or unpause further processing of new auctions. Only the",
-        "`owner` or `owner_assistant` can pause the program.",
+        "This instruction is used to execute an owner set transaction once the threshold of member",
+        "approvals has been met. The transaction's accounts must be passed as remaining accounts. The",
+        "transaction account is closed and its lamports are returned to the proposer. This",
+        "instruction can be called by anyone.",
         "",
         "# Arguments",
         "",
-        "* `ctx`   - `SetPause` context.",
-        "* `pause` - Boolean indicating whether to pause the program."
+        "* `ctx` - `ExecuteOwnerSetTransaction` context."
       ],
       "discriminator": [
-        63,
-        32,
-        154,
-        2,
-        56,
-        103,
-        79,
-        45
+        128,
+        33,
+        61,
+        27,
+        160,
+        74,
+        159,
+        209
+      ],
+      "accounts": [
+        {
+          "name": "ownerSet"
+        },
+        {
+          "name": "proposedBy",
+          "writable": true
+        },
+        {
+          "name": "transaction",
+          "writable": true
+        },
+        {
+          "name": "matchingEngineProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "grantRole",
+      "docs": [
+        "This instruction is used to grant a role to a holder, creating the holder's `RoleHolder`",
+        "account if it does not exist. This instruction can only be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `GrantRole` context.",
+        "* `role` - The `Role` to grant."
+      ],
+      "discriminator": [
+        218,
+        234,
+        128,
+        15,
+        82,
+        33,
+        236,
+        253
       ],
       "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "admin",
           "accounts": [
             {
-              "name": "ownerOrAssistant",
+              "name": "owner",
               "signer": true
             },
             {
               "name": "custodian",
-              "writable": true
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
             }
           ]
+        },
+        {
+          "name": "holder",
+          "docs": [
+            "Role holder.",
+            ""
+          ]
+        },
+        {
+          "name": "roleHolder",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
         }
       ],
       "args": [
         {
-          "name": "pause",
-          "type": "bool"
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "role"
+            }
+          }
         }
       ]
     },
     {
-      "name": "settleAuctionComplete",
+      "name": "improveOffer",
       "docs": [
-        "This instruction is used to settle the acution after the `FastMarketOrder` has been",
-        "executed, and the `PreparedOrderResponse` has been created. This instruction will settle the",
-        "auction by transferring the funds from the `prepared_custody_token` account to the best",
-        "offer account.",
-        "",
-        "# Arguments",
+        "This instruction is used to improve an existing auction offer. The `offer_price` must be",
+        "greater than the current `offer_price` in the auction. This instruction will revert if the",
+        "`offer_price` is less than the current `offer_price`. This instruction can be called by",
+        "anyone.",
         "",
-        "* `ctx` - `SettleAuctionComplete` context."
+        "# Arguments",
+        "",
+        "* `ctx`         - `ImproveOffer` context.",
+        "* `offer_price` - The fee that the caller is willing to accept in order for fufilling the",
+        "fast order. This fee is paid in USDC."
       ],
       "discriminator": [
-        84,
-        39,
-        0,
-        132,
-        21,
-        101,
-        222,
-        137
+        171,
+        112,
+        46,
+        172,
+        194,
+        135,
+        23,
+        102
       ],
       "accounts": [
         {
-          "name": "beneficiary",
-          "docs": [
-            "finalized VAA."
-          ],
-          "writable": true
-        },
-        {
-          "name": "baseFeeToken",
+          "name": "transferAuthority",
           "docs": [
-            "This token account will receive the base fee only if there was a penalty when executing the",
-            "order. If it does not exist when there is a penalty, this instruction handler will revert.",
+            "The auction participant needs to set approval to this PDA.",
             ""
-          ],
-          "writable": true
-        },
-        {
-          "name": "bestOfferToken",
-          "docs": [
-            "Destination token account, which the redeemer may not own. But because the redeemer is a",
-            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
-            "to any account he chooses (this one).",
-            "",
-            "of the tokens to the base fee token account."
-          ],
-          "writable": true
+          ]
         },
         {
-          "name": "preparedOrderResponse",
-          "writable": true
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
         },
         {
-          "name": "preparedCustodyToken",
-          "writable": true
+          "name": "activeAuction",
+          "accounts": [
+            {
+              "name": "auction",
+              "writable": true
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            },
+            {
+              "name": "config"
+            },
+            {
+              "name": "bestOfferToken",
+              "writable": true
+            }
+          ]
         },
         {
-          "name": "auction",
-          "writable": true
+          "name": "offerToken"
         },
         {
           "name": "tokenProgram"
@@ -1936,43 +2057,44 @@ export type MatchingEngine = {
           "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "offerPrice",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "settleAuctionNoneCctp",
+      "name": "improveOfferDestinationAsset",
       "docs": [
-        "This instruction is used to route funds to the `recipient` for a `FastMarketOrder` with",
-        "no corresponding auction on Solana. This instruction can be called by anyone, but the sum of",
-        "`init_auction_fee` and `base_fee` associated with relaying a finalized VAA will be paid to",
-        "the `fee_recipient`. This instruction generates a `Fill` message.",
+        "This instruction is used to improve an existing destination asset auction offer. The",
+        "`amount_out` must exceed the current amount out by at least the auction's minimum offer",
+        "delta. This instruction can be called by anyone.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `SettleAuctionNoneCctp` context."
+        "* `ctx`        - `ImproveOfferDestinationAsset` context.",
+        "* `amount_out` - The amount of the destination asset the caller is willing to deliver in",
+        "order to fulfill the fast order."
       ],
       "discriminator": [
-        120,
-        236,
-        82,
-        121,
-        242,
-        118,
-        74,
-        161
+        206,
+        130,
+        4,
+        219,
+        243,
+        100,
+        72,
+        243
       ],
       "accounts": [
         {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "coreMessage",
-          "writable": true
-        },
-        {
-          "name": "cctpMessage",
-          "writable": true
+          "name": "transferAuthority",
+          "docs": [
+            "The auction participant needs to set approval to this PDA for both the offer token and the",
+            "destination offer token.",
+            ""
+          ]
         },
         {
           "name": "custodian",
@@ -1983,136 +2105,41 @@ export type MatchingEngine = {
           ]
         },
         {
-          "name": "feeRecipientToken",
-          "docs": [
-            "Destination token account, which the redeemer may not own. But because the redeemer is a",
-            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
-            "to any account he chooses (this one).",
-            ""
-          ],
-          "writable": true
-        },
-        {
-          "name": "prepared",
+          "name": "activeAuction",
           "accounts": [
             {
-              "name": "by",
-              "writable": true
-            },
-            {
-              "name": "orderResponse",
+              "name": "auction",
               "writable": true
             },
             {
               "name": "custodyToken",
               "writable": true
-            }
-          ]
-        },
-        {
-          "name": "auction",
-          "docs": [
-            "There should be no account data here because an auction was never created."
-          ],
-          "writable": true
-        },
-        {
-          "name": "wormhole",
-          "accounts": [
-            {
-              "name": "config",
-              "writable": true
             },
             {
-              "name": "emitterSequence",
-              "writable": true
+              "name": "config"
             },
             {
-              "name": "feeCollector",
+              "name": "bestOfferToken",
               "writable": true
-            },
-            {
-              "name": "coreBridgeProgram"
             }
           ]
         },
         {
-          "name": "cctp",
-          "accounts": [
-            {
-              "name": "mint",
-              "docs": [
-                "Circle-supported mint.",
-                "",
-                "Token Messenger Minter program's local token account."
-              ],
-              "writable": true
-            },
-            {
-              "name": "tokenMessengerMinterSenderAuthority"
-            },
-            {
-              "name": "messageTransmitterConfig",
-              "writable": true
-            },
-            {
-              "name": "tokenMessenger"
-            },
-            {
-              "name": "remoteTokenMessenger",
-              "docs": [
-                "Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "tokenMinter",
-              "docs": [
-                "CHECK Seeds must be \\[\"token_minter\"\\] (CCTP Token Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "localToken",
-              "docs": [
-                "Local token account, which this program uses to validate the `mint` used to burn.",
-                ""
-              ],
-              "writable": true
-            },
-            {
-              "name": "tokenMessengerMinterEventAuthority"
-            },
-            {
-              "name": "tokenMessengerMinterProgram"
-            },
-            {
-              "name": "messageTransmitterProgram"
-            }
-          ]
+          "name": "offerToken"
         },
         {
-          "name": "tokenProgram"
+          "name": "destinationCustodyToken",
+          "writable": true
         },
         {
-          "name": "systemProgram"
+          "name": "destinationOfferToken"
         },
         {
-          "name": "sysvars",
-          "accounts": [
-            {
-              "name": "clock",
-              "docs": [
-                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
-                ""
-              ]
-            },
-            {
-              "name": "rent",
-              "docs": [
-                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
-                ""
-              ]
-            }
-          ]
+          "name": "destinationBestOfferToken",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
         },
         {
           "name": "eventAuthority"
@@ -2121,156 +2148,152 @@ export type MatchingEngine = {
           "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "amountOut",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "settleAuctionNoneLocal",
+      "name": "initialize",
       "docs": [
-        "This instruction is used to settle a `FastMarketOrder` with no corresponding auction. This",
-        "instruction can be called by anyone, but the sum of `init_auction_fee` and `base_fee`",
-        "associated with relaying a finalized VAA will be paid to the `fee_recipient`. This",
-        "instruction creates a `FastFill` account.",
-        "",
-        "# Arguments",
+        "This instruction is be used to generate the program's `custodian` and `auction_config`",
+        "configs. It also reates the `owner` and `fee_recipient` accounts. Finally, it sets the",
+        "upgrade authority to the `upgrade_manager_authority`. Upgrades are managed by the",
+        "`upgrade_manager_program`.",
         "",
-        "* `ctx` - `SettleAuctionNoneLocal` context."
+        "# Arguments",
+        "",
+        "* `ctx`  - `Initialize` context.",
+        "* `args` - Initialize args, which has the initial [AuctionParameters]."
       ],
       "discriminator": [
-        253,
-        213,
-        132,
-        148,
+        175,
+        175,
+        109,
         31,
-        119,
-        215,
-        162
+        13,
+        152,
+        155,
+        237
       ],
       "accounts": [
         {
-          "name": "payer",
+          "name": "owner",
+          "docs": [
+            "Owner of the program, who presumably deployed this program."
+          ],
           "writable": true,
           "signer": true
         },
         {
           "name": "custodian",
-          "accounts": [
-            {
-              "name": "custodian"
-            }
-          ]
+          "docs": [
+            "Custodian account, which saves program data useful for other",
+            "instructions."
+          ],
+          "writable": true
         },
         {
-          "name": "feeRecipientToken",
+          "name": "auctionConfig",
+          "writable": true
+        },
+        {
+          "name": "circuitBreaker",
+          "writable": true
+        },
+        {
+          "name": "ownerAssistant",
           "docs": [
-            "Destination token account, which the redeemer may not own. But because the redeemer is a",
-            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
-            "to any account he chooses (this one).",
-            ""
-          ],
+            "TODO: do we prevent the owner from being the owner assistant?"
+          ]
+        },
+        {
+          "name": "feeRecipient"
+        },
+        {
+          "name": "feeRecipientToken"
+        },
+        {
+          "name": "cctpMintRecipient",
           "writable": true
         },
         {
-          "name": "prepared",
+          "name": "usdc",
           "accounts": [
             {
-              "name": "by",
-              "writable": true
-            },
-            {
-              "name": "orderResponse",
-              "writable": true
-            },
-            {
-              "name": "custodyToken",
-              "writable": true
+              "name": "mint"
             }
           ]
         },
         {
-          "name": "auction",
-          "docs": [
-            "This account will have been created using the reserve fast fill sequence (no auction)",
-            "instruction. We need to make sure that this account has not been used in an auction."
-          ],
-          "writable": true
-        },
-        {
-          "name": "reservedSequence",
+          "name": "programData",
           "docs": [
-            "This account will be closed at the end of this instruction instead of using the close",
-            "account directive here.",
-            "",
-            "If we could reference the beneficiary using `prepared.by`, this would be a different story.",
-            "",
-            "NOTE: We do not need to do a VAA hash check because that was already performed when the",
-            "reserved sequence was created."
+            "We use the program data to make sure this owner is the upgrade authority (the true owner,",
+            "who deployed this program)."
           ],
           "writable": true
         },
         {
-          "name": "fastFill",
-          "writable": true
+          "name": "upgradeManagerAuthority"
         },
         {
-          "name": "localCustodyToken",
-          "writable": true
+          "name": "upgradeManagerProgram"
         },
         {
-          "name": "tokenProgram"
+          "name": "bpfLoaderUpgradeableProgram"
         },
         {
           "name": "systemProgram"
         },
         {
-          "name": "sysvars",
-          "accounts": [
-            {
-              "name": "clock",
-              "docs": [
-                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
-                ""
-              ]
-            },
-            {
-              "name": "rent",
-              "docs": [
-                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
-                ""
-              ]
-            }
-          ]
-        },
-        {
-          "name": "eventAuthority"
+          "name": "tokenProgram"
         },
         {
-          "name": "program"
+          "name": "associatedTokenProgram"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "initializeArgs"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "submitOwnershipTransferRequest",
+      "name": "initializeOwnerSet",
       "docs": [
-        "This instruction sets the `pending_owner` field in the `Custodian` account. This instruction",
-        "can only be called by the `owner`. The `pending_owner` address must be valid, meaning it",
-        "cannot be the zero address or the current owner.",
+        "This instruction is used to create the program's `OwnerSet` account, which holds M-of-N",
+        "members who can collectively act as the program's owner. The owner set only takes control",
+        "once ownership is transferred to the `OwnerSet` address. This instruction can only be called",
+        "by the `owner`.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `SubmitOwnershipTransferRequest` context."
+        "* `ctx`  - `InitializeOwnerSet` context.",
+        "* `args` - The `OwnerSetArgs`, which has the threshold and members."
       ],
       "discriminator": [
-        215,
-        13,
-        88,
-        199,
-        48,
-        195,
-        19,
-        225
+        187,
+        166,
+        73,
+        128,
+        44,
+        168,
+        208,
+        110
       ],
       "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "admin",
           "accounts": [
@@ -2280,424 +2303,3401 @@ export type MatchingEngine = {
             },
             {
               "name": "custodian",
-              "writable": true
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
             }
           ]
         },
         {
-          "name": "newOwner",
-          "docs": [
-            "New Owner.",
-            ""
-          ]
+          "name": "ownerSet",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "ownerSetArgs"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "updateAuctionParameters",
+      "name": "migrate",
       "docs": [
-        "This instruction is used to enact an existing auction update proposal. It can only be",
-        "executed after the `slot_enact_delay` has passed. This instruction can only be called by the",
-        "`owner`.",
+        "This instruction is used for executing logic during an upgrade. This instruction can only be",
+        "called by the `upgrade_manager_program`. If the `Custodian` account still has its legacy",
+        "layout, it is reallocated (with rent paid by the `payer`) and rewritten with new fields set to",
+        "their defaults.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `UpdateAuctionParameters` context."
+        "* `ctx` - `Migrate` context."
       ],
       "discriminator": [
-        10,
-        33,
-        10,
-        75,
-        17,
-        63,
-        21,
-        245
+        155,
+        234,
+        231,
+        146,
+        236,
+        158,
+        162,
+        30
       ],
       "accounts": [
         {
           "name": "payer",
+          "docs": [
+            "Pays for any additional rent required to grow the custodian account."
+          ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "admin",
-          "accounts": [
-            {
-              "name": "owner",
-              "signer": true
-            },
-            {
-              "name": "custodian",
-              "writable": true
-            }
-          ]
+          "name": "owner",
+          "docs": [
+            "Program's owner. This account is checked against the custodian's owner in the instruction",
+            "handler because the custodian may still have the layout of a previous program version."
+          ],
+          "signer": true
         },
         {
-          "name": "proposal",
+          "name": "custodian",
+          "docs": [
+            "using either its legacy or current layout."
+          ],
           "writable": true
         },
         {
-          "name": "auctionConfig",
+          "name": "circuitBreaker",
+          "docs": [
+            "Programs deployed before the circuit breaker was added do not have this account."
+          ],
           "writable": true
         },
         {
           "name": "systemProgram"
-        },
-        {
-          "name": "eventAuthority"
-        },
-        {
-          "name": "program"
         }
       ],
       "args": []
     },
     {
-      "name": "updateCctpRouterEndpoint",
+      "name": "migrateFastFill",
       "docs": [
-        "This instruction is used to update a CCTP router endpoint. It allows the caller to change",
-        "the `address`, `mint_recipient`, and `domain`. This instruction can only be called by the",
-        "`owner`.",
+        "This instruction is used to migrate a `FastFill` account created by a previous version of",
+        "this program. The account is reallocated to fit the fields appended to `FastFill` since",
+        "then. This instruction can be called by anyone, who pays for any additional rent.",
         "",
         "# Arguments",
         "",
-        "* `ctx`  - `UpdateCctpRouterEndpoint` context.",
-        "* `args` - The `AddCctpRouterEndpointArgs`, see `admin.rs`."
+        "* `ctx` - `MigrateFastFill` context."
       ],
       "discriminator": [
-        34,
-        122,
-        31,
-        38,
-        73,
-        126,
-        94,
-        127
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "accounts": [
-            {
-              "name": "owner",
-              "signer": true
-            },
-            {
-              "name": "custodian",
-              "accounts": [
-                {
-                  "name": "custodian"
-                }
-              ]
-            }
-          ]
-        },
+        176,
+        252,
+        61,
+        242,
+        52,
+        169,
+        118,
+        34
+      ],
+      "accounts": [
         {
-          "name": "routerEndpoint",
-          "accounts": [
-            {
-              "name": "endpoint",
-              "writable": true
-            }
-          ]
+          "name": "payer",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "remoteTokenMessenger",
+          "name": "fastFill",
           "docs": [
-            "Messenger Minter program)."
-          ]
-        }
-      ],
-      "args": [
+            "the instruction handler using either its legacy or current layout."
+          ],
+          "writable": true
+        },
         {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "addCctpRouterEndpointArgs"
-            }
-          }
+          "name": "systemProgram"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "updateFeeRecipient",
+      "name": "migrateRouterEndpoint",
       "docs": [
-        "This instruction is used to update the `fee_recipient` field in the `Custodian` account.",
-        "This instruction can only be called by the `owner` or `owner_assistant`.",
+        "This instruction is used to migrate a router endpoint added by a previous program version,",
+        "whose account is reallocated (with rent paid by the `payer`) to fit the fields added since",
+        "then. It also creates the endpoint's `InboundNotional` account if it does not exist. This",
+        "instruction can be called by anyone.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `UpdateFeeRecipient` context."
+        "* `ctx`   - `MigrateRouterEndpoint` context.",
+        "* `chain` - Chain ID of the router endpoint."
       ],
       "discriminator": [
-        249,
-        0,
-        198,
-        35,
-        183,
-        123,
-        57,
-        188
+        95,
+        13,
+        120,
+        66,
+        197,
+        251,
+        179,
+        141
       ],
       "accounts": [
         {
-          "name": "admin",
-          "accounts": [
-            {
-              "name": "ownerOrAssistant",
-              "signer": true
-            },
-            {
-              "name": "custodian",
-              "writable": true
-            }
-          ]
+          "name": "payer",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "newFeeRecipientToken"
+          "name": "routerEndpoint",
+          "docs": [
+            "the instruction handler using either its legacy or current layout."
+          ],
+          "writable": true
         },
         {
-          "name": "newFeeRecipient",
+          "name": "inboundNotional",
           "docs": [
-            "New Fee Recipient.",
-            ""
-          ]
+            "Endpoints added before inbound notional amounts were tracked do not have this account."
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "chain",
+          "type": "u16"
+        }
+      ]
     },
     {
-      "name": "updateLocalRouterEndpoint",
+      "name": "placeInitialOfferCctp",
       "docs": [
-        "This instruction is used to update a Local router endpoint. It allows the caller to change",
-        "the `address` and `mint_recipient`. This instruction can only be called by the `owner`.",
-        "",
+        "This instruction is used to create a new auction given a valid `FastMarketOrder` vaa. This",
+        "instruction will record information about the auction and transfer funds from the payer to",
+        "an auction-specific token custody account. This instruction can be called by anyone.",
         "# Arguments",
         "",
-        "* `ctx` - `UpdateLocalRouterEndpoint` context."
+        "* `ctx`         - `PlaceInitialOfferCctp` context.",
+        "* `offer_price` - The fee that the caller is willing to accept in order for fufilling the",
+        "fast order. This fee is paid in USDC."
       ],
       "discriminator": [
-        222,
-        237,
-        142,
-        228,
-        88,
-        3,
-        49,
-        102
+        157,
+        156,
+        175,
+        35,
+        91,
+        249,
+        1,
+        129
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "transferAuthority",
+          "docs": [
+            "The auction participant needs to set approval to this PDA.",
+            ""
+          ]
+        },
+        {
+          "name": "custodian",
           "accounts": [
             {
-              "name": "owner",
-              "signer": true
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "auctionConfig"
+        },
+        {
+          "name": "fastOrderPath",
+          "accounts": [
+            {
+              "name": "fastVaa",
+              "accounts": [
+                {
+                  "name": "vaa"
+                }
+              ]
             },
             {
-              "name": "custodian",
+              "name": "path",
               "accounts": [
                 {
-                  "name": "custodian"
+                  "name": "fromEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                },
+                {
+                  "name": "toEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
                 }
               ]
             }
           ]
         },
         {
-          "name": "routerEndpoint",
-          "accounts": [
-            {
-              "name": "endpoint",
-              "writable": true
-            }
-          ]
+          "name": "auction",
+          "docs": [
+            "This account should only be created once, and should never be changed to",
+            "init_if_needed. Otherwise someone can game an existing auction."
+          ],
+          "writable": true
         },
         {
-          "name": "local",
+          "name": "offerToken"
+        },
+        {
+          "name": "auctionCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "mint",
           "accounts": [
             {
-              "name": "tokenRouterProgram",
-              "docs": [
-                "emitter (router endpoint) address."
-              ]
-            },
-            {
-              "name": "tokenRouterEmitter"
+              "name": "mint"
             },
             {
-              "name": "tokenRouterMintRecipient"
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
             }
           ]
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "inboundNotional",
+          "docs": [
+            "Notional amounts accepted from the source endpoint. This account is only required if the",
+            "source endpoint's inbound limit or the circuit breaker is enabled."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "circuitBreaker",
+          "docs": [
+            "This account is only required if the circuit breaker is enabled."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "offerPrice",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "updateOwnerAssistant",
+      "name": "placeInitialOfferDestinationAsset",
       "docs": [
-        "This instruction is used to update the `owner_assistant` field in the `Custodian` account.",
-        "This instruction can only be called by the `owner`.",
+        "This instruction is used to create a new auction for a `FastMarketOrder` destined for",
+        "Solana, where participants bid on the amount of a non-USDC asset delivered to the recipient",
+        "instead of an offer price. The order must name this destination asset and the minimum",
+        "amount out of it. The security deposit is paid in USDC and the amount out is held in an",
+        "auction-specific destination custody account. This instruction can be called by anyone.",
         "",
         "# Arguments",
         "",
-        "* `ctx` - `UpdateOwnerAssistant` context."
+        "* `ctx`        - `PlaceInitialOfferDestinationAsset` context.",
+        "* `amount_out` - The amount of the destination asset the caller is willing to deliver in",
+        "order to fulfill the fast order."
       ],
       "discriminator": [
-        153,
-        83,
-        175,
-        53,
-        168,
-        34,
-        131,
-        22
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "accounts": [
+        231,
+        97,
+        162,
+        118,
+        177,
+        206,
+        167,
+        83
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "transferAuthority",
+          "docs": [
+            "The auction participant needs to set approval to this PDA for both the offer token and the",
+            "destination offer token.",
+            ""
+          ]
+        },
+        {
+          "name": "custodian",
+          "accounts": [
             {
-              "name": "owner",
-              "signer": true
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "auctionConfig"
+        },
+        {
+          "name": "fastOrderPath",
+          "accounts": [
+            {
+              "name": "fastVaa",
+              "accounts": [
+                {
+                  "name": "vaa"
+                }
+              ]
             },
             {
-              "name": "custodian",
-              "writable": true
+              "name": "path",
+              "accounts": [
+                {
+                  "name": "fromEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                },
+                {
+                  "name": "toEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                }
+              ]
             }
           ]
         },
         {
-          "name": "newOwnerAssistant",
+          "name": "inboundNotional",
           "docs": [
-            "New Assistant.",
-            ""
+            "Notional amounts accepted from the source endpoint. This account is only required if the",
+            "source endpoint's inbound limit or the circuit breaker is enabled."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "circuitBreaker",
+          "docs": [
+            "This account is only required if the circuit breaker is enabled."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "This account should only be created once, and should never be changed to",
+            "init_if_needed. Otherwise someone can game an existing auction."
+          ],
+          "writable": true
+        },
+        {
+          "name": "offerToken",
+          "docs": [
+            "Token account that the security deposit is transferred from. This token account will be",
+            "repaid the amount in when the auction is settled."
+          ]
+        },
+        {
+          "name": "auctionCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "usdc",
+          "accounts": [
+            {
+              "name": "mint"
+            }
+          ]
+        },
+        {
+          "name": "destinationMint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "destinationOfferToken",
+          "docs": [
+            "Token account that the amount out is transferred from."
           ]
+        },
+        {
+          "name": "destinationCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "auction",
-      "discriminator": [
-        218,
-        94,
-        247,
-        242,
-        126,
-        233,
-        131,
-        81
-      ]
-    },
-    {
-      "name": "auctionConfig",
-      "discriminator": [
-        195,
-        54,
-        8,
-        51,
-        28,
-        231,
-        33,
-        142
+      "args": [
+        {
+          "name": "amountOut",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "custodian",
+      "name": "prepareOrderResponseCctp",
+      "docs": [
+        "This instruction is used to prepare the order response for a CCTP transfer. This instruction",
+        "will redeem the finalized transfer associated with a particular auction, and deposit the",
+        "funds to the `prepared_custody_token` account that is created during execution. This",
+        "instruction will create a `PreparedOrderResponse` account that will be used to settle the",
+        "auction.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `PrepareOrderResponseCctp` context."
+      ],
       "discriminator": [
-        132,
-        228,
-        139,
+        221,
+        178,
         184,
-        112,
-        228,
-        108,
-        240
-      ]
-    },
-    {
-      "name": "fastFill",
-      "discriminator": [
-        89,
-        120,
-        166,
-        41,
-        106,
-        227,
-        218,
-        121
-      ]
-    },
-    {
-      "name": "fastFillSequencer",
-      "discriminator": [
-        70,
-        193,
-        18,
-        127,
-        227,
-        183,
-        46,
-        177
-      ]
-    },
-    {
-      "name": "preparedOrderResponse",
-      "discriminator": [
-        20,
-        123,
-        155,
-        182,
-        141,
-        189,
-        18,
-        173
-      ]
-    },
-    {
-      "name": "proposal",
-      "discriminator": [
-        26,
-        94,
-        189,
-        187,
-        116,
-        136,
-        53,
-        33
-      ]
-    },
-    {
-      "name": "remoteTokenMessenger",
-      "discriminator": [
-        105,
-        115,
-        174,
-        34,
-        95,
-        233,
-        138,
-        252
-      ]
-    },
-    {
-      "name": "reservedFastFillSequence",
-      "discriminator": [
-        77,
-        151,
-        219,
-        66,
-        126,
-        238,
-        151,
+        43,
+        247,
+        248,
+        90,
+        160
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "fastOrderPath",
+          "accounts": [
+            {
+              "name": "fastVaa",
+              "accounts": [
+                {
+                  "name": "vaa"
+                }
+              ]
+            },
+            {
+              "name": "path",
+              "accounts": [
+                {
+                  "name": "fromEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                },
+                {
+                  "name": "toEndpoint",
+                  "accounts": [
+                    {
+                      "name": "endpoint"
+                    }
+                  ]
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "finalizedVaa",
+          "accounts": [
+            {
+              "name": "vaa"
+            }
+          ]
+        },
+        {
+          "name": "preparedOrderResponse",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "baseFeeToken",
+          "docs": [
+            "This token account will be the one that collects the base fee only if an auction's order",
+            "was executed late. Otherwise, the protocol's fee recipient token account will be used for",
+            "non-existent auctions and the best offer token account will be used for orders executed on",
+            "time."
+          ]
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "cctp",
+          "accounts": [
+            {
+              "name": "mintRecipient",
+              "accounts": [
+                {
+                  "name": "mintRecipient",
+                  "writable": true
+                }
+              ]
+            },
+            {
+              "name": "messageTransmitterAuthority"
+            },
+            {
+              "name": "messageTransmitterConfig"
+            },
+            {
+              "name": "usedNonces",
+              "docs": [
+                "first_nonce.to_string()\\] (CCTP Message Transmitter program)."
+              ],
+              "writable": true
+            },
+            {
+              "name": "messageTransmitterEventAuthority"
+            },
+            {
+              "name": "tokenMessenger"
+            },
+            {
+              "name": "remoteTokenMessenger",
+              "docs": [
+                "Messenger Minter program)."
+              ]
+            },
+            {
+              "name": "tokenMinter"
+            },
+            {
+              "name": "localToken",
+              "docs": [
+                "Token Messenger Minter's Local Token account. This program uses the mint of this account to",
+                "validate the `mint_recipient` token account's mint.",
+                ""
+              ],
+              "writable": true
+            },
+            {
+              "name": "tokenPair",
+              "docs": [
+                "Token Messenger Minter program)."
+              ]
+            },
+            {
+              "name": "tokenMessengerMinterCustodyToken",
+              "writable": true
+            },
+            {
+              "name": "tokenMessengerMinterEventAuthority"
+            },
+            {
+              "name": "tokenMessengerMinterProgram"
+            },
+            {
+              "name": "messageTransmitterProgram"
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "inboundNotional",
+          "docs": [
+            "Notional amounts accepted from the source endpoint. This account is only required if the",
+            "circuit breaker is enabled."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "circuitBreaker",
+          "docs": [
+            "This account is only required if the circuit breaker is enabled."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "amount is recorded by the circuit breaker. Otherwise it was recorded when the auction was",
+            "started."
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "cctpMessageArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAuctionParameters",
+      "docs": [
+        "This instruction is used to propose new auction parameters. A proposal cannot be enacted",
+        "until one epoch has passed. This instruction can only be called by the `owner` or",
+        "`owner_assistant`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`    - `ProposeAuctionParameters` context.",
+        "* `params` - The new `AuctionParameters`, see `auction_config.rs`."
+      ],
+      "discriminator": [
+        86,
+        19,
+        21,
+        43,
+        32,
+        106,
+        249,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "ownerOrAssistant",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "epochSchedule"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "auctionParameters"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeOwnerSetTransaction",
+      "docs": [
+        "This instruction is used by an owner set member to propose an instruction for this program,",
+        "which the owner set will sign for once enough members have approved it. The proposer's",
+        "approval is recorded.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `ProposeOwnerSetTransaction` context.",
+        "* `args` - The `OwnerSetTransactionArgs`, which has the instruction's accounts and data."
+      ],
+      "discriminator": [
+        157,
+        40,
+        72,
+        86,
+        38,
+        35,
+        249,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "ownerSetMember",
+          "accounts": [
+            {
+              "name": "member",
+              "signer": true
+            },
+            {
+              "name": "ownerSet",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "transaction",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "ownerSetTransactionArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "reclaimExpiredFastFill",
+      "docs": [
+        "This instruction is used to reclaim a `FastFill` account that was not redeemed within",
+        "`FastFill::EXPIRY_SECONDS` of being issued. The fill amount is delivered to the redeemer's",
+        "associated token account. If this token account cannot receive the fill amount, the amount",
+        "is held in the redeemer's escrow token account instead, which the redeemer can claim with",
+        "`claim_fast_fill_escrow`. Lamports are returned to the creator of the `FastFill` account.",
+        "This instruction can be called by anyone.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `ReclaimExpiredFastFill` context."
+      ],
+      "discriminator": [
+        16,
+        173,
+        146,
+        34,
+        114,
+        158,
+        247,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Pays for the redeemer's escrow token account if it needs to be created."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "preparedBy",
+          "docs": [
+            "Instead of having the preparer sign for this instruction, we allow anyone to call this",
+            "instruction on behalf of the preparer.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "fastFill",
+          "docs": [
+            "Fast fill account, which must not have been redeemed before its expiry.",
+            "",
+            "Seeds must be \\[\"fast-fill\", source_chain, order_sender, sequence\\]."
+          ],
+          "writable": true
+        },
+        {
+          "name": "redeemerToken",
+          "docs": [
+            "Redeemer's associated token account, which receives the fill amount if it can. Otherwise",
+            "the fill amount is held in the escrow token account.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "escrowToken",
+          "docs": [
+            "Escrow token account owned by the custodian, which holds reclaimed fill amounts that could",
+            "not be delivered to the redeemer's associated token account until the redeemer claims them.",
+            "This account is only required in this case, where it is created if it does not exist yet.",
+            ""
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "fromEndpoint",
+          "docs": [
+            "Router endpoint of the fill's source chain, which is the authority of the local custody",
+            "token account."
+          ]
+        },
+        {
+          "name": "localCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "fastFillCustodyToken",
+          "docs": [
+            "Token account owned by the fast fill, which holds the fill amount if the fast fill owns its",
+            "custody token account. Only required in this case, where this account will be closed."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeRouterEndpoint",
+      "docs": [
+        "This instruction is used to remove a router endpoint that has been disabled for at least the",
+        "router endpoint removal delay. The endpoint account (and its local custody token account if",
+        "it exists) is closed and its lamports are returned to the `owner`. This instruction can only",
+        "be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `RemoveRouterEndpoint` context."
+      ],
+      "discriminator": [
+        248,
+        4,
+        62,
+        60,
+        82,
+        35,
+        88,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "The owner receives the rent from the closed accounts."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "routerEndpoint",
+          "writable": true
+        },
+        {
+          "name": "inboundNotional",
+          "writable": true
+        },
+        {
+          "name": "localCustodyToken",
+          "docs": [
+            "This token account only exists for CCTP endpoints. If it exists, it must be empty in order",
+            "to be closed.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "routerEndpointRegistry",
+          "accounts": [
+            {
+              "name": "registry",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeSupportedMint",
+      "docs": [
+        "This instruction is used to remove a supported mint so new auctions cannot be started with",
+        "it. Its `SupportedMint` account is closed and its lamports are returned to the `owner`. This",
+        "instruction can only be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `RemoveSupportedMint` context."
+      ],
+      "discriminator": [
+        229,
+        89,
+        87,
+        50,
+        130,
+        203,
+        193,
+        106
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "The owner receives the rent from the closed account."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "supportedMint",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reserveFastFillSequenceActiveAuction",
+      "docs": [
+        "This instruction is used to reserve a sequence number for a fast fill. Fast fills are orders",
+        "that have been fulfilled and are destined for Solana and are seeded by source chain, order",
+        "sender and sequence number (similar to how Wormhole VAAs are identified by emitter chain,",
+        "emitter address and sequence number).",
+        "",
+        "Prior to executing `execute_fast_order_local` after the duration of an auction, the winning",
+        "auction participant should call this instruction to reserve the fast fill's sequence number.",
+        "This sequence number is warehoused in the `ReservedFastFillSequence` account and will be",
+        "closed when the order is executed.",
+        "",
+        "Auction participants can listen to the `FastFillSequenceReserved` event to track when he",
+        "(or associated payer) called this instruction so he can execute local orders easily.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `ReserveFastFillSequenceActiveAuction` context."
+      ],
+      "discriminator": [
+        206,
+        255,
+        241,
+        68,
+        224,
+        129,
+        210,
+        187
+      ],
+      "accounts": [
+        {
+          "name": "reserveSequence",
+          "accounts": [
+            {
+              "name": "payer",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "fastOrderPath",
+              "accounts": [
+                {
+                  "name": "fastVaa",
+                  "accounts": [
+                    {
+                      "name": "vaa"
+                    }
+                  ]
+                },
+                {
+                  "name": "path",
+                  "accounts": [
+                    {
+                      "name": "fromEndpoint",
+                      "accounts": [
+                        {
+                          "name": "endpoint"
+                        }
+                      ]
+                    },
+                    {
+                      "name": "toEndpoint",
+                      "accounts": [
+                        {
+                          "name": "endpoint"
+                        }
+                      ]
+                    }
+                  ]
+                }
+              ]
+            },
+            {
+              "name": "sequencer",
+              "docs": [
+                "This sequencer determines the next reserved sequence. If it does not exist for a given",
+                "source chain and sender, it will be created.",
+                "",
+                "Auction participants may want to consider pricing the creation of this account into their",
+                "offer prices by checking whether this sequencer already exists for those orders destined for",
+                "Solana."
+              ],
+              "writable": true
+            },
+            {
+              "name": "reserved",
+              "docs": [
+                "This account will be used to determine the sequence of the next fast fill. When a local",
+                "order is executed or an non-existent auction is settled, this account will be closed."
+              ],
+              "writable": true
+            },
+            {
+              "name": "auction",
+              "docs": [
+                "must have been created by this point. Otherwise the auction account must reflect a completed",
+                "auction."
+              ],
+              "writable": true
+            },
+            {
+              "name": "systemProgram"
+            }
+          ]
+        },
+        {
+          "name": "auctionConfig"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reserveFastFillSequenceNoAuction",
+      "docs": [
+        "This instruction is used to reserve a sequence number for a fast fill. Fast fills are orders",
+        "that have been fulfilled and are destined for Solana and are seeded by source chain, order",
+        "sender and sequence number (similar to how Wormhole VAAs are identified by emitter chain,",
+        "emitter address and sequence number).",
+        "",
+        "Prior to executing `settle_auction_none_local` if there is no auction, whomever prepared the",
+        "order response should call this instruction to reserve the fast fill's sequence number.",
+        "This sequence number is warehoused in the `ReservedFastFillSequence` account and will be",
+        "closed when the funds are finally settled.",
+        "",
+        "NOTE: This instruction is expected to be in the same transaction as the one that executes",
+        "the prepare order response instruction. If it is not, there is a risk that after preparing",
+        "the order response that someone starts an auction. This scenario risks the preparer's rent",
+        "that he paid because the winning auction participant can take his lamports when he calls",
+        "settle auction complete. Although this is an unlikely scenario, it is possible if there is",
+        "no deadline specified to start the auction and no participants use the fast VAA to start an",
+        "auction until the finalized VAA exists (which guarantees that the funds have finalized on",
+        "the source network).",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `ReserveFastFillSequenceNoAuction` context."
+      ],
+      "discriminator": [
+        61,
+        148,
+        140,
+        87,
+        186,
+        87,
+        212,
+        239
+      ],
+      "accounts": [
+        {
+          "name": "reserveSequence",
+          "accounts": [
+            {
+              "name": "payer",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "fastOrderPath",
+              "accounts": [
+                {
+                  "name": "fastVaa",
+                  "accounts": [
+                    {
+                      "name": "vaa"
+                    }
+                  ]
+                },
+                {
+                  "name": "path",
+                  "accounts": [
+                    {
+                      "name": "fromEndpoint",
+                      "accounts": [
+                        {
+                          "name": "endpoint"
+                        }
+                      ]
+                    },
+                    {
+                      "name": "toEndpoint",
+                      "accounts": [
+                        {
+                          "name": "endpoint"
+                        }
+                      ]
+                    }
+                  ]
+                }
+              ]
+            },
+            {
+              "name": "sequencer",
+              "docs": [
+                "This sequencer determines the next reserved sequence. If it does not exist for a given",
+                "source chain and sender, it will be created.",
+                "",
+                "Auction participants may want to consider pricing the creation of this account into their",
+                "offer prices by checking whether this sequencer already exists for those orders destined for",
+                "Solana."
+              ],
+              "writable": true
+            },
+            {
+              "name": "reserved",
+              "docs": [
+                "This account will be used to determine the sequence of the next fast fill. When a local",
+                "order is executed or an non-existent auction is settled, this account will be closed."
+              ],
+              "writable": true
+            },
+            {
+              "name": "auction",
+              "docs": [
+                "must have been created by this point. Otherwise the auction account must reflect a completed",
+                "auction."
+              ],
+              "writable": true
+            },
+            {
+              "name": "systemProgram"
+            }
+          ]
+        },
+        {
+          "name": "preparedOrderResponse",
+          "docs": [
+            "The preparer will be the beneficiary of the reserved fast fill sequence account when it is",
+            "closed. This instruction will not allow this account to be provided if there is an existing",
+            "auction, which would enforce the order be executed when it is time to complete the auction."
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeRole",
+      "docs": [
+        "This instruction is used to revoke a role from a holder. This instruction can only be called",
+        "by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `RevokeRole` context.",
+        "* `role` - The `Role` to revoke."
+      ],
+      "discriminator": [
+        179,
+        232,
+        2,
+        180,
+        48,
+        227,
+        82,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "roleHolder",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "role"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPause",
+      "docs": [
+        "This instruction is used to pause or unpause further processing of new auctions. Only the",
+        "`owner`, `owner_assistant` or a `Pauser` role holder can pause the program.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`   - `SetPause` context.",
+        "* `pause` - Boolean indicating whether to pause the program."
+      ],
+      "discriminator": [
+        63,
+        32,
+        154,
+        2,
+        56,
+        103,
+        79,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setRouterEndpointInboundLimit",
+      "docs": [
+        "This instruction is used to cap the notional amount of fast orders accepted from a router",
+        "endpoint per window of slots. Setting a window of zero slots removes the limit. Only the",
+        "`owner`, `owner_assistant` or an `EndpointManager` role holder can set this limit.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `SetRouterEndpointInboundLimit` context.",
+        "* `args` - The `SetRouterEndpointInboundLimitArgs`, which has the window size in slots and",
+        "the max notional amount per window."
+      ],
+      "discriminator": [
+        102,
+        169,
+        248,
+        198,
+        66,
+        235,
+        192,
+        206
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "inboundNotional",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "setRouterEndpointInboundLimitArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRouterEndpointLimits",
+      "docs": [
+        "This instruction is used to set the optional limits on orders destined for a router",
+        "endpoint, which are the max redeemer message length and the min and max order amounts. These",
+        "limits are enforced when market orders are prepared by the Token Router and when auctions",
+        "are started. Only the `owner`, `owner_assistant` or an `EndpointManager` role holder can set",
+        "these limits.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`    - `SetRouterEndpointLimits` context.",
+        "* `limits` - The `EndpointLimits`."
+      ],
+      "discriminator": [
+        133,
+        237,
+        93,
+        255,
+        194,
+        80,
+        32,
+        141
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "limits",
+          "type": {
+            "defined": {
+              "name": "endpointLimits"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRouterEndpointPause",
+      "docs": [
+        "This instruction is used to temporarily pause a router endpoint without disabling it. While",
+        "paused, orders to and from this endpoint are rejected. A pause can be lifted automatically at",
+        "a specified slot. Only the `owner`, `owner_assistant` or a `Pauser` role holder can pause an",
+        "endpoint.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `SetRouterEndpointPause` context.",
+        "* `args` - The `SetRouterEndpointPauseArgs`, which has the pause flag, optional unpause slot",
+        "and reason code."
+      ],
+      "discriminator": [
+        104,
+        114,
+        113,
+        9,
+        121,
+        174,
+        253,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "setRouterEndpointPauseArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setStagePause",
+      "docs": [
+        "This instruction is used to pause or unpause a specific stage of order processing (new",
+        "auctions, offer improvement, order execution, fast fill creation or settlement). A pause can",
+        "be lifted automatically at a specified slot. Only the `owner`, `owner_assistant` or a",
+        "`Pauser` role holder can pause a stage.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `SetStagePause` context.",
+        "* `args` - The `SetStagePauseArgs`, which has the stage, pause flag, optional unpause slot",
+        "and reason code."
+      ],
+      "discriminator": [
+        93,
+        102,
+        98,
+        157,
+        223,
+        97,
+        135,
+        134
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "setStagePauseArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "settleAuctionComplete",
+      "docs": [
+        "This instruction is used to settle the acution after the `FastMarketOrder` has been",
+        "executed, and the `PreparedOrderResponse` has been created. This instruction will settle the",
+        "auction by transferring the funds from the `prepared_custody_token` account to the best",
+        "offer account.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `SettleAuctionComplete` context."
+      ],
+      "discriminator": [
+        84,
+        39,
+        0,
+        132,
+        21,
+        101,
+        222,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "beneficiary",
+          "docs": [
+            "finalized VAA."
+          ],
+          "writable": true
+        },
+        {
+          "name": "baseFeeToken",
+          "docs": [
+            "This token account will receive the base fee only if there was a penalty when executing the",
+            "order. If it does not exist when there is a penalty, this instruction handler will revert.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "bestOfferToken",
+          "docs": [
+            "Destination token account, which the redeemer may not own. But because the redeemer is a",
+            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
+            "to any account he chooses (this one).",
+            "",
+            "of the tokens to the base fee token account."
+          ],
+          "writable": true
+        },
+        {
+          "name": "preparedOrderResponse",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "auction",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleAuctionNoneCctp",
+      "docs": [
+        "This instruction is used to route funds to the `recipient` for a `FastMarketOrder` with",
+        "no corresponding auction on Solana. This instruction can be called by anyone, but the sum of",
+        "`init_auction_fee` and `base_fee` associated with relaying a finalized VAA will be paid to",
+        "the `fee_recipient`. This instruction generates a `Fill` message.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `SettleAuctionNoneCctp` context."
+      ],
+      "discriminator": [
+        120,
+        236,
+        82,
+        121,
+        242,
+        118,
+        74,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "coreMessage",
+          "writable": true
+        },
+        {
+          "name": "cctpMessage",
+          "writable": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "feeRecipientToken",
+          "docs": [
+            "Destination token account, which the redeemer may not own. But because the redeemer is a",
+            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
+            "to any account he chooses (this one).",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "prepared",
+          "accounts": [
+            {
+              "name": "by",
+              "writable": true
+            },
+            {
+              "name": "orderResponse",
+              "writable": true
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "There should be no account data here because an auction was never created."
+          ],
+          "writable": true
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "config",
+              "writable": true
+            },
+            {
+              "name": "emitterSequence",
+              "writable": true
+            },
+            {
+              "name": "feeCollector",
+              "writable": true
+            },
+            {
+              "name": "coreBridgeProgram"
+            }
+          ]
+        },
+        {
+          "name": "cctp",
+          "accounts": [
+            {
+              "name": "mint",
+              "docs": [
+                "Circle-supported mint.",
+                "",
+                "Token Messenger Minter program's local token account."
+              ],
+              "writable": true
+            },
+            {
+              "name": "tokenMessengerMinterSenderAuthority"
+            },
+            {
+              "name": "messageTransmitterConfig",
+              "writable": true
+            },
+            {
+              "name": "tokenMessenger"
+            },
+            {
+              "name": "remoteTokenMessenger",
+              "docs": [
+                "Messenger Minter program)."
+              ]
+            },
+            {
+              "name": "tokenMinter",
+              "docs": [
+                "CHECK Seeds must be \\[\"token_minter\"\\] (CCTP Token Messenger Minter program)."
+              ]
+            },
+            {
+              "name": "localToken",
+              "docs": [
+                "Local token account, which this program uses to validate the `mint` used to burn.",
+                ""
+              ],
+              "writable": true
+            },
+            {
+              "name": "tokenMessengerMinterEventAuthority"
+            },
+            {
+              "name": "tokenMessengerMinterProgram"
+            },
+            {
+              "name": "messageTransmitterProgram"
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "sysvars",
+          "accounts": [
+            {
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
+            }
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleAuctionNoneLocal",
+      "docs": [
+        "This instruction is used to settle a `FastMarketOrder` with no corresponding auction. This",
+        "instruction can be called by anyone, but the sum of `init_auction_fee` and `base_fee`",
+        "associated with relaying a finalized VAA will be paid to the `fee_recipient`. This",
+        "instruction creates a `FastFill` account, whose relayer fee is read from the `fast_vaa`",
+        "account if it is provided.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `SettleAuctionNoneLocal` context."
+      ],
+      "discriminator": [
+        253,
+        213,
+        132,
+        148,
+        31,
+        119,
+        215,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "feeRecipientToken",
+          "docs": [
+            "Destination token account, which the redeemer may not own. But because the redeemer is a",
+            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
+            "to any account he chooses (this one).",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "prepared",
+          "accounts": [
+            {
+              "name": "by",
+              "writable": true
+            },
+            {
+              "name": "orderResponse",
+              "writable": true
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "This account will have been created using the reserve fast fill sequence (no auction)",
+            "instruction. We need to make sure that this account has not been used in an auction."
+          ],
+          "writable": true
+        },
+        {
+          "name": "reservedSequence",
+          "docs": [
+            "This account will be closed at the end of this instruction instead of using the close",
+            "account directive here.",
+            "",
+            "If we could reference the beneficiary using `prepared.by`, this would be a different story.",
+            "",
+            "NOTE: We do not need to do a VAA hash check because that was already performed when the",
+            "reserved sequence was created."
+          ],
+          "writable": true
+        },
+        {
+          "name": "fastFill",
+          "writable": true
+        },
+        {
+          "name": "localCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "fastVaa",
+          "docs": [
+            "Fast VAA of the order, which encodes the relayer fee. If this account is not provided, the",
+            "fast fill will not be automatically delivered to the redeemer.",
+            ""
+          ],
+          "optional": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "sysvars",
+          "accounts": [
+            {
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
+            }
+          ]
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleAuctionNoneNtt",
+      "docs": [
+        "This instruction is used to route funds via an NTT manager for a `FastMarketOrder` with no",
+        "corresponding auction on Solana. This instruction can be called by anyone, but the sum of",
+        "`init_auction_fee` and `base_fee` associated with relaying a finalized VAA will be paid to",
+        "the `fee_recipient`. This instruction generates a `Fill` message.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `SettleAuctionNoneNtt` context."
+      ],
+      "discriminator": [
+        30,
+        145,
+        91,
+        237,
+        148,
+        182,
+        232,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "coreMessage",
+          "writable": true
+        },
+        {
+          "name": "nttOutboxItem",
+          "writable": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "feeRecipientToken",
+          "docs": [
+            "Destination token account, which the redeemer may not own. But because the redeemer is a",
+            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
+            "to any account he chooses (this one).",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "prepared",
+          "accounts": [
+            {
+              "name": "by",
+              "writable": true
+            },
+            {
+              "name": "orderResponse",
+              "writable": true
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "There should be no account data here because an auction was never created."
+          ],
+          "writable": true
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "config",
+              "writable": true
+            },
+            {
+              "name": "emitterSequence",
+              "writable": true
+            },
+            {
+              "name": "feeCollector",
+              "writable": true
+            },
+            {
+              "name": "coreBridgeProgram"
+            }
+          ]
+        },
+        {
+          "name": "ntt",
+          "accounts": [
+            {
+              "name": "mint",
+              "docs": [
+                "instruction using this account."
+              ]
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            },
+            {
+              "name": "transceiver"
+            },
+            {
+              "name": "nttManagerProgram"
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "sysvars",
+          "accounts": [
+            {
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
+            }
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "settleAuctionNoneTokenBridge",
+      "docs": [
+        "This instruction is used to route funds via the Token Bridge for a `FastMarketOrder` with",
+        "no corresponding auction on Solana. This instruction can be called by anyone, but the sum of",
+        "`init_auction_fee` and `base_fee` associated with relaying a finalized VAA will be paid to",
+        "the `fee_recipient`. This instruction generates a `Fill` message.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `SettleAuctionNoneTokenBridge` context."
+      ],
+      "discriminator": [
+        206,
+        249,
+        88,
+        95,
+        207,
+        141,
+        216,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "coreMessage",
+          "writable": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "feeRecipientToken",
+          "docs": [
+            "Destination token account, which the redeemer may not own. But because the redeemer is a",
+            "signer and is the one encoded in the Deposit Fill message, he may have the tokens be sent",
+            "to any account he chooses (this one).",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "prepared",
+          "accounts": [
+            {
+              "name": "by",
+              "writable": true
+            },
+            {
+              "name": "orderResponse",
+              "writable": true
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "There should be no account data here because an auction was never created."
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenBridge",
+          "accounts": [
+            {
+              "name": "mint",
+              "docs": [
+                "by the instruction using this account."
+              ],
+              "writable": true
+            },
+            {
+              "name": "config"
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            },
+            {
+              "name": "transferAuthority"
+            },
+            {
+              "name": "custodyAuthority"
+            },
+            {
+              "name": "emitter"
+            },
+            {
+              "name": "coreBridgeConfig",
+              "writable": true
+            },
+            {
+              "name": "emitterSequence",
+              "writable": true
+            },
+            {
+              "name": "coreFeeCollector",
+              "writable": true
+            },
+            {
+              "name": "coreBridgeProgram"
+            },
+            {
+              "name": "tokenBridgeProgram"
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "sysvars",
+          "accounts": [
+            {
+              "name": "clock",
+              "docs": [
+                "Wormhole Core Bridge needs the clock sysvar based on its legacy implementation.",
+                ""
+              ]
+            },
+            {
+              "name": "rent",
+              "docs": [
+                "Wormhole Core Bridge needs the rent sysvar based on its legacy implementation.",
+                ""
+              ]
+            }
+          ]
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitOwnershipTransferRequest",
+      "docs": [
+        "This instruction sets the `pending_owner` field in the `Custodian` account. This instruction",
+        "can only be called by the `owner`. The `pending_owner` address must be valid, meaning it",
+        "cannot be the zero address or the current owner. The request expires if it is not confirmed",
+        "within `OWNERSHIP_TRANSFER_REQUEST_EXPIRATION` slots.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `SubmitOwnershipTransferRequest` context."
+      ],
+      "discriminator": [
+        215,
+        13,
+        88,
+        199,
+        48,
+        195,
+        19,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "newOwner",
+          "docs": [
+            "New Owner.",
+            ""
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateAuctionParameters",
+      "docs": [
+        "This instruction is used to enact an existing auction update proposal. It can only be",
+        "executed after the `slot_enact_delay` has passed. This instruction can only be called by the",
+        "`owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `UpdateAuctionParameters` context."
+      ],
+      "discriminator": [
+        10,
+        33,
+        10,
+        75,
+        17,
+        63,
+        21,
+        245
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        },
+        {
+          "name": "auctionConfig",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateCctpRouterEndpoint",
+      "docs": [
+        "This instruction is used to update a CCTP router endpoint. It allows the caller to change",
+        "the `address`, `mint_recipient`, and `domain`. When the `address` changes, the previous",
+        "address can still be accepted as a source of messages until a specified slot so that",
+        "messages in flight from the old emitter can be processed. This instruction can only be",
+        "called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `UpdateCctpRouterEndpoint` context.",
+        "* `args` - The `UpdateCctpRouterEndpointArgs`, which has the `AddCctpRouterEndpointArgs`",
+        "(see `admin.rs`) and the optional expiry slot of the previous address."
+      ],
+      "discriminator": [
+        34,
+        122,
+        31,
+        38,
+        73,
+        126,
+        94,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "routerEndpointRegistry",
+          "accounts": [
+            {
+              "name": "registry",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "remoteTokenMessenger",
+          "docs": [
+            "Messenger Minter program)."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "updateCctpRouterEndpointArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateCircuitBreaker",
+      "docs": [
+        "This instruction is used to configure the circuit breaker, which caps the notional amount of",
+        "inbound fast orders per window of slots, both globally and per source chain. Once either",
+        "threshold is exceeded, new auctions are refused until the window rolls over. The circuit",
+        "breaker is disabled when the window size is zero. This instruction can only be called by",
+        "the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `UpdateCircuitBreaker` context.",
+        "* `args` - The `UpdateCircuitBreakerArgs`, which has the window size in slots and the global",
+        "and per chain thresholds."
+      ],
+      "discriminator": [
+        8,
+        64,
+        141,
+        250,
+        128,
+        202,
+        179,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "updateCircuitBreakerArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeeRecipient",
+      "docs": [
+        "This instruction is used to update the `fee_recipient` field in the `Custodian` account.",
+        "This instruction can only be called by the `owner`, `owner_assistant` or a `FeeManager` role",
+        "holder.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `UpdateFeeRecipient` context."
+      ],
+      "discriminator": [
+        249,
+        0,
+        198,
+        35,
+        183,
+        123,
+        57,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "newFeeRecipientToken"
+        },
+        {
+          "name": "newFeeRecipient",
+          "docs": [
+            "New Fee Recipient.",
+            ""
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateLocalRouterEndpoint",
+      "docs": [
+        "This instruction is used to update a Local router endpoint. It allows the caller to change",
+        "the `address` and `mint_recipient`. This instruction can only be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `UpdateLocalRouterEndpoint` context."
+      ],
+      "discriminator": [
+        222,
+        237,
+        142,
+        228,
+        88,
+        3,
+        49,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "routerEndpointRegistry",
+          "accounts": [
+            {
+              "name": "registry",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "local",
+          "accounts": [
+            {
+              "name": "tokenRouterProgram",
+              "docs": [
+                "emitter (router endpoint) address."
+              ]
+            },
+            {
+              "name": "tokenRouterEmitter"
+            },
+            {
+              "name": "tokenRouterMintRecipient"
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateMaxGasDropoff",
+      "docs": [
+        "This instruction is used to update the maximum gas drop-off (in lamports) a fast market",
+        "order delivered to Solana can request. This instruction can only be called by the `owner`,",
+        "`owner_assistant` or a `FeeManager` role holder.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`             - `UpdateMaxGasDropoff` context.",
+        "* `max_gas_dropoff` - Maximum gas drop-off in lamports."
+      ],
+      "discriminator": [
+        137,
+        122,
+        33,
+        50,
+        35,
+        79,
+        97,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "maxGasDropoff",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateNttRouterEndpoint",
+      "docs": [
+        "This instruction is used to update an NTT router endpoint. It allows the caller to change",
+        "the `address`, `mint_recipient`, NTT manager and transceiver. When the `address` changes, the",
+        "previous address can still be accepted as a source of messages until a specified slot. This",
+        "instruction can only be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `UpdateNttRouterEndpoint` context.",
+        "* `args` - The `UpdateNttRouterEndpointArgs`, which has the `AddNttRouterEndpointArgs`",
+        "(see `admin.rs`) and the optional expiry slot of the previous address."
+      ],
+      "discriminator": [
+        130,
+        171,
+        67,
+        209,
+        214,
+        42,
+        233,
+        115
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "routerEndpointRegistry",
+          "accounts": [
+            {
+              "name": "registry",
+              "writable": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "updateNttRouterEndpointArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateOwnerAssistant",
+      "docs": [
+        "This instruction is used to propose a new `owner_assistant`, which is set as the",
+        "`pending_owner_assistant` in the `Custodian` account. The new assistant must accept the role",
+        "using `accept_owner_assistant`. This instruction can only be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx` - `UpdateOwnerAssistant` context."
+      ],
+      "discriminator": [
+        153,
+        83,
+        175,
+        53,
+        168,
+        34,
+        131,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "newOwnerAssistant",
+          "docs": [
+            "New Assistant.",
+            ""
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateOwnerSet",
+      "docs": [
+        "This instruction is used to replace the threshold and members of the `OwnerSet` account.",
+        "This instruction can only be called by the `owner`, which is the owner set itself if",
+        "ownership has been transferred to it.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `UpdateOwnerSet` context.",
+        "* `args` - The `OwnerSetArgs`, which has the threshold and members.",
+        "",
+        "Pending `OwnerSetTransaction` accounts may be passed as remaining accounts, which drops",
+        "approvals from members who are no longer in the set."
+      ],
+      "discriminator": [
+        243,
+        143,
+        98,
+        184,
+        163,
+        238,
+        178,
+        207
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "ownerSet",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "ownerSetArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateRouterEndpointRemovalDelay",
+      "docs": [
+        "This instruction is used to update the number of slots a router endpoint must be disabled",
+        "for before it can be removed. This instruction can only be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`   - `UpdateRouterEndpointRemovalDelay` context.",
+        "* `delay` - Number of slots."
+      ],
+      "discriminator": [
+        194,
+        1,
+        247,
+        227,
+        36,
+        81,
+        68,
+        85
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "delay",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateTokenBridgeRouterEndpoint",
+      "docs": [
+        "This instruction is used to update a Token Bridge router endpoint. It allows the caller to",
+        "change the `address` and `mint_recipient`. When the `address` changes, the previous address",
+        "can still be accepted as a source of messages until a specified slot. This instruction can",
+        "only be called by the `owner`.",
+        "",
+        "# Arguments",
+        "",
+        "* `ctx`  - `UpdateTokenBridgeRouterEndpoint` context.",
+        "* `args` - The `UpdateTokenBridgeRouterEndpointArgs`, which has the",
+        "`AddTokenBridgeRouterEndpointArgs` (see `admin.rs`) and the optional expiry slot",
+        "of the previous address."
+      ],
+      "discriminator": [
+        194,
+        216,
+        119,
+        9,
+        226,
+        183,
+        84,
+        17
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "routerEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "routerEndpointRegistry",
+          "accounts": [
+            {
+              "name": "registry",
+              "writable": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "updateTokenBridgeRouterEndpointArgs"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "auction",
+      "discriminator": [
+        218,
+        94,
+        247,
+        242,
+        126,
+        233,
+        131,
+        81
+      ]
+    },
+    {
+      "name": "auctionConfig",
+      "discriminator": [
+        195,
+        54,
+        8,
+        51,
+        28,
+        231,
+        33,
+        142
+      ]
+    },
+    {
+      "name": "circuitBreaker",
+      "discriminator": [
+        123,
+        141,
+        226,
+        182,
+        3,
+        205,
+        19,
+        253
+      ]
+    },
+    {
+      "name": "custodian",
+      "discriminator": [
+        132,
+        228,
+        139,
+        184,
+        112,
+        228,
+        108,
+        240
+      ]
+    },
+    {
+      "name": "fastFill",
+      "discriminator": [
+        89,
+        120,
+        166,
+        41,
+        106,
+        227,
+        218,
+        121
+      ]
+    },
+    {
+      "name": "fastFillSequencer",
+      "discriminator": [
+        70,
+        193,
+        18,
+        127,
+        227,
+        183,
+        46,
+        177
+      ]
+    },
+    {
+      "name": "inboundNotional",
+      "discriminator": [
+        183,
+        69,
+        203,
+        203,
+        48,
+        117,
+        178,
+        177
+      ]
+    },
+    {
+      "name": "ownerSet",
+      "discriminator": [
+        65,
+        59,
+        155,
+        73,
+        75,
+        114,
+        167,
+        212
+      ]
+    },
+    {
+      "name": "ownerSetTransaction",
+      "discriminator": [
+        114,
+        30,
+        81,
+        122,
+        227,
+        240,
+        67,
+        134
+      ]
+    },
+    {
+      "name": "preparedOrderResponse",
+      "discriminator": [
+        20,
+        123,
+        155,
+        182,
+        141,
+        189,
+        18,
+        173
+      ]
+    },
+    {
+      "name": "proposal",
+      "discriminator": [
+        26,
+        94,
+        189,
+        187,
+        116,
+        136,
+        53,
+        33
+      ]
+    },
+    {
+      "name": "remoteTokenMessenger",
+      "discriminator": [
+        105,
+        115,
+        174,
+        34,
+        95,
+        233,
+        138,
+        252
+      ]
+    },
+    {
+      "name": "reservedFastFillSequence",
+      "discriminator": [
+        77,
+        151,
+        219,
+        66,
+        126,
+        238,
+        151,
         179
       ]
     },
+    {
+      "name": "roleHolder",
+      "discriminator": [
+        107,
+        145,
+        245,
+        94,
+        248,
+        36,
+        134,
+        52
+      ]
+    },
     {
       "name": "routerEndpoint",
       "discriminator": [
@@ -2710,6 +5710,32 @@ export type MatchingEngine = {
         154,
         205
       ]
+    },
+    {
+      "name": "routerEndpointRegistry",
+      "discriminator": [
+        62,
+        123,
+        218,
+        142,
+        246,
+        43,
+        86,
+        52
+      ]
+    },
+    {
+      "name": "supportedMint",
+      "discriminator": [
+        121,
+        81,
+        245,
+        119,
+        245,
+        243,
+        243,
+        158
+      ]
     }
   ],
   "events": [
@@ -2752,6 +5778,19 @@ export type MatchingEngine = {
         111
       ]
     },
+    {
+      "name": "circuitBreakerTripped",
+      "discriminator": [
+        188,
+        9,
+        111,
+        118,
+        136,
+        206,
+        199,
+        65
+      ]
+    },
     {
       "name": "enacted",
       "discriminator": [
@@ -2765,6 +5804,19 @@ export type MatchingEngine = {
         143
       ]
     },
+    {
+      "name": "fastFillReclaimed",
+      "discriminator": [
+        70,
+        8,
+        60,
+        73,
+        128,
+        210,
+        104,
+        48
+      ]
+    },
     {
       "name": "fastFillRedeemed",
       "discriminator": [
@@ -2817,6 +5869,19 @@ export type MatchingEngine = {
         117
       ]
     },
+    {
+      "name": "pauseUpdated",
+      "discriminator": [
+        203,
+        203,
+        33,
+        225,
+        130,
+        103,
+        90,
+        105
+      ]
+    },
     {
       "name": "proposed",
       "discriminator": [
@@ -2829,6 +5894,32 @@ export type MatchingEngine = {
         180,
         153
       ]
+    },
+    {
+      "name": "routerEndpointPauseUpdated",
+      "discriminator": [
+        53,
+        133,
+        3,
+        220,
+        107,
+        88,
+        153,
+        234
+      ]
+    },
+    {
+      "name": "routerEndpointRemoved",
+      "discriminator": [
+        77,
+        236,
+        214,
+        180,
+        209,
+        122,
+        22,
+        217
+      ]
     }
   ],
   "errors": [
@@ -2840,6 +5931,10 @@ export type MatchingEngine = {
       "code": 6004,
       "name": "ownerOrAssistantOnly"
     },
+    {
+      "code": 6006,
+      "name": "ownerAssistantOrRoleOnly"
+    },
     {
       "code": 6016,
       "name": "u64Overflow"
@@ -2888,6 +5983,18 @@ export type MatchingEngine = {
       "code": 6078,
       "name": "redeemerMessageTooLarge"
     },
+    {
+      "code": 6080,
+      "name": "invalidMint"
+    },
+    {
+      "code": 6082,
+      "name": "unsupportedMint"
+    },
+    {
+      "code": 6084,
+      "name": "mintMismatch"
+    },
     {
       "code": 6096,
       "name": "invalidSourceRouter"
@@ -2904,10 +6011,70 @@ export type MatchingEngine = {
       "code": 6102,
       "name": "invalidCctpEndpoint"
     },
+    {
+      "code": 6104,
+      "name": "endpointPaused"
+    },
+    {
+      "code": 6106,
+      "name": "endpointThroughputExceeded"
+    },
+    {
+      "code": 6108,
+      "name": "invalidInboundLimit"
+    },
+    {
+      "code": 6110,
+      "name": "routerEndpointRegistryFull"
+    },
+    {
+      "code": 6112,
+      "name": "routerEndpointNotDisabled"
+    },
+    {
+      "code": 6114,
+      "name": "routerEndpointRemovalDelayNotElapsed"
+    },
+    {
+      "code": 6116,
+      "name": "invalidPreviousAddressExpiry"
+    },
+    {
+      "code": 6118,
+      "name": "invalidEndpointLimits"
+    },
+    {
+      "code": 6120,
+      "name": "invalidTokenBridgeEndpoint"
+    },
+    {
+      "code": 6122,
+      "name": "invalidNttEndpoint"
+    },
+    {
+      "code": 6124,
+      "name": "inboundNotionalRequired"
+    },
     {
       "code": 6128,
       "name": "paused"
     },
+    {
+      "code": 6130,
+      "name": "invalidUnpauseSlot"
+    },
+    {
+      "code": 6132,
+      "name": "circuitBreakerTripped"
+    },
+    {
+      "code": 6134,
+      "name": "invalidCircuitBreakerThreshold"
+    },
+    {
+      "code": 6136,
+      "name": "circuitBreakerRequired"
+    },
     {
       "code": 6256,
       "name": "assistantZeroPubkey"
@@ -2920,219 +6087,1384 @@ export type MatchingEngine = {
       "code": 6258,
       "name": "immutableProgram"
     },
+    {
+      "code": 6259,
+      "name": "roleHolderZeroPubkey"
+    },
     {
       "code": 6260,
       "name": "zeroDuration"
     },
     {
-      "code": 6262,
-      "name": "zeroGracePeriod"
+      "code": 6262,
+      "name": "zeroGracePeriod"
+    },
+    {
+      "code": 6263,
+      "name": "zeroPenaltyPeriod"
+    },
+    {
+      "code": 6264,
+      "name": "userPenaltyRewardBpsTooLarge",
+      "msg": "Value exceeds 1000000"
+    },
+    {
+      "code": 6266,
+      "name": "initialPenaltyBpsTooLarge",
+      "msg": "Value exceeds 1000000"
+    },
+    {
+      "code": 6268,
+      "name": "minOfferDeltaBpsTooLarge",
+      "msg": "Value exceeds 1000000"
+    },
+    {
+      "code": 6270,
+      "name": "zeroSecurityDepositBase"
+    },
+    {
+      "code": 6271,
+      "name": "securityDepositBpsTooLarge",
+      "msg": "Value exceeds 1000000"
+    },
+    {
+      "code": 6514,
+      "name": "invalidNewOwner"
+    },
+    {
+      "code": 6516,
+      "name": "alreadyOwner"
+    },
+    {
+      "code": 6518,
+      "name": "noTransferOwnershipRequest"
+    },
+    {
+      "code": 6520,
+      "name": "notPendingOwner"
+    },
+    {
+      "code": 6524,
+      "name": "invalidChain"
+    },
+    {
+      "code": 6526,
+      "name": "ownershipTransferRequestExpired"
+    },
+    {
+      "code": 6528,
+      "name": "invalidOwnerSet"
+    },
+    {
+      "code": 6530,
+      "name": "ownerSetMemberOnly"
+    },
+    {
+      "code": 6532,
+      "name": "ownerSetTransactionAlreadyApproved"
+    },
+    {
+      "code": 6534,
+      "name": "ownerSetThresholdNotMet"
+    },
+    {
+      "code": 6536,
+      "name": "invalidOwnerSetTransaction"
+    },
+    {
+      "code": 6544,
+      "name": "roleAlreadyGranted"
+    },
+    {
+      "code": 6546,
+      "name": "roleNotGranted"
+    },
+    {
+      "code": 6548,
+      "name": "noPendingOwnerAssistant"
+    },
+    {
+      "code": 6550,
+      "name": "notPendingOwnerAssistant"
+    },
+    {
+      "code": 6576,
+      "name": "chainNotAllowed"
+    },
+    {
+      "code": 6578,
+      "name": "invalidMintRecipient"
+    },
+    {
+      "code": 6768,
+      "name": "proposalAlreadyEnacted"
+    },
+    {
+      "code": 6770,
+      "name": "proposalDelayNotExpired"
+    },
+    {
+      "code": 6772,
+      "name": "invalidProposal"
+    },
+    {
+      "code": 6832,
+      "name": "auctionConfigMismatch"
+    },
+    {
+      "code": 7024,
+      "name": "fastMarketOrderExpired"
+    },
+    {
+      "code": 7026,
+      "name": "offerPriceTooHigh"
+    },
+    {
+      "code": 7032,
+      "name": "auctionNotActive"
+    },
+    {
+      "code": 7034,
+      "name": "auctionPeriodExpired"
+    },
+    {
+      "code": 7036,
+      "name": "auctionPeriodNotExpired"
+    },
+    {
+      "code": 7044,
+      "name": "executorTokenMismatch"
+    },
+    {
+      "code": 7050,
+      "name": "auctionNotCompleted"
+    },
+    {
+      "code": 7054,
+      "name": "carpingNotAllowed"
+    },
+    {
+      "code": 7056,
+      "name": "auctionNotSettled"
+    },
+    {
+      "code": 7058,
+      "name": "executorNotPreparedBy"
+    },
+    {
+      "code": 7060,
+      "name": "invalidOfferToken"
+    },
+    {
+      "code": 7062,
+      "name": "fastFillTooLarge"
+    },
+    {
+      "code": 7064,
+      "name": "auctionExists"
+    },
+    {
+      "code": 7065,
+      "name": "noAuction"
+    },
+    {
+      "code": 7066,
+      "name": "bestOfferTokenMismatch"
+    },
+    {
+      "code": 7068,
+      "name": "bestOfferTokenRequired"
+    },
+    {
+      "code": 7070,
+      "name": "preparedByMismatch"
+    },
+    {
+      "code": 7071,
+      "name": "preparedOrderResponseNotRequired"
     },
     {
-      "code": 6263,
-      "name": "zeroPenaltyPeriod"
+      "code": 7072,
+      "name": "auctionConfigNotRequired"
     },
     {
-      "code": 6264,
-      "name": "userPenaltyRewardBpsTooLarge",
-      "msg": "Value exceeds 1000000"
+      "code": 7073,
+      "name": "bestOfferTokenNotRequired"
     },
     {
-      "code": 6266,
-      "name": "initialPenaltyBpsTooLarge",
-      "msg": "Value exceeds 1000000"
+      "code": 7076,
+      "name": "fastFillAlreadyRedeemed"
     },
     {
-      "code": 6268,
-      "name": "minOfferDeltaBpsTooLarge",
-      "msg": "Value exceeds 1000000"
+      "code": 7077,
+      "name": "fastFillNotRedeemed"
     },
     {
-      "code": 6270,
-      "name": "zeroSecurityDepositBase"
+      "code": 7080,
+      "name": "reservedSequenceMismatch"
     },
     {
-      "code": 6271,
-      "name": "securityDepositBpsTooLarge",
-      "msg": "Value exceeds 1000000"
+      "code": 7082,
+      "name": "auctionAlreadySettled"
     },
     {
-      "code": 6514,
-      "name": "invalidNewOwner"
+      "code": 7084,
+      "name": "invalidBaseFeeToken"
     },
     {
-      "code": 6516,
-      "name": "alreadyOwner"
+      "code": 7086,
+      "name": "baseFeeTokenRequired"
     },
     {
-      "code": 6518,
-      "name": "noTransferOwnershipRequest"
+      "code": 7088,
+      "name": "orderAmountBelowMinimum"
     },
     {
-      "code": 6520,
-      "name": "notPendingOwner"
+      "code": 7090,
+      "name": "orderAmountAboveMaximum"
     },
     {
-      "code": 6524,
-      "name": "invalidChain"
+      "code": 7092,
+      "name": "invalidAmountOut"
     },
     {
-      "code": 6576,
-      "name": "chainNotAllowed"
+      "code": 7094,
+      "name": "invalidDestinationAsset"
     },
     {
-      "code": 6578,
-      "name": "invalidMintRecipient"
+      "code": 7096,
+      "name": "fastFillCustodyTokenRequired"
     },
     {
-      "code": 6768,
-      "name": "proposalAlreadyEnacted"
+      "code": 7098,
+      "name": "gasDropoffTooLarge"
     },
     {
-      "code": 6770,
-      "name": "proposalDelayNotExpired"
+      "code": 7100,
+      "name": "gasDropoffRecipientRequired"
     },
     {
-      "code": 6772,
-      "name": "invalidProposal"
+      "code": 7102,
+      "name": "fastFillNotExpired"
     },
     {
-      "code": 6832,
-      "name": "auctionConfigMismatch"
+      "code": 7104,
+      "name": "fastFillEscrowTokenRequired"
     },
     {
-      "code": 7024,
-      "name": "fastMarketOrderExpired"
+      "code": 7106,
+      "name": "amountOutTooLow"
     },
     {
-      "code": 7026,
-      "name": "offerPriceTooHigh"
+      "code": 7108,
+      "name": "userRewardTokenRequired"
     },
     {
-      "code": 7032,
-      "name": "auctionNotActive"
+      "code": 7110,
+      "name": "invalidGasDropoffFeeToken"
     },
     {
-      "code": 7034,
-      "name": "auctionPeriodExpired"
+      "code": 7280,
+      "name": "cannotCloseAuctionYet"
     },
     {
-      "code": 7036,
-      "name": "auctionPeriodNotExpired"
+      "code": 7282,
+      "name": "auctionHistoryNotFull"
     },
     {
-      "code": 7044,
-      "name": "executorTokenMismatch"
+      "code": 7284,
+      "name": "auctionHistoryFull"
+    }
+  ],
+  "types": [
+    {
+      "name": "addCctpRouterEndpointArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chain",
+            "type": "u16"
+          },
+          {
+            "name": "cctpDomain",
+            "type": "u32"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mintRecipient",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7050,
-      "name": "auctionNotCompleted"
+      "name": "addNttRouterEndpointArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chain",
+            "type": "u16"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mintRecipient",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "manager",
+            "type": "pubkey"
+          },
+          {
+            "name": "transceiver",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 7054,
-      "name": "carpingNotAllowed"
+      "name": "addTokenBridgeRouterEndpointArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chain",
+            "type": "u16"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mintRecipient",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7056,
-      "name": "auctionNotSettled"
+      "name": "auction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vaaHash",
+            "docs": [
+              "VAA hash of the auction."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "vaaTimestamp",
+            "docs": [
+              "Timestamp of the fast market order VAA."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "targetProtocol",
+            "docs": [
+              "Transfer protocol used to move assets."
+            ],
+            "type": {
+              "defined": {
+                "name": "messageProtocol"
+              }
+            }
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Auction status."
+            ],
+            "type": {
+              "defined": {
+                "name": "auctionStatus"
+              }
+            }
+          },
+          {
+            "name": "preparedBy",
+            "docs": [
+              "The fee payer when placing the initial offer."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "info",
+            "docs": [
+              "Optional auction info. This field will be `None`` if there is no auction."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "auctionInfo"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auction",
+            "type": {
+              "defined": {
+                "name": "auction"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7058,
-      "name": "executorNotPreparedBy"
+      "name": "auctionConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "docs": [
+              "Monotonically increasing identifier for auction configs."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "parameters",
+            "docs": [
+              "Auction parameters, which are validated by [crate::utils::auction::require_valid_parameters]."
+            ],
+            "type": {
+              "defined": {
+                "name": "auctionParameters"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7060,
-      "name": "invalidOfferToken"
+      "name": "auctionDestinationAssetInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "custodyTokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "amountOut",
+            "docs": [
+              "The amount of the destination asset offered by the highest bidder."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the destination asset."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bestOfferToken",
+            "docs": [
+              "The highest bidder's destination asset token account, which is refunded if the offer is",
+              "improved."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 7062,
-      "name": "fastFillTooLarge"
+      "name": "auctionInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "configId",
+            "type": "u32"
+          },
+          {
+            "name": "custodyTokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "vaaSequence",
+            "docs": [
+              "Sequence of the fast market order VAA."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sourceChain",
+            "docs": [
+              "The chain where the transfer is initiated."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bestOfferToken",
+            "docs": [
+              "The highest bidder of the auction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "initialOfferToken",
+            "docs": [
+              "The initial bidder of the auction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "startSlot",
+            "docs": [
+              "The slot when the auction started."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amountIn",
+            "docs": [
+              "The amount reflecting the amount of assets transferred into the matching engine. This plus",
+              "and the security deposit are used to participate in the auction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "securityDeposit",
+            "docs": [
+              "The additional deposit made by the highest bidder.",
+              "",
+              "NOTE: This may not be the same denomination as the `amount_in`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "offerPrice",
+            "docs": [
+              "The offer price of the auction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "redeemerMessageLen",
+            "docs": [
+              "Length of the redeemer message, which may impact the expense to execute the auction."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "destinationAssetInfo",
+            "docs": [
+              "If the destination asset is not equal to the asset used for auctions, this will be some",
+              "value specifying its custody token bump and amount out. Participants bid on the amount out",
+              "of this asset instead of an offer price, and the fast fill is delivered in this asset. The",
+              "highest bidder is still repaid in the auction's mint when the auction is settled."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "auctionDestinationAssetInfo"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7064,
-      "name": "auctionExists"
+      "name": "auctionParameters",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "userPenaltyRewardBps",
+            "type": "u32"
+          },
+          {
+            "name": "initialPenaltyBps",
+            "type": "u32"
+          },
+          {
+            "name": "duration",
+            "type": "u16"
+          },
+          {
+            "name": "gracePeriod",
+            "docs": [
+              "* The grace period of the auction in slots. This is the number of slots the highest bidder\n     * has to execute the fast order before incurring a penalty. About 15 seconds on Avalanche.\n     * This value INCLUDES the `_auctionDuration`."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "penaltyPeriod",
+            "type": "u16"
+          },
+          {
+            "name": "minOfferDeltaBps",
+            "type": "u32"
+          },
+          {
+            "name": "securityDepositBase",
+            "docs": [
+              "The base security deposit, which will the the additional amount an auction participant must",
+              "deposit to participate in an auction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "securityDepositBps",
+            "docs": [
+              "Additional security deposit based on the notional of the order amount."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 7065,
-      "name": "noAuction"
+      "name": "auctionSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fastVaaHash",
+            "docs": [
+              "The pubkey of the auction that was settled."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "bestOfferToken",
+            "docs": [
+              "If there was an active auction, this field will have the pubkey of the best offer token that",
+              "was paid back and its balance after repayment."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "settledTokenAccountInfo"
+                }
+              }
+            }
+          },
+          {
+            "name": "baseFeeToken",
+            "docs": [
+              "Depending on whether there was an active auction, this field will have the pubkey of the",
+              "base fee token account (if there was an auction) or fee recipient token (if there was no",
+              "auction)."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "settledTokenAccountInfo"
+                }
+              }
+            }
+          },
+          {
+            "name": "withExecute",
+            "docs": [
+              "This value will only be some if there was no active auction."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "messageProtocol"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7066,
-      "name": "bestOfferTokenMismatch"
+      "name": "auctionStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "notStarted"
+          },
+          {
+            "name": "active"
+          },
+          {
+            "name": "completed",
+            "fields": [
+              {
+                "name": "slot",
+                "type": "u64"
+              },
+              {
+                "name": "executePenalty",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "settled",
+            "fields": [
+              {
+                "name": "fee",
+                "type": "u64"
+              },
+              {
+                "name": "totalPenalty",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 7068,
-      "name": "bestOfferTokenRequired"
+      "name": "auctionUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "configId",
+            "type": "u32"
+          },
+          {
+            "name": "fastVaaHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "vaa",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "sourceChain",
+            "type": "u16"
+          },
+          {
+            "name": "targetProtocol",
+            "type": {
+              "defined": {
+                "name": "messageProtocol"
+              }
+            }
+          },
+          {
+            "name": "redeemerMessageLen",
+            "type": "u16"
+          },
+          {
+            "name": "endSlot",
+            "type": "u64"
+          },
+          {
+            "name": "bestOfferToken",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenBalanceBefore",
+            "type": "u64"
+          },
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "totalDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "maxOfferPriceAllowed",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "destinationAssetInfo",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "auctionDestinationAssetInfo"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7070,
-      "name": "preparedByMismatch"
+      "name": "cctpMessageArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "encodedCctpMessage",
+            "type": "bytes"
+          },
+          {
+            "name": "cctpAttestation",
+            "type": "bytes"
+          }
+        ]
+      }
     },
     {
-      "code": 7071,
-      "name": "preparedOrderResponseNotRequired"
+      "name": "circuitBreaker",
+      "docs": [
+        "Notional amount of inbound fast orders across all source chains accumulated by the circuit",
+        "breaker, whose configuration is stored in the [Custodian](super::Custodian).",
+        "",
+        "NOTE: The per source chain accumulators are stored in each [InboundNotional](super::InboundNotional)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "global",
+            "type": {
+              "defined": {
+                "name": "notionalAccumulator"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7072,
-      "name": "auctionConfigNotRequired"
+      "name": "circuitBreakerConfig",
+      "docs": [
+        "Caps the notional amount of inbound fast orders, both globally and per source chain. Once",
+        "either threshold is exceeded, new auctions are refused until the window rolls over."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "windowSlots",
+            "docs": [
+              "Number of slots in each window. Zero disables the circuit breaker."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "globalThreshold",
+            "docs": [
+              "Notional amount across all source chains, which trips the breaker when exceeded."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "chainThreshold",
+            "docs": [
+              "Notional amount from any single source chain, which trips the breaker when exceeded."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 7073,
-      "name": "bestOfferTokenNotRequired"
+      "name": "circuitBreakerTripped",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceChain",
+            "type": "u16"
+          },
+          {
+            "name": "globalNotional",
+            "type": "u64"
+          },
+          {
+            "name": "chainNotional",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 7076,
-      "name": "fastFillAlreadyRedeemed"
+      "name": "custodian",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "Program's owner."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingOwner",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Boolean indicating whether inbound auctions are paused."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "pausedSetBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "ownerAssistant",
+            "docs": [
+              "Program's assistant."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRecipientToken",
+            "type": "pubkey"
+          },
+          {
+            "name": "auctionConfigId",
+            "type": "u32"
+          },
+          {
+            "name": "nextProposalId",
+            "type": "u64"
+          },
+          {
+            "name": "pendingOwnerExpirySlot",
+            "docs": [
+              "Slot after which the pending owner can no longer confirm the ownership transfer request."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingOwnerAssistant",
+            "docs": [
+              "Proposed assistant, who must accept the role before replacing the current assistant."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "pauseFlags",
+            "docs": [
+              "Pause flags for each stage of order processing. These are independent of [paused], which",
+              "only pauses new auctions.",
+              "",
+              "[paused]: Self::paused"
+            ],
+            "type": {
+              "defined": {
+                "name": "pauseFlags"
+              }
+            }
+          },
+          {
+            "name": "circuitBreaker",
+            "docs": [
+              "Circuit breaker thresholds for inbound fast orders. The notional amounts accumulated by the",
+              "circuit breaker are stored in the [CircuitBreaker](super::CircuitBreaker) and each",
+              "[InboundNotional](super::InboundNotional) account."
+            ],
+            "type": {
+              "defined": {
+                "name": "circuitBreakerConfig"
+              }
+            }
+          },
+          {
+            "name": "routerEndpointRemovalDelay",
+            "docs": [
+              "Number of slots a router endpoint must be disabled for before it can be removed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxGasDropoff",
+            "docs": [
+              "Maximum gas drop-off (in lamports) a fast market order delivered to Solana can request.",
+              "Gas drop-offs are disabled when this value is zero."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 7077,
-      "name": "fastFillNotRedeemed"
+      "name": "enacted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "proposalAction"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7080,
-      "name": "reservedSequenceMismatch"
+      "name": "endpointInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chain",
+            "docs": [
+              "Emitter chain. Cannot equal `1` (Solana's Chain ID)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "address",
+            "docs": [
+              "Emitter address. Cannot be zero address."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mintRecipient",
+            "docs": [
+              "Future-proof field in case another network has token accounts to send assets to instead of",
+              "sending to the address directly."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "protocol",
+            "docs": [
+              "Specific message protocol used to move assets."
+            ],
+            "type": {
+              "defined": {
+                "name": "messageProtocol"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7082,
-      "name": "auctionAlreadySettled"
+      "name": "endpointLimits",
+      "docs": [
+        "Optional limits on orders destined for an endpoint."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxRedeemerMessageLen",
+            "docs": [
+              "Max redeemer message length. This limit can only be more restrictive than the max enforced",
+              "by the Token Router."
+            ],
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "minOrderAmount",
+            "docs": [
+              "Minimum order amount."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxOrderAmount",
+            "docs": [
+              "Maximum order amount."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7084,
-      "name": "invalidBaseFeeToken"
+      "name": "fastFill",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seeds",
+            "type": {
+              "defined": {
+                "name": "fastFillSeeds"
+              }
+            }
+          },
+          {
+            "name": "redeemed",
+            "docs": [
+              "Whether the [FastFill] has been redeemed via the local Token Router."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "info",
+            "type": {
+              "defined": {
+                "name": "fastFillInfo"
+              }
+            }
+          },
+          {
+            "name": "redeemerMessage",
+            "type": "bytes"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the fill amount."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "relayerFee",
+            "docs": [
+              "If nonzero, the order sender opted into having the fill automatically delivered to the",
+              "redeemer's associated token account by a relayer, who earns this fee."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "ownsCustodyToken",
+            "docs": [
+              "Whether the fill amount is held in a token account owned by the [FastFill] instead of the",
+              "local custody token account, which is the case for fills delivered in a destination asset."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 7086,
-      "name": "baseFeeTokenRequired"
+      "name": "fastFillInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "preparedBy",
+            "docs": [
+              "Who paid the lamports to create the [FastFill] account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Fill amount."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "redeemer",
+            "docs": [
+              "Authority allowed to redeem [FastFill]."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Timestamp at the time a fill was issued. When the fast fill is created, it is set using the",
+              "current [Clock] unix timestamp."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 7280,
-      "name": "cannotCloseAuctionYet"
+      "name": "fastFillReclaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "preparedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "fastFill",
+            "type": {
+              "defined": {
+                "name": "fastFillSeeds"
+              }
+            }
+          },
+          {
+            "name": "recipientToken",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 7282,
-      "name": "auctionHistoryNotFull"
+      "name": "fastFillRedeemed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "preparedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "fastFill",
+            "type": {
+              "defined": {
+                "name": "fastFillSeeds"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 7284,
-      "name": "auctionHistoryFull"
-    }
-  ],
-  "types": [
-    {
-      "name": "addCctpRouterEndpointArgs",
+      "name": "fastFillSeeds",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "chain",
+            "name": "sourceChain",
+            "docs": [
+              "Wormhole chain ID reflecting where the order was created."
+            ],
             "type": "u16"
           },
           {
-            "name": "cctpDomain",
-            "type": "u32"
+            "name": "orderSender",
+            "docs": [
+              "Universal address of the order sender."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "address",
+            "name": "sequence",
+            "docs": [
+              "Sequence generated by the [FastFillSequencer](crate::state::FastFillSequencer) when it",
+              "reserved a sequence number for this fill."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for the [FastFill] account."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fastFillSequenceReserved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fastVaaHash",
             "type": {
               "array": [
                 "u8",
@@ -3141,13 +7473,10 @@ export type MatchingEngine = {
             }
           },
           {
-            "name": "mintRecipient",
+            "name": "fastFill",
             "type": {
-              "option": {
-                "array": [
-                  "u8",
-                  32
-                ]
+              "defined": {
+                "name": "fastFillSeeds"
               }
             }
           }
@@ -3155,19 +7484,36 @@ export type MatchingEngine = {
       }
     },
     {
-      "name": "auction",
+      "name": "fastFillSequencer",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "seeds",
+            "type": {
+              "defined": {
+                "name": "fastFillSequencerSeeds"
+              }
+            }
           },
           {
-            "name": "vaaHash",
-            "docs": [
-              "VAA hash of the auction."
-            ],
+            "name": "nextSequence",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "fastFillSequencerSeeds",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceChain",
+            "type": "u16"
+          },
+          {
+            "name": "sender",
             "type": {
               "array": [
                 "u8",
@@ -3176,554 +7522,675 @@ export type MatchingEngine = {
             }
           },
           {
-            "name": "vaaTimestamp",
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboundLimit",
+      "docs": [
+        "Cap on the notional amount of fast orders accepted from an endpoint within fixed windows of",
+        "slots. The accepted amount is tracked by the endpoint's [InboundNotional](super::InboundNotional)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "windowSlots",
             "docs": [
-              "Timestamp of the fast market order VAA."
+              "Number of slots in each window. Zero means there is no limit."
             ],
-            "type": "u32"
+            "type": "u64"
           },
           {
-            "name": "targetProtocol",
+            "name": "maxNotional",
             "docs": [
-              "Transfer protocol used to move assets."
+              "Maximum notional amount accepted within a window."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboundNotional",
+      "docs": [
+        "Notional amounts of fast orders accepted from a router endpoint. These are kept separate from",
+        "the [RouterEndpoint](super::RouterEndpoint) so that the endpoint is only read when starting",
+        "auctions, and this account is only written to when a limit applies to the endpoint."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "inboundLimit",
+            "docs": [
+              "Notional amount accepted within the endpoint's current inbound limit window."
             ],
             "type": {
               "defined": {
-                "name": "messageProtocol"
+                "name": "notionalAccumulator"
               }
             }
           },
           {
-            "name": "status",
+            "name": "circuitBreaker",
             "docs": [
-              "Auction status."
+              "Notional amount accumulated by the circuit breaker for the endpoint's chain."
             ],
             "type": {
               "defined": {
-                "name": "auctionStatus"
+                "name": "notionalAccumulator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "initializeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionParams",
+            "type": {
+              "defined": {
+                "name": "auctionParameters"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "localFastOrderFilled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seeds",
+            "type": {
+              "defined": {
+                "name": "fastFillSeeds"
               }
             }
           },
           {
-            "name": "preparedBy",
+            "name": "info",
+            "type": {
+              "defined": {
+                "name": "fastFillInfo"
+              }
+            }
+          },
+          {
+            "name": "auction",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "mint",
             "docs": [
-              "The fee payer when placing the initial offer."
+              "Mint of the fill amount."
             ],
             "type": "pubkey"
           },
           {
-            "name": "info",
+            "name": "relayerFee",
             "docs": [
-              "Optional auction info. This field will be `None`` if there is no auction."
+              "Fee earned by a relayer who automatically delivers the fill. See",
+              "[FastFill::relayer_fee](crate::state::FastFill::relayer_fee)."
             ],
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "auctionInfo"
-                }
-              }
-            }
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "auctionClosed",
+      "name": "messageProtocol",
+      "docs": [
+        "Protocol used to transfer assets."
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "auction",
-            "type": {
-              "defined": {
-                "name": "auction"
+            "name": "none"
+          },
+          {
+            "name": "local",
+            "fields": [
+              {
+                "name": "programId",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "cctp",
+            "fields": [
+              {
+                "name": "domain",
+                "docs": [
+                  "CCTP domain, which is how CCTP registers identifies foreign networks."
+                ],
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "tokenBridge"
+          },
+          {
+            "name": "ntt",
+            "fields": [
+              {
+                "name": "manager",
+                "docs": [
+                  "NTT manager program, which custodies or burns the transferred tokens."
+                ],
+                "type": "pubkey"
+              },
+              {
+                "name": "transceiver",
+                "docs": [
+                  "Transceiver that releases the NTT manager's outbound transfers."
+                ],
+                "type": "pubkey"
               }
-            }
+            ]
           }
         ]
       }
     },
     {
-      "name": "auctionConfig",
+      "name": "notionalAccumulator",
+      "docs": [
+        "Notional amount accumulated within the current window of the [CircuitBreaker]."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "id",
+            "name": "windowStartSlot",
             "docs": [
-              "Monotonically increasing identifier for auction configs."
+              "Slot at which the current window started."
             ],
-            "type": "u32"
+            "type": "u64"
           },
           {
-            "name": "parameters",
+            "name": "notional",
             "docs": [
-              "Auction parameters, which are validated by [crate::utils::auction::require_valid_parameters]."
+              "Notional amount accumulated within the current window."
             ],
-            "type": {
-              "defined": {
-                "name": "auctionParameters"
-              }
-            }
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "auctionDestinationAssetInfo",
+      "name": "orderExecuted",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "custodyTokenBump",
-            "type": "u8"
+            "name": "fastVaaHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "amountOut",
-            "type": "u64"
+            "name": "vaa",
+            "type": "pubkey"
+          },
+          {
+            "name": "sourceChain",
+            "type": "u16"
+          },
+          {
+            "name": "targetProtocol",
+            "type": {
+              "defined": {
+                "name": "messageProtocol"
+              }
+            }
+          },
+          {
+            "name": "penalized",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "auctionInfo",
+      "name": "ownerSet",
+      "docs": [
+        "M-of-N set of members that can act as the program's owner. Once the [Custodian] owner is set to",
+        "this account's address, owner-only instructions can only be invoked by executing an approved",
+        "[OwnerSetTransaction].",
+        "",
+        "[Custodian]: crate::state::Custodian"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "configId",
-            "type": "u32"
-          },
-          {
-            "name": "custodyTokenBump",
+            "name": "bump",
             "type": "u8"
           },
           {
-            "name": "vaaSequence",
-            "docs": [
-              "Sequence of the fast market order VAA."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "sourceChain",
+            "name": "threshold",
             "docs": [
-              "The chain where the transfer is initiated."
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "bestOfferToken",
-            "docs": [
-              "The highest bidder of the auction."
+              "Number of member approvals required to execute a transaction."
             ],
-            "type": "pubkey"
+            "type": "u8"
           },
           {
-            "name": "initialOfferToken",
-            "docs": [
-              "The initial bidder of the auction."
-            ],
-            "type": "pubkey"
+            "name": "members",
+            "type": {
+              "vec": "pubkey"
+            }
           },
           {
-            "name": "startSlot",
-            "docs": [
-              "The slot when the auction started."
-            ],
+            "name": "nextTransactionId",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "ownerSetAccountMeta",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "amountIn",
-            "docs": [
-              "The amount reflecting the amount of assets transferred into the matching engine. This plus",
-              "and the security deposit are used to participate in the auction."
-            ],
-            "type": "u64"
+            "name": "pubkey",
+            "type": "pubkey"
           },
           {
-            "name": "securityDeposit",
-            "docs": [
-              "The additional deposit made by the highest bidder.",
-              "",
-              "NOTE: This may not be the same denomination as the `amount_in`."
-            ],
-            "type": "u64"
+            "name": "isSigner",
+            "type": "bool"
           },
           {
-            "name": "offerPrice",
-            "docs": [
-              "The offer price of the auction."
-            ],
-            "type": "u64"
-          },
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ownerSetArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "redeemerMessageLen",
+            "name": "threshold",
             "docs": [
-              "Length of the redeemer message, which may impact the expense to execute the auction."
+              "Number of member approvals required to execute an owner set transaction."
             ],
-            "type": "u16"
+            "type": "u8"
           },
           {
-            "name": "destinationAssetInfo",
+            "name": "members",
             "docs": [
-              "If the destination asset is not equal to the asset used for auctions, this will be some",
-              "value specifying its custody token bump and amount out.",
-              "",
-              "NOTE: Because this is an option, the `AuctionDestinationAssetInfo` having some definition while this",
-              "field is None will not impact future serialization because the option's serialized value is",
-              "zero. Only when there will be other assets will this struct's members have to be carefully",
-              "considered."
+              "Members of the owner set. There can be at most [OwnerSet::MAX_MEMBERS] members."
             ],
             "type": {
-              "option": {
-                "defined": {
-                  "name": "auctionDestinationAssetInfo"
-                }
-              }
+              "vec": "pubkey"
             }
           }
         ]
       }
     },
     {
-      "name": "auctionParameters",
+      "name": "ownerSetTransaction",
+      "docs": [
+        "Instruction for this program proposed by an [OwnerSet] member. Once enough members have",
+        "approved it, anyone can execute it, where the owner set signs for the instruction."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "userPenaltyRewardBps",
-            "type": "u32"
-          },
-          {
-            "name": "initialPenaltyBps",
-            "type": "u32"
+            "name": "id",
+            "type": "u64"
           },
           {
-            "name": "duration",
-            "type": "u16"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "gracePeriod",
+            "name": "proposedBy",
             "docs": [
-              "* The grace period of the auction in slots. This is the number of slots the highest bidder\n     * has to execute the fast order before incurring a penalty. About 15 seconds on Avalanche.\n     * This value INCLUDES the `_auctionDuration`."
+              "Member who proposed (and paid for) this transaction."
             ],
-            "type": "u16"
-          },
-          {
-            "name": "penaltyPeriod",
-            "type": "u16"
+            "type": "pubkey"
           },
           {
-            "name": "minOfferDeltaBps",
-            "type": "u32"
+            "name": "approvals",
+            "docs": [
+              "Members who have approved this transaction."
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
           },
           {
-            "name": "securityDepositBase",
+            "name": "accounts",
             "docs": [
-              "The base security deposit, which will the the additional amount an auction participant must",
-              "deposit to participate in an auction."
+              "Accounts expected by the instruction, which must be passed as remaining accounts when this",
+              "transaction is executed."
             ],
-            "type": "u64"
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "ownerSetAccountMeta"
+                }
+              }
+            }
           },
           {
-            "name": "securityDepositBps",
+            "name": "data",
             "docs": [
-              "Additional security deposit based on the notional of the order amount."
+              "Encoded instruction data (including the Anchor discriminator)."
             ],
-            "type": "u32"
+            "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "auctionSettled",
+      "name": "ownerSetTransactionArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "fastVaaHash",
-            "docs": [
-              "The pubkey of the auction that was settled."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "bestOfferToken",
-            "docs": [
-              "If there was an active auction, this field will have the pubkey of the best offer token that",
-              "was paid back and its balance after repayment."
-            ],
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "settledTokenAccountInfo"
-                }
-              }
-            }
-          },
-          {
-            "name": "baseFeeToken",
+            "name": "accounts",
             "docs": [
-              "Depending on whether there was an active auction, this field will have the pubkey of the",
-              "base fee token account (if there was an auction) or fee recipient token (if there was no",
-              "auction)."
+              "Accounts of the instruction to be invoked by the owner set."
             ],
             "type": {
-              "option": {
+              "vec": {
                 "defined": {
-                  "name": "settledTokenAccountInfo"
+                  "name": "ownerSetAccountMeta"
                 }
               }
             }
           },
           {
-            "name": "withExecute",
+            "name": "data",
             "docs": [
-              "This value will only be some if there was no active auction."
+              "Instruction data, which must begin with an instruction discriminator for this program."
             ],
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "messageProtocol"
-                }
-              }
-            }
+            "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "auctionStatus",
+      "name": "pauseFlag",
       "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "notStarted"
-          },
+        "kind": "struct",
+        "fields": [
           {
-            "name": "active"
+            "name": "paused",
+            "type": "bool"
           },
           {
-            "name": "completed",
-            "fields": [
-              {
-                "name": "slot",
-                "type": "u64"
-              },
-              {
-                "name": "executePenalty",
-                "type": {
-                  "option": "u64"
-                }
-              }
-            ]
+            "name": "unpauseSlot",
+            "docs": [
+              "Slot at which the pause is lifted automatically. If none, the pause is in effect until it is",
+              "explicitly lifted."
+            ],
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "settled",
-            "fields": [
-              {
-                "name": "fee",
-                "type": "u64"
-              },
-              {
-                "name": "totalPenalty",
-                "type": {
-                  "option": "u64"
-                }
-              }
-            ]
+            "name": "reason",
+            "docs": [
+              "Arbitrary code describing why this flag was set, which is meant for off-chain reporting."
+            ],
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "auctionUpdated",
+      "name": "pauseFlags",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "configId",
-            "type": "u32"
+            "name": "newAuctions",
+            "type": {
+              "defined": {
+                "name": "pauseFlag"
+              }
+            }
           },
           {
-            "name": "fastVaaHash",
+            "name": "offerImprovement",
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": {
+                "name": "pauseFlag"
+              }
             }
           },
           {
-            "name": "vaa",
+            "name": "orderExecution",
             "type": {
-              "option": "pubkey"
+              "defined": {
+                "name": "pauseFlag"
+              }
             }
           },
           {
-            "name": "sourceChain",
-            "type": "u16"
+            "name": "fastFillCreation",
+            "type": {
+              "defined": {
+                "name": "pauseFlag"
+              }
+            }
           },
           {
-            "name": "targetProtocol",
+            "name": "settlement",
             "type": {
               "defined": {
-                "name": "messageProtocol"
+                "name": "pauseFlag"
               }
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseTarget",
+      "docs": [
+        "Stages of order processing that can be paused independently."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "redeemerMessageLen",
-            "type": "u16"
+            "name": "newAuctions"
           },
           {
-            "name": "endSlot",
-            "type": "u64"
+            "name": "offerImprovement"
           },
           {
-            "name": "bestOfferToken",
-            "type": "pubkey"
+            "name": "orderExecution"
           },
           {
-            "name": "tokenBalanceBefore",
-            "type": "u64"
+            "name": "fastFillCreation"
           },
           {
-            "name": "amountIn",
-            "type": "u64"
-          },
+            "name": "settlement"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "totalDeposit",
-            "type": "u64"
+            "name": "target",
+            "type": {
+              "defined": {
+                "name": "pauseTarget"
+              }
+            }
           },
           {
-            "name": "maxOfferPriceAllowed",
+            "name": "flag",
             "type": {
-              "option": "u64"
+              "defined": {
+                "name": "pauseFlag"
+              }
             }
+          },
+          {
+            "name": "setBy",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "cctpMessageArgs",
+      "name": "preparedOrderResponse",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "encodedCctpMessage",
-            "type": "bytes"
+            "name": "seeds",
+            "type": {
+              "defined": {
+                "name": "preparedOrderResponseSeeds"
+              }
+            }
           },
           {
-            "name": "cctpAttestation",
+            "name": "info",
+            "type": {
+              "defined": {
+                "name": "preparedOrderResponseInfo"
+              }
+            }
+          },
+          {
+            "name": "toEndpoint",
+            "type": {
+              "defined": {
+                "name": "endpointInfo"
+              }
+            }
+          },
+          {
+            "name": "redeemerMessage",
             "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "custodian",
+      "name": "preparedOrderResponseInfo",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
-            "docs": [
-              "Program's owner."
-            ],
+            "name": "preparedBy",
             "type": "pubkey"
           },
           {
-            "name": "pendingOwner",
-            "type": {
-              "option": "pubkey"
-            }
+            "name": "baseFeeToken",
+            "type": "pubkey"
           },
           {
-            "name": "paused",
-            "docs": [
-              "Boolean indicating whether inbound auctions are paused."
-            ],
-            "type": "bool"
+            "name": "fastVaaTimestamp",
+            "type": "u32"
           },
           {
-            "name": "pausedSetBy",
-            "type": "pubkey"
+            "name": "sourceChain",
+            "type": "u16"
           },
           {
-            "name": "ownerAssistant",
-            "docs": [
-              "Program's assistant."
-            ],
-            "type": "pubkey"
+            "name": "baseFee",
+            "type": "u64"
           },
           {
-            "name": "feeRecipientToken",
-            "type": "pubkey"
+            "name": "initAuctionFee",
+            "type": "u64"
           },
           {
-            "name": "auctionConfigId",
-            "type": "u32"
+            "name": "sender",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "nextProposalId",
+            "name": "redeemer",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "amountIn",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "enacted",
+      "name": "preparedOrderResponseSeeds",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "action",
+            "name": "fastVaaHash",
             "type": {
-              "defined": {
-                "name": "proposalAction"
-              }
+              "array": [
+                "u8",
+                32
+              ]
             }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "endpointInfo",
+      "name": "previousAddress",
+      "docs": [
+        "Emitter address replaced by the most recent update, which is still accepted as a source of",
+        "messages until the expiry slot."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "chain",
-            "docs": [
-              "Emitter chain. Cannot equal `1` (Solana's Chain ID)."
-            ],
-            "type": "u16"
-          },
           {
             "name": "address",
-            "docs": [
-              "Emitter address. Cannot be zero address."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "mintRecipient",
-            "docs": [
-              "Future-proof field in case another network has token accounts to send assets to instead of",
-              "sending to the address directly."
-            ],
             "type": {
               "array": [
                 "u8",
@@ -3732,105 +8199,96 @@ export type MatchingEngine = {
             }
           },
           {
-            "name": "protocol",
-            "docs": [
-              "Specific message protocol used to move assets."
-            ],
-            "type": {
-              "defined": {
-                "name": "messageProtocol"
-              }
-            }
+            "name": "expirySlot",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "fastFill",
+      "name": "proposal",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "seeds",
-            "type": {
-              "defined": {
-                "name": "fastFillSeeds"
-              }
-            }
+            "name": "id",
+            "type": "u64"
           },
           {
-            "name": "redeemed",
-            "docs": [
-              "Whether the [FastFill] has been redeemed via the local Token Router."
-            ],
-            "type": "bool"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "info",
+            "name": "action",
             "type": {
               "defined": {
-                "name": "fastFillInfo"
+                "name": "proposalAction"
               }
             }
           },
           {
-            "name": "redeemerMessage",
-            "type": "bytes"
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "slotProposedAt",
+            "type": "u64"
+          },
+          {
+            "name": "slotEnactDelay",
+            "type": "u64"
+          },
+          {
+            "name": "slotEnactedAt",
+            "type": {
+              "option": "u64"
+            }
           }
         ]
       }
     },
     {
-      "name": "fastFillInfo",
+      "name": "proposalAction",
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "preparedBy",
-            "docs": [
-              "Who paid the lamports to create the [FastFill] account."
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "amount",
-            "docs": [
-              "Fill amount."
-            ],
-            "type": "u64"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "redeemer",
-            "docs": [
-              "Authority allowed to redeem [FastFill]."
-            ],
-            "type": "pubkey"
+            "name": "none"
           },
           {
-            "name": "timestamp",
-            "docs": [
-              "Timestamp at the time a fill was issued. When the fast fill is created, it is set using the",
-              "current [Clock] unix timestamp."
-            ],
-            "type": "i64"
+            "name": "updateAuctionParameters",
+            "fields": [
+              {
+                "name": "id",
+                "type": "u32"
+              },
+              {
+                "name": "parameters",
+                "type": {
+                  "defined": {
+                    "name": "auctionParameters"
+                  }
+                }
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "fastFillRedeemed",
+      "name": "proposed",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "preparedBy",
-            "type": "pubkey"
-          },
-          {
-            "name": "fastFill",
+            "name": "action",
             "type": {
               "defined": {
-                "name": "fastFillSeeds"
+                "name": "proposalAction"
               }
             }
           }
@@ -3838,74 +8296,48 @@ export type MatchingEngine = {
       }
     },
     {
-      "name": "fastFillSeeds",
+      "name": "registeredRouterEndpoint",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "sourceChain",
-            "docs": [
-              "Wormhole chain ID reflecting where the order was created."
-            ],
+            "name": "chain",
             "type": "u16"
           },
           {
-            "name": "orderSender",
-            "docs": [
-              "Universal address of the order sender."
-            ],
+            "name": "protocol",
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": {
+                "name": "messageProtocol"
+              }
             }
-          },
-          {
-            "name": "sequence",
-            "docs": [
-              "Sequence generated by the [FastFillSequencer](crate::state::FastFillSequencer) when it",
-              "reserved a sequence number for this fill."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "docs": [
-              "Bump seed for the [FastFill] account."
-            ],
-            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "fastFillSequenceReserved",
+      "name": "remoteTokenMessenger",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "fastVaaHash",
+            "name": "domain",
+            "type": "u32"
+          },
+          {
+            "name": "tokenMessenger",
             "type": {
               "array": [
                 "u8",
                 32
               ]
             }
-          },
-          {
-            "name": "fastFill",
-            "type": {
-              "defined": {
-                "name": "fastFillSeeds"
-              }
-            }
           }
         ]
       }
     },
     {
-      "name": "fastFillSequencer",
+      "name": "reservedFastFillSequence",
       "type": {
         "kind": "struct",
         "fields": [
@@ -3913,28 +8345,32 @@ export type MatchingEngine = {
             "name": "seeds",
             "type": {
               "defined": {
-                "name": "fastFillSequencerSeeds"
+                "name": "reservedFastFillSequenceSeeds"
               }
             }
           },
           {
-            "name": "nextSequence",
-            "type": "u64"
+            "name": "beneficiary",
+            "type": "pubkey"
+          },
+          {
+            "name": "fastFillSeeds",
+            "type": {
+              "defined": {
+                "name": "fastFillSeeds"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "fastFillSequencerSeeds",
+      "name": "reservedFastFillSequenceSeeds",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "sourceChain",
-            "type": "u16"
-          },
-          {
-            "name": "sender",
+            "name": "fastVaaHash",
             "type": {
               "array": [
                 "u8",
@@ -3950,15 +8386,49 @@ export type MatchingEngine = {
       }
     },
     {
-      "name": "initializeArgs",
+      "name": "role",
+      "docs": [
+        "Roles that can be granted in addition to the owner and owner assistant. Each role only grants",
+        "access to the instructions relevant to it."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pauser"
+          },
+          {
+            "name": "endpointManager"
+          },
+          {
+            "name": "feeManager"
+          }
+        ]
+      }
+    },
+    {
+      "name": "roleHolder",
+      "docs": [
+        "Roles granted to a holder by the owner. See [Role] for what each role is permitted to do."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "auctionParams",
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "roles",
             "type": {
-              "defined": {
-                "name": "auctionParameters"
+              "vec": {
+                "defined": {
+                  "name": "role"
+                }
               }
             }
           }
@@ -3966,413 +8436,385 @@ export type MatchingEngine = {
       }
     },
     {
-      "name": "localFastOrderFilled",
+      "name": "routerEndpoint",
+      "docs": [
+        "Foreign emitter account data."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "seeds",
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "info",
             "type": {
               "defined": {
-                "name": "fastFillSeeds"
+                "name": "endpointInfo"
               }
             }
           },
           {
-            "name": "info",
+            "name": "pause",
+            "docs": [
+              "Temporarily blocks orders to and from this endpoint without disabling it."
+            ],
             "type": {
               "defined": {
-                "name": "fastFillInfo"
+                "name": "pauseFlag"
               }
             }
           },
           {
-            "name": "auction",
+            "name": "inboundLimit",
+            "docs": [
+              "Cap on the notional amount of fast orders accepted from this endpoint."
+            ],
             "type": {
-              "option": "pubkey"
+              "defined": {
+                "name": "inboundLimit"
+              }
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "messageProtocol",
-      "docs": [
-        "Protocol used to transfer assets."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
+          },
           {
-            "name": "none"
+            "name": "disabledSlot",
+            "docs": [
+              "Slot at which this endpoint was disabled. This endpoint can be removed once the router",
+              "endpoint removal delay has elapsed since this slot."
+            ],
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "local",
-            "fields": [
-              {
-                "name": "programId",
-                "type": "pubkey"
+            "name": "previousAddress",
+            "docs": [
+              "Previous emitter address, which is accepted during a migration to a new address."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "previousAddress"
+                }
               }
-            ]
+            }
           },
           {
-            "name": "cctp",
-            "fields": [
-              {
-                "name": "domain",
-                "docs": [
-                  "CCTP domain, which is how CCTP registers identifies foreign networks."
-                ],
-                "type": "u32"
+            "name": "limits",
+            "docs": [
+              "Limits on orders destined for this endpoint."
+            ],
+            "type": {
+              "defined": {
+                "name": "endpointLimits"
               }
-            ]
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the asset transferred by orders from this endpoint, which is either USDC or a",
+              "[SupportedMint](super::SupportedMint). This mint is set when the endpoint is added and",
+              "cannot be changed by updating the endpoint."
+            ],
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "orderExecuted",
+      "name": "routerEndpointPauseUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "fastVaaHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "vaa",
-            "type": "pubkey"
-          },
-          {
-            "name": "sourceChain",
+            "name": "chain",
             "type": "u16"
           },
           {
-            "name": "targetProtocol",
+            "name": "flag",
             "type": {
               "defined": {
-                "name": "messageProtocol"
+                "name": "pauseFlag"
               }
             }
           },
           {
-            "name": "penalized",
-            "type": "bool"
+            "name": "setBy",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "preparedOrderResponse",
+      "name": "routerEndpointRegistry",
+      "docs": [
+        "Lists every configured router endpoint, ordered by chain ID, so that routes can be enumerated",
+        "without scanning program accounts."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "seeds",
-            "type": {
-              "defined": {
-                "name": "preparedOrderResponseSeeds"
-              }
-            }
-          },
-          {
-            "name": "info",
-            "type": {
-              "defined": {
-                "name": "preparedOrderResponseInfo"
-              }
-            }
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "toEndpoint",
+            "name": "endpoints",
             "type": {
-              "defined": {
-                "name": "endpointInfo"
+              "vec": {
+                "defined": {
+                  "name": "registeredRouterEndpoint"
+                }
               }
             }
-          },
-          {
-            "name": "redeemerMessage",
-            "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "preparedOrderResponseInfo",
+      "name": "routerEndpointRemoved",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "preparedBy",
-            "type": "pubkey"
-          },
-          {
-            "name": "baseFeeToken",
-            "type": "pubkey"
-          },
-          {
-            "name": "fastVaaTimestamp",
-            "type": "u32"
-          },
-          {
-            "name": "sourceChain",
+            "name": "chain",
             "type": "u16"
           },
           {
-            "name": "baseFee",
-            "type": "u64"
-          },
+            "name": "removedBy",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "setRouterEndpointInboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "initAuctionFee",
+            "name": "windowSlots",
+            "docs": [
+              "Number of slots in each window. Zero removes the limit."
+            ],
             "type": "u64"
           },
           {
-            "name": "sender",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "redeemer",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "amountIn",
+            "name": "maxNotional",
+            "docs": [
+              "Maximum notional amount of fast orders accepted from this endpoint within a window."
+            ],
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "preparedOrderResponseSeeds",
+      "name": "setRouterEndpointPauseArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "fastVaaHash",
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "unpauseSlot",
+            "docs": [
+              "Slot at which the pause is lifted automatically. This must be in the future and can only be",
+              "specified when pausing."
+            ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "option": "u64"
             }
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "reason",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "proposal",
+      "name": "setStagePauseArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "id",
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "action",
+            "name": "target",
             "type": {
               "defined": {
-                "name": "proposalAction"
+                "name": "pauseTarget"
               }
             }
           },
           {
-            "name": "by",
-            "type": "pubkey"
-          },
-          {
-            "name": "owner",
-            "type": "pubkey"
-          },
-          {
-            "name": "slotProposedAt",
-            "type": "u64"
-          },
-          {
-            "name": "slotEnactDelay",
-            "type": "u64"
+            "name": "paused",
+            "type": "bool"
           },
           {
-            "name": "slotEnactedAt",
+            "name": "unpauseSlot",
+            "docs": [
+              "Slot at which the pause is lifted automatically. This must be in the future and can only be",
+              "specified when pausing."
+            ],
             "type": {
               "option": "u64"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "proposalAction",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "none"
           },
           {
-            "name": "updateAuctionParameters",
-            "fields": [
-              {
-                "name": "id",
-                "type": "u32"
-              },
-              {
-                "name": "parameters",
-                "type": {
-                  "defined": {
-                    "name": "auctionParameters"
-                  }
-                }
-              }
-            ]
+            "name": "reason",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "proposed",
+      "name": "settledTokenAccountInfo",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "action",
-            "type": {
-              "defined": {
-                "name": "proposalAction"
-              }
-            }
+            "name": "key",
+            "type": "pubkey"
+          },
+          {
+            "name": "balanceAfter",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "remoteTokenMessenger",
+      "name": "supportedMint",
+      "docs": [
+        "Mint, other than USDC, whose orders can be auctioned and settled. USDC is always supported, so",
+        "it never has one of these accounts."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "domain",
-            "type": "u32"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "tokenMessenger",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRecipientToken",
+            "docs": [
+              "Token account (whose mint is this mint) that collects fees from orders settled without an",
+              "auction."
+            ],
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "reservedFastFillSequence",
+      "name": "updateCctpRouterEndpointArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "seeds",
+            "name": "endpoint",
             "type": {
               "defined": {
-                "name": "reservedFastFillSequenceSeeds"
+                "name": "addCctpRouterEndpointArgs"
               }
             }
           },
           {
-            "name": "beneficiary",
-            "type": "pubkey"
-          },
-          {
-            "name": "fastFillSeeds",
+            "name": "previousAddressExpirySlot",
+            "docs": [
+              "If the address changes, the previous address is still accepted as a source of messages",
+              "until this slot, which must be in the future. Otherwise the previous address is no longer",
+              "accepted."
+            ],
             "type": {
-              "defined": {
-                "name": "fastFillSeeds"
-              }
+              "option": "u64"
             }
           }
         ]
       }
     },
     {
-      "name": "reservedFastFillSequenceSeeds",
+      "name": "updateCircuitBreakerArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "fastVaaHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "windowSlots",
+            "docs": [
+              "Number of slots in each window. Zero disables the circuit breaker."
+            ],
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "globalThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "chainThreshold",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "routerEndpoint",
-      "docs": [
-        "Foreign emitter account data."
-      ],
+      "name": "updateNttRouterEndpointArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "info",
+            "name": "endpoint",
             "type": {
               "defined": {
-                "name": "endpointInfo"
+                "name": "addNttRouterEndpointArgs"
               }
             }
+          },
+          {
+            "name": "previousAddressExpirySlot",
+            "docs": [
+              "If the address changes, the previous address is still accepted as a source of messages",
+              "until this slot, which must be in the future. Otherwise the previous address is no longer",
+              "accepted."
+            ],
+            "type": {
+              "option": "u64"
+            }
           }
         ]
       }
     },
     {
-      "name": "settledTokenAccountInfo",
+      "name": "updateTokenBridgeRouterEndpointArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "key",
-            "type": "pubkey"
+            "name": "endpoint",
+            "type": {
+              "defined": {
+                "name": "addTokenBridgeRouterEndpointArgs"
+              }
+            }
           },
           {
-            "name": "balanceAfter",
-            "type": "u64"
+            "name": "previousAddressExpirySlot",
+            "docs": [
+              "If the address changes, the previous address is still accepted as a source of messages",
+              "until this slot, which must be in the future. Otherwise the previous address is no longer",
+              "accepted."
+            ],
+            "type": {
+              "option": "u64"
+            }
           }
         ]
       }
@@ -14,6 +14,167 @@ export type TokenRouter = {
     "repository": "https://github.com/wormhole-foundation/example-liquidity-layer"
   },
   "instructions": [
+    {
+      "name": "acceptOwnerAssistant",
+      "docs": [
+        "This instruction is used to accept the owner assistant role, replacing the `owner_assistant`",
+        "with the `pending_owner_assistant` in the `Custodian` account. This instruction can only be",
+        "called by the `pending_owner_assistant`.",
+        "# Arguments",
+        "",
+        "* `ctx` - `AcceptOwnerAssistant` context."
+      ],
+      "discriminator": [
+        172,
+        99,
+        207,
+        235,
+        246,
+        32,
+        232,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "pendingOwnerAssistant",
+          "docs": [
+            "Must be the pending owner assistant set in the [Custodian] account."
+          ],
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addSwapProgram",
+      "docs": [
+        "This instruction is used to allow prepared fills to be swapped with a swap program using",
+        "`redeem_and_swap`. This instruction can only be called by the `owner`.",
+        "# Arguments",
+        "",
+        "* `ctx` - `AddSwapProgram` context."
+      ],
+      "discriminator": [
+        221,
+        234,
+        75,
+        24,
+        107,
+        165,
+        114,
+        39
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "swapProgram",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveOwnerSetTransaction",
+      "docs": [
+        "This instruction is used by an owner set member to approve a proposed owner set",
+        "transaction.",
+        "# Arguments",
+        "",
+        "* `ctx` - `ApproveOwnerSetTransaction` context."
+      ],
+      "discriminator": [
+        15,
+        246,
+        135,
+        33,
+        204,
+        184,
+        231,
+        11
+      ],
+      "accounts": [
+        {
+          "name": "ownerSetMember",
+          "accounts": [
+            {
+              "name": "member",
+              "signer": true
+            },
+            {
+              "name": "ownerSet"
+            }
+          ]
+        },
+        {
+          "name": "transaction",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelOwnerSetTransaction",
+      "docs": [
+        "This instruction is used to close a proposed owner set transaction without executing it.",
+        "This instruction can only be called by the member who proposed the transaction.",
+        "# Arguments",
+        "",
+        "* `ctx` - `CancelOwnerSetTransaction` context."
+      ],
+      "discriminator": [
+        223,
+        188,
+        106,
+        36,
+        89,
+        97,
+        65,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "proposedBy",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "transaction",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
     {
       "name": "cancelOwnershipTransferRequest",
       "docs": [
@@ -119,7 +280,8 @@ export type TokenRouter = {
       "docs": [
         "This instruction confirms the ownership transfer request and sets the new `owner` in the",
         "`Custodian` account. This instruction can only be called by the `pending_owner`. The",
-        "`pending_owner` must be the same as the `pending_owner` in the `Custodian` account.",
+        "`pending_owner` must be the same as the `pending_owner` in the `Custodian` account, and the",
+        "request must not have expired.",
         "# Arguments",
         "",
         "* `ctx` - `ConfirmOwnershipTransferRequest` context."
@@ -217,108 +379,222 @@ export type TokenRouter = {
       "args": []
     },
     {
-      "name": "initialize",
+      "name": "consumePreparedFillPartial",
       "docs": [
-        "This instruction is be used to generate the program's `custodian` and `auction_config`",
-        "configs. It saves the `payer` as the `owner`. Finally, it sets the upgrade",
-        "authority to the `upgrade_manager_authority`. Upgrades are managed by the `upgrade_manager_program`.",
+        "This instruction is used to consume part of a `prepared_fill` account. The `amount` is",
+        "transferred from the `prepared_custody_token` account to the `dst_token` account, whose",
+        "remaining balance is the amount left to consume. Once the `prepared_custody_token` account",
+        "is drained, both it and the `prepared_fill` are closed. The consumed and remaining amounts are",
+        "emitted in a `PreparedFillConsumed` event. This instruction can only be called by the",
+        "`redeemer` that is saved in the `prepared_fill`.",
         "# Arguments",
         "",
-        "* `ctx`            - `Initialize` context."
+        "* `ctx`    - `ConsumePreparedFillPartial` context.",
+        "* `amount` - Amount to transfer to the `dst_token` account."
       ],
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        173,
+        207,
+        27,
+        134,
+        116,
+        231,
+        194,
+        143
       ],
       "accounts": [
         {
-          "name": "owner",
+          "name": "redeemer",
           "docs": [
-            "Owner of the program, who presumably deployed this program."
+            "This signer must be the same one encoded in the prepared fill."
           ],
-          "writable": true,
           "signer": true
         },
         {
-          "name": "custodian",
+          "name": "beneficiary",
           "docs": [
-            "Sender Config account, which saves program data useful for other",
-            "instructions, specifically for outbound transfers. Also saves the payer",
-            "of the [`initialize`](crate::initialize) instruction as the program's",
-            "owner."
+            "the payer). The lamports of the prepared fill and its custody token account are only sent",
+            "to this account when the custody token account is drained."
           ],
           "writable": true
         },
         {
-          "name": "ownerAssistant"
+          "name": "preparedFill",
+          "docs": [
+            "This account will be closed once the custody token account is drained."
+          ],
+          "writable": true
         },
         {
-          "name": "cctpMintRecipient",
+          "name": "dstToken",
+          "docs": [
+            "Destination token account, which the redeemer may not own. The redeemer may have the tokens",
+            "be sent to any account he chooses (this one).",
+            ""
+          ],
           "writable": true
         },
         {
-          "name": "mint",
-          "accounts": [
-            {
-              "name": "mint"
-            }
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Custody token account. This account will be closed once it is drained.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "deliverPreparedFill",
+      "docs": [
+        "This instruction is used to deliver a `prepared_fill` to a redeemer that is a program. The",
+        "tokens are transferred from the `prepared_custody_token` account to the redeemer program's",
+        "vault token account and the redeemer program's `on_liquidity_layer_fill` instruction is",
+        "called with the fill, all in this instruction. The `prepared_custody_token` and",
+        "`prepared_fill` accounts are closed. Any accounts the redeemer program needs are passed as",
+        "remaining accounts. This instruction can be called by anyone.",
+        "# Arguments",
+        "",
+        "* `ctx` - `DeliverPreparedFill` context."
+      ],
+      "discriminator": [
+        149,
+        21,
+        36,
+        64,
+        227,
+        38,
+        224,
+        249
+      ],
+      "accounts": [
+        {
+          "name": "callbackAuthority",
+          "docs": [
+            "Signs for the call into the redeemer program. This authority is only used for this purpose.",
+            ""
           ]
         },
         {
-          "name": "programData",
+          "name": "preparedBy",
           "docs": [
-            "We use the program data to make sure this owner is the upgrade authority (the true owner,",
-            "who deployed this program)."
+            "lamports of the closed accounts."
           ],
           "writable": true
         },
         {
-          "name": "upgradeManagerAuthority"
+          "name": "preparedFill",
+          "writable": true
         },
         {
-          "name": "upgradeManagerProgram"
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Custody token account. This account will be closed at the end of this instruction.",
+            ""
+          ],
+          "writable": true
         },
         {
-          "name": "bpfLoaderUpgradeableProgram"
+          "name": "redeemerProgram",
+          "docs": [
+            "Only a redeemer that is a program opts into having its fills delivered. Because a program",
+            "cannot sign for its own address, these fills can only be consumed this way.",
+            ""
+          ]
         },
         {
-          "name": "systemProgram"
+          "name": "redeemerVaultToken",
+          "docs": [
+            "Redeemer program's vault token account."
+          ],
+          "writable": true
         },
         {
           "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeOwnerSetTransaction",
+      "docs": [
+        "This instruction is used to execute an owner set transaction once the threshold of member",
+        "approvals has been met. The transaction's accounts must be passed as remaining accounts. The",
+        "transaction account is closed and its lamports are returned to the proposer. This",
+        "instruction can be called by anyone.",
+        "# Arguments",
+        "",
+        "* `ctx` - `ExecuteOwnerSetTransaction` context."
+      ],
+      "discriminator": [
+        128,
+        33,
+        61,
+        27,
+        160,
+        74,
+        159,
+        209
+      ],
+      "accounts": [
+        {
+          "name": "ownerSet"
         },
         {
-          "name": "associatedTokenProgram"
+          "name": "proposedBy",
+          "writable": true
+        },
+        {
+          "name": "transaction",
+          "writable": true
+        },
+        {
+          "name": "tokenRouterProgram"
         }
       ],
       "args": []
     },
     {
-      "name": "migrate",
+      "name": "grantRole",
       "docs": [
-        "This instruction is used for executing logic during an upgrade. This instruction can only be",
-        "called by the `upgrade_manager_program`.",
+        "This instruction is used to grant a role to a holder, creating the holder's `RoleHolder`",
+        "account if it does not exist. This instruction can only be called by the `owner`.",
         "# Arguments",
         "",
-        "* `ctx` - `Migrate` context."
+        "* `ctx`  - `GrantRole` context.",
+        "* `role` - The `Role` to grant."
       ],
       "discriminator": [
-        155,
+        218,
         234,
-        231,
-        146,
+        128,
+        15,
+        82,
+        33,
         236,
-        158,
-        162,
-        30
+        253
       ],
       "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "admin",
           "accounts": [
@@ -335,41 +611,270 @@ export type TokenRouter = {
               ]
             }
           ]
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "placeMarketOrderCctp",
-      "docs": [
-        "This instruction is used to place a `MarketOrder`. This order type transfers tokens",
-        "from Solana to another registered Token Router endpoint on a different chain. This",
-        "instruction requires a `prepared_market_order` account to be present. Note: this",
-        "is the only order type on the Solana Token Router currently, and does not pass",
-        "through the matching engine.",
+        },
+        {
+          "name": "holder",
+          "docs": [
+            "Role holder.",
+            ""
+          ]
+        },
+        {
+          "name": "roleHolder",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "role"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initialize",
+      "docs": [
+        "This instruction is be used to generate the program's `custodian` and `auction_config`",
+        "configs. It saves the `payer` as the `owner`. Finally, it sets the upgrade",
+        "authority to the `upgrade_manager_authority`. Upgrades are managed by the `upgrade_manager_program`.",
         "# Arguments",
         "",
-        "* `ctx` - `PlaceMarketOrder` context."
+        "* `ctx`            - `Initialize` context."
+      ],
+      "discriminator": [
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "Owner of the program, who presumably deployed this program."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "docs": [
+            "Sender Config account, which saves program data useful for other",
+            "instructions, specifically for outbound transfers. Also saves the payer",
+            "of the [`initialize`](crate::initialize) instruction as the program's",
+            "owner."
+          ],
+          "writable": true
+        },
+        {
+          "name": "ownerAssistant"
+        },
+        {
+          "name": "cctpMintRecipient",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            }
+          ]
+        },
+        {
+          "name": "programData",
+          "docs": [
+            "We use the program data to make sure this owner is the upgrade authority (the true owner,",
+            "who deployed this program)."
+          ],
+          "writable": true
+        },
+        {
+          "name": "upgradeManagerAuthority"
+        },
+        {
+          "name": "upgradeManagerProgram"
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeOwnerSet",
+      "docs": [
+        "This instruction is used to create the program's `OwnerSet` account, which holds M-of-N",
+        "members who can collectively act as the program's owner. The owner set only takes control",
+        "once ownership is transferred to the `OwnerSet` address. This instruction can only be called",
+        "by the `owner`.",
+        "# Arguments",
+        "",
+        "* `ctx`  - `InitializeOwnerSet` context.",
+        "* `args` - The `OwnerSetArgs`, which has the threshold and members."
       ],
       "discriminator": [
+        187,
         166,
-        53,
-        183,
-        130,
-        108,
-        24,
-        173,
-        152
+        73,
+        128,
+        44,
+        168,
+        208,
+        110
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "ownerSet",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "ownerSetArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrate",
+      "docs": [
+        "This instruction is used for executing logic during an upgrade. This instruction can only be",
+        "called by the `upgrade_manager_program`. If the `Custodian` account still has its legacy",
+        "layout, it is reallocated (with rent paid by the `payer`) and rewritten with new fields set to",
+        "their defaults.",
+        "# Arguments",
+        "",
+        "* `ctx` - `Migrate` context."
+      ],
+      "discriminator": [
+        155,
+        234,
+        231,
+        146,
+        236,
+        158,
+        162,
+        30
       ],
       "accounts": [
         {
           "name": "payer",
           "docs": [
-            "This account must be the same pubkey as the one who prepared the order."
+            "Pays for any additional rent required to grow the custodian account."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "Program's owner. This account is checked against the custodian's owner in the instruction",
+            "handler because the custodian may still have the layout of a previous program version."
+          ],
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "docs": [
+            "using either its legacy or current layout."
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "placeLimitOrderCctp",
+      "docs": [
+        "This instruction is used to place a limit order once its auction has completed at or below",
+        "its limit price. The caller (e.g. a keeper) collects `keeper_fee` from the amount in, which",
+        "cannot exceed the order's maximum keeper fee. The remaining amount cannot be less than the",
+        "order's minimum amount out.",
+        "# Arguments",
+        "",
+        "* `ctx`        - `PlaceLimitOrderCctp` context.",
+        "* `keeper_fee` - Fee deducted from the amount in and transferred to the `keeper_token`."
+      ],
+      "discriminator": [
+        153,
+        46,
+        197,
+        238,
+        82,
+        162,
+        250,
+        185
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Keeper who places the order. This account does not have to be the one who prepared the",
+            "order."
           ],
           "writable": true,
           "signer": true
         },
+        {
+          "name": "keeperToken",
+          "docs": [
+            "Token account that receives the keeper fee deducted from the amount in."
+          ],
+          "writable": true
+        },
         {
           "name": "preparedBy",
           "writable": true
@@ -384,8 +889,17 @@ export type TokenRouter = {
         },
         {
           "name": "preparedOrder",
+          "docs": [
+            "This account's limit order conditions are checked in the instruction handler."
+          ],
           "writable": true
         },
+        {
+          "name": "auction",
+          "docs": [
+            "Matching Engine auction whose outcome determines whether the limit order can be placed."
+          ]
+        },
         {
           "name": "mint",
           "docs": [
@@ -488,35 +1002,48 @@ export type TokenRouter = {
           "name": "rent"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "keeperFee",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "prepareMarketOrder",
+      "name": "placeMarketOrderCctp",
       "docs": [
-        "This instruction is used to prepare a `PrepareOrder` account for a market order. The `amount_in`",
-        "is transferred from the `source` account to the `prepared_custody_token` account. Anyone",
-        "can call this instruction.",
+        "This instruction is used to place a `MarketOrder`. This order type transfers tokens",
+        "from Solana to another registered Token Router endpoint on a different chain. This",
+        "instruction requires a `prepared_market_order` account to be present. Note: this",
+        "is the only order type on the Solana Token Router currently, and does not pass",
+        "through the matching engine.",
         "# Arguments",
         "",
-        "* `ctx` - `PrepareMarketOrder` context.",
-        "* `args` - `PreparedMarketOrderArgs` struct, see `prepare.rs` for more info."
+        "* `ctx` - `PlaceMarketOrder` context."
       ],
       "discriminator": [
-        19,
-        157,
-        161,
-        196,
-        88,
-        176,
-        70,
-        21
-      ],
-      "accounts": [
-        {
-          "name": "payer",
+        166,
+        53,
+        183,
+        130,
+        108,
+        24,
+        173,
+        152
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "This account must be the same pubkey as the one who prepared the order."
+          ],
           "writable": true,
           "signer": true
         },
+        {
+          "name": "preparedBy",
+          "writable": true
+        },
         {
           "name": "custodian",
           "accounts": [
@@ -526,120 +1053,159 @@ export type TokenRouter = {
           ]
         },
         {
-          "name": "programTransferAuthority",
-          "docs": [
-            "The auction participant needs to set approval to this PDA if the sender (signer) is not",
-            "provided. The delegated amount must equal the amount in or this instruction will revert.",
-            "",
-            "NOTE: If this account is provided, the sender token's owner will be encoded as the order",
-            "sender.",
-            ""
-          ],
-          "optional": true
-        },
-        {
-          "name": "sender",
+          "name": "preparedOrder",
           "docs": [
-            "Sender, who has the authority to transfer assets from the sender token account. If this",
-            "account is not provided, the program transfer authority account must be some account.",
-            "",
-            "NOTE: If this account is provided, this pubkey will be encoded as the order sender."
+            "This account's order type is checked in the instruction handler. Limit and scheduled orders",
+            "cannot be placed with this instruction."
           ],
-          "signer": true,
-          "optional": true
-        },
-        {
-          "name": "preparedOrder",
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "senderToken",
+          "name": "mint",
           "docs": [
-            "Token account where assets are burned from. The CCTP Token Messenger Minter program will",
-            "burn the configured [amount](TransferTokensWithPayloadArgs::amount) from this account.",
-            "",
-            "[burn_source_authority](Self::burn_source_authority). Its mint must be USDC.",
+            "Circle-supported mint.",
             "",
-            "NOTE: This token account must have delegated transfer authority to the custodian prior to",
-            "invoking this instruction."
+            "Token Messenger Minter program's local token account."
           ],
           "writable": true
         },
-        {
-          "name": "refundToken"
-        },
         {
           "name": "preparedCustodyToken",
           "docs": [
-            "Custody token account. This account will be closed at the end of this instruction. It just",
-            "acts as a conduit to allow this program to be the transfer initiator in the CCTP message.",
+            "Temporary custody token account. This account will be closed at the end of this instruction.",
+            "It just acts as a conduit to allow this program to be the transfer initiator in the CCTP",
+            "message.",
             ""
           ],
           "writable": true
         },
         {
-          "name": "usdc",
+          "name": "targetRouterEndpoint",
           "accounts": [
             {
-              "name": "mint"
+              "name": "endpoint"
             }
           ]
         },
         {
-          "name": "targetRouterEndpoint",
-          "accounts": [
-            {
-              "name": "endpoint"
-            }
+          "name": "coreBridgeConfig",
+          "writable": true
+        },
+        {
+          "name": "coreMessage",
+          "writable": true
+        },
+        {
+          "name": "cctpMessage",
+          "writable": true
+        },
+        {
+          "name": "coreEmitterSequence",
+          "writable": true
+        },
+        {
+          "name": "coreFeeCollector",
+          "writable": true
+        },
+        {
+          "name": "tokenMessengerMinterSenderAuthority"
+        },
+        {
+          "name": "messageTransmitterConfig",
+          "writable": true
+        },
+        {
+          "name": "tokenMessenger"
+        },
+        {
+          "name": "remoteTokenMessenger",
+          "docs": [
+            "Messenger Minter program)."
+          ]
+        },
+        {
+          "name": "tokenMinter",
+          "docs": [
+            "CHECK Seeds must be \\[\"token_minter\"\\] (CCTP Token Messenger Minter program)."
           ]
         },
+        {
+          "name": "localToken",
+          "docs": [
+            "Local token account, which this program uses to validate the `mint` used to burn.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenMessengerMinterEventAuthority"
+        },
+        {
+          "name": "coreBridgeProgram"
+        },
+        {
+          "name": "tokenMessengerMinterProgram"
+        },
+        {
+          "name": "messageTransmitterProgram"
+        },
         {
           "name": "tokenProgram"
         },
         {
           "name": "systemProgram"
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "prepareMarketOrderArgs"
-            }
-          }
+          "name": "clock"
+        },
+        {
+          "name": "rent"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "redeemCctpFill",
+      "name": "placeScheduledOrderSliceCctp",
       "docs": [
-        "This instruction is used to redeem a `Fill` VAA and redeem tokens from a CCTP transfer. After",
-        "the tokens are minted by the CCTP program, they are transferred to a token custody account.",
-        "The `prepared_fill` account is populated with information from the `Fill` vaa. This",
-        "This instruction only handles CCTP transfers.",
+        "This instruction is used to place the next slice of a scheduled order once it is due. Each",
+        "slice transfers tokens from Solana to another registered Token Router endpoint on a",
+        "different chain and rewards the caller with the order's keeper reward. The",
+        "`prepared_order` account is closed once the last slice is placed.",
         "# Arguments",
         "",
-        "* `ctx`  - `RedeemCctpFill` context.",
-        "* `args` - `CctpMessageArgs` struct, see `redeem_fill/cctp.rs` for more info."
+        "* `ctx` - `PlaceScheduledOrderSliceCctp` context."
       ],
       "discriminator": [
-        61,
-        85,
-        136,
-        127,
-        30,
-        118,
-        37,
-        126
+        202,
+        73,
+        200,
+        237,
+        72,
+        84,
+        57,
+        105
       ],
       "accounts": [
         {
           "name": "payer",
+          "docs": [
+            "Keeper who places the slice. This account does not have to be the one who prepared the",
+            "order."
+          ],
           "writable": true,
           "signer": true
         },
+        {
+          "name": "keeperToken",
+          "docs": [
+            "Token account that receives the keeper reward taken from the slice."
+          ],
+          "writable": true
+        },
+        {
+          "name": "preparedBy",
+          "writable": true
+        },
         {
           "name": "custodian",
           "accounts": [
@@ -649,151 +1215,145 @@ export type TokenRouter = {
           ]
         },
         {
-          "name": "fillVaa",
-          "accounts": [
-            {
-              "name": "vaa"
-            }
-          ]
+          "name": "preparedOrder",
+          "docs": [
+            "This account will be closed once the last slice is placed."
+          ],
+          "writable": true
         },
         {
-          "name": "preparedFill",
+          "name": "mint",
+          "docs": [
+            "Circle-supported mint.",
+            "",
+            "Token Messenger Minter program's local token account."
+          ],
           "writable": true
         },
         {
           "name": "preparedCustodyToken",
           "docs": [
-            "Mint recipient token account, which is encoded as the mint recipient in the CCTP message.",
-            "The CCTP Token Messenger Minter program will transfer the amount encoded in the CCTP message",
-            "from its custody account to this account.",
+            "Temporary custody token account. This account will be closed at the end of this instruction.",
+            "It just acts as a conduit to allow this program to be the transfer initiator in the CCTP",
+            "message.",
             ""
           ],
           "writable": true
         },
         {
-          "name": "usdc",
+          "name": "targetRouterEndpoint",
           "accounts": [
             {
-              "name": "mint"
+              "name": "endpoint"
             }
           ]
         },
         {
-          "name": "sourceRouterEndpoint",
-          "accounts": [
-            {
-              "name": "endpoint"
-            }
+          "name": "coreBridgeConfig",
+          "writable": true
+        },
+        {
+          "name": "coreMessage",
+          "docs": [
+            "remaining_slices.to_be_bytes()\\]."
+          ],
+          "writable": true
+        },
+        {
+          "name": "cctpMessage",
+          "docs": [
+            "remaining_slices.to_be_bytes()\\]."
+          ],
+          "writable": true
+        },
+        {
+          "name": "coreEmitterSequence",
+          "writable": true
+        },
+        {
+          "name": "coreFeeCollector",
+          "writable": true
+        },
+        {
+          "name": "tokenMessengerMinterSenderAuthority"
+        },
+        {
+          "name": "messageTransmitterConfig",
+          "writable": true
+        },
+        {
+          "name": "tokenMessenger"
+        },
+        {
+          "name": "remoteTokenMessenger",
+          "docs": [
+            "Messenger Minter program)."
           ]
         },
         {
-          "name": "cctp",
-          "accounts": [
-            {
-              "name": "mintRecipient",
-              "accounts": [
-                {
-                  "name": "mintRecipient",
-                  "writable": true
-                }
-              ]
-            },
-            {
-              "name": "messageTransmitterAuthority"
-            },
-            {
-              "name": "messageTransmitterConfig"
-            },
-            {
-              "name": "usedNonces",
-              "docs": [
-                "first_nonce.to_string()\\] (CCTP Message Transmitter program)."
-              ],
-              "writable": true
-            },
-            {
-              "name": "messageTransmitterEventAuthority"
-            },
-            {
-              "name": "tokenMessenger"
-            },
-            {
-              "name": "remoteTokenMessenger",
-              "docs": [
-                "Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "tokenMinter"
-            },
-            {
-              "name": "localToken",
-              "docs": [
-                "Token Messenger Minter's Local Token account. This program uses the mint of this account to",
-                "validate the `mint_recipient` token account's mint.",
-                ""
-              ],
-              "writable": true
-            },
-            {
-              "name": "tokenPair",
-              "docs": [
-                "Token Messenger Minter program)."
-              ]
-            },
-            {
-              "name": "tokenMessengerMinterCustodyToken",
-              "writable": true
-            },
-            {
-              "name": "tokenMessengerMinterEventAuthority"
-            },
-            {
-              "name": "tokenMessengerMinterProgram"
-            },
-            {
-              "name": "messageTransmitterProgram"
-            }
+          "name": "tokenMinter",
+          "docs": [
+            "CHECK Seeds must be \\[\"token_minter\"\\] (CCTP Token Messenger Minter program)."
           ]
         },
+        {
+          "name": "localToken",
+          "docs": [
+            "Local token account, which this program uses to validate the `mint` used to burn.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenMessengerMinterEventAuthority"
+        },
+        {
+          "name": "coreBridgeProgram"
+        },
+        {
+          "name": "tokenMessengerMinterProgram"
+        },
+        {
+          "name": "messageTransmitterProgram"
+        },
         {
           "name": "tokenProgram"
         },
         {
           "name": "systemProgram"
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "cctpMessageArgs"
-            }
-          }
+          "name": "clock"
+        },
+        {
+          "name": "rent"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "redeemFastFill",
+      "name": "prepareLimitOrder",
       "docs": [
-        "This instruction is used to redeem a `FastFill` VAA created by the matching engine. This instruction",
-        "performs a cpi call to the matching engine to complete the fast fill. The tokens transferred to the",
-        "`prepared_custody_token` account, and a `prepared_fill` account is created. This instruction only",
-        "handles fast fills.",
+        "This instruction is used to prepare a `PrepareOrder` account for a limit order. The",
+        "`amount_in` is transferred from the `source` account to the `prepared_custody_token` account",
+        "like a market order. But the order can only be placed (by anyone) with",
+        "`place_limit_order_cctp` if it has not expired and its Matching Engine `auction` has",
+        "completed with an offer price at or below `max_offer_price`. Anyone can call this",
+        "instruction.",
         "# Arguments",
         "",
-        "* `ctx` - `RedeemFastFill` context."
+        "* `ctx`  - `PrepareLimitOrder` context.",
+        "* `args` - `PrepareLimitOrderArgs` struct, see `limit_order/prepare.rs` for more info."
       ],
       "discriminator": [
-        11,
-        52,
-        181,
-        5,
-        101,
-        194,
-        200,
-        15
+        37,
+        129,
+        172,
+        240,
+        16,
+        104,
+        69,
+        218
       ],
       "accounts": [
         {
@@ -810,52 +1370,78 @@ export type TokenRouter = {
           ]
         },
         {
-          "name": "fastFill",
-          "writable": true
-        },
-        {
-          "name": "preparedFill",
-          "writable": true
-        },
-        {
-          "name": "preparedCustodyToken",
+          "name": "programTransferAuthority",
           "docs": [
-            "Mint recipient token account, which is encoded as the mint recipient in the CCTP message.",
-            "The CCTP Token Messenger Minter program will transfer the amount encoded in the CCTP message",
-            "from its custody account to this account.",
-            ""
+            "The auction participant needs to set approval to this PDA if the sender (signer) is not",
+            "provided. The delegated amount must equal the amount in or this instruction will revert.",
+            "",
+            "NOTE: If this account is provided, the sender token's owner will be encoded as the order",
+            "sender.",
+            "",
+            "refund_token.key()\\]."
           ],
-          "writable": true
+          "optional": true
         },
         {
-          "name": "usdc",
-          "accounts": [
-            {
-              "name": "mint"
-            }
-          ]
+          "name": "sender",
+          "docs": [
+            "Sender, who has the authority to transfer assets from the sender token account. If this",
+            "account is not provided, the program transfer authority account must be some account.",
+            "",
+            "NOTE: If this account is provided, this pubkey will be encoded as the order sender."
+          ],
+          "signer": true,
+          "optional": true
         },
         {
-          "name": "matchingEngineCustodian"
+          "name": "preparedOrder",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "matchingEngineFromEndpoint"
+          "name": "senderToken",
+          "docs": [
+            "Token account where the amount in is transferred from. This account must be owned by the",
+            "sender or have delegated authority to the program transfer authority."
+          ],
+          "writable": true
         },
         {
-          "name": "matchingEngineToEndpoint"
+          "name": "refundToken",
+          "docs": [
+            "Token account that the amount in is refunded to if the order is closed or expires."
+          ]
         },
         {
-          "name": "matchingEngineLocalCustodyToken",
+          "name": "preparedCustodyToken",
           "docs": [
-            "(Matching Engine program)."
+            "Custody token account, which holds the amount in until the order is placed or refunded.",
+            ""
           ],
           "writable": true
         },
         {
-          "name": "matchingEngineEventAuthority"
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
         },
         {
-          "name": "matchingEngineProgram"
+          "name": "targetRouterEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint"
+            }
+          ]
         },
         {
           "name": "tokenProgram"
@@ -864,185 +1450,1540 @@ export type TokenRouter = {
           "name": "systemProgram"
         }
       ],
-      "args": []
-    },
-    {
-      "name": "setPause",
-      "docs": [
-        "This instruction is used to pause or unpause further processing of new transfer. Only the `owner`",
-        "or `owner_assistant` can pause the program.",
-        "# Arguments",
-        "",
-        "* `ctx`   - `SetPause` context.",
-        "* `pause` - Boolean indicating whether to pause the program."
-      ],
-      "discriminator": [
-        63,
-        32,
-        154,
-        2,
-        56,
-        103,
-        79,
-        45
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "accounts": [
-            {
-              "name": "ownerOrAssistant",
-              "signer": true
-            },
-            {
-              "name": "custodian",
-              "writable": true
-            }
-          ]
-        }
-      ],
       "args": [
         {
-          "name": "paused",
-          "type": "bool"
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "prepareLimitOrderArgs"
+            }
+          }
         }
       ]
     },
     {
-      "name": "submitOwnershipTransferRequest",
+      "name": "prepareMarketOrder",
       "docs": [
-        "This instruction sets the `pending_owner` field in the `Custodian` account. This instruction",
-        "can only be called by the `owner`. The `pending_owner` address must be valid, meaning it",
-        "cannot be the zero address or the current owner.",
+        "This instruction is used to prepare a `PrepareOrder` account for a market order. The `amount_in`",
+        "is transferred from the `source` account to the `prepared_custody_token` account. Orders",
+        "prepared with this instruction do not expire and do not pay an integrator fee (see",
+        "`prepare_market_order_v2`). Anyone can call this instruction.",
         "# Arguments",
         "",
-        "* `ctx` - `SubmitOwnershipTransferRequest` context."
+        "* `ctx` - `PrepareMarketOrder` context.",
+        "* `args` - `PreparedMarketOrderArgs` struct, see `prepare.rs` for more info."
       ],
       "discriminator": [
-        215,
-        13,
-        88,
-        199,
-        48,
-        195,
         19,
-        225
+        157,
+        161,
+        196,
+        88,
+        176,
+        70,
+        21
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
           "accounts": [
             {
-              "name": "owner",
-              "signer": true
-            },
-            {
-              "name": "custodian",
-              "writable": true
+              "name": "custodian"
             }
           ]
         },
         {
-          "name": "newOwner",
+          "name": "programTransferAuthority",
           "docs": [
-            "New Owner.",
+            "The auction participant needs to set approval to this PDA if the sender (signer) is not",
+            "provided. The delegated amount must equal the amount in or this instruction will revert.",
+            "",
+            "NOTE: If this account is provided, the sender token's owner will be encoded as the order",
+            "sender.",
+            "",
+            "refund_token.key()\\]."
+          ],
+          "optional": true
+        },
+        {
+          "name": "sender",
+          "docs": [
+            "Sender, who has the authority to transfer assets from the sender token account. If this",
+            "account is not provided, the program transfer authority account must be some account.",
+            "",
+            "NOTE: If this account is provided, this pubkey will be encoded as the order sender."
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "preparedOrder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "senderToken",
+          "docs": [
+            "Token account where assets are burned from. The CCTP Token Messenger Minter program will",
+            "burn the configured [amount](TransferTokensWithPayloadArgs::amount) from this account.",
+            "",
+            "[burn_source_authority](Self::burn_source_authority). Its mint must be a supported mint.",
+            "",
+            "NOTE: This token account must have delegated transfer authority to the custodian prior to",
+            "invoking this instruction."
+          ],
+          "writable": true
+        },
+        {
+          "name": "refundToken"
+        },
+        {
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Custody token account. This account will be closed at the end of this instruction. It just",
+            "acts as a conduit to allow this program to be the transfer initiator in the CCTP message.",
             ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "targetRouterEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint"
+            }
           ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "prepareMarketOrderArgs"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "updateOwnerAssistant",
+      "name": "prepareMarketOrderV2",
       "docs": [
-        "This instruction is used to update the `owner_assistant` field in the `Custodian` account. This",
-        "instruction can only be called by the `owner`.",
+        "This instruction is used to prepare a `PrepareOrder` account for a market order like",
+        "`prepare_market_order`. But instead of being created from an arbitrary keypair, the",
+        "`prepared_order` account is a PDA derived from the order sender and the nonce tracked in",
+        "the order sender's `SenderState` account, which is incremented. The order may also be given",
+        "an expiry, after which anyone can refund it with `refund_expired_prepared_order`, and an",
+        "integrator fee, which is transferred to the `integrator_fee_token` account. Anyone can call",
+        "this instruction.",
         "# Arguments",
         "",
-        "* `ctx` - `UpdateOwnerAssistant` context."
+        "* `ctx`  - `PrepareMarketOrderV2` context.",
+        "* `args` - `PrepareMarketOrderV2Args` struct, see `prepare_v2.rs` for more info."
       ],
       "discriminator": [
-        153,
-        83,
-        175,
-        53,
-        168,
-        34,
         131,
-        22
+        189,
+        80,
+        19,
+        68,
+        38,
+        78,
+        245
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
           "accounts": [
             {
-              "name": "owner",
-              "signer": true
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "sender",
+          "docs": [
+            "Sender, who has the authority to transfer assets from the sender token account. If this",
+            "account is not provided, the program transfer authority account must be some account.",
+            "",
+            "NOTE: If this account is provided, this pubkey will be encoded as the order sender."
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "senderToken",
+          "docs": [
+            "Token account where the amount in is transferred from. This account must be owned by the",
+            "sender or have delegated authority to the program transfer authority."
+          ],
+          "writable": true
+        },
+        {
+          "name": "senderState",
+          "docs": [
+            "Order sender's state, which tracks the nonce of its next prepared order. This account is",
+            "created if it does not exist yet.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "preparedOrder",
+          "docs": [
+            "Prepared order, whose address is derived from the order sender and its next nonce.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "programTransferAuthority",
+          "docs": [
+            "The auction participant needs to set approval to this PDA if the sender (signer) is not",
+            "provided. The delegated amount must equal the amount in or this instruction will revert.",
+            "",
+            "NOTE: If this account is provided, the sender token's owner will be encoded as the order",
+            "sender.",
+            "",
+            "refund_token.key()\\]."
+          ],
+          "optional": true
+        },
+        {
+          "name": "refundToken",
+          "docs": [
+            "Token account that the amount in is refunded to if the order is closed or expires."
+          ]
+        },
+        {
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Custody token account, which holds the amount in until the order is placed or refunded.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
             },
             {
-              "name": "custodian",
-              "writable": true
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
             }
           ]
         },
         {
-          "name": "newOwnerAssistant",
+          "name": "integratorFeeToken",
           "docs": [
-            "New Assistant.",
-            ""
+            "Integrator's token account, which receives the integrator fee. This account is required if",
+            "an integrator fee is provided."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "targetRouterEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint"
+            }
           ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "eventAuthority"
+        },
+        {
+          "name": "program"
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "custodian",
-      "discriminator": [
-        132,
-        228,
-        139,
-        184,
-        112,
-        228,
-        108,
-        240
-      ]
-    },
-    {
-      "name": "fastFill",
-      "discriminator": [
-        89,
-        120,
-        166,
-        41,
-        106,
-        227,
-        218,
-        121
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "prepareMarketOrderV2Args"
+            }
+          }
+        }
       ]
     },
     {
-      "name": "preparedFill",
+      "name": "prepareScheduledOrder",
+      "docs": [
+        "This instruction is used to prepare a `PrepareOrder` account for a scheduled order. The",
+        "`amount_in` is transferred from the `source` account to the `prepared_custody_token` account",
+        "like a market order. But the order is placed in `num_slices` slices, one every",
+        "`slice_interval` slots, where anyone who places a slice is rewarded with `keeper_reward`.",
+        "Anyone can call this instruction.",
+        "# Arguments",
+        "",
+        "* `ctx`  - `PrepareScheduledOrder` context.",
+        "* `args` - `PrepareScheduledOrderArgs` struct, see `scheduled_order/prepare.rs` for more",
+        "info."
+      ],
       "discriminator": [
+        201,
+        82,
+        253,
+        224,
+        47,
+        94,
         202,
-        241,
-        65,
-        186,
-        110,
-        235,
-        238,
-        80
-      ]
-    },
-    {
-      "name": "preparedOrder",
-      "discriminator": [
-        3,
-        21,
-        13,
-        182,
+        44
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "programTransferAuthority",
+          "docs": [
+            "The auction participant needs to set approval to this PDA if the sender (signer) is not",
+            "provided. The delegated amount must equal the amount in or this instruction will revert.",
+            "",
+            "NOTE: If this account is provided, the sender token's owner will be encoded as the order",
+            "sender.",
+            "",
+            "refund_token.key()\\]."
+          ],
+          "optional": true
+        },
+        {
+          "name": "sender",
+          "docs": [
+            "Sender, who has the authority to transfer assets from the sender token account. If this",
+            "account is not provided, the program transfer authority account must be some account.",
+            "",
+            "NOTE: If this account is provided, this pubkey will be encoded as the order sender."
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "preparedOrder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "senderToken",
+          "docs": [
+            "Token account where the amount in is transferred from. This account must be owned by the",
+            "sender or have delegated authority to the program transfer authority."
+          ],
+          "writable": true
+        },
+        {
+          "name": "refundToken",
+          "docs": [
+            "Token account that the amount in is refunded to if the order is closed before its last",
+            "slice is placed."
+          ]
+        },
+        {
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Custody token account, which holds the amount in until the order is placed or refunded.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "targetRouterEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint"
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "prepareScheduledOrderArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeOwnerSetTransaction",
+      "docs": [
+        "This instruction is used by an owner set member to propose an instruction for this program,",
+        "which the owner set will sign for once enough members have approved it. The proposer's",
+        "approval is recorded.",
+        "# Arguments",
+        "",
+        "* `ctx`  - `ProposeOwnerSetTransaction` context.",
+        "* `args` - The `OwnerSetTransactionArgs`, which has the instruction's accounts and data."
+      ],
+      "discriminator": [
+        157,
+        40,
+        72,
+        86,
+        38,
+        35,
+        249,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "ownerSetMember",
+          "accounts": [
+            {
+              "name": "member",
+              "signer": true
+            },
+            {
+              "name": "ownerSet",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "transaction",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "ownerSetTransactionArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeemAndSwap",
+      "docs": [
+        "This instruction is used to consume a `prepared_fill` account by swapping its tokens with an",
+        "allowlisted swap program. The swap instruction's accounts are passed as remaining accounts,",
+        "and the `prepared_fill` signs for the `prepared_custody_token` account. The swap must",
+        "deliver at least `min_amount_out` to the redeemer's `dst_token` account and must consume all",
+        "of the `prepared_custody_token` account's tokens, which is then closed. This instruction can",
+        "only be called by the `redeemer` that is saved in the `prepared_fill`.",
+        "# Arguments",
+        "",
+        "* `ctx`  - `RedeemAndSwap` context.",
+        "* `args` - `RedeemAndSwapArgs` struct, see `redeem_and_swap.rs` for more info."
+      ],
+      "discriminator": [
+        73,
+        4,
+        177,
+        140,
+        86,
+        139,
+        143,
+        71
+      ],
+      "accounts": [
+        {
+          "name": "redeemer",
+          "docs": [
+            "This signer must be the same one encoded in the prepared fill."
+          ],
+          "signer": true
+        },
+        {
+          "name": "beneficiary",
+          "docs": [
+            "the payer). See [ConsumePreparedFill](crate::processor::ConsumePreparedFill)."
+          ],
+          "writable": true
+        },
+        {
+          "name": "preparedFill",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Custody token account, which the swap program transfers the prepared fill's tokens from.",
+            "This account will be closed at the end of this instruction.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "dstToken",
+          "docs": [
+            "Redeemer's token account, which receives the output token of the swap."
+          ],
+          "writable": true
+        },
+        {
+          "name": "swapProgram",
+          "docs": [
+            "Allowlisted swap program."
+          ]
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "redeemAndSwapArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeemCctpFill",
+      "docs": [
+        "This instruction is used to redeem a `Fill` VAA and redeem tokens from a CCTP transfer. After",
+        "the tokens are minted by the CCTP program, they are transferred to a token custody account.",
+        "The `prepared_fill` account is populated with information from the `Fill` vaa. This",
+        "This instruction only handles CCTP transfers.",
+        "# Arguments",
+        "",
+        "* `ctx`  - `RedeemCctpFill` context.",
+        "* `args` - `CctpMessageArgs` struct, see `redeem_fill/cctp.rs` for more info."
+      ],
+      "discriminator": [
+        61,
+        85,
+        136,
+        127,
+        30,
+        118,
+        37,
+        126
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "fillVaa",
+          "accounts": [
+            {
+              "name": "vaa"
+            }
+          ]
+        },
+        {
+          "name": "preparedFill",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Mint recipient token account, which is encoded as the mint recipient in the CCTP message.",
+            "The CCTP Token Messenger Minter program will transfer the amount encoded in the CCTP message",
+            "from its custody account to this account.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "sourceRouterEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint"
+            }
+          ]
+        },
+        {
+          "name": "cctp",
+          "accounts": [
+            {
+              "name": "mintRecipient",
+              "accounts": [
+                {
+                  "name": "mintRecipient",
+                  "writable": true
+                }
+              ]
+            },
+            {
+              "name": "messageTransmitterAuthority"
+            },
+            {
+              "name": "messageTransmitterConfig"
+            },
+            {
+              "name": "usedNonces",
+              "docs": [
+                "first_nonce.to_string()\\] (CCTP Message Transmitter program)."
+              ],
+              "writable": true
+            },
+            {
+              "name": "messageTransmitterEventAuthority"
+            },
+            {
+              "name": "tokenMessenger"
+            },
+            {
+              "name": "remoteTokenMessenger",
+              "docs": [
+                "Messenger Minter program)."
+              ]
+            },
+            {
+              "name": "tokenMinter"
+            },
+            {
+              "name": "localToken",
+              "docs": [
+                "Token Messenger Minter's Local Token account. This program uses the mint of this account to",
+                "validate the `mint_recipient` token account's mint.",
+                ""
+              ],
+              "writable": true
+            },
+            {
+              "name": "tokenPair",
+              "docs": [
+                "Token Messenger Minter program)."
+              ]
+            },
+            {
+              "name": "tokenMessengerMinterCustodyToken",
+              "writable": true
+            },
+            {
+              "name": "tokenMessengerMinterEventAuthority"
+            },
+            {
+              "name": "tokenMessengerMinterProgram"
+            },
+            {
+              "name": "messageTransmitterProgram"
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "cctpMessageArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeemFastFill",
+      "docs": [
+        "This instruction is used to redeem a `FastFill` VAA created by the matching engine. This instruction",
+        "performs a cpi call to the matching engine to complete the fast fill. The tokens transferred to the",
+        "`prepared_custody_token` account, and a `prepared_fill` account is created. This instruction only",
+        "handles fast fills.",
+        "# Arguments",
+        "",
+        "* `ctx` - `RedeemFastFill` context."
+      ],
+      "discriminator": [
+        11,
+        52,
+        181,
+        5,
+        101,
+        194,
+        200,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "fastFill",
+          "writable": true
+        },
+        {
+          "name": "preparedFill",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Mint recipient token account, which is encoded as the mint recipient in the CCTP message.",
+            "The CCTP Token Messenger Minter program will transfer the amount encoded in the CCTP message",
+            "from its custody account to this account.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint of the fast fill amount, which is USDC unless the fast fill was delivered in a",
+            "destination asset.",
+            ""
+          ]
+        },
+        {
+          "name": "matchingEngineCustodian"
+        },
+        {
+          "name": "matchingEngineFromEndpoint"
+        },
+        {
+          "name": "matchingEngineToEndpoint"
+        },
+        {
+          "name": "matchingEngineLocalCustodyToken",
+          "docs": [
+            "(Matching Engine program)."
+          ],
+          "writable": true
+        },
+        {
+          "name": "matchingEngineFastFillCustodyToken",
+          "docs": [
+            "required if the fast fill owns its custody token account."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "matchingEngineEventAuthority"
+        },
+        {
+          "name": "matchingEngineProgram"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeemTokenBridgeFill",
+      "docs": [
+        "This instruction is used to redeem a Token Bridge transfer whose message is a `Fill`. After",
+        "the tokens are released by the Token Bridge program, they are transferred to a token custody",
+        "account. The `prepared_fill` account is populated with information from the `Fill` message.",
+        "This instruction only handles Token Bridge transfers.",
+        "# Arguments",
+        "",
+        "* `ctx` - `RedeemTokenBridgeFill` context."
+      ],
+      "discriminator": [
+        8,
+        210,
+        113,
+        10,
+        86,
+        26,
+        238,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "fillVaa",
+          "accounts": [
+            {
+              "name": "vaa"
+            }
+          ]
+        },
+        {
+          "name": "preparedFill",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "accounts": [
+            {
+              "name": "mint"
+            },
+            {
+              "name": "supportedMint",
+              "docs": [
+                "Only required if the mint is not USDC."
+              ],
+              "optional": true
+            }
+          ]
+        },
+        {
+          "name": "sourceRouterEndpoint",
+          "accounts": [
+            {
+              "name": "endpoint"
+            }
+          ]
+        },
+        {
+          "name": "tokenBridge",
+          "accounts": [
+            {
+              "name": "mintRecipient",
+              "accounts": [
+                {
+                  "name": "mintRecipient",
+                  "writable": true
+                }
+              ]
+            },
+            {
+              "name": "config"
+            },
+            {
+              "name": "claim",
+              "docs": [
+                "program)."
+              ],
+              "writable": true
+            },
+            {
+              "name": "registeredEmitter"
+            },
+            {
+              "name": "custodyToken",
+              "writable": true
+            },
+            {
+              "name": "custodyAuthority"
+            },
+            {
+              "name": "coreBridgeProgram"
+            },
+            {
+              "name": "tokenBridgeProgram"
+            },
+            {
+              "name": "rent"
+            }
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundExpiredPreparedOrder",
+      "docs": [
+        "This instruction is used to close a `PreparedOrder` account whose expiry has passed. The",
+        "`prepared_custody_token` account is closed and the tokens are refunded to the",
+        "`refund_token` account, where the rent goes to the `prepared_by` account. Anyone can call",
+        "this instruction.",
+        "# Arguments",
+        "",
+        "* `ctx` - `RefundExpiredPreparedOrder` context."
+      ],
+      "discriminator": [
+        7,
+        107,
+        154,
+        54,
+        21,
+        28,
+        98,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "preparedOrder",
+          "docs": [
+            "Only a prepared order whose expiry has passed can be refunded."
+          ],
+          "writable": true
+        },
+        {
+          "name": "preparedBy",
+          "writable": true
+        },
+        {
+          "name": "refundToken",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Custody token account. This account will be closed at the end of this instruction.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "relayFastFill",
+      "docs": [
+        "This instruction is used to deliver a `prepared_fill` redeemed from a fast fill that pays a",
+        "relayer fee. The relayer fee is transferred from the `prepared_custody_token` account to the",
+        "`relayer_token` account and the remaining tokens are transferred to the redeemer's",
+        "associated token account. The `prepared_custody_token` and `prepared_fill` accounts are",
+        "closed. This instruction can be called by anyone, who would typically redeem the fast fill",
+        "with `redeem_fast_fill` in the same transaction.",
+        "# Arguments",
+        "",
+        "* `ctx` - `RelayFastFill` context."
+      ],
+      "discriminator": [
+        183,
+        69,
+        91,
+        70,
+        205,
+        18,
+        101,
+        115
+      ],
+      "accounts": [
+        {
+          "name": "relayer",
+          "signer": true
+        },
+        {
+          "name": "relayerToken",
+          "docs": [
+            "Relayer's token account, which receives the relayer fee."
+          ],
+          "writable": true
+        },
+        {
+          "name": "preparedBy",
+          "docs": [
+            "lamports of the closed accounts."
+          ],
+          "writable": true
+        },
+        {
+          "name": "fastFill",
+          "docs": [
+            "Fast fill that the prepared fill was redeemed from. Only a fast fill with a relayer fee can",
+            "be relayed."
+          ]
+        },
+        {
+          "name": "preparedFill",
+          "writable": true
+        },
+        {
+          "name": "preparedCustodyToken",
+          "docs": [
+            "Custody token account. This account will be closed at the end of this instruction.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "redeemer"
+        },
+        {
+          "name": "redeemerToken",
+          "docs": [
+            "Redeemer's associated token account, which receives the fill amount less the relayer fee."
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeSwapProgram",
+      "docs": [
+        "This instruction is used to disallow a swap program by closing its `SwapProgram` account.",
+        "This instruction can only be called by the `owner`.",
+        "# Arguments",
+        "",
+        "* `ctx` - `RemoveSwapProgram` context."
+      ],
+      "discriminator": [
+        173,
+        83,
+        7,
+        127,
+        57,
+        211,
+        188,
+        13
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "The owner receives the rent from the closed account."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "custodian",
+          "accounts": [
+            {
+              "name": "custodian"
+            }
+          ]
+        },
+        {
+          "name": "swapProgram",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeRole",
+      "docs": [
+        "This instruction is used to revoke a role from a holder. This instruction can only be called",
+        "by the `owner`.",
+        "# Arguments",
+        "",
+        "* `ctx`  - `RevokeRole` context.",
+        "* `role` - The `Role` to revoke."
+      ],
+      "discriminator": [
+        179,
+        232,
+        2,
+        180,
+        48,
+        227,
+        82,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "roleHolder",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "role"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPause",
+      "docs": [
+        "This instruction is used to pause or unpause further processing of new transfer. Only the `owner`,",
+        "`owner_assistant` or a `Pauser` role holder can pause the program.",
+        "# Arguments",
+        "",
+        "* `ctx`   - `SetPause` context.",
+        "* `pause` - Boolean indicating whether to pause the program."
+      ],
+      "discriminator": [
+        63,
+        32,
+        154,
+        2,
+        56,
+        103,
+        79,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "submitOwnershipTransferRequest",
+      "docs": [
+        "This instruction sets the `pending_owner` field in the `Custodian` account. This instruction",
+        "can only be called by the `owner`. The `pending_owner` address must be valid, meaning it",
+        "cannot be the zero address or the current owner. The request expires if it is not confirmed",
+        "within `OWNERSHIP_TRANSFER_REQUEST_EXPIRATION` slots.",
+        "# Arguments",
+        "",
+        "* `ctx` - `SubmitOwnershipTransferRequest` context."
+      ],
+      "discriminator": [
+        215,
+        13,
+        88,
+        199,
+        48,
+        195,
+        19,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "newOwner",
+          "docs": [
+            "New Owner.",
+            ""
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateMaxIntegratorFeeBps",
+      "docs": [
+        "This instruction is used to update the maximum fee an integrator can collect from an",
+        "order's amount in, where `1_000_000` is 100%. This instruction can only be called by the",
+        "`owner`, `owner_assistant` or a `FeeManager` role holder.",
+        "# Arguments",
+        "",
+        "* `ctx`         - `UpdateMaxIntegratorFeeBps` context.",
+        "* `max_fee_bps` - New maximum integrator fee."
+      ],
+      "discriminator": [
+        192,
+        248,
+        222,
+        47,
+        0,
+        41,
+        241,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "authority",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            },
+            {
+              "name": "roleHolder",
+              "docs": [
+                "Only required if the authority is neither the owner nor the owner assistant."
+              ],
+              "optional": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "maxFeeBps",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "updateOwnerAssistant",
+      "docs": [
+        "This instruction is used to propose a new `owner_assistant`, which is set as the",
+        "`pending_owner_assistant` in the `Custodian` account. The new assistant must accept the role",
+        "using `accept_owner_assistant`. This instruction can only be called by the `owner`.",
+        "# Arguments",
+        "",
+        "* `ctx` - `UpdateOwnerAssistant` context."
+      ],
+      "discriminator": [
+        153,
+        83,
+        175,
+        53,
+        168,
+        34,
+        131,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "writable": true
+            }
+          ]
+        },
+        {
+          "name": "newOwnerAssistant",
+          "docs": [
+            "New Assistant.",
+            ""
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateOwnerSet",
+      "docs": [
+        "This instruction is used to replace the threshold and members of the `OwnerSet` account.",
+        "This instruction can only be called by the `owner`, which is the owner set itself if",
+        "ownership has been transferred to it.",
+        "# Arguments",
+        "",
+        "* `ctx`  - `UpdateOwnerSet` context.",
+        "* `args` - The `OwnerSetArgs`, which has the threshold and members.",
+        "",
+        "Pending `OwnerSetTransaction` accounts may be passed as remaining accounts, which drops",
+        "approvals from members who are no longer in the set."
+      ],
+      "discriminator": [
+        243,
+        143,
+        98,
+        184,
+        163,
+        238,
+        178,
+        207
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "accounts": [
+            {
+              "name": "owner",
+              "signer": true
+            },
+            {
+              "name": "custodian",
+              "accounts": [
+                {
+                  "name": "custodian"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "ownerSet",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "ownerSetArgs"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "auction",
+      "discriminator": [
+        218,
+        94,
+        247,
+        242,
+        126,
+        233,
+        131,
+        81
+      ]
+    },
+    {
+      "name": "custodian",
+      "discriminator": [
+        132,
+        228,
+        139,
+        184,
+        112,
+        228,
+        108,
+        240
+      ]
+    },
+    {
+      "name": "fastFill",
+      "discriminator": [
+        89,
+        120,
+        166,
+        41,
+        106,
+        227,
+        218,
+        121
+      ]
+    },
+    {
+      "name": "ownerSet",
+      "discriminator": [
+        65,
+        59,
+        155,
+        73,
+        75,
+        114,
+        167,
+        212
+      ]
+    },
+    {
+      "name": "ownerSetTransaction",
+      "discriminator": [
+        114,
+        30,
+        81,
+        122,
+        227,
+        240,
+        67,
+        134
+      ]
+    },
+    {
+      "name": "preparedFill",
+      "discriminator": [
+        202,
+        241,
+        65,
+        186,
+        110,
+        235,
+        238,
+        80
+      ]
+    },
+    {
+      "name": "preparedOrder",
+      "discriminator": [
+        3,
+        21,
+        13,
+        182,
         167,
         149,
         128,
@@ -1050,329 +2991,1473 @@ export type TokenRouter = {
       ]
     },
     {
-      "name": "routerEndpoint",
-      "discriminator": [
-        217,
-        148,
-        188,
-        203,
-        183,
-        105,
-        154,
-        205
-      ]
-    }
-  ],
-  "errors": [
+      "name": "roleHolder",
+      "discriminator": [
+        107,
+        145,
+        245,
+        94,
+        248,
+        36,
+        134,
+        52
+      ]
+    },
+    {
+      "name": "routerEndpoint",
+      "discriminator": [
+        217,
+        148,
+        188,
+        203,
+        183,
+        105,
+        154,
+        205
+      ]
+    },
+    {
+      "name": "senderState",
+      "discriminator": [
+        15,
+        38,
+        72,
+        105,
+        206,
+        169,
+        147,
+        163
+      ]
+    },
+    {
+      "name": "supportedMint",
+      "discriminator": [
+        121,
+        81,
+        245,
+        119,
+        245,
+        243,
+        243,
+        158
+      ]
+    },
+    {
+      "name": "swapProgram",
+      "discriminator": [
+        179,
+        98,
+        244,
+        241,
+        193,
+        31,
+        200,
+        163
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "integratorFeeCollected",
+      "discriminator": [
+        208,
+        107,
+        10,
+        21,
+        184,
+        191,
+        139,
+        41
+      ]
+    },
+    {
+      "name": "preparedFillConsumed",
+      "discriminator": [
+        101,
+        212,
+        182,
+        5,
+        9,
+        87,
+        28,
+        3
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6002,
+      "name": "ownerOnly"
+    },
+    {
+      "code": 6004,
+      "name": "ownerOrAssistantOnly"
+    },
+    {
+      "code": 6006,
+      "name": "ownerAssistantOrRoleOnly"
+    },
+    {
+      "code": 6016,
+      "name": "u64Overflow"
+    },
+    {
+      "code": 6048,
+      "name": "invalidVaa"
+    },
+    {
+      "code": 6068,
+      "name": "invalidDepositMessage"
+    },
+    {
+      "code": 6070,
+      "name": "invalidPayloadId"
+    },
+    {
+      "code": 6072,
+      "name": "invalidDepositPayloadId"
+    },
+    {
+      "code": 6078,
+      "name": "redeemerMessageTooLarge"
+    },
+    {
+      "code": 6082,
+      "name": "unsupportedMint"
+    },
+    {
+      "code": 6096,
+      "name": "invalidSourceRouter"
+    },
+    {
+      "code": 6098,
+      "name": "invalidTargetRouter"
+    },
+    {
+      "code": 6100,
+      "name": "endpointDisabled"
+    },
+    {
+      "code": 6102,
+      "name": "invalidCctpEndpoint"
+    },
+    {
+      "code": 6104,
+      "name": "endpointPaused"
+    },
+    {
+      "code": 6128,
+      "name": "paused"
+    },
+    {
+      "code": 6256,
+      "name": "assistantZeroPubkey"
+    },
+    {
+      "code": 6258,
+      "name": "immutableProgram"
+    },
+    {
+      "code": 6259,
+      "name": "roleHolderZeroPubkey"
+    },
+    {
+      "code": 6260,
+      "name": "maxIntegratorFeeBpsTooLarge",
+      "msg": "Value exceeds 1000000"
+    },
+    {
+      "code": 6514,
+      "name": "invalidNewOwner"
+    },
+    {
+      "code": 6516,
+      "name": "alreadyOwner"
+    },
+    {
+      "code": 6518,
+      "name": "noTransferOwnershipRequest"
+    },
+    {
+      "code": 6520,
+      "name": "notPendingOwner"
+    },
+    {
+      "code": 6522,
+      "name": "eitherSenderOrProgramTransferAuthority"
+    },
+    {
+      "code": 6524,
+      "name": "delegatedAmountMismatch"
+    },
+    {
+      "code": 6526,
+      "name": "ownershipTransferRequestExpired"
+    },
+    {
+      "code": 6528,
+      "name": "invalidOwnerSet"
+    },
+    {
+      "code": 6530,
+      "name": "ownerSetMemberOnly"
+    },
+    {
+      "code": 6532,
+      "name": "ownerSetTransactionAlreadyApproved"
+    },
+    {
+      "code": 6534,
+      "name": "ownerSetThresholdNotMet"
+    },
+    {
+      "code": 6536,
+      "name": "invalidOwnerSetTransaction"
+    },
+    {
+      "code": 6544,
+      "name": "roleAlreadyGranted"
+    },
+    {
+      "code": 6546,
+      "name": "roleNotGranted"
+    },
+    {
+      "code": 6548,
+      "name": "noPendingOwnerAssistant"
+    },
+    {
+      "code": 6550,
+      "name": "notPendingOwnerAssistant"
+    },
+    {
+      "code": 6578,
+      "name": "invalidMintRecipient"
+    },
+    {
+      "code": 7024,
+      "name": "insufficientAmount"
+    },
+    {
+      "code": 7026,
+      "name": "minAmountOutTooHigh"
+    },
+    {
+      "code": 7028,
+      "name": "invalidRedeemer"
+    },
+    {
+      "code": 7030,
+      "name": "preparedFillTooLarge"
+    },
+    {
+      "code": 7032,
+      "name": "orderAmountBelowMinimum"
+    },
+    {
+      "code": 7034,
+      "name": "orderAmountAboveMaximum"
+    },
+    {
+      "code": 7036,
+      "name": "invalidSwapProgram"
+    },
+    {
+      "code": 7038,
+      "name": "swapAmountOutTooLow"
+    },
     {
-      "code": 6002,
-      "name": "ownerOnly"
+      "code": 7040,
+      "name": "swapAmountInRemaining"
     },
     {
-      "code": 6004,
-      "name": "ownerOrAssistantOnly"
+      "code": 7042,
+      "name": "orderExpired"
     },
     {
-      "code": 6016,
-      "name": "u64Overflow"
+      "code": 7044,
+      "name": "orderConditionsNotMet"
     },
     {
-      "code": 6048,
-      "name": "invalidVaa"
+      "code": 7046,
+      "name": "invalidSchedule"
     },
     {
-      "code": 6068,
-      "name": "invalidDepositMessage"
+      "code": 7048,
+      "name": "scheduledSliceNotDue"
     },
     {
-      "code": 6070,
-      "name": "invalidPayloadId"
+      "code": 7050,
+      "name": "orderNotExpired"
     },
     {
-      "code": 6072,
-      "name": "invalidDepositPayloadId"
+      "code": 7052,
+      "name": "integratorFeeTooHigh"
     },
     {
-      "code": 6078,
-      "name": "redeemerMessageTooLarge"
+      "code": 7054,
+      "name": "integratorFeeTokenRequired"
     },
     {
-      "code": 6096,
-      "name": "invalidSourceRouter"
+      "code": 7056,
+      "name": "fastFillNotRelayable"
     },
     {
-      "code": 6098,
-      "name": "invalidTargetRouter"
+      "code": 7058,
+      "name": "invalidLimitOrderAuction"
+    }
+  ],
+  "types": [
+    {
+      "name": "auction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vaaHash",
+            "docs": [
+              "VAA hash of the auction."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "vaaTimestamp",
+            "docs": [
+              "Timestamp of the fast market order VAA."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "targetProtocol",
+            "docs": [
+              "Transfer protocol used to move assets."
+            ],
+            "type": {
+              "defined": {
+                "name": "messageProtocol"
+              }
+            }
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Auction status."
+            ],
+            "type": {
+              "defined": {
+                "name": "auctionStatus"
+              }
+            }
+          },
+          {
+            "name": "preparedBy",
+            "docs": [
+              "The fee payer when placing the initial offer."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "info",
+            "docs": [
+              "Optional auction info. This field will be `None`` if there is no auction."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "auctionInfo"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6100,
-      "name": "endpointDisabled"
+      "name": "auctionDestinationAssetInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "custodyTokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "amountOut",
+            "docs": [
+              "The amount of the destination asset offered by the highest bidder."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the destination asset."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bestOfferToken",
+            "docs": [
+              "The highest bidder's destination asset token account, which is refunded if the offer is",
+              "improved."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6102,
-      "name": "invalidCctpEndpoint"
+      "name": "auctionInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "configId",
+            "type": "u32"
+          },
+          {
+            "name": "custodyTokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "vaaSequence",
+            "docs": [
+              "Sequence of the fast market order VAA."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sourceChain",
+            "docs": [
+              "The chain where the transfer is initiated."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bestOfferToken",
+            "docs": [
+              "The highest bidder of the auction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "initialOfferToken",
+            "docs": [
+              "The initial bidder of the auction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "startSlot",
+            "docs": [
+              "The slot when the auction started."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amountIn",
+            "docs": [
+              "The amount reflecting the amount of assets transferred into the matching engine. This plus",
+              "and the security deposit are used to participate in the auction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "securityDeposit",
+            "docs": [
+              "The additional deposit made by the highest bidder.",
+              "",
+              "NOTE: This may not be the same denomination as the `amount_in`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "offerPrice",
+            "docs": [
+              "The offer price of the auction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "redeemerMessageLen",
+            "docs": [
+              "Length of the redeemer message, which may impact the expense to execute the auction."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "destinationAssetInfo",
+            "docs": [
+              "If the destination asset is not equal to the asset used for auctions, this will be some",
+              "value specifying its custody token bump and amount out. Participants bid on the amount out",
+              "of this asset instead of an offer price, and the fast fill is delivered in this asset. The",
+              "highest bidder is still repaid in the auction's mint when the auction is settled."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "auctionDestinationAssetInfo"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6128,
-      "name": "paused"
+      "name": "auctionStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "notStarted"
+          },
+          {
+            "name": "active"
+          },
+          {
+            "name": "completed",
+            "fields": [
+              {
+                "name": "slot",
+                "type": "u64"
+              },
+              {
+                "name": "executePenalty",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "settled",
+            "fields": [
+              {
+                "name": "fee",
+                "type": "u64"
+              },
+              {
+                "name": "totalPenalty",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "cctpMessageArgs",
+      "docs": [
+        "Arguments for [redeem_cctp_fill]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "encodedCctpMessage",
+            "docs": [
+              "CCTP message."
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "cctpAttestation",
+            "docs": [
+              "Attestation of [encoded_cctp_message](Self::encoded_cctp_message)."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "custodian",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paused",
+            "docs": [
+              "Boolean indicating whether outbound transfers are paused."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Program's owner."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingOwner",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "ownerAssistant",
+            "docs": [
+              "Program's assistant. Can be used to update the relayer fee and swap rate."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pausedSetBy",
+            "docs": [
+              "Indicate who last set the `paused` value. When the program is first initialized, this is set",
+              "to the `owner`."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingOwnerExpirySlot",
+            "docs": [
+              "Slot after which the pending owner can no longer confirm the ownership transfer request."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingOwnerAssistant",
+            "docs": [
+              "Proposed assistant, who must accept the role before replacing the current assistant."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "maxIntegratorFeeBps",
+            "docs": [
+              "Maximum fee an integrator can collect from an order's amount in, where",
+              "[FEE_PRECISION_MAX](crate::FEE_PRECISION_MAX) is 100%. Integrator fees are disabled when",
+              "this value is zero."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 6256,
-      "name": "assistantZeroPubkey"
+      "name": "endpointInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chain",
+            "docs": [
+              "Emitter chain. Cannot equal `1` (Solana's Chain ID)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "address",
+            "docs": [
+              "Emitter address. Cannot be zero address."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mintRecipient",
+            "docs": [
+              "Future-proof field in case another network has token accounts to send assets to instead of",
+              "sending to the address directly."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "protocol",
+            "docs": [
+              "Specific message protocol used to move assets."
+            ],
+            "type": {
+              "defined": {
+                "name": "messageProtocol"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6258,
-      "name": "immutableProgram"
+      "name": "endpointLimits",
+      "docs": [
+        "Optional limits on orders destined for an endpoint."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxRedeemerMessageLen",
+            "docs": [
+              "Max redeemer message length. This limit can only be more restrictive than the max enforced",
+              "by the Token Router."
+            ],
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "minOrderAmount",
+            "docs": [
+              "Minimum order amount."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxOrderAmount",
+            "docs": [
+              "Maximum order amount."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6514,
-      "name": "invalidNewOwner"
+      "name": "fastFill",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "seeds",
+            "type": {
+              "defined": {
+                "name": "fastFillSeeds"
+              }
+            }
+          },
+          {
+            "name": "redeemed",
+            "docs": [
+              "Whether the [FastFill] has been redeemed via the local Token Router."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "info",
+            "type": {
+              "defined": {
+                "name": "fastFillInfo"
+              }
+            }
+          },
+          {
+            "name": "redeemerMessage",
+            "type": "bytes"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the fill amount."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "relayerFee",
+            "docs": [
+              "If nonzero, the order sender opted into having the fill automatically delivered to the",
+              "redeemer's associated token account by a relayer, who earns this fee."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "ownsCustodyToken",
+            "docs": [
+              "Whether the fill amount is held in a token account owned by the [FastFill] instead of the",
+              "local custody token account, which is the case for fills delivered in a destination asset."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6516,
-      "name": "alreadyOwner"
+      "name": "fastFillInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "preparedBy",
+            "docs": [
+              "Who paid the lamports to create the [FastFill] account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Fill amount."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "redeemer",
+            "docs": [
+              "Authority allowed to redeem [FastFill]."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Timestamp at the time a fill was issued. When the fast fill is created, it is set using the",
+              "current [Clock] unix timestamp."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6518,
-      "name": "noTransferOwnershipRequest"
+      "name": "fastFillSeeds",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceChain",
+            "docs": [
+              "Wormhole chain ID reflecting where the order was created."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "orderSender",
+            "docs": [
+              "Universal address of the order sender."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sequence",
+            "docs": [
+              "Sequence generated by the [FastFillSequencer](crate::state::FastFillSequencer) when it",
+              "reserved a sequence number for this fill."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for the [FastFill] account."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6520,
-      "name": "notPendingOwner"
+      "name": "fillType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "unset"
+          },
+          {
+            "name": "wormholeCctpDeposit"
+          },
+          {
+            "name": "fastFill"
+          },
+          {
+            "name": "wormholeTokenBridgeTransfer"
+          }
+        ]
+      }
     },
     {
-      "code": 6522,
-      "name": "eitherSenderOrProgramTransferAuthority"
+      "name": "inboundLimit",
+      "docs": [
+        "Cap on the notional amount of fast orders accepted from an endpoint within fixed windows of",
+        "slots. The accepted amount is tracked by the endpoint's [InboundNotional](super::InboundNotional)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "windowSlots",
+            "docs": [
+              "Number of slots in each window. Zero means there is no limit."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxNotional",
+            "docs": [
+              "Maximum notional amount accepted within a window."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6524,
-      "name": "delegatedAmountMismatch"
+      "name": "integratorFee",
+      "docs": [
+        "Fee collected by an integrator when an order is prepared."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "bps",
+            "fields": [
+              "u32"
+            ]
+          },
+          {
+            "name": "amount",
+            "fields": [
+              "u64"
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 7024,
-      "name": "insufficientAmount"
+      "name": "integratorFeeCollected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "preparedOrder",
+            "type": "pubkey"
+          },
+          {
+            "name": "integratorFeeToken",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 7026,
-      "name": "minAmountOutTooHigh"
+      "name": "messageProtocol",
+      "docs": [
+        "Protocol used to transfer assets."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "local",
+            "fields": [
+              {
+                "name": "programId",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "cctp",
+            "fields": [
+              {
+                "name": "domain",
+                "docs": [
+                  "CCTP domain, which is how CCTP registers identifies foreign networks."
+                ],
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "tokenBridge"
+          },
+          {
+            "name": "ntt",
+            "fields": [
+              {
+                "name": "manager",
+                "docs": [
+                  "NTT manager program, which custodies or burns the transferred tokens."
+                ],
+                "type": "pubkey"
+              },
+              {
+                "name": "transceiver",
+                "docs": [
+                  "Transceiver that releases the NTT manager's outbound transfers."
+                ],
+                "type": "pubkey"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 7028,
-      "name": "invalidRedeemer"
+      "name": "orderType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "market",
+            "fields": [
+              {
+                "name": "minAmountOut",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "limit",
+            "fields": [
+              {
+                "name": "maxKeeperFee",
+                "docs": [
+                  "Maximum keeper fee deducted from the amount in when the order is placed."
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "minAmountOut",
+                "docs": [
+                  "If provided, minimum amount of tokens to receive in exchange for the amount in."
+                ],
+                "type": {
+                  "option": "u64"
+                }
+              },
+              {
+                "name": "auction",
+                "docs": [
+                  "Matching Engine auction whose outcome determines whether this order can be placed."
+                ],
+                "type": "pubkey"
+              },
+              {
+                "name": "maxOfferPrice",
+                "docs": [
+                  "Limit price. This order can only be placed once the auction has completed with an offer",
+                  "price no greater than this value."
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "expiry",
+                "docs": [
+                  "Timestamp after which this order can no longer be placed."
+                ],
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "scheduled",
+            "fields": [
+              {
+                "name": "remainingSlices",
+                "docs": [
+                  "Number of slices that have not been placed yet. The last slice places whatever remains",
+                  "in custody."
+                ],
+                "type": "u16"
+              },
+              {
+                "name": "sliceAmount",
+                "docs": [
+                  "Amount placed by each slice, excluding the keeper reward."
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "keeperReward",
+                "docs": [
+                  "Amount taken from each slice to reward the keeper who placed it."
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "sliceInterval",
+                "docs": [
+                  "Number of slots between slices."
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "nextSliceSlot",
+                "docs": [
+                  "Slot at which the next slice can be placed."
+                ],
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "expiringMarket",
+            "fields": [
+              {
+                "name": "minAmountOut",
+                "type": {
+                  "option": "u64"
+                }
+              },
+              {
+                "name": "expiry",
+                "docs": [
+                  "Timestamp after which this order can no longer be placed."
+                ],
+                "type": "i64"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 7030,
-      "name": "preparedFillTooLarge"
-    }
-  ],
-  "types": [
-    {
-      "name": "cctpMessageArgs",
+      "name": "ownerSet",
       "docs": [
-        "Arguments for [redeem_cctp_fill]."
+        "M-of-N set of members that can act as the program's owner. Once the [Custodian] owner is set to",
+        "this account's address, owner-only instructions can only be invoked by executing an approved",
+        "[OwnerSetTransaction].",
+        "",
+        "[Custodian]: crate::state::Custodian"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "encodedCctpMessage",
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
             "docs": [
-              "CCTP message."
+              "Number of member approvals required to execute a transaction."
             ],
-            "type": "bytes"
+            "type": "u8"
           },
           {
-            "name": "cctpAttestation",
+            "name": "members",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "nextTransactionId",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ownerSetAccountMeta",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "pubkey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ownerSetArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
             "docs": [
-              "Attestation of [encoded_cctp_message](Self::encoded_cctp_message)."
+              "Number of member approvals required to execute an owner set transaction."
             ],
-            "type": "bytes"
+            "type": "u8"
+          },
+          {
+            "name": "members",
+            "docs": [
+              "Members of the owner set. There can be at most [OwnerSet::MAX_MEMBERS] members."
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
           }
         ]
       }
     },
     {
-      "name": "custodian",
+      "name": "ownerSetTransaction",
+      "docs": [
+        "Instruction for this program proposed by an [OwnerSet] member. Once enough members have",
+        "approved it, anyone can execute it, where the owner set signs for the instruction."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "paused",
-            "docs": [
-              "Boolean indicating whether outbound transfers are paused."
-            ],
-            "type": "bool"
+            "name": "id",
+            "type": "u64"
           },
           {
-            "name": "owner",
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "proposedBy",
             "docs": [
-              "Program's owner."
+              "Member who proposed (and paid for) this transaction."
             ],
             "type": "pubkey"
           },
           {
-            "name": "pendingOwner",
+            "name": "approvals",
+            "docs": [
+              "Members who have approved this transaction."
+            ],
             "type": {
-              "option": "pubkey"
+              "vec": "pubkey"
             }
           },
           {
-            "name": "ownerAssistant",
+            "name": "accounts",
             "docs": [
-              "Program's assistant. Can be used to update the relayer fee and swap rate."
+              "Accounts expected by the instruction, which must be passed as remaining accounts when this",
+              "transaction is executed."
             ],
-            "type": "pubkey"
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "ownerSetAccountMeta"
+                }
+              }
+            }
           },
           {
-            "name": "pausedSetBy",
+            "name": "data",
             "docs": [
-              "Indicate who last set the `paused` value. When the program is first initialized, this is set",
-              "to the `owner`."
+              "Encoded instruction data (including the Anchor discriminator)."
             ],
-            "type": "pubkey"
+            "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "endpointInfo",
+      "name": "ownerSetTransactionArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "chain",
+            "name": "accounts",
             "docs": [
-              "Emitter chain. Cannot equal `1` (Solana's Chain ID)."
+              "Accounts of the instruction to be invoked by the owner set."
             ],
-            "type": "u16"
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "ownerSetAccountMeta"
+                }
+              }
+            }
           },
           {
-            "name": "address",
+            "name": "data",
             "docs": [
-              "Emitter address. Cannot be zero address."
+              "Instruction data, which must begin with an instruction discriminator for this program."
             ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseFlag",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paused",
+            "type": "bool"
           },
           {
-            "name": "mintRecipient",
+            "name": "unpauseSlot",
             "docs": [
-              "Future-proof field in case another network has token accounts to send assets to instead of",
-              "sending to the address directly."
+              "Slot at which the pause is lifted automatically. If none, the pause is in effect until it is",
+              "explicitly lifted."
             ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "option": "u64"
             }
           },
           {
-            "name": "protocol",
+            "name": "reason",
             "docs": [
-              "Specific message protocol used to move assets."
+              "Arbitrary code describing why this flag was set, which is meant for off-chain reporting."
             ],
-            "type": {
-              "defined": {
-                "name": "messageProtocol"
-              }
-            }
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "fastFill",
+      "name": "prepareLimitOrderArgs",
+      "docs": [
+        "Arguments for [prepare_limit_order]."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "seeds",
-            "type": {
-              "defined": {
-                "name": "fastFillSeeds"
-              }
-            }
+            "name": "amountIn",
+            "docs": [
+              "Amount of tokens to transfer."
+            ],
+            "type": "u64"
           },
           {
-            "name": "redeemed",
+            "name": "maxKeeperFee",
             "docs": [
-              "Whether the [FastFill] has been redeemed via the local Token Router."
+              "Maximum keeper fee deducted from [amount_in](Self::amount_in) when the order is placed."
             ],
-            "type": "bool"
+            "type": "u64"
           },
           {
-            "name": "info",
+            "name": "minAmountOut",
+            "docs": [
+              "If provided, minimum amount of tokens to receive in exchange for",
+              "[amount_in](Self::amount_in)."
+            ],
             "type": {
-              "defined": {
-                "name": "fastFillInfo"
-              }
+              "option": "u64"
             }
           },
           {
-            "name": "redeemerMessage",
-            "type": "bytes"
-          }
-        ]
-      }
-    },
-    {
-      "name": "fastFillInfo",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "preparedBy",
+            "name": "auction",
             "docs": [
-              "Who paid the lamports to create the [FastFill] account."
+              "Matching Engine auction whose outcome determines whether the order can be placed."
             ],
             "type": "pubkey"
           },
           {
-            "name": "amount",
+            "name": "maxOfferPrice",
             "docs": [
-              "Fill amount."
+              "Limit price. The order can only be placed once the [auction](Self::auction) has completed",
+              "with an offer price no greater than this value."
             ],
             "type": "u64"
           },
+          {
+            "name": "expiry",
+            "docs": [
+              "Timestamp after which the order can no longer be placed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "targetChain",
+            "docs": [
+              "The Wormhole chain ID of the network to transfer tokens to."
+            ],
+            "type": "u16"
+          },
           {
             "name": "redeemer",
             "docs": [
-              "Authority allowed to redeem [FastFill]."
+              "The address of the redeeming contract on the target chain."
             ],
-            "type": "pubkey"
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "timestamp",
+            "name": "redeemerMessage",
             "docs": [
-              "Timestamp at the time a fill was issued. When the fast fill is created, it is set using the",
-              "current [Clock] unix timestamp."
+              "Arbitrary payload to be sent to the [redeemer](Self::redeemer), which can be used to encode",
+              "instructions or data for another network's smart contract."
             ],
-            "type": "i64"
+            "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "fastFillSeeds",
+      "name": "prepareMarketOrderArgs",
+      "docs": [
+        "Arguments for [prepare_market_order]."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "sourceChain",
+            "name": "amountIn",
             "docs": [
-              "Wormhole chain ID reflecting where the order was created."
+              "Amount of tokens to transfer."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minAmountOut",
+            "docs": [
+              "If provided, minimum amount of tokens to receive in exchange for",
+              "[amount_in](Self::amount_in)."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "targetChain",
+            "docs": [
+              "The Wormhole chain ID of the network to transfer tokens to."
             ],
             "type": "u16"
           },
           {
-            "name": "orderSender",
+            "name": "redeemer",
             "docs": [
-              "Universal address of the order sender."
+              "The address of the redeeming contract on the target chain."
             ],
             "type": {
               "array": [
@@ -1382,98 +4467,100 @@ export type TokenRouter = {
             }
           },
           {
-            "name": "sequence",
-            "docs": [
-              "Sequence generated by the [FastFillSequencer](crate::state::FastFillSequencer) when it",
-              "reserved a sequence number for this fill."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "bump",
+            "name": "redeemerMessage",
             "docs": [
-              "Bump seed for the [FastFill] account."
+              "Arbitrary payload to be sent to the [redeemer](Self::redeemer), which can be used to encode",
+              "instructions or data for another network's smart contract."
             ],
-            "type": "u8"
+            "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "fillType",
+      "name": "prepareMarketOrderV2Args",
+      "docs": [
+        "Arguments for [prepare_market_order_v2]."
+      ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "unset"
+            "name": "amountIn",
+            "docs": [
+              "Amount of tokens to transfer."
+            ],
+            "type": "u64"
           },
           {
-            "name": "wormholeCctpDeposit"
+            "name": "minAmountOut",
+            "docs": [
+              "If provided, minimum amount of tokens to receive in exchange for",
+              "[amount_in](Self::amount_in)."
+            ],
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "fastFill"
-          }
-        ]
-      }
-    },
-    {
-      "name": "messageProtocol",
-      "docs": [
-        "Protocol used to transfer assets."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
+            "name": "expiry",
+            "docs": [
+              "If provided, timestamp after which the order can no longer be placed. Once it passes, anyone",
+              "can refund the order to the [refund token](crate::state::PreparedOrderInfo::refund_token)."
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
           {
-            "name": "none"
+            "name": "targetChain",
+            "docs": [
+              "The Wormhole chain ID of the network to transfer tokens to."
+            ],
+            "type": "u16"
           },
           {
-            "name": "local",
-            "fields": [
-              {
-                "name": "programId",
-                "type": "pubkey"
-              }
-            ]
+            "name": "redeemer",
+            "docs": [
+              "The address of the redeeming contract on the target chain."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "cctp",
-            "fields": [
-              {
-                "name": "domain",
-                "docs": [
-                  "CCTP domain, which is how CCTP registers identifies foreign networks."
-                ],
-                "type": "u32"
-              }
-            ]
-          }
-        ]
-      }
-    },
-    {
-      "name": "orderType",
-      "type": {
-        "kind": "enum",
-        "variants": [
+            "name": "redeemerMessage",
+            "docs": [
+              "Arbitrary payload to be sent to the [redeemer](Self::redeemer), which can be used to encode",
+              "instructions or data for another network's smart contract."
+            ],
+            "type": "bytes"
+          },
           {
-            "name": "market",
-            "fields": [
-              {
-                "name": "minAmountOut",
-                "type": {
-                  "option": "u64"
+            "name": "integratorFee",
+            "docs": [
+              "If provided, fee collected by an integrator (e.g. a front-end) from",
+              "[amount_in](Self::amount_in). This fee cannot exceed the maximum integrator fee found in the",
+              "[Custodian](crate::state::Custodian)."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "integratorFee"
                 }
               }
-            ]
+            }
           }
         ]
       }
     },
     {
-      "name": "prepareMarketOrderArgs",
+      "name": "prepareScheduledOrderArgs",
       "docs": [
-        "Arguments for [prepare_market_order]."
+        "Arguments for [prepare_scheduled_order]."
       ],
       "type": {
         "kind": "struct",
@@ -1481,19 +4568,30 @@ export type TokenRouter = {
           {
             "name": "amountIn",
             "docs": [
-              "Amount of tokens to transfer."
+              "Amount of tokens to transfer over all slices."
             ],
             "type": "u64"
           },
           {
-            "name": "minAmountOut",
+            "name": "numSlices",
             "docs": [
-              "If provided, minimum amount of tokens to receive in exchange for",
-              "[amount_in](Self::amount_in)."
+              "Number of slices to place [amount_in](Self::amount_in) in."
             ],
-            "type": {
-              "option": "u64"
-            }
+            "type": "u16"
+          },
+          {
+            "name": "sliceInterval",
+            "docs": [
+              "Number of slots between slices."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "keeperReward",
+            "docs": [
+              "Amount taken from each slice to reward the keeper who placed it."
+            ],
+            "type": "u64"
           },
           {
             "name": "targetChain",
@@ -1517,8 +4615,8 @@ export type TokenRouter = {
           {
             "name": "redeemerMessage",
             "docs": [
-              "Arbitrary payload to be sent to the [redeemer](Self::redeemer), which can be used to encode",
-              "instructions or data for another network's smart contract."
+              "Arbitrary payload to be sent to the [redeemer](Self::redeemer) with each slice, which can be",
+              "used to encode instructions or data for another network's smart contract."
             ],
             "type": "bytes"
           }
@@ -1553,6 +4651,30 @@ export type TokenRouter = {
         ]
       }
     },
+    {
+      "name": "preparedFillConsumed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "preparedFill",
+            "type": "pubkey"
+          },
+          {
+            "name": "dstToken",
+            "type": "pubkey"
+          },
+          {
+            "name": "amountConsumed",
+            "type": "u64"
+          },
+          {
+            "name": "amountRemaining",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
       "name": "preparedFillInfo",
       "type": {
@@ -1709,6 +4831,108 @@ export type TokenRouter = {
         ]
       }
     },
+    {
+      "name": "previousAddress",
+      "docs": [
+        "Emitter address replaced by the most recent update, which is still accepted as a source of",
+        "messages until the expiry slot."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "expirySlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "redeemAndSwapArgs",
+      "docs": [
+        "Arguments for [redeem_and_swap]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmountOut",
+            "docs": [
+              "Minimum amount of the output token the redeemer is willing to accept from the swap."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "swapData",
+            "docs": [
+              "Instruction data for the swap program. The swap instruction's accounts are passed as",
+              "remaining accounts, where the prepared fill signs as the owner of the prepared custody token",
+              "account."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "role",
+      "docs": [
+        "Roles that can be granted in addition to the owner and owner assistant. Each role only grants",
+        "access to the instructions relevant to it."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pauser"
+          },
+          {
+            "name": "endpointManager"
+          },
+          {
+            "name": "feeManager"
+          }
+        ]
+      }
+    },
+    {
+      "name": "roleHolder",
+      "docs": [
+        "Roles granted to a holder by the owner. See [Role] for what each role is permitted to do."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "roles",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "role"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "routerEndpoint",
       "docs": [
@@ -1728,6 +4952,142 @@ export type TokenRouter = {
                 "name": "endpointInfo"
               }
             }
+          },
+          {
+            "name": "pause",
+            "docs": [
+              "Temporarily blocks orders to and from this endpoint without disabling it."
+            ],
+            "type": {
+              "defined": {
+                "name": "pauseFlag"
+              }
+            }
+          },
+          {
+            "name": "inboundLimit",
+            "docs": [
+              "Cap on the notional amount of fast orders accepted from this endpoint."
+            ],
+            "type": {
+              "defined": {
+                "name": "inboundLimit"
+              }
+            }
+          },
+          {
+            "name": "disabledSlot",
+            "docs": [
+              "Slot at which this endpoint was disabled. This endpoint can be removed once the router",
+              "endpoint removal delay has elapsed since this slot."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "previousAddress",
+            "docs": [
+              "Previous emitter address, which is accepted during a migration to a new address."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "previousAddress"
+                }
+              }
+            }
+          },
+          {
+            "name": "limits",
+            "docs": [
+              "Limits on orders destined for this endpoint."
+            ],
+            "type": {
+              "defined": {
+                "name": "endpointLimits"
+              }
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the asset transferred by orders from this endpoint, which is either USDC or a",
+              "[SupportedMint](super::SupportedMint). This mint is set when the endpoint is added and",
+              "cannot be changed by updating the endpoint."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "senderState",
+      "docs": [
+        "Tracks the nonce of the next [PreparedOrder](crate::state::PreparedOrder) account prepared for",
+        "an order sender. See [prepare_market_order_v2](crate::token_router::prepare_market_order_v2)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nextNonce",
+            "docs": [
+              "Nonce used to derive the address of the order sender's next prepared order."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "supportedMint",
+      "docs": [
+        "Mint, other than USDC, whose orders can be auctioned and settled. USDC is always supported, so",
+        "it never has one of these accounts."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRecipientToken",
+            "docs": [
+              "Token account (whose mint is this mint) that collects fees from orders settled without an",
+              "auction."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "swapProgram",
+      "docs": [
+        "Swap program that a redeemer may swap a prepared fill's tokens with. See",
+        "[redeem_and_swap](crate::token_router::redeem_and_swap)."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "programId",
+            "type": "pubkey"
           }
         ]
       }
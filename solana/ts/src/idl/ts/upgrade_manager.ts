@@ -31,9 +31,25 @@ export type UpgradeManager = {
           "name": "matchingEngineCustodian",
           "writable": true
         },
+        {
+          "name": "matchingEngineCircuitBreaker",
+          "docs": [
+            "created when migrating if it does not exist."
+          ],
+          "writable": true
+        },
         {
           "name": "commitUpgrade",
           "accounts": [
+            {
+              "name": "payer",
+              "docs": [
+                "Pays for any additional rent required when the liquidity layer program migrates its",
+                "accounts."
+              ],
+              "writable": true,
+              "signer": true
+            },
             {
               "name": "admin",
               "accounts": [
@@ -65,6 +81,9 @@ export type UpgradeManager = {
             },
             {
               "name": "programData"
+            },
+            {
+              "name": "systemProgram"
             }
           ]
         }
@@ -91,6 +110,15 @@ export type UpgradeManager = {
         {
           "name": "commitUpgrade",
           "accounts": [
+            {
+              "name": "payer",
+              "docs": [
+                "Pays for any additional rent required when the liquidity layer program migrates its",
+                "accounts."
+              ],
+              "writable": true,
+              "signer": true
+            },
             {
               "name": "admin",
               "accounts": [
@@ -122,6 +150,9 @@ export type UpgradeManager = {
             },
             {
               "name": "programData"
+            },
+            {
+              "name": "systemProgram"
             }
           ]
         }
@@ -42,19 +42,32 @@ import {
     AuctionHistory,
     AuctionHistoryHeader,
     AuctionInfo,
+    AuctionDestinationAssetInfo,
     AuctionParameters,
+    CircuitBreaker,
     Custodian,
     EndpointInfo,
+    EndpointLimits,
     FastFill,
     FastFillInfo,
     FastFillSeeds,
     FastFillSequencer,
+    InboundNotional,
     MessageProtocol,
+    OwnerSet,
+    OwnerSetAccountMeta,
+    OwnerSetTransaction,
+    PauseFlag,
+    PauseTarget,
     PreparedOrderResponse,
     Proposal,
     ProposalAction,
     ReservedFastFillSequence,
+    Role,
+    RoleHolder,
     RouterEndpoint,
+    RouterEndpointRegistry,
+    SupportedMint,
 } from "./state";
 
 export const PROGRAM_IDS = [
@@ -76,6 +89,58 @@ export type AddCctpRouterEndpointArgs = {
     mintRecipient: Array<number> | null;
 };
 
+export type AddTokenBridgeRouterEndpointArgs = {
+    chain: ChainId;
+    address: Array<number>;
+    mintRecipient: Array<number> | null;
+};
+
+export type AddNttRouterEndpointArgs = {
+    chain: ChainId;
+    address: Array<number>;
+    mintRecipient: Array<number> | null;
+    manager: PublicKey;
+    transceiver: PublicKey;
+};
+
+export type UpdateRouterEndpointOpts = {
+    previousAddressExpirySlot?: Uint64;
+};
+
+export type SetStagePauseArgs = {
+    target: PauseTarget;
+    paused: boolean;
+    unpauseSlot: BN | null;
+    reason: number;
+};
+
+export type SetRouterEndpointPauseArgs = {
+    paused: boolean;
+    unpauseSlot: BN | null;
+    reason: number;
+};
+
+export type SetRouterEndpointInboundLimitArgs = {
+    windowSlots: BN;
+    maxNotional: BN;
+};
+
+export type UpdateCircuitBreakerArgs = {
+    windowSlots: BN;
+    globalThreshold: BN;
+    chainThreshold: BN;
+};
+
+export type OwnerSetArgs = {
+    threshold: number;
+    members: PublicKey[];
+};
+
+export type OwnerSetTransactionArgs = {
+    accounts: OwnerSetAccountMeta[];
+    data: Buffer;
+};
+
 export type WormholeCoreBridgeAccounts = {
     coreBridgeConfig: PublicKey;
     coreEmitterSequence: PublicKey;
@@ -128,11 +193,34 @@ export type BurnAndPublishAccounts = {
     tokenMessengerMinterProgram: PublicKey;
 };
 
+export type TokenBridgeTransferAccounts = {
+    mint: PublicKey;
+    config: PublicKey;
+    custodyToken: PublicKey;
+    transferAuthority: PublicKey;
+    custodyAuthority: PublicKey;
+    emitter: PublicKey;
+    coreBridgeConfig: PublicKey;
+    emitterSequence: PublicKey;
+    coreFeeCollector: PublicKey;
+    coreBridgeProgram: PublicKey;
+    tokenBridgeProgram: PublicKey;
+};
+
+export type NttTransferAccounts = {
+    mint: PublicKey;
+    custodyToken: PublicKey;
+    transceiver: PublicKey;
+    nttManagerProgram: PublicKey;
+};
+
 export type RedeemFastFillAccounts = {
     custodian: PublicKey;
     fromRouterEndpoint: PublicKey;
     toRouterEndpoint: PublicKey;
     localCustodyToken: PublicKey;
+    fastFillCustodyToken: PublicKey | null;
+    mint: PublicKey;
     eventAuthority: PublicKey;
     matchingEngineProgram: PublicKey;
 };
@@ -167,6 +255,7 @@ export type AuctionUpdated = {
     amountIn: BN;
     totalDeposit: BN;
     maxOfferPriceAllowed: BN | null;
+    destinationAssetInfo: AuctionDestinationAssetInfo | null;
 };
 
 export type OrderExecuted = {
@@ -187,6 +276,8 @@ export type LocalFastOrderFilled = {
     seeds: FastFillSeeds;
     info: FastFillInfo;
     auction: PublicKey | null;
+    mint: PublicKey;
+    relayerFee: BN;
 };
 
 export type FastFillSequenceReserved = {
@@ -203,6 +294,35 @@ export type AuctionClosed = {
     auction: Auction;
 };
 
+export type FastFillReclaimed = {
+    preparedBy: PublicKey;
+    fastFill: FastFillSeeds;
+    recipientToken: PublicKey;
+};
+
+export type CircuitBreakerTripped = {
+    sourceChain: number;
+    globalNotional: BN;
+    chainNotional: BN;
+};
+
+export type PauseUpdated = {
+    target: PauseTarget;
+    flag: PauseFlag;
+    setBy: PublicKey;
+};
+
+export type RouterEndpointPauseUpdated = {
+    chain: number;
+    flag: PauseFlag;
+    setBy: PublicKey;
+};
+
+export type RouterEndpointRemoved = {
+    chain: number;
+    removedBy: PublicKey;
+};
+
 export type MatchingEngineEvent = {
     auctionSettled?: AuctionSettled;
     auctionUpdated?: AuctionUpdated;
@@ -213,6 +333,11 @@ export type MatchingEngineEvent = {
     fastFillSequenceReserved?: FastFillSequenceReserved;
     fastFillRedeemed?: FastFillRedeemed;
     auctionClosed?: AuctionClosed;
+    fastFillReclaimed?: FastFillReclaimed;
+    circuitBreakerTripped?: CircuitBreakerTripped;
+    pauseUpdated?: PauseUpdated;
+    routerEndpointPauseUpdated?: RouterEndpointPauseUpdated;
+    routerEndpointRemoved?: RouterEndpointRemoved;
 };
 
 export type FastOrderPathComposite = {
@@ -416,6 +541,80 @@ export class MatchingEngineProgram {
         return info;
     }
 
+    routerEndpointRegistryAddress(): PublicKey {
+        return RouterEndpointRegistry.address(this.ID);
+    }
+
+    async fetchRouterEndpointRegistry(input?: {
+        address: PublicKey;
+    }): Promise<RouterEndpointRegistry> {
+        const addr = input === undefined ? this.routerEndpointRegistryAddress() : input.address;
+        return this.program.account.routerEndpointRegistry.fetch(addr);
+    }
+
+    inboundNotionalAddress(chain: ChainId): PublicKey {
+        return InboundNotional.address(this.ID, chain);
+    }
+
+    async fetchInboundNotional(input: ChainId | { address: PublicKey }): Promise<InboundNotional> {
+        const addr =
+            typeof input == "object" && "address" in input
+                ? input.address
+                : this.inboundNotionalAddress(input);
+        return this.program.account.inboundNotional.fetch(addr);
+    }
+
+    circuitBreakerAddress(): PublicKey {
+        return CircuitBreaker.address(this.ID);
+    }
+
+    async fetchCircuitBreaker(input?: { address: PublicKey }): Promise<CircuitBreaker> {
+        const addr = input === undefined ? this.circuitBreakerAddress() : input.address;
+        return this.program.account.circuitBreaker.fetch(addr);
+    }
+
+    supportedMintAddress(mint: PublicKey): PublicKey {
+        return SupportedMint.address(this.ID, mint);
+    }
+
+    async fetchSupportedMint(input: PublicKey | { address: PublicKey }): Promise<SupportedMint> {
+        const addr = "address" in input ? input.address : this.supportedMintAddress(input);
+        return this.program.account.supportedMint.fetch(addr);
+    }
+
+    roleHolderAddress(holder: PublicKey): PublicKey {
+        return RoleHolder.address(this.ID, holder);
+    }
+
+    async fetchRoleHolder(input: PublicKey | { address: PublicKey }): Promise<RoleHolder> {
+        const addr = "address" in input ? input.address : this.roleHolderAddress(input);
+        return this.program.account.roleHolder.fetch(addr);
+    }
+
+    ownerSetAddress(): PublicKey {
+        return OwnerSet.address(this.ID);
+    }
+
+    async fetchOwnerSet(input?: { address: PublicKey }): Promise<OwnerSet> {
+        const addr = input === undefined ? this.ownerSetAddress() : input.address;
+        return this.program.account.ownerSet.fetch(addr);
+    }
+
+    ownerSetTransactionAddress(id: Uint64): PublicKey {
+        return OwnerSetTransaction.address(this.ID, id);
+    }
+
+    async fetchOwnerSetTransaction(
+        input: Uint64 | { address: PublicKey },
+    ): Promise<OwnerSetTransaction> {
+        const addr =
+            typeof input == "object" && "address" in input
+                ? input.address
+                : this.ownerSetTransactionAddress(input);
+        // @ts-ignore This is BS. This is correct.
+        return this.program.account.ownerSetTransaction.fetch(addr);
+    }
+
     auctionAddress(vaaHash: VaaHash): PublicKey {
         return Auction.address(this.ID, vaaHash);
     }
@@ -484,6 +683,27 @@ export class MatchingEngineProgram {
         )[0];
     }
 
+    auctionDestinationCustodyTokenAddress(auction: PublicKey): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("auction-destination-custody"), auction.toBuffer()],
+            this.ID,
+        )[0];
+    }
+
+    fastFillEscrowTokenAddress(redeemer: PublicKey, mint: PublicKey): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("fast-fill-escrow"), redeemer.toBuffer(), mint.toBuffer()],
+            this.ID,
+        )[0];
+    }
+
+    nttOutboxItemAddress(auction: PublicKey): PublicKey {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("ntt-outbox"), auction.toBuffer()],
+            this.ID,
+        )[0];
+    }
+
     async fetchAuctionCustodyTokenBalance(auction: PublicKey): Promise<bigint> {
         return splToken
             .getAccount(this.program.provider.connection, this.auctionCustodyTokenAddress(auction))
@@ -491,21 +711,25 @@ export class MatchingEngineProgram {
             .catch((_) => 0n);
     }
 
-    localCustodyTokenAddress(sourceChain: ChainId): PublicKey {
+    /// Local custody token accounts are keyed by chain and mint. USDC local custody token accounts
+    /// keep their legacy seeds, which omit the mint.
+    localCustodyTokenAddress(sourceChain: ChainId, mint?: PublicKey): PublicKey {
         const encodedSourceChain = Buffer.alloc(2);
         encodedSourceChain.writeUInt16BE(sourceChain);
 
-        return PublicKey.findProgramAddressSync(
-            [Buffer.from("local-custody"), encodedSourceChain],
-            this.ID,
-        )[0];
+        const seeds = [Buffer.from("local-custody"), encodedSourceChain];
+        if (mint !== undefined && !mint.equals(this.mint)) {
+            seeds.push(mint.toBuffer());
+        }
+
+        return PublicKey.findProgramAddressSync(seeds, this.ID)[0];
     }
 
-    async fetchLocalCustodyTokenBalance(sourceChain: ChainId): Promise<bigint> {
+    async fetchLocalCustodyTokenBalance(sourceChain: ChainId, mint?: PublicKey): Promise<bigint> {
         return splToken
             .getAccount(
                 this.program.provider.connection,
-                this.localCustodyTokenAddress(sourceChain),
+                this.localCustodyTokenAddress(sourceChain, mint),
             )
             .then((token) => token.amount)
             .catch((_) => 0n);
@@ -689,13 +913,14 @@ export class MatchingEngineProgram {
         accounts: {
             auction: PublicKey;
             owner: PublicKey;
+            mint?: PublicKey;
         },
         amounts: {
             offerPrice: Uint64;
             totalDeposit: Uint64;
         },
     ): Promise<{ transferAuthority: PublicKey; ix: TransactionInstruction }> {
-        const { auction, owner } = accounts;
+        const { auction, owner, mint } = accounts;
         const { offerPrice, totalDeposit } = amounts;
 
         const transferAuthority = this.transferAuthorityAddress(auction, offerPrice);
@@ -703,7 +928,7 @@ export class MatchingEngineProgram {
         return {
             transferAuthority,
             ix: splToken.createApproveInstruction(
-                splToken.getAssociatedTokenAddressSync(this.mint, owner),
+                splToken.getAssociatedTokenAddressSync(mint ?? this.mint, owner),
                 transferAuthority,
                 owner,
                 uint64ToBigInt(totalDeposit),
@@ -782,6 +1007,62 @@ export class MatchingEngineProgram {
         return { owner, custodian: custodian ?? this.custodianAddress() };
     }
 
+    async roleComposite(
+        authority: PublicKey,
+        opts: { custodian?: PublicKey; roleHolder?: PublicKey | null } = {},
+    ): Promise<{
+        authority: PublicKey;
+        custodian: { custodian: PublicKey };
+        roleHolder: PublicKey | null;
+    }> {
+        const { custodian } = opts;
+        return {
+            authority,
+            custodian: this.checkedCustodianComposite(custodian),
+            roleHolder: await this.existingRoleHolder(authority, opts.roleHolder),
+        };
+    }
+
+    async roleMutComposite(
+        authority: PublicKey,
+        opts: { custodian?: PublicKey; roleHolder?: PublicKey | null } = {},
+    ): Promise<{ authority: PublicKey; custodian: PublicKey; roleHolder: PublicKey | null }> {
+        const { custodian } = opts;
+        return {
+            authority,
+            custodian: custodian ?? this.custodianAddress(),
+            roleHolder: await this.existingRoleHolder(authority, opts.roleHolder),
+        };
+    }
+
+    /// Role holder accounts are optional. If one is not specified, the authority's role holder is
+    /// only used if it exists.
+    private async existingRoleHolder(
+        authority: PublicKey,
+        roleHolder?: PublicKey | null,
+    ): Promise<PublicKey | null> {
+        if (roleHolder !== undefined) {
+            return roleHolder;
+        }
+
+        const addr = this.roleHolderAddress(authority);
+        const accInfo = await this.program.provider.connection.getAccountInfo(addr);
+        return accInfo === null ? null : addr;
+    }
+
+    ownerSetMemberComposite(
+        member: PublicKey,
+        ownerSet?: PublicKey,
+    ): { member: PublicKey; ownerSet: PublicKey } {
+        return { member, ownerSet: ownerSet ?? this.ownerSetAddress() };
+    }
+
+    routerEndpointRegistryComposite(addr?: PublicKey): { registry: PublicKey } {
+        return {
+            registry: addr ?? this.routerEndpointRegistryAddress(),
+        };
+    }
+
     routerEndpointComposite(addr: PublicKey): { endpoint: PublicKey } {
         return {
             endpoint: addr,
@@ -800,6 +1081,14 @@ export class MatchingEngineProgram {
         };
     }
 
+    checkedMintComposite(mint?: PublicKey): { mint: PublicKey; supportedMint: PublicKey | null } {
+        mint ??= this.mint;
+        return {
+            mint,
+            supportedMint: mint.equals(this.mint) ? null : this.supportedMintAddress(mint),
+        };
+    }
+
     localTokenRouterComposite(tokenRouterProgram: PublicKey): {
         tokenRouterProgram: PublicKey;
         tokenRouterEmitter: PublicKey;
@@ -926,6 +1215,7 @@ export class MatchingEngineProgram {
                 owner,
                 custodian: this.custodianAddress(),
                 auctionConfig: this.auctionConfigAddress(0),
+                circuitBreaker: this.circuitBreakerAddress(),
                 ownerAssistant,
                 feeRecipient,
                 feeRecipientToken: splToken.getAssociatedTokenAddressSync(this.mint, feeRecipient),
@@ -944,16 +1234,80 @@ export class MatchingEngineProgram {
 
     async setPauseIx(
         accounts: {
-            ownerOrAssistant: PublicKey;
+            authority: PublicKey;
             custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
         },
         paused: boolean,
     ): Promise<TransactionInstruction> {
-        const { ownerOrAssistant, custodian } = accounts;
+        const { authority, custodian, roleHolder } = accounts;
         return this.program.methods
             .setPause(paused)
             .accounts({
-                admin: this.adminMutComposite(ownerOrAssistant, custodian),
+                admin: await this.roleMutComposite(authority, { custodian, roleHolder }),
+            })
+            .instruction();
+    }
+
+    async setStagePauseIx(
+        accounts: {
+            authority: PublicKey;
+            custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
+        },
+        args: SetStagePauseArgs,
+    ): Promise<TransactionInstruction> {
+        const { authority, custodian, roleHolder } = accounts;
+        return this.program.methods
+            .setStagePause(args)
+            .accounts({
+                admin: await this.roleMutComposite(authority, { custodian, roleHolder }),
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async grantRoleIx(
+        accounts: {
+            owner: PublicKey;
+            holder: PublicKey;
+            payer?: PublicKey;
+            custodian?: PublicKey;
+        },
+        role: Role,
+    ): Promise<TransactionInstruction> {
+        const { owner, holder, custodian } = accounts;
+
+        let { payer } = accounts;
+        payer ??= owner;
+
+        return this.program.methods
+            .grantRole(role)
+            .accounts({
+                payer,
+                admin: this.ownerOnlyComposite(owner, custodian),
+                holder,
+                roleHolder: this.roleHolderAddress(holder),
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    async revokeRoleIx(
+        accounts: {
+            owner: PublicKey;
+            holder: PublicKey;
+            custodian?: PublicKey;
+        },
+        role: Role,
+    ): Promise<TransactionInstruction> {
+        const { owner, holder, custodian } = accounts;
+        return this.program.methods
+            .revokeRole(role)
+            .accounts({
+                admin: this.ownerOnlyComposite(owner, custodian),
+                roleHolder: this.roleHolderAddress(holder),
             })
             .instruction();
     }
@@ -1014,117 +1368,417 @@ export class MatchingEngineProgram {
             .instruction();
     }
 
-    async addCctpRouterEndpointIx(
+    async acceptOwnerAssistantIx(accounts: {
+        pendingOwnerAssistant: PublicKey;
+        custodian?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { pendingOwnerAssistant } = accounts;
+
+        let { custodian } = accounts;
+        custodian ??= this.custodianAddress();
+
+        return this.program.methods
+            .acceptOwnerAssistant()
+            .accounts({ pendingOwnerAssistant, custodian })
+            .instruction();
+    }
+
+    async initializeOwnerSetIx(
         accounts: {
-            ownerOrAssistant: PublicKey;
+            owner: PublicKey;
             payer?: PublicKey;
             custodian?: PublicKey;
-            routerEndpoint?: PublicKey;
-            remoteTokenMessenger?: PublicKey;
         },
-        args: AddCctpRouterEndpointArgs,
+        args: OwnerSetArgs,
     ): Promise<TransactionInstruction> {
-        const { ownerOrAssistant, custodian } = accounts;
-        const { chain, cctpDomain } = args;
+        const { owner, custodian } = accounts;
 
-        let { payer, routerEndpoint, remoteTokenMessenger } = accounts;
-        payer ??= ownerOrAssistant;
-        routerEndpoint ??= this.routerEndpointAddress(chain);
-        remoteTokenMessenger ??=
-            this.tokenMessengerMinterProgram().remoteTokenMessengerAddress(cctpDomain);
+        let { payer } = accounts;
+        payer ??= owner;
 
         return this.program.methods
-            .addCctpRouterEndpoint(args)
+            .initializeOwnerSet(args)
             .accounts({
                 payer,
-                admin: this.adminComposite(ownerOrAssistant, custodian),
-                routerEndpoint,
-                localCustodyToken: this.localCustodyTokenAddress(chain),
-                remoteTokenMessenger,
-                usdc: this.usdcComposite(),
-                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                admin: this.ownerOnlyComposite(owner, custodian),
+                ownerSet: this.ownerSetAddress(),
                 systemProgram: SystemProgram.programId,
             })
             .instruction();
     }
 
-    async updateCctpRouterEndpointIx(
+    /// Approvals from former members are dropped from the specified pending transactions.
+    async updateOwnerSetIx(
         accounts: {
             owner: PublicKey;
             custodian?: PublicKey;
-            routerEndpoint?: PublicKey;
-            remoteTokenMessenger?: PublicKey;
+            pendingTransactions?: PublicKey[];
         },
-        args: AddCctpRouterEndpointArgs,
+        args: OwnerSetArgs,
     ): Promise<TransactionInstruction> {
         const { owner, custodian } = accounts;
-        const { chain, cctpDomain } = args;
 
-        let { routerEndpoint, remoteTokenMessenger } = accounts;
-        routerEndpoint ??= this.routerEndpointAddress(chain);
-        remoteTokenMessenger ??=
-            this.tokenMessengerMinterProgram().remoteTokenMessengerAddress(cctpDomain);
+        let { pendingTransactions } = accounts;
+        pendingTransactions ??= [];
 
         return this.program.methods
-            .updateCctpRouterEndpoint(args)
+            .updateOwnerSet(args)
             .accounts({
                 admin: this.ownerOnlyComposite(owner, custodian),
-                routerEndpoint: this.routerEndpointComposite(routerEndpoint),
-                remoteTokenMessenger,
+                ownerSet: this.ownerSetAddress(),
             })
+            .remainingAccounts(
+                pendingTransactions.map((pubkey) => ({
+                    pubkey,
+                    isSigner: false,
+                    isWritable: true,
+                })),
+            )
             .instruction();
     }
 
-    async proposeAuctionParametersIx(
+    async proposeOwnerSetTransactionIx(
         accounts: {
-            ownerOrAssistant: PublicKey;
+            member: PublicKey;
             payer?: PublicKey;
-            custodian?: PublicKey;
-            proposal?: PublicKey;
+            transaction?: PublicKey;
         },
-        parameters: AuctionParameters,
-        opts: {
-            proposalId?: Uint64;
-        } = {},
+        args: OwnerSetTransactionArgs,
     ): Promise<TransactionInstruction> {
-        const { ownerOrAssistant, custodian } = accounts;
+        const { member } = accounts;
 
-        let { payer, proposal } = accounts;
-        payer ??= ownerOrAssistant;
-        proposal ??= await this.proposalAddress(opts.proposalId);
+        let { payer, transaction } = accounts;
+        payer ??= member;
+
+        if (transaction === undefined) {
+            const { nextTransactionId } = await this.fetchOwnerSet();
+            transaction = this.ownerSetTransactionAddress(nextTransactionId);
+        }
 
         return this.program.methods
-            .proposeAuctionParameters(parameters)
+            .proposeOwnerSetTransaction(args)
             .accounts({
                 payer,
-                admin: {
-                    ownerOrAssistant,
-                    custodian: this.checkedCustodianComposite(custodian),
-                },
-                proposal,
-                epochSchedule: SYSVAR_EPOCH_SCHEDULE_PUBKEY,
+                ownerSetMember: this.ownerSetMemberComposite(member),
+                transaction,
                 systemProgram: SystemProgram.programId,
-                eventAuthority: this.eventAuthorityAddress(),
-                program: this.ID,
             })
             .instruction();
     }
 
-    async closeProposalIx(
-        accounts: {
-            ownerOrAssistant: PublicKey;
-            proposal?: PublicKey;
-        },
-        opts: {
-            proposalId?: Uint64;
-        } = {},
-    ): Promise<TransactionInstruction> {
-        const { ownerOrAssistant } = accounts;
-
-        let { proposal } = accounts;
-        proposal ??= await this.proposalAddress(opts.proposalId);
-
-        const { by: proposedBy } = await this.fetchProposal({ address: proposal });
+    async approveOwnerSetTransactionIx(accounts: {
+        member: PublicKey;
+        transaction: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { member, transaction } = accounts;
+        return this.program.methods
+            .approveOwnerSetTransaction()
+            .accounts({
+                ownerSetMember: this.ownerSetMemberComposite(member),
+                transaction,
+            })
+            .instruction();
+    }
+
+    async cancelOwnerSetTransactionIx(accounts: {
+        proposedBy: PublicKey;
+        transaction: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { proposedBy, transaction } = accounts;
+        return this.program.methods
+            .cancelOwnerSetTransaction()
+            .accounts({ proposedBy, transaction })
+            .instruction();
+    }
+
+    async executeOwnerSetTransactionIx(accounts: {
+        transaction: PublicKey;
+        proposedBy?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { transaction } = accounts;
+
+        let { proposedBy } = accounts;
+
+        const { proposedBy: fetchedProposedBy, accounts: metas } =
+            await this.fetchOwnerSetTransaction({ address: transaction });
+        proposedBy ??= fetchedProposedBy;
+
+        // The owner set signs for the proposed instruction, so it cannot be a signer here.
+        const ownerSet = this.ownerSetAddress();
+        return this.program.methods
+            .executeOwnerSetTransaction()
+            .accounts({
+                ownerSet,
+                proposedBy,
+                transaction,
+                matchingEngineProgram: this.ID,
+            })
+            .remainingAccounts(
+                metas.map(({ pubkey, isWritable }) => ({
+                    pubkey,
+                    isSigner: false,
+                    isWritable,
+                })),
+            )
+            .instruction();
+    }
+
+    async addCctpRouterEndpointIx(
+        accounts: {
+            authority: PublicKey;
+            payer?: PublicKey;
+            custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
+            routerEndpoint?: PublicKey;
+            remoteTokenMessenger?: PublicKey;
+            mint?: PublicKey;
+        },
+        args: AddCctpRouterEndpointArgs,
+    ): Promise<TransactionInstruction> {
+        const { authority, custodian, roleHolder, mint } = accounts;
+        const { chain, cctpDomain } = args;
+
+        let { payer, routerEndpoint, remoteTokenMessenger } = accounts;
+        payer ??= authority;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+        remoteTokenMessenger ??=
+            this.tokenMessengerMinterProgram().remoteTokenMessengerAddress(cctpDomain);
+
+        return this.program.methods
+            .addCctpRouterEndpoint(args)
+            .accounts({
+                payer,
+                admin: await this.roleComposite(authority, { custodian, roleHolder }),
+                routerEndpoint,
+                routerEndpointRegistry: this.routerEndpointRegistryAddress(),
+                inboundNotional: this.inboundNotionalAddress(chain),
+                mint: this.checkedMintComposite(mint),
+                localCustodyToken: this.localCustodyTokenAddress(chain, mint),
+                remoteTokenMessenger,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    async updateCctpRouterEndpointIx(
+        accounts: {
+            owner: PublicKey;
+            custodian?: PublicKey;
+            routerEndpoint?: PublicKey;
+            remoteTokenMessenger?: PublicKey;
+        },
+        args: AddCctpRouterEndpointArgs,
+        opts: UpdateRouterEndpointOpts = {},
+    ): Promise<TransactionInstruction> {
+        const { owner, custodian } = accounts;
+        const { chain, cctpDomain } = args;
+        const { previousAddressExpirySlot } = opts;
+
+        let { routerEndpoint, remoteTokenMessenger } = accounts;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+        remoteTokenMessenger ??=
+            this.tokenMessengerMinterProgram().remoteTokenMessengerAddress(cctpDomain);
+
+        return this.program.methods
+            .updateCctpRouterEndpoint({
+                endpoint: args,
+                previousAddressExpirySlot:
+                    previousAddressExpirySlot === undefined
+                        ? null
+                        : uint64ToBN(previousAddressExpirySlot),
+            })
+            .accounts({
+                admin: this.ownerOnlyComposite(owner, custodian),
+                routerEndpoint: this.routerEndpointComposite(routerEndpoint),
+                routerEndpointRegistry: this.routerEndpointRegistryComposite(),
+                remoteTokenMessenger,
+            })
+            .instruction();
+    }
+
+    async addTokenBridgeRouterEndpointIx(
+        accounts: {
+            authority: PublicKey;
+            mint: PublicKey;
+            payer?: PublicKey;
+            custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
+            routerEndpoint?: PublicKey;
+        },
+        args: AddTokenBridgeRouterEndpointArgs,
+    ): Promise<TransactionInstruction> {
+        const { authority, mint, custodian, roleHolder } = accounts;
+        const { chain } = args;
+
+        let { payer, routerEndpoint } = accounts;
+        payer ??= authority;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+
+        return this.program.methods
+            .addTokenBridgeRouterEndpoint(args)
+            .accounts({
+                payer,
+                admin: await this.roleComposite(authority, { custodian, roleHolder }),
+                routerEndpoint,
+                routerEndpointRegistry: this.routerEndpointRegistryAddress(),
+                inboundNotional: this.inboundNotionalAddress(chain),
+                mint: this.checkedMintComposite(mint),
+                localCustodyToken: this.localCustodyTokenAddress(chain, mint),
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    async updateTokenBridgeRouterEndpointIx(
+        accounts: {
+            owner: PublicKey;
+            custodian?: PublicKey;
+            routerEndpoint?: PublicKey;
+        },
+        args: AddTokenBridgeRouterEndpointArgs,
+        opts: UpdateRouterEndpointOpts = {},
+    ): Promise<TransactionInstruction> {
+        const { owner, custodian } = accounts;
+        const { chain } = args;
+        const { previousAddressExpirySlot } = opts;
+
+        let { routerEndpoint } = accounts;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+
+        return this.program.methods
+            .updateTokenBridgeRouterEndpoint({
+                endpoint: args,
+                previousAddressExpirySlot:
+                    previousAddressExpirySlot === undefined
+                        ? null
+                        : uint64ToBN(previousAddressExpirySlot),
+            })
+            .accounts({
+                admin: this.ownerOnlyComposite(owner, custodian),
+                routerEndpoint: this.routerEndpointComposite(routerEndpoint),
+                routerEndpointRegistry: this.routerEndpointRegistryComposite(),
+            })
+            .instruction();
+    }
+
+    async addNttRouterEndpointIx(
+        accounts: {
+            authority: PublicKey;
+            mint: PublicKey;
+            payer?: PublicKey;
+            custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
+            routerEndpoint?: PublicKey;
+        },
+        args: AddNttRouterEndpointArgs,
+    ): Promise<TransactionInstruction> {
+        const { authority, mint, custodian, roleHolder } = accounts;
+        const { chain } = args;
+
+        let { payer, routerEndpoint } = accounts;
+        payer ??= authority;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+
+        return this.program.methods
+            .addNttRouterEndpoint(args)
+            .accounts({
+                payer,
+                admin: await this.roleComposite(authority, { custodian, roleHolder }),
+                routerEndpoint,
+                routerEndpointRegistry: this.routerEndpointRegistryAddress(),
+                inboundNotional: this.inboundNotionalAddress(chain),
+                mint: this.checkedMintComposite(mint),
+                localCustodyToken: this.localCustodyTokenAddress(chain, mint),
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    async updateNttRouterEndpointIx(
+        accounts: {
+            owner: PublicKey;
+            custodian?: PublicKey;
+            routerEndpoint?: PublicKey;
+        },
+        args: AddNttRouterEndpointArgs,
+        opts: UpdateRouterEndpointOpts = {},
+    ): Promise<TransactionInstruction> {
+        const { owner, custodian } = accounts;
+        const { chain } = args;
+        const { previousAddressExpirySlot } = opts;
+
+        let { routerEndpoint } = accounts;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+
+        return this.program.methods
+            .updateNttRouterEndpoint({
+                endpoint: args,
+                previousAddressExpirySlot:
+                    previousAddressExpirySlot === undefined
+                        ? null
+                        : uint64ToBN(previousAddressExpirySlot),
+            })
+            .accounts({
+                admin: this.ownerOnlyComposite(owner, custodian),
+                routerEndpoint: this.routerEndpointComposite(routerEndpoint),
+                routerEndpointRegistry: this.routerEndpointRegistryComposite(),
+            })
+            .instruction();
+    }
+
+    async proposeAuctionParametersIx(
+        accounts: {
+            ownerOrAssistant: PublicKey;
+            payer?: PublicKey;
+            custodian?: PublicKey;
+            proposal?: PublicKey;
+        },
+        parameters: AuctionParameters,
+        opts: {
+            proposalId?: Uint64;
+        } = {},
+    ): Promise<TransactionInstruction> {
+        const { ownerOrAssistant, custodian } = accounts;
+
+        let { payer, proposal } = accounts;
+        payer ??= ownerOrAssistant;
+        proposal ??= await this.proposalAddress(opts.proposalId);
+
+        return this.program.methods
+            .proposeAuctionParameters(parameters)
+            .accounts({
+                payer,
+                admin: {
+                    ownerOrAssistant,
+                    custodian: this.checkedCustodianComposite(custodian),
+                },
+                proposal,
+                epochSchedule: SYSVAR_EPOCH_SCHEDULE_PUBKEY,
+                systemProgram: SystemProgram.programId,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async closeProposalIx(
+        accounts: {
+            ownerOrAssistant: PublicKey;
+            proposal?: PublicKey;
+        },
+        opts: {
+            proposalId?: Uint64;
+        } = {},
+    ): Promise<TransactionInstruction> {
+        const { ownerOrAssistant } = accounts;
+
+        let { proposal } = accounts;
+        proposal ??= await this.proposalAddress(opts.proposalId);
+
+        const { by: proposedBy } = await this.fetchProposal({ address: proposal });
 
         return this.program.methods
             .closeProposal()
@@ -1175,24 +1829,27 @@ export class MatchingEngineProgram {
     }
 
     async addLocalRouterEndpointIx(accounts: {
-        ownerOrAssistant: PublicKey;
+        authority: PublicKey;
         tokenRouterProgram: PublicKey;
         payer?: PublicKey;
         custodian?: PublicKey;
+        roleHolder?: PublicKey | null;
         routerEndpoint?: PublicKey;
     }): Promise<TransactionInstruction> {
-        const { ownerOrAssistant, tokenRouterProgram, custodian } = accounts;
+        const { authority, tokenRouterProgram, custodian, roleHolder } = accounts;
 
         let { payer, routerEndpoint } = accounts;
-        payer ??= ownerOrAssistant;
+        payer ??= authority;
         routerEndpoint ??= this.routerEndpointAddress(toChainId("Solana"));
 
         return this.program.methods
             .addLocalRouterEndpoint()
             .accounts({
                 payer,
-                admin: this.adminComposite(ownerOrAssistant, custodian),
+                admin: await this.roleComposite(authority, { custodian, roleHolder }),
                 routerEndpoint,
+                routerEndpointRegistry: this.routerEndpointRegistryAddress(),
+                inboundNotional: this.inboundNotionalAddress(toChainId("Solana")),
                 local: this.localTokenRouterComposite(tokenRouterProgram),
                 systemProgram: SystemProgram.programId,
             })
@@ -1215,6 +1872,7 @@ export class MatchingEngineProgram {
             .accounts({
                 admin: this.ownerOnlyComposite(owner, custodian),
                 routerEndpoint: this.routerEndpointComposite(routerEndpoint),
+                routerEndpointRegistry: this.routerEndpointRegistryComposite(),
                 local: this.localTokenRouterComposite(tokenRouterProgram),
             })
             .instruction();
@@ -1238,48 +1896,276 @@ export class MatchingEngineProgram {
             .accounts({
                 admin: this.ownerOnlyComposite(owner, custodian),
                 routerEndpoint: this.routerEndpointComposite(routerEndpoint),
+                routerEndpointRegistry: this.routerEndpointRegistryComposite(),
             })
             .instruction();
     }
 
-    async updateFeeRecipientIx(accounts: {
-        ownerOrAssistant: PublicKey;
-        newFeeRecipient: PublicKey;
-        custodian?: PublicKey;
-    }): Promise<TransactionInstruction> {
-        const { ownerOrAssistant, newFeeRecipient, custodian } = accounts;
+    async removeRouterEndpointIx(
+        accounts: {
+            owner: PublicKey;
+            custodian?: PublicKey;
+            routerEndpoint?: PublicKey;
+        },
+        chain: ChainId,
+    ): Promise<TransactionInstruction> {
+        const { owner, custodian } = accounts;
+
+        let { routerEndpoint } = accounts;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+
+        const { mint } = await this.fetchRouterEndpoint({ address: routerEndpoint });
 
         return this.program.methods
-            .updateFeeRecipient()
+            .removeRouterEndpoint()
             .accounts({
-                admin: this.adminMutComposite(ownerOrAssistant, custodian),
-                newFeeRecipient,
-                newFeeRecipientToken: splToken.getAssociatedTokenAddressSync(
-                    this.mint,
-                    newFeeRecipient,
-                ),
+                owner,
+                custodian: this.checkedCustodianComposite(custodian),
+                routerEndpoint,
+                inboundNotional: this.inboundNotionalAddress(chain),
+                localCustodyToken: this.localCustodyTokenAddress(chain, mint),
+                routerEndpointRegistry: this.routerEndpointRegistryComposite(),
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
             })
             .instruction();
     }
 
-    async fetchCctpMintRecipient(): Promise<splToken.Account> {
-        return splToken.getAccount(
-            this.program.provider.connection,
-            this.cctpMintRecipientAddress(),
-        );
-    }
-
-    async placeInitialOfferTx(
+    async migrateRouterEndpointIx(
         accounts: {
             payer: PublicKey;
-            fastVaa: PublicKey;
-            fromRouterEndpoint: PublicKey;
-            toRouterEndpoint: PublicKey;
-            auction: PublicKey;
-        },
-        args: {
-            offerPrice: Uint64;
-            totalDeposit: Uint64;
+            routerEndpoint?: PublicKey;
+        },
+        chain: ChainId,
+    ): Promise<TransactionInstruction> {
+        const { payer } = accounts;
+
+        let { routerEndpoint } = accounts;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+
+        return this.program.methods
+            .migrateRouterEndpoint(chain)
+            .accounts({
+                payer,
+                routerEndpoint,
+                inboundNotional: this.inboundNotionalAddress(chain),
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    async setRouterEndpointPauseIx(
+        accounts: {
+            authority: PublicKey;
+            custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
+            routerEndpoint?: PublicKey;
+        },
+        chain: ChainId,
+        args: SetRouterEndpointPauseArgs,
+    ): Promise<TransactionInstruction> {
+        const { authority, custodian, roleHolder } = accounts;
+
+        let { routerEndpoint } = accounts;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+
+        return this.program.methods
+            .setRouterEndpointPause(args)
+            .accounts({
+                admin: await this.roleMutComposite(authority, { custodian, roleHolder }),
+                routerEndpoint: this.routerEndpointComposite(routerEndpoint),
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async setRouterEndpointInboundLimitIx(
+        accounts: {
+            authority: PublicKey;
+            custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
+            routerEndpoint?: PublicKey;
+        },
+        chain: ChainId,
+        args: SetRouterEndpointInboundLimitArgs,
+    ): Promise<TransactionInstruction> {
+        const { authority, custodian, roleHolder } = accounts;
+
+        let { routerEndpoint } = accounts;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+
+        return this.program.methods
+            .setRouterEndpointInboundLimit(args)
+            .accounts({
+                admin: await this.roleComposite(authority, { custodian, roleHolder }),
+                routerEndpoint: this.routerEndpointComposite(routerEndpoint),
+                inboundNotional: this.inboundNotionalAddress(chain),
+            })
+            .instruction();
+    }
+
+    async setRouterEndpointLimitsIx(
+        accounts: {
+            authority: PublicKey;
+            custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
+            routerEndpoint?: PublicKey;
+        },
+        chain: ChainId,
+        limits: EndpointLimits,
+    ): Promise<TransactionInstruction> {
+        const { authority, custodian, roleHolder } = accounts;
+
+        let { routerEndpoint } = accounts;
+        routerEndpoint ??= this.routerEndpointAddress(chain);
+
+        return this.program.methods
+            .setRouterEndpointLimits(limits)
+            .accounts({
+                admin: await this.roleComposite(authority, { custodian, roleHolder }),
+                routerEndpoint: this.routerEndpointComposite(routerEndpoint),
+            })
+            .instruction();
+    }
+
+    async updateRouterEndpointRemovalDelayIx(
+        accounts: {
+            owner: PublicKey;
+            custodian?: PublicKey;
+        },
+        delay: Uint64,
+    ): Promise<TransactionInstruction> {
+        const { owner, custodian } = accounts;
+        return this.program.methods
+            .updateRouterEndpointRemovalDelay(uint64ToBN(delay))
+            .accounts({
+                admin: this.ownerOnlyMutComposite(owner, custodian),
+            })
+            .instruction();
+    }
+
+    async updateCircuitBreakerIx(
+        accounts: {
+            owner: PublicKey;
+            custodian?: PublicKey;
+        },
+        args: UpdateCircuitBreakerArgs,
+    ): Promise<TransactionInstruction> {
+        const { owner, custodian } = accounts;
+        return this.program.methods
+            .updateCircuitBreaker(args)
+            .accounts({
+                admin: this.ownerOnlyMutComposite(owner, custodian),
+            })
+            .instruction();
+    }
+
+    async updateMaxGasDropoffIx(
+        accounts: {
+            authority: PublicKey;
+            custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
+        },
+        maxGasDropoff: Uint64,
+    ): Promise<TransactionInstruction> {
+        const { authority, custodian, roleHolder } = accounts;
+        return this.program.methods
+            .updateMaxGasDropoff(uint64ToBN(maxGasDropoff))
+            .accounts({
+                admin: await this.roleMutComposite(authority, { custodian, roleHolder }),
+            })
+            .instruction();
+    }
+
+    async addSupportedMintIx(accounts: {
+        owner: PublicKey;
+        mint: PublicKey;
+        feeRecipient: PublicKey;
+        payer?: PublicKey;
+        custodian?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { owner, mint, feeRecipient, custodian } = accounts;
+
+        let { payer } = accounts;
+        payer ??= owner;
+
+        return this.program.methods
+            .addSupportedMint()
+            .accounts({
+                payer,
+                admin: this.ownerOnlyComposite(owner, custodian),
+                mint,
+                supportedMint: this.supportedMintAddress(mint),
+                feeRecipientToken: splToken.getAssociatedTokenAddressSync(mint, feeRecipient),
+                cctpMintRecipient: splToken.getAssociatedTokenAddressSync(
+                    mint,
+                    this.custodianAddress(),
+                    true,
+                ),
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    async removeSupportedMintIx(accounts: {
+        owner: PublicKey;
+        mint: PublicKey;
+        custodian?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { owner, mint, custodian } = accounts;
+        return this.program.methods
+            .removeSupportedMint()
+            .accounts({
+                owner,
+                custodian: this.checkedCustodianComposite(custodian),
+                supportedMint: this.supportedMintAddress(mint),
+            })
+            .instruction();
+    }
+
+    async updateFeeRecipientIx(accounts: {
+        authority: PublicKey;
+        newFeeRecipient: PublicKey;
+        custodian?: PublicKey;
+        roleHolder?: PublicKey | null;
+    }): Promise<TransactionInstruction> {
+        const { authority, newFeeRecipient, custodian, roleHolder } = accounts;
+
+        return this.program.methods
+            .updateFeeRecipient()
+            .accounts({
+                admin: await this.roleMutComposite(authority, { custodian, roleHolder }),
+                newFeeRecipient,
+                newFeeRecipientToken: splToken.getAssociatedTokenAddressSync(
+                    this.mint,
+                    newFeeRecipient,
+                ),
+            })
+            .instruction();
+    }
+
+    async fetchCctpMintRecipient(): Promise<splToken.Account> {
+        return splToken.getAccount(
+            this.program.provider.connection,
+            this.cctpMintRecipientAddress(),
+        );
+    }
+
+    async placeInitialOfferTx(
+        accounts: {
+            payer: PublicKey;
+            fastVaa: PublicKey;
+            fromRouterEndpoint: PublicKey;
+            toRouterEndpoint: PublicKey;
+            auction: PublicKey;
+        },
+        args: {
+            offerPrice: Uint64;
+            totalDeposit: Uint64;
         },
         signers: Signer[],
         opts: PreparedTransactionOptions,
@@ -1320,6 +2206,8 @@ export class MatchingEngineProgram {
             auctionConfig?: PublicKey;
             fromRouterEndpoint?: PublicKey;
             toRouterEndpoint?: PublicKey;
+            inboundNotional?: PublicKey | null;
+            mint?: PublicKey;
         },
         args: {
             offerPrice: Uint64;
@@ -1328,15 +2216,27 @@ export class MatchingEngineProgram {
     ): Promise<
         [approveIx: TransactionInstruction, placeInitialOfferCctpIx: TransactionInstruction]
     > {
-        const { payer, feePayer, fastVaa } = accounts;
+        const { payer, feePayer, fastVaa, mint } = accounts;
 
         const { offerPrice } = args;
 
-        let { auction, auctionConfig, offerToken, fromRouterEndpoint, toRouterEndpoint } = accounts;
+        let {
+            auction,
+            auctionConfig,
+            offerToken,
+            fromRouterEndpoint,
+            toRouterEndpoint,
+            inboundNotional,
+        } = accounts;
         let { totalDeposit } = args;
 
-        offerToken ??= await splToken.getAssociatedTokenAddress(this.mint, payer);
+        offerToken ??= await splToken.getAssociatedTokenAddress(mint ?? this.mint, payer);
         let fetchedConfigId: Uint64 | null = null;
+        if (inboundNotional === undefined) {
+            const vaaAccount = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
+            inboundNotional = this.inboundNotionalAddress(vaaAccount.emitterInfo().chain);
+        }
+
         if (
             auction === undefined ||
             fromRouterEndpoint === undefined ||
@@ -1373,7 +2273,7 @@ export class MatchingEngineProgram {
 
         const auctionCustodyToken = this.auctionCustodyTokenAddress(auction);
         const { transferAuthority, ix: approveIx } = await this.approveTransferAuthorityIx(
-            { auction, owner: payer },
+            { auction, owner: payer, mint },
             {
                 totalDeposit,
                 offerPrice,
@@ -1392,9 +2292,11 @@ export class MatchingEngineProgram {
                     fromEndpoint: fromRouterEndpoint,
                     toEndpoint: toRouterEndpoint,
                 }),
+                inboundNotional,
+                circuitBreaker: this.circuitBreakerAddress(),
                 offerToken,
                 auctionCustodyToken,
-                usdc: this.usdcComposite(),
+                mint: this.checkedMintComposite(mint),
                 tokenProgram: splToken.TOKEN_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
                 eventAuthority: this.eventAuthorityAddress(),
@@ -1405,6 +2307,115 @@ export class MatchingEngineProgram {
         return [approveIx, placeInitialOfferCctpIx];
     }
 
+    async placeInitialOfferDestinationAssetIx(
+        accounts: {
+            payer: PublicKey;
+            fastVaa: PublicKey;
+            destinationMint: PublicKey;
+            offerToken?: PublicKey;
+            destinationOfferToken?: PublicKey;
+            auction?: PublicKey;
+            auctionConfig?: PublicKey;
+            fromRouterEndpoint?: PublicKey;
+            toRouterEndpoint?: PublicKey;
+        },
+        args: {
+            amountOut: Uint64;
+            securityDeposit?: Uint64;
+        },
+    ): Promise<
+        [
+            approveIx: TransactionInstruction,
+            approveDestinationIx: TransactionInstruction,
+            placeInitialOfferDestinationAssetIx: TransactionInstruction,
+        ]
+    > {
+        const { payer, fastVaa, destinationMint } = accounts;
+        const { amountOut } = args;
+
+        let {
+            offerToken,
+            destinationOfferToken,
+            auction,
+            auctionConfig,
+            fromRouterEndpoint,
+            toRouterEndpoint,
+        } = accounts;
+        let { securityDeposit } = args;
+
+        offerToken ??= splToken.getAssociatedTokenAddressSync(this.mint, payer);
+        destinationOfferToken ??= splToken.getAssociatedTokenAddressSync(destinationMint, payer);
+
+        const vaaAccount = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
+        const sourceChain = vaaAccount.emitterInfo().chain;
+        auction ??= this.auctionAddress(vaaAccount.digest());
+        fromRouterEndpoint ??= this.routerEndpointAddress(sourceChain);
+        toRouterEndpoint ??= this.routerEndpointAddress(toChainId("Solana"));
+
+        const { auctionConfigId } = await this.fetchCustodian();
+        auctionConfig ??= this.auctionConfigAddress(auctionConfigId);
+
+        if (securityDeposit === undefined) {
+            const { fastMarketOrder } = LiquidityLayerMessage.decode(vaaAccount.payload());
+            if (fastMarketOrder === undefined) {
+                throw new Error("Message not FastMarketOrder");
+            }
+
+            // The amount in is not deposited when the fast fill is delivered in a destination
+            // asset, so only the security deposit is transferred from the offer token.
+            securityDeposit =
+                fastMarketOrder.maxFee +
+                (await this.computeNotionalSecurityDeposit(
+                    fastMarketOrder.amountIn,
+                    auctionConfigId,
+                ));
+        }
+
+        const transferAuthority = this.transferAuthorityAddress(auction, amountOut);
+        const approveIx = splToken.createApproveInstruction(
+            offerToken,
+            transferAuthority,
+            payer,
+            uint64ToBigInt(securityDeposit),
+        );
+        const approveDestinationIx = splToken.createApproveInstruction(
+            destinationOfferToken,
+            transferAuthority,
+            payer,
+            uint64ToBigInt(amountOut),
+        );
+
+        const placeInitialOfferDestinationAssetIx = await this.program.methods
+            .placeInitialOfferDestinationAsset(uint64ToBN(amountOut))
+            .accounts({
+                payer,
+                transferAuthority,
+                custodian: this.checkedCustodianComposite(),
+                auctionConfig,
+                fastOrderPath: this.fastOrderPathComposite({
+                    fastVaa,
+                    fromEndpoint: fromRouterEndpoint,
+                    toEndpoint: toRouterEndpoint,
+                }),
+                inboundNotional: this.inboundNotionalAddress(sourceChain),
+                circuitBreaker: this.circuitBreakerAddress(),
+                auction,
+                offerToken,
+                auctionCustodyToken: this.auctionCustodyTokenAddress(auction),
+                usdc: this.usdcComposite(),
+                destinationMint: this.checkedMintComposite(destinationMint),
+                destinationOfferToken,
+                destinationCustodyToken: this.auctionDestinationCustodyTokenAddress(auction),
+                systemProgram: SystemProgram.programId,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+
+        return [approveIx, approveDestinationIx, placeInitialOfferDestinationAssetIx];
+    }
+
     async improveOfferTx(
         accounts: {
             participant: PublicKey;
@@ -1450,10 +2461,11 @@ export class MatchingEngineProgram {
             auction: PublicKey;
             auctionConfig?: PublicKey;
             bestOfferToken?: PublicKey;
+            mint?: PublicKey;
         },
         args: { offerPrice: Uint64; totalDeposit?: Uint64 },
     ): Promise<[approveIx: TransactionInstruction, improveOfferIx: TransactionInstruction]> {
-        const { participant, auction, auctionConfig, bestOfferToken } = accounts;
+        const { participant, auction, auctionConfig, bestOfferToken, mint } = accounts;
         const { offerPrice } = args;
 
         let { totalDeposit } = args;
@@ -1469,7 +2481,7 @@ export class MatchingEngineProgram {
         }
 
         const { transferAuthority, ix: approveIx } = await this.approveTransferAuthorityIx(
-            { auction, owner: participant },
+            { auction, owner: participant, mint },
             {
                 offerPrice,
                 totalDeposit,
@@ -1480,11 +2492,12 @@ export class MatchingEngineProgram {
             .improveOffer(uint64ToBN(offerPrice))
             .accounts({
                 transferAuthority,
+                custodian: this.checkedCustodianComposite(),
                 activeAuction: await this.activeAuctionComposite(
                     { auction, config: auctionConfig, bestOfferToken: bestOfferToken },
                     { auctionInfo },
                 ),
-                offerToken: splToken.getAssociatedTokenAddressSync(this.mint, participant),
+                offerToken: splToken.getAssociatedTokenAddressSync(mint ?? this.mint, participant),
                 tokenProgram: splToken.TOKEN_PROGRAM_ID,
                 eventAuthority: this.eventAuthorityAddress(),
                 program: this.ID,
@@ -1494,22 +2507,97 @@ export class MatchingEngineProgram {
         return [approveIx, improveOfferIx];
     }
 
-    async prepareOrderResponseCctpIx(
+    async improveOfferDestinationAssetIx(
         accounts: {
-            payer: PublicKey;
-            fastVaa: PublicKey;
-            finalizedVaa: PublicKey;
-            baseFeeToken?: PublicKey;
+            participant: PublicKey;
+            auction: PublicKey;
+            auctionConfig?: PublicKey;
+            offerToken?: PublicKey;
+            destinationOfferToken?: PublicKey;
         },
-        args: CctpMessageArgs,
-    ): Promise<TransactionInstruction> {
-        const { payer, fastVaa, finalizedVaa } = accounts;
+        args: { amountOut: Uint64 },
+    ): Promise<
+        [
+            approveIx: TransactionInstruction,
+            approveDestinationIx: TransactionInstruction,
+            improveOfferDestinationAssetIx: TransactionInstruction,
+        ]
+    > {
+        const { participant, auction, auctionConfig } = accounts;
+        const { amountOut } = args;
 
-        let { baseFeeToken } = accounts;
-        baseFeeToken ??= await splToken.getAssociatedTokenAddress(this.mint, payer);
+        let { offerToken, destinationOfferToken } = accounts;
 
-        const fastVaaAcct = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
-        const fromEndpoint = this.routerEndpointAddress(fastVaaAcct.emitterInfo().chain);
+        const { info } = await this.fetchAuction({ address: auction });
+        if (info === null) {
+            throw new Error("no auction info found");
+        }
+        if (info.destinationAssetInfo === null) {
+            throw new Error("auction not in destination asset");
+        }
+        const { mint: destinationMint, bestOfferToken: destinationBestOfferToken } =
+            info.destinationAssetInfo;
+
+        offerToken ??= splToken.getAssociatedTokenAddressSync(this.mint, participant);
+        destinationOfferToken ??= splToken.getAssociatedTokenAddressSync(
+            destinationMint,
+            participant,
+        );
+
+        const transferAuthority = this.transferAuthorityAddress(auction, amountOut);
+        const approveIx = splToken.createApproveInstruction(
+            offerToken,
+            transferAuthority,
+            participant,
+            BigInt(info.securityDeposit.toString()),
+        );
+        const approveDestinationIx = splToken.createApproveInstruction(
+            destinationOfferToken,
+            transferAuthority,
+            participant,
+            uint64ToBigInt(amountOut),
+        );
+
+        const improveOfferDestinationAssetIx = await this.program.methods
+            .improveOfferDestinationAsset(uint64ToBN(amountOut))
+            .accounts({
+                transferAuthority,
+                custodian: this.checkedCustodianComposite(),
+                activeAuction: await this.activeAuctionComposite(
+                    { auction, config: auctionConfig },
+                    { auctionInfo: info },
+                ),
+                offerToken,
+                destinationCustodyToken: this.auctionDestinationCustodyTokenAddress(auction),
+                destinationOfferToken,
+                destinationBestOfferToken,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+
+        return [approveIx, approveDestinationIx, improveOfferDestinationAssetIx];
+    }
+
+    async prepareOrderResponseCctpIx(
+        accounts: {
+            payer: PublicKey;
+            fastVaa: PublicKey;
+            finalizedVaa: PublicKey;
+            baseFeeToken?: PublicKey;
+            mint?: PublicKey;
+        },
+        args: CctpMessageArgs,
+    ): Promise<TransactionInstruction> {
+        const { payer, fastVaa, finalizedVaa, mint } = accounts;
+
+        let { baseFeeToken } = accounts;
+        baseFeeToken ??= await splToken.getAssociatedTokenAddress(mint ?? this.mint, payer);
+
+        const fastVaaAcct = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
+        const sourceChain = fastVaaAcct.emitterInfo().chain;
+        const fromEndpoint = this.routerEndpointAddress(sourceChain);
 
         const { fastMarketOrder } = LiquidityLayerMessage.decode(fastVaaAcct.payload());
         if (fastMarketOrder === undefined) {
@@ -1545,10 +2633,13 @@ export class MatchingEngineProgram {
                 custodian: this.checkedCustodianComposite(),
                 fastOrderPath: this.fastOrderPathComposite({ fastVaa, fromEndpoint, toEndpoint }),
                 finalizedVaa: this.liquidityLayerVaaComposite(finalizedVaa),
+                inboundNotional: this.inboundNotionalAddress(sourceChain),
+                circuitBreaker: this.circuitBreakerAddress(),
+                auction: this.auctionAddress(fastVaaAcct.digest()),
                 preparedOrderResponse,
                 preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrderResponse),
                 baseFeeToken,
-                usdc: this.usdcComposite(),
+                mint: this.checkedMintComposite(mint),
                 cctp: {
                     mintRecipient: this.cctpMintRecipientComposite(),
                     messageTransmitterAuthority,
@@ -1567,6 +2658,8 @@ export class MatchingEngineProgram {
                 },
                 tokenProgram: splToken.TOKEN_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
             })
             .instruction();
     }
@@ -1660,6 +2753,7 @@ export class MatchingEngineProgram {
         return this.program.methods
             .settleAuctionComplete()
             .accounts({
+                custodian: this.checkedCustodianComposite(),
                 beneficiary,
                 baseFeeToken,
                 preparedOrderResponse,
@@ -1679,36 +2773,68 @@ export class MatchingEngineProgram {
             fastVaa: PublicKey;
             finalizedVaa: PublicKey;
             auction?: PublicKey;
+            mint?: PublicKey;
+            nttCustodyToken?: PublicKey;
         },
         args: CctpMessageArgs,
         signers: Signer[],
         opts: PreparedTransactionOptions,
         confirmOptions?: ConfirmOptions,
     ): Promise<PreparedTransaction> {
-        const { executor, fastVaa, finalizedVaa, auction } = accounts;
+        const { executor, fastVaa, finalizedVaa, auction, mint, nttCustodyToken } = accounts;
 
-        const { feeRecipientToken } = await this.fetchCustodian();
-        const prepareOrderResponseIx = await this.prepareOrderResponseCctpIx(
-            { payer: executor, fastVaa, finalizedVaa, baseFeeToken: feeRecipientToken },
-            args,
-        );
         const fastVaaAccount = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
         const { fastMarketOrder } = LiquidityLayerMessage.decode(fastVaaAccount.payload());
         if (fastMarketOrder === undefined) {
             throw new Error("Message not FastMarketOrder");
         }
 
+        const prepareOrderResponseIx = await this.prepareOrderResponseCctpIx(
+            {
+                payer: executor,
+                fastVaa,
+                finalizedVaa,
+                baseFeeToken: await this.feeRecipientTokenAddress(mint),
+                mint,
+            },
+            args,
+        );
+
         // Fetch the prepared order response.
         const fastVaaHash = fastVaaAccount.digest();
         const preparedOrderResponse = this.preparedOrderResponseAddress(fastVaaHash);
 
+        const targetChain = toChainId(fastMarketOrder.targetChain);
+        const { protocol } = await this.fetchRouterEndpointInfo(targetChain);
         const settleAuctionNoneIx = await (async () => {
-            if (fastMarketOrder.targetChain === "Solana") {
+            if (protocol.local !== undefined) {
                 return this.settleAuctionNoneLocalIx({
                     payer: executor,
                     reservedSequence: this.reservedFastFillSequenceAddress(fastVaaHash),
                     preparedOrderResponse,
                     auction,
+                    fastVaa,
+                });
+            } else if (protocol.ntt !== undefined) {
+                if (nttCustodyToken === undefined) {
+                    throw new Error("NTT custody token required");
+                }
+                return this.settleAuctionNoneNttIx(
+                    {
+                        payer: executor,
+                        fastVaa,
+                        preparedOrderResponse,
+                        nttCustodyToken,
+                        mint,
+                    },
+                    { targetChain },
+                );
+            } else if (protocol.tokenBridge !== undefined) {
+                return this.settleAuctionNoneTokenBridgeIx({
+                    payer: executor,
+                    fastVaa,
+                    preparedOrderResponse,
+                    mint,
                 });
             } else {
                 return this.settleAuctionNoneCctpIx(
@@ -1716,8 +2842,9 @@ export class MatchingEngineProgram {
                         payer: executor,
                         fastVaa,
                         preparedOrderResponse,
+                        mint,
                     },
-                    { targetChain: toChainId(fastMarketOrder.targetChain) },
+                    { targetChain },
                 );
             }
         })();
@@ -1742,6 +2869,7 @@ export class MatchingEngineProgram {
             reservedSequence: PublicKey;
             preparedOrderResponse?: PublicKey;
             auction?: PublicKey;
+            fastVaa?: PublicKey | null;
         },
         opts: {
             sourceChain?: ChainId;
@@ -1749,7 +2877,7 @@ export class MatchingEngineProgram {
             sequence?: Uint64;
         } = {},
     ) {
-        const { payer, reservedSequence } = accounts;
+        const { payer, reservedSequence, fastVaa } = accounts;
 
         let { auction, preparedOrderResponse } = accounts;
         let { sourceChain, orderSender, sequence } = opts;
@@ -1778,7 +2906,8 @@ export class MatchingEngineProgram {
             sequence ??= fastFillSeeds.sequence;
         }
 
-        const { feeRecipientToken } = await this.fetchCustodian();
+        const mint = await this.preparedCustodyTokenMint(preparedOrderResponse);
+        const feeRecipientToken = await this.feeRecipientTokenAddress(mint);
 
         return this.program.methods
             .settleAuctionNoneLocal()
@@ -1795,10 +2924,12 @@ export class MatchingEngineProgram {
                 fastFill: this.fastFillAddress(sourceChain, orderSender, sequence),
                 eventAuthority: this.eventAuthorityAddress(),
                 program: this.ID,
-                localCustodyToken: this.localCustodyTokenAddress(sourceChain),
+                localCustodyToken: this.localCustodyTokenAddress(sourceChain, mint),
+                fastVaa: fastVaa ?? null,
                 tokenProgram: splToken.TOKEN_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
                 sysvars: this.requiredSysvarsComposite(),
+                mint: this.checkedMintComposite(mint),
             })
             .instruction();
     }
@@ -1809,13 +2940,14 @@ export class MatchingEngineProgram {
             fastVaa: PublicKey;
             preparedOrderResponse?: PublicKey;
             auction?: PublicKey;
+            mint?: PublicKey;
         },
         opts: {
             sourceChain?: ChainId;
             targetChain?: ChainId;
         } = {},
     ) {
-        const { payer, fastVaa } = accounts;
+        const { payer, fastVaa, mint } = accounts;
 
         let { auction, preparedOrderResponse } = accounts;
         let { sourceChain, targetChain } = opts;
@@ -1858,8 +2990,6 @@ export class MatchingEngineProgram {
             tokenMessengerMinterProgram,
         } = await this.burnAndPublishAccounts(auction, { targetChain });
 
-        const { feeRecipientToken } = await this.fetchCustodian();
-
         return this.program.methods
             .settleAuctionNoneCctp()
             .accounts({
@@ -1867,7 +2997,8 @@ export class MatchingEngineProgram {
                 coreMessage,
                 cctpMessage,
                 custodian: this.checkedCustodianComposite(custodian),
-                feeRecipientToken,
+                feeRecipientToken: await this.feeRecipientTokenAddress(mint),
+                mint: this.checkedMintComposite(mint),
                 prepared: this.closePreparedOrderResponseComposite({
                     by: payer,
                     orderResponse: preparedOrderResponse,
@@ -1900,6 +3031,130 @@ export class MatchingEngineProgram {
             .instruction();
     }
 
+    async settleAuctionNoneNttIx(
+        accounts: {
+            payer: PublicKey;
+            fastVaa: PublicKey;
+            nttCustodyToken: PublicKey;
+            preparedOrderResponse?: PublicKey;
+            auction?: PublicKey;
+            mint?: PublicKey;
+        },
+        opts: {
+            targetChain?: ChainId;
+        } = {},
+    ): Promise<TransactionInstruction> {
+        const { payer, fastVaa, nttCustodyToken } = accounts;
+
+        let { auction, preparedOrderResponse, mint } = accounts;
+        let { targetChain } = opts;
+
+        let fastVaaAccount: VaaAccount | undefined;
+        if (auction === undefined || preparedOrderResponse === undefined) {
+            fastVaaAccount = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
+            auction ??= this.auctionAddress(fastVaaAccount.digest());
+            preparedOrderResponse ??= this.preparedOrderResponseAddress(fastVaaAccount.digest());
+        }
+
+        if (targetChain === undefined) {
+            fastVaaAccount ??= await VaaAccount.fetch(this.program.provider.connection, fastVaa);
+
+            const message = LiquidityLayerMessage.decode(fastVaaAccount.payload());
+            if (message.fastMarketOrder === undefined) {
+                throw new Error("Message not FastMarketOrder");
+            }
+
+            targetChain = toChainId(message.fastMarketOrder.targetChain);
+        }
+
+        mint ??= await this.preparedCustodyTokenMint(preparedOrderResponse);
+
+        const {
+            custodian,
+            coreMessage,
+            coreBridgeConfig,
+            coreEmitterSequence,
+            coreFeeCollector,
+            coreBridgeProgram,
+        } = await this.publishMessageAccounts(auction);
+        const ntt = await this.nttTransferAccounts(targetChain, mint, nttCustodyToken);
+
+        return this.program.methods
+            .settleAuctionNoneNtt()
+            .accounts({
+                payer,
+                coreMessage,
+                nttOutboxItem: this.nttOutboxItemAddress(auction),
+                custodian: this.checkedCustodianComposite(custodian),
+                feeRecipientToken: await this.feeRecipientTokenAddress(mint),
+                mint: this.checkedMintComposite(mint),
+                prepared: this.closePreparedOrderResponseComposite({
+                    by: payer,
+                    orderResponse: preparedOrderResponse,
+                }),
+                auction,
+                wormhole: {
+                    config: coreBridgeConfig,
+                    emitterSequence: coreEmitterSequence,
+                    feeCollector: coreFeeCollector,
+                    coreBridgeProgram,
+                },
+                ntt,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                sysvars: this.requiredSysvarsComposite(),
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async settleAuctionNoneTokenBridgeIx(accounts: {
+        payer: PublicKey;
+        fastVaa: PublicKey;
+        preparedOrderResponse?: PublicKey;
+        auction?: PublicKey;
+        mint?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { payer, fastVaa } = accounts;
+
+        let { auction, preparedOrderResponse, mint } = accounts;
+        if (auction === undefined || preparedOrderResponse === undefined) {
+            const fastVaaAccount = await VaaAccount.fetch(
+                this.program.provider.connection,
+                fastVaa,
+            );
+            auction ??= this.auctionAddress(fastVaaAccount.digest());
+            preparedOrderResponse ??= this.preparedOrderResponseAddress(fastVaaAccount.digest());
+        }
+
+        mint ??= await this.preparedCustodyTokenMint(preparedOrderResponse);
+
+        const { custodian, coreMessage } = await this.publishMessageAccounts(auction);
+
+        return this.program.methods
+            .settleAuctionNoneTokenBridge()
+            .accounts({
+                payer,
+                coreMessage,
+                custodian: this.checkedCustodianComposite(custodian),
+                feeRecipientToken: await this.feeRecipientTokenAddress(mint),
+                mint: this.checkedMintComposite(mint),
+                prepared: this.closePreparedOrderResponseComposite({
+                    by: payer,
+                    orderResponse: preparedOrderResponse,
+                }),
+                auction,
+                tokenBridge: this.tokenBridgeTransferAccounts(mint),
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                sysvars: this.requiredSysvarsComposite(),
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
     async executeFastOrderTx(
         accounts: {
             payer: PublicKey;
@@ -1907,12 +3162,13 @@ export class MatchingEngineProgram {
             auction?: PublicKey;
             executorToken?: PublicKey;
             reservedSequence?: PublicKey;
+            nttCustodyToken?: PublicKey;
         },
         signers: Signer[],
         opts: PreparedTransactionOptions,
         confirmOptions?: ConfirmOptions,
     ): Promise<PreparedTransaction> {
-        const { payer, fastVaa, executorToken } = accounts;
+        const { payer, fastVaa, executorToken, nttCustodyToken } = accounts;
 
         let { auction, reservedSequence } = accounts;
 
@@ -1944,8 +3200,9 @@ export class MatchingEngineProgram {
             targetChain = toChainId(fastMarketOrder.targetChain);
         }
 
+        const { protocol } = await this.fetchRouterEndpointInfo(targetChain);
         const executeOrderIx = await (async () => {
-            if (targetChain === toChainId("Solana")) {
+            if (protocol.local !== undefined) {
                 return this.executeFastOrderLocalIx({
                     payer,
                     fastVaa,
@@ -1953,6 +3210,30 @@ export class MatchingEngineProgram {
                     executorToken,
                     reservedSequence,
                 });
+            } else if (protocol.ntt !== undefined) {
+                if (nttCustodyToken === undefined) {
+                    throw new Error("NTT custody token required");
+                }
+                return this.executeFastOrderNttIx(
+                    {
+                        payer,
+                        fastVaa,
+                        auction,
+                        executorToken,
+                        nttCustodyToken,
+                    },
+                    { targetChain },
+                );
+            } else if (protocol.tokenBridge !== undefined) {
+                return this.executeFastOrderTokenBridgeIx(
+                    {
+                        payer,
+                        fastVaa,
+                        auction,
+                        executorToken,
+                    },
+                    { targetChain },
+                );
             } else {
                 return this.executeFastOrderCctpIx(
                     {
@@ -1966,19 +3247,230 @@ export class MatchingEngineProgram {
             }
         })();
 
-        return {
-            ixs: [executeOrderIx],
-            signers,
-            computeUnits: opts.computeUnits!,
-            feeMicroLamports: opts.feeMicroLamports,
-            nonceAccount: opts.nonceAccount,
-            addressLookupTableAccounts: opts.addressLookupTableAccounts,
-            txName: "executeOrder",
-            confirmOptions,
-        };
+        return {
+            ixs: [executeOrderIx],
+            signers,
+            computeUnits: opts.computeUnits!,
+            feeMicroLamports: opts.feeMicroLamports,
+            nonceAccount: opts.nonceAccount,
+            addressLookupTableAccounts: opts.addressLookupTableAccounts,
+            txName: "executeOrder",
+            confirmOptions,
+        };
+    }
+
+    async executeFastOrderCctpIx(
+        accounts: {
+            payer: PublicKey;
+            fastVaa: PublicKey;
+            executorToken?: PublicKey;
+            auction?: PublicKey;
+            auctionConfig?: PublicKey;
+            bestOfferToken?: PublicKey;
+            initialOfferToken?: PublicKey;
+            initialParticipant?: PublicKey;
+        },
+        opts: {
+            targetChain?: ChainId;
+        } = {},
+    ) {
+        const connection = this.program.provider.connection;
+
+        const { payer, fastVaa, auctionConfig, bestOfferToken } = accounts;
+
+        let { auction, executorToken, initialOfferToken, initialParticipant } = accounts;
+        let { targetChain } = opts;
+
+        executorToken ??= splToken.getAssociatedTokenAddressSync(this.mint, payer);
+
+        let fastVaaAccount: VaaAccount | undefined;
+        if (auction === undefined) {
+            fastVaaAccount = await VaaAccount.fetch(connection, fastVaa);
+            auction = this.auctionAddress(fastVaaAccount.digest());
+        }
+
+        if (targetChain === undefined) {
+            fastVaaAccount ??= await VaaAccount.fetch(connection, fastVaa);
+
+            const { fastMarketOrder } = LiquidityLayerMessage.decode(fastVaaAccount.payload());
+            if (fastMarketOrder === undefined) {
+                throw new Error("Message not FastMarketOrder");
+            }
+            targetChain ??= toChainId(fastMarketOrder.targetChain);
+        }
+
+        let auctionInfo: AuctionInfo | undefined;
+        if (initialOfferToken === undefined || initialParticipant === undefined) {
+            const { preparedBy, info } = await this.fetchAuction({ address: auction });
+            if (info === null) {
+                throw new Error("no auction info found");
+            }
+            auctionInfo = info;
+            initialOfferToken ??= info.initialOfferToken;
+            initialParticipant ??= preparedBy;
+        }
+
+        const {
+            custodian,
+            routerEndpoint: toRouterEndpoint,
+            coreMessage,
+            cctpMessage,
+            coreBridgeConfig,
+            coreEmitterSequence,
+            coreFeeCollector,
+            coreBridgeProgram,
+            tokenMessengerMinterSenderAuthority,
+            messageTransmitterConfig,
+            tokenMessenger,
+            remoteTokenMessenger,
+            tokenMinter,
+            localToken,
+            tokenMessengerMinterEventAuthority,
+            messageTransmitterProgram,
+            tokenMessengerMinterProgram,
+        } = await this.burnAndPublishAccounts(auction, { targetChain });
+
+        const mint = this.mint;
+        return this.program.methods
+            .executeFastOrderCctp()
+            .accounts({
+                payer,
+                coreMessage,
+                cctpMessage,
+                executeOrder: {
+                    fastVaa: this.liquidityLayerVaaComposite(fastVaa),
+                    activeAuction: await this.activeAuctionComposite(
+                        {
+                            auction,
+                            config: auctionConfig,
+                            bestOfferToken,
+                        },
+                        { auctionInfo },
+                    ),
+                    executorToken,
+                    initialOfferToken,
+                    initialParticipant,
+                },
+                toRouterEndpoint: this.routerEndpointComposite(toRouterEndpoint),
+                custodian: this.checkedCustodianComposite(custodian),
+                wormhole: {
+                    config: coreBridgeConfig,
+                    emitterSequence: coreEmitterSequence,
+                    feeCollector: coreFeeCollector,
+                    coreBridgeProgram,
+                },
+                cctp: {
+                    mint,
+                    tokenMessengerMinterSenderAuthority,
+                    messageTransmitterConfig,
+                    tokenMessenger,
+                    remoteTokenMessenger,
+                    tokenMinter,
+                    localToken,
+                    tokenMessengerMinterEventAuthority,
+                    tokenMessengerMinterProgram,
+                    messageTransmitterProgram,
+                },
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                sysvars: this.requiredSysvarsComposite(),
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async executeFastOrderNttIx(
+        accounts: {
+            payer: PublicKey;
+            fastVaa: PublicKey;
+            nttCustodyToken: PublicKey;
+            executorToken?: PublicKey;
+            auction?: PublicKey;
+            auctionConfig?: PublicKey;
+            bestOfferToken?: PublicKey;
+            initialOfferToken?: PublicKey;
+            initialParticipant?: PublicKey;
+            mint?: PublicKey;
+        },
+        opts: {
+            targetChain?: ChainId;
+        } = {},
+    ): Promise<TransactionInstruction> {
+        const { payer, fastVaa, nttCustodyToken, auctionConfig, bestOfferToken } = accounts;
+
+        let { auction, executorToken, initialOfferToken, initialParticipant, mint } = accounts;
+        let { targetChain } = opts;
+
+        const { auction: definedAuction, targetChain: definedTargetChain } =
+            await this.executeFastOrderAccounts(fastVaa, { auction, targetChain });
+        auction = definedAuction;
+        targetChain = definedTargetChain;
+
+        mint ??= await this.auctionCustodyTokenMint(auction);
+        executorToken ??= splToken.getAssociatedTokenAddressSync(mint, payer);
+
+        let auctionInfo: AuctionInfo | undefined;
+        if (initialOfferToken === undefined || initialParticipant === undefined) {
+            const { preparedBy, info } = await this.fetchAuction({ address: auction });
+            if (info === null) {
+                throw new Error("no auction info found");
+            }
+            auctionInfo = info;
+            initialOfferToken ??= info.initialOfferToken;
+            initialParticipant ??= preparedBy;
+        }
+
+        const {
+            custodian,
+            coreMessage,
+            coreBridgeConfig,
+            coreEmitterSequence,
+            coreFeeCollector,
+            coreBridgeProgram,
+        } = await this.publishMessageAccounts(auction);
+
+        return this.program.methods
+            .executeFastOrderNtt()
+            .accounts({
+                payer,
+                coreMessage,
+                nttOutboxItem: this.nttOutboxItemAddress(auction),
+                custodian: this.checkedCustodianComposite(custodian),
+                executeOrder: {
+                    fastVaa: this.liquidityLayerVaaComposite(fastVaa),
+                    activeAuction: await this.activeAuctionComposite(
+                        {
+                            auction,
+                            config: auctionConfig,
+                            bestOfferToken,
+                        },
+                        { auctionInfo },
+                    ),
+                    executorToken,
+                    initialOfferToken,
+                    initialParticipant,
+                },
+                toRouterEndpoint: this.routerEndpointComposite(
+                    this.routerEndpointAddress(targetChain),
+                ),
+                wormhole: {
+                    config: coreBridgeConfig,
+                    emitterSequence: coreEmitterSequence,
+                    feeCollector: coreFeeCollector,
+                    coreBridgeProgram,
+                },
+                ntt: await this.nttTransferAccounts(targetChain, mint, nttCustodyToken),
+                systemProgram: SystemProgram.programId,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                sysvars: this.requiredSysvarsComposite(),
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
     }
 
-    async executeFastOrderCctpIx(
+    async executeFastOrderTokenBridgeIx(
         accounts: {
             payer: PublicKey;
             fastVaa: PublicKey;
@@ -1988,35 +3480,24 @@ export class MatchingEngineProgram {
             bestOfferToken?: PublicKey;
             initialOfferToken?: PublicKey;
             initialParticipant?: PublicKey;
+            mint?: PublicKey;
         },
         opts: {
             targetChain?: ChainId;
         } = {},
-    ) {
-        const connection = this.program.provider.connection;
-
+    ): Promise<TransactionInstruction> {
         const { payer, fastVaa, auctionConfig, bestOfferToken } = accounts;
 
-        let { auction, executorToken, initialOfferToken, initialParticipant } = accounts;
+        let { auction, executorToken, initialOfferToken, initialParticipant, mint } = accounts;
         let { targetChain } = opts;
 
-        executorToken ??= splToken.getAssociatedTokenAddressSync(this.mint, payer);
-
-        let fastVaaAccount: VaaAccount | undefined;
-        if (auction === undefined) {
-            fastVaaAccount = await VaaAccount.fetch(connection, fastVaa);
-            auction = this.auctionAddress(fastVaaAccount.digest());
-        }
-
-        if (targetChain === undefined) {
-            fastVaaAccount ??= await VaaAccount.fetch(connection, fastVaa);
+        const { auction: definedAuction, targetChain: definedTargetChain } =
+            await this.executeFastOrderAccounts(fastVaa, { auction, targetChain });
+        auction = definedAuction;
+        targetChain = definedTargetChain;
 
-            const { fastMarketOrder } = LiquidityLayerMessage.decode(fastVaaAccount.payload());
-            if (fastMarketOrder === undefined) {
-                throw new Error("Message not FastMarketOrder");
-            }
-            targetChain ??= toChainId(fastMarketOrder.targetChain);
-        }
+        mint ??= await this.auctionCustodyTokenMint(auction);
+        executorToken ??= splToken.getAssociatedTokenAddressSync(mint, payer);
 
         let auctionInfo: AuctionInfo | undefined;
         if (initialOfferToken === undefined || initialParticipant === undefined) {
@@ -2029,33 +3510,14 @@ export class MatchingEngineProgram {
             initialParticipant ??= preparedBy;
         }
 
-        const {
-            custodian,
-            routerEndpoint: toRouterEndpoint,
-            coreMessage,
-            cctpMessage,
-            coreBridgeConfig,
-            coreEmitterSequence,
-            coreFeeCollector,
-            coreBridgeProgram,
-            tokenMessengerMinterSenderAuthority,
-            messageTransmitterConfig,
-            tokenMessenger,
-            remoteTokenMessenger,
-            tokenMinter,
-            localToken,
-            tokenMessengerMinterEventAuthority,
-            messageTransmitterProgram,
-            tokenMessengerMinterProgram,
-        } = await this.burnAndPublishAccounts(auction, { targetChain });
+        const { custodian, coreMessage } = await this.publishMessageAccounts(auction);
 
-        const mint = this.mint;
         return this.program.methods
-            .executeFastOrderCctp()
+            .executeFastOrderTokenBridge()
             .accounts({
                 payer,
                 coreMessage,
-                cctpMessage,
+                custodian: this.checkedCustodianComposite(custodian),
                 executeOrder: {
                     fastVaa: this.liquidityLayerVaaComposite(fastVaa),
                     activeAuction: await this.activeAuctionComposite(
@@ -2070,28 +3532,12 @@ export class MatchingEngineProgram {
                     initialOfferToken,
                     initialParticipant,
                 },
-                toRouterEndpoint: this.routerEndpointComposite(toRouterEndpoint),
-                custodian: this.checkedCustodianComposite(custodian),
-                wormhole: {
-                    config: coreBridgeConfig,
-                    emitterSequence: coreEmitterSequence,
-                    feeCollector: coreFeeCollector,
-                    coreBridgeProgram,
-                },
-                cctp: {
-                    mint,
-                    tokenMessengerMinterSenderAuthority,
-                    messageTransmitterConfig,
-                    tokenMessenger,
-                    remoteTokenMessenger,
-                    tokenMinter,
-                    localToken,
-                    tokenMessengerMinterEventAuthority,
-                    tokenMessengerMinterProgram,
-                    messageTransmitterProgram,
-                },
-                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                toRouterEndpoint: this.routerEndpointComposite(
+                    this.routerEndpointAddress(targetChain),
+                ),
+                tokenBridge: this.tokenBridgeTransferAccounts(mint),
                 systemProgram: SystemProgram.programId,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
                 sysvars: this.requiredSysvarsComposite(),
                 eventAuthority: this.eventAuthorityAddress(),
                 program: this.ID,
@@ -2282,6 +3728,9 @@ export class MatchingEngineProgram {
             initialOfferToken?: PublicKey;
             initialParticipant?: PublicKey;
             reserveBeneficiary?: PublicKey;
+            destinationCustodyToken?: PublicKey | null;
+            gasDropoffRecipient?: PublicKey | null;
+            userRewardToken?: PublicKey | null;
         },
         opts: {
             sourceChain?: ChainId;
@@ -2300,15 +3749,20 @@ export class MatchingEngineProgram {
             initialOfferToken,
             initialParticipant,
             reserveBeneficiary,
+            destinationCustodyToken,
+            gasDropoffRecipient,
+            userRewardToken,
         } = accounts;
         let { sourceChain, orderSender, sequence } = opts;
-        executorToken ??= splToken.getAssociatedTokenAddressSync(this.mint, payer);
 
+        let redeemer: PublicKey | undefined;
         if (
             auction === undefined ||
             reservedSequence === undefined ||
             sourceChain === undefined ||
-            orderSender === undefined
+            orderSender === undefined ||
+            gasDropoffRecipient === undefined ||
+            userRewardToken === undefined
         ) {
             const fastVaaAccount = await VaaAccount.fetch(connection, fastVaa);
             const fastVaaHash = fastVaaAccount.digest();
@@ -2316,13 +3770,26 @@ export class MatchingEngineProgram {
             reservedSequence ??= this.reservedFastFillSequenceAddress(fastVaaHash);
             sourceChain ??= fastVaaAccount.emitterInfo().chain;
 
-            const { fastMarketOrder } = LiquidityLayerMessage.decode(fastVaaAccount.payload());
+            const { fastMarketOrder, fastMarketOrderV2 } = LiquidityLayerMessage.decode(
+                fastVaaAccount.payload(),
+            );
             if (fastMarketOrder === undefined) {
                 throw new Error("Message not FastMarketOrder");
             }
             orderSender ??= Array.from(fastMarketOrder.sender.toUint8Array());
+
+            redeemer = new PublicKey(fastMarketOrder.redeemer.toUint8Array());
+
+            // The redeemer only needs to be passed in if the order requests a gas drop-off.
+            gasDropoffRecipient ??=
+                fastMarketOrderV2 !== undefined && fastMarketOrderV2.gasDropoff > 0n
+                    ? redeemer
+                    : null;
         }
 
+        const mint = await this.auctionCustodyTokenMint(auction);
+        executorToken ??= splToken.getAssociatedTokenAddressSync(mint, payer);
+
         if (sequence === undefined || reserveBeneficiary === undefined) {
             const reservedData = await this.fetchReservedFastFillSequence({
                 address: reservedSequence,
@@ -2342,6 +3809,31 @@ export class MatchingEngineProgram {
             initialParticipant ??= preparedBy;
         }
 
+        if (destinationCustodyToken === undefined || userRewardToken === undefined) {
+            if (auctionInfo === undefined) {
+                const { info } = await this.fetchAuction({ address: auction });
+                if (info === null) {
+                    throw new Error("no auction info found");
+                }
+                auctionInfo = info;
+            }
+
+            // Fast fills delivered in a destination asset take over the auction's destination
+            // custody token. The user reward (if any) is paid in the auctioned asset to the
+            // redeemer's token account.
+            if (auctionInfo.destinationAssetInfo === null) {
+                destinationCustodyToken ??= null;
+                userRewardToken ??= null;
+            } else {
+                destinationCustodyToken ??= this.auctionDestinationCustodyTokenAddress(auction);
+                if (userRewardToken === undefined) {
+                    const token = splToken.getAssociatedTokenAddressSync(mint, redeemer!);
+                    const accInfo = await connection.getAccountInfo(token);
+                    userRewardToken = accInfo === null ? null : token;
+                }
+            }
+        }
+
         const activeAuction = await this.activeAuctionComposite(
             {
                 auction,
@@ -2368,7 +3860,10 @@ export class MatchingEngineProgram {
                 fastFill: this.fastFillAddress(sourceChain, orderSender, sequence),
                 eventAuthority: this.eventAuthorityAddress(),
                 program: this.ID,
-                localCustodyToken: this.localCustodyTokenAddress(sourceChain),
+                localCustodyToken: this.localCustodyTokenAddress(sourceChain, mint),
+                destinationCustodyToken,
+                gasDropoffRecipient,
+                userRewardToken,
                 tokenProgram: splToken.TOKEN_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
                 sysvars: this.requiredSysvarsComposite(),
@@ -2376,6 +3871,99 @@ export class MatchingEngineProgram {
             .instruction();
     }
 
+    async migrateFastFillIx(accounts: {
+        payer: PublicKey;
+        fastFill: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { payer, fastFill } = accounts;
+        return this.program.methods
+            .migrateFastFill()
+            .accounts({
+                payer,
+                fastFill,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    async reclaimExpiredFastFillIx(accounts: {
+        payer: PublicKey;
+        fastFill: PublicKey;
+        escrowToken?: PublicKey | null;
+        fastFillCustodyToken?: PublicKey | null;
+    }): Promise<TransactionInstruction> {
+        const { payer, fastFill } = accounts;
+
+        let { escrowToken, fastFillCustodyToken } = accounts;
+
+        const {
+            seeds: { sourceChain },
+            info: { preparedBy, redeemer },
+            mint,
+            ownsCustodyToken,
+        } = await this.fetchFastFill({ address: fastFill });
+        if (!isChainId(sourceChain)) {
+            throw new Error("invalid source chain");
+        }
+
+        const redeemerToken = splToken.getAssociatedTokenAddressSync(mint, redeemer, true);
+
+        // The escrow token account is only needed if the redeemer's associated token account
+        // cannot receive the fill amount.
+        if (escrowToken === undefined) {
+            const accInfo = await this.program.provider.connection.getAccountInfo(redeemerToken);
+            escrowToken = accInfo === null ? this.fastFillEscrowTokenAddress(redeemer, mint) : null;
+        }
+        fastFillCustodyToken ??= await this.fastFillCustodyTokenAddress(
+            fastFill,
+            mint,
+            ownsCustodyToken,
+        );
+        const { mint: localCustodyMint } = await this.fetchRouterEndpoint(sourceChain);
+
+        return this.program.methods
+            .reclaimExpiredFastFill()
+            .accounts({
+                payer,
+                custodian: this.checkedCustodianComposite(),
+                preparedBy,
+                fastFill,
+                redeemerToken,
+                escrowToken,
+                mint,
+                fromEndpoint: this.routerEndpointAddress(sourceChain),
+                localCustodyToken: this.localCustodyTokenAddress(sourceChain, localCustodyMint),
+                fastFillCustodyToken,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                eventAuthority: this.eventAuthorityAddress(),
+                program: this.ID,
+            })
+            .instruction();
+    }
+
+    async claimFastFillEscrowIx(accounts: {
+        redeemer: PublicKey;
+        mint: PublicKey;
+        recipientToken?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { redeemer, mint } = accounts;
+
+        let { recipientToken } = accounts;
+        recipientToken ??= splToken.getAssociatedTokenAddressSync(mint, redeemer);
+
+        return this.program.methods
+            .claimFastFillEscrow()
+            .accounts({
+                redeemer,
+                custodian: this.checkedCustodianComposite(),
+                escrowToken: this.fastFillEscrowTokenAddress(redeemer, mint),
+                recipientToken,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+            })
+            .instruction();
+    }
+
     async closeAuctionIx(accounts: {
         auction: PublicKey;
         beneficiary?: PublicKey;
@@ -2422,17 +4010,27 @@ export class MatchingEngineProgram {
     async redeemFastFillAccounts(fastFill: PublicKey): Promise<RedeemFastFillAccounts> {
         const {
             seeds: { sourceChain },
+            mint,
+            ownsCustodyToken,
         } = await this.fetchFastFill({ address: fastFill });
 
         if (!isChainId(sourceChain)) {
             throw new Error("invalid source chain");
         }
 
+        const { mint: localCustodyMint } = await this.fetchRouterEndpoint(sourceChain);
+
         return {
             custodian: this.custodianAddress(),
             fromRouterEndpoint: this.routerEndpointAddress(sourceChain),
             toRouterEndpoint: this.routerEndpointAddress(toChainId("Solana")),
-            localCustodyToken: this.localCustodyTokenAddress(sourceChain),
+            localCustodyToken: this.localCustodyTokenAddress(sourceChain, localCustodyMint),
+            fastFillCustodyToken: await this.fastFillCustodyTokenAddress(
+                fastFill,
+                mint,
+                ownsCustodyToken,
+            ),
+            mint,
             eventAuthority: this.eventAuthorityAddress(),
             matchingEngineProgram: this.ID,
         };
@@ -2527,6 +4125,129 @@ export class MatchingEngineProgram {
         };
     }
 
+    tokenBridgeTransferAccounts(mint: PublicKey): TokenBridgeTransferAccounts {
+        const tokenBridgeProgram = this.tokenBridgeProgramId();
+        const coreBridgeProgram = this.coreBridgeProgramId();
+
+        const emitter = PublicKey.findProgramAddressSync(
+            [Buffer.from("emitter")],
+            tokenBridgeProgram,
+        )[0];
+
+        return {
+            mint,
+            config: PublicKey.findProgramAddressSync([Buffer.from("config")], tokenBridgeProgram)[0],
+            custodyToken: PublicKey.findProgramAddressSync([mint.toBuffer()], tokenBridgeProgram)[0],
+            transferAuthority: PublicKey.findProgramAddressSync(
+                [Buffer.from("authority_signer")],
+                tokenBridgeProgram,
+            )[0],
+            custodyAuthority: PublicKey.findProgramAddressSync(
+                [Buffer.from("custody_signer")],
+                tokenBridgeProgram,
+            )[0],
+            emitter,
+            coreBridgeConfig: PublicKey.findProgramAddressSync(
+                [Buffer.from("Bridge")],
+                coreBridgeProgram,
+            )[0],
+            emitterSequence: PublicKey.findProgramAddressSync(
+                [Buffer.from("Sequence"), emitter.toBuffer()],
+                coreBridgeProgram,
+            )[0],
+            coreFeeCollector: PublicKey.findProgramAddressSync(
+                [Buffer.from("fee_collector")],
+                coreBridgeProgram,
+            )[0],
+            coreBridgeProgram,
+            tokenBridgeProgram,
+        };
+    }
+
+    async nttTransferAccounts(
+        targetChain: ChainId,
+        mint: PublicKey,
+        custodyToken: PublicKey,
+    ): Promise<NttTransferAccounts> {
+        const { protocol } = await this.fetchRouterEndpointInfo(targetChain);
+        if (protocol.ntt === undefined) {
+            throw new Error("not NTT endpoint");
+        }
+
+        return {
+            mint,
+            custodyToken,
+            transceiver: protocol.ntt.transceiver,
+            nttManagerProgram: protocol.ntt.manager,
+        };
+    }
+
+    /// Fee recipient token account for the given mint. USDC fees are collected by the custodian's
+    /// fee recipient token account, otherwise by the supported mint's.
+    async feeRecipientTokenAddress(mint?: PublicKey): Promise<PublicKey> {
+        if (mint === undefined || mint.equals(this.mint)) {
+            const { feeRecipientToken } = await this.fetchCustodian();
+            return feeRecipientToken;
+        } else {
+            const { feeRecipientToken } = await this.fetchSupportedMint(mint);
+            return feeRecipientToken;
+        }
+    }
+
+    /// Token account owned by the fast fill, which only exists if the fast fill owns its custody
+    /// token account.
+    async fastFillCustodyTokenAddress(
+        fastFill: PublicKey,
+        mint: PublicKey,
+        ownsCustodyToken: boolean,
+    ): Promise<PublicKey | null> {
+        if (!ownsCustodyToken) {
+            return null;
+        }
+
+        const { value } = await this.program.provider.connection.getTokenAccountsByOwner(
+            fastFill,
+            { mint },
+        );
+        return value.length == 0 ? null : value[0].pubkey;
+    }
+
+    private async auctionCustodyTokenMint(auction: PublicKey): Promise<PublicKey> {
+        const { mint } = await splToken.getAccount(
+            this.program.provider.connection,
+            this.auctionCustodyTokenAddress(auction),
+        );
+        return mint;
+    }
+
+    private async preparedCustodyTokenMint(preparedOrderResponse: PublicKey): Promise<PublicKey> {
+        const { mint } = await splToken.getAccount(
+            this.program.provider.connection,
+            this.preparedCustodyTokenAddress(preparedOrderResponse),
+        );
+        return mint;
+    }
+
+    private async executeFastOrderAccounts(
+        fastVaa: PublicKey,
+        opts: { auction?: PublicKey; targetChain?: ChainId },
+    ): Promise<{ auction: PublicKey; targetChain: ChainId }> {
+        let { auction, targetChain } = opts;
+
+        if (auction === undefined || targetChain === undefined) {
+            const fastVaaAccount = await VaaAccount.fetch(this.program.provider.connection, fastVaa);
+            auction ??= this.auctionAddress(fastVaaAccount.digest());
+
+            const { fastMarketOrder } = LiquidityLayerMessage.decode(fastVaaAccount.payload());
+            if (fastMarketOrder === undefined) {
+                throw new Error("Message not FastMarketOrder");
+            }
+            targetChain ??= toChainId(fastMarketOrder.targetChain);
+        }
+
+        return { auction, targetChain };
+    }
+
     upgradeManagerProgram(): UpgradeManagerProgram {
         switch (this._programId) {
             case mainnet(): {
@@ -2561,6 +4282,23 @@ export class MatchingEngineProgram {
         return new MessageTransmitterProgram(this.program.provider.connection);
     }
 
+    tokenBridgeProgramId(): PublicKey {
+        switch (this._programId) {
+            case mainnet(): {
+                return new PublicKey("wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb");
+            }
+            case testnet(): {
+                return new PublicKey("DZnkkTmCiFWfYTfT41X3Rd1kDgozqzxWaHqsw6W4x2oe");
+            }
+            case localnet(): {
+                return new PublicKey("wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb");
+            }
+            default: {
+                throw new Error("unsupported network");
+            }
+        }
+    }
+
     coreBridgeProgramId(): PublicKey {
         switch (this._programId) {
             case mainnet(): {
@@ -15,6 +15,8 @@ export type AuctionStatus = {
 export type AuctionDestinationAssetInfo = {
     custodyTokenBump: number;
     amountOut: BN;
+    mint: PublicKey;
+    bestOfferToken: PublicKey;
 };
 
 export type AuctionInfo = {
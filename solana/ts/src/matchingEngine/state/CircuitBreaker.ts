@@ -0,0 +1,21 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+export type NotionalAccumulator = {
+    windowStartSlot: BN;
+    notional: BN;
+};
+
+export class CircuitBreaker {
+    bump: number;
+    global: NotionalAccumulator;
+
+    constructor(bump: number, global: NotionalAccumulator) {
+        this.bump = bump;
+        this.global = global;
+    }
+
+    static address(programId: PublicKey) {
+        return PublicKey.findProgramAddressSync([Buffer.from("circuit-breaker")], programId)[0];
+    }
+}
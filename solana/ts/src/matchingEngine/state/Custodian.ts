@@ -2,6 +2,34 @@ import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { emitterAddress } from "../../common";
 
+export type PauseTarget = {
+    newAuctions?: {};
+    offerImprovement?: {};
+    orderExecution?: {};
+    fastFillCreation?: {};
+    settlement?: {};
+};
+
+export type PauseFlag = {
+    paused: boolean;
+    unpauseSlot: BN | null;
+    reason: number;
+};
+
+export type PauseFlags = {
+    newAuctions: PauseFlag;
+    offerImprovement: PauseFlag;
+    orderExecution: PauseFlag;
+    fastFillCreation: PauseFlag;
+    settlement: PauseFlag;
+};
+
+export type CircuitBreakerConfig = {
+    windowSlots: BN;
+    globalThreshold: BN;
+    chainThreshold: BN;
+};
+
 export class Custodian {
     owner: PublicKey;
     pendingOwner: PublicKey | null;
@@ -11,6 +39,12 @@ export class Custodian {
     feeRecipientToken: PublicKey;
     auctionConfigId: number;
     nextProposalId: BN;
+    pendingOwnerExpirySlot: BN;
+    pendingOwnerAssistant: PublicKey | null;
+    pauseFlags: PauseFlags;
+    circuitBreaker: CircuitBreakerConfig;
+    routerEndpointRemovalDelay: BN;
+    maxGasDropoff: BN;
 
     constructor(
         owner: PublicKey,
@@ -21,6 +55,12 @@ export class Custodian {
         feeRecipientToken: PublicKey,
         auctionConfigId: number,
         nextProposalId: BN,
+        pendingOwnerExpirySlot: BN,
+        pendingOwnerAssistant: PublicKey | null,
+        pauseFlags: PauseFlags,
+        circuitBreaker: CircuitBreakerConfig,
+        routerEndpointRemovalDelay: BN,
+        maxGasDropoff: BN,
     ) {
         this.owner = owner;
         this.pendingOwner = pendingOwner;
@@ -30,6 +70,12 @@ export class Custodian {
         this.feeRecipientToken = feeRecipientToken;
         this.auctionConfigId = auctionConfigId;
         this.nextProposalId = nextProposalId;
+        this.pendingOwnerExpirySlot = pendingOwnerExpirySlot;
+        this.pendingOwnerAssistant = pendingOwnerAssistant;
+        this.pauseFlags = pauseFlags;
+        this.circuitBreaker = circuitBreaker;
+        this.routerEndpointRemovalDelay = routerEndpointRemovalDelay;
+        this.maxGasDropoff = maxGasDropoff;
     }
 
     static address(programId: PublicKey) {
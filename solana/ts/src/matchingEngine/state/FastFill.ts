@@ -22,17 +22,26 @@ export class FastFill {
     redeemed: boolean;
     info: FastFillInfo;
     redeemerMessage: Buffer;
+    mint: PublicKey;
+    relayerFee: BN;
+    ownsCustodyToken: boolean;
 
     constructor(
         seeds: FastFillSeeds,
         redeemed: boolean,
         info: FastFillInfo,
         redeemerMessage: Buffer,
+        mint: PublicKey,
+        relayerFee: BN,
+        ownsCustodyToken: boolean,
     ) {
         this.seeds = seeds;
         this.redeemed = redeemed;
         this.info = info;
         this.redeemerMessage = redeemerMessage;
+        this.mint = mint;
+        this.relayerFee = relayerFee;
+        this.ownsCustodyToken = ownsCustodyToken;
     }
 
     static address(
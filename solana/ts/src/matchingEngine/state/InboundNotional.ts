@@ -0,0 +1,27 @@
+import { PublicKey } from "@solana/web3.js";
+import { NotionalAccumulator } from "./CircuitBreaker";
+
+export class InboundNotional {
+    bump: number;
+    inboundLimit: NotionalAccumulator;
+    circuitBreaker: NotionalAccumulator;
+
+    constructor(
+        bump: number,
+        inboundLimit: NotionalAccumulator,
+        circuitBreaker: NotionalAccumulator,
+    ) {
+        this.bump = bump;
+        this.inboundLimit = inboundLimit;
+        this.circuitBreaker = circuitBreaker;
+    }
+
+    static address(programId: PublicKey, chain: number) {
+        const encodedChain = Buffer.alloc(2);
+        encodedChain.writeUInt16BE(chain);
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("inbound-notional"), encodedChain],
+            programId,
+        )[0];
+    }
+}
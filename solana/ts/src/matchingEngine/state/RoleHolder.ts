@@ -0,0 +1,26 @@
+import { PublicKey } from "@solana/web3.js";
+
+export type Role = {
+    pauser?: {};
+    endpointManager?: {};
+    feeManager?: {};
+};
+
+export class RoleHolder {
+    bump: number;
+    holder: PublicKey;
+    roles: Role[];
+
+    constructor(bump: number, holder: PublicKey, roles: Role[]) {
+        this.bump = bump;
+        this.holder = holder;
+        this.roles = roles;
+    }
+
+    static address(programId: PublicKey, holder: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("role-holder"), holder.toBuffer()],
+            programId,
+        )[0];
+    }
+}
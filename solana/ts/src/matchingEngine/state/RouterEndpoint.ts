@@ -1,8 +1,12 @@
+import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
+import { PauseFlag } from "./Custodian";
 
 export type MessageProtocol = {
     local?: { programId: PublicKey };
     cctp?: { domain: number };
+    tokenBridge?: {};
+    ntt?: { manager: PublicKey; transceiver: PublicKey };
     none?: {};
 };
 
@@ -13,13 +17,50 @@ export type EndpointInfo = {
     protocol: MessageProtocol;
 };
 
+export type EndpointLimits = {
+    maxRedeemerMessageLen: number | null;
+    minOrderAmount: BN | null;
+    maxOrderAmount: BN | null;
+};
+
+export type PreviousAddress = {
+    address: Array<number>;
+    expirySlot: BN;
+};
+
+export type InboundLimit = {
+    windowSlots: BN;
+    maxNotional: BN;
+};
+
 export class RouterEndpoint {
     bump: number;
     info: EndpointInfo;
+    pause: PauseFlag;
+    inboundLimit: InboundLimit;
+    disabledSlot: BN | null;
+    previousAddress: PreviousAddress | null;
+    limits: EndpointLimits;
+    mint: PublicKey;
 
-    constructor(bump: number, info: EndpointInfo) {
+    constructor(
+        bump: number,
+        info: EndpointInfo,
+        pause: PauseFlag,
+        inboundLimit: InboundLimit,
+        disabledSlot: BN | null,
+        previousAddress: PreviousAddress | null,
+        limits: EndpointLimits,
+        mint: PublicKey,
+    ) {
         this.bump = bump;
         this.info = info;
+        this.pause = pause;
+        this.inboundLimit = inboundLimit;
+        this.disabledSlot = disabledSlot;
+        this.previousAddress = previousAddress;
+        this.limits = limits;
+        this.mint = mint;
     }
 
     static address(programId: PublicKey, chain: number) {
@@ -0,0 +1,21 @@
+import { PublicKey } from "@solana/web3.js";
+import { MessageProtocol } from "./RouterEndpoint";
+
+export type RegisteredRouterEndpoint = {
+    chain: number;
+    protocol: MessageProtocol;
+};
+
+export class RouterEndpointRegistry {
+    bump: number;
+    endpoints: RegisteredRouterEndpoint[];
+
+    constructor(bump: number, endpoints: RegisteredRouterEndpoint[]) {
+        this.bump = bump;
+        this.endpoints = endpoints;
+    }
+
+    static address(programId: PublicKey) {
+        return PublicKey.findProgramAddressSync([Buffer.from("endpoint-registry")], programId)[0];
+    }
+}
@@ -0,0 +1,20 @@
+import { PublicKey } from "@solana/web3.js";
+
+export class SupportedMint {
+    bump: number;
+    mint: PublicKey;
+    feeRecipientToken: PublicKey;
+
+    constructor(bump: number, mint: PublicKey, feeRecipientToken: PublicKey) {
+        this.bump = bump;
+        this.mint = mint;
+        this.feeRecipientToken = feeRecipientToken;
+    }
+
+    static address(programId: PublicKey, mint: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("supported-mint"), mint.toBuffer()],
+            programId,
+        )[0];
+    }
+}
@@ -1,10 +1,16 @@
 export * from "./Auction";
 export * from "./AuctionConfig";
 export * from "./AuctionHistory";
+export * from "./CircuitBreaker";
 export * from "./Custodian";
 export * from "./FastFill";
 export * from "./FastFillSequencer";
+export * from "./InboundNotional";
+export * from "./OwnerSet";
 export * from "./PreparedOrderResponse";
 export * from "./Proposal";
 export * from "./ReservedFastFillSequence";
+export * from "./RoleHolder";
 export * from "./RouterEndpoint";
+export * from "./RouterEndpointRegistry";
+export * from "./SupportedMint";
@@ -1,7 +1,8 @@
 export * from "./state";
-import { Program } from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
 import * as splToken from "@solana/spl-token";
 import {
+    AccountMeta,
     Connection,
     PublicKey,
     SYSVAR_CLOCK_PUBKEY,
@@ -18,6 +19,7 @@ import {
     TokenMessengerMinterProgram,
 } from "../cctp";
 import {
+    Uint64,
     cctpMessageAddress,
     coreMessageAddress,
     reclaimCctpMessageIx,
@@ -27,7 +29,18 @@ import * as matchingEngineSdk from "../matchingEngine";
 import { UpgradeManagerProgram } from "../upgradeManager";
 import { BPF_LOADER_UPGRADEABLE_PROGRAM_ID, programDataAddress } from "../utils";
 import { VaaAccount } from "../wormhole";
-import { Custodian, PreparedFill, PreparedOrder } from "./state";
+import {
+    Custodian,
+    OwnerSet,
+    OwnerSetAccountMeta,
+    OwnerSetTransaction,
+    PreparedFill,
+    PreparedOrder,
+    Role,
+    RoleHolder,
+    SenderState,
+    SwapProgram,
+} from "./state";
 import { ChainId, isChainId } from "@wormhole-foundation/sdk-base";
 
 export const PROGRAM_IDS = [
@@ -46,6 +59,50 @@ export type PrepareMarketOrderArgs = {
     redeemerMessage: Buffer;
 };
 
+export type IntegratorFee = { bps: number } | { amount: bigint };
+
+export type PrepareMarketOrderV2Args = PrepareMarketOrderArgs & {
+    expiry: bigint | null;
+    integratorFee: IntegratorFee | null;
+};
+
+export type PrepareLimitOrderArgs = {
+    amountIn: bigint;
+    maxKeeperFee: bigint;
+    minAmountOut: bigint | null;
+    auction: PublicKey;
+    maxOfferPrice: bigint;
+    expiry: bigint;
+    targetChain: ChainId;
+    redeemer: Array<number>;
+    redeemerMessage: Buffer;
+};
+
+export type PrepareScheduledOrderArgs = {
+    amountIn: bigint;
+    numSlices: number;
+    sliceInterval: bigint;
+    keeperReward: bigint;
+    targetChain: ChainId;
+    redeemer: Array<number>;
+    redeemerMessage: Buffer;
+};
+
+export type RedeemAndSwapArgs = {
+    minAmountOut: bigint;
+    swapData: Buffer;
+};
+
+export type OwnerSetArgs = {
+    threshold: number;
+    members: PublicKey[];
+};
+
+export type OwnerSetTransactionArgs = {
+    accounts: OwnerSetAccountMeta[];
+    data: Buffer;
+};
+
 export type PublishMessageAccounts = {
     coreBridgeConfig: PublicKey;
     coreEmitterSequence: PublicKey;
@@ -100,14 +157,30 @@ export type RedeemFastFillAccounts = {
     custodian: PublicKey;
     preparedFill: PublicKey;
     cctpMintRecipient: PublicKey;
+    mint: PublicKey;
     matchingEngineCustodian: PublicKey;
     matchingEngineFromEndpoint: PublicKey;
     matchingEngineToEndpoint: PublicKey;
     matchingEngineLocalCustodyToken: PublicKey;
+    matchingEngineFastFillCustodyToken: PublicKey | null;
     matchingEngineEventAuthority: PublicKey;
     matchingEngineProgram: PublicKey;
 };
 
+export type RedeemFillTokenBridgeAccounts = {
+    custodian: PublicKey;
+    preparedFill: PublicKey;
+    mintRecipient: PublicKey;
+    sourceRouterEndpoint: PublicKey;
+    tokenBridgeConfig: PublicKey;
+    tokenBridgeClaim: PublicKey;
+    tokenBridgeRegisteredEmitter: PublicKey;
+    tokenBridgeCustodyToken: PublicKey;
+    tokenBridgeCustodyAuthority: PublicKey;
+    coreBridgeProgram: PublicKey;
+    tokenBridgeProgram: PublicKey;
+};
+
 export type AddCctpRouterEndpointArgs = {
     chain: number;
     cctpDomain: number;
@@ -169,6 +242,25 @@ export class TokenRouterProgram {
         return cctpMessageAddress(this.ID, preparedOrder);
     }
 
+    /// Core and CCTP message addresses of a scheduled order's slice, which are derived from the
+    /// number of slices remaining when the slice is placed.
+    scheduledSliceMessageAddresses(
+        preparedOrder: PublicKey,
+        remainingSlices: number,
+    ): { coreMessage: PublicKey; cctpMessage: PublicKey } {
+        const encodedRemainingSlices = Buffer.alloc(2);
+        encodedRemainingSlices.writeUInt16BE(remainingSlices);
+
+        const [coreMessage, cctpMessage] = ["core-msg", "cctp-msg"].map(
+            (prefix) =>
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from(prefix), preparedOrder.toBuffer(), encodedRemainingSlices],
+                    this.ID,
+                )[0],
+        );
+        return { coreMessage, cctpMessage };
+    }
+
     async reclaimCctpMessageIx(
         accounts: {
             payer: PublicKey;
@@ -192,6 +284,76 @@ export class TokenRouterProgram {
         return this.program.account.preparedFill.fetch(addr);
     }
 
+    senderStateAddress(orderSender: PublicKey): PublicKey {
+        return SenderState.address(this.ID, orderSender);
+    }
+
+    async fetchSenderState(input: PublicKey | { address: PublicKey }): Promise<SenderState> {
+        const addr = "address" in input ? input.address : this.senderStateAddress(input);
+        return this.program.account.senderState.fetch(addr);
+    }
+
+    preparedOrderAddress(orderSender: PublicKey, nonce: Uint64): PublicKey {
+        return PreparedOrder.address(this.ID, orderSender, nonce);
+    }
+
+    /// Address of the order sender's next prepared order created by `prepareMarketOrderV2Ix`. If
+    /// the sender state does not exist yet, the nonce is zero.
+    async nextPreparedOrderAddress(orderSender: PublicKey): Promise<PublicKey> {
+        const nextNonce = await this.fetchSenderState(orderSender).then(
+            (state) => state.nextNonce,
+            (_) => 0,
+        );
+        return this.preparedOrderAddress(orderSender, nextNonce);
+    }
+
+    swapProgramAddress(swapProgramId: PublicKey): PublicKey {
+        return SwapProgram.address(this.ID, swapProgramId);
+    }
+
+    async fetchSwapProgram(input: PublicKey | { address: PublicKey }): Promise<SwapProgram> {
+        const addr = "address" in input ? input.address : this.swapProgramAddress(input);
+        return this.program.account.swapProgram.fetch(addr);
+    }
+
+    roleHolderAddress(holder: PublicKey): PublicKey {
+        return RoleHolder.address(this.ID, holder);
+    }
+
+    async fetchRoleHolder(input: PublicKey | { address: PublicKey }): Promise<RoleHolder> {
+        const addr = "address" in input ? input.address : this.roleHolderAddress(input);
+        return this.program.account.roleHolder.fetch(addr);
+    }
+
+    ownerSetAddress(): PublicKey {
+        return OwnerSet.address(this.ID);
+    }
+
+    async fetchOwnerSet(input?: { address: PublicKey }): Promise<OwnerSet> {
+        const addr = input === undefined ? this.ownerSetAddress() : input.address;
+        return this.program.account.ownerSet.fetch(addr);
+    }
+
+    ownerSetTransactionAddress(id: Uint64): PublicKey {
+        return OwnerSetTransaction.address(this.ID, id);
+    }
+
+    async fetchOwnerSetTransaction(
+        input: Uint64 | { address: PublicKey },
+    ): Promise<OwnerSetTransaction> {
+        const addr =
+            typeof input == "object" && "address" in input
+                ? input.address
+                : this.ownerSetTransactionAddress(input);
+        // @ts-ignore This is BS. This is correct.
+        return this.program.account.ownerSetTransaction.fetch(addr);
+    }
+
+    /// Authority that signs when a prepared fill is delivered to its redeemer program.
+    callbackAuthorityAddress(): PublicKey {
+        return PublicKey.findProgramAddressSync([Buffer.from("callback-authority")], this.ID)[0];
+    }
+
     transferAuthorityAddress(
         preparedOrder: PublicKey,
         args: PrepareMarketOrderArgs,
@@ -213,11 +375,111 @@ export class TokenRouterProgram {
         hasher.update(Buffer.from(redeemer));
         hasher.update(redeemerMessage);
 
+        return this.hashedArgsTransferAuthorityAddress(preparedOrder, hasher.digest(), refundToken);
+    }
+
+    transferAuthorityAddressV2(
+        preparedOrder: PublicKey,
+        args: PrepareMarketOrderV2Args,
+        refundToken: PublicKey,
+    ): PublicKey {
+        const {
+            amountIn,
+            minAmountOut,
+            expiry,
+            targetChain,
+            redeemer,
+            redeemerMessage,
+            integratorFee,
+        } = args;
+
+        // These arguments are hashed in their serialized (Borsh) form.
+        const hasher = new Keccak(256);
+        hasher.update(borshU64(amountIn));
+        hasher.update(borshOption(minAmountOut, borshU64));
+        hasher.update(borshOption(expiry, borshI64));
+        hasher.update(borshU16(targetChain));
+        hasher.update(Buffer.from(redeemer));
+        hasher.update(borshBytes(redeemerMessage));
+        hasher.update(
+            borshOption(integratorFee, (fee) =>
+                "bps" in fee
+                    ? Buffer.concat([Buffer.from([0]), borshU32(fee.bps)])
+                    : Buffer.concat([Buffer.from([1]), borshU64(fee.amount)]),
+            ),
+        );
+
+        return this.hashedArgsTransferAuthorityAddress(preparedOrder, hasher.digest(), refundToken);
+    }
+
+    limitOrderTransferAuthorityAddress(
+        preparedOrder: PublicKey,
+        args: PrepareLimitOrderArgs,
+        refundToken: PublicKey,
+    ): PublicKey {
+        const {
+            amountIn,
+            maxKeeperFee,
+            minAmountOut,
+            auction,
+            maxOfferPrice,
+            expiry,
+            targetChain,
+            redeemer,
+            redeemerMessage,
+        } = args;
+
+        const hasher = new Keccak(256);
+        hasher.update(borshU64(amountIn));
+        hasher.update(borshU64(maxKeeperFee));
+        hasher.update(borshOption(minAmountOut, borshU64));
+        hasher.update(auction.toBuffer());
+        hasher.update(borshU64(maxOfferPrice));
+        hasher.update(borshI64(expiry));
+        hasher.update(borshU16(targetChain));
+        hasher.update(Buffer.from(redeemer));
+        hasher.update(borshBytes(redeemerMessage));
+
+        return this.hashedArgsTransferAuthorityAddress(preparedOrder, hasher.digest(), refundToken);
+    }
+
+    scheduledOrderTransferAuthorityAddress(
+        preparedOrder: PublicKey,
+        args: PrepareScheduledOrderArgs,
+        refundToken: PublicKey,
+    ): PublicKey {
+        const {
+            amountIn,
+            numSlices,
+            sliceInterval,
+            keeperReward,
+            targetChain,
+            redeemer,
+            redeemerMessage,
+        } = args;
+
+        const hasher = new Keccak(256);
+        hasher.update(borshU64(amountIn));
+        hasher.update(borshU16(numSlices));
+        hasher.update(borshU64(sliceInterval));
+        hasher.update(borshU64(keeperReward));
+        hasher.update(borshU16(targetChain));
+        hasher.update(Buffer.from(redeemer));
+        hasher.update(borshBytes(redeemerMessage));
+
+        return this.hashedArgsTransferAuthorityAddress(preparedOrder, hasher.digest(), refundToken);
+    }
+
+    private hashedArgsTransferAuthorityAddress(
+        preparedOrder: PublicKey,
+        hashedArgs: Buffer,
+        refundToken: PublicKey,
+    ): PublicKey {
         return PublicKey.findProgramAddressSync(
             [
                 Buffer.from("transfer-authority"),
                 preparedOrder.toBuffer(),
-                hasher.digest(),
+                hashedArgs,
                 refundToken.toBuffer(),
             ],
             this.ID,
@@ -286,6 +548,40 @@ export class TokenRouterProgram {
         return { ownerOrAssistant, custodian: custodian ?? this.custodianAddress() };
     }
 
+    async roleMutComposite(
+        authority: PublicKey,
+        opts: { custodian?: PublicKey; roleHolder?: PublicKey | null } = {},
+    ): Promise<{ authority: PublicKey; custodian: PublicKey; roleHolder: PublicKey | null }> {
+        const { custodian } = opts;
+        return {
+            authority,
+            custodian: custodian ?? this.custodianAddress(),
+            roleHolder: await this.existingRoleHolder(authority, opts.roleHolder),
+        };
+    }
+
+    /// Role holder accounts are optional. If one is not specified, the authority's role holder is
+    /// only used if it exists.
+    private async existingRoleHolder(
+        authority: PublicKey,
+        roleHolder?: PublicKey | null,
+    ): Promise<PublicKey | null> {
+        if (roleHolder !== undefined) {
+            return roleHolder;
+        }
+
+        const addr = this.roleHolderAddress(authority);
+        const accInfo = await this.program.provider.connection.getAccountInfo(addr);
+        return accInfo === null ? null : addr;
+    }
+
+    ownerSetMemberComposite(
+        member: PublicKey,
+        ownerSet?: PublicKey,
+    ): { member: PublicKey; ownerSet: PublicKey } {
+        return { member, ownerSet: ownerSet ?? this.ownerSetAddress() };
+    }
+
     ownerOnlyComposite(
         owner: PublicKey,
         custodian?: PublicKey,
@@ -326,6 +622,17 @@ export class TokenRouterProgram {
         };
     }
 
+    /// Mints other than USDC must be supported by the Matching Engine.
+    checkedMintComposite(mint?: PublicKey): { mint: PublicKey; supportedMint: PublicKey | null } {
+        mint ??= this.mint;
+        return {
+            mint,
+            supportedMint: mint.equals(this.mint)
+                ? null
+                : this.matchingEngineProgram().supportedMintAddress(mint),
+        };
+    }
+
     initIfNeededPreparedFillComposite(accounts: {
         payer: PublicKey;
         vaa: PublicKey;
@@ -352,77 +659,122 @@ export class TokenRouterProgram {
         },
         args: PrepareMarketOrderArgs,
     ): Promise<{ transferAuthority: PublicKey; ix: TransactionInstruction }> {
-        const { preparedOrder, senderToken, refundToken } = accounts;
+        const { preparedOrder, senderToken, refundToken, senderTokenAuthority } = accounts;
         const { amountIn } = args;
 
-        let { senderTokenAuthority } = accounts;
-        senderTokenAuthority ??= await (async () => {
-            const tokenAccount = await splToken.getAccount(
-                this.program.provider.connection,
-                senderToken,
-            );
-            return tokenAccount.owner;
-        })();
-
         const transferAuthority = this.transferAuthorityAddress(preparedOrder, args, refundToken);
 
         return {
             transferAuthority,
-            ix: splToken.createApproveInstruction(
+            ix: await this.approveSenderTokenIx(
                 senderToken,
                 transferAuthority,
-                senderTokenAuthority,
                 amountIn,
+                senderTokenAuthority,
             ),
         };
     }
 
-    async prepareMarketOrderIx(
+    private async approveSenderTokenIx(
+        senderToken: PublicKey,
+        transferAuthority: PublicKey,
+        amountIn: bigint,
+        senderTokenAuthority?: PublicKey,
+    ): Promise<TransactionInstruction> {
+        senderTokenAuthority ??= await this.senderTokenOwner(senderToken);
+
+        return splToken.createApproveInstruction(
+            senderToken,
+            transferAuthority,
+            senderTokenAuthority,
+            amountIn,
+        );
+    }
+
+    private async senderTokenOwner(senderToken: PublicKey): Promise<PublicKey> {
+        const tokenAccount = await splToken.getAccount(
+            this.program.provider.connection,
+            senderToken,
+        );
+        return tokenAccount.owner;
+    }
+
+    /// If neither the sender nor the program transfer authority is specified, the program transfer
+    /// authority is used by default, which the sender token must approve for the amount in.
+    private async orderSenderAccounts(
         accounts: {
-            payer: PublicKey;
-            preparedOrder: PublicKey;
             senderToken: PublicKey;
             senderTokenAuthority?: PublicKey;
-            refundToken?: PublicKey;
             programTransferAuthority?: PublicKey | null;
             sender?: PublicKey | null;
-            targetRouterEndpoint?: PublicKey;
         },
-        args: { useTransferAuthority?: boolean } & PrepareMarketOrderArgs,
-    ): Promise<[approveIx: TransactionInstruction | null, prepareIx: TransactionInstruction]> {
-        const {
-            payer,
-            preparedOrder,
-            senderToken,
-            senderTokenAuthority,
-            targetRouterEndpoint: endpoint,
-        } = accounts;
-
-        let { refundToken, programTransferAuthority, sender } = accounts;
-        refundToken ??= senderToken;
-
-        let { useTransferAuthority } = args;
+        opts: {
+            amountIn: bigint;
+            useTransferAuthority?: boolean;
+            transferAuthority: () => PublicKey;
+        },
+    ): Promise<{
+        approveIx: TransactionInstruction | null;
+        programTransferAuthority: PublicKey | null;
+        sender: PublicKey | null;
+    }> {
+        const { senderToken, senderTokenAuthority } = accounts;
+        const { amountIn, transferAuthority } = opts;
+
+        let { programTransferAuthority, sender } = accounts;
+        sender ??= null;
+
+        let { useTransferAuthority } = opts;
         useTransferAuthority ??= true;
 
         let approveIx: TransactionInstruction | null = null;
 
-        if (sender === undefined) {
-            sender = null;
-        }
-
         if (programTransferAuthority === undefined) {
             if (useTransferAuthority) {
-                const approveResult = await this.approveTransferAuthorityIx(
-                    { preparedOrder, senderToken, refundToken, senderTokenAuthority },
-                    args,
+                programTransferAuthority = transferAuthority();
+                approveIx = await this.approveSenderTokenIx(
+                    senderToken,
+                    programTransferAuthority,
+                    amountIn,
+                    senderTokenAuthority,
                 );
-                programTransferAuthority = approveResult.transferAuthority;
-                approveIx = approveResult.ix;
             } else {
                 programTransferAuthority = null;
             }
         }
 
+        return { approveIx, programTransferAuthority, sender };
+    }
+
+    async prepareMarketOrderIx(
+        accounts: {
+            payer: PublicKey;
+            preparedOrder: PublicKey;
+            senderToken: PublicKey;
+            senderTokenAuthority?: PublicKey;
+            refundToken?: PublicKey;
+            programTransferAuthority?: PublicKey | null;
+            sender?: PublicKey | null;
+            targetRouterEndpoint?: PublicKey;
+            mint?: PublicKey;
+        },
+        args: { useTransferAuthority?: boolean } & PrepareMarketOrderArgs,
+    ): Promise<[approveIx: TransactionInstruction | null, prepareIx: TransactionInstruction]> {
+        const { payer, preparedOrder, senderToken, targetRouterEndpoint: endpoint, mint } = accounts;
+
+        let { refundToken } = accounts;
+        refundToken ??= senderToken;
+
+        const { approveIx, programTransferAuthority, sender } = await this.orderSenderAccounts(
+            accounts,
+            {
+                amountIn: args.amountIn,
+                useTransferAuthority: args.useTransferAuthority,
+                transferAuthority: () =>
+                    this.transferAuthorityAddress(preparedOrder, args, refundToken!),
+            },
+        );
+
         const targetRouterEndpoint = this.registeredEndpointComposite({
             chain: args.targetChain,
             endpoint,
@@ -444,7 +796,219 @@ export class TokenRouterProgram {
                 senderToken,
                 refundToken,
                 preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrder),
-                usdc: this.usdcComposite(),
+                mint: this.checkedMintComposite(mint),
+                targetRouterEndpoint,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+
+        return [approveIx, prepareIx];
+    }
+
+    /// Unlike `prepareMarketOrderIx`, the prepared order's address is derived from the order
+    /// sender's next nonce. See `nextPreparedOrderAddress`.
+    async prepareMarketOrderV2Ix(
+        accounts: {
+            payer: PublicKey;
+            senderToken: PublicKey;
+            senderTokenAuthority?: PublicKey;
+            preparedOrder?: PublicKey;
+            refundToken?: PublicKey;
+            programTransferAuthority?: PublicKey | null;
+            sender?: PublicKey | null;
+            integratorFeeToken?: PublicKey | null;
+            targetRouterEndpoint?: PublicKey;
+            mint?: PublicKey;
+        },
+        args: { useTransferAuthority?: boolean } & PrepareMarketOrderV2Args,
+    ): Promise<[approveIx: TransactionInstruction | null, prepareIx: TransactionInstruction]> {
+        const { payer, senderToken, targetRouterEndpoint: endpoint, mint } = accounts;
+
+        let { senderTokenAuthority, preparedOrder, refundToken, integratorFeeToken } = accounts;
+        refundToken ??= senderToken;
+        integratorFeeToken ??= null;
+
+        // The order sender is the sender if specified. Otherwise it is the sender token's owner.
+        const orderSender =
+            accounts.sender ?? (senderTokenAuthority ??= await this.senderTokenOwner(senderToken));
+        preparedOrder ??= await this.nextPreparedOrderAddress(orderSender);
+
+        const { approveIx, programTransferAuthority, sender } = await this.orderSenderAccounts(
+            { ...accounts, senderTokenAuthority },
+            {
+                amountIn: args.amountIn,
+                useTransferAuthority: args.useTransferAuthority,
+                transferAuthority: () =>
+                    this.transferAuthorityAddressV2(preparedOrder!, args, refundToken!),
+            },
+        );
+
+        const targetRouterEndpoint = this.registeredEndpointComposite({
+            chain: args.targetChain,
+            endpoint,
+        });
+
+        const { integratorFee } = args;
+
+        const prepareIx = await this.program.methods
+            .prepareMarketOrderV2({
+                amountIn: uint64ToBN(args.amountIn),
+                minAmountOut: args.minAmountOut === null ? null : uint64ToBN(args.minAmountOut),
+                expiry: args.expiry === null ? null : new BN(args.expiry.toString()),
+                targetChain: args.targetChain,
+                redeemer: args.redeemer,
+                redeemerMessage: args.redeemerMessage,
+                integratorFee:
+                    integratorFee === null
+                        ? null
+                        : "bps" in integratorFee
+                          ? { bps: [integratorFee.bps] }
+                          : { amount: [uint64ToBN(integratorFee.amount)] },
+            })
+            .accounts({
+                payer,
+                custodian: this.checkedCustodianComposite(),
+                // @ts-ignore Sender can be null.
+                sender,
+                senderToken,
+                senderState: this.senderStateAddress(orderSender),
+                preparedOrder,
+                programTransferAuthority,
+                refundToken,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrder),
+                mint: this.checkedMintComposite(mint),
+                integratorFeeToken,
+                targetRouterEndpoint,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+
+        return [approveIx, prepareIx];
+    }
+
+    async prepareLimitOrderIx(
+        accounts: {
+            payer: PublicKey;
+            preparedOrder: PublicKey;
+            senderToken: PublicKey;
+            senderTokenAuthority?: PublicKey;
+            refundToken?: PublicKey;
+            programTransferAuthority?: PublicKey | null;
+            sender?: PublicKey | null;
+            targetRouterEndpoint?: PublicKey;
+            mint?: PublicKey;
+        },
+        args: { useTransferAuthority?: boolean } & PrepareLimitOrderArgs,
+    ): Promise<[approveIx: TransactionInstruction | null, prepareIx: TransactionInstruction]> {
+        const { payer, preparedOrder, senderToken, targetRouterEndpoint: endpoint, mint } = accounts;
+
+        let { refundToken } = accounts;
+        refundToken ??= senderToken;
+
+        const { approveIx, programTransferAuthority, sender } = await this.orderSenderAccounts(
+            accounts,
+            {
+                amountIn: args.amountIn,
+                useTransferAuthority: args.useTransferAuthority,
+                transferAuthority: () =>
+                    this.limitOrderTransferAuthorityAddress(preparedOrder, args, refundToken!),
+            },
+        );
+
+        const targetRouterEndpoint = this.registeredEndpointComposite({
+            chain: args.targetChain,
+            endpoint,
+        });
+
+        const prepareIx = await this.program.methods
+            .prepareLimitOrder({
+                amountIn: uint64ToBN(args.amountIn),
+                maxKeeperFee: uint64ToBN(args.maxKeeperFee),
+                minAmountOut: args.minAmountOut === null ? null : uint64ToBN(args.minAmountOut),
+                auction: args.auction,
+                maxOfferPrice: uint64ToBN(args.maxOfferPrice),
+                expiry: new BN(args.expiry.toString()),
+                targetChain: args.targetChain,
+                redeemer: args.redeemer,
+                redeemerMessage: args.redeemerMessage,
+            })
+            .accounts({
+                payer,
+                custodian: this.checkedCustodianComposite(),
+                programTransferAuthority,
+                // @ts-ignore Sender can be null.
+                sender,
+                preparedOrder,
+                senderToken,
+                refundToken,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrder),
+                mint: this.checkedMintComposite(mint),
+                targetRouterEndpoint,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+
+        return [approveIx, prepareIx];
+    }
+
+    async prepareScheduledOrderIx(
+        accounts: {
+            payer: PublicKey;
+            preparedOrder: PublicKey;
+            senderToken: PublicKey;
+            senderTokenAuthority?: PublicKey;
+            refundToken?: PublicKey;
+            programTransferAuthority?: PublicKey | null;
+            sender?: PublicKey | null;
+            targetRouterEndpoint?: PublicKey;
+            mint?: PublicKey;
+        },
+        args: { useTransferAuthority?: boolean } & PrepareScheduledOrderArgs,
+    ): Promise<[approveIx: TransactionInstruction | null, prepareIx: TransactionInstruction]> {
+        const { payer, preparedOrder, senderToken, targetRouterEndpoint: endpoint, mint } = accounts;
+
+        let { refundToken } = accounts;
+        refundToken ??= senderToken;
+
+        const { approveIx, programTransferAuthority, sender } = await this.orderSenderAccounts(
+            accounts,
+            {
+                amountIn: args.amountIn,
+                useTransferAuthority: args.useTransferAuthority,
+                transferAuthority: () =>
+                    this.scheduledOrderTransferAuthorityAddress(preparedOrder, args, refundToken!),
+            },
+        );
+
+        const targetRouterEndpoint = this.registeredEndpointComposite({
+            chain: args.targetChain,
+            endpoint,
+        });
+
+        const prepareIx = await this.program.methods
+            .prepareScheduledOrder({
+                amountIn: uint64ToBN(args.amountIn),
+                numSlices: args.numSlices,
+                sliceInterval: uint64ToBN(args.sliceInterval),
+                keeperReward: uint64ToBN(args.keeperReward),
+                targetChain: args.targetChain,
+                redeemer: args.redeemer,
+                redeemerMessage: args.redeemerMessage,
+            })
+            .accounts({
+                payer,
+                custodian: this.checkedCustodianComposite(),
+                programTransferAuthority,
+                // @ts-ignore Sender can be null.
+                sender,
+                preparedOrder,
+                senderToken,
+                refundToken,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrder),
+                mint: this.checkedMintComposite(mint),
                 targetRouterEndpoint,
                 tokenProgram: splToken.TOKEN_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
@@ -485,28 +1049,284 @@ export class TokenRouterProgram {
             .instruction();
     }
 
-    async consumePreparedFillIx(accounts: {
-        preparedFill: PublicKey;
-        redeemer: PublicKey;
-        dstToken: PublicKey;
-        beneficiary: PublicKey;
-    }): Promise<TransactionInstruction> {
-        const { preparedFill, redeemer, dstToken, beneficiary } = accounts;
+    async consumePreparedFillIx(accounts: {
+        preparedFill: PublicKey;
+        redeemer: PublicKey;
+        dstToken: PublicKey;
+        beneficiary: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { preparedFill, redeemer, dstToken, beneficiary } = accounts;
+
+        return this.program.methods
+            .consumePreparedFill()
+            .accounts({
+                redeemer,
+                beneficiary,
+                preparedFill,
+                dstToken,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedFill),
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+            })
+            .instruction();
+    }
+
+    async consumePreparedFillPartialIx(
+        accounts: {
+            preparedFill: PublicKey;
+            redeemer: PublicKey;
+            dstToken: PublicKey;
+            beneficiary: PublicKey;
+        },
+        amount: bigint,
+    ): Promise<TransactionInstruction> {
+        const { preparedFill, redeemer, dstToken, beneficiary } = accounts;
+
+        return this.program.methods
+            .consumePreparedFillPartial(uint64ToBN(amount))
+            .accounts({
+                redeemer,
+                beneficiary,
+                preparedFill,
+                dstToken,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedFill),
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+            })
+            .instruction();
+    }
+
+    /// The swap instruction's accounts are passed as remaining accounts. The prepared fill signs
+    /// for this instruction, so it should not be flagged as a signer.
+    async redeemAndSwapIx(
+        accounts: {
+            preparedFill: PublicKey;
+            redeemer: PublicKey;
+            dstToken: PublicKey;
+            beneficiary: PublicKey;
+            swapProgram: PublicKey;
+            swapAccounts: AccountMeta[];
+        },
+        args: RedeemAndSwapArgs,
+    ): Promise<TransactionInstruction> {
+        const { preparedFill, redeemer, dstToken, beneficiary, swapProgram, swapAccounts } =
+            accounts;
+        const { minAmountOut, swapData } = args;
+
+        return this.program.methods
+            .redeemAndSwap({ minAmountOut: uint64ToBN(minAmountOut), swapData })
+            .accounts({
+                redeemer,
+                beneficiary,
+                preparedFill,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedFill),
+                dstToken,
+                swapProgram: this.swapProgramAddress(swapProgram),
+                program: swapProgram,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+            })
+            .remainingAccounts(swapAccounts)
+            .instruction();
+    }
+
+    /// Any accounts required by the redeemer program's callback are passed as remaining accounts.
+    async deliverPreparedFillIx(
+        accounts: {
+            preparedFill: PublicKey;
+            redeemerVaultToken: PublicKey;
+            preparedBy?: PublicKey;
+            redeemerProgram?: PublicKey;
+        },
+        remainingAccounts: AccountMeta[] = [],
+    ): Promise<TransactionInstruction> {
+        const { preparedFill, redeemerVaultToken } = accounts;
+
+        let { preparedBy, redeemerProgram } = accounts;
+
+        if (preparedBy === undefined || redeemerProgram === undefined) {
+            const { info } = await this.fetchPreparedFill(preparedFill);
+
+            preparedBy ??= info.preparedBy;
+            redeemerProgram ??= info.redeemer;
+        }
+
+        return this.program.methods
+            .deliverPreparedFill()
+            .accounts({
+                callbackAuthority: this.callbackAuthorityAddress(),
+                preparedBy,
+                preparedFill,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedFill),
+                redeemerProgram,
+                redeemerVaultToken,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+            })
+            .remainingAccounts(remainingAccounts)
+            .instruction();
+    }
+
+    async relayFastFillIx(accounts: {
+        relayer: PublicKey;
+        relayerToken: PublicKey;
+        preparedFill: PublicKey;
+        preparedBy?: PublicKey;
+        fastFill?: PublicKey;
+        redeemer?: PublicKey;
+        redeemerToken?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { relayer, relayerToken, preparedFill } = accounts;
+
+        let { preparedBy, fastFill, redeemer, redeemerToken } = accounts;
+
+        if (preparedBy === undefined || fastFill === undefined || redeemer === undefined) {
+            const { seeds, info } = await this.fetchPreparedFill(preparedFill);
+
+            preparedBy ??= info.preparedBy;
+            fastFill ??= seeds.fillSource;
+            redeemer ??= info.redeemer;
+        }
+
+        const preparedCustodyToken = this.preparedCustodyTokenAddress(preparedFill);
+
+        if (redeemerToken === undefined) {
+            const { mint } = await splToken.getAccount(
+                this.program.provider.connection,
+                preparedCustodyToken,
+            );
+            redeemerToken = splToken.getAssociatedTokenAddressSync(mint, redeemer, true);
+        }
+
+        return this.program.methods
+            .relayFastFill()
+            .accounts({
+                relayer,
+                relayerToken,
+                preparedBy,
+                fastFill,
+                preparedFill,
+                preparedCustodyToken,
+                redeemer,
+                redeemerToken,
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+            })
+            .instruction();
+    }
+
+    async refundExpiredPreparedOrderIx(accounts: {
+        preparedOrder: PublicKey;
+        preparedBy?: PublicKey;
+        refundToken?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { preparedOrder } = accounts;
+
+        let { preparedBy, refundToken } = accounts;
+
+        if (preparedBy === undefined || refundToken === undefined) {
+            const { info } = await this.fetchPreparedOrder(preparedOrder);
+
+            preparedBy ??= info.preparedBy;
+            refundToken ??= info.refundToken;
+        }
+
+        return this.program.methods
+            .refundExpiredPreparedOrder()
+            .accounts({
+                custodian: this.checkedCustodianComposite(),
+                preparedOrder,
+                preparedBy,
+                refundToken,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrder),
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+            })
+            .instruction();
+    }
+
+    async placeMarketOrderCctpIx(
+        accounts: {
+            payer: PublicKey;
+            preparedOrder: PublicKey;
+            preparedBy?: PublicKey;
+            targetRouterEndpoint?: PublicKey;
+        },
+        args: {
+            targetChain?: ChainId;
+            destinationDomain?: number;
+        } = {},
+    ): Promise<TransactionInstruction> {
+        return this.program.methods
+            .placeMarketOrderCctp()
+            .accounts(await this.placeOrderCctpAccounts(accounts, args))
+            .instruction();
+    }
+
+    async placeLimitOrderCctpIx(
+        accounts: {
+            payer: PublicKey;
+            preparedOrder: PublicKey;
+            keeperToken: PublicKey;
+            auction?: PublicKey;
+            preparedBy?: PublicKey;
+            targetRouterEndpoint?: PublicKey;
+        },
+        args: {
+            keeperFee: bigint;
+            targetChain?: ChainId;
+            destinationDomain?: number;
+        },
+    ): Promise<TransactionInstruction> {
+        const { preparedOrder, keeperToken } = accounts;
+
+        let { auction } = accounts;
+        if (auction === undefined) {
+            const { info } = await this.fetchPreparedOrder(preparedOrder);
+            if (info.orderType.limit === undefined) {
+                throw new Error("Prepared order is not a limit order");
+            }
+            auction = info.orderType.limit.auction;
+        }
+
+        return this.program.methods
+            .placeLimitOrderCctp(uint64ToBN(args.keeperFee))
+            .accounts({
+                ...(await this.placeOrderCctpAccounts(accounts, args)),
+                keeperToken,
+                auction,
+            })
+            .instruction();
+    }
+
+    async placeScheduledOrderSliceCctpIx(
+        accounts: {
+            payer: PublicKey;
+            preparedOrder: PublicKey;
+            keeperToken: PublicKey;
+            preparedBy?: PublicKey;
+            targetRouterEndpoint?: PublicKey;
+        },
+        args: {
+            targetChain?: ChainId;
+            destinationDomain?: number;
+        } = {},
+    ): Promise<TransactionInstruction> {
+        const { preparedOrder, keeperToken } = accounts;
+
+        const { info } = await this.fetchPreparedOrder(preparedOrder);
+        if (info.orderType.scheduled === undefined) {
+            throw new Error("Prepared order is not a scheduled order");
+        }
 
         return this.program.methods
-            .consumePreparedFill()
+            .placeScheduledOrderSliceCctp()
             .accounts({
-                redeemer,
-                beneficiary,
-                preparedFill,
-                dstToken,
-                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedFill),
-                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                ...(await this.placeOrderCctpAccounts(accounts, args)),
+                ...this.scheduledSliceMessageAddresses(
+                    preparedOrder,
+                    info.orderType.scheduled.remainingSlices,
+                ),
+                keeperToken,
             })
             .instruction();
     }
 
-    async placeMarketOrderCctpIx(
+    private async placeOrderCctpAccounts(
         accounts: {
             payer: PublicKey;
             preparedOrder: PublicKey;
@@ -516,8 +1336,8 @@ export class TokenRouterProgram {
         args: {
             targetChain?: ChainId;
             destinationDomain?: number;
-        } = {},
-    ): Promise<TransactionInstruction> {
+        },
+    ) {
         const { payer, preparedOrder, targetRouterEndpoint: endpoint } = accounts;
         let { preparedBy } = accounts;
         let { targetChain, destinationDomain } = args;
@@ -573,37 +1393,34 @@ export class TokenRouterProgram {
         const { coreBridgeConfig, coreEmitterSequence, coreFeeCollector, coreBridgeProgram } =
             this.publishMessageAccounts(custodian);
 
-        return this.program.methods
-            .placeMarketOrderCctp()
-            .accounts({
-                payer,
-                preparedBy,
-                custodian: this.checkedCustodianComposite(),
-                preparedOrder,
-                mint: this.mint,
-                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrder),
-                targetRouterEndpoint,
-                coreBridgeConfig,
-                coreMessage,
-                cctpMessage,
-                coreEmitterSequence,
-                coreFeeCollector,
-                tokenMessengerMinterSenderAuthority,
-                messageTransmitterConfig,
-                tokenMessenger,
-                remoteTokenMessenger,
-                tokenMinter,
-                localToken,
-                tokenMessengerMinterEventAuthority,
-                coreBridgeProgram,
-                tokenMessengerMinterProgram,
-                messageTransmitterProgram,
-                tokenProgram: splToken.TOKEN_PROGRAM_ID,
-                systemProgram: SystemProgram.programId,
-                rent: SYSVAR_RENT_PUBKEY,
-                clock: SYSVAR_CLOCK_PUBKEY,
-            })
-            .instruction();
+        return {
+            payer,
+            preparedBy,
+            custodian: this.checkedCustodianComposite(),
+            preparedOrder,
+            mint: this.mint,
+            preparedCustodyToken: this.preparedCustodyTokenAddress(preparedOrder),
+            targetRouterEndpoint,
+            coreBridgeConfig,
+            coreMessage,
+            cctpMessage,
+            coreEmitterSequence,
+            coreFeeCollector,
+            tokenMessengerMinterSenderAuthority,
+            messageTransmitterConfig,
+            tokenMessenger,
+            remoteTokenMessenger,
+            tokenMinter,
+            localToken,
+            tokenMessengerMinterEventAuthority,
+            coreBridgeProgram,
+            tokenMessengerMinterProgram,
+            messageTransmitterProgram,
+            tokenProgram: splToken.TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+            clock: SYSVAR_CLOCK_PUBKEY,
+        };
     }
 
     async redeemCctpFillAccounts(
@@ -701,7 +1518,7 @@ export class TokenRouterProgram {
                 fillVaa: this.liquidityLayerVaaComposite(vaa),
                 preparedFill,
                 preparedCustodyToken: this.preparedCustodyTokenAddress(preparedFill),
-                usdc: this.usdcComposite(),
+                mint: this.checkedMintComposite(),
                 sourceRouterEndpoint,
                 cctp: {
                     mintRecipient: { mintRecipient: cctpMintRecipient },
@@ -731,6 +1548,8 @@ export class TokenRouterProgram {
             fromRouterEndpoint: matchingEngineFromEndpoint,
             toRouterEndpoint: matchingEngineToEndpoint,
             localCustodyToken: matchingEngineLocalCustodyToken,
+            fastFillCustodyToken: matchingEngineFastFillCustodyToken,
+            mint,
             eventAuthority: matchingEngineEventAuthority,
             matchingEngineProgram,
         } = await this.matchingEngineProgram().redeemFastFillAccounts(fastFill);
@@ -739,14 +1558,17 @@ export class TokenRouterProgram {
             custodian: this.custodianAddress(),
             preparedFill: this.preparedFillAddress(fastFill),
             cctpMintRecipient: this.cctpMintRecipientAddress(),
+            mint,
             matchingEngineCustodian,
             matchingEngineFromEndpoint,
             matchingEngineToEndpoint,
             matchingEngineLocalCustodyToken,
+            matchingEngineFastFillCustodyToken,
             matchingEngineEventAuthority,
             matchingEngineProgram,
         };
     }
+
     async redeemFastFillIx(accounts: {
         payer: PublicKey;
         fastFill: PublicKey;
@@ -754,10 +1576,12 @@ export class TokenRouterProgram {
         const { payer, fastFill } = accounts;
         const {
             preparedFill,
+            mint,
             matchingEngineCustodian,
             matchingEngineFromEndpoint,
             matchingEngineToEndpoint,
             matchingEngineLocalCustodyToken,
+            matchingEngineFastFillCustodyToken,
             matchingEngineEventAuthority,
             matchingEngineProgram,
         } = await this.redeemFastFillAccounts(fastFill);
@@ -770,11 +1594,12 @@ export class TokenRouterProgram {
                 fastFill,
                 preparedFill,
                 preparedCustodyToken: this.preparedCustodyTokenAddress(preparedFill),
-                usdc: this.usdcComposite(),
+                mint,
                 matchingEngineCustodian,
                 matchingEngineFromEndpoint,
                 matchingEngineToEndpoint,
                 matchingEngineLocalCustodyToken,
+                matchingEngineFastFillCustodyToken,
                 matchingEngineEventAuthority,
                 matchingEngineProgram,
                 tokenProgram: splToken.TOKEN_PROGRAM_ID,
@@ -783,6 +1608,105 @@ export class TokenRouterProgram {
             .instruction();
     }
 
+    async redeemTokenBridgeFillAccounts(
+        fillVaa: PublicKey,
+        mint: PublicKey,
+    ): Promise<RedeemFillTokenBridgeAccounts> {
+        const vaaAccount = await VaaAccount.fetch(this.program.provider.connection, fillVaa);
+        const { chain, address, sequence } = vaaAccount.emitterInfo();
+
+        // The claim and registered emitter are derived from the foreign Token Bridge's emitter info.
+        const matchingEngine = this.matchingEngineProgram();
+        const tokenBridgeProgram = matchingEngine.tokenBridgeProgramId();
+
+        const encodedChain = Buffer.alloc(2);
+        encodedChain.writeUInt16BE(chain);
+
+        return {
+            custodian: this.custodianAddress(),
+            preparedFill: this.preparedFillAddress(fillVaa),
+            mintRecipient: splToken.getAssociatedTokenAddressSync(
+                mint,
+                this.custodianAddress(),
+                true,
+            ),
+            sourceRouterEndpoint: matchingEngine.routerEndpointAddress(chain),
+            tokenBridgeConfig: PublicKey.findProgramAddressSync(
+                [Buffer.from("config")],
+                tokenBridgeProgram,
+            )[0],
+            tokenBridgeClaim: PublicKey.findProgramAddressSync(
+                [Buffer.from(address), encodedChain, uint64ToBN(sequence).toBuffer("be", 8)],
+                tokenBridgeProgram,
+            )[0],
+            tokenBridgeRegisteredEmitter: PublicKey.findProgramAddressSync(
+                [encodedChain, Buffer.from(address)],
+                tokenBridgeProgram,
+            )[0],
+            tokenBridgeCustodyToken: PublicKey.findProgramAddressSync(
+                [mint.toBuffer()],
+                tokenBridgeProgram,
+            )[0],
+            tokenBridgeCustodyAuthority: PublicKey.findProgramAddressSync(
+                [Buffer.from("custody_signer")],
+                tokenBridgeProgram,
+            )[0],
+            coreBridgeProgram: this.coreBridgeProgramId(),
+            tokenBridgeProgram,
+        };
+    }
+
+    async redeemTokenBridgeFillIx(accounts: {
+        payer: PublicKey;
+        vaa: PublicKey;
+        mint: PublicKey;
+        sourceRouterEndpoint?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { payer, vaa, mint, sourceRouterEndpoint: endpoint } = accounts;
+
+        const {
+            preparedFill,
+            mintRecipient,
+            sourceRouterEndpoint: derivedRouterEndpoint,
+            tokenBridgeConfig,
+            tokenBridgeClaim,
+            tokenBridgeRegisteredEmitter,
+            tokenBridgeCustodyToken,
+            tokenBridgeCustodyAuthority,
+            coreBridgeProgram,
+            tokenBridgeProgram,
+        } = await this.redeemTokenBridgeFillAccounts(vaa, mint);
+        const sourceRouterEndpoint = this.registeredEndpointComposite({
+            endpoint: endpoint ?? derivedRouterEndpoint,
+        });
+
+        return this.program.methods
+            .redeemTokenBridgeFill()
+            .accounts({
+                payer,
+                custodian: this.checkedCustodianComposite(),
+                fillVaa: this.liquidityLayerVaaComposite(vaa),
+                preparedFill,
+                preparedCustodyToken: this.preparedCustodyTokenAddress(preparedFill),
+                mint: this.checkedMintComposite(mint),
+                sourceRouterEndpoint,
+                tokenBridge: {
+                    mintRecipient: { mintRecipient },
+                    config: tokenBridgeConfig,
+                    claim: tokenBridgeClaim,
+                    registeredEmitter: tokenBridgeRegisteredEmitter,
+                    custodyToken: tokenBridgeCustodyToken,
+                    custodyAuthority: tokenBridgeCustodyAuthority,
+                    coreBridgeProgram,
+                    tokenBridgeProgram,
+                    rent: SYSVAR_RENT_PUBKEY,
+                },
+                tokenProgram: splToken.TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
     async initializeIx(accounts: {
         owner: PublicKey;
         ownerAssistant: PublicKey;
@@ -810,18 +1734,141 @@ export class TokenRouterProgram {
             .instruction();
     }
 
+    async migrateIx(accounts: {
+        owner: PublicKey;
+        payer?: PublicKey;
+        custodian?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { owner } = accounts;
+
+        let { payer, custodian } = accounts;
+        payer ??= owner;
+        custodian ??= this.custodianAddress();
+
+        return this.program.methods
+            .migrate()
+            .accounts({
+                payer,
+                owner,
+                custodian,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
     async setPauseIx(
         accounts: {
-            ownerOrAssistant: PublicKey;
+            authority: PublicKey;
             custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
         },
         paused: boolean,
     ): Promise<TransactionInstruction> {
-        const { ownerOrAssistant, custodian: inputCustodian } = accounts;
+        const { authority, custodian, roleHolder } = accounts;
         return this.program.methods
             .setPause(paused)
             .accounts({
-                admin: this.adminMutComposite(ownerOrAssistant, inputCustodian),
+                admin: await this.roleMutComposite(authority, { custodian, roleHolder }),
+            })
+            .instruction();
+    }
+
+    async grantRoleIx(
+        accounts: {
+            owner: PublicKey;
+            holder: PublicKey;
+            payer?: PublicKey;
+            custodian?: PublicKey;
+        },
+        role: Role,
+    ): Promise<TransactionInstruction> {
+        const { owner, holder, custodian } = accounts;
+
+        let { payer } = accounts;
+        payer ??= owner;
+
+        return this.program.methods
+            .grantRole(role)
+            .accounts({
+                payer,
+                admin: this.ownerOnlyComposite(owner, custodian),
+                holder,
+                roleHolder: this.roleHolderAddress(holder),
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    async revokeRoleIx(
+        accounts: {
+            owner: PublicKey;
+            holder: PublicKey;
+            custodian?: PublicKey;
+        },
+        role: Role,
+    ): Promise<TransactionInstruction> {
+        const { owner, holder, custodian } = accounts;
+        return this.program.methods
+            .revokeRole(role)
+            .accounts({
+                admin: this.ownerOnlyComposite(owner, custodian),
+                roleHolder: this.roleHolderAddress(holder),
+            })
+            .instruction();
+    }
+
+    async addSwapProgramIx(accounts: {
+        owner: PublicKey;
+        program: PublicKey;
+        payer?: PublicKey;
+        custodian?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { owner, program, custodian } = accounts;
+
+        let { payer } = accounts;
+        payer ??= owner;
+
+        return this.program.methods
+            .addSwapProgram()
+            .accounts({
+                payer,
+                admin: this.ownerOnlyComposite(owner, custodian),
+                program,
+                swapProgram: this.swapProgramAddress(program),
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    async removeSwapProgramIx(accounts: {
+        owner: PublicKey;
+        program: PublicKey;
+        custodian?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { owner, program, custodian } = accounts;
+        return this.program.methods
+            .removeSwapProgram()
+            .accounts({
+                owner,
+                custodian: this.checkedCustodianComposite(custodian),
+                swapProgram: this.swapProgramAddress(program),
+            })
+            .instruction();
+    }
+
+    async updateMaxIntegratorFeeBpsIx(
+        accounts: {
+            authority: PublicKey;
+            custodian?: PublicKey;
+            roleHolder?: PublicKey | null;
+        },
+        maxFeeBps: number,
+    ): Promise<TransactionInstruction> {
+        const { authority, custodian, roleHolder } = accounts;
+        return this.program.methods
+            .updateMaxIntegratorFeeBps(maxFeeBps)
+            .accounts({
+                admin: await this.roleMutComposite(authority, { custodian, roleHolder }),
             })
             .instruction();
     }
@@ -883,6 +1930,160 @@ export class TokenRouterProgram {
             .instruction();
     }
 
+    async acceptOwnerAssistantIx(accounts: {
+        pendingOwnerAssistant: PublicKey;
+        custodian?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { pendingOwnerAssistant } = accounts;
+
+        let { custodian } = accounts;
+        custodian ??= this.custodianAddress();
+
+        return this.program.methods
+            .acceptOwnerAssistant()
+            .accounts({ pendingOwnerAssistant, custodian })
+            .instruction();
+    }
+
+    async initializeOwnerSetIx(
+        accounts: {
+            owner: PublicKey;
+            payer?: PublicKey;
+            custodian?: PublicKey;
+        },
+        args: OwnerSetArgs,
+    ): Promise<TransactionInstruction> {
+        const { owner, custodian } = accounts;
+
+        let { payer } = accounts;
+        payer ??= owner;
+
+        return this.program.methods
+            .initializeOwnerSet(args)
+            .accounts({
+                payer,
+                admin: this.ownerOnlyComposite(owner, custodian),
+                ownerSet: this.ownerSetAddress(),
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    /// Approvals from former members are dropped from the specified pending transactions.
+    async updateOwnerSetIx(
+        accounts: {
+            owner: PublicKey;
+            custodian?: PublicKey;
+            pendingTransactions?: PublicKey[];
+        },
+        args: OwnerSetArgs,
+    ): Promise<TransactionInstruction> {
+        const { owner, custodian } = accounts;
+
+        let { pendingTransactions } = accounts;
+        pendingTransactions ??= [];
+
+        return this.program.methods
+            .updateOwnerSet(args)
+            .accounts({
+                admin: this.ownerOnlyComposite(owner, custodian),
+                ownerSet: this.ownerSetAddress(),
+            })
+            .remainingAccounts(
+                pendingTransactions.map((pubkey) => ({
+                    pubkey,
+                    isSigner: false,
+                    isWritable: true,
+                })),
+            )
+            .instruction();
+    }
+
+    async proposeOwnerSetTransactionIx(
+        accounts: {
+            member: PublicKey;
+            payer?: PublicKey;
+            transaction?: PublicKey;
+        },
+        args: OwnerSetTransactionArgs,
+    ): Promise<TransactionInstruction> {
+        const { member } = accounts;
+
+        let { payer, transaction } = accounts;
+        payer ??= member;
+
+        if (transaction === undefined) {
+            const { nextTransactionId } = await this.fetchOwnerSet();
+            transaction = this.ownerSetTransactionAddress(nextTransactionId);
+        }
+
+        return this.program.methods
+            .proposeOwnerSetTransaction(args)
+            .accounts({
+                payer,
+                ownerSetMember: this.ownerSetMemberComposite(member),
+                transaction,
+                systemProgram: SystemProgram.programId,
+            })
+            .instruction();
+    }
+
+    async approveOwnerSetTransactionIx(accounts: {
+        member: PublicKey;
+        transaction: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { member, transaction } = accounts;
+        return this.program.methods
+            .approveOwnerSetTransaction()
+            .accounts({
+                ownerSetMember: this.ownerSetMemberComposite(member),
+                transaction,
+            })
+            .instruction();
+    }
+
+    async cancelOwnerSetTransactionIx(accounts: {
+        proposedBy: PublicKey;
+        transaction: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { proposedBy, transaction } = accounts;
+        return this.program.methods
+            .cancelOwnerSetTransaction()
+            .accounts({ proposedBy, transaction })
+            .instruction();
+    }
+
+    async executeOwnerSetTransactionIx(accounts: {
+        transaction: PublicKey;
+        proposedBy?: PublicKey;
+    }): Promise<TransactionInstruction> {
+        const { transaction } = accounts;
+
+        let { proposedBy } = accounts;
+
+        const { proposedBy: fetchedProposedBy, accounts: metas } =
+            await this.fetchOwnerSetTransaction({ address: transaction });
+        proposedBy ??= fetchedProposedBy;
+
+        // The owner set signs for the proposed instruction, so it cannot be a signer here.
+        return this.program.methods
+            .executeOwnerSetTransaction()
+            .accounts({
+                ownerSet: this.ownerSetAddress(),
+                proposedBy,
+                transaction,
+                tokenRouterProgram: this.ID,
+            })
+            .remainingAccounts(
+                metas.map(({ pubkey, isWritable }) => ({
+                    pubkey,
+                    isSigner: false,
+                    isWritable,
+                })),
+            )
+            .instruction();
+    }
+
     publishMessageAccounts(emitter: PublicKey): PublishMessageAccounts {
         const coreBridgeProgram = this.coreBridgeProgramId();
 
@@ -995,3 +2196,33 @@ export function testnet(): ProgramId {
 export function localnet(): ProgramId {
     return "TokenRouter11111111111111111111111111111111";
 }
+
+function borshU16(value: number): Buffer {
+    const buf = Buffer.alloc(2);
+    buf.writeUInt16LE(value);
+    return buf;
+}
+
+function borshU32(value: number): Buffer {
+    const buf = Buffer.alloc(4);
+    buf.writeUInt32LE(value);
+    return buf;
+}
+
+function borshU64(value: Uint64): Buffer {
+    return uint64ToBN(value).toArrayLike(Buffer, "le", 8);
+}
+
+function borshI64(value: bigint): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigInt64LE(value);
+    return buf;
+}
+
+function borshBytes(value: Buffer): Buffer {
+    return Buffer.concat([borshU32(value.length), value]);
+}
+
+function borshOption<T>(value: T | null, encode: (value: T) => Buffer): Buffer {
+    return value === null ? Buffer.from([0]) : Buffer.concat([Buffer.from([1]), encode(value)]);
+}
@@ -1,3 +1,4 @@
+import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { emitterAddress } from "../../common";
 
@@ -7,6 +8,9 @@ export class Custodian {
     pendingOwner: PublicKey | null;
     ownerAssistant: PublicKey;
     pausedSetBy: PublicKey;
+    pendingOwnerExpirySlot: BN;
+    pendingOwnerAssistant: PublicKey | null;
+    maxIntegratorFeeBps: number;
 
     constructor(
         paused: boolean,
@@ -14,12 +18,18 @@ export class Custodian {
         pendingOwner: PublicKey | null,
         ownerAssistant: PublicKey,
         pausedSetBy: PublicKey,
+        pendingOwnerExpirySlot: BN,
+        pendingOwnerAssistant: PublicKey | null,
+        maxIntegratorFeeBps: number,
     ) {
         this.paused = paused;
         this.owner = owner;
         this.pendingOwner = pendingOwner;
         this.ownerAssistant = ownerAssistant;
         this.pausedSetBy = pausedSetBy;
+        this.pendingOwnerExpirySlot = pendingOwnerExpirySlot;
+        this.pendingOwnerAssistant = pendingOwnerAssistant;
+        this.maxIntegratorFeeBps = maxIntegratorFeeBps;
     }
 
     static address(programId: PublicKey) {
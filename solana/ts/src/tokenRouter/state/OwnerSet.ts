@@ -0,0 +1,61 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { Uint64, writeUint64BE } from "../../common";
+
+export type OwnerSetAccountMeta = {
+    pubkey: PublicKey;
+    isSigner: boolean;
+    isWritable: boolean;
+};
+
+export class OwnerSet {
+    bump: number;
+    threshold: number;
+    members: PublicKey[];
+    nextTransactionId: BN;
+
+    constructor(bump: number, threshold: number, members: PublicKey[], nextTransactionId: BN) {
+        this.bump = bump;
+        this.threshold = threshold;
+        this.members = members;
+        this.nextTransactionId = nextTransactionId;
+    }
+
+    static address(programId: PublicKey) {
+        return PublicKey.findProgramAddressSync([Buffer.from("owner-set")], programId)[0];
+    }
+}
+
+export class OwnerSetTransaction {
+    id: BN;
+    bump: number;
+    proposedBy: PublicKey;
+    approvals: PublicKey[];
+    accounts: OwnerSetAccountMeta[];
+    data: Buffer;
+
+    constructor(
+        id: BN,
+        bump: number,
+        proposedBy: PublicKey,
+        approvals: PublicKey[],
+        accounts: OwnerSetAccountMeta[],
+        data: Buffer,
+    ) {
+        this.id = id;
+        this.bump = bump;
+        this.proposedBy = proposedBy;
+        this.approvals = approvals;
+        this.accounts = accounts;
+        this.data = data;
+    }
+
+    static address(programId: PublicKey, id: Uint64) {
+        const encodedId = Buffer.alloc(8);
+        writeUint64BE(encodedId, id);
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("owner-set-tx"), encodedId],
+            programId,
+        )[0];
+    }
+}
@@ -5,6 +5,7 @@ export type FillType = {
     unset?: {};
     wormholeCctpDeposit?: {};
     fastFill?: {};
+    wormholeTokenBridgeTransfer?: {};
 };
 
 export type PreparedFillSeeds = {
@@ -7,6 +7,24 @@ export type OrderType = {
     market?: {
         minAmountOut: BN | null;
     };
+    limit?: {
+        maxKeeperFee: BN;
+        minAmountOut: BN | null;
+        auction: PublicKey;
+        maxOfferPrice: BN;
+        expiry: BN;
+    };
+    scheduled?: {
+        remainingSlices: number;
+        sliceAmount: BN;
+        keeperReward: BN;
+        sliceInterval: BN;
+        nextSliceSlot: BN;
+    };
+    expiringMarket?: {
+        minAmountOut: BN | null;
+        expiry: BN;
+    };
 };
 
 export type PreparedOrderInfo = {
@@ -28,4 +46,15 @@ export class PreparedOrder {
         this.info = info;
         this.redeemerMessage = redeemerMessage;
     }
+
+    static address(programId: PublicKey, orderSender: PublicKey, nonce: Uint64) {
+        return PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("prepared-order"),
+                orderSender.toBuffer(),
+                uint64ToBN(nonce).toBuffer("be", 8),
+            ],
+            programId,
+        )[0];
+    }
 }
@@ -0,0 +1,19 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+export class SenderState {
+    bump: number;
+    nextNonce: BN;
+
+    constructor(bump: number, nextNonce: BN) {
+        this.bump = bump;
+        this.nextNonce = nextNonce;
+    }
+
+    static address(programId: PublicKey, orderSender: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("sender-state"), orderSender.toBuffer()],
+            programId,
+        )[0];
+    }
+}
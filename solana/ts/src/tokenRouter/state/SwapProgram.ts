@@ -0,0 +1,18 @@
+import { PublicKey } from "@solana/web3.js";
+
+export class SwapProgram {
+    bump: number;
+    programId: PublicKey;
+
+    constructor(bump: number, programId: PublicKey) {
+        this.bump = bump;
+        this.programId = programId;
+    }
+
+    static address(programId: PublicKey, swapProgramId: PublicKey) {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("swap-program"), swapProgramId.toBuffer()],
+            programId,
+        )[0];
+    }
+}
@@ -1,6 +1,10 @@
 export * from "./Custodian";
+export * from "./OwnerSet";
 export * from "./PreparedFill";
 export * from "./PreparedOrder";
+export * from "./RoleHolder";
+export * from "./SenderState";
+export * from "./SwapProgram";
 
 import { utils } from "@wormhole-foundation/sdk-solana";
 import { BN } from "@coral-xyz/anchor";
@@ -97,14 +97,17 @@ export class UpgradeManagerProgram {
         owner: PublicKey;
         program: PublicKey;
         recipient?: PublicKey;
+        payer?: PublicKey;
     }) {
-        const { owner, program, recipient: inputRecipient } = accounts;
+        const { owner, program, recipient: inputRecipient, payer: inputPayer } = accounts;
         return {
+            payer: inputPayer ?? owner,
             admin: this.ownerOnlyComposite(owner),
             recipient: inputRecipient ?? owner,
             receipt: this.upgradeReceiptAddress(program),
             programData: programDataAddress(program),
             program,
+            systemProgram: SystemProgram.programId,
         };
     }
 
@@ -133,18 +136,21 @@ export class UpgradeManagerProgram {
     async commitMatchingEngineUpgradeIx(accounts: {
         owner: PublicKey;
         recipient?: PublicKey;
+        payer?: PublicKey;
     }): Promise<TransactionInstruction> {
-        const { owner, recipient } = accounts;
+        const { owner, recipient, payer } = accounts;
         const matchingEngine = this.matchingEngineProgram();
 
         return this.program.methods
             .commitMatchingEngineUpgrade()
             .accounts({
                 matchingEngineCustodian: matchingEngine.custodianAddress(),
+                matchingEngineCircuitBreaker: matchingEngine.circuitBreakerAddress(),
                 commitUpgrade: this.commitUpgradeComposite({
                     owner,
                     program: matchingEngine.ID,
                     recipient,
+                    payer,
                 }),
             })
             .instruction();
@@ -175,8 +181,9 @@ export class UpgradeManagerProgram {
     async commitTokenRouterUpgradeIx(accounts: {
         owner: PublicKey;
         recipient?: PublicKey;
+        payer?: PublicKey;
     }): Promise<TransactionInstruction> {
-        const { owner, recipient } = accounts;
+        const { owner, recipient, payer } = accounts;
         const tokenRouter = this.tokenRouterProgram();
 
         return this.program.methods
@@ -187,6 +194,7 @@ export class UpgradeManagerProgram {
                     owner,
                     program: tokenRouter.ID,
                     recipient,
+                    payer,
                 }),
             })
             .instruction();
@@ -36,7 +36,9 @@ import {
     CPI_EVENT_IX_SELECTOR,
     CctpMessageArgs,
     Custodian,
+    EndpointInfo,
     MatchingEngineProgram,
+    PauseFlag,
     PreparedOrderResponse,
     Proposal,
     RouterEndpoint,
@@ -406,10 +408,32 @@ describe("Matching Engine", function () {
                         ownerAssistant.publicKey,
                         feeRecipientToken,
                         expectedAuctionConfigId,
-                        uint64ToBN(0),
+                        uint64ToBN(0), // nextProposalId
+                        uint64ToBN(0), // pendingOwnerExpirySlot
+                        null, // pendingOwnerAssistant
+                        {
+                            newAuctions: defaultPauseFlag(),
+                            offerImprovement: defaultPauseFlag(),
+                            orderExecution: defaultPauseFlag(),
+                            fastFillCreation: defaultPauseFlag(),
+                            settlement: defaultPauseFlag(),
+                        },
+                        {
+                            windowSlots: uint64ToBN(0),
+                            globalThreshold: uint64ToBN(0),
+                            chainThreshold: uint64ToBN(0),
+                        },
+                        uint64ToBN(1_512_000), // routerEndpointRemovalDelay
+                        uint64ToBN(0), // maxGasDropoff
                     ),
                 );
 
+                const { global } = await engine.fetchCircuitBreaker();
+                expect(global).to.eql({
+                    windowStartSlot: uint64ToBN(0),
+                    notional: uint64ToBN(0),
+                });
+
                 const auctionConfigData = await engine.fetchAuctionConfig(0);
                 expect(auctionConfigData).to.eql(
                     new AuctionConfig(expectedAuctionConfigId, auctionParams),
@@ -531,6 +555,7 @@ describe("Matching Engine", function () {
             });
 
             it("Submit Ownership Transfer Request as Owner", async function () {
+                const slot = await connection.getSlot();
                 await expectIxOk(
                     connection,
                     [await createSubmitOwnershipTransferIx()],
@@ -540,6 +565,7 @@ describe("Matching Engine", function () {
                 // Confirm that the pending owner variable is set in the owner config.
                 const custodianData = await engine.fetchCustodian();
                 expect(custodianData.pendingOwner).to.eql(relayer.publicKey);
+                expect(custodianData.pendingOwnerExpirySlot.toNumber()).is.greaterThan(slot);
             });
 
             it("Cannot Confirm Ownership Transfer Request as Non Pending Owner", async function () {
@@ -625,9 +651,10 @@ describe("Matching Engine", function () {
                     [payer, owner],
                 );
 
-                // Confirm the pending owner field was reset.
+                // Confirm the pending owner fields were reset.
                 const custodianData = await engine.fetchCustodian();
                 expect(custodianData.pendingOwner).to.eql(null);
+                expect(custodianData.pendingOwnerExpirySlot.toNumber()).equals(0);
             });
         });
 
@@ -667,9 +694,40 @@ describe("Matching Engine", function () {
                     [payer, owner],
                 );
 
+                // The assistant is not replaced until the pending assistant accepts the role.
+                const custodianData = await engine.fetchCustodian();
+                expect(custodianData.ownerAssistant).to.eql(ownerAssistant.publicKey);
+                expect(custodianData.pendingOwnerAssistant).to.eql(relayer.publicKey);
+            });
+
+            it("Cannot Accept Assistant as Non-Pending Assistant", async function () {
+                await expectIxErr(
+                    connection,
+                    [
+                        await engine.acceptOwnerAssistantIx({
+                            pendingOwnerAssistant: ownerAssistant.publicKey,
+                        }),
+                    ],
+                    [payer, ownerAssistant],
+                    "NotPendingOwnerAssistant",
+                );
+            });
+
+            it("Accept Assistant as Pending Assistant", async function () {
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.acceptOwnerAssistantIx({
+                            pendingOwnerAssistant: relayer.publicKey,
+                        }),
+                    ],
+                    [payer, relayer],
+                );
+
                 // Confirm the assistant field was updated.
                 const custodianData = await engine.fetchCustodian();
                 expect(custodianData.ownerAssistant).to.eql(relayer.publicKey);
+                expect(custodianData.pendingOwnerAssistant).to.eql(null);
 
                 // Set the assistant back to the assistant key.
                 await expectIxOk(
@@ -678,8 +736,24 @@ describe("Matching Engine", function () {
                         await createUpdateOwnerAssistantIx({
                             newAssistant: ownerAssistant.publicKey,
                         }),
+                        await engine.acceptOwnerAssistantIx({
+                            pendingOwnerAssistant: ownerAssistant.publicKey,
+                        }),
                     ],
-                    [payer, owner],
+                    [payer, owner, ownerAssistant],
+                );
+            });
+
+            it("Cannot Accept Assistant without Pending Assistant", async function () {
+                await expectIxErr(
+                    connection,
+                    [
+                        await engine.acceptOwnerAssistantIx({
+                            pendingOwnerAssistant: relayer.publicKey,
+                        }),
+                    ],
+                    [payer, relayer],
+                    "NoPendingOwnerAssistant",
                 );
             });
         });
@@ -688,19 +762,24 @@ describe("Matching Engine", function () {
             it("Cannot Set Pause for Transfers as Non-Owner", async function () {
                 const ix = await engine.setPauseIx(
                     {
-                        ownerOrAssistant: payer.publicKey,
+                        authority: payer.publicKey,
                     },
                     true, // paused
                 );
 
-                await expectIxErr(connection, [ix], [payer], "Error Code: OwnerOrAssistantOnly");
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer],
+                    "Error Code: OwnerAssistantOrRoleOnly",
+                );
             });
 
             it("Set Paused == true as Owner Assistant", async function () {
                 const paused = true;
                 const ix = await engine.setPauseIx(
                     {
-                        ownerOrAssistant: ownerAssistant.publicKey,
+                        authority: ownerAssistant.publicKey,
                     },
                     paused,
                 );
@@ -716,7 +795,7 @@ describe("Matching Engine", function () {
                 const paused = false;
                 const ix = await engine.setPauseIx(
                     {
-                        ownerOrAssistant: owner.publicKey,
+                        authority: owner.publicKey,
                     },
                     paused,
                 );
@@ -727,6 +806,196 @@ describe("Matching Engine", function () {
                 expect(actualPaused).equals(paused);
                 expect(pausedSetBy).eql(owner.publicKey);
             });
+
+            it("Cannot Set Stage Pause as Non-Owner", async function () {
+                const ix = await engine.setStagePauseIx(
+                    {
+                        authority: payer.publicKey,
+                    },
+                    { target: { settlement: {} }, paused: true, unpauseSlot: null, reason: 0 },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer],
+                    "Error Code: OwnerAssistantOrRoleOnly",
+                );
+            });
+
+            it("Cannot Set Stage Pause with Past Unpause Slot", async function () {
+                const slot = await connection.getSlot();
+                const ix = await engine.setStagePauseIx(
+                    {
+                        authority: ownerAssistant.publicKey,
+                    },
+                    {
+                        target: { settlement: {} },
+                        paused: true,
+                        unpauseSlot: uint64ToBN(slot),
+                        reason: 0,
+                    },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [ownerAssistant],
+                    "Error Code: InvalidUnpauseSlot",
+                );
+            });
+
+            it("Cannot Set Stage Unpause with Unpause Slot", async function () {
+                const slot = await connection.getSlot();
+                const ix = await engine.setStagePauseIx(
+                    {
+                        authority: ownerAssistant.publicKey,
+                    },
+                    {
+                        target: { settlement: {} },
+                        paused: false,
+                        unpauseSlot: uint64ToBN(slot + 100),
+                        reason: 0,
+                    },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [ownerAssistant],
+                    "Error Code: InvalidUnpauseSlot",
+                );
+            });
+
+            it("Set Stage Pause as Owner Assistant", async function () {
+                const slot = await connection.getSlot();
+                const unpauseSlot = uint64ToBN(slot + 100);
+                const ix = await engine.setStagePauseIx(
+                    {
+                        authority: ownerAssistant.publicKey,
+                    },
+                    { target: { settlement: {} }, paused: true, unpauseSlot, reason: 69 },
+                );
+
+                await expectIxOk(connection, [ix], [ownerAssistant]);
+
+                const { pauseFlags } = await engine.fetchCustodian();
+                expect(pauseFlags.settlement).to.eql({ paused: true, unpauseSlot, reason: 69 });
+
+                // Only the targeted stage is paused.
+                expect(pauseFlags.newAuctions).to.eql(defaultPauseFlag());
+            });
+
+            it("Cannot Grant Pauser Role as Owner Assistant", async function () {
+                const ix = await engine.grantRoleIx(
+                    {
+                        owner: ownerAssistant.publicKey,
+                        holder: relayer.publicKey,
+                    },
+                    { pauser: {} },
+                );
+
+                await expectIxErr(connection, [ix], [ownerAssistant], "Error Code: OwnerOnly");
+            });
+
+            it("Grant Pauser Role as Owner", async function () {
+                const ix = await engine.grantRoleIx(
+                    {
+                        owner: owner.publicKey,
+                        holder: relayer.publicKey,
+                    },
+                    { pauser: {} },
+                );
+
+                await expectIxOk(connection, [ix], [owner]);
+
+                const { holder, roles } = await engine.fetchRoleHolder(relayer.publicKey);
+                expect(holder).to.eql(relayer.publicKey);
+                expect(roles).to.eql([{ pauser: {} }]);
+            });
+
+            it("Cannot Grant Pauser Role Again", async function () {
+                const ix = await engine.grantRoleIx(
+                    {
+                        owner: owner.publicKey,
+                        holder: relayer.publicKey,
+                    },
+                    { pauser: {} },
+                );
+
+                await expectIxErr(connection, [ix], [owner], "Error Code: RoleAlreadyGranted");
+            });
+
+            it("Cannot Add Router Endpoint as Pauser", async function () {
+                const ix = await engine.addCctpRouterEndpointIx(
+                    { authority: relayer.publicKey },
+                    {
+                        chain: ethChain,
+                        cctpDomain: ethDomain,
+                        address: ethRouter,
+                        mintRecipient: null,
+                    },
+                );
+
+                await expectIxErr(connection, [ix], [payer, relayer], "OwnerAssistantOrRoleOnly");
+            });
+
+            it("Set Stage Unpause as Pauser", async function () {
+                const ix = await engine.setStagePauseIx(
+                    {
+                        authority: relayer.publicKey,
+                    },
+                    { target: { settlement: {} }, paused: false, unpauseSlot: null, reason: 0 },
+                );
+
+                await expectIxOk(connection, [ix], [payer, relayer]);
+
+                const { pauseFlags } = await engine.fetchCustodian();
+                expect(pauseFlags.settlement).to.eql(defaultPauseFlag());
+            });
+
+            it("Revoke Pauser Role as Owner", async function () {
+                const ix = await engine.revokeRoleIx(
+                    {
+                        owner: owner.publicKey,
+                        holder: relayer.publicKey,
+                    },
+                    { pauser: {} },
+                );
+
+                await expectIxOk(connection, [ix], [owner]);
+
+                const { roles } = await engine.fetchRoleHolder(relayer.publicKey);
+                expect(roles).has.length(0);
+            });
+
+            it("Cannot Revoke Pauser Role Again", async function () {
+                const ix = await engine.revokeRoleIx(
+                    {
+                        owner: owner.publicKey,
+                        holder: relayer.publicKey,
+                    },
+                    { pauser: {} },
+                );
+
+                await expectIxErr(connection, [ix], [owner], "Error Code: RoleNotGranted");
+            });
+
+            it("Cannot Set Pause after Pauser Role Revoked", async function () {
+                const ix = await engine.setPauseIx(
+                    {
+                        authority: relayer.publicKey,
+                    },
+                    true, // paused
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer, relayer],
+                    "Error Code: OwnerAssistantOrRoleOnly",
+                );
+            });
         });
 
         describe("Router Endpoint (CCTP)", function () {
@@ -735,7 +1004,7 @@ describe("Matching Engine", function () {
             after("Register To Router Endpoints", async function () {
                 const ix = await engine.addCctpRouterEndpointIx(
                     {
-                        ownerOrAssistant: owner.publicKey,
+                        authority: owner.publicKey,
                     },
                     {
                         chain: toChainId("Arbitrum"),
@@ -749,7 +1018,7 @@ describe("Matching Engine", function () {
 
             it("Cannot Add Router Endpoint as Non-Owner and Non-Assistant", async function () {
                 const ix = await engine.addCctpRouterEndpointIx(
-                    { ownerOrAssistant: payer.publicKey },
+                    { authority: payer.publicKey },
                     {
                         chain: ethChain,
                         cctpDomain: ethDomain,
@@ -758,13 +1027,13 @@ describe("Matching Engine", function () {
                     },
                 );
 
-                await expectIxErr(connection, [ix], [payer], "OwnerOrAssistantOnly");
+                await expectIxErr(connection, [ix], [payer], "OwnerAssistantOrRoleOnly");
             });
 
             [0, solanaChain].forEach((chain) =>
                 it(`Cannot Register Chain ID == ${chain}`, async function () {
                     const ix = await engine.addCctpRouterEndpointIx(
-                        { ownerOrAssistant: owner.publicKey },
+                        { authority: owner.publicKey },
                         {
                             chain: chain as ChainId,
                             cctpDomain: ethDomain,
@@ -778,7 +1047,7 @@ describe("Matching Engine", function () {
 
             it("Cannot Register Zero Address", async function () {
                 const ix = await engine.addCctpRouterEndpointIx(
-                    { ownerOrAssistant: owner.publicKey },
+                    { authority: owner.publicKey },
                     {
                         chain: ethChain,
                         cctpDomain: ethDomain,
@@ -794,7 +1063,7 @@ describe("Matching Engine", function () {
                 const contractAddress = Array.from(Buffer.alloc(32, "fbadc0de", "hex"));
                 const mintRecipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
                 const ix = await engine.addCctpRouterEndpointIx(
-                    { ownerOrAssistant: ownerAssistant.publicKey },
+                    { authority: ownerAssistant.publicKey },
                     {
                         chain: ethChain,
                         cctpDomain: ethDomain,
@@ -806,7 +1075,7 @@ describe("Matching Engine", function () {
 
                 const routerEndpointData = await engine.fetchRouterEndpoint(ethChain);
                 expect(routerEndpointData).to.eql(
-                    new RouterEndpoint(255, {
+                    newRouterEndpoint(255, {
                         chain: ethChain,
                         address: contractAddress,
                         mintRecipient,
@@ -816,6 +1085,11 @@ describe("Matching Engine", function () {
                     }),
                 );
 
+                const { endpoints } = await engine.fetchRouterEndpointRegistry();
+                expect(endpoints).to.eql([
+                    { chain: ethChain, protocol: { cctp: { domain: ethDomain } } },
+                ]);
+
                 // Save for later.
                 localVariables.set("ix", ix);
             });
@@ -851,14 +1125,36 @@ describe("Matching Engine", function () {
                 await expectIxOk(connection, [ix], [owner]);
 
                 const routerEndpointData = await engine.fetchRouterEndpoint(ethChain);
-                const { bump } = routerEndpointData;
+                const { bump, disabledSlot } = routerEndpointData;
+                expect(disabledSlot).is.not.null;
                 expect(routerEndpointData).to.eql(
-                    new RouterEndpoint(bump, {
-                        chain: ethChain,
-                        address: new Array(32).fill(0),
-                        mintRecipient: new Array(32).fill(0),
-                        protocol: { none: {} },
-                    }),
+                    newRouterEndpoint(
+                        bump,
+                        {
+                            chain: ethChain,
+                            address: new Array(32).fill(0),
+                            mintRecipient: new Array(32).fill(0),
+                            protocol: { none: {} },
+                        },
+                        { disabledSlot },
+                    ),
+                );
+
+                const { endpoints } = await engine.fetchRouterEndpointRegistry();
+                expect(endpoints).to.eql([{ chain: ethChain, protocol: { none: {} } }]);
+            });
+
+            it("Cannot Remove Router Endpoint before Removal Delay", async function () {
+                const ix = await engine.removeRouterEndpointIx(
+                    { owner: owner.publicKey },
+                    ethChain,
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [owner],
+                    "Error Code: RouterEndpointRemovalDelayNotElapsed",
                 );
             });
 
@@ -891,7 +1187,7 @@ describe("Matching Engine", function () {
 
                 const routerEndpointData = await engine.fetchRouterEndpoint(ethChain);
                 expect(routerEndpointData).to.eql(
-                    new RouterEndpoint(255, {
+                    newRouterEndpoint(255, {
                         chain: ethChain,
                         address: ethRouter,
                         mintRecipient: ethRouter,
@@ -900,84 +1196,429 @@ describe("Matching Engine", function () {
                         },
                     }),
                 );
-            });
-        });
-
-        describe("Update Fee Recipient", async function () {
-            const localVariables = new Map<string, any>();
 
-            it("Cannot Update Fee Recipient with Non-Existent ATA", async function () {
-                const ix = await engine.updateFeeRecipientIx({
-                    ownerOrAssistant: ownerAssistant.publicKey,
-                    newFeeRecipient,
+                const { endpoints } = await engine.fetchRouterEndpointRegistry();
+                expect(endpoints.find((endpoint) => endpoint.chain == ethChain)).to.eql({
+                    chain: ethChain,
+                    protocol: { cctp: { domain: ethDomain } },
                 });
+            });
+
+            it("Cannot Remove Enabled Router Endpoint", async function () {
+                const ix = await engine.removeRouterEndpointIx(
+                    { owner: owner.publicKey },
+                    ethChain,
+                );
 
                 await expectIxErr(
                     connection,
                     [ix],
-                    [ownerAssistant],
-                    "new_fee_recipient_token. Error Code: AccountNotInitialized",
+                    [owner],
+                    "Error Code: RouterEndpointNotDisabled",
                 );
-
-                localVariables.set("ix", ix);
             });
+        });
 
-            it("Update Fee Recipient as Owner Assistant", async function () {
-                const ix = localVariables.get("ix") as TransactionInstruction;
-                expect(localVariables.delete("ix")).is.true;
+        describe("Router Endpoint (Token Bridge)", function () {
+            const chain = toChainId("Aptos");
+            const address = Array.from(Buffer.alloc(32, "70b1d9e5", "hex"));
 
-                await splToken.getOrCreateAssociatedTokenAccount(
-                    connection,
-                    payer,
-                    USDC_MINT_ADDRESS,
-                    newFeeRecipient,
+            it("Cannot Add Token Bridge Router Endpoint with Unsupported Mint", async function () {
+                const mint = await splToken.createMint(connection, payer, payer.publicKey, null, 6);
+
+                const ix = await engine.addTokenBridgeRouterEndpointIx(
+                    { authority: owner.publicKey, mint },
+                    { chain, address, mintRecipient: null },
                 );
+                await expectIxErr(connection, [ix], [owner], "Error Code: UnsupportedMint");
+            });
 
+            it("Add Token Bridge Router Endpoint as Owner Assistant", async function () {
+                const ix = await engine.addTokenBridgeRouterEndpointIx(
+                    { authority: ownerAssistant.publicKey, mint: USDC_MINT_ADDRESS },
+                    { chain, address, mintRecipient: null },
+                );
                 await expectIxOk(connection, [ix], [ownerAssistant]);
 
-                const custodianData = await engine.fetchCustodian();
-                expect(custodianData.feeRecipientToken).to.eql(
-                    splToken.getAssociatedTokenAddressSync(USDC_MINT_ADDRESS, newFeeRecipient),
+                const routerEndpointData = await engine.fetchRouterEndpoint(chain);
+                expect(routerEndpointData).to.eql(
+                    newRouterEndpoint(routerEndpointData.bump, {
+                        chain,
+                        address,
+                        mintRecipient: address,
+                        protocol: { tokenBridge: {} },
+                    }),
                 );
-            });
 
-            it("Cannot Update Fee Recipient without Owner or Assistant", async function () {
-                const ix = await engine.updateFeeRecipientIx({
-                    ownerOrAssistant: payer.publicKey,
-                    newFeeRecipient: feeRecipient,
+                const { endpoints } = await engine.fetchRouterEndpointRegistry();
+                expect(endpoints.find((endpoint) => endpoint.chain == chain)).to.eql({
+                    chain,
+                    protocol: { tokenBridge: {} },
                 });
 
-                await expectIxErr(connection, [ix], [payer], "Error Code: OwnerOrAssistantOnly");
+                // Orders from this endpoint are held in its own local custody token account.
+                const { mint, owner: custodyOwner } = await splToken.getAccount(
+                    connection,
+                    engine.localCustodyTokenAddress(chain),
+                );
+                expect(mint).to.eql(USDC_MINT_ADDRESS);
+                expect(custodyOwner).to.eql(engine.routerEndpointAddress(chain));
             });
 
-            it("Cannot Update Fee Recipient to Default Pubkey", async function () {
-                const ix = await engine.updateFeeRecipientIx({
-                    ownerOrAssistant: ownerAssistant.publicKey,
-                    newFeeRecipient: PublicKey.default,
-                });
+            it("Cannot Update Token Bridge Router Endpoint as Owner Assistant", async function () {
+                const ix = await engine.updateTokenBridgeRouterEndpointIx(
+                    { owner: ownerAssistant.publicKey },
+                    { chain, address, mintRecipient: null },
+                );
 
-                await expectIxErr(connection, [ix], [ownerAssistant], "FeeRecipientZeroPubkey");
+                await expectIxErr(connection, [ix], [ownerAssistant], "Error Code: OwnerOnly");
             });
 
-            it("Update Fee Recipient as Owner", async function () {
-                const ix = await engine.updateFeeRecipientIx({
-                    ownerOrAssistant: owner.publicKey,
-                    newFeeRecipient: feeRecipient,
-                });
+            it("Update Token Bridge Router Endpoint as Owner", async function () {
+                const newAddress = Array.from(Buffer.alloc(32, "70b1d9e6", "hex"));
+                const mintRecipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+
+                const ix = await engine.updateTokenBridgeRouterEndpointIx(
+                    { owner: owner.publicKey },
+                    { chain, address: newAddress, mintRecipient },
+                );
                 await expectIxOk(connection, [ix], [owner]);
 
-                const custodianData = await engine.fetchCustodian();
-                expect(custodianData.feeRecipientToken).to.eql(feeRecipientToken);
+                const routerEndpointData = await engine.fetchRouterEndpoint(chain);
+                expect(routerEndpointData).to.eql(
+                    newRouterEndpoint(routerEndpointData.bump, {
+                        chain,
+                        address: newAddress,
+                        mintRecipient,
+                        protocol: { tokenBridge: {} },
+                    }),
+                );
+
+                // The registry only tracks the protocol, which is unchanged.
+                const { endpoints } = await engine.fetchRouterEndpointRegistry();
+                expect(endpoints.filter((endpoint) => endpoint.chain == chain)).to.eql([
+                    { chain, protocol: { tokenBridge: {} } },
+                ]);
             });
         });
 
-        describe("Propose New Auction Parameters", async function () {
-            // Create a new set of auction parameters.
-            const newAuctionParameters: AuctionParameters = {
-                userPenaltyRewardBps: 1_000_000, // 100%
-                initialPenaltyBps: 1_000_000, // 100%
-                duration: 1,
-                gracePeriod: 3,
+        describe("Router Endpoint Settings", function () {
+            it("Cannot Pause Router Endpoint as Non-Owner", async function () {
+                const ix = await engine.setRouterEndpointPauseIx(
+                    { authority: payer.publicKey },
+                    ethChain,
+                    { paused: true, unpauseSlot: null, reason: 0 },
+                );
+
+                await expectIxErr(connection, [ix], [payer], "OwnerAssistantOrRoleOnly");
+            });
+
+            it("Pause Router Endpoint as Owner Assistant", async function () {
+                const ix = await engine.setRouterEndpointPauseIx(
+                    { authority: ownerAssistant.publicKey },
+                    ethChain,
+                    { paused: true, unpauseSlot: null, reason: 420 },
+                );
+
+                await expectIxOk(connection, [ix], [ownerAssistant]);
+
+                const { pause } = await engine.fetchRouterEndpoint(ethChain);
+                expect(pause).to.eql({ paused: true, unpauseSlot: null, reason: 420 });
+            });
+
+            it("Unpause Router Endpoint as Owner", async function () {
+                const ix = await engine.setRouterEndpointPauseIx(
+                    { authority: owner.publicKey },
+                    ethChain,
+                    { paused: false, unpauseSlot: null, reason: 0 },
+                );
+
+                await expectIxOk(connection, [ix], [owner]);
+
+                const { pause } = await engine.fetchRouterEndpoint(ethChain);
+                expect(pause).to.eql(defaultPauseFlag());
+            });
+
+            it("Cannot Set Router Endpoint Limits (Min > Max)", async function () {
+                const ix = await engine.setRouterEndpointLimitsIx(
+                    { authority: ownerAssistant.publicKey },
+                    ethChain,
+                    {
+                        maxRedeemerMessageLen: null,
+                        minOrderAmount: uint64ToBN(2),
+                        maxOrderAmount: uint64ToBN(1),
+                    },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [ownerAssistant],
+                    "Error Code: InvalidEndpointLimits",
+                );
+            });
+
+            it("Set Router Endpoint Limits as Owner Assistant", async function () {
+                const limits = {
+                    maxRedeemerMessageLen: 512,
+                    minOrderAmount: uint64ToBN(1),
+                    maxOrderAmount: uint64ToBN(1_000_000_000_000n),
+                };
+                const ix = await engine.setRouterEndpointLimitsIx(
+                    { authority: ownerAssistant.publicKey },
+                    ethChain,
+                    limits,
+                );
+
+                await expectIxOk(connection, [ix], [ownerAssistant]);
+
+                {
+                    const routerEndpointData = await engine.fetchRouterEndpoint(ethChain);
+                    expect(routerEndpointData.limits).to.eql(limits);
+                }
+
+                // Remove the limits.
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.setRouterEndpointLimitsIx(
+                            { authority: ownerAssistant.publicKey },
+                            ethChain,
+                            {
+                                maxRedeemerMessageLen: null,
+                                minOrderAmount: null,
+                                maxOrderAmount: null,
+                            },
+                        ),
+                    ],
+                    [ownerAssistant],
+                );
+            });
+
+            it("Cannot Set Router Endpoint Inbound Limit (Zero Max Notional)", async function () {
+                const ix = await engine.setRouterEndpointInboundLimitIx(
+                    { authority: ownerAssistant.publicKey },
+                    ethChain,
+                    { windowSlots: uint64ToBN(100), maxNotional: uint64ToBN(0) },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [ownerAssistant],
+                    "Error Code: InvalidInboundLimit",
+                );
+            });
+
+            it("Set Router Endpoint Inbound Limit as Endpoint Manager", async function () {
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.grantRoleIx(
+                            {
+                                owner: owner.publicKey,
+                                holder: relayer.publicKey,
+                            },
+                            { endpointManager: {} },
+                        ),
+                    ],
+                    [owner],
+                );
+
+                const inboundLimit = {
+                    windowSlots: uint64ToBN(100),
+                    maxNotional: uint64ToBN(1_000_000_000n),
+                };
+                const ix = await engine.setRouterEndpointInboundLimitIx(
+                    { authority: relayer.publicKey },
+                    ethChain,
+                    inboundLimit,
+                );
+
+                const txDetails = await expectIxOkDetails(connection, [ix], [payer, relayer]);
+
+                const routerEndpointData = await engine.fetchRouterEndpoint(ethChain);
+                expect(routerEndpointData.inboundLimit).to.eql(inboundLimit);
+
+                // Setting the limit starts a new window.
+                const inboundNotionalData = await engine.fetchInboundNotional(ethChain);
+                expect(inboundNotionalData.inboundLimit).to.eql({
+                    windowStartSlot: uint64ToBN(txDetails!.slot),
+                    notional: uint64ToBN(0),
+                });
+
+                // Remove the limit and the role.
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.setRouterEndpointInboundLimitIx(
+                            { authority: relayer.publicKey },
+                            ethChain,
+                            { windowSlots: uint64ToBN(0), maxNotional: uint64ToBN(0) },
+                        ),
+                        await engine.revokeRoleIx(
+                            {
+                                owner: owner.publicKey,
+                                holder: relayer.publicKey,
+                            },
+                            { endpointManager: {} },
+                        ),
+                    ],
+                    [payer, relayer, owner],
+                );
+            });
+        });
+
+        describe("Router Endpoint Removal Delay", function () {
+            it("Cannot Update Router Endpoint Removal Delay as Owner Assistant", async function () {
+                const ix = await engine.updateRouterEndpointRemovalDelayIx(
+                    { owner: ownerAssistant.publicKey },
+                    0,
+                );
+
+                await expectIxErr(connection, [ix], [ownerAssistant], "Error Code: OwnerOnly");
+            });
+
+            it("Remove Disabled Router Endpoint after Removal Delay", async function () {
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.updateRouterEndpointRemovalDelayIx(
+                            { owner: owner.publicKey },
+                            0,
+                        ),
+                    ],
+                    [owner],
+                );
+
+                {
+                    const { routerEndpointRemovalDelay } = await engine.fetchCustodian();
+                    expect(routerEndpointRemovalDelay.toNumber()).equals(0);
+                }
+
+                // Register an endpoint that will be removed.
+                const chain = toChainId("Base");
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.addTokenBridgeRouterEndpointIx(
+                            { authority: owner.publicKey, mint: USDC_MINT_ADDRESS },
+                            {
+                                chain,
+                                address: Array.from(Buffer.alloc(32, "baadf00d", "hex")),
+                                mintRecipient: null,
+                            },
+                        ),
+                        await engine.disableRouterEndpointIx({ owner: owner.publicKey }, chain),
+                    ],
+                    [owner],
+                );
+
+                const ix = await engine.removeRouterEndpointIx({ owner: owner.publicKey }, chain);
+                await expectIxOk(connection, [ix], [owner]);
+
+                const accInfo = await connection.getAccountInfo(
+                    engine.routerEndpointAddress(chain),
+                );
+                expect(accInfo).is.null;
+
+                const { endpoints } = await engine.fetchRouterEndpointRegistry();
+                expect(endpoints.find((endpoint) => endpoint.chain == chain)).is.undefined;
+
+                // Restore the default removal delay.
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.updateRouterEndpointRemovalDelayIx(
+                            { owner: owner.publicKey },
+                            1_512_000,
+                        ),
+                    ],
+                    [owner],
+                );
+            });
+        });
+
+        describe("Update Fee Recipient", async function () {
+            const localVariables = new Map<string, any>();
+
+            it("Cannot Update Fee Recipient with Non-Existent ATA", async function () {
+                const ix = await engine.updateFeeRecipientIx({
+                    authority: ownerAssistant.publicKey,
+                    newFeeRecipient,
+                });
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [ownerAssistant],
+                    "new_fee_recipient_token. Error Code: AccountNotInitialized",
+                );
+
+                localVariables.set("ix", ix);
+            });
+
+            it("Update Fee Recipient as Owner Assistant", async function () {
+                const ix = localVariables.get("ix") as TransactionInstruction;
+                expect(localVariables.delete("ix")).is.true;
+
+                await splToken.getOrCreateAssociatedTokenAccount(
+                    connection,
+                    payer,
+                    USDC_MINT_ADDRESS,
+                    newFeeRecipient,
+                );
+
+                await expectIxOk(connection, [ix], [ownerAssistant]);
+
+                const custodianData = await engine.fetchCustodian();
+                expect(custodianData.feeRecipientToken).to.eql(
+                    splToken.getAssociatedTokenAddressSync(USDC_MINT_ADDRESS, newFeeRecipient),
+                );
+            });
+
+            it("Cannot Update Fee Recipient without Owner or Assistant", async function () {
+                const ix = await engine.updateFeeRecipientIx({
+                    authority: payer.publicKey,
+                    newFeeRecipient: feeRecipient,
+                });
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer],
+                    "Error Code: OwnerAssistantOrRoleOnly",
+                );
+            });
+
+            it("Cannot Update Fee Recipient to Default Pubkey", async function () {
+                const ix = await engine.updateFeeRecipientIx({
+                    authority: ownerAssistant.publicKey,
+                    newFeeRecipient: PublicKey.default,
+                });
+
+                await expectIxErr(connection, [ix], [ownerAssistant], "FeeRecipientZeroPubkey");
+            });
+
+            it("Update Fee Recipient as Owner", async function () {
+                const ix = await engine.updateFeeRecipientIx({
+                    authority: owner.publicKey,
+                    newFeeRecipient: feeRecipient,
+                });
+                await expectIxOk(connection, [ix], [owner]);
+
+                const custodianData = await engine.fetchCustodian();
+                expect(custodianData.feeRecipientToken).to.eql(feeRecipientToken);
+            });
+        });
+
+        describe("Propose New Auction Parameters", async function () {
+            // Create a new set of auction parameters.
+            const newAuctionParameters: AuctionParameters = {
+                userPenaltyRewardBps: 1_000_000, // 100%
+                initialPenaltyBps: 1_000_000, // 100%
+                duration: 1,
+                gracePeriod: 3,
                 penaltyPeriod: 5,
                 minOfferDeltaBps: 10_000, // 1%
                 securityDepositBase: uint64ToBN(69),
@@ -1356,45 +1997,432 @@ describe("Matching Engine", function () {
                 await expectIxErr(connection, [ix], [owner], "Error Code: ProposalAlreadyEnacted");
             });
         });
-    });
-
-    describe("Business Logic", function () {
-        const baseFastOrder: FastMarketOrder = {
-            amountIn: 50000000000n,
-            minAmountOut: 0n,
-            targetChain: toChain(arbChain),
-            redeemer: toUniversalAddress(Buffer.alloc(32, "deadbeef", "hex")),
-            sender: toUniversalAddress(Buffer.alloc(32, "beefdead", "hex")),
-            refundAddress: toUniversalAddress(Buffer.alloc(32, "beef", "hex")),
-            maxFee: 1000000n,
-            initAuctionFee: 100n,
-            deadline: 0,
-            redeemerMessage: encoding.bytes.encode("All your base are belong to us."),
-        };
 
-        describe("Place Initial CCTP Offer", function () {
-            for (const offerPrice of [0n, baseFastOrder.maxFee / 2n, baseFastOrder.maxFee]) {
-                it(`Place Initial Offer (Price == ${offerPrice})`, async function () {
-                    await placeInitialOfferCctpForTest(
-                        {
-                            payer: playerOne.publicKey,
-                        },
-                        {
-                            args: {
-                                offerPrice,
-                            },
-                            signers: [playerOne],
-                            finalized: false,
-                            fastMarketOrder: baseFastOrder,
-                        },
-                    );
-                });
-            }
+        describe("Circuit Breaker", function () {
+            it("Cannot Update Circuit Breaker as Owner Assistant", async function () {
+                const ix = await engine.updateCircuitBreakerIx(
+                    { owner: ownerAssistant.publicKey },
+                    {
+                        windowSlots: uint64ToBN(100),
+                        globalThreshold: uint64ToBN(1),
+                        chainThreshold: uint64ToBN(1),
+                    },
+                );
 
-            it("Place Initial Offer (Tx)", async function () {
-                const { fast } = await observeCctpOrderVaas({ sourceChain: "Ethereum" });
+                await expectIxErr(connection, [ix], [ownerAssistant], "Error Code: OwnerOnly");
+            });
 
-                const auction = engine.auctionAddress(fast.vaaAccount.digest());
+            it("Cannot Update Circuit Breaker (Zero Threshold)", async function () {
+                const ix = await engine.updateCircuitBreakerIx(
+                    { owner: owner.publicKey },
+                    {
+                        windowSlots: uint64ToBN(100),
+                        globalThreshold: uint64ToBN(1),
+                        chainThreshold: uint64ToBN(0),
+                    },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [owner],
+                    "Error Code: InvalidCircuitBreakerThreshold",
+                );
+            });
+
+            it("Update Circuit Breaker as Owner", async function () {
+                const circuitBreaker = {
+                    windowSlots: uint64ToBN(100),
+                    globalThreshold: uint64ToBN(1_000_000_000_000n),
+                    chainThreshold: uint64ToBN(100_000_000_000n),
+                };
+                const ix = await engine.updateCircuitBreakerIx(
+                    { owner: owner.publicKey },
+                    circuitBreaker,
+                );
+
+                await expectIxOk(connection, [ix], [owner]);
+
+                {
+                    const custodianData = await engine.fetchCustodian();
+                    expect(custodianData.circuitBreaker).to.eql(circuitBreaker);
+                }
+
+                // Disable the circuit breaker.
+                const disabled = {
+                    windowSlots: uint64ToBN(0),
+                    globalThreshold: uint64ToBN(0),
+                    chainThreshold: uint64ToBN(0),
+                };
+                await expectIxOk(
+                    connection,
+                    [await engine.updateCircuitBreakerIx({ owner: owner.publicKey }, disabled)],
+                    [owner],
+                );
+
+                {
+                    const custodianData = await engine.fetchCustodian();
+                    expect(custodianData.circuitBreaker).to.eql(disabled);
+                }
+            });
+        });
+
+        describe("Update Max Gas Drop-off", function () {
+            it("Cannot Update Max Gas Drop-off as Non-Owner", async function () {
+                const ix = await engine.updateMaxGasDropoffIx({ authority: payer.publicKey }, 1);
+
+                await expectIxErr(connection, [ix], [payer], "OwnerAssistantOrRoleOnly");
+            });
+
+            it("Update Max Gas Drop-off as Owner Assistant", async function () {
+                const maxGasDropoff = 1_000_000_000n;
+                const ix = await engine.updateMaxGasDropoffIx(
+                    { authority: ownerAssistant.publicKey },
+                    maxGasDropoff,
+                );
+
+                await expectIxOk(connection, [ix], [ownerAssistant]);
+
+                const custodianData = await engine.fetchCustodian();
+                expect(custodianData.maxGasDropoff).to.eql(uint64ToBN(maxGasDropoff));
+            });
+        });
+
+        describe("Supported Mint", function () {
+            const localVariables = new Map<string, any>();
+
+            before("Create Mint", async function () {
+                const mint = await splToken.createMint(connection, payer, payer.publicKey, null, 6);
+                await splToken.getOrCreateAssociatedTokenAccount(
+                    connection,
+                    payer,
+                    mint,
+                    feeRecipient,
+                );
+
+                localVariables.set("mint", mint);
+            });
+
+            it("Cannot Add Supported Mint as Owner Assistant", async function () {
+                const mint = localVariables.get("mint") as PublicKey;
+                const ix = await engine.addSupportedMintIx({
+                    owner: ownerAssistant.publicKey,
+                    mint,
+                    feeRecipient,
+                });
+
+                await expectIxErr(connection, [ix], [ownerAssistant], "Error Code: OwnerOnly");
+            });
+
+            it("Cannot Add USDC as Supported Mint", async function () {
+                const ix = await engine.addSupportedMintIx({
+                    owner: owner.publicKey,
+                    mint: USDC_MINT_ADDRESS,
+                    feeRecipient,
+                });
+
+                await expectIxErr(connection, [ix], [owner], "Error Code: InvalidMint");
+            });
+
+            it("Add Supported Mint as Owner", async function () {
+                const mint = localVariables.get("mint") as PublicKey;
+                const ix = await engine.addSupportedMintIx({
+                    owner: owner.publicKey,
+                    mint,
+                    feeRecipient,
+                });
+
+                await expectIxOk(connection, [ix], [owner]);
+
+                const supportedMintData = await engine.fetchSupportedMint(mint);
+                expect(supportedMintData.mint).to.eql(mint);
+                expect(supportedMintData.feeRecipientToken).to.eql(
+                    splToken.getAssociatedTokenAddressSync(mint, feeRecipient),
+                );
+            });
+
+            it("Cannot Remove Supported Mint as Owner Assistant", async function () {
+                const mint = localVariables.get("mint") as PublicKey;
+                const ix = await engine.removeSupportedMintIx({
+                    owner: ownerAssistant.publicKey,
+                    mint,
+                });
+
+                await expectIxErr(connection, [ix], [ownerAssistant], "Error Code: OwnerOnly");
+            });
+
+            it("Remove Supported Mint as Owner", async function () {
+                const mint = localVariables.get("mint") as PublicKey;
+                expect(localVariables.delete("mint")).is.true;
+
+                const ix = await engine.removeSupportedMintIx({
+                    owner: owner.publicKey,
+                    mint,
+                });
+
+                await expectIxOk(connection, [ix], [owner]);
+
+                const accInfo = await connection.getAccountInfo(engine.supportedMintAddress(mint));
+                expect(accInfo).is.null;
+            });
+        });
+
+        describe("Owner Set", function () {
+            const ownerSet = engine.ownerSetAddress();
+
+            it("Cannot Initialize Owner Set as Owner Assistant", async function () {
+                const ix = await engine.initializeOwnerSetIx(
+                    { owner: ownerAssistant.publicKey },
+                    { threshold: 1, members: [ownerAssistant.publicKey] },
+                );
+
+                await expectIxErr(connection, [ix], [ownerAssistant], "Error Code: OwnerOnly");
+            });
+
+            it("Cannot Initialize Owner Set with Threshold Above Member Count", async function () {
+                const ix = await engine.initializeOwnerSetIx(
+                    { owner: owner.publicKey },
+                    { threshold: 2, members: [owner.publicKey] },
+                );
+
+                await expectIxErr(connection, [ix], [owner], "Error Code: InvalidOwnerSet");
+            });
+
+            it("Initialize Owner Set as Owner", async function () {
+                const members = [owner.publicKey, ownerAssistant.publicKey];
+                const ix = await engine.initializeOwnerSetIx(
+                    { owner: owner.publicKey },
+                    { threshold: 2, members },
+                );
+
+                await expectIxOk(connection, [ix], [owner]);
+
+                const ownerSetData = await engine.fetchOwnerSet();
+                expect(ownerSetData.threshold).equals(2);
+                expect(ownerSetData.members).to.eql(members);
+                expect(ownerSetData.nextTransactionId.toNumber()).equals(0);
+            });
+
+            it("Cannot Propose Owner Set Transaction as Non-Member", async function () {
+                const { args } = await proposedInstruction(
+                    await engine.updateRouterEndpointRemovalDelayIx({ owner: ownerSet }, 0),
+                );
+                const ix = await engine.proposeOwnerSetTransactionIx(
+                    { member: relayer.publicKey, payer: payer.publicKey },
+                    args,
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer, relayer],
+                    "Error Code: OwnerSetMemberOnly",
+                );
+            });
+
+            it("Transfer Ownership to Owner Set", async function () {
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.submitOwnershipTransferIx({
+                            owner: owner.publicKey,
+                            newOwner: ownerSet,
+                        }),
+                    ],
+                    [owner],
+                );
+
+                const { transaction, args } = await proposedInstruction(
+                    await engine.confirmOwnershipTransferIx({ pendingOwner: ownerSet }),
+                );
+                await expectIxOk(
+                    connection,
+                    [await engine.proposeOwnerSetTransactionIx({ member: owner.publicKey }, args)],
+                    [owner],
+                );
+
+                // Only the proposer has approved, so the threshold is not met yet.
+                await expectIxErr(
+                    connection,
+                    [await engine.executeOwnerSetTransactionIx({ transaction })],
+                    [payer],
+                    "Error Code: OwnerSetThresholdNotMet",
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.approveOwnerSetTransactionIx({
+                            member: ownerAssistant.publicKey,
+                            transaction,
+                        }),
+                    ],
+                    [ownerAssistant],
+                );
+
+                await expectIxOk(
+                    connection,
+                    [await engine.executeOwnerSetTransactionIx({ transaction })],
+                    [payer],
+                );
+
+                const custodianData = await engine.fetchCustodian();
+                expect(custodianData.owner).to.eql(ownerSet);
+                expect(custodianData.pendingOwner).is.null;
+
+                // The executed transaction is closed.
+                const accInfo = await connection.getAccountInfo(transaction);
+                expect(accInfo).is.null;
+            });
+
+            it("Return Ownership from Owner Set", async function () {
+                const { transaction, args } = await proposedInstruction(
+                    await engine.submitOwnershipTransferIx({
+                        owner: ownerSet,
+                        newOwner: owner.publicKey,
+                    }),
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.proposeOwnerSetTransactionIx(
+                            { member: ownerAssistant.publicKey },
+                            args,
+                        ),
+                        await engine.approveOwnerSetTransactionIx({
+                            member: owner.publicKey,
+                            transaction,
+                        }),
+                    ],
+                    [ownerAssistant, owner],
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.executeOwnerSetTransactionIx({ transaction }),
+                        await engine.confirmOwnershipTransferIx({ pendingOwner: owner.publicKey }),
+                    ],
+                    [payer, owner],
+                );
+
+                const custodianData = await engine.fetchCustodian();
+                expect(custodianData.owner).to.eql(owner.publicKey);
+            });
+
+            it("Update Owner Set Drops Approvals from Former Members", async function () {
+                const { transaction, args } = await proposedInstruction(
+                    await engine.updateRouterEndpointRemovalDelayIx({ owner: ownerSet }, 0),
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.proposeOwnerSetTransactionIx(
+                            { member: ownerAssistant.publicKey },
+                            args,
+                        ),
+                        await engine.approveOwnerSetTransactionIx({
+                            member: owner.publicKey,
+                            transaction,
+                        }),
+                    ],
+                    [ownerAssistant, owner],
+                );
+
+                // Replace the owner assistant with the relayer.
+                const members = [owner.publicKey, relayer.publicKey];
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.updateOwnerSetIx(
+                            { owner: owner.publicKey, pendingTransactions: [transaction] },
+                            { threshold: 2, members },
+                        ),
+                    ],
+                    [owner],
+                );
+
+                const { approvals } = await engine.fetchOwnerSetTransaction({
+                    address: transaction,
+                });
+                expect(approvals).to.eql([owner.publicKey]);
+
+                await expectIxErr(
+                    connection,
+                    [await engine.executeOwnerSetTransactionIx({ transaction })],
+                    [payer],
+                    "Error Code: OwnerSetThresholdNotMet",
+                );
+
+                // Clean up.
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.cancelOwnerSetTransactionIx({
+                            proposedBy: ownerAssistant.publicKey,
+                            transaction,
+                        }),
+                    ],
+                    [ownerAssistant],
+                );
+            });
+
+            async function proposedInstruction(ix: TransactionInstruction) {
+                const { nextTransactionId } = await engine.fetchOwnerSet();
+                return {
+                    transaction: engine.ownerSetTransactionAddress(nextTransactionId),
+                    args: {
+                        accounts: ix.keys.map(({ pubkey, isSigner, isWritable }) => ({
+                            pubkey,
+                            isSigner,
+                            isWritable,
+                        })),
+                        data: ix.data,
+                    },
+                };
+            }
+        });
+    });
+
+    describe("Business Logic", function () {
+        const baseFastOrder: FastMarketOrder = {
+            amountIn: 50000000000n,
+            minAmountOut: 0n,
+            targetChain: toChain(arbChain),
+            redeemer: toUniversalAddress(Buffer.alloc(32, "deadbeef", "hex")),
+            sender: toUniversalAddress(Buffer.alloc(32, "beefdead", "hex")),
+            refundAddress: toUniversalAddress(Buffer.alloc(32, "beef", "hex")),
+            maxFee: 1000000n,
+            initAuctionFee: 100n,
+            deadline: 0,
+            redeemerMessage: encoding.bytes.encode("All your base are belong to us."),
+        };
+
+        describe("Place Initial CCTP Offer", function () {
+            for (const offerPrice of [0n, baseFastOrder.maxFee / 2n, baseFastOrder.maxFee]) {
+                it(`Place Initial Offer (Price == ${offerPrice})`, async function () {
+                    await placeInitialOfferCctpForTest(
+                        {
+                            payer: playerOne.publicKey,
+                        },
+                        {
+                            args: {
+                                offerPrice,
+                            },
+                            signers: [playerOne],
+                            finalized: false,
+                            fastMarketOrder: baseFastOrder,
+                        },
+                    );
+                });
+            }
+
+            it("Place Initial Offer (Tx)", async function () {
+                const { fast } = await observeCctpOrderVaas({ sourceChain: "Ethereum" });
+
+                const auction = engine.auctionAddress(fast.vaaAccount.digest());
                 const { auctionConfigId } = await engine.fetchCustodian();
 
                 const { vaa: fastVaa, fastMarketOrder } = fast;
@@ -1500,24 +2528,235 @@ describe("Matching Engine", function () {
                             totalDeposit: 69n,
                         },
                         signers: [playerOne],
-                        errorMsg: "Error Code: InvalidVaa",
+                        errorMsg: "Error Code: InvalidVaa",
+                    },
+                );
+            });
+
+            it("Cannot Place Initial Offer (Paused)", async function () {
+                // Pause the matching engine. TODO: make pauseForTest.
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.setPauseIx(
+                            {
+                                authority: owner.publicKey,
+                            },
+                            true,
+                        ),
+                    ],
+                    [owner],
+                );
+
+                await placeInitialOfferCctpForTest(
+                    {
+                        payer: playerOne.publicKey,
+                    },
+                    {
+                        signers: [playerOne],
+                        finalized: false,
+                        fastMarketOrder: baseFastOrder,
+                        errorMsg: "Error Code: Paused",
+                    },
+                );
+
+                // Unpause the matching engine.
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.setPauseIx(
+                            {
+                                authority: owner.publicKey,
+                            },
+                            false,
+                        ),
+                    ],
+                    [owner],
+                );
+            });
+
+            it("Cannot Place Initial Offer (Endpoint Disabled)", async function () {
+                // Disable the Eth router endpoint. TODO: make disableEndpointForTest.
+                await expectIxOk(
+                    connection,
+                    [await engine.disableRouterEndpointIx({ owner: owner.publicKey }, ethChain)],
+                    [owner],
+                );
+
+                await placeInitialOfferCctpForTest(
+                    {
+                        payer: playerOne.publicKey,
+                    },
+                    {
+                        signers: [playerOne],
+                        finalized: false,
+                        fastMarketOrder: baseFastOrder,
+                        errorMsg: "Error Code: EndpointDisabled",
+                    },
+                );
+
+                // Enabled the Eth Router again.
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.updateCctpRouterEndpointIx(
+                            { owner: owner.publicKey },
+                            {
+                                chain: ethChain,
+                                cctpDomain: ethDomain,
+                                address: ethRouter,
+                                mintRecipient: null,
+                            },
+                        ),
+                    ],
+                    [owner],
+                );
+            });
+
+            it("Cannot Place Initial Offer (New Auctions Paused)", async function () {
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.setStagePauseIx(
+                            { authority: ownerAssistant.publicKey },
+                            {
+                                target: { newAuctions: {} },
+                                paused: true,
+                                unpauseSlot: null,
+                                reason: 0,
+                            },
+                        ),
+                    ],
+                    [ownerAssistant],
+                );
+
+                await placeInitialOfferCctpForTest(
+                    {
+                        payer: playerOne.publicKey,
+                    },
+                    {
+                        signers: [playerOne],
+                        finalized: false,
+                        fastMarketOrder: baseFastOrder,
+                        errorMsg: "Error Code: Paused",
+                    },
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.setStagePauseIx(
+                            { authority: ownerAssistant.publicKey },
+                            {
+                                target: { newAuctions: {} },
+                                paused: false,
+                                unpauseSlot: null,
+                                reason: 0,
+                            },
+                        ),
+                    ],
+                    [ownerAssistant],
+                );
+            });
+
+            it("Cannot Place Initial Offer (Endpoint Paused)", async function () {
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.setRouterEndpointPauseIx(
+                            { authority: ownerAssistant.publicKey },
+                            ethChain,
+                            { paused: true, unpauseSlot: null, reason: 0 },
+                        ),
+                    ],
+                    [ownerAssistant],
+                );
+
+                await placeInitialOfferCctpForTest(
+                    {
+                        payer: playerOne.publicKey,
+                    },
+                    {
+                        signers: [playerOne],
+                        finalized: false,
+                        fastMarketOrder: baseFastOrder,
+                        errorMsg: "Error Code: EndpointPaused",
+                    },
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.setRouterEndpointPauseIx(
+                            { authority: ownerAssistant.publicKey },
+                            ethChain,
+                            { paused: false, unpauseSlot: null, reason: 0 },
+                        ),
+                    ],
+                    [ownerAssistant],
+                );
+            });
+
+            it("Cannot Place Initial Offer (Order Amount Below Minimum)", async function () {
+                const noLimits = {
+                    maxRedeemerMessageLen: null,
+                    minOrderAmount: null,
+                    maxOrderAmount: null,
+                };
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.setRouterEndpointLimitsIx(
+                            { authority: ownerAssistant.publicKey },
+                            arbChain,
+                            {
+                                ...noLimits,
+                                minOrderAmount: uint64ToBN(baseFastOrder.amountIn + 1n),
+                            },
+                        ),
+                    ],
+                    [ownerAssistant],
+                );
+
+                await placeInitialOfferCctpForTest(
+                    {
+                        payer: playerOne.publicKey,
+                    },
+                    {
+                        signers: [playerOne],
+                        finalized: false,
+                        fastMarketOrder: baseFastOrder,
+                        errorMsg: "Error Code: OrderAmountBelowMinimum",
                     },
                 );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.setRouterEndpointLimitsIx(
+                            { authority: ownerAssistant.publicKey },
+                            arbChain,
+                            noLimits,
+                        ),
+                    ],
+                    [ownerAssistant],
+                );
             });
 
-            it("Cannot Place Initial Offer (Paused)", async function () {
-                // Pause the matching engine. TODO: make pauseForTest.
+            it("Cannot Place Initial Offer (Endpoint Throughput Exceeded)", async function () {
                 await expectIxOk(
                     connection,
                     [
-                        await engine.setPauseIx(
+                        await engine.setRouterEndpointInboundLimitIx(
+                            { authority: ownerAssistant.publicKey },
+                            ethChain,
                             {
-                                ownerOrAssistant: owner.publicKey,
+                                windowSlots: uint64ToBN(1_000_000),
+                                maxNotional: uint64ToBN(baseFastOrder.amountIn - 1n),
                             },
-                            true,
                         ),
                     ],
-                    [owner],
+                    [ownerAssistant],
                 );
 
                 await placeInitialOfferCctpForTest(
@@ -1528,30 +2767,37 @@ describe("Matching Engine", function () {
                         signers: [playerOne],
                         finalized: false,
                         fastMarketOrder: baseFastOrder,
-                        errorMsg: "Error Code: Paused",
+                        errorMsg: "Error Code: EndpointThroughputExceeded",
                     },
                 );
 
-                // Unpause the matching engine.
                 await expectIxOk(
                     connection,
                     [
-                        await engine.setPauseIx(
-                            {
-                                ownerOrAssistant: owner.publicKey,
-                            },
-                            false,
+                        await engine.setRouterEndpointInboundLimitIx(
+                            { authority: ownerAssistant.publicKey },
+                            ethChain,
+                            { windowSlots: uint64ToBN(0), maxNotional: uint64ToBN(0) },
                         ),
                     ],
-                    [owner],
+                    [ownerAssistant],
                 );
             });
 
-            it("Cannot Place Initial Offer (Endpoint Disabled)", async function () {
-                // Disable the Eth router endpoint. TODO: make disableEndpointForTest.
+            it("Cannot Place Initial Offer (Circuit Breaker Tripped)", async function () {
+                // The first order trips the circuit breaker, which refuses subsequent orders.
                 await expectIxOk(
                     connection,
-                    [await engine.disableRouterEndpointIx({ owner: owner.publicKey }, ethChain)],
+                    [
+                        await engine.updateCircuitBreakerIx(
+                            { owner: owner.publicKey },
+                            {
+                                windowSlots: uint64ToBN(1_000_000),
+                                globalThreshold: uint64ToBN(2n ** 64n - 1n),
+                                chainThreshold: uint64ToBN(baseFastOrder.amountIn - 1n),
+                            },
+                        ),
+                    ],
                     [owner],
                 );
 
@@ -1563,21 +2809,36 @@ describe("Matching Engine", function () {
                         signers: [playerOne],
                         finalized: false,
                         fastMarketOrder: baseFastOrder,
-                        errorMsg: "Error Code: EndpointDisabled",
                     },
                 );
 
-                // Enabled the Eth Router again.
+                {
+                    const { circuitBreaker } = await engine.fetchInboundNotional(ethChain);
+                    expect(circuitBreaker.notional).to.eql(uint64ToBN(baseFastOrder.amountIn));
+                }
+
+                await placeInitialOfferCctpForTest(
+                    {
+                        payer: playerOne.publicKey,
+                    },
+                    {
+                        signers: [playerOne],
+                        finalized: false,
+                        fastMarketOrder: baseFastOrder,
+                        errorMsg: "Error Code: CircuitBreakerTripped",
+                    },
+                );
+
+                // Disable the circuit breaker.
                 await expectIxOk(
                     connection,
                     [
-                        await engine.updateCctpRouterEndpointIx(
+                        await engine.updateCircuitBreakerIx(
                             { owner: owner.publicKey },
                             {
-                                chain: ethChain,
-                                cctpDomain: ethDomain,
-                                address: ethRouter,
-                                mintRecipient: null,
+                                windowSlots: uint64ToBN(0),
+                                globalThreshold: uint64ToBN(0),
+                                chainThreshold: uint64ToBN(0),
                             },
                         ),
                     ],
@@ -1755,6 +3016,123 @@ describe("Matching Engine", function () {
             });
         });
 
+        describe("Place Initial CCTP Offer from Previous Router Address", function () {
+            const newEthRouter = Array.from(Buffer.alloc(32, "e7e7e7e7", "hex"));
+
+            const localVariables = new Map<string, any>();
+
+            after("Restore Ethereum Router Endpoint", async function () {
+                await expectIxOk(
+                    connection,
+                    [
+                        await engine.updateCctpRouterEndpointIx(
+                            { owner: owner.publicKey },
+                            {
+                                chain: ethChain,
+                                cctpDomain: ethDomain,
+                                address: ethRouter,
+                                mintRecipient: null,
+                            },
+                        ),
+                    ],
+                    [owner],
+                );
+
+                const { previousAddress } = await engine.fetchRouterEndpoint(ethChain);
+                expect(previousAddress).is.null;
+            });
+
+            it("Cannot Update Router Endpoint with Expired Previous Address", async function () {
+                const ix = await engine.updateCctpRouterEndpointIx(
+                    { owner: owner.publicKey },
+                    {
+                        chain: ethChain,
+                        cctpDomain: ethDomain,
+                        address: newEthRouter,
+                        mintRecipient: null,
+                    },
+                    { previousAddressExpirySlot: await connection.getSlot() },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [owner],
+                    "Error Code: InvalidPreviousAddressExpiry",
+                );
+            });
+
+            it("Update Router Endpoint with Previous Address", async function () {
+                const previousAddressExpirySlot = (await connection.getSlot()) + 100;
+
+                const ix = await engine.updateCctpRouterEndpointIx(
+                    { owner: owner.publicKey },
+                    {
+                        chain: ethChain,
+                        cctpDomain: ethDomain,
+                        address: newEthRouter,
+                        mintRecipient: null,
+                    },
+                    { previousAddressExpirySlot },
+                );
+                await expectIxOk(connection, [ix], [owner]);
+
+                const { info, previousAddress } = await engine.fetchRouterEndpoint(ethChain);
+                expect(info.address).to.eql(newEthRouter);
+                expect(previousAddress).to.eql({
+                    address: ethRouter,
+                    expirySlot: uint64ToBN(previousAddressExpirySlot),
+                });
+
+                // Save for later.
+                localVariables.set("previousAddressExpirySlot", previousAddressExpirySlot);
+            });
+
+            it("Place Initial Offer from Previous Router Address before Expiry", async function () {
+                await placeInitialOfferCctpForTest(
+                    { payer: playerOne.publicKey },
+                    {
+                        signers: [playerOne],
+                        finalized: false,
+                        fastMarketOrder: baseFastOrder,
+                        emitter: ethRouter,
+                    },
+                );
+            });
+
+            it("Place Initial Offer from New Router Address", async function () {
+                await placeInitialOfferCctpForTest(
+                    { payer: playerOne.publicKey },
+                    {
+                        signers: [playerOne],
+                        finalized: false,
+                        fastMarketOrder: baseFastOrder,
+                        emitter: newEthRouter,
+                    },
+                );
+            });
+
+            it("Cannot Place Initial Offer from Previous Router Address after Expiry", async function () {
+                const previousAddressExpirySlot = localVariables.get(
+                    "previousAddressExpirySlot",
+                ) as number;
+                expect(localVariables.delete("previousAddressExpirySlot")).is.true;
+
+                await waitUntilSlot(connection, previousAddressExpirySlot);
+
+                await placeInitialOfferCctpForTest(
+                    { payer: playerOne.publicKey },
+                    {
+                        signers: [playerOne],
+                        finalized: false,
+                        fastMarketOrder: baseFastOrder,
+                        emitter: ethRouter,
+                        errorMsg: "Error Code: InvalidSourceRouter",
+                    },
+                );
+            });
+        });
+
         describe("Improve Offer", function () {
             for (const newOffer of [0n, baseFastOrder.maxFee / 2n]) {
                 it(`Improve Offer (Price == ${newOffer})`, async function () {
@@ -3778,6 +5156,216 @@ describe("Matching Engine", function () {
             });
         });
 
+        describe("NTT", function () {
+            const nttChain = toChainId("Optimism");
+            const nttManagerProgram = new PublicKey("MockNttManager11111111111111111111111111111");
+            const nttTransceiver = Keypair.generate().publicKey;
+            const [nttCustodyToken] = PublicKey.findProgramAddressSync(
+                [Buffer.from("custody"), USDC_MINT_ADDRESS.toBuffer()],
+                nttManagerProgram,
+            );
+
+            before("Add NTT Router Endpoint", async function () {
+                const ix = await engine.addNttRouterEndpointIx(
+                    { authority: owner.publicKey, mint: USDC_MINT_ADDRESS },
+                    {
+                        chain: nttChain,
+                        address: Array.from(Buffer.alloc(32, "f00d", "hex")),
+                        mintRecipient: null,
+                        manager: nttManagerProgram,
+                        transceiver: nttTransceiver,
+                    },
+                );
+                await expectIxOk(connection, [ix], [owner]);
+            });
+
+            it("Execute Fast Order (NTT)", async function () {
+                const { fastVaa, auction } = await placeInitialOfferNttForTest();
+
+                const { info } = await engine.fetchAuction({ address: auction });
+                const { duration, gracePeriod } = await engine.fetchAuctionParameters();
+                await waitUntilSlot(
+                    connection,
+                    info!.startSlot.addn(duration + gracePeriod - 1).toNumber(),
+                );
+
+                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
+                    units: 300_000,
+                });
+
+                // The NTT manager requires its own custody token account.
+                {
+                    const ix = await engine.executeFastOrderNttIx({
+                        payer: playerOne.publicKey,
+                        fastVaa,
+                        nttCustodyToken: Keypair.generate().publicKey,
+                    });
+
+                    await expectIxErr(
+                        connection,
+                        [computeIx, ix],
+                        [playerOne],
+                        "custody. Error Code: ConstraintSeeds",
+                    );
+                }
+
+                const custodyTokenBefore = await engine.fetchAuctionCustodyTokenBalance(auction);
+                const nttCustodyTokenBefore = await getNttCustodyTokenBalance();
+
+                const ix = await engine.executeFastOrderNttIx({
+                    payer: playerOne.publicKey,
+                    fastVaa,
+                    nttCustodyToken,
+                });
+
+                await expectIxOk(connection, [computeIx, ix], [playerOne]);
+
+                // The auction custody token account is closed after the user amount is locked in
+                // the NTT manager's custody.
+                const amountIn = uint64ToBigInt(info!.amountIn);
+                const maxUserAmount = amountIn - uint64ToBigInt(info!.offerPrice);
+                expect(custodyTokenBefore).equals(amountIn + uint64ToBigInt(info!.securityDeposit));
+
+                const nttCustodyTokenAfter = await getNttCustodyTokenBalance();
+                const userAmount = nttCustodyTokenAfter - nttCustodyTokenBefore;
+                expect(userAmount > 0n && userAmount <= maxUserAmount).is.true;
+                {
+                    const accInfo = await connection.getAccountInfo(
+                        engine.auctionCustodyTokenAddress(auction),
+                    );
+                    expect(accInfo).is.null;
+                }
+
+                // The outbox item is written by the NTT manager.
+                {
+                    const accInfo = await connection.getAccountInfo(
+                        engine.nttOutboxItemAddress(auction),
+                    );
+                    expect(accInfo!.owner).to.eql(nttManagerProgram);
+                }
+
+                const { status, targetProtocol } = await engine.fetchAuction({ address: auction });
+                expect(status.completed).is.not.undefined;
+                expect(targetProtocol).to.eql({
+                    ntt: { manager: nttManagerProgram, transceiver: nttTransceiver },
+                });
+            });
+
+            it("Settle No Auction (NTT)", async function () {
+                const result = await prepareOrderResponseCctpForTest(
+                    {
+                        payer: payer.publicKey,
+                    },
+                    {
+                        placeInitialOffer: false,
+                        fastMarketOrder: newFastMarketOrder({ targetChain: toChain(nttChain) }),
+                    },
+                );
+                const { fastVaa, finalizedVaa, preparedOrderResponse } = result!;
+
+                const ix = await engine.settleAuctionNoneNttIx({
+                    payer: payer.publicKey,
+                    fastVaa,
+                    nttCustodyToken,
+                    preparedOrderResponse,
+                });
+
+                const { amount: feeBalanceBefore } = await splToken.getAccount(
+                    connection,
+                    feeRecipientToken,
+                );
+                const nttCustodyTokenBefore = await getNttCustodyTokenBalance();
+
+                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
+                    units: 300_000,
+                });
+
+                await expectIxOk(connection, [computeIx, ix], [payer]);
+
+                const fastVaaAccount = await VaaAccount.fetch(connection, fastVaa);
+                const { fastMarketOrder } = LiquidityLayerMessage.decode(fastVaaAccount.payload());
+                const finalizedVaaAccount = await VaaAccount.fetch(connection, finalizedVaa);
+                const {
+                    message: { payload: slowOrderResponse },
+                } = LiquidityLayerMessage.decode(finalizedVaaAccount.payload()).deposit!;
+
+                const fee =
+                    (slowOrderResponse! as SlowOrderResponse).baseFee +
+                    fastMarketOrder!.initAuctionFee;
+
+                const { amount: feeBalanceAfter } = await splToken.getAccount(
+                    connection,
+                    feeRecipientToken,
+                );
+                expect(feeBalanceAfter).equals(feeBalanceBefore + fee);
+
+                const nttCustodyTokenAfter = await getNttCustodyTokenBalance();
+                expect(nttCustodyTokenAfter).equals(
+                    nttCustodyTokenBefore + fastMarketOrder!.amountIn - fee,
+                );
+
+                {
+                    const preparedCustodyToken =
+                        engine.preparedCustodyTokenAddress(preparedOrderResponse);
+                    const accInfo = await connection.getAccountInfo(preparedCustodyToken);
+                    expect(accInfo).is.null;
+                }
+
+                const fastVaaHash = fastVaaAccount.digest();
+                const auction = engine.auctionAddress(fastVaaHash);
+                {
+                    const accInfo = await connection.getAccountInfo(
+                        engine.nttOutboxItemAddress(auction),
+                    );
+                    expect(accInfo!.owner).to.eql(nttManagerProgram);
+                }
+
+                const auctionData = await engine.fetchAuction({ address: auction });
+                const { bump, preparedBy } = auctionData;
+                expect(auctionData).to.eql(
+                    new Auction(
+                        bump,
+                        Array.from(fastVaaHash),
+                        fastVaaAccount.timestamp(),
+                        { ntt: { manager: nttManagerProgram, transceiver: nttTransceiver } },
+                        {
+                            settled: {
+                                fee: uint64ToBN(fee),
+                                totalPenalty: null,
+                            },
+                        },
+                        preparedBy,
+                        null,
+                    ),
+                );
+            });
+
+            async function placeInitialOfferNttForTest() {
+                const { fast } = await observeCctpOrderVaas({
+                    finalized: false,
+                    fastMarketOrder: newFastMarketOrder({ targetChain: toChain(nttChain) }),
+                });
+
+                const ixs = await engine.placeInitialOfferCctpIx(
+                    { payer: playerOne.publicKey, fastVaa: fast.vaa },
+                    { offerPrice: fast.fastMarketOrder.maxFee },
+                );
+                await expectIxOk(connection, ixs, [playerOne]);
+
+                return {
+                    fastVaa: fast.vaa,
+                    auction: engine.auctionAddress(fast.vaaAccount.digest()),
+                };
+            }
+
+            async function getNttCustodyTokenBalance() {
+                return splToken
+                    .getAccount(connection, nttCustodyToken)
+                    .then((token) => token.amount)
+                    .catch(() => 0n);
+            }
+        });
+
         describe("Close Auction", function () {
             it("Cannot Close Unsettled Auction", async function () {
                 const result = await placeInitialOfferCctpForTest(
@@ -5019,4 +6607,27 @@ describe("Matching Engine", function () {
             cctpAttestation,
         };
     }
+
+    function defaultPauseFlag(): PauseFlag {
+        return { paused: false, unpauseSlot: null, reason: 0 };
+    }
+
+    function newRouterEndpoint(
+        bump: number,
+        info: EndpointInfo,
+        overrides: { disabledSlot?: BN | null } = {},
+    ) {
+        const { disabledSlot } = overrides;
+
+        return new RouterEndpoint(
+            bump,
+            info,
+            defaultPauseFlag(),
+            { windowSlots: uint64ToBN(0), maxNotional: uint64ToBN(0) },
+            disabledSlot ?? null,
+            null, // previousAddress
+            { maxRedeemerMessageLen: null, minOrderAmount: null, maxOrderAmount: null },
+            USDC_MINT_ADDRESS,
+        );
+    }
 });
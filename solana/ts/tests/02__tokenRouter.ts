@@ -1,3 +1,4 @@
+import { utils } from "@coral-xyz/anchor";
 import * as splToken from "@solana/spl-token";
 import {
     AddressLookupTableProgram,
@@ -12,8 +13,10 @@ import { ChainId, toChain, toChainId } from "@wormhole-foundation/sdk-base";
 import { toUniversal } from "@wormhole-foundation/sdk-definitions";
 import { deserializePostMessage } from "@wormhole-foundation/sdk-solana-core";
 import { expect } from "chai";
+import { createHash } from "crypto";
 import { CctpTokenBurnMessage } from "../src/cctp";
 import { LiquidityLayerDeposit, LiquidityLayerMessage, uint64ToBN } from "../src/common";
+import { CPI_EVENT_IX_SELECTOR } from "../src/matchingEngine";
 import {
     CircleAttester,
     ETHEREUM_USDC_ADDRESS,
@@ -26,8 +29,12 @@ import {
     USDC_MINT_ADDRESS,
     expectIxErr,
     expectIxOk,
+    expectIxOkDetails,
+    getBlockTime,
     postLiquidityLayerVaa,
     toUniversalAddress,
+    waitUntilSlot,
+    waitUntilTimestamp,
 } from "../src/testing";
 import { Custodian, PreparedOrder, TokenRouterProgram, localnet } from "../src/tokenRouter";
 
@@ -92,6 +99,9 @@ describe("Token Router", function () {
                         null, // pendingOwner
                         ownerAssistant.publicKey,
                         payer.publicKey, // pausedSetBy
+                        uint64ToBN(0), // pendingOwnerExpirySlot
+                        null, // pendingOwnerAssistant
+                        0, // maxIntegratorFeeBps
                     ),
                 );
 
@@ -179,12 +189,14 @@ describe("Token Router", function () {
                     newOwner: owner.publicKey,
                 });
 
+                const slot = await connection.getSlot();
                 await expectIxOk(connection, [ix], [payer]);
 
                 // Confirm that the pending owner variable is set in the owner config.
-                const { pendingOwner } = await tokenRouter.fetchCustodian();
+                const { pendingOwner, pendingOwnerExpirySlot } = await tokenRouter.fetchCustodian();
 
                 expect(pendingOwner).deep.equals(owner.publicKey);
+                expect(pendingOwnerExpirySlot.toNumber()).is.greaterThan(slot);
             });
 
             it("Cannot Cancel Ownership Request as Non-Owner", async function () {
@@ -202,9 +214,10 @@ describe("Token Router", function () {
 
                 await expectIxOk(connection, [ix], [payer]);
 
-                // Confirm the pending owner field was reset.
-                const { pendingOwner } = await tokenRouter.fetchCustodian();
+                // Confirm the pending owner fields were reset.
+                const { pendingOwner, pendingOwnerExpirySlot } = await tokenRouter.fetchCustodian();
                 expect(pendingOwner).deep.equals(null);
+                expect(pendingOwnerExpirySlot.toNumber()).equals(0);
             });
 
             it("Submit Ownership Transfer Request as Payer Again to Owner Pubkey", async function () {
@@ -304,9 +317,38 @@ describe("Token Router", function () {
 
                 await expectIxOk(connection, [ix], [payer, owner]);
 
+                // The assistant is not replaced until the pending assistant accepts the role.
+                const { ownerAssistant: actualOwnerAssistant, pendingOwnerAssistant } =
+                    await tokenRouter.fetchCustodian();
+                expect(actualOwnerAssistant).to.eql(ownerAssistant.publicKey);
+                expect(pendingOwnerAssistant).to.eql(relayer.publicKey);
+            });
+
+            it("Cannot Accept Assistant as Non-Pending Assistant", async function () {
+                const ix = await tokenRouter.acceptOwnerAssistantIx({
+                    pendingOwnerAssistant: ownerAssistant.publicKey,
+                });
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [ownerAssistant],
+                    "Error Code: NotPendingOwnerAssistant",
+                );
+            });
+
+            it("Accept Assistant as Pending Assistant", async function () {
+                const ix = await tokenRouter.acceptOwnerAssistantIx({
+                    pendingOwnerAssistant: relayer.publicKey,
+                });
+
+                await expectIxOk(connection, [ix], [relayer]);
+
                 // Confirm the assistant field was updated.
-                const { ownerAssistant: actualOwnerAssistant } = await tokenRouter.fetchCustodian();
+                const { ownerAssistant: actualOwnerAssistant, pendingOwnerAssistant } =
+                    await tokenRouter.fetchCustodian();
                 expect(actualOwnerAssistant).to.eql(relayer.publicKey);
+                expect(pendingOwnerAssistant).is.null;
 
                 // Set the assistant back to the assistant key.
                 await expectIxOk(
@@ -316,8 +358,24 @@ describe("Token Router", function () {
                             owner: owner.publicKey,
                             newOwnerAssistant: ownerAssistant.publicKey,
                         }),
+                        await tokenRouter.acceptOwnerAssistantIx({
+                            pendingOwnerAssistant: ownerAssistant.publicKey,
+                        }),
                     ],
-                    [owner],
+                    [owner, ownerAssistant],
+                );
+            });
+
+            it("Cannot Accept Assistant without Pending Assistant", async function () {
+                const ix = await tokenRouter.acceptOwnerAssistantIx({
+                    pendingOwnerAssistant: relayer.publicKey,
+                });
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [relayer],
+                    "Error Code: NoPendingOwnerAssistant",
                 );
             });
         });
@@ -326,19 +384,24 @@ describe("Token Router", function () {
             it("Cannot Set Pause for Transfers as Non-Owner", async function () {
                 const ix = await tokenRouter.setPauseIx(
                     {
-                        ownerOrAssistant: payer.publicKey,
+                        authority: payer.publicKey,
                     },
                     true, // paused
                 );
 
-                await expectIxErr(connection, [ix], [payer], "Error Code: OwnerOrAssistantOnly");
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer],
+                    "Error Code: OwnerAssistantOrRoleOnly",
+                );
             });
 
             it("Set Paused == true as Owner Assistant", async function () {
                 const paused = true;
                 const ix = await tokenRouter.setPauseIx(
                     {
-                        ownerOrAssistant: ownerAssistant.publicKey,
+                        authority: ownerAssistant.publicKey,
                     },
                     paused,
                 );
@@ -354,7 +417,7 @@ describe("Token Router", function () {
                 const paused = false;
                 const ix = await tokenRouter.setPauseIx(
                     {
-                        ownerOrAssistant: owner.publicKey,
+                        authority: owner.publicKey,
                     },
                     paused,
                 );
@@ -365,263 +428,455 @@ describe("Token Router", function () {
                 expect(actualPaused).equals(paused);
                 expect(pausedSetBy).eql(owner.publicKey);
             });
-        });
-    });
-
-    describe("Business Logic", function () {
-        let testCctpNonce = 2n ** 64n - 1n;
 
-        // Hack to prevent math overflow error when invoking CCTP programs.
-        testCctpNonce -= 20n * 6400n;
+            it("Cannot Grant Pauser Role as Non-Owner", async function () {
+                const ix = await tokenRouter.grantRoleIx(
+                    {
+                        owner: ownerAssistant.publicKey,
+                        holder: relayer.publicKey,
+                    },
+                    { pauser: {} },
+                );
 
-        let wormholeSequence = 2000n;
+                await expectIxErr(connection, [ix], [ownerAssistant], "Error Code: OwnerOnly");
+            });
 
-        describe("Preparing Order", function () {
-            const payerToken = splToken.getAssociatedTokenAddressSync(
-                USDC_MINT_ADDRESS,
-                payer.publicKey,
-            );
+            it("Grant Pauser Role as Owner", async function () {
+                const ix = await tokenRouter.grantRoleIx(
+                    {
+                        owner: owner.publicKey,
+                        holder: relayer.publicKey,
+                    },
+                    { pauser: {} },
+                );
 
-            const localVariables = new Map<string, any>();
+                await expectIxOk(connection, [ix], [owner]);
 
-            it("Cannot Prepare Market Order with Large Redeemer Payload", async function () {
-                const preparedOrder = Keypair.generate();
+                const { holder, roles } = await tokenRouter.fetchRoleHolder(relayer.publicKey);
+                expect(holder).to.eql(relayer.publicKey);
+                expect(roles).to.eql([{ pauser: {} }]);
+            });
 
-                const amountIn = 5000n;
-                const minAmountOut = 0n;
-                const targetChain = foreignChain;
-                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-                const redeemerMessage = Buffer.alloc(501, "deadbeef", "hex");
-                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
+            it("Cannot Grant Pauser Role Again", async function () {
+                const ix = await tokenRouter.grantRoleIx(
                     {
-                        payer: payer.publicKey,
-                        preparedOrder: preparedOrder.publicKey,
-                        senderToken: payerToken,
+                        owner: owner.publicKey,
+                        holder: relayer.publicKey,
                     },
+                    { pauser: {} },
+                );
+
+                await expectIxErr(connection, [ix], [owner], "Error Code: RoleAlreadyGranted");
+            });
+
+            it("Set Paused == true as Pauser", async function () {
+                const ix = await tokenRouter.setPauseIx(
                     {
-                        amountIn,
-                        minAmountOut,
-                        targetChain,
-                        redeemer,
-                        redeemerMessage,
+                        authority: relayer.publicKey,
                     },
+                    true, // paused
                 );
 
-                await expectIxErr(
-                    connection,
-                    [approveIx!, prepareIx],
-                    [payer, preparedOrder],
-                    "Error Code: RedeemerMessageTooLarge",
-                );
-            });
+                await expectIxOk(connection, [ix], [relayer]);
 
-            it("Cannot Prepare Market Order with Insufficient Amount", async function () {
-                const preparedOrder = Keypair.generate();
+                const { paused, pausedSetBy } = await tokenRouter.fetchCustodian();
+                expect(paused).is.true;
+                expect(pausedSetBy).eql(relayer.publicKey);
+            });
 
-                const amountIn = 0n;
-                const minAmountOut = 0n;
-                const targetChain = foreignChain;
-                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-                const redeemerMessage = Buffer.from("All your base are belong to us");
-                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
+            it("Revoke Pauser Role as Owner", async function () {
+                const ix = await tokenRouter.revokeRoleIx(
                     {
-                        payer: payer.publicKey,
-                        preparedOrder: preparedOrder.publicKey,
-                        senderToken: payerToken,
+                        owner: owner.publicKey,
+                        holder: relayer.publicKey,
                     },
+                    { pauser: {} },
+                );
+
+                await expectIxOk(connection, [ix], [owner]);
+
+                const { roles } = await tokenRouter.fetchRoleHolder(relayer.publicKey);
+                expect(roles).has.length(0);
+            });
+
+            it("Cannot Set Pause after Pauser Role Revoked", async function () {
+                const ix = await tokenRouter.setPauseIx(
                     {
-                        amountIn,
-                        minAmountOut,
-                        targetChain,
-                        redeemer,
-                        redeemerMessage,
+                        authority: relayer.publicKey,
                     },
+                    false, // paused
                 );
 
                 await expectIxErr(
                     connection,
-                    [approveIx!, prepareIx],
-                    [payer, preparedOrder],
-                    "Error Code: InsufficientAmount",
+                    [ix],
+                    [relayer],
+                    "Error Code: OwnerAssistantOrRoleOnly",
                 );
             });
 
-            it("Cannot Prepare Market Order with Invalid Redeemer", async function () {
-                const preparedOrder = Keypair.generate();
-
-                const amountIn = 69n;
-                const minAmountOut = 0n;
-                const targetChain = foreignChain;
-                const redeemer = Array.from(Buffer.alloc(32, 0, "hex"));
-                const redeemerMessage = Buffer.from("All your base are belong to us");
-                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
+            it("Set Paused == false as Owner Assistant", async function () {
+                const ix = await tokenRouter.setPauseIx(
                     {
-                        payer: payer.publicKey,
-                        preparedOrder: preparedOrder.publicKey,
-                        senderToken: payerToken,
+                        authority: ownerAssistant.publicKey,
                     },
+                    false, // paused
+                );
+
+                await expectIxOk(connection, [ix], [ownerAssistant]);
+
+                const { paused } = await tokenRouter.fetchCustodian();
+                expect(paused).is.false;
+            });
+        });
+
+        describe("Update Max Integrator Fee", async function () {
+            it("Cannot Update Max Integrator Fee without Fee Manager Role", async function () {
+                const ix = await tokenRouter.updateMaxIntegratorFeeBpsIx(
                     {
-                        amountIn,
-                        minAmountOut,
-                        targetChain,
-                        redeemer,
-                        redeemerMessage,
+                        authority: relayer.publicKey,
                     },
+                    10_000,
                 );
 
                 await expectIxErr(
                     connection,
-                    [approveIx!, prepareIx],
-                    [payer, preparedOrder],
-                    "Error Code: InvalidRedeemer",
+                    [ix],
+                    [relayer],
+                    "Error Code: OwnerAssistantOrRoleOnly",
                 );
             });
 
-            it("Cannot Prepare Market Order with Min Amount Too High", async function () {
-                const preparedOrder = Keypair.generate();
-
-                const amountIn = 1n;
-                const minAmountOut = 2n;
-                const targetChain = foreignChain;
-                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-                const redeemerMessage = Buffer.from("All your base are belong to us");
-                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
-                    {
-                        payer: payer.publicKey,
-                        preparedOrder: preparedOrder.publicKey,
-                        senderToken: payerToken,
-                    },
+            it("Cannot Update Max Integrator Fee Above 100%", async function () {
+                const ix = await tokenRouter.updateMaxIntegratorFeeBpsIx(
                     {
-                        amountIn,
-                        minAmountOut,
-                        targetChain,
-                        redeemer,
-                        redeemerMessage,
+                        authority: owner.publicKey,
                     },
+                    1_000_001,
                 );
 
                 await expectIxErr(
                     connection,
-                    [approveIx!, prepareIx],
-                    [payer, preparedOrder],
-                    "Error Code: MinAmountOutTooHigh",
+                    [ix],
+                    [owner],
+                    "Error Code: MaxIntegratorFeeBpsTooLarge",
                 );
             });
 
-            it("Cannot Prepare Market Order without Delegating Authority to Program Transfer Authority", async function () {
-                const preparedOrder = Keypair.generate();
-
-                const amountIn = 69n;
-                const minAmountOut = 0n;
-                const targetChain = foreignChain;
-                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-                const redeemerMessage = Buffer.from("All your base are belong to us");
-                const [, ix] = await tokenRouter.prepareMarketOrderIx(
-                    {
-                        payer: payer.publicKey,
-                        preparedOrder: preparedOrder.publicKey,
-                        senderToken: payerToken,
-                    },
+            it("Update Max Integrator Fee as Owner Assistant", async function () {
+                const maxFeeBps = 5_000;
+                const ix = await tokenRouter.updateMaxIntegratorFeeBpsIx(
                     {
-                        amountIn,
-                        minAmountOut,
-                        targetChain,
-                        redeemer,
-                        redeemerMessage,
+                        authority: ownerAssistant.publicKey,
                     },
+                    maxFeeBps,
                 );
 
-                await expectIxErr(
-                    connection,
-                    [ix],
-                    [payer, preparedOrder],
-                    "Error Code: DelegatedAmountMismatch",
-                );
+                await expectIxOk(connection, [ix], [ownerAssistant]);
+
+                const { maxIntegratorFeeBps } = await tokenRouter.fetchCustodian();
+                expect(maxIntegratorFeeBps).equals(maxFeeBps);
             });
 
-            it("Cannot Prepare Market Order Delegating Too Much to Program Transfer Authority", async function () {
-                const preparedOrder = Keypair.generate();
+            it("Update Max Integrator Fee as Fee Manager", async function () {
+                const grantIx = await tokenRouter.grantRoleIx(
+                    {
+                        owner: owner.publicKey,
+                        holder: relayer.publicKey,
+                    },
+                    { feeManager: {} },
+                );
 
-                const amountIn = 69n;
-                const minAmountOut = 0n;
-                const targetChain = foreignChain;
-                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-                const redeemerMessage = Buffer.from("All your base are belong to us");
-                const [approveIx, ix] = await tokenRouter.prepareMarketOrderIx(
+                // 1%.
+                const maxFeeBps = 10_000;
+                const ix = await tokenRouter.updateMaxIntegratorFeeBpsIx(
                     {
-                        payer: payer.publicKey,
-                        preparedOrder: preparedOrder.publicKey,
-                        senderToken: payerToken,
+                        authority: relayer.publicKey,
                     },
+                    maxFeeBps,
+                );
+
+                const revokeIx = await tokenRouter.revokeRoleIx(
                     {
-                        amountIn,
-                        minAmountOut,
-                        targetChain,
-                        redeemer,
-                        redeemerMessage,
+                        owner: owner.publicKey,
+                        holder: relayer.publicKey,
                     },
+                    { feeManager: {} },
                 );
 
-                // Approve for more.
-                approveIx!.data.writeBigUInt64LE(amountIn + 1n, 1);
+                await expectIxOk(connection, [grantIx, ix, revokeIx], [owner, relayer]);
 
-                await expectIxErr(
-                    connection,
-                    [approveIx!, ix],
-                    [payer, preparedOrder],
-                    "Error Code: DelegatedAmountMismatch",
-                );
+                const { maxIntegratorFeeBps } = await tokenRouter.fetchCustodian();
+                expect(maxIntegratorFeeBps).equals(maxFeeBps);
+
+                const { roles } = await tokenRouter.fetchRoleHolder(relayer.publicKey);
+                expect(roles).has.length(0);
             });
+        });
 
-            it("Cannot Prepare Market Order with Both Sender and Program Transfer Authority", async function () {
-                const preparedOrder = Keypair.generate();
+        describe("Owner Set", async function () {
+            const ownerSet = tokenRouter.ownerSetAddress();
 
-                const amountIn = 69n;
-                const minAmountOut = 0n;
-                const targetChain = foreignChain;
-                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-                const redeemerMessage = Buffer.from("All your base are belong to us");
-                const [approveIx, ix] = await tokenRouter.prepareMarketOrderIx(
+            it("Cannot Initialize Owner Set as Non-Owner", async function () {
+                const ix = await tokenRouter.initializeOwnerSetIx(
                     {
-                        payer: payer.publicKey,
-                        preparedOrder: preparedOrder.publicKey,
-                        senderToken: payerToken,
-                        sender: payer.publicKey,
+                        owner: ownerAssistant.publicKey,
                     },
+                    { threshold: 1, members: [ownerAssistant.publicKey] },
+                );
+
+                await expectIxErr(connection, [ix], [ownerAssistant], "Error Code: OwnerOnly");
+            });
+
+            it("Cannot Initialize Owner Set with Threshold Above Member Count", async function () {
+                const ix = await tokenRouter.initializeOwnerSetIx(
                     {
-                        useTransferAuthority: true,
-                        amountIn,
-                        minAmountOut,
-                        targetChain,
-                        redeemer,
-                        redeemerMessage,
+                        owner: owner.publicKey,
                     },
+                    { threshold: 2, members: [owner.publicKey] },
                 );
-                expect(approveIx).is.not.null;
+
+                await expectIxErr(connection, [ix], [owner], "Error Code: InvalidOwnerSet");
+            });
+
+            it("Initialize Owner Set as Owner", async function () {
+                const members = [owner.publicKey, ownerAssistant.publicKey];
+                const ix = await tokenRouter.initializeOwnerSetIx(
+                    {
+                        owner: owner.publicKey,
+                    },
+                    { threshold: 2, members },
+                );
+
+                await expectIxOk(connection, [ix], [owner]);
+
+                const ownerSetData = await tokenRouter.fetchOwnerSet();
+                expect(ownerSetData.threshold).equals(2);
+                expect(ownerSetData.members).to.eql(members);
+                expect(ownerSetData.nextTransactionId.toNumber()).equals(0);
+            });
+
+            it("Submit Ownership Transfer Request to Owner Set", async function () {
+                const ix = await tokenRouter.submitOwnershipTransferIx({
+                    owner: owner.publicKey,
+                    newOwner: ownerSet,
+                });
+
+                await expectIxOk(connection, [ix], [owner]);
+            });
+
+            it("Cannot Propose Owner Set Transaction as Non-Member", async function () {
+                const { args } = await proposedInstruction(
+                    await tokenRouter.confirmOwnershipTransferIx({ pendingOwner: ownerSet }),
+                );
+                const ix = await tokenRouter.proposeOwnerSetTransactionIx(
+                    {
+                        member: relayer.publicKey,
+                    },
+                    args,
+                );
+
+                await expectIxErr(connection, [ix], [relayer], "Error Code: OwnerSetMemberOnly");
+            });
+
+            it("Confirm Ownership Transfer Request via Owner Set", async function () {
+                const { transaction, args } = await proposedInstruction(
+                    await tokenRouter.confirmOwnershipTransferIx({ pendingOwner: ownerSet }),
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await tokenRouter.proposeOwnerSetTransactionIx(
+                            {
+                                member: owner.publicKey,
+                            },
+                            args,
+                        ),
+                    ],
+                    [owner],
+                );
+
+                // Only the proposer has approved, so the threshold is not met yet.
+                {
+                    const ix = await tokenRouter.executeOwnerSetTransactionIx({ transaction });
+                    await expectIxErr(
+                        connection,
+                        [ix],
+                        [payer],
+                        "Error Code: OwnerSetThresholdNotMet",
+                    );
+                }
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await tokenRouter.approveOwnerSetTransactionIx({
+                            member: ownerAssistant.publicKey,
+                            transaction,
+                        }),
+                    ],
+                    [ownerAssistant],
+                );
+
+                const ix = await tokenRouter.executeOwnerSetTransactionIx({ transaction });
+                await expectIxOk(connection, [ix], [payer]);
+
+                const { owner: actualOwner, pendingOwner } = await tokenRouter.fetchCustodian();
+                expect(actualOwner).to.eql(ownerSet);
+                expect(pendingOwner).is.null;
+
+                // The executed transaction is closed.
+                const accInfo = await connection.getAccountInfo(transaction);
+                expect(accInfo).is.null;
+            });
+
+            it("Return Ownership from Owner Set", async function () {
+                const { transaction, args } = await proposedInstruction(
+                    await tokenRouter.submitOwnershipTransferIx({
+                        owner: ownerSet,
+                        newOwner: owner.publicKey,
+                    }),
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await tokenRouter.proposeOwnerSetTransactionIx(
+                            {
+                                member: ownerAssistant.publicKey,
+                            },
+                            args,
+                        ),
+                        await tokenRouter.approveOwnerSetTransactionIx({
+                            member: owner.publicKey,
+                            transaction,
+                        }),
+                    ],
+                    [ownerAssistant, owner],
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await tokenRouter.executeOwnerSetTransactionIx({ transaction }),
+                        await tokenRouter.confirmOwnershipTransferIx({
+                            pendingOwner: owner.publicKey,
+                        }),
+                    ],
+                    [payer, owner],
+                );
+
+                const { owner: actualOwner } = await tokenRouter.fetchCustodian();
+                expect(actualOwner).to.eql(owner.publicKey);
+            });
+
+            it("Update Owner Set Drops Approvals from Former Members", async function () {
+                const { transaction, args } = await proposedInstruction(
+                    await tokenRouter.confirmOwnershipTransferIx({ pendingOwner: ownerSet }),
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await tokenRouter.proposeOwnerSetTransactionIx(
+                            {
+                                member: ownerAssistant.publicKey,
+                            },
+                            args,
+                        ),
+                        await tokenRouter.approveOwnerSetTransactionIx({
+                            member: owner.publicKey,
+                            transaction,
+                        }),
+                    ],
+                    [ownerAssistant, owner],
+                );
+
+                // Replace the owner assistant with the relayer.
+                const members = [owner.publicKey, relayer.publicKey];
+                await expectIxOk(
+                    connection,
+                    [
+                        await tokenRouter.updateOwnerSetIx(
+                            { owner: owner.publicKey, pendingTransactions: [transaction] },
+                            { threshold: 2, members },
+                        ),
+                    ],
+                    [owner],
+                );
+
+                const { approvals } = await tokenRouter.fetchOwnerSetTransaction({
+                    address: transaction,
+                });
+                expect(approvals).to.eql([owner.publicKey]);
 
                 await expectIxErr(
                     connection,
-                    [approveIx!, ix],
-                    [payer, preparedOrder],
-                    "Error Code: EitherSenderOrProgramTransferAuthority",
+                    [await tokenRouter.executeOwnerSetTransactionIx({ transaction })],
+                    [payer],
+                    "Error Code: OwnerSetThresholdNotMet",
+                );
+
+                // Clean up.
+                await expectIxOk(
+                    connection,
+                    [
+                        await tokenRouter.cancelOwnerSetTransactionIx({
+                            proposedBy: ownerAssistant.publicKey,
+                            transaction,
+                        }),
+                    ],
+                    [ownerAssistant],
                 );
             });
 
-            it("Cannot Prepare Market Order without Sender or Program Transfer Authority", async function () {
+            async function proposedInstruction(ix: TransactionInstruction) {
+                const { nextTransactionId } = await tokenRouter.fetchOwnerSet();
+                return {
+                    transaction: tokenRouter.ownerSetTransactionAddress(nextTransactionId),
+                    args: {
+                        accounts: ix.keys.map(({ pubkey, isSigner, isWritable }) => ({
+                            pubkey,
+                            isSigner,
+                            isWritable,
+                        })),
+                        data: ix.data,
+                    },
+                };
+            }
+        });
+    });
+
+    describe("Business Logic", function () {
+        let testCctpNonce = 2n ** 64n - 1n;
+
+        // Hack to prevent math overflow error when invoking CCTP programs.
+        testCctpNonce -= 20n * 6400n;
+
+        let wormholeSequence = 2000n;
+
+        describe("Preparing Order", function () {
+            const payerToken = splToken.getAssociatedTokenAddressSync(
+                USDC_MINT_ADDRESS,
+                payer.publicKey,
+            );
+
+            const localVariables = new Map<string, any>();
+
+            it("Cannot Prepare Market Order with Large Redeemer Payload", async function () {
                 const preparedOrder = Keypair.generate();
 
-                const amountIn = 69n;
+                const amountIn = 5000n;
                 const minAmountOut = 0n;
                 const targetChain = foreignChain;
                 const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-                const redeemerMessage = Buffer.from("All your base are belong to us");
-                const [, ix] = await tokenRouter.prepareMarketOrderIx(
+                const redeemerMessage = Buffer.alloc(501, "deadbeef", "hex");
+                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
                     {
                         payer: payer.publicKey,
                         preparedOrder: preparedOrder.publicKey,
                         senderToken: payerToken,
-                        programTransferAuthority: null,
-                        sender: null,
                     },
                     {
                         amountIn,
@@ -634,16 +889,16 @@ describe("Token Router", function () {
 
                 await expectIxErr(
                     connection,
-                    [ix],
+                    [approveIx!, prepareIx],
                     [payer, preparedOrder],
-                    "Error Code: EitherSenderOrProgramTransferAuthority",
+                    "Error Code: RedeemerMessageTooLarge",
                 );
             });
 
-            it("Prepare Market Order with Program Transfer Authority Specifying Some Min Amount Out", async function () {
+            it("Cannot Prepare Market Order with Insufficient Amount", async function () {
                 const preparedOrder = Keypair.generate();
 
-                const amountIn = 69n;
+                const amountIn = 0n;
                 const minAmountOut = 0n;
                 const targetChain = foreignChain;
                 const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
@@ -663,50 +918,22 @@ describe("Token Router", function () {
                     },
                 );
 
-                const { amount: balanceBefore } = await splToken.getAccount(connection, payerToken);
-
-                await expectIxOk(connection, [approveIx!, prepareIx], [payer, preparedOrder]);
-
-                const { amount: balanceAfter } = await splToken.getAccount(connection, payerToken);
-                expect(balanceAfter).equals(balanceBefore - amountIn);
-
-                const preparedOrderData = await tokenRouter.fetchPreparedOrder(
-                    preparedOrder.publicKey,
-                );
-                const {
-                    info: { preparedCustodyTokenBump },
-                } = preparedOrderData;
-                expect(preparedOrderData).to.eql(
-                    new PreparedOrder(
-                        {
-                            orderSender: payer.publicKey,
-                            preparedBy: payer.publicKey,
-                            orderType: {
-                                market: {
-                                    minAmountOut: uint64ToBN(minAmountOut),
-                                },
-                            },
-                            srcToken: payerToken,
-                            refundToken: payerToken,
-                            targetChain,
-                            redeemer,
-                            preparedCustodyTokenBump,
-                        },
-                        redeemerMessage,
-                    ),
-                );
-
-                const { amount: preparedCustodyTokenBalance } = await splToken.getAccount(
+                await expectIxErr(
                     connection,
-                    tokenRouter.preparedCustodyTokenAddress(preparedOrder.publicKey),
+                    [approveIx!, prepareIx],
+                    [payer, preparedOrder],
+                    "Error Code: InsufficientAmount",
                 );
-                expect(preparedCustodyTokenBalance).equals(amountIn);
             });
 
-            it("Prepare Market Order with Transfer Authority without Specifying Min Amount Out", async function () {
+            it("Cannot Prepare Market Order with Invalid Redeemer", async function () {
                 const preparedOrder = Keypair.generate();
 
                 const amountIn = 69n;
+                const minAmountOut = 0n;
+                const targetChain = foreignChain;
+                const redeemer = Array.from(Buffer.alloc(32, 0, "hex"));
+                const redeemerMessage = Buffer.from("All your base are belong to us");
                 const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
                     {
                         payer: payer.publicKey,
@@ -715,54 +942,307 @@ describe("Token Router", function () {
                     },
                     {
                         amountIn,
-                        minAmountOut: null,
-                        targetChain: foreignChain,
-                        redeemer: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
-                        redeemerMessage: Buffer.from("All your base are belong to us"),
+                        minAmountOut,
+                        targetChain,
+                        redeemer,
+                        redeemerMessage,
                     },
                 );
-                expect(approveIx).is.not.null;
-
-                const { amount: balanceBefore } = await splToken.getAccount(connection, payerToken);
-
-                await expectIxOk(connection, [approveIx!, prepareIx], [payer, preparedOrder]);
-
-                const { amount: balanceAfter } = await splToken.getAccount(connection, payerToken);
-                expect(balanceAfter).equals(balanceBefore - amountIn);
 
-                const { amount: preparedCustodyTokenBalance } = await splToken.getAccount(
+                await expectIxErr(
                     connection,
-                    tokenRouter.preparedCustodyTokenAddress(preparedOrder.publicKey),
+                    [approveIx!, prepareIx],
+                    [payer, preparedOrder],
+                    "Error Code: InvalidRedeemer",
                 );
-                expect(preparedCustodyTokenBalance).equals(amountIn);
-
-                // We've checked other fields in a previous test. Just make sure the min amount out
-                // is null.
-                const { info } = await tokenRouter.fetchPreparedOrder(preparedOrder.publicKey);
-                expect(info.orderType).to.eql({ market: { minAmountOut: null } });
-
-                // Save for later.
-                localVariables.set("preparedOrder", preparedOrder.publicKey);
-                localVariables.set("amountIn", amountIn);
             });
 
-            it("Cannot Close Prepared Order without Original Payer", async function () {
-                const preparedOrder = localVariables.get("preparedOrder") as PublicKey;
+            it("Cannot Prepare Market Order with Min Amount Too High", async function () {
+                const preparedOrder = Keypair.generate();
 
-                const ix = await tokenRouter.closePreparedOrderIx({
-                    preparedOrder,
-                    preparedBy: ownerAssistant.publicKey,
-                });
+                const amountIn = 1n;
+                const minAmountOut = 2n;
+                const targetChain = foreignChain;
+                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+                const redeemerMessage = Buffer.from("All your base are belong to us");
+                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
+                    {
+                        payer: payer.publicKey,
+                        preparedOrder: preparedOrder.publicKey,
+                        senderToken: payerToken,
+                    },
+                    {
+                        amountIn,
+                        minAmountOut,
+                        targetChain,
+                        redeemer,
+                        redeemerMessage,
+                    },
+                );
 
                 await expectIxErr(
                     connection,
-                    [ix],
-                    [ownerAssistant, payer],
-                    "prepared_by. Error Code: ConstraintAddress",
+                    [approveIx!, prepareIx],
+                    [payer, preparedOrder],
+                    "Error Code: MinAmountOutTooHigh",
                 );
             });
 
-            it("Cannot Close Prepared Order without Order Sender", async function () {
+            it("Cannot Prepare Market Order without Delegating Authority to Program Transfer Authority", async function () {
+                const preparedOrder = Keypair.generate();
+
+                const amountIn = 69n;
+                const minAmountOut = 0n;
+                const targetChain = foreignChain;
+                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+                const redeemerMessage = Buffer.from("All your base are belong to us");
+                const [, ix] = await tokenRouter.prepareMarketOrderIx(
+                    {
+                        payer: payer.publicKey,
+                        preparedOrder: preparedOrder.publicKey,
+                        senderToken: payerToken,
+                    },
+                    {
+                        amountIn,
+                        minAmountOut,
+                        targetChain,
+                        redeemer,
+                        redeemerMessage,
+                    },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer, preparedOrder],
+                    "Error Code: DelegatedAmountMismatch",
+                );
+            });
+
+            it("Cannot Prepare Market Order Delegating Too Much to Program Transfer Authority", async function () {
+                const preparedOrder = Keypair.generate();
+
+                const amountIn = 69n;
+                const minAmountOut = 0n;
+                const targetChain = foreignChain;
+                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+                const redeemerMessage = Buffer.from("All your base are belong to us");
+                const [approveIx, ix] = await tokenRouter.prepareMarketOrderIx(
+                    {
+                        payer: payer.publicKey,
+                        preparedOrder: preparedOrder.publicKey,
+                        senderToken: payerToken,
+                    },
+                    {
+                        amountIn,
+                        minAmountOut,
+                        targetChain,
+                        redeemer,
+                        redeemerMessage,
+                    },
+                );
+
+                // Approve for more.
+                approveIx!.data.writeBigUInt64LE(amountIn + 1n, 1);
+
+                await expectIxErr(
+                    connection,
+                    [approveIx!, ix],
+                    [payer, preparedOrder],
+                    "Error Code: DelegatedAmountMismatch",
+                );
+            });
+
+            it("Cannot Prepare Market Order with Both Sender and Program Transfer Authority", async function () {
+                const preparedOrder = Keypair.generate();
+
+                const amountIn = 69n;
+                const minAmountOut = 0n;
+                const targetChain = foreignChain;
+                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+                const redeemerMessage = Buffer.from("All your base are belong to us");
+                const [approveIx, ix] = await tokenRouter.prepareMarketOrderIx(
+                    {
+                        payer: payer.publicKey,
+                        preparedOrder: preparedOrder.publicKey,
+                        senderToken: payerToken,
+                        sender: payer.publicKey,
+                    },
+                    {
+                        useTransferAuthority: true,
+                        amountIn,
+                        minAmountOut,
+                        targetChain,
+                        redeemer,
+                        redeemerMessage,
+                    },
+                );
+                expect(approveIx).is.not.null;
+
+                await expectIxErr(
+                    connection,
+                    [approveIx!, ix],
+                    [payer, preparedOrder],
+                    "Error Code: EitherSenderOrProgramTransferAuthority",
+                );
+            });
+
+            it("Cannot Prepare Market Order without Sender or Program Transfer Authority", async function () {
+                const preparedOrder = Keypair.generate();
+
+                const amountIn = 69n;
+                const minAmountOut = 0n;
+                const targetChain = foreignChain;
+                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+                const redeemerMessage = Buffer.from("All your base are belong to us");
+                const [, ix] = await tokenRouter.prepareMarketOrderIx(
+                    {
+                        payer: payer.publicKey,
+                        preparedOrder: preparedOrder.publicKey,
+                        senderToken: payerToken,
+                        programTransferAuthority: null,
+                        sender: null,
+                    },
+                    {
+                        amountIn,
+                        minAmountOut,
+                        targetChain,
+                        redeemer,
+                        redeemerMessage,
+                    },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer, preparedOrder],
+                    "Error Code: EitherSenderOrProgramTransferAuthority",
+                );
+            });
+
+            it("Prepare Market Order with Program Transfer Authority Specifying Some Min Amount Out", async function () {
+                const preparedOrder = Keypair.generate();
+
+                const amountIn = 69n;
+                const minAmountOut = 0n;
+                const targetChain = foreignChain;
+                const redeemer = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+                const redeemerMessage = Buffer.from("All your base are belong to us");
+                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
+                    {
+                        payer: payer.publicKey,
+                        preparedOrder: preparedOrder.publicKey,
+                        senderToken: payerToken,
+                    },
+                    {
+                        amountIn,
+                        minAmountOut,
+                        targetChain,
+                        redeemer,
+                        redeemerMessage,
+                    },
+                );
+
+                const { amount: balanceBefore } = await splToken.getAccount(connection, payerToken);
+
+                await expectIxOk(connection, [approveIx!, prepareIx], [payer, preparedOrder]);
+
+                const { amount: balanceAfter } = await splToken.getAccount(connection, payerToken);
+                expect(balanceAfter).equals(balanceBefore - amountIn);
+
+                const preparedOrderData = await tokenRouter.fetchPreparedOrder(
+                    preparedOrder.publicKey,
+                );
+                const {
+                    info: { preparedCustodyTokenBump },
+                } = preparedOrderData;
+                expect(preparedOrderData).to.eql(
+                    new PreparedOrder(
+                        {
+                            orderSender: payer.publicKey,
+                            preparedBy: payer.publicKey,
+                            orderType: {
+                                market: {
+                                    minAmountOut: uint64ToBN(minAmountOut),
+                                },
+                            },
+                            srcToken: payerToken,
+                            refundToken: payerToken,
+                            targetChain,
+                            redeemer,
+                            preparedCustodyTokenBump,
+                        },
+                        redeemerMessage,
+                    ),
+                );
+
+                const { amount: preparedCustodyTokenBalance } = await splToken.getAccount(
+                    connection,
+                    tokenRouter.preparedCustodyTokenAddress(preparedOrder.publicKey),
+                );
+                expect(preparedCustodyTokenBalance).equals(amountIn);
+            });
+
+            it("Prepare Market Order with Transfer Authority without Specifying Min Amount Out", async function () {
+                const preparedOrder = Keypair.generate();
+
+                const amountIn = 69n;
+                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
+                    {
+                        payer: payer.publicKey,
+                        preparedOrder: preparedOrder.publicKey,
+                        senderToken: payerToken,
+                    },
+                    {
+                        amountIn,
+                        minAmountOut: null,
+                        targetChain: foreignChain,
+                        redeemer: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+                        redeemerMessage: Buffer.from("All your base are belong to us"),
+                    },
+                );
+                expect(approveIx).is.not.null;
+
+                const { amount: balanceBefore } = await splToken.getAccount(connection, payerToken);
+
+                await expectIxOk(connection, [approveIx!, prepareIx], [payer, preparedOrder]);
+
+                const { amount: balanceAfter } = await splToken.getAccount(connection, payerToken);
+                expect(balanceAfter).equals(balanceBefore - amountIn);
+
+                const { amount: preparedCustodyTokenBalance } = await splToken.getAccount(
+                    connection,
+                    tokenRouter.preparedCustodyTokenAddress(preparedOrder.publicKey),
+                );
+                expect(preparedCustodyTokenBalance).equals(amountIn);
+
+                // We've checked other fields in a previous test. Just make sure the min amount out
+                // is null.
+                const { info } = await tokenRouter.fetchPreparedOrder(preparedOrder.publicKey);
+                expect(info.orderType).to.eql({ market: { minAmountOut: null } });
+
+                // Save for later.
+                localVariables.set("preparedOrder", preparedOrder.publicKey);
+                localVariables.set("amountIn", amountIn);
+            });
+
+            it("Cannot Close Prepared Order without Original Payer", async function () {
+                const preparedOrder = localVariables.get("preparedOrder") as PublicKey;
+
+                const ix = await tokenRouter.closePreparedOrderIx({
+                    preparedOrder,
+                    preparedBy: ownerAssistant.publicKey,
+                });
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [ownerAssistant, payer],
+                    "prepared_by. Error Code: ConstraintAddress",
+                );
+            });
+
+            it("Cannot Close Prepared Order without Order Sender", async function () {
                 const preparedOrder = localVariables.get("preparedOrder") as PublicKey;
 
                 const ix = await tokenRouter.closePreparedOrderIx({
@@ -862,7 +1342,277 @@ describe("Token Router", function () {
                 const { info } = await tokenRouter.fetchPreparedOrder(preparedOrder.publicKey);
                 expect(info.orderType).to.eql({ market: { minAmountOut: null } });
             });
-        });
+
+            it("Cannot Prepare Market Order V2 with Integrator Fee Too High", async function () {
+                const [, prepareIx] = await tokenRouter.prepareMarketOrderV2Ix(
+                    {
+                        payer: payer.publicKey,
+                        senderToken: payerToken,
+                        sender: payer.publicKey,
+                        integratorFeeToken: payerToken,
+                    },
+                    {
+                        useTransferAuthority: false,
+                        amountIn: 1_000_000n,
+                        minAmountOut: null,
+                        targetChain: foreignChain,
+                        redeemer: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+                        redeemerMessage: Buffer.from("All your base are belong to us"),
+                        expiry: null,
+                        integratorFee: { bps: 10_001 },
+                    },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [prepareIx],
+                    [payer],
+                    "Error Code: IntegratorFeeTooHigh",
+                );
+            });
+
+            it("Cannot Prepare Market Order V2 without Integrator Fee Token", async function () {
+                const [, prepareIx] = await tokenRouter.prepareMarketOrderV2Ix(
+                    {
+                        payer: payer.publicKey,
+                        senderToken: payerToken,
+                        sender: payer.publicKey,
+                    },
+                    {
+                        useTransferAuthority: false,
+                        amountIn: 1_000_000n,
+                        minAmountOut: null,
+                        targetChain: foreignChain,
+                        redeemer: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+                        redeemerMessage: Buffer.from("All your base are belong to us"),
+                        expiry: null,
+                        integratorFee: { amount: 69n },
+                    },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [prepareIx],
+                    [payer],
+                    "Error Code: IntegratorFeeTokenRequired",
+                );
+            });
+
+            it("Cannot Prepare Market Order V2 with Past Expiry", async function () {
+                const expiry = await getBlockTime(connection).then((time) => BigInt(time - 1));
+
+                const [, prepareIx] = await tokenRouter.prepareMarketOrderV2Ix(
+                    {
+                        payer: payer.publicKey,
+                        senderToken: payerToken,
+                        sender: payer.publicKey,
+                    },
+                    {
+                        useTransferAuthority: false,
+                        amountIn: 1_000_000n,
+                        minAmountOut: null,
+                        targetChain: foreignChain,
+                        redeemer: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+                        redeemerMessage: Buffer.from("All your base are belong to us"),
+                        expiry,
+                        integratorFee: null,
+                    },
+                );
+
+                await expectIxErr(connection, [prepareIx], [payer], "Error Code: OrderExpired");
+            });
+
+            it("Prepare Market Order V2 with Integrator Fee", async function () {
+                const integratorFeeToken = await splToken
+                    .getOrCreateAssociatedTokenAccount(
+                        connection,
+                        payer,
+                        USDC_MINT_ADDRESS,
+                        relayer.publicKey,
+                    )
+                    .then((token) => token.address);
+
+                const nextNonce = await tokenRouter
+                    .fetchSenderState(payer.publicKey)
+                    .then((state) => state.nextNonce.toNumber(), (_) => 0);
+                const preparedOrder = await tokenRouter.nextPreparedOrderAddress(payer.publicKey);
+
+                const amountIn = 1_000_000n;
+                const expiry = await getBlockTime(connection).then((time) => BigInt(time + 5));
+                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderV2Ix(
+                    {
+                        payer: payer.publicKey,
+                        senderToken: payerToken,
+                        sender: payer.publicKey,
+                        integratorFeeToken,
+                    },
+                    {
+                        useTransferAuthority: false,
+                        amountIn,
+                        minAmountOut: null,
+                        targetChain: foreignChain,
+                        redeemer: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+                        redeemerMessage: Buffer.from("All your base are belong to us"),
+                        expiry,
+                        integratorFee: { bps: 10_000 },
+                    },
+                );
+                expect(approveIx).is.null;
+
+                const { amount: balanceBefore } = await splToken.getAccount(connection, payerToken);
+
+                await expectIxOk(connection, [prepareIx], [payer]);
+
+                const { amount: balanceAfter } = await splToken.getAccount(connection, payerToken);
+                expect(balanceAfter).equals(balanceBefore - amountIn);
+
+                // One percent of the amount in is paid to the integrator.
+                const integratorFee = 10_000n;
+                const { amount: integratorFeeBalance } = await splToken.getAccount(
+                    connection,
+                    integratorFeeToken,
+                );
+                expect(integratorFeeBalance).equals(integratorFee);
+
+                const { amount: preparedCustodyTokenBalance } = await splToken.getAccount(
+                    connection,
+                    tokenRouter.preparedCustodyTokenAddress(preparedOrder),
+                );
+                expect(preparedCustodyTokenBalance).equals(amountIn - integratorFee);
+
+                const { info } = await tokenRouter.fetchPreparedOrder(preparedOrder);
+                expect(info.orderType).to.eql({
+                    expiringMarket: {
+                        minAmountOut: null,
+                        expiry: uint64ToBN(expiry),
+                    },
+                });
+
+                const { nextNonce: nextNonceAfter } = await tokenRouter.fetchSenderState(
+                    payer.publicKey,
+                );
+                expect(nextNonceAfter.toNumber()).equals(nextNonce + 1);
+
+                // Save for later.
+                localVariables.set("preparedOrder", preparedOrder);
+                localVariables.set("expiry", expiry);
+            });
+
+            it("Cannot Prepare Market Order V2 with Used Nonce", async function () {
+                const { nextNonce } = await tokenRouter.fetchSenderState(payer.publicKey);
+                const preparedOrder = tokenRouter.preparedOrderAddress(payer.publicKey, nextNonce);
+
+                const args = {
+                    useTransferAuthority: false,
+                    amountIn: 1_000_000n,
+                    minAmountOut: null,
+                    targetChain: foreignChain,
+                    redeemer: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+                    redeemerMessage: Buffer.from("All your base are belong to us"),
+                    expiry: null,
+                    integratorFee: null,
+                };
+                const [, prepareIx] = await tokenRouter.prepareMarketOrderV2Ix(
+                    {
+                        payer: payer.publicKey,
+                        senderToken: payerToken,
+                        sender: payer.publicKey,
+                        preparedOrder,
+                    },
+                    args,
+                );
+
+                // Preparing two orders with the same nonce in one transaction fails on the second
+                // one because the first one increments the nonce.
+                await expectIxErr(
+                    connection,
+                    [prepareIx, prepareIx],
+                    [payer],
+                    "prepared_order. Error Code: ConstraintSeeds",
+                );
+
+                await expectIxOk(connection, [prepareIx], [payer]);
+
+                const { nextNonce: nextNonceAfter } = await tokenRouter.fetchSenderState(
+                    payer.publicKey,
+                );
+                expect(nextNonceAfter.toString()).equals(nextNonce.addn(1).toString());
+
+                // Closing the prepared order does not free its nonce.
+                const closeIx = await tokenRouter.closePreparedOrderIx({ preparedOrder });
+                await expectIxOk(connection, [closeIx], [payer]);
+                expect(await connection.getAccountInfo(preparedOrder)).is.null;
+
+                await expectIxErr(
+                    connection,
+                    [prepareIx],
+                    [payer],
+                    "prepared_order. Error Code: ConstraintSeeds",
+                );
+
+                // The next order is prepared at the next nonce.
+                const [, nextPrepareIx] = await tokenRouter.prepareMarketOrderV2Ix(
+                    {
+                        payer: payer.publicKey,
+                        senderToken: payerToken,
+                        sender: payer.publicKey,
+                    },
+                    args,
+                );
+                await expectIxOk(connection, [nextPrepareIx], [payer]);
+
+                const nextPreparedOrder = tokenRouter.preparedOrderAddress(
+                    payer.publicKey,
+                    nextNonceAfter,
+                );
+                const { info } = await tokenRouter.fetchPreparedOrder(nextPreparedOrder);
+                expect(info.orderSender).to.eql(payer.publicKey);
+
+                await expectIxOk(
+                    connection,
+                    [await tokenRouter.closePreparedOrderIx({ preparedOrder: nextPreparedOrder })],
+                    [payer],
+                );
+            });
+
+            it("Cannot Refund Unexpired Prepared Order", async function () {
+                const preparedOrder = localVariables.get("preparedOrder") as PublicKey;
+
+                const ix = await tokenRouter.refundExpiredPreparedOrderIx({ preparedOrder });
+
+                await expectIxErr(connection, [ix], [payer], "Error Code: OrderNotExpired");
+            });
+
+            it("Refund Expired Prepared Order", async function () {
+                const preparedOrder = localVariables.get("preparedOrder") as PublicKey;
+                expect(localVariables.delete("preparedOrder")).is.true;
+                const expiry = localVariables.get("expiry") as bigint;
+                expect(localVariables.delete("expiry")).is.true;
+
+                await waitUntilTimestamp(connection, Number(expiry) + 1);
+
+                const preparedCustodyToken = tokenRouter.preparedCustodyTokenAddress(preparedOrder);
+                const { amount: custodyBalance } = await splToken.getAccount(
+                    connection,
+                    preparedCustodyToken,
+                );
+
+                // Anyone can refund an expired order.
+                const ix = await tokenRouter.refundExpiredPreparedOrderIx({ preparedOrder });
+
+                const { amount: balanceBefore } = await splToken.getAccount(connection, payerToken);
+
+                await expectIxOk(connection, [ix], [ownerAssistant]);
+
+                const { amount: balanceAfter } = await splToken.getAccount(connection, payerToken);
+                expect(balanceAfter).equals(balanceBefore + custodyBalance);
+
+                for (const key of [preparedOrder, preparedCustodyToken]) {
+                    const accInfo = await connection.getAccountInfo(key);
+                    expect(accInfo).is.null;
+                }
+            });
+        });
 
         describe("Place Market Order (CCTP)", function () {
             const payerToken = splToken.getAssociatedTokenAddressSync(
@@ -1039,7 +1789,7 @@ describe("Token Router", function () {
             it("Pause", async function () {
                 const ix = await tokenRouter.setPauseIx(
                     {
-                        ownerOrAssistant: owner.publicKey,
+                        authority: owner.publicKey,
                     },
                     true, // paused
                 );
@@ -1072,7 +1822,7 @@ describe("Token Router", function () {
             it("Unpause", async function () {
                 const ix = await tokenRouter.setPauseIx(
                     {
-                        ownerOrAssistant: ownerAssistant.publicKey,
+                        authority: ownerAssistant.publicKey,
                     },
                     false, // paused
                 );
@@ -1135,91 +1885,894 @@ describe("Token Router", function () {
                 });
             });
 
-            async function prepareOrder(amountIn: bigint) {
+            it("Prepare Scheduled Order", async function () {
                 const preparedOrder = Keypair.generate();
-                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
+
+                // Each slice places 500 minus the keeper reward. The last slice also places the
+                // remainder.
+                const amountIn = 1_001n;
+                const [approveIx, prepareIx] = await tokenRouter.prepareScheduledOrderIx(
                     {
                         payer: payer.publicKey,
                         preparedOrder: preparedOrder.publicKey,
                         senderToken: payerToken,
-                        refundToken: payerToken,
+                        sender: payer.publicKey,
                     },
                     {
+                        useTransferAuthority: false,
                         amountIn,
-                        minAmountOut: null,
+                        numSlices: 2,
+                        sliceInterval: 4n,
+                        keeperReward: 10n,
                         targetChain: foreignChain,
                         redeemer,
                         redeemerMessage,
                     },
                 );
+                expect(approveIx).is.null;
 
-                return { preparedOrder, approveIx, prepareIx };
-            }
+                await expectIxOk(connection, [prepareIx], [payer, preparedOrder]);
 
-            async function checkAfterEffects(args: {
-                preparedOrder: PublicKey;
-                amountIn: bigint;
-                burnSource: PublicKey;
-            }) {
-                const { preparedOrder, amountIn, burnSource } = args;
+                const { info } = await tokenRouter.fetchPreparedOrder(preparedOrder.publicKey);
+                const { scheduled } = info.orderType;
+                expect(scheduled).is.not.undefined;
+                expect(scheduled!.remainingSlices).equals(2);
+                expect(scheduled!.sliceAmount.toString()).equals("490");
+                expect(scheduled!.keeperReward.toString()).equals("10");
+                expect(scheduled!.sliceInterval.toString()).equals("4");
 
-                const message = tokenRouter.coreMessageAddress(preparedOrder);
-                const { payload, emitterAddress } = await connection
-                    .getAccountInfo(new PublicKey(message))
-                    .then((info) => deserializePostMessage(info?.data!));
+                // Save for later.
+                localVariables.set("preparedOrder", preparedOrder.publicKey);
+            });
 
-                expect(emitterAddress).to.eql(tokenRouter.custodianAddress().toBuffer());
+            it("Place First Scheduled Order Slice", async function () {
+                const preparedOrder = localVariables.get("preparedOrder") as PublicKey;
 
-                const { sourceCctpDomain, cctpNonce } = await (async () => {
-                    const transmitter = tokenRouter.messageTransmitterProgram();
-                    const config = transmitter.messageTransmitterConfigAddress();
-                    const { localDomain, nextAvailableNonce } =
-                        await transmitter.fetchMessageTransmitterConfig(config);
-                    return {
-                        sourceCctpDomain: localDomain,
-                        cctpNonce: BigInt(nextAvailableNonce.subn(1).toString()),
-                    };
-                })();
+                const keeperToken = await splToken
+                    .getOrCreateAssociatedTokenAccount(
+                        connection,
+                        payer,
+                        USDC_MINT_ADDRESS,
+                        relayer.publicKey,
+                    )
+                    .then((token) => token.address);
+                const { amount: keeperBalanceBefore } = await splToken.getAccount(
+                    connection,
+                    keeperToken,
+                );
 
-                const {
-                    protocol: { cctp: cctpProtocol },
-                } = await tokenRouter.matchingEngineProgram().fetchRouterEndpointInfo(foreignChain);
-                expect(cctpProtocol).is.not.null;
-                const { domain: destinationCctpDomain } = cctpProtocol!;
+                const ix = await tokenRouter.placeScheduledOrderSliceCctpIx({
+                    payer: payer.publicKey,
+                    preparedOrder,
+                    keeperToken,
+                });
 
-                const depositMessage = LiquidityLayerMessage.decode(Buffer.from(payload));
-                expect(depositMessage).to.eql(
-                    new LiquidityLayerMessage({
-                        deposit: new LiquidityLayerDeposit({
-                            tokenAddress: toUniversalAddress(USDC_MINT_ADDRESS.toBuffer()),
-                            amount: amountIn,
-                            sourceCctpDomain,
-                            destinationCctpDomain,
-                            cctpNonce,
-                            burnSource: toUniversalAddress(burnSource.toBuffer()),
-                            mintRecipient: toUniversalAddress(foreignEndpointAddress),
-                            payload: {
-                                id: 1,
-                                sourceChain: toChain(SOLANA_CHAIN_ID),
-                                orderSender: toUniversalAddress(payer.publicKey.toBuffer()),
-                                redeemer: toUniversalAddress(redeemer),
-                                redeemerMessage,
-                            },
-                        }),
-                    }),
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                await expectIxOk(connection, [ix], [payer], {
+                    addressLookupTableAccounts: [lookupTableAccount!],
+                });
+
+                const { amount: keeperBalanceAfter } = await splToken.getAccount(
+                    connection,
+                    keeperToken,
+                );
+                expect(keeperBalanceAfter).equals(keeperBalanceBefore + 10n);
+
+                const { amount: custodyBalance } = await splToken.getAccount(
+                    connection,
+                    tokenRouter.preparedCustodyTokenAddress(preparedOrder),
+                );
+                expect(custodyBalance).equals(501n);
+
+                const { coreMessage } = tokenRouter.scheduledSliceMessageAddresses(
+                    preparedOrder,
+                    2,
+                );
+                expect(await sliceDepositAmount(coreMessage)).equals(490n);
+
+                const { info } = await tokenRouter.fetchPreparedOrder(preparedOrder);
+                expect(info.orderType.scheduled!.remainingSlices).equals(1);
+
+                // Save for later.
+                localVariables.set("keeperToken", keeperToken);
+            });
+
+            it("Cannot Place Scheduled Order Slice before It Is Due", async function () {
+                const preparedOrder = localVariables.get("preparedOrder") as PublicKey;
+                const keeperToken = localVariables.get("keeperToken") as PublicKey;
+
+                const ix = await tokenRouter.placeScheduledOrderSliceCctpIx({
+                    payer: payer.publicKey,
+                    preparedOrder,
+                    keeperToken,
+                });
+
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                await expectIxErr(connection, [ix], [payer], "Error Code: ScheduledSliceNotDue", {
+                    addressLookupTableAccounts: [lookupTableAccount!],
+                });
+            });
+
+            it("Place Last Scheduled Order Slice", async function () {
+                const preparedOrder = localVariables.get("preparedOrder") as PublicKey;
+                expect(localVariables.delete("preparedOrder")).is.true;
+                const keeperToken = localVariables.get("keeperToken") as PublicKey;
+                expect(localVariables.delete("keeperToken")).is.true;
+
+                const { info } = await tokenRouter.fetchPreparedOrder(preparedOrder);
+                await waitUntilSlot(connection, info.orderType.scheduled!.nextSliceSlot.toNumber());
+
+                const { amount: keeperBalanceBefore } = await splToken.getAccount(
+                    connection,
+                    keeperToken,
+                );
+
+                const ix = await tokenRouter.placeScheduledOrderSliceCctpIx({
+                    payer: payer.publicKey,
+                    preparedOrder,
+                    keeperToken,
+                });
+
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                await expectIxOk(connection, [ix], [payer], {
+                    addressLookupTableAccounts: [lookupTableAccount!],
+                });
+
+                const { amount: keeperBalanceAfter } = await splToken.getAccount(
+                    connection,
+                    keeperToken,
+                );
+                expect(keeperBalanceAfter).equals(keeperBalanceBefore + 10n);
+
+                // The last slice places the remainder.
+                const { coreMessage } = tokenRouter.scheduledSliceMessageAddresses(
+                    preparedOrder,
+                    1,
+                );
+                expect(await sliceDepositAmount(coreMessage)).equals(491n);
+
+                for (const key of [
+                    preparedOrder,
+                    tokenRouter.preparedCustodyTokenAddress(preparedOrder),
+                ]) {
+                    const accInfo = await connection.getAccountInfo(key);
+                    expect(accInfo).is.null;
+                }
+            });
+
+            async function sliceDepositAmount(coreMessage: PublicKey) {
+                const { payload } = await connection
+                    .getAccountInfo(coreMessage)
+                    .then((info) => deserializePostMessage(info?.data!));
+                const { deposit } = LiquidityLayerMessage.decode(Buffer.from(payload));
+                expect(deposit).is.not.undefined;
+                expect(deposit!.message.payload).to.eql({
+                    id: 1,
+                    sourceChain: toChain(SOLANA_CHAIN_ID),
+                    orderSender: toUniversalAddress(payer.publicKey.toBuffer()),
+                    redeemer: toUniversalAddress(redeemer),
+                    redeemerMessage,
+                });
+
+                return deposit!.message.amount;
+            }
+
+            async function prepareOrder(amountIn: bigint) {
+                const preparedOrder = Keypair.generate();
+                const [approveIx, prepareIx] = await tokenRouter.prepareMarketOrderIx(
+                    {
+                        payer: payer.publicKey,
+                        preparedOrder: preparedOrder.publicKey,
+                        senderToken: payerToken,
+                        refundToken: payerToken,
+                    },
+                    {
+                        amountIn,
+                        minAmountOut: null,
+                        targetChain: foreignChain,
+                        redeemer,
+                        redeemerMessage,
+                    },
+                );
+
+                return { preparedOrder, approveIx, prepareIx };
+            }
+
+            async function checkAfterEffects(args: {
+                preparedOrder: PublicKey;
+                amountIn: bigint;
+                burnSource: PublicKey;
+            }) {
+                const { preparedOrder, amountIn, burnSource } = args;
+
+                const message = tokenRouter.coreMessageAddress(preparedOrder);
+                const { payload, emitterAddress } = await connection
+                    .getAccountInfo(new PublicKey(message))
+                    .then((info) => deserializePostMessage(info?.data!));
+
+                expect(emitterAddress).to.eql(tokenRouter.custodianAddress().toBuffer());
+
+                const { sourceCctpDomain, cctpNonce } = await (async () => {
+                    const transmitter = tokenRouter.messageTransmitterProgram();
+                    const config = transmitter.messageTransmitterConfigAddress();
+                    const { localDomain, nextAvailableNonce } =
+                        await transmitter.fetchMessageTransmitterConfig(config);
+                    return {
+                        sourceCctpDomain: localDomain,
+                        cctpNonce: BigInt(nextAvailableNonce.subn(1).toString()),
+                    };
+                })();
+
+                const {
+                    protocol: { cctp: cctpProtocol },
+                } = await tokenRouter.matchingEngineProgram().fetchRouterEndpointInfo(foreignChain);
+                expect(cctpProtocol).is.not.null;
+                const { domain: destinationCctpDomain } = cctpProtocol!;
+
+                const depositMessage = LiquidityLayerMessage.decode(Buffer.from(payload));
+                expect(depositMessage).to.eql(
+                    new LiquidityLayerMessage({
+                        deposit: new LiquidityLayerDeposit({
+                            tokenAddress: toUniversalAddress(USDC_MINT_ADDRESS.toBuffer()),
+                            amount: amountIn,
+                            sourceCctpDomain,
+                            destinationCctpDomain,
+                            cctpNonce,
+                            burnSource: toUniversalAddress(burnSource.toBuffer()),
+                            mintRecipient: toUniversalAddress(foreignEndpointAddress),
+                            payload: {
+                                id: 1,
+                                sourceChain: toChain(SOLANA_CHAIN_ID),
+                                orderSender: toUniversalAddress(payer.publicKey.toBuffer()),
+                                redeemer: toUniversalAddress(redeemer),
+                                redeemerMessage,
+                            },
+                        }),
+                    }),
+                );
+
+                for (const key of [
+                    preparedOrder,
+                    tokenRouter.preparedCustodyTokenAddress(preparedOrder),
+                ]) {
+                    const accInfo = await connection.getAccountInfo(key);
+                    expect(accInfo).is.null;
+                }
+            }
+        });
+
+        describe("Redeem Fill (CCTP)", function () {
+            const encodedMintRecipient = Array.from(
+                tokenRouter.cctpMintRecipientAddress().toBuffer(),
+            );
+            const sourceCctpDomain = 0;
+            const amount = 69n;
+            const burnSource = Array.from(Buffer.alloc(32, "beefdead", "hex"));
+            const redeemer = Keypair.generate();
+
+            const localVariables = new Map<string, any>();
+
+            it("Cannot Redeem Fill from Invalid Source Router Chain", async function () {
+                const cctpNonce = testCctpNonce++;
+
+                // Concoct a Circle message.
+                const { destinationCctpDomain, burnMessage, encodedCctpMessage, cctpAttestation } =
+                    await craftCctpTokenBurnMessage(
+                        tokenRouter,
+                        sourceCctpDomain,
+                        cctpNonce,
+                        encodedMintRecipient,
+                        amount,
+                        burnSource,
+                    );
+
+                const message = new LiquidityLayerMessage({
+                    deposit: new LiquidityLayerDeposit({
+                        tokenAddress: toUniversalAddress(burnMessage.burnTokenAddress),
+                        amount,
+                        sourceCctpDomain,
+                        destinationCctpDomain,
+                        cctpNonce,
+                        burnSource: toUniversalAddress(burnSource),
+                        mintRecipient: toUniversalAddress(encodedMintRecipient),
+                        payload: {
+                            id: 1,
+                            sourceChain: toChain(foreignChain),
+                            orderSender: toUniversalAddress(Buffer.alloc(32, "d00d", "hex")),
+                            redeemer: toUniversalAddress(redeemer.publicKey.toBuffer()),
+                            redeemerMessage: Buffer.from("Somebody set up us the bomb"),
+                        },
+                    }),
+                });
+
+                const vaa = await postLiquidityLayerVaa(
+                    connection,
+                    payer,
+                    MOCK_GUARDIANS,
+                    foreignEndpointAddress,
+                    wormholeSequence++,
+                    message,
+                    { sourceChain: "Polygon" },
+                );
+                const ix = await tokenRouter.redeemCctpFillIx(
+                    {
+                        payer: payer.publicKey,
+                        vaa,
+                        sourceRouterEndpoint: tokenRouter
+                            .matchingEngineProgram()
+                            .routerEndpointAddress(foreignChain),
+                    },
+                    {
+                        encodedCctpMessage,
+                        cctpAttestation,
+                    },
+                );
+
+                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
+                    units: 300_000,
+                });
+
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                await expectIxErr(
+                    connection,
+                    [computeIx, ix],
+                    [payer],
+                    "Error Code: InvalidSourceRouter",
+                    {
+                        addressLookupTableAccounts: [lookupTableAccount!],
+                    },
+                );
+            });
+
+            it("Cannot Redeem Fill from Invalid Source Router Address", async function () {
+                const cctpNonce = testCctpNonce++;
+
+                // Concoct a Circle message.
+                const { destinationCctpDomain, burnMessage, encodedCctpMessage, cctpAttestation } =
+                    await craftCctpTokenBurnMessage(
+                        tokenRouter,
+                        sourceCctpDomain,
+                        cctpNonce,
+                        encodedMintRecipient,
+                        amount,
+                        burnSource,
+                    );
+
+                const message = new LiquidityLayerMessage({
+                    deposit: new LiquidityLayerDeposit({
+                        tokenAddress: toUniversalAddress(burnMessage.burnTokenAddress),
+                        amount,
+                        sourceCctpDomain,
+                        destinationCctpDomain,
+                        cctpNonce,
+                        burnSource: toUniversalAddress(burnSource),
+                        mintRecipient: toUniversalAddress(encodedMintRecipient),
+                        payload: {
+                            id: 1,
+                            sourceChain: toChain(foreignChain),
+                            orderSender: toUniversalAddress(Buffer.alloc(32, "d00d", "hex")),
+                            redeemer: toUniversalAddress(redeemer.publicKey.toBuffer()),
+                            redeemerMessage: Buffer.from("Somebody set up us the bomb"),
+                        },
+                    }),
+                });
+
+                const vaa = await postLiquidityLayerVaa(
+                    connection,
+                    payer,
+                    MOCK_GUARDIANS,
+                    new Array(32).fill(0), // emitter address
+                    wormholeSequence++,
+                    message,
+                );
+                const ix = await tokenRouter.redeemCctpFillIx(
+                    {
+                        payer: payer.publicKey,
+                        vaa,
+                    },
+                    {
+                        encodedCctpMessage,
+                        cctpAttestation,
+                    },
+                );
+
+                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
+                    units: 300_000,
+                });
+
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                await expectIxErr(
+                    connection,
+                    [computeIx, ix],
+                    [payer],
+                    "Error Code: InvalidSourceRouter",
+                    {
+                        addressLookupTableAccounts: [lookupTableAccount!],
+                    },
+                );
+            });
+
+            it("Cannot Redeem Fill with Invalid Deposit Message", async function () {
+                const cctpNonce = testCctpNonce++;
+
+                // Concoct a Circle message.
+                const { destinationCctpDomain, burnMessage, encodedCctpMessage, cctpAttestation } =
+                    await craftCctpTokenBurnMessage(
+                        tokenRouter,
+                        sourceCctpDomain,
+                        cctpNonce,
+                        encodedMintRecipient,
+                        amount,
+                        burnSource,
+                    );
+
+                const message = new LiquidityLayerMessage({
+                    deposit: new LiquidityLayerDeposit({
+                        tokenAddress: toUniversalAddress(burnMessage.burnTokenAddress),
+                        amount,
+                        sourceCctpDomain,
+                        destinationCctpDomain,
+                        cctpNonce,
+                        burnSource: toUniversalAddress(burnSource),
+                        mintRecipient: toUniversalAddress(encodedMintRecipient),
+                        payload: {
+                            id: 2,
+                            baseFee: 69n,
+                        },
+                    }),
+                });
+
+                // Override the payload ID in the deposit message.
+                const encodedMessage = message.encode();
+                encodedMessage[147] = 69;
+
+                const vaa = await postLiquidityLayerVaa(
+                    connection,
+                    payer,
+                    MOCK_GUARDIANS,
+                    foreignEndpointAddress,
+                    wormholeSequence++,
+                    encodedMessage,
+                );
+                const ix = await tokenRouter.redeemCctpFillIx(
+                    {
+                        payer: payer.publicKey,
+                        vaa,
+                    },
+                    {
+                        encodedCctpMessage,
+                        cctpAttestation,
+                    },
+                );
+
+                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
+                    units: 300_000,
+                });
+
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                await expectIxErr(
+                    connection,
+                    [computeIx, ix],
+                    [payer],
+                    "Error Code: InvalidDepositMessage",
+                    {
+                        addressLookupTableAccounts: [lookupTableAccount!],
+                    },
+                );
+            });
+
+            it("Cannot Redeem Fill with Invalid Deposit Payload ID", async function () {
+                const cctpNonce = testCctpNonce++;
+
+                // Concoct a Circle message.
+                const { destinationCctpDomain, burnMessage, encodedCctpMessage, cctpAttestation } =
+                    await craftCctpTokenBurnMessage(
+                        tokenRouter,
+                        sourceCctpDomain,
+                        cctpNonce,
+                        encodedMintRecipient,
+                        amount,
+                        burnSource,
+                    );
+
+                const message = new LiquidityLayerMessage({
+                    deposit: new LiquidityLayerDeposit({
+                        tokenAddress: toUniversalAddress(burnMessage.burnTokenAddress),
+                        amount,
+                        sourceCctpDomain,
+                        destinationCctpDomain,
+                        cctpNonce,
+                        burnSource: toUniversalAddress(burnSource),
+                        mintRecipient: toUniversalAddress(encodedMintRecipient),
+                        payload: {
+                            id: 2,
+                            baseFee: 69n,
+                        },
+                    }),
+                });
+
+                const vaa = await postLiquidityLayerVaa(
+                    connection,
+                    payer,
+                    MOCK_GUARDIANS,
+                    foreignEndpointAddress,
+                    wormholeSequence++,
+                    message,
+                );
+                const ix = await tokenRouter.redeemCctpFillIx(
+                    {
+                        payer: payer.publicKey,
+                        vaa,
+                    },
+                    {
+                        encodedCctpMessage,
+                        cctpAttestation,
+                    },
+                );
+
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer],
+                    "Error Code: InvalidDepositPayloadId",
+                    {
+                        addressLookupTableAccounts: [lookupTableAccount!],
+                    },
+                );
+            });
+
+            it("Disable Router Endpoint on Matching Engine", async function () {
+                const ix = await tokenRouter.matchingEngineProgram().disableRouterEndpointIx(
+                    {
+                        owner: owner.publicKey,
+                    },
+                    foreignChain,
+                );
+
+                await expectIxOk(connection, [ix], [owner]);
+            });
+
+            it("Cannot Redeem Fill without Router Endpoint", async function () {
+                const cctpNonce = testCctpNonce++;
+
+                // Concoct a Circle message.
+                const { destinationCctpDomain, burnMessage, encodedCctpMessage, cctpAttestation } =
+                    await craftCctpTokenBurnMessage(
+                        tokenRouter,
+                        sourceCctpDomain,
+                        cctpNonce,
+                        encodedMintRecipient,
+                        amount,
+                        burnSource,
+                    );
+
+                const message = new LiquidityLayerMessage({
+                    deposit: new LiquidityLayerDeposit({
+                        tokenAddress: toUniversalAddress(burnMessage.burnTokenAddress),
+                        amount,
+                        sourceCctpDomain,
+                        destinationCctpDomain,
+                        cctpNonce,
+                        burnSource: toUniversalAddress(burnSource),
+                        mintRecipient: toUniversalAddress(encodedMintRecipient),
+                        payload: {
+                            id: 1,
+                            sourceChain: toChain(foreignChain),
+                            orderSender: toUniversalAddress(Buffer.alloc(32, "d00d", "hex")),
+                            redeemer: toUniversalAddress(redeemer.publicKey.toBuffer()),
+                            redeemerMessage: Buffer.from("Somebody set up us the bomb"),
+                        },
+                    }),
+                });
+
+                const vaa = await postLiquidityLayerVaa(
+                    connection,
+                    payer,
+                    MOCK_GUARDIANS,
+                    foreignEndpointAddress,
+                    wormholeSequence++,
+                    message,
+                );
+                const ix = await tokenRouter.redeemCctpFillIx(
+                    {
+                        payer: payer.publicKey,
+                        vaa,
+                    },
+                    {
+                        encodedCctpMessage,
+                        cctpAttestation,
+                    },
+                );
+
+                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
+                    units: 300_000,
+                });
+
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                await expectIxErr(
+                    connection,
+                    [computeIx, ix],
+                    [payer],
+                    "Error Code: EndpointDisabled",
+                    {
+                        addressLookupTableAccounts: [lookupTableAccount!],
+                    },
+                );
+
+                // Save for later.
+                localVariables.set("args", { encodedCctpMessage, cctpAttestation });
+                localVariables.set("vaa", vaa);
+            });
+
+            it("Update Router Endpoint", async function () {
+                const ix = await tokenRouter.matchingEngineProgram().updateCctpRouterEndpointIx(
+                    {
+                        owner: owner.publicKey,
+                    },
+                    {
+                        chain: foreignChain,
+                        address: foreignEndpointAddress,
+                        cctpDomain: foreignCctpDomain,
+                        mintRecipient: null,
+                    },
+                );
+
+                await expectIxOk(connection, [ix], [owner]);
+            });
+
+            it("Redeem Fill", async function () {
+                const args = localVariables.get("args") as {
+                    encodedCctpMessage: Buffer;
+                    cctpAttestation: Buffer;
+                };
+                const vaa = localVariables.get("vaa") as PublicKey;
+
+                const ix = await tokenRouter.redeemCctpFillIx(
+                    {
+                        payer: payer.publicKey,
+                        vaa,
+                    },
+                    args,
+                );
+
+                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
+                    units: 400_000,
+                });
+
+                const cctpMintRecipient = tokenRouter.cctpMintRecipientAddress();
+                const { amount: balanceBefore } = await splToken.getAccount(
+                    connection,
+                    cctpMintRecipient,
+                );
+
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                await expectIxOk(connection, [computeIx, ix], [payer], {
+                    addressLookupTableAccounts: [lookupTableAccount!],
+                });
+
+                // Check balance.
+                const { amount: balanceAfter } = await splToken.getAccount(
+                    connection,
+                    cctpMintRecipient,
+                );
+                expect(balanceAfter).equals(balanceBefore);
+
+                // TODO: check prepared fill account.
+            });
+
+            it("Redeem Same Fill is No-op", async function () {
+                const args = localVariables.get("args") as {
+                    encodedCctpMessage: Buffer;
+                    cctpAttestation: Buffer;
+                };
+                expect(localVariables.delete("args")).is.true;
+
+                const vaa = localVariables.get("vaa") as PublicKey;
+
+                const ix = await tokenRouter.redeemCctpFillIx(
+                    {
+                        payer: payer.publicKey,
+                        vaa,
+                    },
+                    args,
+                );
+
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                await expectIxOk(connection, [ix], [payer], {
+                    addressLookupTableAccounts: [lookupTableAccount!],
+                });
+
+                // TODO: check prepared fill account.
+
+                // Save for later.
+                localVariables.set("redeemIx", ix);
+            });
+
+            it("Consume Prepared Fill Partially after Redeem Fill", async function () {
+                const vaa = localVariables.get("vaa") as PublicKey;
+
+                const someone = Keypair.generate();
+                const dstToken = await splToken.createAssociatedTokenAccount(
+                    connection,
+                    payer,
+                    USDC_MINT_ADDRESS,
+                    someone.publicKey,
+                );
+
+                const preparedFill = tokenRouter.preparedFillAddress(vaa);
+                const custodyToken = tokenRouter.preparedCustodyTokenAddress(preparedFill);
+                const { amount: custodyTokenBalanceBefore } = await splToken.getAccount(
+                    connection,
+                    custodyToken,
+                );
+
+                const amount = custodyTokenBalanceBefore / 2n;
+                const ix = await tokenRouter.consumePreparedFillPartialIx(
+                    {
+                        preparedFill,
+                        redeemer: redeemer.publicKey,
+                        dstToken,
+                        beneficiary: Keypair.generate().publicKey,
+                    },
+                    amount,
+                );
+
+                const commitment = "confirmed";
+                const txSig = await expectIxOk(connection, [ix], [payer, redeemer], {
+                    confirmOptions: { commitment },
+                });
+
+                const parsedTx = await connection.getParsedTransaction(txSig, {
+                    commitment,
+                    maxSupportedTransactionVersion: 0,
+                });
+                if (parsedTx === null) {
+                    throw new Error("parsedTx is null");
+                }
+
+                let evt: any = null;
+                for (const innerIx of parsedTx.meta?.innerInstructions!) {
+                    for (const ix of innerIx.instructions) {
+                        if (!ix.programId.equals(tokenRouter.ID) || !("data" in ix)) {
+                            continue;
+                        }
+
+                        const data = utils.bytes.bs58.decode(ix.data);
+                        if (!data.subarray(0, 8).equals(CPI_EVENT_IX_SELECTOR)) {
+                            continue;
+                        }
+
+                        const decoded = tokenRouter.program.coder.events.decode(
+                            utils.bytes.base64.encode(data.subarray(8)),
+                        );
+                        if (decoded !== null && decoded.name === "preparedFillConsumed") {
+                            evt = decoded.data;
+                        }
+                    }
+                }
+                expect(evt).is.not.null;
+                expect(evt.preparedFill).to.eql(preparedFill);
+                expect(evt.dstToken).to.eql(dstToken);
+                expect(BigInt(evt.amountConsumed.toString())).equals(amount);
+                expect(BigInt(evt.amountRemaining.toString())).equals(
+                    custodyTokenBalanceBefore - amount,
+                );
+
+                // The prepared fill stays open until its custody token account is drained.
+                const { amount: custodyTokenBalanceAfter } = await splToken.getAccount(
+                    connection,
+                    custodyToken,
+                );
+                expect(custodyTokenBalanceAfter).equals(custodyTokenBalanceBefore - amount);
+
+                const { amount: dstTokenBalance } = await splToken.getAccount(connection, dstToken);
+                expect(dstTokenBalance).equals(amount);
+
+                const accInfo = await connection.getAccountInfo(preparedFill);
+                expect(accInfo).is.not.null;
+            });
+
+            it("Consume Prepared Fill after Redeem Fill", async function () {
+                const vaa = localVariables.get("vaa") as PublicKey;
+                expect(localVariables.delete("vaa")).is.true;
+
+                const someone = Keypair.generate();
+                const dstToken = await splToken.createAssociatedTokenAccount(
+                    connection,
+                    payer,
+                    USDC_MINT_ADDRESS,
+                    someone.publicKey,
+                );
+
+                const beneficiary = Keypair.generate().publicKey;
+
+                const preparedFill = tokenRouter.preparedFillAddress(vaa);
+                const expectedPreparedFillLamports = await connection
+                    .getAccountInfo(preparedFill)
+                    .then((info) => info!.lamports);
+
+                const custodyToken = tokenRouter.preparedCustodyTokenAddress(preparedFill);
+                const { amount: custodyTokenBalance } = await splToken.getAccount(
+                    connection,
+                    custodyToken,
                 );
+                const expectedCustodyTokenLamports = await connection
+                    .getAccountInfo(custodyToken)
+                    .then((info) => info!.lamports);
 
-                for (const key of [
-                    preparedOrder,
-                    tokenRouter.preparedCustodyTokenAddress(preparedOrder),
-                ]) {
-                    const accInfo = await connection.getAccountInfo(key);
+                const ix = await tokenRouter.consumePreparedFillIx({
+                    preparedFill,
+                    redeemer: redeemer.publicKey,
+                    dstToken,
+                    beneficiary,
+                });
+
+                await expectIxOk(connection, [ix], [payer, redeemer]);
+
+                {
+                    const accInfo = await connection.getAccountInfo(preparedFill);
                     expect(accInfo).is.null;
                 }
-            }
+                {
+                    const accInfo = await connection.getAccountInfo(custodyToken);
+                    expect(accInfo).is.null;
+                }
+
+                const { amount: dstTokenBalance } = await splToken.getAccount(connection, dstToken);
+                expect(dstTokenBalance).equals(custodyTokenBalance);
+
+                const beneficiaryBalance = await connection.getBalance(beneficiary);
+                expect(beneficiaryBalance).equals(
+                    expectedPreparedFillLamports + expectedCustodyTokenLamports,
+                );
+
+                // Save for later.
+                localVariables.set("consumeIx", ix);
+            });
+
+            it("Cannot Consume Fill Again", async function () {
+                const ix = localVariables.get("consumeIx") as TransactionInstruction;
+                expect(localVariables.delete("consumeIx")).is.true;
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer, redeemer],
+                    "prepared_fill. Error Code: AccountNotInitialized",
+                );
+            });
+
+            it("Cannot Redeem Fill Again (CCTP)", async function () {
+                const ix = localVariables.get("redeemIx") as TransactionInstruction;
+                expect(localVariables.delete("redeemIx")).is.true;
+
+                // NOTE: This is a CCTP message transmitter error.
+                await expectIxErr(connection, [ix], [payer], "Error Code: NonceAlreadyUsed");
+            });
         });
 
-        describe("Redeem Fill (CCTP)", function () {
+        describe("Redeem and Swap", function () {
             const encodedMintRecipient = Array.from(
                 tokenRouter.cctpMintRecipientAddress().toBuffer(),
             );
@@ -1228,83 +2781,61 @@ describe("Token Router", function () {
             const burnSource = Array.from(Buffer.alloc(32, "beefdead", "hex"));
             const redeemer = Keypair.generate();
 
-            const localVariables = new Map<string, any>();
-
-            it("Cannot Redeem Fill from Invalid Source Router Chain", async function () {
-                const cctpNonce = testCctpNonce++;
+            // The mock AMM swaps USDC for the output mint at a fixed price of two.
+            const mockAmmProgram = new PublicKey("MockAmm111111111111111111111111111111111111");
+            const priceNumerator = 2n;
+            let mintOut: PublicKey;
+            let pool: PublicKey;
+            let dstToken: PublicKey;
 
-                // Concoct a Circle message.
-                const { destinationCctpDomain, burnMessage, encodedCctpMessage, cctpAttestation } =
-                    await craftCctpTokenBurnMessage(
-                        tokenRouter,
-                        sourceCctpDomain,
-                        cctpNonce,
-                        encodedMintRecipient,
-                        amount,
-                        burnSource,
-                    );
+            const localVariables = new Map<string, any>();
 
-                const message = new LiquidityLayerMessage({
-                    deposit: new LiquidityLayerDeposit({
-                        tokenAddress: toUniversalAddress(burnMessage.burnTokenAddress),
-                        amount,
-                        sourceCctpDomain,
-                        destinationCctpDomain,
-                        cctpNonce,
-                        burnSource: toUniversalAddress(burnSource),
-                        mintRecipient: toUniversalAddress(encodedMintRecipient),
-                        payload: {
-                            id: 1,
-                            sourceChain: toChain(foreignChain),
-                            orderSender: toUniversalAddress(Buffer.alloc(32, "d00d", "hex")),
-                            redeemer: toUniversalAddress(redeemer.publicKey.toBuffer()),
-                            redeemerMessage: Buffer.from("Somebody set up us the bomb"),
-                        },
-                    }),
+            before("Set Up Mock AMM Pool", async function () {
+                mintOut = await splToken.createMint(connection, payer, payer.publicKey, null, 6);
+
+                [pool] = PublicKey.findProgramAddressSync(
+                    [Buffer.from("pool"), USDC_MINT_ADDRESS.toBuffer(), mintOut.toBuffer()],
+                    mockAmmProgram,
+                );
+
+                const ix = new TransactionInstruction({
+                    programId: mockAmmProgram,
+                    keys: [
+                        { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+                        { pubkey: USDC_MINT_ADDRESS, isSigner: false, isWritable: false },
+                        { pubkey: mintOut, isSigner: false, isWritable: false },
+                        { pubkey: pool, isSigner: false, isWritable: true },
+                        { pubkey: poolVault(USDC_MINT_ADDRESS), isSigner: false, isWritable: true },
+                        { pubkey: poolVault(mintOut), isSigner: false, isWritable: true },
+                        { pubkey: splToken.TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+                        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                    ],
+                    data: Buffer.concat([
+                        instructionDiscriminator("initialize_pool"),
+                        encodeU64(priceNumerator),
+                        encodeU64(1n),
+                    ]),
                 });
+                await expectIxOk(connection, [ix], [payer]);
 
-                const vaa = await postLiquidityLayerVaa(
+                await splToken.mintTo(
                     connection,
                     payer,
-                    MOCK_GUARDIANS,
-                    foreignEndpointAddress,
-                    wormholeSequence++,
-                    message,
-                    { sourceChain: "Polygon" },
-                );
-                const ix = await tokenRouter.redeemCctpFillIx(
-                    {
-                        payer: payer.publicKey,
-                        vaa,
-                        sourceRouterEndpoint: tokenRouter
-                            .matchingEngineProgram()
-                            .routerEndpointAddress(foreignChain),
-                    },
-                    {
-                        encodedCctpMessage,
-                        cctpAttestation,
-                    },
+                    mintOut,
+                    poolVault(mintOut),
+                    payer,
+                    1_000_000_000n,
                 );
 
-                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
-                    units: 300_000,
-                });
-
-                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
-                    lookupTableAddress,
-                );
-                await expectIxErr(
+                dstToken = await splToken.createAssociatedTokenAccount(
                     connection,
-                    [computeIx, ix],
-                    [payer],
-                    "Error Code: InvalidSourceRouter",
-                    {
-                        addressLookupTableAccounts: [lookupTableAccount!],
-                    },
+                    payer,
+                    mintOut,
+                    redeemer.publicKey,
                 );
             });
 
-            it("Cannot Redeem Fill from Invalid Source Router Address", async function () {
+            it("Redeem Fill", async function () {
                 const cctpNonce = testCctpNonce++;
 
                 // Concoct a Circle message.
@@ -1341,7 +2872,7 @@ describe("Token Router", function () {
                     connection,
                     payer,
                     MOCK_GUARDIANS,
-                    new Array(32).fill(0), // emitter address
+                    foreignEndpointAddress,
                     wormholeSequence++,
                     message,
                 );
@@ -1357,169 +2888,241 @@ describe("Token Router", function () {
                 );
 
                 const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
-                    units: 300_000,
+                    units: 400_000,
                 });
 
                 const { value: lookupTableAccount } = await connection.getAddressLookupTable(
                     lookupTableAddress,
                 );
-                await expectIxErr(
-                    connection,
-                    [computeIx, ix],
-                    [payer],
-                    "Error Code: InvalidSourceRouter",
-                    {
-                        addressLookupTableAccounts: [lookupTableAccount!],
-                    },
-                );
-            });
-
-            it("Cannot Redeem Fill with Invalid Deposit Message", async function () {
-                const cctpNonce = testCctpNonce++;
-
-                // Concoct a Circle message.
-                const { destinationCctpDomain, burnMessage, encodedCctpMessage, cctpAttestation } =
-                    await craftCctpTokenBurnMessage(
-                        tokenRouter,
-                        sourceCctpDomain,
-                        cctpNonce,
-                        encodedMintRecipient,
-                        amount,
-                        burnSource,
-                    );
-
-                const message = new LiquidityLayerMessage({
-                    deposit: new LiquidityLayerDeposit({
-                        tokenAddress: toUniversalAddress(burnMessage.burnTokenAddress),
-                        amount,
-                        sourceCctpDomain,
-                        destinationCctpDomain,
-                        cctpNonce,
-                        burnSource: toUniversalAddress(burnSource),
-                        mintRecipient: toUniversalAddress(encodedMintRecipient),
-                        payload: {
-                            id: 2,
-                            baseFee: 69n,
-                        },
-                    }),
+                await expectIxOk(connection, [computeIx, ix], [payer], {
+                    addressLookupTableAccounts: [lookupTableAccount!],
                 });
 
-                // Override the payload ID in the deposit message.
-                const encodedMessage = message.encode();
-                encodedMessage[147] = 69;
+                // Save for later.
+                localVariables.set("preparedFill", tokenRouter.preparedFillAddress(vaa));
+            });
 
-                const vaa = await postLiquidityLayerVaa(
-                    connection,
-                    payer,
-                    MOCK_GUARDIANS,
-                    foreignEndpointAddress,
-                    wormholeSequence++,
-                    encodedMessage,
-                );
-                const ix = await tokenRouter.redeemCctpFillIx(
-                    {
-                        payer: payer.publicKey,
-                        vaa,
-                    },
+            it("Cannot Redeem and Swap with Non-Allowlisted Program", async function () {
+                const preparedFill = localVariables.get("preparedFill") as PublicKey;
+
+                const ix = await tokenRouter.redeemAndSwapIx(
                     {
-                        encodedCctpMessage,
-                        cctpAttestation,
+                        preparedFill,
+                        redeemer: redeemer.publicKey,
+                        dstToken,
+                        beneficiary: payer.publicKey,
+                        swapProgram: mockAmmProgram,
+                        swapAccounts: swapAccounts(preparedFill),
                     },
+                    { minAmountOut: 0n, swapData: swapData(amount) },
                 );
 
-                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
-                    units: 300_000,
-                });
-
-                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
-                    lookupTableAddress,
-                );
                 await expectIxErr(
                     connection,
-                    [computeIx, ix],
-                    [payer],
-                    "Error Code: InvalidDepositMessage",
-                    {
-                        addressLookupTableAccounts: [lookupTableAccount!],
-                    },
+                    [ix],
+                    [payer, redeemer],
+                    "swap_program. Error Code: AccountNotInitialized",
                 );
             });
 
-            it("Cannot Redeem Fill with Invalid Deposit Payload ID", async function () {
-                const cctpNonce = testCctpNonce++;
+            it("Add Swap Program", async function () {
+                const ix = await tokenRouter.addSwapProgramIx({
+                    owner: owner.publicKey,
+                    program: mockAmmProgram,
+                });
 
-                // Concoct a Circle message.
-                const { destinationCctpDomain, burnMessage, encodedCctpMessage, cctpAttestation } =
-                    await craftCctpTokenBurnMessage(
-                        tokenRouter,
-                        sourceCctpDomain,
-                        cctpNonce,
-                        encodedMintRecipient,
-                        amount,
-                        burnSource,
-                    );
+                await expectIxOk(connection, [ix], [owner]);
 
-                const message = new LiquidityLayerMessage({
-                    deposit: new LiquidityLayerDeposit({
-                        tokenAddress: toUniversalAddress(burnMessage.burnTokenAddress),
-                        amount,
-                        sourceCctpDomain,
-                        destinationCctpDomain,
-                        cctpNonce,
-                        burnSource: toUniversalAddress(burnSource),
-                        mintRecipient: toUniversalAddress(encodedMintRecipient),
-                        payload: {
-                            id: 2,
-                            baseFee: 69n,
-                        },
-                    }),
-                });
+                const { programId } = await tokenRouter.fetchSwapProgram(mockAmmProgram);
+                expect(programId).to.eql(mockAmmProgram);
+            });
 
-                const vaa = await postLiquidityLayerVaa(
-                    connection,
-                    payer,
-                    MOCK_GUARDIANS,
-                    foreignEndpointAddress,
-                    wormholeSequence++,
-                    message,
-                );
-                const ix = await tokenRouter.redeemCctpFillIx(
+            it("Cannot Redeem and Swap with Amount Out Too Low", async function () {
+                const preparedFill = localVariables.get("preparedFill") as PublicKey;
+
+                const ix = await tokenRouter.redeemAndSwapIx(
                     {
-                        payer: payer.publicKey,
-                        vaa,
+                        preparedFill,
+                        redeemer: redeemer.publicKey,
+                        dstToken,
+                        beneficiary: payer.publicKey,
+                        swapProgram: mockAmmProgram,
+                        swapAccounts: swapAccounts(preparedFill),
                     },
+                    { minAmountOut: amount * priceNumerator + 1n, swapData: swapData(amount) },
+                );
+
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer, redeemer],
+                    "Error Code: SwapAmountOutTooLow",
+                );
+            });
+
+            it("Cannot Redeem and Swap with Amount In Remaining", async function () {
+                const preparedFill = localVariables.get("preparedFill") as PublicKey;
+
+                const ix = await tokenRouter.redeemAndSwapIx(
                     {
-                        encodedCctpMessage,
-                        cctpAttestation,
+                        preparedFill,
+                        redeemer: redeemer.publicKey,
+                        dstToken,
+                        beneficiary: payer.publicKey,
+                        swapProgram: mockAmmProgram,
+                        swapAccounts: swapAccounts(preparedFill),
                     },
+                    { minAmountOut: 0n, swapData: swapData(amount - 1n) },
                 );
 
-                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
-                    lookupTableAddress,
-                );
                 await expectIxErr(
                     connection,
                     [ix],
-                    [payer],
-                    "Error Code: InvalidDepositPayloadId",
+                    [payer, redeemer],
+                    "Error Code: SwapAmountInRemaining",
+                );
+            });
+
+            it("Redeem and Swap", async function () {
+                const preparedFill = localVariables.get("preparedFill") as PublicKey;
+                expect(localVariables.delete("preparedFill")).is.true;
+
+                const beneficiary = Keypair.generate().publicKey;
+
+                const expectedPreparedFillLamports = await connection
+                    .getAccountInfo(preparedFill)
+                    .then((info) => info!.lamports);
+
+                const custodyToken = tokenRouter.preparedCustodyTokenAddress(preparedFill);
+                const expectedCustodyTokenLamports = await connection
+                    .getAccountInfo(custodyToken)
+                    .then((info) => info!.lamports);
+
+                const amountOut = amount * priceNumerator;
+                const ix = await tokenRouter.redeemAndSwapIx(
                     {
-                        addressLookupTableAccounts: [lookupTableAccount!],
+                        preparedFill,
+                        redeemer: redeemer.publicKey,
+                        dstToken,
+                        beneficiary,
+                        swapProgram: mockAmmProgram,
+                        swapAccounts: swapAccounts(preparedFill),
                     },
+                    { minAmountOut: amountOut, swapData: swapData(amount) },
+                );
+
+                const { amount: dstTokenBalanceBefore } = await splToken.getAccount(
+                    connection,
+                    dstToken,
+                );
+
+                await expectIxOk(connection, [ix], [payer, redeemer]);
+
+                for (const key of [preparedFill, custodyToken]) {
+                    const accInfo = await connection.getAccountInfo(key);
+                    expect(accInfo).is.null;
+                }
+
+                const { amount: dstTokenBalanceAfter } = await splToken.getAccount(
+                    connection,
+                    dstToken,
+                );
+                expect(dstTokenBalanceAfter).equals(dstTokenBalanceBefore + amountOut);
+
+                const { amount: vaultInBalance } = await splToken.getAccount(
+                    connection,
+                    poolVault(USDC_MINT_ADDRESS),
+                );
+                expect(vaultInBalance).equals(amount);
+
+                const beneficiaryBalance = await connection.getBalance(beneficiary);
+                expect(beneficiaryBalance).equals(
+                    expectedPreparedFillLamports + expectedCustodyTokenLamports,
                 );
             });
 
-            it("Disable Router Endpoint on Matching Engine", async function () {
-                const ix = await tokenRouter.matchingEngineProgram().disableRouterEndpointIx(
+            function poolVault(mint: PublicKey) {
+                return PublicKey.findProgramAddressSync(
+                    [Buffer.from("vault"), pool.toBuffer(), mint.toBuffer()],
+                    mockAmmProgram,
+                )[0];
+            }
+
+            function swapAccounts(preparedFill: PublicKey) {
+                // The prepared fill signs for its custody token account, which the AMM transfers
+                // the amount in from.
+                return [
+                    { pubkey: preparedFill, isSigner: false, isWritable: false },
                     {
-                        owner: owner.publicKey,
+                        pubkey: tokenRouter.preparedCustodyTokenAddress(preparedFill),
+                        isSigner: false,
+                        isWritable: true,
                     },
-                    foreignChain,
-                );
+                    { pubkey: dstToken, isSigner: false, isWritable: true },
+                    { pubkey: pool, isSigner: false, isWritable: false },
+                    { pubkey: poolVault(USDC_MINT_ADDRESS), isSigner: false, isWritable: true },
+                    { pubkey: poolVault(mintOut), isSigner: false, isWritable: true },
+                    { pubkey: splToken.TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+                ];
+            }
 
-                await expectIxOk(connection, [ix], [owner]);
+            function swapData(amountIn: bigint) {
+                // The router checks the min amount out, so the AMM does not have to.
+                return Buffer.concat([
+                    instructionDiscriminator("swap"),
+                    encodeU64(amountIn),
+                    encodeU64(0n),
+                ]);
+            }
+        });
+
+        describe("Deliver Prepared Fill", function () {
+            const encodedMintRecipient = Array.from(
+                tokenRouter.cctpMintRecipientAddress().toBuffer(),
+            );
+            const sourceCctpDomain = 0;
+            const amount = 69n;
+            const burnSource = Array.from(Buffer.alloc(32, "beefdead", "hex"));
+
+            // The mock fill receiver is the redeemer, which owns its vault via its vault authority.
+            const fillReceiverProgram = new PublicKey(
+                "MockFi11Receiver111111111111111111111111111",
+            );
+            const [receiver] = PublicKey.findProgramAddressSync(
+                [Buffer.from("receiver")],
+                fillReceiverProgram,
+            );
+            const [vaultAuthority] = PublicKey.findProgramAddressSync(
+                [Buffer.from("liquidity-layer-vault")],
+                fillReceiverProgram,
+            );
+            const [vaultToken] = PublicKey.findProgramAddressSync(
+                [Buffer.from("liquidity-layer-vault"), USDC_MINT_ADDRESS.toBuffer()],
+                fillReceiverProgram,
+            );
+            const orderSender = Array.from(Buffer.alloc(32, "d00d", "hex"));
+            const redeemerMessage = Buffer.from("Somebody set up us the bomb");
+
+            const localVariables = new Map<string, any>();
+
+            before("Initialize Mock Fill Receiver", async function () {
+                const ix = new TransactionInstruction({
+                    programId: fillReceiverProgram,
+                    keys: [
+                        { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+                        { pubkey: receiver, isSigner: false, isWritable: true },
+                        { pubkey: vaultAuthority, isSigner: false, isWritable: false },
+                        { pubkey: USDC_MINT_ADDRESS, isSigner: false, isWritable: false },
+                        { pubkey: vaultToken, isSigner: false, isWritable: true },
+                        { pubkey: splToken.TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+                        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                    ],
+                    data: instructionDiscriminator("initialize"),
+                });
+                await expectIxOk(connection, [ix], [payer]);
             });
 
-            it("Cannot Redeem Fill without Router Endpoint", async function () {
+            it("Redeem Fill", async function () {
                 const cctpNonce = testCctpNonce++;
 
                 // Concoct a Circle message.
@@ -1545,9 +3148,9 @@ describe("Token Router", function () {
                         payload: {
                             id: 1,
                             sourceChain: toChain(foreignChain),
-                            orderSender: toUniversalAddress(Buffer.alloc(32, "d00d", "hex")),
-                            redeemer: toUniversalAddress(redeemer.publicKey.toBuffer()),
-                            redeemerMessage: Buffer.from("Somebody set up us the bomb"),
+                            orderSender: toUniversalAddress(orderSender),
+                            redeemer: toUniversalAddress(fillReceiverProgram.toBuffer()),
+                            redeemerMessage,
                         },
                     }),
                 });
@@ -1572,192 +3175,127 @@ describe("Token Router", function () {
                 );
 
                 const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
-                    units: 300_000,
+                    units: 400_000,
                 });
 
                 const { value: lookupTableAccount } = await connection.getAddressLookupTable(
                     lookupTableAddress,
                 );
-                await expectIxErr(
-                    connection,
-                    [computeIx, ix],
-                    [payer],
-                    "Error Code: EndpointDisabled",
-                    {
-                        addressLookupTableAccounts: [lookupTableAccount!],
-                    },
-                );
+                await expectIxOk(connection, [computeIx, ix], [payer], {
+                    addressLookupTableAccounts: [lookupTableAccount!],
+                });
 
                 // Save for later.
-                localVariables.set("args", { encodedCctpMessage, cctpAttestation });
-                localVariables.set("vaa", vaa);
-            });
-
-            it("Update Router Endpoint", async function () {
-                const ix = await tokenRouter.matchingEngineProgram().updateCctpRouterEndpointIx(
-                    {
-                        owner: owner.publicKey,
-                    },
-                    {
-                        chain: foreignChain,
-                        address: foreignEndpointAddress,
-                        cctpDomain: foreignCctpDomain,
-                        mintRecipient: null,
-                    },
-                );
-
-                await expectIxOk(connection, [ix], [owner]);
+                localVariables.set("preparedFill", tokenRouter.preparedFillAddress(vaa));
             });
 
-            it("Redeem Fill", async function () {
-                const args = localVariables.get("args") as {
-                    encodedCctpMessage: Buffer;
-                    cctpAttestation: Buffer;
-                };
-                const vaa = localVariables.get("vaa") as PublicKey;
+            it("Cannot Deliver Prepared Fill to Token Account Not Owned by Vault Authority", async function () {
+                const preparedFill = localVariables.get("preparedFill") as PublicKey;
 
-                const ix = await tokenRouter.redeemCctpFillIx(
+                const ix = await tokenRouter.deliverPreparedFillIx(
                     {
-                        payer: payer.publicKey,
-                        vaa,
+                        preparedFill,
+                        redeemerVaultToken: splToken.getAssociatedTokenAddressSync(
+                            USDC_MINT_ADDRESS,
+                            payer.publicKey,
+                        ),
                     },
-                    args,
+                    fillReceiverAccounts(),
                 );
 
-                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
-                    units: 400_000,
-                });
-
-                const cctpMintRecipient = tokenRouter.cctpMintRecipientAddress();
-                const { amount: balanceBefore } = await splToken.getAccount(
-                    connection,
-                    cctpMintRecipient,
-                );
+                await expectIxErr(connection, [ix], [payer], "Error Code: InvalidRedeemer");
+            });
 
-                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
-                    lookupTableAddress,
-                );
-                await expectIxOk(connection, [computeIx, ix], [payer], {
-                    addressLookupTableAccounts: [lookupTableAccount!],
+            it("Cannot Call Fill Receiver without Callback Authority Signer", async function () {
+                // Only the Token Router can sign for its callback authority, so nobody else can
+                // tell the fill receiver that it received a fill.
+                const ix = new TransactionInstruction({
+                    programId: fillReceiverProgram,
+                    keys: [
+                        {
+                            pubkey: tokenRouter.callbackAuthorityAddress(),
+                            isSigner: false,
+                            isWritable: false,
+                        },
+                        { pubkey: vaultToken, isSigner: false, isWritable: true },
+                        ...fillReceiverAccounts(),
+                    ],
+                    data: Buffer.concat([
+                        instructionDiscriminator("on_liquidity_layer_fill"),
+                        encodeU16(foreignChain),
+                        Buffer.from(orderSender),
+                        encodeU64(amount),
+                        Buffer.from([0, 0, 0, 0]), // redeemerMessage
+                    ]),
                 });
 
-                // Check balance.
-                const { amount: balanceAfter } = await splToken.getAccount(
+                await expectIxErr(
                     connection,
-                    cctpMintRecipient,
+                    [ix],
+                    [payer],
+                    "callback_authority. Error Code: AccountNotSigner",
                 );
-                expect(balanceAfter).equals(balanceBefore);
-
-                // TODO: check prepared fill account.
             });
 
-            it("Redeem Same Fill is No-op", async function () {
-                const args = localVariables.get("args") as {
-                    encodedCctpMessage: Buffer;
-                    cctpAttestation: Buffer;
-                };
-                expect(localVariables.delete("args")).is.true;
+            it("Deliver Prepared Fill", async function () {
+                const preparedFill = localVariables.get("preparedFill") as PublicKey;
+                expect(localVariables.delete("preparedFill")).is.true;
 
-                const vaa = localVariables.get("vaa") as PublicKey;
+                const custodyToken = tokenRouter.preparedCustodyTokenAddress(preparedFill);
+                const expectedLamports = await Promise.all(
+                    [preparedFill, custodyToken].map((key) =>
+                        connection.getAccountInfo(key).then((info) => info!.lamports),
+                    ),
+                ).then(([fill, custody]) => fill + custody);
 
-                const ix = await tokenRouter.redeemCctpFillIx(
+                const ix = await tokenRouter.deliverPreparedFillIx(
                     {
-                        payer: payer.publicKey,
-                        vaa,
+                        preparedFill,
+                        redeemerVaultToken: vaultToken,
                     },
-                    args,
-                );
-
-                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
-                    lookupTableAddress,
-                );
-                await expectIxOk(connection, [ix], [payer], {
-                    addressLookupTableAccounts: [lookupTableAccount!],
-                });
-
-                // TODO: check prepared fill account.
-
-                // Save for later.
-                localVariables.set("redeemIx", ix);
-            });
-
-            it("Consume Prepared Fill after Redeem Fill", async function () {
-                const vaa = localVariables.get("vaa") as PublicKey;
-                expect(localVariables.delete("vaa")).is.true;
-
-                const someone = Keypair.generate();
-                const dstToken = await splToken.createAssociatedTokenAccount(
-                    connection,
-                    payer,
-                    USDC_MINT_ADDRESS,
-                    someone.publicKey,
+                    fillReceiverAccounts(),
                 );
 
-                const beneficiary = Keypair.generate().publicKey;
-
-                const preparedFill = tokenRouter.preparedFillAddress(vaa);
-                const expectedPreparedFillLamports = await connection
-                    .getAccountInfo(preparedFill)
-                    .then((info) => info!.lamports);
-
-                const custodyToken = tokenRouter.preparedCustodyTokenAddress(preparedFill);
-                const { amount: custodyTokenBalance } = await splToken.getAccount(
+                const { amount: vaultBalanceBefore } = await splToken.getAccount(
                     connection,
-                    custodyToken,
+                    vaultToken,
                 );
-                const expectedCustodyTokenLamports = await connection
-                    .getAccountInfo(custodyToken)
-                    .then((info) => info!.lamports);
-
-                const ix = await tokenRouter.consumePreparedFillIx({
-                    preparedFill,
-                    redeemer: redeemer.publicKey,
-                    dstToken,
-                    beneficiary,
-                });
+                const payerBalanceBefore = await connection.getBalance(payer.publicKey);
 
-                await expectIxOk(connection, [ix], [payer, redeemer]);
+                const txDetails = await expectIxOkDetails(connection, [ix], [payer]);
 
-                {
-                    const accInfo = await connection.getAccountInfo(preparedFill);
-                    expect(accInfo).is.null;
-                }
-                {
-                    const accInfo = await connection.getAccountInfo(custodyToken);
+                for (const key of [preparedFill, custodyToken]) {
+                    const accInfo = await connection.getAccountInfo(key);
                     expect(accInfo).is.null;
                 }
 
-                const { amount: dstTokenBalance } = await splToken.getAccount(connection, dstToken);
-                expect(dstTokenBalance).equals(custodyTokenBalance);
-
-                const beneficiaryBalance = await connection.getBalance(beneficiary);
-                expect(beneficiaryBalance).equals(
-                    expectedPreparedFillLamports + expectedCustodyTokenLamports,
-                );
-
-                // Save for later.
-                localVariables.set("consumeIx", ix);
-            });
-
-            it("Cannot Consume Fill Again", async function () {
-                const ix = localVariables.get("consumeIx") as TransactionInstruction;
-                expect(localVariables.delete("consumeIx")).is.true;
-
-                await expectIxErr(
+                const { amount: vaultBalanceAfter } = await splToken.getAccount(
                     connection,
-                    [ix],
-                    [payer, redeemer],
-                    "prepared_fill. Error Code: AccountNotInitialized",
+                    vaultToken,
                 );
-            });
+                expect(vaultBalanceAfter).equals(vaultBalanceBefore + amount);
 
-            it("Cannot Redeem Fill Again (CCTP)", async function () {
-                const ix = localVariables.get("redeemIx") as TransactionInstruction;
-                expect(localVariables.delete("redeemIx")).is.true;
+                // The payer prepared the fill, so it receives the lamports of the closed accounts.
+                const payerBalanceAfter = await connection.getBalance(payer.publicKey);
+                expect(payerBalanceAfter).equals(
+                    payerBalanceBefore + expectedLamports - txDetails!.meta!.fee,
+                );
 
-                // NOTE: This is a CCTP message transmitter error.
-                await expectIxErr(connection, [ix], [payer], "Error Code: NonceAlreadyUsed");
+                // The fill receiver recorded the fill.
+                const { data } = await connection.getAccountInfo(receiver).then((info) => info!);
+                expect(data.readBigUInt64LE(9)).equals(1n); // numFills
+                expect(data.readBigUInt64LE(17)).equals(amount); // totalAmount
+                expect(data.readUInt16LE(25)).equals(foreignChain); // lastSourceChain
+                expect(Array.from(data.subarray(27, 59))).to.eql(orderSender); // lastOrderSender
+                expect(data.readUInt32LE(59)).equals(redeemerMessage.length);
             });
+
+            function fillReceiverAccounts() {
+                return [
+                    { pubkey: receiver, isSigner: false, isWritable: true },
+                    { pubkey: vaultAuthority, isSigner: false, isWritable: false },
+                ];
+            }
         });
     });
 });
@@ -1812,3 +3350,19 @@ async function craftCctpTokenBurnMessage(
         cctpAttestation,
     };
 }
+
+function instructionDiscriminator(name: string) {
+    return createHash("sha256").update(`global:${name}`).digest().subarray(0, 8);
+}
+
+function encodeU16(value: number) {
+    const buf = Buffer.alloc(2);
+    buf.writeUInt16LE(value);
+    return buf;
+}
+
+function encodeU64(value: bigint) {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(value);
+    return buf;
+}
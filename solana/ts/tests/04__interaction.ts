@@ -14,6 +14,7 @@ import {
 } from "@solana/web3.js";
 import {
     FastMarketOrder,
+    FastMarketOrderV2,
     SlowOrderResponse,
 } from "@wormhole-foundation/example-liquidity-layer-definitions";
 import { Chain, ChainId, toChainId } from "@wormhole-foundation/sdk-base";
@@ -22,6 +23,7 @@ import { expect } from "chai";
 import { afterEach } from "mocha";
 import { CctpTokenBurnMessage } from "../src/cctp";
 import {
+    ID_FAST_MARKET_ORDER_V2,
     LiquidityLayerDeposit,
     LiquidityLayerMessage,
     uint64ToBN,
@@ -48,12 +50,16 @@ import {
     postLiquidityLayerVaa,
     toUniversalAddress,
     waitUntilSlot,
+    waitUntilTimestamp,
 } from "../src/testing";
 import * as tokenRouterSdk from "../src/tokenRouter";
 import { VaaAccount } from "../src/wormhole";
 
 const SOLANA_CHAIN_ID = toChainId("Solana");
 
+// Matches `FastFill::EXPIRY_SECONDS` when the Matching Engine is built for integration tests.
+const FAST_FILL_EXPIRY = 8;
+
 describe("Matching Engine <> Token Router", function () {
     const connection = new Connection(LOCALHOST, "processed");
 
@@ -93,7 +99,7 @@ describe("Matching Engine <> Token Router", function () {
 
             it("Cannot Add Local Router Endpoint without Executable", async function () {
                 const ix = await matchingEngine.addLocalRouterEndpointIx({
-                    ownerOrAssistant: ownerAssistant.publicKey,
+                    authority: ownerAssistant.publicKey,
                     tokenRouterProgram: SYSVAR_RENT_PUBKEY,
                 });
 
@@ -119,7 +125,7 @@ describe("Matching Engine <> Token Router", function () {
 
             it("Cannot Add Local Router Endpoint using System Program", async function () {
                 const ix = await matchingEngine.addLocalRouterEndpointIx({
-                    ownerOrAssistant: ownerAssistant.publicKey,
+                    authority: ownerAssistant.publicKey,
                     tokenRouterProgram: SystemProgram.programId,
                 });
 
@@ -145,7 +151,7 @@ describe("Matching Engine <> Token Router", function () {
 
             it("Add Local Router Endpoint using Token Router Program", async function () {
                 const ix = await matchingEngine.addLocalRouterEndpointIx({
-                    ownerOrAssistant: ownerAssistant.publicKey,
+                    authority: ownerAssistant.publicKey,
                     tokenRouterProgram: tokenRouter.ID,
                 });
                 await expectIxOk(connection, [ix], [ownerAssistant]);
@@ -155,7 +161,7 @@ describe("Matching Engine <> Token Router", function () {
                 );
                 const { bump } = routerEndpointData;
                 expect(routerEndpointData).to.eql(
-                    new matchingEngineSdk.RouterEndpoint(bump, {
+                    newRouterEndpoint(bump, {
                         chain: SOLANA_CHAIN_ID,
                         address: Array.from(tokenRouter.custodianAddress().toBuffer()),
                         mintRecipient: Array.from(
@@ -164,6 +170,9 @@ describe("Matching Engine <> Token Router", function () {
                         protocol: { local: { programId: tokenRouter.ID } },
                     }),
                 );
+                await expectRegisteredProtocol(SOLANA_CHAIN_ID, {
+                    local: { programId: tokenRouter.ID },
+                });
 
                 // Save for later.
                 localVariables.set("ix", ix);
@@ -629,6 +638,728 @@ describe("Matching Engine <> Token Router", function () {
                 });
             });
         });
+
+        describe("Token Router -- Redeem Non-USDC Fast Fill", function () {
+            const sourceChain: Chain = "Sui";
+            const sourceEmitter = Array.from(Buffer.alloc(32, "5e", "hex"));
+
+            const localVariables = new Map<string, any>();
+
+            before("Add Supported Mint and Token Bridge Router Endpoint", async function () {
+                const mint = await splToken.createMint(connection, payer, payer.publicKey, null, 6);
+                const { address: payerToken } = await splToken.getOrCreateAssociatedTokenAccount(
+                    connection,
+                    payer,
+                    mint,
+                    payer.publicKey,
+                );
+                await splToken.mintTo(
+                    connection,
+                    payer,
+                    mint,
+                    payerToken,
+                    payer,
+                    1_000_000_000_000n,
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await matchingEngine.addSupportedMintIx({
+                            owner: owner.publicKey,
+                            mint,
+                            feeRecipient: payer.publicKey,
+                        }),
+                        await matchingEngine.addTokenBridgeRouterEndpointIx(
+                            { authority: owner.publicKey, mint },
+                            {
+                                chain: toChainId(sourceChain),
+                                address: sourceEmitter,
+                                mintRecipient: null,
+                            },
+                        ),
+                    ],
+                    [payer, owner],
+                );
+
+                // Local custody token accounts are keyed by chain and mint.
+                const { mint: localCustodyMint } = await splToken.getAccount(
+                    connection,
+                    matchingEngine.localCustodyTokenAddress(toChainId(sourceChain), mint),
+                );
+                expect(localCustodyMint).to.eql(mint);
+
+                localVariables.set("mint", mint);
+                localVariables.set("payerToken", payerToken);
+            });
+
+            it("Execute Non-USDC Fast Order (Local)", async function () {
+                const mint = localVariables.get("mint") as PublicKey;
+                const payerToken = localVariables.get("payerToken") as PublicKey;
+
+                const fastMarketOrder = newFastMarketOrder();
+                const fastVaa = await postLiquidityLayerVaa(
+                    connection,
+                    payer,
+                    MOCK_GUARDIANS,
+                    sourceEmitter,
+                    wormholeSequence++,
+                    new LiquidityLayerMessage({ fastMarketOrder }),
+                    { sourceChain },
+                );
+                const fastVaaAccount = await VaaAccount.fetch(connection, fastVaa);
+                const auction = matchingEngine.auctionAddress(fastVaaAccount.digest());
+
+                await expectIxOk(
+                    connection,
+                    await matchingEngine.placeInitialOfferCctpIx(
+                        { payer: payer.publicKey, fastVaa, mint },
+                        { offerPrice: fastMarketOrder.maxFee },
+                    ),
+                    [payer],
+                );
+
+                const { info } = await matchingEngine.fetchAuction({ address: auction });
+                const { duration, gracePeriod } = await matchingEngine.fetchAuctionParameters(
+                    info!.configId,
+                );
+                await waitUntilSlot(
+                    connection,
+                    info!.startSlot.toNumber() + duration + gracePeriod - 1,
+                );
+
+                const reservedSequence = matchingEngine.reservedFastFillSequenceAddress(
+                    fastVaaAccount.digest(),
+                );
+                await expectIxOk(
+                    connection,
+                    [
+                        await matchingEngine.reserveFastFillSequenceActiveAuctionIx({
+                            payer: payer.publicKey,
+                            fastVaa,
+                        }),
+                    ],
+                    [payer],
+                );
+                const {
+                    fastFillSeeds: { orderSender, sequence },
+                } = await matchingEngine.fetchReservedFastFillSequence({
+                    address: reservedSequence,
+                });
+
+                const localCustodyTokenBalanceBefore =
+                    await matchingEngine.fetchLocalCustodyTokenBalance(
+                        toChainId(sourceChain),
+                        mint,
+                    );
+
+                const ix = await matchingEngine.executeFastOrderLocalIx({
+                    payer: payer.publicKey,
+                    fastVaa,
+                    auction,
+                    executorToken: payerToken,
+                });
+                await expectIxOk(connection, [ix], [payer]);
+
+                const fastFill = matchingEngine.fastFillAddress(
+                    toChainId(sourceChain),
+                    orderSender,
+                    sequence,
+                );
+                const fastFillData = await matchingEngine.fetchFastFill({ address: fastFill });
+                expect(fastFillData.mint).to.eql(mint);
+                expect(fastFillData.ownsCustodyToken).is.false;
+
+                const localCustodyTokenBalanceAfter =
+                    await matchingEngine.fetchLocalCustodyTokenBalance(
+                        toChainId(sourceChain),
+                        mint,
+                    );
+                expect(localCustodyTokenBalanceAfter).equals(
+                    localCustodyTokenBalanceBefore + uint64ToBigInt(fastFillData.info.amount),
+                );
+
+                // The USDC local custody token account for this chain does not exist.
+                const accInfo = await connection.getAccountInfo(
+                    matchingEngine.localCustodyTokenAddress(toChainId(sourceChain)),
+                );
+                expect(accInfo).is.null;
+
+                localVariables.set("fastFill", fastFill);
+            });
+
+            it("Redeem Non-USDC Fast Fill", async function () {
+                const mint = localVariables.get("mint") as PublicKey;
+                const fastFill = localVariables.get("fastFill") as PublicKey;
+                expect(localVariables.delete("fastFill")).is.true;
+
+                const {
+                    info: { amount },
+                } = await matchingEngine.fetchFastFill({ address: fastFill });
+                const localCustodyTokenBalanceBefore =
+                    await matchingEngine.fetchLocalCustodyTokenBalance(
+                        toChainId(sourceChain),
+                        mint,
+                    );
+
+                const ix = await tokenRouter.redeemFastFillIx({
+                    payer: payer.publicKey,
+                    fastFill,
+                });
+                await expectIxOk(connection, [ix], [payer]);
+
+                const { redeemed } = await matchingEngine.fetchFastFill({ address: fastFill });
+                expect(redeemed).is.true;
+
+                const localCustodyTokenBalanceAfter =
+                    await matchingEngine.fetchLocalCustodyTokenBalance(
+                        toChainId(sourceChain),
+                        mint,
+                    );
+                expect(localCustodyTokenBalanceAfter).equals(
+                    localCustodyTokenBalanceBefore - uint64ToBigInt(amount),
+                );
+
+                const preparedFill = tokenRouter.preparedFillAddress(fastFill);
+                const { mint: preparedMint, amount: preparedAmount } = await splToken.getAccount(
+                    connection,
+                    tokenRouter.preparedCustodyTokenAddress(preparedFill),
+                );
+                expect(preparedMint).to.eql(mint);
+                expect(preparedAmount).equals(uint64ToBigInt(amount));
+            });
+        });
+
+        describe("Token Router -- Relay Fast Fill", function () {
+            const emittedEvents: EmittedFilledLocalFastOrder[] = [];
+            let listenerId: number | undefined;
+
+            const relayerFee = 69_420n;
+
+            const localVariables = new Map<string, any>();
+
+            before("Start Event Listener", async function () {
+                listenerId = matchingEngine.onEventCpi((event, slot, signature) => {
+                    const { localFastOrderFilled } = event;
+                    if (localFastOrderFilled !== undefined) {
+                        emittedEvents.push({
+                            event: localFastOrderFilled,
+                            slot,
+                            signature,
+                        });
+                    }
+                });
+            });
+
+            after("Stop Event Listener", async function () {
+                if (listenerId !== undefined) {
+                    matchingEngine.program.removeEventListener(listenerId);
+                }
+            });
+
+            afterEach("Clear Emitted Events", function () {
+                while (emittedEvents.length > 0) {
+                    emittedEvents.pop();
+                }
+            });
+
+            before("Create Redeemer Token Account", async function () {
+                await splToken.getOrCreateAssociatedTokenAccount(
+                    connection,
+                    payer,
+                    USDC_MINT_ADDRESS,
+                    fastFillRedeemer.publicKey,
+                );
+            });
+
+            it("Cannot Relay Fast Fill without Relayer Fee", async function () {
+                const { preparedFill } = (await redeemFastFillForTest(
+                    { payer: payer.publicKey },
+                    emittedEvents,
+                ))!;
+
+                const ix = await tokenRouter.relayFastFillIx({
+                    relayer: liquidator.publicKey,
+                    relayerToken: splToken.getAssociatedTokenAddressSync(
+                        USDC_MINT_ADDRESS,
+                        liquidator.publicKey,
+                    ),
+                    preparedFill,
+                });
+                await expectIxErr(
+                    connection,
+                    [ix],
+                    [payer, liquidator],
+                    "Error Code: FastFillNotRelayable",
+                );
+            });
+
+            it("Execute Fast Order with Relayer Fee (Local)", async function () {
+                const fastMarketOrderV2: FastMarketOrderV2 = {
+                    ...newFastMarketOrder(),
+                    payloadId: ID_FAST_MARKET_ORDER_V2,
+                    gasDropoff: 0n,
+                    gasDropoffFee: 0n,
+                    relayerFee,
+                    destinationMint: toUniversalAddress(new Array(32).fill(0)),
+                    destinationMinAmountOut: 0n,
+                };
+                const fastFill = await executeFastOrderLocalToFill(
+                    new LiquidityLayerMessage({ fastMarketOrderV2 }),
+                );
+
+                const fastFillData = await matchingEngine.fetchFastFill({ address: fastFill });
+                expect(uint64ToBigInt(fastFillData.relayerFee)).equals(relayerFee);
+
+                localVariables.set("fastFill", fastFill);
+            });
+
+            it("Relay Fast Fill", async function () {
+                const fastFill = localVariables.get("fastFill") as PublicKey;
+                expect(localVariables.delete("fastFill")).is.true;
+
+                await expectIxOk(
+                    connection,
+                    [await tokenRouter.redeemFastFillIx({ payer: payer.publicKey, fastFill })],
+                    [payer],
+                );
+
+                const preparedFill = tokenRouter.preparedFillAddress(fastFill);
+                const preparedCustodyToken = tokenRouter.preparedCustodyTokenAddress(preparedFill);
+                const { amount } = await splToken.getAccount(connection, preparedCustodyToken);
+
+                const relayerToken = splToken.getAssociatedTokenAddressSync(
+                    USDC_MINT_ADDRESS,
+                    liquidator.publicKey,
+                );
+                const redeemerToken = splToken.getAssociatedTokenAddressSync(
+                    USDC_MINT_ADDRESS,
+                    fastFillRedeemer.publicKey,
+                );
+                const { amount: relayerBalanceBefore } = await splToken.getAccount(
+                    connection,
+                    relayerToken,
+                );
+                const { amount: redeemerBalanceBefore } = await splToken.getAccount(
+                    connection,
+                    redeemerToken,
+                );
+
+                const ix = await tokenRouter.relayFastFillIx({
+                    relayer: liquidator.publicKey,
+                    relayerToken,
+                    preparedFill,
+                });
+                await expectIxOk(connection, [ix], [payer, liquidator]);
+
+                const { amount: relayerBalanceAfter } = await splToken.getAccount(
+                    connection,
+                    relayerToken,
+                );
+                expect(relayerBalanceAfter).equals(relayerBalanceBefore + relayerFee);
+
+                const { amount: redeemerBalanceAfter } = await splToken.getAccount(
+                    connection,
+                    redeemerToken,
+                );
+                expect(redeemerBalanceAfter).equals(redeemerBalanceBefore + amount - relayerFee);
+
+                for (const key of [preparedFill, preparedCustodyToken]) {
+                    const accInfo = await connection.getAccountInfo(key);
+                    expect(accInfo).is.null;
+                }
+            });
+        });
+
+        describe("Matching Engine -- Reclaim Expired Fast Fill", function () {
+            const escrowRedeemer = Keypair.generate();
+
+            const localVariables = new Map<string, any>();
+
+            before("Create Redeemer Token Account", async function () {
+                await splToken.getOrCreateAssociatedTokenAccount(
+                    connection,
+                    payer,
+                    USDC_MINT_ADDRESS,
+                    fastFillRedeemer.publicKey,
+                );
+            });
+
+            it("Cannot Reclaim Fast Fill before Expiry", async function () {
+                const fastFill = await executeFastOrderLocalToFill(
+                    new LiquidityLayerMessage({ fastMarketOrder: newFastMarketOrder() }),
+                );
+
+                const ix = await matchingEngine.reclaimExpiredFastFillIx({
+                    payer: payer.publicKey,
+                    fastFill,
+                });
+                await expectIxErr(connection, [ix], [payer], "Error Code: FastFillNotExpired");
+
+                // Save for later.
+                localVariables.set("fastFill", fastFill);
+            });
+
+            it("Reclaim Expired Fast Fill to Redeemer Token Account", async function () {
+                const fastFill = localVariables.get("fastFill") as PublicKey;
+                expect(localVariables.delete("fastFill")).is.true;
+
+                const {
+                    info: { amount, timestamp },
+                } = await matchingEngine.fetchFastFill({ address: fastFill });
+                await waitUntilTimestamp(connection, timestamp.toNumber() + FAST_FILL_EXPIRY);
+
+                const redeemerToken = splToken.getAssociatedTokenAddressSync(
+                    USDC_MINT_ADDRESS,
+                    fastFillRedeemer.publicKey,
+                );
+                const { amount: balanceBefore } = await splToken.getAccount(
+                    connection,
+                    redeemerToken,
+                );
+                const localCustodyTokenBalanceBefore =
+                    await matchingEngine.fetchLocalCustodyTokenBalance(foreignChain);
+
+                const ix = await matchingEngine.reclaimExpiredFastFillIx({
+                    payer: payer.publicKey,
+                    fastFill,
+                });
+                await expectIxOk(connection, [ix], [payer]);
+
+                const { amount: balanceAfter } = await splToken.getAccount(
+                    connection,
+                    redeemerToken,
+                );
+                expect(balanceAfter).equals(balanceBefore + uint64ToBigInt(amount));
+
+                const localCustodyTokenBalanceAfter =
+                    await matchingEngine.fetchLocalCustodyTokenBalance(foreignChain);
+                expect(localCustodyTokenBalanceAfter).equals(
+                    localCustodyTokenBalanceBefore - uint64ToBigInt(amount),
+                );
+
+                const accInfo = await connection.getAccountInfo(fastFill);
+                expect(accInfo).is.null;
+            });
+
+            it("Reclaim Expired Fast Fill into Escrow", async function () {
+                const fastFill = await executeFastOrderLocalToFill(
+                    new LiquidityLayerMessage({
+                        fastMarketOrder: {
+                            ...newFastMarketOrder(),
+                            redeemer: toUniversalAddress(escrowRedeemer.publicKey.toBuffer()),
+                        },
+                    }),
+                );
+
+                const {
+                    info: { amount, timestamp },
+                } = await matchingEngine.fetchFastFill({ address: fastFill });
+                await waitUntilTimestamp(connection, timestamp.toNumber() + FAST_FILL_EXPIRY);
+
+                // The redeemer does not have an associated token account, so the fill amount is
+                // escrowed.
+                const ix = await matchingEngine.reclaimExpiredFastFillIx({
+                    payer: payer.publicKey,
+                    fastFill,
+                });
+                await expectIxOk(connection, [ix], [payer]);
+
+                const escrowToken = matchingEngine.fastFillEscrowTokenAddress(
+                    escrowRedeemer.publicKey,
+                    USDC_MINT_ADDRESS,
+                );
+                const { amount: escrowBalance, owner } = await splToken.getAccount(
+                    connection,
+                    escrowToken,
+                );
+                expect(escrowBalance).equals(uint64ToBigInt(amount));
+                expect(owner).to.eql(matchingEngine.custodianAddress());
+
+                const accInfo = await connection.getAccountInfo(fastFill);
+                expect(accInfo).is.null;
+            });
+
+            it("Claim Fast Fill Escrow", async function () {
+                const escrowToken = matchingEngine.fastFillEscrowTokenAddress(
+                    escrowRedeemer.publicKey,
+                    USDC_MINT_ADDRESS,
+                );
+                const { amount } = await splToken.getAccount(connection, escrowToken);
+
+                // The redeemer can claim to a token account it does not own.
+                const recipientToken = splToken.getAssociatedTokenAddressSync(
+                    USDC_MINT_ADDRESS,
+                    payer.publicKey,
+                );
+                const { amount: balanceBefore } = await splToken.getAccount(
+                    connection,
+                    recipientToken,
+                );
+
+                const ix = await matchingEngine.claimFastFillEscrowIx({
+                    redeemer: escrowRedeemer.publicKey,
+                    mint: USDC_MINT_ADDRESS,
+                    recipientToken,
+                });
+                await expectIxOk(connection, [ix], [payer, escrowRedeemer]);
+
+                const { amount: balanceAfter } = await splToken.getAccount(
+                    connection,
+                    recipientToken,
+                );
+                expect(balanceAfter).equals(balanceBefore + amount);
+
+                const accInfo = await connection.getAccountInfo(escrowToken);
+                expect(accInfo).is.null;
+            });
+        });
+
+        describe("Matching Engine -- Destination Asset Auction (Local)", function () {
+            const amountOut = 600_000_000n;
+
+            const localVariables = new Map<string, any>();
+
+            before("Add Supported Destination Mint", async function () {
+                const destinationMint = await splToken.createMint(
+                    connection,
+                    payer,
+                    payer.publicKey,
+                    null,
+                    6,
+                );
+                const { address: destinationOfferToken } =
+                    await splToken.getOrCreateAssociatedTokenAccount(
+                        connection,
+                        payer,
+                        destinationMint,
+                        payer.publicKey,
+                    );
+                await splToken.mintTo(
+                    connection,
+                    payer,
+                    destinationMint,
+                    destinationOfferToken,
+                    payer,
+                    1_000_000_000_000n,
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await matchingEngine.addSupportedMintIx({
+                            owner: owner.publicKey,
+                            mint: destinationMint,
+                            feeRecipient: payer.publicKey,
+                        }),
+                    ],
+                    [payer, owner],
+                );
+
+                localVariables.set("destinationMint", destinationMint);
+                localVariables.set("destinationOfferToken", destinationOfferToken);
+            });
+
+            it("Cannot Place Initial Offer in Destination Asset without Opting In", async function () {
+                const destinationMint = localVariables.get("destinationMint") as PublicKey;
+
+                const { fast } = await observeCctpOrderVaas({ finalized: false });
+
+                const ixs = await matchingEngine.placeInitialOfferDestinationAssetIx(
+                    { payer: payer.publicKey, fastVaa: fast.vaa, destinationMint },
+                    { amountOut },
+                );
+                await expectIxErr(connection, ixs, [payer], "Error Code: InvalidDestinationAsset");
+            });
+
+            it("Place Initial Offer in Destination Asset", async function () {
+                const destinationMint = localVariables.get("destinationMint") as PublicKey;
+                const destinationOfferToken = localVariables.get(
+                    "destinationOfferToken",
+                ) as PublicKey;
+
+                const { fast, finalized } = await observeCctpOrderVaas({
+                    fastMarketOrderV2: {
+                        ...newFastMarketOrder(),
+                        payloadId: ID_FAST_MARKET_ORDER_V2,
+                        gasDropoff: 0n,
+                        gasDropoffFee: 0n,
+                        relayerFee: 0n,
+                        destinationMint: toUniversalAddress(destinationMint.toBuffer()),
+                        destinationMinAmountOut: amountOut,
+                    },
+                });
+                const auction = matchingEngine.auctionAddress(fast.vaaAccount.digest());
+
+                // The offer must deliver at least the minimum amount out.
+                await expectIxErr(
+                    connection,
+                    await matchingEngine.placeInitialOfferDestinationAssetIx(
+                        { payer: payer.publicKey, fastVaa: fast.vaa, destinationMint },
+                        { amountOut: amountOut - 1n },
+                    ),
+                    [payer],
+                    "Error Code: AmountOutTooLow",
+                );
+
+                const { amount: balanceBefore } = await splToken.getAccount(
+                    connection,
+                    destinationOfferToken,
+                );
+
+                await expectIxOk(
+                    connection,
+                    await matchingEngine.placeInitialOfferDestinationAssetIx(
+                        { payer: payer.publicKey, fastVaa: fast.vaa, destinationMint },
+                        { amountOut },
+                    ),
+                    [payer],
+                );
+
+                const { amount: balanceAfter } = await splToken.getAccount(
+                    connection,
+                    destinationOfferToken,
+                );
+                expect(balanceAfter).equals(balanceBefore - amountOut);
+
+                const { info } = await matchingEngine.fetchAuction({ address: auction });
+                expect(info!.destinationAssetInfo).to.eql({
+                    custodyTokenBump: info!.destinationAssetInfo!.custodyTokenBump,
+                    amountOut: uint64ToBN(amountOut),
+                    mint: destinationMint,
+                    bestOfferToken: destinationOfferToken,
+                });
+
+                const { amount: destinationCustodyBalance } = await splToken.getAccount(
+                    connection,
+                    matchingEngine.auctionDestinationCustodyTokenAddress(auction),
+                );
+                expect(destinationCustodyBalance).equals(amountOut);
+
+                localVariables.set("fastVaa", fast.vaa);
+                localVariables.set("finalizedVaa", finalized!.vaa);
+                localVariables.set("cctp", finalized!.cctp);
+                localVariables.set("auction", auction);
+            });
+
+            it("Execute Fast Order in Destination Asset (Local)", async function () {
+                const destinationMint = localVariables.get("destinationMint") as PublicKey;
+                const fastVaa = localVariables.get("fastVaa") as PublicKey;
+                const auction = localVariables.get("auction") as PublicKey;
+
+                const { info } = await matchingEngine.fetchAuction({ address: auction });
+                const { duration, gracePeriod } = await matchingEngine.fetchAuctionParameters(
+                    info!.configId,
+                );
+                await waitUntilSlot(
+                    connection,
+                    info!.startSlot.toNumber() + duration + gracePeriod - 1,
+                );
+
+                await expectIxOk(
+                    connection,
+                    [
+                        await matchingEngine.reserveFastFillSequenceActiveAuctionIx({
+                            payer: payer.publicKey,
+                            fastVaa,
+                        }),
+                    ],
+                    [payer],
+                );
+                const {
+                    fastFillSeeds: { orderSender, sequence },
+                } = await matchingEngine.fetchReservedFastFillSequence({
+                    address: matchingEngine.reservedFastFillSequenceAddress(
+                        (await VaaAccount.fetch(connection, fastVaa)).digest(),
+                    ),
+                });
+
+                const ix = await matchingEngine.executeFastOrderLocalIx({
+                    payer: payer.publicKey,
+                    fastVaa,
+                    auction,
+                });
+                await expectIxOk(connection, [ix], [payer]);
+
+                // The fast fill is delivered in the destination asset and owns the token account
+                // holding the amount out.
+                const fastFill = matchingEngine.fastFillAddress(
+                    foreignChain,
+                    orderSender,
+                    sequence,
+                );
+                const { mint, ownsCustodyToken, info: fastFillInfo } =
+                    await matchingEngine.fetchFastFill({ address: fastFill });
+                expect(mint).to.eql(destinationMint);
+                expect(ownsCustodyToken).is.true;
+                expect(uint64ToBigInt(fastFillInfo.amount)).equals(amountOut);
+
+                const { amount: custodyBalance, owner: custodyOwner } = await splToken.getAccount(
+                    connection,
+                    matchingEngine.auctionDestinationCustodyTokenAddress(auction),
+                );
+                expect(custodyBalance).equals(amountOut);
+                expect(custodyOwner).to.eql(fastFill);
+
+                const { status } = await matchingEngine.fetchAuction({ address: auction });
+                expect(status.completed).is.not.undefined;
+            });
+
+            it("Settle Auction Complete in USDC", async function () {
+                const fastVaa = localVariables.get("fastVaa") as PublicKey;
+                expect(localVariables.delete("fastVaa")).is.true;
+                const finalizedVaa = localVariables.get("finalizedVaa") as PublicKey;
+                expect(localVariables.delete("finalizedVaa")).is.true;
+                const cctp = localVariables.get("cctp") as matchingEngineSdk.CctpMessageArgs;
+                expect(localVariables.delete("cctp")).is.true;
+                const auction = localVariables.get("auction") as PublicKey;
+                expect(localVariables.delete("auction")).is.true;
+
+                const prepareIx = await matchingEngine.prepareOrderResponseCctpIx(
+                    { payer: payer.publicKey, fastVaa, finalizedVaa },
+                    cctp,
+                );
+                const { value: lookupTableAccount } = await connection.getAddressLookupTable(
+                    lookupTableAddress,
+                );
+                const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
+                    units: 280_000,
+                });
+                await expectIxOk(connection, [computeIx, prepareIx], [payer], {
+                    addressLookupTableAccounts: [lookupTableAccount!],
+                });
+
+                const preparedOrderResponse = matchingEngine.preparedOrderResponseAddress(
+                    (await VaaAccount.fetch(connection, fastVaa)).digest(),
+                );
+                const { amount: repayment } = await splToken.getAccount(
+                    connection,
+                    matchingEngine.preparedCustodyTokenAddress(preparedOrderResponse),
+                );
+
+                // The solver is repaid in USDC.
+                const { info } = await matchingEngine.fetchAuction({ address: auction });
+                const { amount: balanceBefore, mint } = await splToken.getAccount(
+                    connection,
+                    info!.bestOfferToken,
+                );
+                expect(mint).to.eql(USDC_MINT_ADDRESS);
+
+                const settleIx = await matchingEngine.settleAuctionCompleteIx({
+                    preparedOrderResponse,
+                });
+                await expectIxOk(connection, [settleIx], [payer]);
+
+                const { amount: balanceAfter } = await splToken.getAccount(
+                    connection,
+                    info!.bestOfferToken,
+                );
+                expect(balanceAfter).equals(balanceBefore + repayment);
+
+                const { status } = await matchingEngine.fetchAuction({ address: auction });
+                expect(status.settled).is.not.undefined;
+            });
+        });
     });
 
     type PlaceInitialOfferOpts = ForTestOpts &
@@ -1365,6 +2096,9 @@ describe("Matching Engine <> Token Router", function () {
                     timestamp: new BN(txDetails!.blockTime!, 10, "be"),
                 },
                 Buffer.from(redeemerMessage),
+                USDC_MINT_ADDRESS,
+                uint64ToBN(0), // relayerFee
+                false, // ownsCustodyToken
             ),
         );
 
@@ -1507,6 +2241,9 @@ describe("Matching Engine <> Token Router", function () {
                     timestamp: new BN(txDetails!.blockTime!, 10, "be"),
                 },
                 Buffer.from(redeemerMessage),
+                USDC_MINT_ADDRESS,
+                uint64ToBN(0), // relayerFee
+                false, // ownsCustodyToken
             ),
         );
 
@@ -1554,6 +2291,69 @@ describe("Matching Engine <> Token Router", function () {
         return { event };
     }
 
+    async function executeFastOrderLocalToFill(
+        fastMessage: LiquidityLayerMessage,
+    ): Promise<PublicKey> {
+        const { fastMarketOrder, fastMarketOrderV2 } = fastMessage;
+
+        const fastVaa = await postLiquidityLayerVaa(
+            connection,
+            payer,
+            MOCK_GUARDIANS,
+            ethRouter,
+            wormholeSequence++,
+            fastMessage,
+            { sourceChain: "Ethereum" },
+        );
+        const fastVaaAccount = await VaaAccount.fetch(connection, fastVaa);
+        const auction = matchingEngine.auctionAddress(fastVaaAccount.digest());
+
+        await expectIxOk(
+            connection,
+            await matchingEngine.placeInitialOfferCctpIx(
+                { payer: payer.publicKey, fastVaa },
+                { offerPrice: (fastMarketOrderV2 ?? fastMarketOrder)!.maxFee },
+            ),
+            [payer],
+        );
+
+        const { info } = await matchingEngine.fetchAuction({ address: auction });
+        const { duration, gracePeriod } = await matchingEngine.fetchAuctionParameters(
+            info!.configId,
+        );
+        await waitUntilSlot(connection, info!.startSlot.toNumber() + duration + gracePeriod - 1);
+
+        const reservedSequence = matchingEngine.reservedFastFillSequenceAddress(
+            fastVaaAccount.digest(),
+        );
+        await expectIxOk(
+            connection,
+            [
+                await matchingEngine.reserveFastFillSequenceActiveAuctionIx({
+                    payer: payer.publicKey,
+                    fastVaa,
+                }),
+            ],
+            [payer],
+        );
+        const {
+            fastFillSeeds: { orderSender, sequence },
+        } = await matchingEngine.fetchReservedFastFillSequence({ address: reservedSequence });
+
+        const ix = await matchingEngine.executeFastOrderLocalIx({
+            payer: payer.publicKey,
+            fastVaa,
+            auction,
+            executorToken: splToken.getAssociatedTokenAddressSync(
+                USDC_MINT_ADDRESS,
+                payer.publicKey,
+            ),
+        });
+        await expectIxOk(connection, [ix], [payer]);
+
+        return matchingEngine.fastFillAddress(foreignChain, orderSender, sequence);
+    }
+
     async function disableRouterEndpointForTest(
         accounts: {
             owner: PublicKey;
@@ -1578,15 +2378,21 @@ describe("Matching Engine <> Token Router", function () {
         await expectIxOk(connection, [ix], signers);
 
         const routerEndpointData = await matchingEngine.fetchRouterEndpoint(SOLANA_CHAIN_ID);
-        const { bump } = routerEndpointData;
+        const { bump, disabledSlot } = routerEndpointData;
+        expect(disabledSlot).is.not.null;
         expect(routerEndpointData).to.eql(
-            new matchingEngineSdk.RouterEndpoint(bump, {
-                chain,
-                address: new Array(32).fill(0),
-                mintRecipient: new Array(32).fill(0),
-                protocol: { none: {} },
-            }),
+            newRouterEndpoint(
+                bump,
+                {
+                    chain,
+                    address: new Array(32).fill(0),
+                    mintRecipient: new Array(32).fill(0),
+                    protocol: { none: {} },
+                },
+                { disabledSlot },
+            ),
         );
+        await expectRegisteredProtocol(chain, { none: {} });
     }
 
     async function updateLocalRouterEndpointForTest(
@@ -1616,13 +2422,24 @@ describe("Matching Engine <> Token Router", function () {
         const routerEndpointData = await matchingEngine.fetchRouterEndpoint(SOLANA_CHAIN_ID);
         const { bump } = routerEndpointData;
         expect(routerEndpointData).to.eql(
-            new matchingEngineSdk.RouterEndpoint(bump, {
+            newRouterEndpoint(bump, {
                 chain: SOLANA_CHAIN_ID,
                 address: Array.from(tokenRouter.custodianAddress().toBuffer()),
                 mintRecipient: Array.from(tokenRouter.cctpMintRecipientAddress().toBuffer()),
                 protocol: { local: { programId: tokenRouter.ID } },
             }),
         );
+        await expectRegisteredProtocol(SOLANA_CHAIN_ID, { local: { programId: tokenRouter.ID } });
+    }
+
+    async function expectRegisteredProtocol(
+        chain: ChainId,
+        protocol: matchingEngineSdk.MessageProtocol,
+    ) {
+        const { endpoints } = await matchingEngine.fetchRouterEndpointRegistry();
+        expect(endpoints.filter((endpoint) => endpoint.chain == chain)).to.eql([
+            { chain, protocol },
+        ]);
     }
 
     async function redeemFastFillForTest(
@@ -1676,6 +2493,9 @@ describe("Matching Engine <> Token Router", function () {
             redeemed,
             info: fastFillInfo,
             redeemerMessage,
+            mint,
+            relayerFee,
+            ownsCustodyToken,
         } = await matchingEngine.fetchFastFill({ address: fastFill });
         expect(redeemed).equals(expectedRedeemed);
 
@@ -1693,6 +2513,9 @@ describe("Matching Engine <> Token Router", function () {
                 true, // redeemed
                 fastFillInfo,
                 redeemerMessage,
+                mint,
+                relayerFee,
+                ownsCustodyToken,
             ),
         );
 
@@ -1803,6 +2626,7 @@ describe("Matching Engine <> Token Router", function () {
         emitter?: Array<number>;
         vaaTimestamp?: number;
         fastMarketOrder?: FastMarketOrder;
+        fastMarketOrderV2?: FastMarketOrderV2;
         finalized?: boolean;
         slowOrderResponse?: SlowOrderResponse;
         finalizedSourceChain?: Chain;
@@ -1820,6 +2644,7 @@ describe("Matching Engine <> Token Router", function () {
             emitter,
             vaaTimestamp,
             fastMarketOrder,
+            fastMarketOrderV2,
             finalized,
             slowOrderResponse,
             finalizedSourceChain,
@@ -1832,6 +2657,13 @@ describe("Matching Engine <> Token Router", function () {
         vaaTimestamp ??= await getBlockTime(connection);
         fastMarketOrder ??= newFastMarketOrder();
         finalized ??= true;
+
+        // If the second version of the fast market order is specified, it is observed instead.
+        const fastMessage =
+            fastMarketOrderV2 !== undefined
+                ? new LiquidityLayerMessage({ fastMarketOrderV2 })
+                : new LiquidityLayerMessage({ fastMarketOrder });
+        fastMarketOrder = LiquidityLayerMessage.decode(fastMessage.encode()).fastMarketOrder!;
         slowOrderResponse ??= newSlowOrderResponse();
         finalizedSourceChain ??= sourceChain;
         finalizedEmitter ??= emitter;
@@ -1849,9 +2681,7 @@ describe("Matching Engine <> Token Router", function () {
             MOCK_GUARDIANS,
             emitter,
             wormholeSequence++,
-            new LiquidityLayerMessage({
-                fastMarketOrder,
-            }),
+            fastMessage,
             { sourceChain, timestamp: vaaTimestamp },
         );
         const fastVaaAccount = await VaaAccount.fetch(connection, fastVaa);
@@ -1955,4 +2785,23 @@ describe("Matching Engine <> Token Router", function () {
             cctpAttestation,
         };
     }
+
+    function newRouterEndpoint(
+        bump: number,
+        info: matchingEngineSdk.EndpointInfo,
+        overrides: { disabledSlot?: BN | null } = {},
+    ) {
+        const { disabledSlot } = overrides;
+
+        return new matchingEngineSdk.RouterEndpoint(
+            bump,
+            info,
+            { paused: false, unpauseSlot: null, reason: 0 },
+            { windowSlots: uint64ToBN(0), maxNotional: uint64ToBN(0) },
+            disabledSlot ?? null,
+            null, // previousAddress
+            { maxRedeemerMessageLen: null, minOrderAmount: null, maxOrderAmount: null },
+            USDC_MINT_ADDRESS,
+        );
+    }
 });
@@ -78,6 +78,75 @@ impl TypePrefixedPayload<1> for FastMarketOrder {
     }
 }
 
+/// Second version of [FastMarketOrder], which requests a gas drop-off, automatic delivery and
+/// delivery in a destination asset for an order delivered to Solana. It is encoded like a [FastMarketOrder] followed by these fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastMarketOrderV2 {
+    pub order: FastMarketOrder,
+
+    /// Amount of native gas (lamports on Solana) to deliver to the redeemer.
+    pub gas_dropoff: u64,
+
+    /// Amount deducted from the order's amount to compensate whoever pays the gas drop-off.
+    pub gas_dropoff_fee: u64,
+
+    /// If nonzero, fee earned by a relayer who automatically delivers the fill to the redeemer.
+    pub relayer_fee: u64,
+
+    /// If nonzero, mint of the asset the fill must be delivered in on Solana. Solvers bid on the
+    /// amount out of this asset instead of an offer price.
+    pub destination_mint: [u8; 32],
+
+    /// Minimum amount of the destination asset that the fill must deliver.
+    pub destination_min_amount_out: u64,
+}
+
+impl Readable for FastMarketOrderV2 {
+    fn read<R>(reader: &mut R) -> std::io::Result<Self>
+    where
+        Self: Sized,
+        R: std::io::Read,
+    {
+        Ok(Self {
+            order: Readable::read(reader)?,
+            gas_dropoff: Readable::read(reader)?,
+            gas_dropoff_fee: Readable::read(reader)?,
+            relayer_fee: Readable::read(reader)?,
+            destination_mint: Readable::read(reader)?,
+            destination_min_amount_out: Readable::read(reader)?,
+        })
+    }
+}
+
+impl Writeable for FastMarketOrderV2 {
+    fn write<W>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        Self: Sized,
+        W: std::io::Write,
+    {
+        self.order.write(writer)?;
+        self.gas_dropoff.write(writer)?;
+        self.gas_dropoff_fee.write(writer)?;
+        self.relayer_fee.write(writer)?;
+        self.destination_mint.write(writer)?;
+        self.destination_min_amount_out.write(writer)?;
+        Ok(())
+    }
+}
+
+impl TypePrefixedPayload<1> for FastMarketOrderV2 {
+    const TYPE: Option<[u8; 1]> = Some([13]);
+
+    fn written_size(&self) -> usize {
+        // This will panic if the size is too large to fit in a usize. But better to panic than to
+        // saturate to usize::MAX.
+        self.order
+            .written_size()
+            .checked_add(crate::raw::V2_FIELDS_LEN)
+            .unwrap()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::raw;
@@ -129,5 +198,66 @@ mod test {
         };
 
         assert_eq!(fast_market_order, expected);
+        assert_eq!(parsed.gas_dropoff(), 0);
+        assert_eq!(parsed.gas_dropoff_fee(), 0);
+        assert_eq!(parsed.relayer_fee(), 0);
+        assert_eq!(parsed.destination_mint(), [0; 32]);
+        assert_eq!(parsed.destination_min_amount_out(), 0);
+    }
+
+    #[test]
+    fn serde_v2() {
+        let fast_market_order = FastMarketOrderV2 {
+            order: FastMarketOrder {
+                amount_in: 1234567890,
+                min_amount_out: 69420,
+                target_chain: 1,
+                redeemer: hex!("deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef"),
+                sender: hex!("beefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdead"),
+                refund_address: hex!(
+                    "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+                ),
+                max_fee: 1234567890,
+                init_auction_fee: 69420,
+                deadline: 420,
+                redeemer_message: b"All your base are belong to us."
+                    .to_vec()
+                    .try_into()
+                    .unwrap(),
+            },
+            gas_dropoff: 10_000_000,
+            gas_dropoff_fee: 2_000_000,
+            relayer_fee: 420_000,
+            destination_mint: hex!(
+                "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+            ),
+            destination_min_amount_out: 69_000,
+        };
+
+        let encoded = fast_market_order.to_vec();
+        assert_eq!(encoded[1..].len(), fast_market_order.written_size());
+
+        let msg = raw::LiquidityLayerMessage::parse(&encoded).unwrap();
+        let parsed = msg.to_fast_market_order_unchecked();
+
+        assert_eq!(parsed.amount_in(), fast_market_order.order.amount_in);
+        assert_eq!(
+            parsed.redeemer_message().as_ref(),
+            b"All your base are belong to us."
+        );
+        assert_eq!(parsed.gas_dropoff(), fast_market_order.gas_dropoff);
+        assert_eq!(parsed.gas_dropoff_fee(), fast_market_order.gas_dropoff_fee);
+        assert_eq!(parsed.relayer_fee(), fast_market_order.relayer_fee);
+        assert_eq!(
+            parsed.destination_mint(),
+            fast_market_order.destination_mint
+        );
+        assert_eq!(
+            parsed.destination_min_amount_out(),
+            fast_market_order.destination_min_amount_out
+        );
+
+        // These fields are only encoded in the second version.
+        assert!(raw::FastMarketOrder::parse(&encoded[1..]).is_err());
     }
 }
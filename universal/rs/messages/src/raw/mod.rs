@@ -72,11 +72,23 @@ impl<'a> LiquidityLayerMessage<'a> {
         match span[0] {
             1 => Ok(Self::Deposit(Deposit::parse(&span[1..])?)),
             11 => Ok(Self::FastMarketOrder(FastMarketOrder::parse(&span[1..])?)),
+            13 => Ok(Self::FastMarketOrder(FastMarketOrder::parse_v2(
+                &span[1..],
+            )?)),
             _ => Err("Unknown LiquidityLayerMessage type"),
         }
     }
 }
 
+/// Length of the fields encoded after the redeemer message in the second version of the fast
+/// market order.
+pub(crate) const V2_FIELDS_LEN: usize = 8 // gas_dropoff
+    + 8 // gas_dropoff_fee
+    + 8 // relayer_fee
+    + 32 // destination_mint
+    + 8 // destination_min_amount_out
+    ;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct FastMarketOrder<'a>(&'a [u8]);
 
@@ -128,20 +140,92 @@ impl<'a> FastMarketOrder<'a> {
     }
 
     pub fn redeemer_message(&'a self) -> Payload<'a> {
-        Payload::parse(&self.0[136..])
+        Payload::parse(&self.0[136..self.redeemer_message_end()])
+    }
+
+    /// Amount of native gas to deliver to the redeemer, which is only encoded in the second version
+    /// of the fast market order. Otherwise this amount is zero.
+    pub fn gas_dropoff(&self) -> u64 {
+        match self.v2_span() {
+            Some(span) => u64::from_be_bytes(span[..8].try_into().unwrap()),
+            None => Default::default(),
+        }
+    }
+
+    /// Amount deducted from the order's amount to compensate whoever pays the gas drop-off, which is
+    /// only encoded in the second version of the fast market order. Otherwise this amount is zero.
+    pub fn gas_dropoff_fee(&self) -> u64 {
+        match self.v2_span() {
+            Some(span) => u64::from_be_bytes(span[8..16].try_into().unwrap()),
+            None => Default::default(),
+        }
+    }
+
+    /// Fee earned by a relayer who automatically delivers the fill to the redeemer, which is only
+    /// encoded in the second version of the fast market order. Otherwise this fee is zero, meaning
+    /// the fill is not automatically delivered.
+    pub fn relayer_fee(&self) -> u64 {
+        match self.v2_span() {
+            Some(span) => u64::from_be_bytes(span[16..24].try_into().unwrap()),
+            None => Default::default(),
+        }
+    }
+
+    /// Mint of the asset the fill must be delivered in, which is only encoded in the second version
+    /// of the fast market order. Otherwise (or if this mint is zero) the fill is delivered in the
+    /// auctioned asset.
+    pub fn destination_mint(&self) -> [u8; 32] {
+        match self.v2_span() {
+            Some(span) => span[24..56].try_into().unwrap(),
+            None => Default::default(),
+        }
+    }
+
+    /// Minimum amount of the destination asset that the fill must deliver, which is only encoded in
+    /// the second version of the fast market order. Otherwise this amount is zero.
+    pub fn destination_min_amount_out(&self) -> u64 {
+        match self.v2_span() {
+            Some(span) => u64::from_be_bytes(span[56..64].try_into().unwrap()),
+            None => Default::default(),
+        }
     }
 
     pub fn parse(span: &'a [u8]) -> Result<Self, &'static str> {
+        Self::parse_versioned(span, false)
+    }
+
+    /// Parses the second version of the fast market order, which encodes a gas drop-off, a relayer
+    /// fee and a destination asset after the redeemer message.
+    pub fn parse_v2(span: &'a [u8]) -> Result<Self, &'static str> {
+        Self::parse_versioned(span, true)
+    }
+
+    fn redeemer_message_end(&self) -> usize {
+        usize::from(self.redeemer_message_len()).saturating_add(136)
+    }
+
+    fn v2_span(&self) -> Option<&[u8]> {
+        self.0
+            .get(self.redeemer_message_end()..)
+            .filter(|span| span.len() == V2_FIELDS_LEN)
+    }
+
+    fn parse_versioned(span: &'a [u8], is_v2: bool) -> Result<Self, &'static str> {
         if span.len() < 136 {
             return Err("FastMarketOrder span too short. Need at least 136 bytes");
         }
 
         let fast_market_order = Self(span);
 
-        // Check payload length vs actual payload.
-        if fast_market_order.redeemer_message().len()
-            != usize::from(fast_market_order.redeemer_message_len())
-        {
+        // Check payload length vs actual payload (and the second version's fields if there are any).
+        let expected_len = if is_v2 {
+            fast_market_order
+                .redeemer_message_end()
+                .saturating_add(V2_FIELDS_LEN)
+        } else {
+            fast_market_order.redeemer_message_end()
+        };
+        if span.len() != expected_len {
             return Err("FastMarketOrder payload length mismatch");
         }
 
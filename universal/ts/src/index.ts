@@ -22,6 +22,10 @@ export * from "./payloads";
 export namespace Message {
     // Type guard for message types
     export function is<N extends MessageName>(message: any, name: N): message is MessageType<N> {
+        // The second version of the fast market order has all of the first version's items, so
+        // check the payload ID if there is one.
+        if ("payloadId" in message && message.payloadId !== messages(name).id) return false;
+
         // Check that all items in the layout are represented in the message
         return messages(name).layout.filter((item) => !(item.name in message)).length === 0;
     }
@@ -46,11 +46,22 @@ const fastFillLayout = [
     { name: "redeemerMessage", binary: "bytes", lengthSize: 2 },
 ] as const satisfies Layout;
 
+// The second version of the fast market order encodes additional fields after the redeemer message.
+const fastMarketOrderV2Layout = [
+    ...fastMarketOrderLayout,
+    { name: "gasDropoff", binary: "uint", size: 8 },
+    { name: "gasDropoffFee", binary: "uint", size: 8 },
+    { name: "relayerFee", binary: "uint", size: 8 },
+    { name: "destinationMint", ...layoutItems.universalAddressItem },
+    { name: "destinationMinAmountOut", binary: "uint", size: 8 },
+] as const satisfies Layout;
+
 // prettier-ignore
 const messageLayouts = [
-    ["CctpDeposit",     { id: 1,  layout: cctpDepositLayout }],
-    ["FastMarketOrder", { id: 11, layout: fastMarketOrderLayout }],
-    ["FastFill",        { id: 12, layout: fastFillLayout }],
+    ["CctpDeposit",       { id: 1,  layout: cctpDepositLayout }],
+    ["FastMarketOrder",   { id: 11, layout: fastMarketOrderLayout }],
+    ["FastFill",          { id: 12, layout: fastFillLayout }],
+    ["FastMarketOrderV2", { id: 13, layout: fastMarketOrderV2Layout }],
 ] as const satisfies RoArray<[string, { id: number; layout: Layout }]>;
 
 export const messages = constMap(messageLayouts);
@@ -79,3 +90,4 @@ export function messageLayout<N extends MessageName>(name: N): MessageLayout<N>
 export type CctpDeposit = MessageType<"CctpDeposit">;
 export type FastMarketOrder = MessageType<"FastMarketOrder">;
 export type FastFill = MessageType<"FastFill">;
+export type FastMarketOrderV2 = MessageType<"FastMarketOrderV2">;
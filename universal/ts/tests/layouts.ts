@@ -1,5 +1,13 @@
-import { encoding } from "@wormhole-foundation/sdk-base";
-import { Message, MessageName, MessageType, Payload, PayloadName, PayloadType } from "../src/index";
+import { deserializeLayout, encoding, serializeLayout } from "@wormhole-foundation/sdk-base";
+import {
+    Message,
+    MessageName,
+    MessageType,
+    Payload,
+    PayloadName,
+    PayloadType,
+    messages,
+} from "../src/index";
 import { expect } from "chai";
 
 const msgs = [
@@ -36,3 +44,33 @@ describe("Message Serde", () => {
         });
     });
 });
+
+describe("Fast Market Order V2 Serde", () => {
+    // fast market order from above with the second version's fields appended
+    const data =
+        "0d000000003b9aca0000000000000000000002000000000000000000000000ffcf8fdee72ac11b5c542428b35eef5769c409f000000000000000000000000090f8bf6a479f320ead074411a4b0e7944ea8c9c100000000000000000000000090f8bf6a479f320ead074411a4b0e7944ea8c9c10000000000970fe000000000000186a000000000001f416c6c20796f75722062617365206172652062656c6f6e6720746f2075732e" +
+        "00000000000f4240" +
+        "0000000000001388" +
+        "0000000000000000" +
+        "1111111111111111111111111111111111111111111111111111111111111111" +
+        "0000000000000064";
+
+    it("Can decode and encode a FastMarketOrderV2", () => {
+        const bytes = encoding.hex.decode(data);
+        const decoded = Message.deserialize(bytes);
+        expect(Message.is(decoded, "FastMarketOrderV2")).to.be.true;
+        expect(Message.is(decoded, "FastMarketOrder")).to.be.false;
+        if (!Message.is(decoded, "FastMarketOrderV2")) throw new Error("Not a FastMarketOrderV2");
+
+        expect(decoded.payloadId).to.equal(messages("FastMarketOrderV2").id);
+        expect(decoded.amountIn).to.equal(1000000000n);
+        expect(decoded.gasDropoff).to.equal(1000000n);
+        expect(decoded.gasDropoffFee).to.equal(5000n);
+        expect(decoded.relayerFee).to.equal(0n);
+        expect(decoded.destinationMint.toUint8Array()).to.eql(new Uint8Array(32).fill(0x11));
+        expect(decoded.destinationMinAmountOut).to.equal(100n);
+
+        const encoded = Message.serialize(decoded);
+        expect(encoding.hex.encode(encoded)).to.equal(data);
+    });
+});